@@ -235,6 +235,13 @@ impl AttributeOption for Vec<Ident> {
     }
 }
 
+impl AttributeOption for Option<Ident> {
+    #[inline]
+    fn parse(values: Values) -> Result<Self> {
+        <Ident as AttributeOption>::parse(values).map(Some)
+    }
+}
+
 impl AttributeOption for Option<String> {
     fn parse(values: Values) -> Result<Self> {
         validate(&values, &[ValueKind::Name, ValueKind::Equals, ValueKind::SingleList])?;
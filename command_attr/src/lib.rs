@@ -80,6 +80,20 @@ macro_rules! match_options {
 /// the text is delimited by newlines. This mimics the behaviour of regular doc-comments,
 /// which are sugar for the `#[doc = "..."]` attribute.
 ///
+/// ## Converted arguments
+///
+/// Parameters marked with `#[arg]` are converted from the command's arguments,
+/// in the order they are declared, with their type's implementation of
+/// `ArgumentConvert`. If an argument is missing or can't be converted, the
+/// command returns the `ArgError` without running its body.
+///
+/// ```rust,ignore
+/// #[command]
+/// async fn topic(ctx: &Context, msg: &Message, #[arg] channel: ChannelId) -> CommandResult {
+///     ...
+/// }
+/// ```
+///
 /// # Notes
 /// The name of the command is parsed from the applied function,
 /// or may be specified inside the `#[command]` attribute, a lá `#[command("foobar")]`.
@@ -179,6 +193,7 @@ pub fn command(attr: TokenStream, input: TokenStream) -> TokenStream {
     } = options;
 
     propagate_err!(create_declaration_validations(&mut fun, DeclarFor::Command));
+    let conversions = create_argument_conversions(&mut fun);
 
     let res = parse_quote!(serenity::framework::standard::CommandResult);
     create_return_type_validation(&mut fun, res);
@@ -237,7 +252,10 @@ pub fn command(attr: TokenStream, input: TokenStream) -> TokenStream {
         #visibility fn #name<'fut> (#(#args),*) -> ::serenity::futures::future::BoxFuture<'fut, #ret> {
             use ::serenity::futures::future::FutureExt;
 
-            async move { #(#body)* }.boxed()
+            async move {
+                #(#conversions)*
+                #(#body)*
+            }.boxed()
         }
     })
     .into()
@@ -279,6 +297,10 @@ pub fn command(attr: TokenStream, input: TokenStream) -> TokenStream {
 /// | `#[embed_success_colour(n)]`                                                                                                                  | Colour that the help-embed will use normally.                                                                                                                                                                                                    | `n` is a name to one of the provided constants of the `Colour` struct or an RGB value `#RRGGBB`.           |
 /// | `#[max_levenshtein_distance(n)]`                                                                                                              | How much should the help command search for a similiar name.</br> Indicator for a nested guild. The prefix will be repeated based on what kind of level the item sits. A sub-group would be level two, a sub-sub-group would be level three.     | `n` is a 64-bit, unsigned integer.                                                                         |
 /// | `#[indention_prefix(s)]` </br> `#[indention_prefix = s]`                                                                                      | The prefix used to express how deeply nested a command or group is.                                                                                                                                                                              | `s` is a string                                                                                            |
+/// | `#[group_order(s)]`                                                                                                                           | Names of groups to list first, in the given order. Remaining groups follow in their registration order.                                                                                                                                          | `s` is a comma separated list of strings                                                                   |
+/// | `#[hide_empty_groups(b)]`                                                                                                                     | Whether groups without any eligible commands are left out. Defaults to `true`.                                                                                                                                                                   | `b` is a boolean. If no boolean is provided, the value is assumed to be `true`.                            |
+/// | `#[list_aliases(b)]`                                                                                                                          | Whether a command's aliases are listed. Defaults to `true`.                                                                                                                                                                                      | `b` is a boolean. If no boolean is provided, the value is assumed to be `true`.                            |
+/// | `#[formatter(f)]`                                                                                                                             | A function building the help-embed instead of the default layout, see `HelpOptions::formatter`.                                                                                                                                                  | `f` is the name of a function matching `HelpFormatterFn`                                                  |
 ///
 /// [`command`]: macro@command
 #[proc_macro_attribute]
@@ -338,7 +360,11 @@ pub fn help(attr: TokenStream, input: TokenStream) -> TokenStream {
             strikethrough_commands_tip_in_guild;
             sub_commands_label;
             max_levenshtein_distance;
-            indention_prefix
+            indention_prefix;
+            group_order;
+            hide_empty_groups;
+            list_aliases;
+            formatter
         ]);
     }
 
@@ -437,10 +463,15 @@ pub fn help(attr: TokenStream, input: TokenStream) -> TokenStream {
         embed_success_colour,
         max_levenshtein_distance,
         indention_prefix,
+        group_order,
+        hide_empty_groups,
+        list_aliases,
+        formatter,
     } = options;
 
     let strikethrough_commands_tip_in_dm = AsOption(strikethrough_commands_tip_in_dm);
     let strikethrough_commands_tip_in_guild = AsOption(strikethrough_commands_tip_in_guild);
+    let formatter = AsOption(formatter.map(|f| quote!(serenity::framework::standard::HelpFormatter(#f))));
 
     propagate_err!(create_declaration_validations(&mut fun, DeclarFor::Help));
 
@@ -495,6 +526,10 @@ pub fn help(attr: TokenStream, input: TokenStream) -> TokenStream {
             embed_success_colour: #embed_success_colour,
             max_levenshtein_distance: #max_levenshtein_distance,
             indention_prefix: #indention_prefix,
+            group_order: &[#(#group_order),*],
+            hide_empty_groups: #hide_empty_groups,
+            list_aliases: #list_aliases,
+            formatter: #formatter,
         };
 
         #(#cooked)*
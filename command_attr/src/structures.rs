@@ -49,6 +49,15 @@ impl Default for OnlyIn {
     }
 }
 
+/// Test if the argument is marked with `#[arg]`, to be converted from the
+/// command's arguments.
+fn is_converted(arg: &FnArg) -> bool {
+    match arg {
+        FnArg::Typed(typed) => typed.attrs.iter().any(|attr| attr.path.is_ident("arg")),
+        FnArg::Receiver(_) => false,
+    }
+}
+
 fn parse_argument(arg: FnArg) -> Result<Argument> {
     match arg {
         FnArg::Typed(typed) => {
@@ -139,6 +148,8 @@ pub struct CommandFun {
     pub visibility: Visibility,
     pub name: Ident,
     pub args: Vec<Argument>,
+    /// `#[arg]` arguments, converted from the command's arguments in order.
+    pub converted_args: Vec<Argument>,
     pub ret: Type,
     pub body: Vec<Stmt>,
 }
@@ -183,17 +194,25 @@ impl Parse for CommandFun {
         braced!(bcont in input);
         let body = bcont.call(Block::parse_within)?;
 
+        let (converted_args, args): (Vec<_>, Vec<_>) = args.into_iter().partition(is_converted);
+
         let args = args
             .into_iter()
             .map(parse_argument)
             .collect::<Result<Vec<_>>>()?;
 
+        let converted_args = converted_args
+            .into_iter()
+            .map(parse_argument)
+            .collect::<Result<Vec<_>>>()?;
+
         Ok(Self {
             attributes,
             cooked,
             visibility,
             name,
             args,
+            converted_args,
             ret,
             body,
         })
@@ -208,6 +227,7 @@ impl ToTokens for CommandFun {
             visibility,
             name,
             args,
+            converted_args: _,
             ret,
             body,
         } = self;
@@ -547,6 +567,10 @@ pub struct HelpOptions {
     pub embed_success_colour: Colour,
     pub max_levenshtein_distance: usize,
     pub indention_prefix: String,
+    pub group_order: Vec<String>,
+    pub hide_empty_groups: bool,
+    pub list_aliases: bool,
+    pub formatter: Option<Ident>,
 }
 
 impl Default for HelpOptions {
@@ -582,6 +606,10 @@ impl Default for HelpOptions {
             embed_success_colour: Colour::from_str("ROSEWATER").unwrap(),
             max_levenshtein_distance: 0,
             indention_prefix: "-".to_string(),
+            group_order: Vec::new(),
+            hide_empty_groups: true,
+            list_aliases: true,
+            formatter: None,
         }
     }
 }
@@ -181,6 +181,15 @@ pub fn create_declaration_validations(fun: &mut CommandFun, dec_for: DeclarFor)
         DeclarFor::Check => 4,
     };
 
+    if dec_for != DeclarFor::Command {
+        if let Some(arg) = fun.converted_args.first() {
+            return Err(Error::new(
+                arg.name.span(),
+                "`#[arg]` arguments are only supported by commands",
+            ));
+        }
+    }
+
     if fun.args.len() > len {
         return Err(Error::new(
             fun.args.last().unwrap().span(),
@@ -233,6 +242,37 @@ pub fn create_declaration_validations(fun: &mut CommandFun, dec_for: DeclarFor)
     Ok(())
 }
 
+/// Generates the statements converting the `#[arg]` arguments of a command
+/// through `ArgumentConvert`, in the order they were declared.
+///
+/// Must be called after [`create_declaration_validations`], which ensures the
+/// context, message and arguments are declared.
+pub fn create_argument_conversions(fun: &mut CommandFun) -> Vec<syn::Stmt> {
+    if fun.converted_args.is_empty() {
+        return Vec::new();
+    }
+
+    // Wildcards can't be referred to, so they are given a name.
+    for (arg, name) in fun.args.iter_mut().zip(&["_ctx", "_msg", "_args"]) {
+        if arg.name == "_" {
+            arg.name = Ident::new(name, arg.name.span());
+        }
+    }
+
+    fun.args[2].mutable = Some(Mut::default());
+
+    let ctx = &fun.args[0].name;
+    let msg = &fun.args[1].name;
+    let args = &fun.args[2].name;
+
+    fun.converted_args
+        .iter()
+        .map(|Argument { mutable, name, kind }| parse_quote! {
+            let #mutable #name = #args.single_converted::<#kind>(#ctx, #msg).await?;
+        })
+        .collect()
+}
+
 #[inline]
 pub fn create_return_type_validation(r#fn: &mut CommandFun, expect: Type) {
     let stmt = generate_type_validation(r#fn.ret.clone(), expect);
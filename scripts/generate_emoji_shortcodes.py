@@ -0,0 +1,200 @@
+#!/usr/bin/env python3
+"""Generates `src/utils/emoji_shortcodes.rs`, the table of emoji shortcodes.
+
+The shortcodes come from the emoji table of the `rich` library, itself
+generated from the data of the `emoji` package, which merges the Unicode CLDR
+names with the GitHub aliases. On top of these, the Discord client knows some
+names of its own, which are listed in `DISCORD_SHORTCODES` and take precedence.
+
+The table is written with the fully-qualified form of each emoji, adding the
+emoji presentation selector to the code points which default to a text
+presentation, as this is the form Discord expects for reactions.
+
+Usage: python3 scripts/generate_emoji_shortcodes.py
+
+`rich` is vendored by pip, so it does not need to be installed on its own.
+"""
+
+import os
+import re
+
+try:
+    from rich._emoji_codes import EMOJI
+except ImportError:
+    from pip._vendor.rich._emoji_codes import EMOJI
+
+OUTPUT = os.path.join(os.path.dirname(__file__), '..', 'src', 'utils', 'emoji_shortcodes.rs')
+
+# The shortcodes which can be typed in the Discord client.
+NAME = re.compile(r'^[a-z0-9_+\-]+$')
+
+# Emoji presentation selector.
+VS16 = '\ufe0f'
+
+# The emoji code points which default to a text presentation, from the
+# `Emoji` and `Emoji_Presentation` properties of Unicode's `emoji-data.txt`,
+# leaving out the components such as the keycap bases.
+TEXT_PRESENTATION = [
+    (0x00A9, 0x00A9), (0x00AE, 0x00AE), (0x203C, 0x203C), (0x2049, 0x2049),
+    (0x2122, 0x2122), (0x2139, 0x2139), (0x2194, 0x2199), (0x21A9, 0x21AA),
+    (0x2328, 0x2328), (0x23CF, 0x23CF), (0x23ED, 0x23EF), (0x23F1, 0x23F2),
+    (0x23F8, 0x23FA), (0x24C2, 0x24C2), (0x25AA, 0x25AB), (0x25B6, 0x25B6),
+    (0x25C0, 0x25C0), (0x25FB, 0x25FC), (0x2600, 0x2604), (0x260E, 0x260E),
+    (0x2611, 0x2611), (0x2618, 0x2618), (0x261D, 0x261D), (0x2620, 0x2620),
+    (0x2622, 0x2623), (0x2626, 0x2626), (0x262A, 0x262A), (0x262E, 0x262F),
+    (0x2638, 0x263A), (0x2640, 0x2640), (0x2642, 0x2642), (0x265F, 0x2660),
+    (0x2663, 0x2663), (0x2665, 0x2666), (0x2668, 0x2668), (0x267B, 0x267B),
+    (0x267E, 0x267E), (0x2692, 0x2692), (0x2694, 0x2697), (0x2699, 0x2699),
+    (0x269B, 0x269C), (0x26A0, 0x26A0), (0x26A7, 0x26A7), (0x26B0, 0x26B1),
+    (0x26C8, 0x26C8), (0x26CF, 0x26CF), (0x26D1, 0x26D1), (0x26D3, 0x26D3),
+    (0x26E9, 0x26E9), (0x26F0, 0x26F1), (0x26F4, 0x26F4), (0x26F7, 0x26F9),
+    (0x2702, 0x2702), (0x2708, 0x2709), (0x270C, 0x270D), (0x270F, 0x270F),
+    (0x2712, 0x2712), (0x2714, 0x2714), (0x2716, 0x2716), (0x271D, 0x271D),
+    (0x2721, 0x2721), (0x2733, 0x2734), (0x2744, 0x2744), (0x2747, 0x2747),
+    (0x2763, 0x2764), (0x27A1, 0x27A1), (0x2934, 0x2935), (0x2B05, 0x2B07),
+    (0x3030, 0x3030), (0x303D, 0x303D), (0x3297, 0x3297), (0x3299, 0x3299),
+    (0x1F170, 0x1F171), (0x1F17E, 0x1F17F), (0x1F202, 0x1F202), (0x1F237, 0x1F237),
+    (0x1F321, 0x1F321), (0x1F324, 0x1F32C), (0x1F336, 0x1F336), (0x1F37D, 0x1F37D),
+    (0x1F396, 0x1F397), (0x1F399, 0x1F39B), (0x1F39E, 0x1F39F), (0x1F3CB, 0x1F3CE),
+    (0x1F3D4, 0x1F3DF), (0x1F3F3, 0x1F3F3), (0x1F3F5, 0x1F3F5), (0x1F3F7, 0x1F3F7),
+    (0x1F43F, 0x1F43F), (0x1F441, 0x1F441), (0x1F4FD, 0x1F4FD), (0x1F549, 0x1F54A),
+    (0x1F56F, 0x1F570), (0x1F573, 0x1F579), (0x1F587, 0x1F587), (0x1F58A, 0x1F58D),
+    (0x1F590, 0x1F590), (0x1F5A5, 0x1F5A5), (0x1F5A8, 0x1F5A8), (0x1F5B1, 0x1F5B2),
+    (0x1F5BC, 0x1F5BC), (0x1F5C2, 0x1F5C4), (0x1F5D1, 0x1F5D3), (0x1F5DC, 0x1F5DE),
+    (0x1F5E1, 0x1F5E1), (0x1F5E3, 0x1F5E3), (0x1F5E8, 0x1F5E8), (0x1F5EF, 0x1F5EF),
+    (0x1F5F3, 0x1F5F3), (0x1F5FA, 0x1F5FA), (0x1F6CB, 0x1F6CB), (0x1F6CD, 0x1F6CF),
+    (0x1F6E0, 0x1F6E5), (0x1F6E9, 0x1F6E9), (0x1F6F0, 0x1F6F0), (0x1F6F3, 0x1F6F3),
+]
+
+# Skin tone modifiers, which directly follow the emoji they apply to.
+SKIN_TONES = (0x1F3FB, 0x1F3FF)
+
+# Regional indicators, which make flags when paired.
+REGIONAL_INDICATORS = (0x1F1E6, 0x1F1FF)
+
+# The names of the emoji data which are not emojis in Unicode's data.
+NOT_EMOJIS = {'helm_symbol'}
+
+# The shortcodes of the Discord client which are not in the emoji data, or
+# which name another emoji there.
+DISCORD_SHORTCODES = {
+    'asterisk': '*\ufe0f\u20e3',
+    'call_me': '\U0001f919',
+    'champagne': '\U0001f37e',
+    'cheese': '\U0001f9c0',
+    'clown': '\U0001f921',
+    'cowboy': '\U0001f920',
+    'desktop': '\U0001f5a5\ufe0f',
+    'egg': '\U0001f95a',
+    'email': '\U0001f4e7',
+    'fingers_crossed': '\U0001f91e',
+    'first_place': '\U0001f947',
+    'flag_black': '\U0001f3f4',
+    'flag_de': '\U0001f1e9\U0001f1ea',
+    'flag_fr': '\U0001f1eb\U0001f1f7',
+    'flag_gb': '\U0001f1ec\U0001f1e7',
+    'flag_jp': '\U0001f1ef\U0001f1f5',
+    'flag_us': '\U0001f1fa\U0001f1f8',
+    'flag_white': '\U0001f3f3\ufe0f',
+    'fox': '\U0001f98a',
+    'frowning2': '\u2639\ufe0f',
+    'hand_splayed': '\U0001f590\ufe0f',
+    'hash': '#\ufe0f\u20e3',
+    'heart_exclamation': '\u2763\ufe0f',
+    'hotdog': '\U0001f32d',
+    'hugging': '\U0001f917',
+    'keycap_ten': '\U0001f51f',
+    'left_facing_fist': '\U0001f91b',
+    'love_you_gesture': '\U0001f91f',
+    'medal': '\U0001f3c5',
+    'metal': '\U0001f918',
+    'milk': '\U0001f95b',
+    'money_mouth': '\U0001f911',
+    'nerd': '\U0001f913',
+    'ninja': '\U0001f977',
+    'pen_ballpoint': '\U0001f58a\ufe0f',
+    'right_facing_fist': '\U0001f91c',
+    'rofl': '\U0001f923',
+    'rolling_eyes': '\U0001f644',
+    'second_place': '\U0001f948',
+    'skull_crossbones': '\u2620\ufe0f',
+    'slight_frown': '\U0001f641',
+    'slight_smile': '\U0001f642',
+    'snowman': '\u26c4',
+    'star_struck': '\U0001f929',
+    'thinking': '\U0001f914',
+    'third_place': '\U0001f949',
+    'timer': '\u23f2\ufe0f',
+    'tools': '\U0001f6e0\ufe0f',
+    'track_next': '\u23ed\ufe0f',
+    'track_previous': '\u23ee\ufe0f',
+    'train': '\U0001f686',
+    'umbrella': '\u2614',
+    'unicorn': '\U0001f984',
+    'upside_down': '\U0001f643',
+    'vulcan': '\U0001f596',
+    'zipper_mouth': '\U0001f910',
+}
+
+
+def has_text_presentation(char):
+    code = ord(char)
+
+    return any(start <= code <= end for start, end in TEXT_PRESENTATION)
+
+
+def is_skin_tone(char):
+    return SKIN_TONES[0] <= ord(char) <= SKIN_TONES[1]
+
+
+def is_component(emoji):
+    if len(emoji) != 1:
+        return False
+
+    return is_skin_tone(emoji) or REGIONAL_INDICATORS[0] <= ord(emoji) <= REGIONAL_INDICATORS[1]
+
+
+def fully_qualify(emoji):
+    qualified = []
+
+    for index, char in enumerate(emoji):
+        qualified.append(char)
+
+        following = emoji[index + 1] if index + 1 < len(emoji) else None
+
+        if has_text_presentation(char) and following != VS16 and not (following and is_skin_tone(following)):
+            qualified.append(VS16)
+
+    return ''.join(qualified)
+
+
+def escape(emoji):
+    return ''.join(char if char.isascii() and char.isprintable() else '\\u{%x}' % ord(char) for char in emoji)
+
+
+def main():
+    # Skin tones and regional indicators can't be used on their own.
+    shortcodes = {
+        name: fully_qualify(emoji)
+        for name, emoji in EMOJI.items()
+        if NAME.match(name) and name not in NOT_EMOJIS and not is_component(emoji)
+    }
+    shortcodes.update(DISCORD_SHORTCODES)
+
+    lines = [
+        '// This file is generated by `scripts/generate_emoji_shortcodes.py`, do',
+        '// not edit it by hand.',
+        '',
+        '/// Emoji shortcodes as used by the Discord client, sorted by name so they',
+        '/// can be binary searched.',
+        'static SHORTCODES: &[(&str, &str)] = &[',
+    ]
+    lines.extend('    ("%s", "%s"),' % (name, escape(shortcodes[name])) for name in sorted(shortcodes))
+    lines.append('];')
+
+    with open(OUTPUT, 'w', encoding='utf-8') as output:
+        output.write('\n'.join(lines) + '\n')
+
+
+if __name__ == '__main__':
+    main()
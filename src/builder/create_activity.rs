@@ -0,0 +1,90 @@
+use crate::internal::prelude::*;
+use crate::model::gateway::ActivityType;
+
+use std::collections::HashMap;
+
+/// A builder for an [`Activity`] shown in the presence of the current user,
+/// to be used in conjunction with [`Activity::create`].
+///
+/// A [`name`] is required, unless the activity is a custom status. A
+/// [`ActivityType::Streaming`] activity also requires a Twitch or YouTube
+/// [`url`].
+///
+/// # Examples
+///
+/// Create an activity showing the bot as watching over a guild:
+///
+/// ```rust
+/// # use serenity::model::gateway::{Activity, ActivityType};
+/// #
+/// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let activity = Activity::create(|a| {
+///     a.kind(ActivityType::Watching)
+///         .name("over the guild")
+/// })?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Activity`]: crate::model::gateway::Activity
+/// [`Activity::create`]: crate::model::gateway::Activity::create
+/// [`name`]: Self::name
+/// [`url`]: Self::url
+#[derive(Clone, Debug, Default)]
+pub struct CreateActivity(pub HashMap<&'static str, Value>);
+
+impl CreateActivity {
+    /// The type of the activity.
+    ///
+    /// Defaults to [`ActivityType::Playing`].
+    pub fn kind(&mut self, kind: ActivityType) -> &mut Self {
+        self.0.insert("type", Value::Number(Number::from(kind.num())));
+        self
+    }
+
+    /// The name of the activity.
+    ///
+    /// **Note**: Maximum length is 128.
+    pub fn name<D: ToString>(&mut self, name: D) -> &mut Self {
+        self.0.insert("name", Value::String(name.to_string()));
+        self
+    }
+
+    /// The URL of the stream of a [`ActivityType::Streaming`] activity.
+    ///
+    /// **Note**: Must be a Twitch or YouTube URL.
+    pub fn url<D: ToString>(&mut self, url: D) -> &mut Self {
+        self.0.insert("url", Value::String(url.to_string()));
+        self
+    }
+
+    /// The state of the activity, shown as the text of a custom status.
+    pub fn state<D: ToString>(&mut self, state: D) -> &mut Self {
+        self.0.insert("state", Value::String(state.to_string()));
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::CreateActivity;
+    use crate::model::gateway::ActivityType;
+    use crate::utils;
+    use serde_json::{json, Value};
+
+    #[test]
+    fn test_activity() {
+        let mut builder = CreateActivity::default();
+        builder
+            .kind(ActivityType::Streaming)
+            .name("Speedruns")
+            .url("https://twitch.tv/serenity");
+
+        let map = utils::hashmap_to_json_map(builder.0);
+        assert_eq!(Value::Object(map), json!({
+            "type": 1,
+            "name": "Speedruns",
+            "url": "https://twitch.tv/serenity",
+        }));
+    }
+}
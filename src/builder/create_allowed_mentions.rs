@@ -15,8 +15,14 @@ pub enum ParseValue {
     Roles,
 }
 
-/// A builder to manage the allowed mentions on a message,
-/// used by the [`ChannelId::send_message`] method.
+/// A builder to manage the allowed mentions on a message, used by the
+/// `allowed_mentions` methods of [`CreateMessage`], [`EditMessage`] and
+/// [`ExecuteWebhook`].
+///
+/// **Note**: Parsing a type of mentions and listing the allowed mentions of
+/// the same type are mutually exclusive. For example, [`users`] can't be set
+/// along with [`ParseValue::Users`], and sending such a message returns a
+/// [`ModelError::InvalidAllowedMentions`].
 ///
 /// # Examples
 ///
@@ -60,7 +66,11 @@ pub enum ParseValue {
 ///
 /// ```
 ///
-/// [`ChannelId::send_message`]: crate::model::id::ChannelId::send_message
+/// [`CreateMessage`]: super::CreateMessage
+/// [`EditMessage`]: super::EditMessage
+/// [`ExecuteWebhook`]: super::ExecuteWebhook
+/// [`users`]: Self::users
+/// [`ModelError::InvalidAllowedMentions`]: crate::model::ModelError::InvalidAllowedMentions
 #[derive(Clone, Debug)]
 pub struct CreateAllowedMentions(pub HashMap<&'static str, Value>);
 
@@ -74,10 +84,31 @@ impl CreateAllowedMentions {
     /// [`roles`]: Self::roles
     #[inline]
     pub fn parse(&mut self, value: ParseValue) -> &mut Self {
+        let value = json!(value);
+
         if let Some(val) = self.0.get_mut("parse") {
-            val.as_array_mut().unwrap().push(json![value]);
+            let values = val.as_array_mut().unwrap();
+
+            if !values.contains(&value) {
+                values.push(value);
+            }
         } else {
-            self.0.insert("parse", Value::Array(vec![json!(value)]));
+            self.0.insert("parse", Value::Array(vec![value]));
+        }
+        self
+    }
+
+    /// Sets whether `@everyone` and `@here` are allowed to be mentioned.
+    ///
+    /// This adds or removes [`ParseValue::Everyone`] from the parsed values.
+    pub fn everyone(&mut self, everyone: bool) -> &mut Self {
+        if everyone {
+            return self.parse(ParseValue::Everyone);
+        }
+
+        if let Some(val) = self.0.get_mut("parse") {
+            let everyone = json!(ParseValue::Everyone);
+            val.as_array_mut().unwrap().retain(|value| *value != everyone);
         }
         self
     }
@@ -100,6 +131,8 @@ impl CreateAllowedMentions {
     }
 
     /// Sets the users that will be allowed to be mentioned.
+    ///
+    /// **Note**: This can't be combined with [`ParseValue::Users`].
     #[inline]
     pub fn users<U: Into<UserId>>(&mut self, users: impl IntoIterator<Item = U>) -> &mut Self {
         self.0.insert("users", Value::Array({
@@ -120,6 +153,8 @@ impl CreateAllowedMentions {
     }
 
     /// Sets the roles that will be allowed to be mentioned.
+    ///
+    /// **Note**: This can't be combined with [`ParseValue::Roles`].
     #[inline]
     pub fn roles<R: Into<RoleId>>(&mut self, users: impl IntoIterator<Item = R>) -> &mut Self {
         self.0.insert("roles", Value::Array({
@@ -154,3 +189,32 @@ impl Default for CreateAllowedMentions {
         CreateAllowedMentions(map)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{CreateAllowedMentions, ParseValue};
+    use crate::utils;
+    use serde_json::{json, Value};
+
+    #[test]
+    fn test_everyone_and_replied_user() {
+        let mut builder = CreateAllowedMentions::default();
+        builder
+            .everyone(true)
+            .parse(ParseValue::Roles)
+            .everyone(true)
+            .users(vec![1])
+            .replied_user(false);
+
+        let built = Value::Object(utils::hashmap_to_json_map(builder.0.clone()));
+        assert_eq!(built, json!({
+            "parse": ["everyone", "roles"],
+            "users": ["1"],
+            "replied_user": false,
+        }));
+
+        builder.everyone(false).replied_user(true);
+        assert_eq!(builder.0["parse"], json!(["roles"]));
+        assert_eq!(builder.0["replied_user"], json!(true));
+    }
+}
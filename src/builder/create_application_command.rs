@@ -0,0 +1,366 @@
+use crate::internal::prelude::*;
+use crate::model::channel::ChannelType;
+use crate::model::interactions::{ApplicationCommandOptionType, ApplicationCommandType};
+use crate::model::Permissions;
+use crate::utils;
+
+use serde_json::json;
+use std::collections::HashMap;
+
+/// A builder to create an application command, to be used in conjunction with
+/// [`ApplicationCommand::create_global_application_command`] and
+/// [`GuildId::create_application_command`].
+///
+/// The [`name`] is required, and so is the [`description`] of
+/// [chat input][`ApplicationCommandType::ChatInput`] commands.
+///
+/// Options can be nested up to three levels deep: a
+/// [subcommand group][`ApplicationCommandOptionType::SubCommandGroup`] holds
+/// [subcommands][`ApplicationCommandOptionType::SubCommand`], which in turn
+/// hold the other options.
+///
+/// # Examples
+///
+/// Register a `/tag get <name>` command in a guild:
+///
+/// ```rust,no_run
+/// # use serenity::http::Http;
+/// # use serenity::model::id::GuildId;
+/// #
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// # let http = Http::default();
+/// use serenity::model::interactions::ApplicationCommandOptionType;
+///
+/// GuildId(7).create_application_command(&http, 8, |c| {
+///     c.name("tag").description("Manages tags").create_option(|o| {
+///         o.kind(ApplicationCommandOptionType::SubCommand)
+///             .name("get")
+///             .description("Shows a tag")
+///             .create_sub_option(|o| {
+///                 o.kind(ApplicationCommandOptionType::String)
+///                     .name("name")
+///                     .description("The name of the tag")
+///                     .required(true)
+///             })
+///     })
+/// }).await?;
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`ApplicationCommand::create_global_application_command`]: crate::model::interactions::ApplicationCommand::create_global_application_command
+/// [`GuildId::create_application_command`]: crate::model::id::GuildId::create_application_command
+/// [`name`]: Self::name
+/// [`description`]: Self::description
+#[derive(Clone, Debug, Default)]
+pub struct CreateApplicationCommand(pub HashMap<&'static str, Value>);
+
+impl CreateApplicationCommand {
+    /// Sets the name of the command.
+    ///
+    /// **Note**: Must be between 1 and 32 characters long, and lowercase for
+    /// chat input commands.
+    pub fn name<D: ToString>(&mut self, name: D) -> &mut Self {
+        self.0.insert("name", Value::String(name.to_string()));
+        self
+    }
+
+    /// Sets the description of the command.
+    ///
+    /// **Note**: Must be between 1 and 100 characters long.
+    pub fn description<D: ToString>(&mut self, description: D) -> &mut Self {
+        self.0.insert("description", Value::String(description.to_string()));
+        self
+    }
+
+    /// Sets the type of the command. Defaults to
+    /// [`ApplicationCommandType::ChatInput`].
+    pub fn kind(&mut self, kind: ApplicationCommandType) -> &mut Self {
+        self.0.insert("type", Value::Number(Number::from(kind.num())));
+        self
+    }
+
+    /// Sets the permissions a member needs by default to use the command.
+    ///
+    /// Passing [`Permissions::empty`] restricts the command to
+    /// administrators by default.
+    pub fn default_member_permissions(&mut self, permissions: Permissions) -> &mut Self {
+        self.0.insert("default_member_permissions", Value::String(permissions.bits().to_string()));
+        self
+    }
+
+    /// Creates an option for the command.
+    ///
+    /// Refer to the documentation for [`CreateApplicationCommandOption`] for
+    /// more information.
+    pub fn create_option<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateApplicationCommandOption) -> &mut CreateApplicationCommandOption {
+        let mut option = CreateApplicationCommandOption::default();
+        f(&mut option);
+
+        self.add_option(option)
+    }
+
+    /// Adds an option to the command.
+    ///
+    /// **Note**: A command can have up to 25 options.
+    pub fn add_option(&mut self, option: CreateApplicationCommandOption) -> &mut Self {
+        push_option(&mut self.0, option);
+        self
+    }
+
+    /// Sets all the options of the command, replacing the existing ones.
+    pub fn set_options(&mut self, options: Vec<CreateApplicationCommandOption>) -> &mut Self {
+        let options = options.into_iter().map(CreateApplicationCommandOption::build).collect();
+
+        self.0.insert("options", Value::Array(options));
+        self
+    }
+
+    pub(crate) fn build(self) -> Value {
+        Value::Object(utils::hashmap_to_json_map(self.0))
+    }
+}
+
+/// A builder to overwrite all the application commands of an application at
+/// once, to be used in conjunction with
+/// [`ApplicationCommand::set_global_application_commands`] and
+/// [`GuildId::set_application_commands`].
+///
+/// [`ApplicationCommand::set_global_application_commands`]: crate::model::interactions::ApplicationCommand::set_global_application_commands
+/// [`GuildId::set_application_commands`]: crate::model::id::GuildId::set_application_commands
+#[derive(Clone, Debug, Default)]
+pub struct CreateApplicationCommands(pub Vec<Value>);
+
+impl CreateApplicationCommands {
+    /// Creates an application command.
+    ///
+    /// Refer to the documentation for [`CreateApplicationCommand`] for more
+    /// information.
+    pub fn create_application_command<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateApplicationCommand) -> &mut CreateApplicationCommand {
+        let mut command = CreateApplicationCommand::default();
+        f(&mut command);
+
+        self.add_application_command(command)
+    }
+
+    /// Adds an application command.
+    pub fn add_application_command(&mut self, command: CreateApplicationCommand) -> &mut Self {
+        self.0.push(command.build());
+        self
+    }
+
+    /// Sets all the application commands, replacing the existing ones.
+    pub fn set_application_commands(&mut self, commands: Vec<CreateApplicationCommand>) -> &mut Self {
+        self.0 = commands.into_iter().map(CreateApplicationCommand::build).collect();
+        self
+    }
+}
+
+/// A builder for an option of an application command, to be used in
+/// conjunction with [`CreateApplicationCommand::create_option`].
+///
+/// The [`kind`], [`name`] and [`description`] are required.
+///
+/// [`kind`]: Self::kind
+/// [`name`]: Self::name
+/// [`description`]: Self::description
+#[derive(Clone, Debug, Default)]
+pub struct CreateApplicationCommandOption(pub HashMap<&'static str, Value>);
+
+impl CreateApplicationCommandOption {
+    /// Sets the type of the option.
+    pub fn kind(&mut self, kind: ApplicationCommandOptionType) -> &mut Self {
+        self.0.insert("type", Value::Number(Number::from(kind.num())));
+        self
+    }
+
+    /// Sets the name of the option.
+    ///
+    /// **Note**: Must be between 1 and 32 lowercase characters long.
+    pub fn name<D: ToString>(&mut self, name: D) -> &mut Self {
+        self.0.insert("name", Value::String(name.to_string()));
+        self
+    }
+
+    /// Sets the description of the option.
+    ///
+    /// **Note**: Must be between 1 and 100 characters long.
+    pub fn description<D: ToString>(&mut self, description: D) -> &mut Self {
+        self.0.insert("description", Value::String(description.to_string()));
+        self
+    }
+
+    /// Sets whether the option must be filled in. Defaults to `false`.
+    ///
+    /// **Note**: Can't be set on subcommands and subcommand groups.
+    pub fn required(&mut self, required: bool) -> &mut Self {
+        self.0.insert("required", Value::Bool(required));
+        self
+    }
+
+    /// Adds a choice to a [string][`ApplicationCommandOptionType::String`]
+    /// option.
+    ///
+    /// **Note**: An option can have up to 25 choices.
+    pub fn add_string_choice<D: ToString, E: ToString>(&mut self, name: D, value: E) -> &mut Self {
+        self._add_choice(name.to_string(), Value::String(value.to_string()));
+        self
+    }
+
+    /// Adds a choice to an [integer][`ApplicationCommandOptionType::Integer`]
+    /// option.
+    ///
+    /// **Note**: An option can have up to 25 choices.
+    pub fn add_int_choice<D: ToString>(&mut self, name: D, value: i64) -> &mut Self {
+        self._add_choice(name.to_string(), Value::Number(Number::from(value)));
+        self
+    }
+
+    /// Adds a choice to a [number][`ApplicationCommandOptionType::Number`]
+    /// option.
+    ///
+    /// **Note**: An option can have up to 25 choices.
+    pub fn add_number_choice<D: ToString>(&mut self, name: D, value: f64) -> &mut Self {
+        let value = Number::from_f64(value).map_or(Value::Null, Value::Number);
+
+        self._add_choice(name.to_string(), value);
+        self
+    }
+
+    fn _add_choice(&mut self, name: String, value: Value) {
+        let choice = json!({
+            "name": name,
+            "value": value,
+        });
+
+        let entry = self.0
+            .entry("choices")
+            .or_insert_with(|| Value::Array(vec![]));
+
+        if let Value::Array(ref mut inner) = *entry {
+            inner.push(choice);
+        }
+    }
+
+    /// Restricts the types of channel the user can pick from, if the option
+    /// is of type [`ApplicationCommandOptionType::Channel`].
+    pub fn channel_types(&mut self, types: &[ChannelType]) -> &mut Self {
+        let types = types.iter().map(|kind| Value::Number(Number::from(kind.num()))).collect();
+
+        self.0.insert("channel_types", Value::Array(types));
+        self
+    }
+
+    /// Sets the minimum value of an integer or number option.
+    pub fn min_value(&mut self, value: impl Into<Number>) -> &mut Self {
+        self.0.insert("min_value", Value::Number(value.into()));
+        self
+    }
+
+    /// Sets the maximum value of an integer or number option.
+    pub fn max_value(&mut self, value: impl Into<Number>) -> &mut Self {
+        self.0.insert("max_value", Value::Number(value.into()));
+        self
+    }
+
+    /// Sets whether the option is autocompleted, sending
+    /// [autocomplete interactions] while the user is typing it.
+    ///
+    /// **Note**: Can only be set on string, integer and number options
+    /// without any choices.
+    ///
+    /// [autocomplete interactions]: crate::model::interactions::AutocompleteInteraction
+    pub fn autocomplete(&mut self, autocomplete: bool) -> &mut Self {
+        self.0.insert("autocomplete", Value::Bool(autocomplete));
+        self
+    }
+
+    /// Creates an option of a subcommand or subcommand group.
+    ///
+    /// Refer to the documentation for [`CreateApplicationCommand`] for the
+    /// allowed nesting.
+    pub fn create_sub_option<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateApplicationCommandOption) -> &mut CreateApplicationCommandOption {
+        let mut option = CreateApplicationCommandOption::default();
+        f(&mut option);
+
+        self.add_sub_option(option)
+    }
+
+    /// Adds an option to a subcommand or subcommand group.
+    pub fn add_sub_option(&mut self, option: CreateApplicationCommandOption) -> &mut Self {
+        push_option(&mut self.0, option);
+        self
+    }
+
+    fn build(self) -> Value {
+        Value::Object(utils::hashmap_to_json_map(self.0))
+    }
+}
+
+fn push_option(map: &mut HashMap<&'static str, Value>, option: CreateApplicationCommandOption) {
+    let entry = map
+        .entry("options")
+        .or_insert_with(|| Value::Array(vec![]));
+
+    if let Value::Array(ref mut inner) = *entry {
+        inner.push(option.build());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::CreateApplicationCommand;
+    use crate::model::interactions::ApplicationCommandOptionType;
+    use serde_json::json;
+
+    #[test]
+    fn test_subcommand_group_with_choices() {
+        let mut command = CreateApplicationCommand::default();
+        command.name("tag").description("Manages tags").create_option(|group| {
+            group.kind(ApplicationCommandOptionType::SubCommandGroup)
+                .name("color")
+                .description("Colored tags")
+                .create_sub_option(|sub| {
+                    sub.kind(ApplicationCommandOptionType::SubCommand)
+                        .name("set")
+                        .description("Sets the color of a tag")
+                        .create_sub_option(|o| {
+                            o.kind(ApplicationCommandOptionType::String)
+                                .name("color")
+                                .description("The color")
+                                .required(true)
+                                .add_string_choice("Red", "red")
+                                .add_string_choice("Blue", "blue")
+                        })
+                })
+        });
+
+        assert_eq!(command.build(), json!({
+            "name": "tag",
+            "description": "Manages tags",
+            "options": [{
+                "type": 2,
+                "name": "color",
+                "description": "Colored tags",
+                "options": [{
+                    "type": 1,
+                    "name": "set",
+                    "description": "Sets the color of a tag",
+                    "options": [{
+                        "type": 3,
+                        "name": "color",
+                        "description": "The color",
+                        "required": true,
+                        "choices": [
+                            {"name": "Red", "value": "red"},
+                            {"name": "Blue", "value": "blue"},
+                        ],
+                    }],
+                }],
+            }],
+        }));
+    }
+}
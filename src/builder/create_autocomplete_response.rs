@@ -0,0 +1,56 @@
+use crate::internal::prelude::*;
+
+use serde_json::json;
+use std::collections::HashMap;
+
+/// A builder for the choices suggested in response to an autocomplete
+/// interaction, to be used in conjunction with
+/// [`AutocompleteInteraction::create_autocomplete_response`].
+///
+/// The type of the values must match the type of the option being
+/// autocompleted.
+///
+/// **Note**: Up to 25 choices can be suggested. Sending more fails with a
+/// [`ModelError::ChoiceAmount`].
+///
+/// [`AutocompleteInteraction::create_autocomplete_response`]: crate::model::interactions::AutocompleteInteraction::create_autocomplete_response
+/// [`ModelError::ChoiceAmount`]: crate::model::ModelError::ChoiceAmount
+#[derive(Clone, Debug, Default)]
+pub struct CreateAutocompleteResponse(pub HashMap<&'static str, Value>);
+
+impl CreateAutocompleteResponse {
+    /// Adds a choice with a string value.
+    pub fn add_string_choice<D: ToString, E: ToString>(&mut self, name: D, value: E) -> &mut Self {
+        self._add_choice(name.to_string(), Value::String(value.to_string()));
+        self
+    }
+
+    /// Adds a choice with an integer value.
+    pub fn add_int_choice<D: ToString>(&mut self, name: D, value: i64) -> &mut Self {
+        self._add_choice(name.to_string(), Value::Number(Number::from(value)));
+        self
+    }
+
+    /// Adds a choice with a number value.
+    pub fn add_number_choice<D: ToString>(&mut self, name: D, value: f64) -> &mut Self {
+        let value = Number::from_f64(value).map_or(Value::Null, Value::Number);
+
+        self._add_choice(name.to_string(), value);
+        self
+    }
+
+    fn _add_choice(&mut self, name: String, value: Value) {
+        let choice = json!({
+            "name": name,
+            "value": value,
+        });
+
+        let entry = self.0
+            .entry("choices")
+            .or_insert_with(|| Value::Array(vec![]));
+
+        if let Value::Array(ref mut inner) = *entry {
+            inner.push(choice);
+        }
+    }
+}
@@ -1,10 +1,13 @@
 use crate::internal::prelude::*;
 use crate::model::prelude::*;
+use super::CreatePermissionOverwrite;
 
-use serde_json::{json, Value};
+use serde_json::Value;
 
 use std::collections::HashMap;
 
+use crate::utils;
+
 /// A builder for creating a new [`GuildChannel`] in a [`Guild`].
 ///
 /// Except [`name`], all fields are optional.
@@ -124,17 +127,9 @@ impl CreateChannel {
         where I: IntoIterator<Item=PermissionOverwrite>
     {
         let overwrites = perms.into_iter().map(|perm| {
-            let (id, kind) = match perm.kind {
-                PermissionOverwriteType::Member(id) => (id.0, "member"),
-                PermissionOverwriteType::Role(id) => (id.0, "role"),
-            };
-
-            json!({
-                "allow": perm.allow.bits(),
-                "deny": perm.deny.bits(),
-                "id": id,
-                "type": kind,
-            })
+            let overwrite = CreatePermissionOverwrite::from(&perm);
+
+            Value::Object(utils::hashmap_to_json_map(overwrite.0))
         }).collect();
 
         self.0.insert("permission_overwrites", Value::Array(overwrites));
@@ -0,0 +1,538 @@
+use crate::internal::prelude::*;
+use crate::model::channel::{ButtonStyle, ComponentType, InputTextStyle, ReactionType};
+use crate::utils;
+
+use std::collections::HashMap;
+
+/// A builder for the components of a message, to be used in conjunction with
+/// [`CreateMessage::components`].
+///
+/// A message can have up to 5 [action rows].
+///
+/// # Examples
+///
+/// Send a message with a button and a link button:
+///
+/// ```rust,no_run
+/// # use serenity::http::Http;
+/// # use serenity::model::id::ChannelId;
+/// #
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// # let http = Http::default();
+/// use serenity::model::channel::ButtonStyle;
+///
+/// ChannelId(7).send_message(&http, |m| {
+///     m.content("Hello!").components(|c| {
+///         c.create_action_row(|row| {
+///             row.create_button(|b| {
+///                 b.style(ButtonStyle::Primary).label("Wave").custom_id("wave")
+///             })
+///             .create_button(|b| {
+///                 b.style(ButtonStyle::Link).label("Docs").url("https://docs.rs/serenity")
+///             })
+///         })
+///     })
+/// }).await?;
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`CreateMessage::components`]: super::CreateMessage::components
+/// [action rows]: CreateActionRow
+#[derive(Clone, Debug, Default)]
+pub struct CreateComponents(pub Vec<Value>);
+
+impl CreateComponents {
+    /// Creates an action row.
+    ///
+    /// Refer to the documentation for [`CreateActionRow`] for more
+    /// information.
+    pub fn create_action_row<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateActionRow) -> &mut CreateActionRow {
+        let mut row = CreateActionRow::default();
+        f(&mut row);
+
+        self.add_action_row(row)
+    }
+
+    /// Adds an action row.
+    pub fn add_action_row(&mut self, row: CreateActionRow) -> &mut Self {
+        self.0.push(row.build());
+        self
+    }
+
+    /// Sets all the action rows, replacing the existing ones.
+    pub fn set_action_rows(&mut self, rows: Vec<CreateActionRow>) -> &mut Self {
+        self.0 = rows.into_iter().map(CreateActionRow::build).collect();
+        self
+    }
+}
+
+/// A builder for a row of components, to be used in conjunction with
+/// [`CreateComponents`].
+///
+/// A row can hold up to 5 [buttons], or a single [select menu].
+///
+/// [buttons]: CreateButton
+/// [select menu]: CreateSelectMenu
+#[derive(Clone, Debug)]
+pub struct CreateActionRow(pub HashMap<&'static str, Value>);
+
+impl CreateActionRow {
+    /// Creates a button.
+    ///
+    /// Refer to the documentation for [`CreateButton`] for more information.
+    pub fn create_button<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateButton) -> &mut CreateButton {
+        let mut button = CreateButton::default();
+        f(&mut button);
+
+        self.add_button(button)
+    }
+
+    /// Adds a button.
+    pub fn add_button(&mut self, button: CreateButton) -> &mut Self {
+        self._add_component(Value::Object(utils::hashmap_to_json_map(button.0)));
+        self
+    }
+
+    /// Creates a select menu.
+    ///
+    /// Refer to the documentation for [`CreateSelectMenu`] for more
+    /// information.
+    pub fn create_select_menu<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateSelectMenu) -> &mut CreateSelectMenu {
+        let mut menu = CreateSelectMenu::default();
+        f(&mut menu);
+
+        self.add_select_menu(menu)
+    }
+
+    /// Adds a select menu.
+    pub fn add_select_menu(&mut self, menu: CreateSelectMenu) -> &mut Self {
+        self._add_component(Value::Object(utils::hashmap_to_json_map(menu.0)));
+        self
+    }
+
+    /// Creates a text field.
+    ///
+    /// **Note**: Text fields can only be used in modals.
+    ///
+    /// Refer to the documentation for [`CreateInputText`] for more
+    /// information.
+    pub fn create_input_text<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateInputText) -> &mut CreateInputText {
+        let mut input_text = CreateInputText::default();
+        f(&mut input_text);
+
+        self.add_input_text(input_text)
+    }
+
+    /// Adds a text field.
+    pub fn add_input_text(&mut self, input_text: CreateInputText) -> &mut Self {
+        self._add_component(Value::Object(utils::hashmap_to_json_map(input_text.0)));
+        self
+    }
+
+    fn _add_component(&mut self, component: Value) {
+        let entry = self.0
+            .entry("components")
+            .or_insert_with(|| Value::Array(vec![]));
+
+        if let Value::Array(ref mut inner) = *entry {
+            inner.push(component);
+        }
+    }
+
+    fn build(self) -> Value {
+        Value::Object(utils::hashmap_to_json_map(self.0))
+    }
+}
+
+impl Default for CreateActionRow {
+    /// Creates a builder with default values, setting the `type` to an action
+    /// row.
+    fn default() -> CreateActionRow {
+        let mut map = HashMap::new();
+        map.insert("type", Value::Number(Number::from(ComponentType::ActionRow.num())));
+        map.insert("components", Value::Array(vec![]));
+
+        CreateActionRow(map)
+    }
+}
+
+/// A builder for a button, to be used in conjunction with [`CreateActionRow`].
+///
+/// The [`style`] is required. A [link] button requires an [`url`], while the
+/// other buttons require a [`custom_id`] instead.
+///
+/// [`style`]: Self::style
+/// [link]: ButtonStyle::Link
+/// [`url`]: Self::url
+/// [`custom_id`]: Self::custom_id
+#[derive(Clone, Debug)]
+pub struct CreateButton(pub HashMap<&'static str, Value>);
+
+impl CreateButton {
+    /// The style of the button.
+    pub fn style(&mut self, style: ButtonStyle) -> &mut Self {
+        self.0.insert("style", Value::Number(Number::from(style.num())));
+        self
+    }
+
+    /// The text displayed on the button.
+    ///
+    /// **Note**: Can't be longer than 80 characters.
+    pub fn label<D: ToString>(&mut self, label: D) -> &mut Self {
+        self.0.insert("label", Value::String(label.to_string()));
+        self
+    }
+
+    /// The emoji displayed on the button.
+    pub fn emoji<R: Into<ReactionType>>(&mut self, emoji: R) -> &mut Self {
+        self._emoji(emoji.into());
+        self
+    }
+
+    fn _emoji(&mut self, emoji: ReactionType) {
+        self.0.insert("emoji", serde_json::to_value(emoji).unwrap_or(Value::Null));
+    }
+
+    /// The developer-defined Id sent along with the interaction when the
+    /// button is clicked.
+    ///
+    /// **Note**: Can't be longer than 100 characters, and can't be set on
+    /// [link][`ButtonStyle::Link`] buttons.
+    pub fn custom_id<D: ToString>(&mut self, id: D) -> &mut Self {
+        self.0.insert("custom_id", Value::String(id.to_string()));
+        self
+    }
+
+    /// The URL the button leads to.
+    ///
+    /// **Note**: Can only be set on [link][`ButtonStyle::Link`] buttons.
+    pub fn url<D: ToString>(&mut self, url: D) -> &mut Self {
+        self.0.insert("url", Value::String(url.to_string()));
+        self
+    }
+
+    /// Whether the button is disabled.
+    pub fn disabled(&mut self, disabled: bool) -> &mut Self {
+        self.0.insert("disabled", Value::Bool(disabled));
+        self
+    }
+}
+
+impl Default for CreateButton {
+    /// Creates a builder with default values, setting the `type` to a button.
+    fn default() -> CreateButton {
+        let mut map = HashMap::new();
+        map.insert("type", Value::Number(Number::from(ComponentType::Button.num())));
+
+        CreateButton(map)
+    }
+}
+
+/// A builder for a select menu, to be used in conjunction with
+/// [`CreateActionRow`].
+///
+/// The [`custom_id`] and the [`options`] are required.
+///
+/// [`custom_id`]: Self::custom_id
+/// [`options`]: Self::options
+#[derive(Clone, Debug)]
+pub struct CreateSelectMenu(pub HashMap<&'static str, Value>);
+
+impl CreateSelectMenu {
+    /// The developer-defined Id sent along with the interaction when options
+    /// are selected.
+    ///
+    /// **Note**: Can't be longer than 100 characters.
+    pub fn custom_id<D: ToString>(&mut self, id: D) -> &mut Self {
+        self.0.insert("custom_id", Value::String(id.to_string()));
+        self
+    }
+
+    /// The text displayed when no option is selected.
+    ///
+    /// **Note**: Can't be longer than 100 characters.
+    pub fn placeholder<D: ToString>(&mut self, placeholder: D) -> &mut Self {
+        self.0.insert("placeholder", Value::String(placeholder.to_string()));
+        self
+    }
+
+    /// The minimum number of options which must be selected.
+    ///
+    /// Defaults to `1`, and must be between 0 and 25.
+    pub fn min_values(&mut self, min: u64) -> &mut Self {
+        self.0.insert("min_values", Value::Number(Number::from(min)));
+        self
+    }
+
+    /// The maximum number of options which can be selected.
+    ///
+    /// Defaults to `1`, and can't be more than 25.
+    pub fn max_values(&mut self, max: u64) -> &mut Self {
+        self.0.insert("max_values", Value::Number(Number::from(max)));
+        self
+    }
+
+    /// Creates the options of the menu, replacing the existing ones.
+    ///
+    /// Refer to the documentation for [`CreateSelectMenuOptions`] for more
+    /// information.
+    pub fn options<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateSelectMenuOptions) -> &mut CreateSelectMenuOptions {
+        let mut options = CreateSelectMenuOptions::default();
+        f(&mut options);
+
+        self.0.insert("options", Value::Array(options.0));
+        self
+    }
+
+    /// Whether the menu is disabled.
+    pub fn disabled(&mut self, disabled: bool) -> &mut Self {
+        self.0.insert("disabled", Value::Bool(disabled));
+        self
+    }
+}
+
+impl Default for CreateSelectMenu {
+    /// Creates a builder with default values, setting the `type` to a select
+    /// menu.
+    fn default() -> CreateSelectMenu {
+        let mut map = HashMap::new();
+        map.insert("type", Value::Number(Number::from(ComponentType::SelectMenu.num())));
+
+        CreateSelectMenu(map)
+    }
+}
+
+/// A builder for a text field of a modal, to be used in conjunction with
+/// [`CreateActionRow`].
+///
+/// The [`custom_id`], [`style`] and [`label`] are required.
+///
+/// [`custom_id`]: Self::custom_id
+/// [`style`]: Self::style
+/// [`label`]: Self::label
+#[derive(Clone, Debug)]
+pub struct CreateInputText(pub HashMap<&'static str, Value>);
+
+impl CreateInputText {
+    /// The developer-defined Id of the text field, sent along with its value
+    /// when the modal is submitted.
+    ///
+    /// **Note**: Can't be longer than 100 characters.
+    pub fn custom_id<D: ToString>(&mut self, id: D) -> &mut Self {
+        self.0.insert("custom_id", Value::String(id.to_string()));
+        self
+    }
+
+    /// The style of the text field.
+    pub fn style(&mut self, style: InputTextStyle) -> &mut Self {
+        self.0.insert("style", Value::Number(Number::from(style.num())));
+        self
+    }
+
+    /// The text displayed above the text field.
+    ///
+    /// **Note**: Can't be longer than 45 characters.
+    pub fn label<D: ToString>(&mut self, label: D) -> &mut Self {
+        self.0.insert("label", Value::String(label.to_string()));
+        self
+    }
+
+    /// The minimum length of the entered text.
+    ///
+    /// **Note**: Must be between 0 and 4000.
+    pub fn min_length(&mut self, min: u64) -> &mut Self {
+        self.0.insert("min_length", Value::Number(Number::from(min)));
+        self
+    }
+
+    /// The maximum length of the entered text.
+    ///
+    /// **Note**: Must be between 1 and 4000.
+    pub fn max_length(&mut self, max: u64) -> &mut Self {
+        self.0.insert("max_length", Value::Number(Number::from(max)));
+        self
+    }
+
+    /// Whether the text field must be filled in. Defaults to `true`.
+    pub fn required(&mut self, required: bool) -> &mut Self {
+        self.0.insert("required", Value::Bool(required));
+        self
+    }
+
+    /// The text the field is pre-filled with.
+    ///
+    /// **Note**: Can't be longer than 4000 characters.
+    pub fn value<D: ToString>(&mut self, value: D) -> &mut Self {
+        self.0.insert("value", Value::String(value.to_string()));
+        self
+    }
+
+    /// The text displayed when the field is empty.
+    ///
+    /// **Note**: Can't be longer than 100 characters.
+    pub fn placeholder<D: ToString>(&mut self, placeholder: D) -> &mut Self {
+        self.0.insert("placeholder", Value::String(placeholder.to_string()));
+        self
+    }
+}
+
+impl Default for CreateInputText {
+    /// Creates a builder with default values, setting the `type` to a text
+    /// field.
+    fn default() -> CreateInputText {
+        let mut map = HashMap::new();
+        map.insert("type", Value::Number(Number::from(ComponentType::InputText.num())));
+
+        CreateInputText(map)
+    }
+}
+
+/// A builder for the options of a select menu, to be used in conjunction with
+/// [`CreateSelectMenu::options`].
+///
+/// A menu can have up to 25 options.
+#[derive(Clone, Debug, Default)]
+pub struct CreateSelectMenuOptions(pub Vec<Value>);
+
+impl CreateSelectMenuOptions {
+    /// Creates an option.
+    ///
+    /// Refer to the documentation for [`CreateSelectMenuOption`] for more
+    /// information.
+    pub fn create_option<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateSelectMenuOption) -> &mut CreateSelectMenuOption {
+        let mut option = CreateSelectMenuOption::default();
+        f(&mut option);
+
+        self.add_option(option)
+    }
+
+    /// Adds an option.
+    pub fn add_option(&mut self, option: CreateSelectMenuOption) -> &mut Self {
+        self.0.push(Value::Object(utils::hashmap_to_json_map(option.0)));
+        self
+    }
+}
+
+/// A builder for an option of a select menu, to be used in conjunction with
+/// [`CreateSelectMenuOptions`].
+///
+/// The [`label`] and the [`value`] are required.
+///
+/// [`label`]: Self::label
+/// [`value`]: Self::value
+#[derive(Clone, Debug, Default)]
+pub struct CreateSelectMenuOption(pub HashMap<&'static str, Value>);
+
+impl CreateSelectMenuOption {
+    /// The text displayed on the option.
+    ///
+    /// **Note**: Can't be longer than 100 characters.
+    pub fn label<D: ToString>(&mut self, label: D) -> &mut Self {
+        self.0.insert("label", Value::String(label.to_string()));
+        self
+    }
+
+    /// The developer-defined value sent along with the interaction when the
+    /// option is selected.
+    ///
+    /// **Note**: Can't be longer than 100 characters.
+    pub fn value<D: ToString>(&mut self, value: D) -> &mut Self {
+        self.0.insert("value", Value::String(value.to_string()));
+        self
+    }
+
+    /// The additional description of the option.
+    ///
+    /// **Note**: Can't be longer than 100 characters.
+    pub fn description<D: ToString>(&mut self, description: D) -> &mut Self {
+        self.0.insert("description", Value::String(description.to_string()));
+        self
+    }
+
+    /// The emoji displayed on the option.
+    pub fn emoji<R: Into<ReactionType>>(&mut self, emoji: R) -> &mut Self {
+        self._emoji(emoji.into());
+        self
+    }
+
+    fn _emoji(&mut self, emoji: ReactionType) {
+        self.0.insert("emoji", serde_json::to_value(emoji).unwrap_or(Value::Null));
+    }
+
+    /// Whether the option is selected by default.
+    pub fn default_selection(&mut self, default: bool) -> &mut Self {
+        self.0.insert("default", Value::Bool(default));
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::model::channel::{ButtonStyle, Message};
+    use serde_json::{json, Value};
+    use super::CreateComponents;
+
+    #[test]
+    fn test_row_with_two_buttons() {
+        let mut components = CreateComponents::default();
+        components.create_action_row(|row| {
+            row.create_button(|b| b.style(ButtonStyle::Primary).label("Wave").custom_id("wave"))
+                .create_button(|b| {
+                    b.style(ButtonStyle::Link)
+                        .label("Docs")
+                        .emoji('📖')
+                        .url("https://docs.rs/serenity")
+                        .disabled(true)
+                })
+        });
+
+        assert_eq!(json!(components.0), json!([{
+            "type": 1,
+            "components": [
+                {
+                    "type": 2,
+                    "style": 1,
+                    "label": "Wave",
+                    "custom_id": "wave",
+                },
+                {
+                    "type": 2,
+                    "style": 5,
+                    "label": "Docs",
+                    "emoji": { "name": "📖" },
+                    "url": "https://docs.rs/serenity",
+                    "disabled": true,
+                },
+            ],
+        }]));
+    }
+
+    #[cfg(feature = "model")]
+    #[test]
+    fn test_link_button_validation() {
+        let check = |f: fn(&mut super::CreateButton) -> &mut super::CreateButton| {
+            let mut components = CreateComponents::default();
+            components.create_action_row(|row| row.create_button(f));
+
+            let map = json!({ "components": components.0 });
+
+            match map {
+                Value::Object(map) => Message::check_components(&map).is_ok(),
+                _ => unreachable!(),
+            }
+        };
+
+        assert!(check(|b| b.style(ButtonStyle::Link).url("https://docs.rs")));
+        assert!(check(|b| b.style(ButtonStyle::Primary).custom_id("id")));
+        assert!(!check(|b| b.style(ButtonStyle::Link).custom_id("id")));
+        assert!(!check(|b| b.style(ButtonStyle::Link).url("https://docs.rs").custom_id("id")));
+        assert!(!check(|b| b.style(ButtonStyle::Primary).url("https://docs.rs")));
+    }
+}
@@ -14,15 +14,20 @@
 //! [`ExecuteWebhook::embeds`]: crate::builder::ExecuteWebhook::embeds
 //! [here]: https://discord.com/developers/docs/resources/channel#embed-object
 
+use crate::constants;
 use crate::internal::prelude::*;
 use crate::model::channel::Embed;
+use crate::model::ModelError;
+#[cfg(feature = "model")]
+use crate::model::user::User;
 use crate::utils;
 
-use chrono::{DateTime, TimeZone};
+use chrono::{DateTime, NaiveDateTime, ParseError, TimeZone, Utc};
 use serde_json::{json, Value};
 
 use std::fmt::Display;
 use std::collections::HashMap;
+use std::str::FromStr;
 
 #[cfg(feature = "utils")]
 use crate::utils::Colour;
@@ -61,6 +66,14 @@ impl CreateEmbed {
         self
     }
 
+    /// Set the author of the embed to a user, using their name and avatar.
+    ///
+    /// The user's default avatar is used if they don't have one.
+    #[cfg(feature = "model")]
+    pub fn author_from_user(&mut self, user: &User) -> &mut Self {
+        self.author(|a| a.name(&user.name).icon_url(user.face()))
+    }
+
     /// Set the colour of the left-hand side of the embed.
     ///
     /// This is an alias of [`colour`].
@@ -145,6 +158,83 @@ impl CreateEmbed {
         }
     }
 
+    /// Set a field whose value may be longer than the 1024 characters allowed
+    /// for a field value, splitting it across multiple fields.
+    ///
+    /// The value is split on whitespace where possible. The continuation
+    /// fields have a blank name, so that the value reads as one field.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::EmbedFieldAmount`] if the embed would have
+    /// more than 25 fields, or a [`ModelError::EmbedTooLarge`] if its text
+    /// would exceed 6000 characters. The embed is left unchanged in both
+    /// cases.
+    pub fn field_chunked<T, U>(&mut self, name: T, value: U, inline: bool) -> Result<&mut Self>
+        where T: ToString, U: ToString {
+        let name = name.to_string();
+        let value = value.to_string();
+        let chunks = chunk_field_value(&value);
+
+        let fields = self.field_count() + chunks.len();
+        if fields > constants::EMBED_FIELD_MAX_COUNT {
+            return Err(Error::Model(ModelError::EmbedFieldAmount(fields)));
+        }
+
+        let length = self.text_length()
+            + name.chars().count()
+            + (chunks.len() - 1) * CONTINUATION_FIELD_NAME.chars().count()
+            + chunks.iter().map(|chunk| chunk.chars().count()).sum::<usize>();
+        if length > constants::EMBED_MAX_LENGTH {
+            let overflow = length - constants::EMBED_MAX_LENGTH;
+            return Err(Error::Model(ModelError::EmbedTooLarge(overflow)));
+        }
+
+        let mut chunks = chunks.into_iter();
+        if let Some(first) = chunks.next() {
+            self._field(name, first.to_string(), inline);
+        }
+
+        for chunk in chunks {
+            self._field(CONTINUATION_FIELD_NAME.to_string(), chunk.to_string(), inline);
+        }
+
+        Ok(self)
+    }
+
+    fn field_count(&self) -> usize {
+        match self.0.get("fields") {
+            Some(Value::Array(fields)) => fields.len(),
+            _ => 0,
+        }
+    }
+
+    /// The number of characters of the embed counting towards its maximum
+    /// length.
+    fn text_length(&self) -> usize {
+        fn length(value: Option<&Value>) -> usize {
+            value.and_then(Value::as_str).map_or(0, |s| s.chars().count())
+        }
+
+        let mut total = length(self.0.get("title")) + length(self.0.get("description"));
+
+        if let Some(Value::Object(author)) = self.0.get("author") {
+            total += length(author.get("name"));
+        }
+
+        if let Some(Value::Object(footer)) = self.0.get("footer") {
+            total += length(footer.get("text"));
+        }
+
+        if let Some(Value::Array(fields)) = self.0.get("fields") {
+            for field in fields {
+                total += length(field.get("name")) + length(field.get("value"));
+            }
+        }
+
+        total
+    }
+
     /// Adds multiple fields at once.
     ///
     /// This is sugar to reduce the need of calling [`field`] manually multiple times.
@@ -181,6 +271,15 @@ impl CreateEmbed {
         self
     }
 
+    /// Set the footer of the embed to a user, using their name as the text
+    /// and their avatar as the icon.
+    ///
+    /// The user's default avatar is used if they don't have one.
+    #[cfg(feature = "model")]
+    pub fn footer_from_user(&mut self, user: &User) -> &mut Self {
+        self.footer(|f| f.text(&user.name).icon_url(user.face()))
+    }
+
     fn url_object(&mut self, name: &'static str, url: String) -> &mut Self {
         let obj = json!({
             "url": url,
@@ -213,10 +312,15 @@ impl CreateEmbed {
     /// - `2004-06-08T16:04:23`
     /// - `2004-06-08T16:04:23`
     ///
-    /// This timestamp must be in ISO-8601 format. It must also be in UTC format.
+    /// This timestamp must be in RFC 3339 format. A timestamp without an
+    /// offset, like the ones above, is assumed to be in UTC.
     ///
-    /// You can also pass an instance of `chrono::DateTime<Utc>`,
-    /// which will construct the timestamp string out of it.
+    /// You can also pass an instance of `chrono::DateTime`, which will
+    /// construct the timestamp string out of it.
+    ///
+    /// **Note**: Sending a message with an invalid timestamp string returns a
+    /// [`ModelError::InvalidTimestamp`]. Use [`Timestamp::from_str`] to
+    /// validate the string beforehand.
     ///
     /// # Examples
     ///
@@ -306,6 +410,8 @@ impl CreateEmbed {
     /// #     Ok(())
     /// # }
     /// ```
+    ///
+    /// [`ModelError::InvalidTimestamp`]: crate::model::ModelError::InvalidTimestamp
     #[inline]
     pub fn timestamp<T: Into<Timestamp>>(&mut self, timestamp: T) -> &mut Self {
         self._timestamp(timestamp.into());
@@ -316,6 +422,12 @@ impl CreateEmbed {
         self.0.insert("timestamp", Value::String(timestamp.ts));
     }
 
+    /// Set the timestamp to the current time.
+    #[inline]
+    pub fn timestamp_now(&mut self) -> &mut Self {
+        self.timestamp(Utc::now())
+    }
+
     /// Set the title of the embed.
     #[inline]
     pub fn title<D: ToString>(&mut self, title: D) -> &mut Self {
@@ -348,6 +460,47 @@ impl CreateEmbed {
     }
 }
 
+/// The name of the fields continuing the value of a chunked field. Discord
+/// doesn't allow empty names, so this is a zero-width space.
+const CONTINUATION_FIELD_NAME: &str = "\u{200b}";
+
+/// Splits a field value into chunks fitting in a field, preferably on
+/// whitespace.
+///
+/// Chunks made only of whitespace are dropped, as Discord rejects blank field
+/// values. The value itself is kept as the only chunk if it is blank.
+fn chunk_field_value(value: &str) -> Vec<&str> {
+    let mut chunks = Vec::new();
+    let mut rest = value;
+
+    while let Some((end, next)) = rest.char_indices().nth(constants::EMBED_FIELD_VALUE_MAX_LENGTH) {
+        // The character right after the chunk may be the whitespace to split on.
+        let window = &rest[..end + next.len_utf8()];
+
+        match window.rfind(char::is_whitespace) {
+            Some(index) if index > 0 => {
+                let whitespace = rest[index..].chars().next().map_or(0, char::len_utf8);
+
+                chunks.push(&rest[..index]);
+                rest = &rest[index + whitespace..];
+            },
+            _ => {
+                chunks.push(&rest[..end]);
+                rest = &rest[end..];
+            },
+        }
+    }
+
+    chunks.push(rest);
+    chunks.retain(|chunk| !chunk.trim().is_empty());
+
+    if chunks.is_empty() {
+        chunks.push(value);
+    }
+
+    chunks
+}
+
 impl Default for CreateEmbed {
     /// Creates a builder with default values, setting the `type` to `rich`.
     fn default() -> CreateEmbed {
@@ -479,24 +632,39 @@ impl CreateEmbedFooter {
     }
 }
 
+/// A timestamp of an embed, in the RFC 3339 format Discord expects.
+///
+/// Converting a string keeps it unchanged, except for a timestamp without an
+/// offset, which gets the UTC one. Parsing the string with
+/// [`Timestamp::from_str`] instead rejects invalid timestamps.
 #[derive(Clone, Debug)]
 pub struct Timestamp {
     pub ts: String,
 }
 
+impl Timestamp {
+    /// The format of a timestamp without an offset, such as
+    /// `2004-06-08T16:04:23`.
+    const NAIVE_FORMAT: &'static str = "%Y-%m-%dT%H:%M:%S%.f";
+
+    fn parse_naive(ts: &str) -> Option<Self> {
+        NaiveDateTime::parse_from_str(ts, Self::NAIVE_FORMAT)
+            .ok()
+            .map(|dt| Utc.from_utc_datetime(&dt).into())
+    }
+}
+
 impl From<String> for Timestamp {
     fn from(ts: String) -> Self {
-        Self {
+        Self::parse_naive(&ts).unwrap_or(Self {
             ts,
-        }
+        })
     }
 }
 
 impl<'a> From<&'a str> for Timestamp {
     fn from(ts: &'a str) -> Self {
-        Self {
-            ts: ts.to_string(),
-        }
+        ts.to_string().into()
     }
 }
 
@@ -509,12 +677,34 @@ impl<'a, Tz: TimeZone> From<&'a DateTime<Tz>> for Timestamp
     }
 }
 
+impl<Tz: TimeZone> From<DateTime<Tz>> for Timestamp
+    where Tz::Offset: Display {
+    fn from(dt: DateTime<Tz>) -> Self {
+        Self::from(&dt)
+    }
+}
+
+impl FromStr for Timestamp {
+    type Err = ParseError;
+
+    /// Parses an RFC 3339 timestamp, or one without an offset in UTC.
+    fn from_str(ts: &str) -> StdResult<Self, Self::Err> {
+        match DateTime::parse_from_rfc3339(ts) {
+            Ok(dt) => Ok(dt.into()),
+            Err(why) => Self::parse_naive(ts).ok_or(why),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::{model::channel::{Embed, EmbedField, EmbedFooter, EmbedImage, EmbedVideo},
         utils::{self, Colour}};
     use serde_json::{json, Value};
-    use super::CreateEmbed;
+    use super::{CreateEmbed, Timestamp};
+    use crate::model::ModelError;
+    use crate::Error;
+    use chrono::{DateTime, FixedOffset, Utc};
 
     #[test]
     fn test_from_embed() {
@@ -596,4 +786,122 @@ mod test {
 
         assert_eq!(built, obj);
     }
+
+    #[test]
+    fn test_timestamp() {
+        let instant = "2021-01-01T13:30:00.250+01:00".parse::<DateTime<FixedOffset>>().unwrap();
+
+        let mut builder = CreateEmbed::default();
+        builder.timestamp(instant);
+        assert_eq!(builder.0["timestamp"], json!("2021-01-01T13:30:00.250+01:00"));
+
+        builder.timestamp(instant.with_timezone(&Utc));
+        assert_eq!(builder.0["timestamp"], json!("2021-01-01T12:30:00.250+00:00"));
+
+        builder.timestamp("2004-06-08T16:04:23");
+        assert_eq!(builder.0["timestamp"], json!("2004-06-08T16:04:23+00:00"));
+
+        builder.timestamp("2004-06-08T16:04:23Z");
+        assert_eq!(builder.0["timestamp"], json!("2004-06-08T16:04:23Z"));
+
+        assert!("2004-06-08T16:04:23Z".parse::<Timestamp>().is_ok());
+        assert!("yesterday".parse::<Timestamp>().is_err());
+    }
+
+    #[test]
+    fn test_field_chunked() {
+        let value = "word ".repeat(600);
+        assert_eq!(value.len(), 3000);
+
+        let mut builder = CreateEmbed::default();
+        builder.field_chunked("Long", &value, false).unwrap();
+
+        let fields = builder.0["fields"].as_array().unwrap();
+        assert_eq!(fields.len(), 3);
+        assert_eq!(fields[0]["name"], json!("Long"));
+        assert_eq!(fields[1]["name"], json!("\u{200b}"));
+        assert_eq!(fields[2]["name"], json!("\u{200b}"));
+
+        for field in fields {
+            let chunk = field["value"].as_str().unwrap();
+            assert!(!chunk.is_empty() && chunk.chars().count() <= 1024);
+            assert!(chunk.starts_with("word") && chunk.trim_end().ends_with("word"));
+        }
+    }
+
+    #[test]
+    fn test_field_chunked_without_whitespace() {
+        let mut builder = CreateEmbed::default();
+        builder.field_chunked("Long", "é".repeat(3000), true).unwrap();
+
+        let fields = builder.0["fields"].as_array().unwrap();
+        let lengths = fields
+            .iter()
+            .map(|field| field["value"].as_str().unwrap().chars().count())
+            .collect::<Vec<_>>();
+        assert_eq!(lengths, [1024, 1024, 952]);
+    }
+
+    #[test]
+    fn test_field_chunked_whitespace_at_boundary() {
+        let value = format!("{} ", "a".repeat(1024));
+        assert_eq!(super::chunk_field_value(&value), vec!["a".repeat(1024)]);
+
+        let value = format!("{}{}b", "a".repeat(1024), " ".repeat(1025));
+        let chunks = super::chunk_field_value(&value);
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0], "a".repeat(1024));
+        assert_eq!(chunks[1].trim(), "b");
+
+        assert_eq!(super::chunk_field_value(" "), vec![" "]);
+    }
+
+    #[test]
+    fn test_field_chunked_limits() {
+        let mut builder = CreateEmbed::default();
+        builder.fields((0..24).map(|i| (i, "value", false)));
+
+        match builder.field_chunked("Long", "a".repeat(2000), false) {
+            Err(Error::Model(ModelError::EmbedFieldAmount(26))) => {},
+            other => panic!("unexpected result: {:?}", other.map(|_| ())),
+        }
+        assert_eq!(builder.0["fields"].as_array().unwrap().len(), 24);
+
+        let mut builder = CreateEmbed::default();
+        builder.description("a".repeat(4000));
+
+        match builder.field_chunked("Long", "a".repeat(2500), false) {
+            Err(Error::Model(ModelError::EmbedTooLarge(overflow))) => assert_eq!(overflow, 4 + 2 + 500),
+            other => panic!("unexpected result: {:?}", other.map(|_| ())),
+        }
+        assert!(builder.0.get("fields").is_none());
+    }
+
+    #[cfg(feature = "model")]
+    #[test]
+    fn test_from_user() {
+        use crate::model::{id::UserId, user::User};
+
+        let mut user = User::default();
+        user.id = UserId(210);
+        user.name = "ferris".to_string();
+        user.avatar = Some("abc".to_string());
+
+        let mut builder = CreateEmbed::default();
+        builder.author_from_user(&user).footer_from_user(&user);
+
+        assert_eq!(builder.0["author"], json!({
+            "name": "ferris",
+            "icon_url": user.face(),
+        }));
+        assert_eq!(builder.0["footer"], json!({
+            "text": "ferris",
+            "icon_url": user.face(),
+        }));
+        assert_eq!(user.face(), "https://cdn.discordapp.com/avatars/210/abc.webp?size=1024");
+
+        user.avatar = None;
+        builder.author_from_user(&user);
+        assert_eq!(builder.0["author"]["icon_url"], user.default_avatar_url());
+    }
 }
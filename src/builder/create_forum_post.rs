@@ -0,0 +1,192 @@
+use crate::internal::prelude::*;
+use crate::model::id::ForumTagId;
+use super::{CreateComponents, CreateEmbed};
+
+use std::collections::HashMap;
+
+use crate::utils;
+
+/// A builder to create a post in a forum channel, to be used in conjunction
+/// with [`ChannelId::create_forum_post`].
+///
+/// A post is a thread started with an initial message. The [`name`] is
+/// required, and the message must have a [`content`], an [`embed`] or
+/// [`components`].
+///
+/// # Examples
+///
+/// Create a post tagged as a bug report:
+///
+/// ```rust,no_run
+/// # use serenity::http::Http;
+/// # use serenity::model::id::{ChannelId, ForumTagId};
+/// #
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// # let http = Http::default();
+/// let post = ChannelId(7).create_forum_post(&http, |p| {
+///     p.name("The bot is down")
+///         .applied_tags(vec![ForumTagId(1)])
+///         .content("It stopped responding an hour ago.")
+/// }).await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`ChannelId::create_forum_post`]: crate::model::id::ChannelId::create_forum_post
+/// [`name`]: Self::name
+/// [`content`]: Self::content
+/// [`embed`]: Self::embed
+/// [`components`]: Self::components
+#[derive(Clone, Debug, Default)]
+pub struct CreateForumPost(pub HashMap<&'static str, Value>);
+
+impl CreateForumPost {
+    /// The name of the post.
+    ///
+    /// **Note**: Must be between 1 and 100 characters long.
+    pub fn name<D: ToString>(&mut self, name: D) -> &mut Self {
+        self.0.insert("name", Value::String(name.to_string()));
+        self
+    }
+
+    /// The amount of minutes of inactivity after which the post is
+    /// automatically archived.
+    ///
+    /// **Note**: Can only be set to `60`, `1440`, `4320` or `10080`.
+    pub fn auto_archive_duration(&mut self, duration: u16) -> &mut Self {
+        self.0.insert("auto_archive_duration", Value::Number(Number::from(duration)));
+        self
+    }
+
+    /// How many seconds users must wait between sending messages in the
+    /// post. Users with the Manage Messages or Manage Channels permission
+    /// are unaffected.
+    ///
+    /// **Note**: Must be between 0 and 21600 seconds (6 hours).
+    pub fn rate_limit_per_user(&mut self, seconds: u64) -> &mut Self {
+        self.0.insert("rate_limit_per_user", Value::Number(Number::from(seconds)));
+        self
+    }
+
+    /// The tags of the forum channel applied to the post.
+    ///
+    /// **Note**: A post can have up to 5 tags.
+    pub fn applied_tags<I, T>(&mut self, tags: I) -> &mut Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<ForumTagId>,
+    {
+        let tags = tags.into_iter().map(|t| Value::String(t.into().0.to_string())).collect();
+        self.0.insert("applied_tags", Value::Array(tags));
+        self
+    }
+
+    /// Sets the content of the initial message.
+    ///
+    /// **Note**: Message contents must be under 2000 unicode code points.
+    pub fn content<D: ToString>(&mut self, content: D) -> &mut Self {
+        let content = Value::String(content.to_string());
+
+        self.with_message(|message| {
+            message.insert("content".to_string(), content);
+        })
+    }
+
+    /// Creates an embed for the initial message.
+    ///
+    /// **Note**: A message can have up to 10 embeds.
+    pub fn embed<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateEmbed) -> &mut CreateEmbed {
+        let mut embed = CreateEmbed::default();
+        f(&mut embed);
+
+        self.add_embed(embed)
+    }
+
+    /// Adds an embed to the initial message.
+    pub fn add_embed(&mut self, embed: CreateEmbed) -> &mut Self {
+        let embed = Value::Object(utils::hashmap_to_json_map(embed.0));
+
+        self.with_message(|message| {
+            let entry = message
+                .entry("embeds")
+                .or_insert_with(|| Value::Array(vec![]));
+
+            if let Value::Array(ref mut inner) = *entry {
+                inner.push(embed);
+            }
+        })
+    }
+
+    /// Sets all the embeds of the initial message, replacing the existing
+    /// ones.
+    pub fn set_embeds(&mut self, embeds: Vec<CreateEmbed>) -> &mut Self {
+        let embeds = embeds
+            .into_iter()
+            .map(|embed| Value::Object(utils::hashmap_to_json_map(embed.0)))
+            .collect();
+
+        self.with_message(|message| {
+            message.insert("embeds".to_string(), Value::Array(embeds));
+        })
+    }
+
+    /// Sets the components of the initial message, such as buttons and
+    /// select menus.
+    ///
+    /// Refer to the documentation of [`CreateComponents`] for more
+    /// information.
+    pub fn components<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateComponents) -> &mut CreateComponents {
+        let mut components = CreateComponents::default();
+        f(&mut components);
+
+        self.with_message(|message| {
+            message.insert("components".to_string(), Value::Array(components.0));
+        })
+    }
+
+    // If the `message` was replaced by something other than an object, it is
+    // left as is, and the post is rejected when it is created.
+    fn with_message(&mut self, f: impl FnOnce(&mut JsonMap)) -> &mut Self {
+        let entry = self.0
+            .entry("message")
+            .or_insert_with(|| Value::Object(JsonMap::new()));
+
+        if let Value::Object(ref mut message) = *entry {
+            f(message);
+        }
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::CreateForumPost;
+    use crate::model::id::ForumTagId;
+    use crate::utils;
+    use serde_json::{json, Value};
+
+    #[test]
+    fn test_forum_post() {
+        let mut builder = CreateForumPost::default();
+        builder
+            .name("The bot is down")
+            .auto_archive_duration(1440)
+            .applied_tags(vec![ForumTagId(1), ForumTagId(2)])
+            .content("It stopped responding.")
+            .embed(|e| e.title("Logs"));
+
+        let map = utils::hashmap_to_json_map(builder.0);
+        assert_eq!(Value::Object(map), json!({
+            "name": "The bot is down",
+            "auto_archive_duration": 1440,
+            "applied_tags": ["1", "2"],
+            "message": {
+                "content": "It stopped responding.",
+                "embeds": [{"type": "rich", "title": "Logs"}],
+            },
+        }));
+    }
+}
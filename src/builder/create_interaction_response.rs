@@ -0,0 +1,241 @@
+use crate::internal::prelude::*;
+use crate::model::interactions::InteractionResponseType;
+use super::embeds::{push_embed, set_embeds};
+use super::{CreateComponents, CreateEmbed, CreateModal};
+
+use std::collections::HashMap;
+
+use crate::utils;
+
+/// A builder to create the response to an interaction, to be used in
+/// conjunction with [`ApplicationCommandInteraction::create_interaction_response`]
+/// and the methods of the same name on the other interactions.
+///
+/// The response defaults to a [`ChannelMessageWithSource`]. The deferred
+/// responses don't need any data, except for the [ephemeral] flag of a
+/// [`DeferredChannelMessageWithSource`].
+///
+/// [`ApplicationCommandInteraction::create_interaction_response`]: crate::model::interactions::ApplicationCommandInteraction::create_interaction_response
+/// [`ChannelMessageWithSource`]: InteractionResponseType::ChannelMessageWithSource
+/// [`DeferredChannelMessageWithSource`]: InteractionResponseType::DeferredChannelMessageWithSource
+/// [ephemeral]: CreateInteractionResponseData::ephemeral
+#[derive(Clone, Debug)]
+pub struct CreateInteractionResponse(pub HashMap<&'static str, Value>);
+
+impl CreateInteractionResponse {
+    /// Sets the type of the response.
+    pub fn kind(&mut self, kind: InteractionResponseType) -> &mut Self {
+        self.0.insert("type", Value::Number(Number::from(kind.num())));
+        self
+    }
+
+    /// Sets the data of the response, such as the content of the message to
+    /// respond with.
+    ///
+    /// Refer to the documentation of [`CreateInteractionResponseData`] for a
+    /// full list of methods.
+    pub fn interaction_response_data<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateInteractionResponseData) -> &mut CreateInteractionResponseData
+    {
+        let mut data = CreateInteractionResponseData::default();
+        f(&mut data);
+        let map = utils::hashmap_to_json_map(data.0);
+
+        self.0.insert("data", Value::Object(map));
+        self
+    }
+
+    /// Responds with a modal, setting the type of the response to
+    /// [`InteractionResponseType::Modal`].
+    ///
+    /// Refer to the documentation of [`CreateModal`] for a full list of
+    /// methods.
+    ///
+    /// **Note**: Modals can't be sent in response to a modal submission.
+    pub fn modal<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateModal) -> &mut CreateModal
+    {
+        let mut modal = CreateModal::default();
+        f(&mut modal);
+        let map = utils::hashmap_to_json_map(modal.0);
+
+        self.kind(InteractionResponseType::Modal);
+        self.0.insert("data", Value::Object(map));
+        self
+    }
+}
+
+impl Default for CreateInteractionResponse {
+    fn default() -> Self {
+        let mut map = HashMap::new();
+        map.insert("type", Value::Number(Number::from(InteractionResponseType::ChannelMessageWithSource.num())));
+
+        CreateInteractionResponse(map)
+    }
+}
+
+/// A builder for the data of a [`CreateInteractionResponse`].
+#[derive(Clone, Debug, Default)]
+pub struct CreateInteractionResponseData(pub HashMap<&'static str, Value>);
+
+impl CreateInteractionResponseData {
+    /// Sets the content of the message.
+    ///
+    /// **Note**: Message contents must be under 2000 unicode code points.
+    pub fn content<D: ToString>(&mut self, content: D) -> &mut Self {
+        self.0.insert("content", Value::String(content.to_string()));
+        self
+    }
+
+    /// Sets whether the message is text-to-speech.
+    pub fn tts(&mut self, tts: bool) -> &mut Self {
+        self.0.insert("tts", Value::Bool(tts));
+        self
+    }
+
+    /// Creates an embed for the message.
+    ///
+    /// **Note**: A message can have up to 10 embeds.
+    pub fn embed<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateEmbed) -> &mut CreateEmbed {
+        let mut embed = CreateEmbed::default();
+        f(&mut embed);
+
+        self.add_embed(embed)
+    }
+
+    /// Adds an embed to the message.
+    pub fn add_embed(&mut self, embed: CreateEmbed) -> &mut Self {
+        push_embed(&mut self.0, embed);
+        self
+    }
+
+    /// Sets all the embeds of the message, replacing the existing ones.
+    pub fn set_embeds(&mut self, embeds: Vec<CreateEmbed>) -> &mut Self {
+        set_embeds(&mut self.0, embeds);
+        self
+    }
+
+    /// Sets the components of the message, such as buttons and select menus.
+    ///
+    /// Refer to the documentation of [`CreateComponents`] for more
+    /// information.
+    pub fn components<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateComponents) -> &mut CreateComponents {
+        let mut components = CreateComponents::default();
+        f(&mut components);
+
+        self.0.insert("components", Value::Array(components.0));
+        self
+    }
+
+    /// Sets whether the message is only visible to the user who used the
+    /// interaction, by setting its [`EPHEMERAL`] flag.
+    ///
+    /// [`EPHEMERAL`]: crate::model::channel::MessageFlags::EPHEMERAL
+    pub fn ephemeral(&mut self, ephemeral: bool) -> &mut Self {
+        set_ephemeral(&mut self.0, ephemeral);
+        self
+    }
+}
+
+/// The bit of the [`EPHEMERAL`] message flag.
+///
+/// [`EPHEMERAL`]: crate::model::channel::MessageFlags::EPHEMERAL
+const EPHEMERAL_FLAG: u64 = 1 << 6;
+
+pub(super) fn set_ephemeral(map: &mut HashMap<&'static str, Value>, ephemeral: bool) {
+    let flags = map.get("flags").and_then(Value::as_u64).unwrap_or_default();
+    let flags = if ephemeral {
+        flags | EPHEMERAL_FLAG
+    } else {
+        flags & !EPHEMERAL_FLAG
+    };
+
+    map.insert("flags", Value::Number(Number::from(flags)));
+}
+
+#[cfg(test)]
+mod test {
+    use super::{CreateInteractionResponse, CreateInteractionResponseData};
+    use crate::model::interactions::InteractionResponseType;
+    use crate::utils;
+    use serde_json::{json, Value};
+
+    #[test]
+    fn test_interaction_response() {
+        let mut response = CreateInteractionResponse::default();
+        response.interaction_response_data(|d| d.content("pong"));
+
+        let map = utils::hashmap_to_json_map(response.0.clone());
+        assert_eq!(Value::Object(map), json!({"type": 4, "data": {"content": "pong"}}));
+
+        response.kind(InteractionResponseType::UpdateMessage);
+        assert_eq!(response.0["type"], json!(7));
+    }
+
+    #[test]
+    fn test_modal_response() {
+        use crate::model::channel::InputTextStyle;
+
+        let mut response = CreateInteractionResponse::default();
+        response.modal(|m| {
+            m.custom_id("feedback").title("Feedback").components(|c| {
+                c.create_action_row(|row| {
+                    row.create_input_text(|t| {
+                        t.custom_id("text")
+                            .style(InputTextStyle::Paragraph)
+                            .label("Your feedback")
+                            .min_length(10)
+                            .max_length(1000)
+                            .required(false)
+                            .value("Great!")
+                            .placeholder("Tell us")
+                    })
+                })
+            })
+        });
+
+        let map = utils::hashmap_to_json_map(response.0);
+        assert_eq!(Value::Object(map), json!({
+            "type": 9,
+            "data": {
+                "custom_id": "feedback",
+                "title": "Feedback",
+                "components": [{
+                    "type": 1,
+                    "components": [{
+                        "type": 4,
+                        "custom_id": "text",
+                        "style": 2,
+                        "label": "Your feedback",
+                        "min_length": 10,
+                        "max_length": 1000,
+                        "required": false,
+                        "value": "Great!",
+                        "placeholder": "Tell us",
+                    }],
+                }],
+            },
+        }));
+    }
+
+    #[test]
+    fn test_deferred_response_has_no_data() {
+        let mut response = CreateInteractionResponse::default();
+        response.kind(InteractionResponseType::DeferredChannelMessageWithSource);
+
+        let map = utils::hashmap_to_json_map(response.0);
+        assert_eq!(Value::Object(map), json!({"type": 5}));
+    }
+
+    #[test]
+    fn test_ephemeral_flag() {
+        let mut data = CreateInteractionResponseData::default();
+        data.content("secret").ephemeral(true);
+        assert_eq!(data.0["flags"], json!(64));
+
+        data.ephemeral(false);
+        assert_eq!(data.0["flags"], json!(0));
+    }
+}
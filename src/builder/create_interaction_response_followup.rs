@@ -0,0 +1,89 @@
+use crate::internal::prelude::*;
+use super::create_interaction_response::set_ephemeral;
+use super::embeds::{push_embed, set_embeds};
+use super::{CreateComponents, CreateEmbed};
+
+use std::collections::HashMap;
+
+/// A builder to create a follow-up message to an interaction, to be used in
+/// conjunction with [`Interaction::create_followup_message`].
+///
+/// [`Interaction::create_followup_message`]: crate::model::interactions::Interaction::create_followup_message
+#[derive(Clone, Debug, Default)]
+pub struct CreateInteractionResponseFollowup(pub HashMap<&'static str, Value>);
+
+impl CreateInteractionResponseFollowup {
+    /// Sets the content of the message.
+    ///
+    /// **Note**: Message contents must be under 2000 unicode code points.
+    pub fn content<D: ToString>(&mut self, content: D) -> &mut Self {
+        self.0.insert("content", Value::String(content.to_string()));
+        self
+    }
+
+    /// Sets whether the message is text-to-speech.
+    pub fn tts(&mut self, tts: bool) -> &mut Self {
+        self.0.insert("tts", Value::Bool(tts));
+        self
+    }
+
+    /// Creates an embed for the message.
+    ///
+    /// **Note**: A message can have up to 10 embeds.
+    pub fn embed<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateEmbed) -> &mut CreateEmbed {
+        let mut embed = CreateEmbed::default();
+        f(&mut embed);
+
+        self.add_embed(embed)
+    }
+
+    /// Adds an embed to the message.
+    pub fn add_embed(&mut self, embed: CreateEmbed) -> &mut Self {
+        push_embed(&mut self.0, embed);
+        self
+    }
+
+    /// Sets all the embeds of the message, replacing the existing ones.
+    pub fn set_embeds(&mut self, embeds: Vec<CreateEmbed>) -> &mut Self {
+        set_embeds(&mut self.0, embeds);
+        self
+    }
+
+    /// Sets the components of the message, such as buttons and select menus.
+    ///
+    /// Refer to the documentation of [`CreateComponents`] for more
+    /// information.
+    pub fn components<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateComponents) -> &mut CreateComponents {
+        let mut components = CreateComponents::default();
+        f(&mut components);
+
+        self.0.insert("components", Value::Array(components.0));
+        self
+    }
+
+    /// Sets whether the message is only visible to the user who used the
+    /// interaction, by setting its [`EPHEMERAL`] flag.
+    ///
+    /// [`EPHEMERAL`]: crate::model::channel::MessageFlags::EPHEMERAL
+    pub fn ephemeral(&mut self, ephemeral: bool) -> &mut Self {
+        set_ephemeral(&mut self.0, ephemeral);
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::CreateInteractionResponseFollowup;
+    use serde_json::json;
+
+    #[test]
+    fn test_ephemeral_followup() {
+        let mut followup = CreateInteractionResponseFollowup::default();
+        followup.content("only for you").ephemeral(true);
+
+        assert_eq!(followup.0["content"], json!("only for you"));
+        assert_eq!(followup.0["flags"], json!(64));
+    }
+}
@@ -1,11 +1,17 @@
 use crate::internal::prelude::*;
+use crate::model::id::{ApplicationId, UserId};
+use crate::model::invite::InviteTargetType;
 use std::collections::HashMap;
 use serde_json::Value;
 
 /// A builder to create a [`RichInvite`] for use via [`GuildChannel::create_invite`].
 ///
 /// This is a structured and cleaner way of creating an invite, as all
-/// parameters are optional.
+/// parameters are optional. The parameters which are not set are left to
+/// Discord's defaults.
+///
+/// An invite can target either a user's stream, with [`target_user_id`], or
+/// an embedded application, with [`target_application_id`], but not both.
 ///
 /// # Examples
 ///
@@ -67,6 +73,8 @@ use serde_json::Value;
 ///
 /// [`GuildChannel::create_invite`]: crate::model::channel::GuildChannel::create_invite
 /// [`RichInvite`]: crate::model::invite::RichInvite
+/// [`target_user_id`]: Self::target_user_id
+/// [`target_application_id`]: Self::target_application_id
 #[derive(Clone, Debug)]
 pub struct CreateInvite(pub HashMap<&'static str, Value>);
 
@@ -206,6 +214,41 @@ impl CreateInvite {
         self.0.insert("unique", Value::Bool(unique));
         self
     }
+
+    /// The kind of target of the invite.
+    ///
+    /// This is set by [`target_user_id`] and [`target_application_id`], and
+    /// only needs to be set manually for new kinds of targets.
+    ///
+    /// [`target_user_id`]: Self::target_user_id
+    /// [`target_application_id`]: Self::target_application_id
+    pub fn target_type(&mut self, target_type: InviteTargetType) -> &mut Self {
+        self.0.insert("target_type", Value::Number(Number::from(target_type.num())));
+        self
+    }
+
+    /// The user whose stream is displayed by the invite, setting the
+    /// [`target_type`] to [`InviteTargetType::Stream`].
+    ///
+    /// **Note**: The user must be streaming in the voice channel of the
+    /// invite.
+    ///
+    /// [`target_type`]: Self::target_type
+    pub fn target_user_id<U: Into<UserId>>(&mut self, user_id: U) -> &mut Self {
+        self.0.insert("target_user_id", Value::String(user_id.into().0.to_string()));
+        self.target_type(InviteTargetType::Stream)
+    }
+
+    /// The embedded application opened by the invite, setting the
+    /// [`target_type`] to [`InviteTargetType::EmbeddedApplication`].
+    ///
+    /// **Note**: The application must have the `EMBEDDED` flag.
+    ///
+    /// [`target_type`]: Self::target_type
+    pub fn target_application_id<A: Into<ApplicationId>>(&mut self, application_id: A) -> &mut Self {
+        self.0.insert("target_application_id", Value::String(application_id.into().0.to_string()));
+        self.target_type(InviteTargetType::EmbeddedApplication)
+    }
 }
 
 impl Default for CreateInvite {
@@ -1,8 +1,11 @@
 use crate::internal::prelude::*;
 use crate::http::AttachmentType;
 use crate::model::channel::{ReactionType, MessageReference};
+use crate::model::id::StickerId;
+use super::embeds::{push_embed, set_embeds};
 use super::CreateEmbed;
 use super::CreateAllowedMentions;
+use super::CreateComponents;
 use crate::utils;
 
 use std::collections::HashMap;
@@ -70,20 +73,34 @@ impl<'a> CreateMessage<'a> {
         self
     }
 
-    /// Create an embed for the message.
+    /// Create an embed for the message, adding it after the existing ones.
+    ///
+    /// **Note**: A message can have up to 10 embeds.
     pub fn embed<F>(&mut self, f: F) -> &mut Self
     where F: FnOnce(&mut CreateEmbed) -> &mut CreateEmbed {
         let mut embed = CreateEmbed::default();
         f(&mut embed);
-        self.set_embed(embed)
+        self.add_embed(embed)
     }
 
-    /// Set an embed for the message.
+    /// Set an embed for the message, replacing the existing ones.
     pub fn set_embed(&mut self, embed: CreateEmbed) -> &mut Self {
-        let map = utils::hashmap_to_json_map(embed.0);
-        let embed = Value::Object(map);
+        self.set_embeds(vec![embed])
+    }
 
-        self.0.insert("embed", embed);
+    /// Add an embed to the message.
+    ///
+    /// **Note**: A message can have up to 10 embeds.
+    pub fn add_embed(&mut self, embed: CreateEmbed) -> &mut Self {
+        push_embed(&mut self.0, embed);
+        self
+    }
+
+    /// Set all the embeds of the message, replacing the existing ones.
+    ///
+    /// **Note**: A message can have up to 10 embeds.
+    pub fn set_embeds(&mut self, embeds: Vec<CreateEmbed>) -> &mut Self {
+        set_embeds(&mut self.0, embeds);
         self
     }
 
@@ -114,6 +131,17 @@ impl<'a> CreateMessage<'a> {
         self
     }
 
+    /// Appends a file to the message, with a description used as its
+    /// alternative text.
+    ///
+    /// **Note**: The description must be at most 1024 unicode code points.
+    pub fn add_file_with_description<T, D>(&mut self, file: T, description: D) -> &mut Self
+        where T: Into<AttachmentType<'a>>, D: ToString {
+        add_attachment_description(&mut self.0, self.2.len(), description.to_string());
+        self.2.push(file.into());
+        self
+    }
+
     /// Appends a list of files to the message.
     pub fn add_files<T: Into<AttachmentType<'a>>, It: IntoIterator<Item=T>>(&mut self, files: It) -> &mut Self {
         self.2.extend(files.into_iter().map(|f| f.into()));
@@ -141,6 +169,53 @@ impl<'a> CreateMessage<'a> {
         self
     }
 
+    /// Set the components of the message, such as buttons and select menus.
+    ///
+    /// Refer to the documentation for [`CreateComponents`] for more
+    /// information.
+    pub fn components<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateComponents) -> &mut CreateComponents {
+        let mut components = CreateComponents::default();
+        f(&mut components);
+
+        self.0.insert("components", Value::Array(components.0));
+        self
+    }
+
+    /// Add a sticker to the message.
+    ///
+    /// **Note**: At most 3 stickers can be sent with a message.
+    pub fn sticker_id(&mut self, sticker_id: impl Into<StickerId>) -> &mut Self {
+        let sticker_id = Value::Number(Number::from(sticker_id.into().0));
+        let sticker_ids = self.0
+            .entry("sticker_ids")
+            .or_insert_with(|| Value::Array(Vec::new()));
+
+        if let Value::Array(sticker_ids) = sticker_ids {
+            sticker_ids.push(sticker_id);
+        }
+
+        self
+    }
+
+    /// Sets a list of stickers to include in the message.
+    ///
+    /// Calling this multiple times will overwrite the sticker list.
+    /// To append a sticker, call [`sticker_id`] instead.
+    ///
+    /// **Note**: At most 3 stickers can be sent with a message.
+    ///
+    /// [`sticker_id`]: Self::sticker_id
+    pub fn sticker_ids<T: Into<StickerId>, It: IntoIterator<Item=T>>(&mut self, sticker_ids: It) -> &mut Self {
+        let sticker_ids = sticker_ids
+            .into_iter()
+            .map(|id| Value::Number(Number::from(id.into().0)))
+            .collect();
+
+        self.0.insert("sticker_ids", Value::Array(sticker_ids));
+        self
+    }
+
     /// Set the reference message this message is a reply to.
     pub fn reference_message(&mut self, reference: impl Into<MessageReference>) -> &mut Self {
         self.0.insert("message_reference", serde_json::to_value(reference.into()).unwrap());
@@ -161,3 +236,69 @@ impl<'a> Default for CreateMessage<'a> {
         CreateMessage(map, None, Vec::new())
     }
 }
+
+/// Describes the file uploaded with the given Id, which is its index among the
+/// uploaded files, in the `attachments` of a message.
+pub(super) fn add_attachment_description(map: &mut HashMap<&'static str, Value>, id: usize, description: String) {
+    let attachments = map
+        .entry("attachments")
+        .or_insert_with(|| Value::Array(Vec::new()));
+
+    if let Value::Array(attachments) = attachments {
+        let mut attachment = JsonMap::new();
+        attachment.insert("id".to_string(), Value::Number(Number::from(id)));
+        attachment.insert("description".to_string(), Value::String(description));
+
+        attachments.push(Value::Object(attachment));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::CreateMessage;
+    use crate::builder::CreateEmbed;
+    use crate::http::AttachmentType;
+    use serde_json::json;
+
+    #[test]
+    fn test_multiple_embeds() {
+        let mut second = CreateEmbed::default();
+        second.title("second");
+
+        let mut builder = CreateMessage::default();
+        builder
+            .embed(|e| e.title("first"))
+            .add_embed(second)
+            .embed(|e| e.title("third"));
+
+        assert_eq!(builder.0["embeds"], json!([
+            {"type": "rich", "title": "first"},
+            {"type": "rich", "title": "second"},
+            {"type": "rich", "title": "third"},
+        ]));
+
+        builder.set_embed(CreateEmbed::default());
+        assert_eq!(builder.0["embeds"].as_array().map(Vec::len), Some(1));
+    }
+
+    #[test]
+    fn test_file_descriptions() {
+        let file = |name: &str| AttachmentType::Bytes { data: vec![].into(), filename: name.to_string() };
+
+        let mut builder = CreateMessage::default();
+        builder
+            .add_file(file("a.png"))
+            .add_file_with_description(file("b.png"), "A cat sleeping");
+
+        assert_eq!(builder.2.len(), 2);
+        assert_eq!(builder.0["attachments"], json!([{"id": 1, "description": "A cat sleeping"}]));
+    }
+
+    #[test]
+    fn test_files_without_description() {
+        let mut builder = CreateMessage::default();
+        builder.add_file(AttachmentType::Bytes { data: vec![].into(), filename: "a.png".to_string() });
+
+        assert!(builder.0.get("attachments").is_none());
+    }
+}
@@ -0,0 +1,80 @@
+use crate::internal::prelude::*;
+use super::CreateComponents;
+
+use std::collections::HashMap;
+
+/// A builder for a modal, to be used in conjunction with
+/// [`CreateInteractionResponse::modal`].
+///
+/// The [`custom_id`], [`title`] and [`components`] are required. A modal has
+/// up to 5 [action rows], each holding a single [text field].
+///
+/// # Examples
+///
+/// Respond to an interaction with a feedback form:
+///
+/// ```rust,no_run
+/// # use serenity::http::Http;
+/// # use serenity::model::interactions::Interaction;
+/// #
+/// # async fn run(http: Http, interaction: Interaction) -> Result<(), Box<dyn std::error::Error>> {
+/// use serenity::model::channel::InputTextStyle;
+///
+/// interaction.create_response(&http, |r| {
+///     r.modal(|m| {
+///         m.custom_id("feedback").title("Feedback").components(|c| {
+///             c.create_action_row(|row| {
+///                 row.create_input_text(|t| {
+///                     t.custom_id("text")
+///                         .style(InputTextStyle::Paragraph)
+///                         .label("What do you think?")
+///                         .max_length(1000)
+///                 })
+///             })
+///         })
+///     })
+/// }).await?;
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`CreateInteractionResponse::modal`]: super::CreateInteractionResponse::modal
+/// [`custom_id`]: Self::custom_id
+/// [`title`]: Self::title
+/// [`components`]: Self::components
+/// [action rows]: super::CreateActionRow
+/// [text field]: super::CreateInputText
+#[derive(Clone, Debug, Default)]
+pub struct CreateModal(pub HashMap<&'static str, Value>);
+
+impl CreateModal {
+    /// The developer-defined Id sent along with the interaction when the
+    /// modal is submitted.
+    ///
+    /// **Note**: Can't be longer than 100 characters.
+    pub fn custom_id<D: ToString>(&mut self, id: D) -> &mut Self {
+        self.0.insert("custom_id", Value::String(id.to_string()));
+        self
+    }
+
+    /// The title displayed at the top of the modal.
+    ///
+    /// **Note**: Can't be longer than 45 characters.
+    pub fn title<D: ToString>(&mut self, title: D) -> &mut Self {
+        self.0.insert("title", Value::String(title.to_string()));
+        self
+    }
+
+    /// Sets the rows of the modal, each holding a single text field.
+    ///
+    /// Refer to the documentation of [`CreateComponents`] for more
+    /// information.
+    pub fn components<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateComponents) -> &mut CreateComponents {
+        let mut components = CreateComponents::default();
+        f(&mut components);
+
+        self.0.insert("components", Value::Array(components.0));
+        self
+    }
+}
@@ -0,0 +1,104 @@
+use crate::internal::prelude::*;
+use crate::model::prelude::*;
+
+use std::collections::HashMap;
+
+/// A builder for a channel's permission overwrite of a [`Member`] or [`Role`],
+/// to be used in conjunction with [`GuildChannel::create_permission`] and
+/// [`CreateChannel::permissions`].
+///
+/// The allowed and denied permissions are sent as stringified bitfields, and
+/// the target as the `type` integer used by Discord.
+///
+/// [`Member`]: crate::model::guild::Member
+/// [`Role`]: crate::model::guild::Role
+/// [`GuildChannel::create_permission`]: crate::model::channel::GuildChannel::create_permission
+/// [`CreateChannel::permissions`]: super::CreateChannel::permissions
+#[derive(Clone, Debug, Default)]
+pub struct CreatePermissionOverwrite(pub HashMap<&'static str, Value>);
+
+impl CreatePermissionOverwrite {
+    /// The member or role the overwrite applies to.
+    pub fn target(&mut self, target: PermissionOverwriteType) -> &mut Self {
+        let (id, kind) = match target {
+            PermissionOverwriteType::Role(id) => (id.0, 0),
+            PermissionOverwriteType::Member(id) => (id.0, 1),
+        };
+
+        self.0.insert("id", Value::String(id.to_string()));
+        self.0.insert("type", Value::Number(Number::from(kind)));
+        self
+    }
+
+    /// The permissions explicitly allowed to the target.
+    pub fn allow(&mut self, permissions: Permissions) -> &mut Self {
+        self.0.insert("allow", Value::String(permissions.bits().to_string()));
+        self
+    }
+
+    /// The permissions explicitly denied to the target.
+    pub fn deny(&mut self, permissions: Permissions) -> &mut Self {
+        self.0.insert("deny", Value::String(permissions.bits().to_string()));
+        self
+    }
+}
+
+impl From<&PermissionOverwrite> for CreatePermissionOverwrite {
+    fn from(overwrite: &PermissionOverwrite) -> Self {
+        let mut builder = CreatePermissionOverwrite::default();
+        builder.target(overwrite.kind).allow(overwrite.allow).deny(overwrite.deny);
+
+        builder
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::CreatePermissionOverwrite;
+    use crate::model::prelude::*;
+    use crate::utils;
+    use serde_json::{json, Value};
+
+    #[test]
+    fn member_overwrite() {
+        let mut builder = CreatePermissionOverwrite::default();
+        builder
+            .target(PermissionOverwriteType::Member(UserId(7)))
+            .allow(Permissions::SEND_MESSAGES)
+            .deny(Permissions::empty());
+
+        let map = utils::hashmap_to_json_map(builder.0);
+        assert_eq!(Value::Object(map), json!({
+            "id": "7",
+            "type": 1,
+            "allow": "2048",
+            "deny": "0",
+        }));
+    }
+
+    #[test]
+    fn role_overwrite() {
+        let overwrite = PermissionOverwrite {
+            allow: Permissions::empty(),
+            deny: Permissions::ATTACH_FILES | Permissions::SEND_TTS_MESSAGES,
+            kind: PermissionOverwriteType::Role(RoleId(8)),
+        };
+
+        let map = utils::hashmap_to_json_map(CreatePermissionOverwrite::from(&overwrite).0);
+        assert_eq!(Value::Object(map), json!({
+            "id": "8",
+            "type": 0,
+            "allow": "0",
+            "deny": "36864",
+        }));
+    }
+
+    #[test]
+    fn bitfields_are_strings() {
+        let mut builder = CreatePermissionOverwrite::default();
+        builder.allow(Permissions::all());
+
+        let allow = builder.0["allow"].as_str().unwrap();
+        assert_eq!(allow.parse::<u64>().unwrap(), Permissions::all().bits());
+    }
+}
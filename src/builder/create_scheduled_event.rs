@@ -0,0 +1,87 @@
+use crate::internal::prelude::*;
+use crate::model::prelude::*;
+use super::Timestamp;
+
+use serde_json::json;
+
+use std::collections::HashMap;
+
+/// A builder for creating a new [`ScheduledEvent`] in a [`Guild`], to be used
+/// in conjunction with [`GuildId::create_scheduled_event`].
+///
+/// The [`name`], [`start_time`] and [`kind`] are required. If the event is an
+/// [external] one, the [`end_time`] and [`location`] are required as well.
+/// Otherwise, the [`channel_id`] is required.
+///
+/// [`ScheduledEvent`]: crate::model::guild::ScheduledEvent
+/// [`Guild`]: crate::model::guild::Guild
+/// [`GuildId::create_scheduled_event`]: crate::model::id::GuildId::create_scheduled_event
+/// [`name`]: Self::name
+/// [`start_time`]: Self::start_time
+/// [`kind`]: Self::kind
+/// [external]: ScheduledEventType::External
+/// [`end_time`]: Self::end_time
+/// [`location`]: Self::location
+/// [`channel_id`]: Self::channel_id
+#[derive(Clone, Debug)]
+pub struct CreateScheduledEvent(pub HashMap<&'static str, Value>);
+
+impl CreateScheduledEvent {
+    /// The name of the event.
+    ///
+    /// **Note**: Must be between 1 and 100 characters long.
+    pub fn name<D: ToString>(&mut self, name: D) -> &mut Self {
+        self.0.insert("name", Value::String(name.to_string()));
+        self
+    }
+
+    /// The description of the event.
+    ///
+    /// **Note**: Must be between 1 and 1000 characters long.
+    pub fn description<D: ToString>(&mut self, description: D) -> &mut Self {
+        self.0.insert("description", Value::String(description.to_string()));
+        self
+    }
+
+    /// The channel the event will be hosted in.
+    pub fn channel_id<C: Into<ChannelId>>(&mut self, channel_id: C) -> &mut Self {
+        self.0.insert("channel_id", Value::Number(Number::from(channel_id.into().0)));
+        self
+    }
+
+    /// The time at which the event will start.
+    pub fn start_time<T: Into<Timestamp>>(&mut self, timestamp: T) -> &mut Self {
+        self.0.insert("scheduled_start_time", Value::String(timestamp.into().ts));
+        self
+    }
+
+    /// The time at which the event will end.
+    pub fn end_time<T: Into<Timestamp>>(&mut self, timestamp: T) -> &mut Self {
+        self.0.insert("scheduled_end_time", Value::String(timestamp.into().ts));
+        self
+    }
+
+    /// The type of entity hosting the event.
+    pub fn kind(&mut self, kind: ScheduledEventType) -> &mut Self {
+        self.0.insert("entity_type", Value::Number(Number::from(kind.num())));
+        self
+    }
+
+    /// The location of an external event.
+    pub fn location<D: ToString>(&mut self, location: D) -> &mut Self {
+        self.0.insert("entity_metadata", json!({ "location": location.to_string() }));
+        self
+    }
+}
+
+impl Default for CreateScheduledEvent {
+    /// Creates a builder with default values, setting the `privacy_level` to
+    /// [`ScheduledEventPrivacyLevel::GuildOnly`], as it is the only one
+    /// supported by Discord.
+    fn default() -> Self {
+        let mut map = HashMap::new();
+        map.insert("privacy_level", Value::Number(Number::from(ScheduledEventPrivacyLevel::GuildOnly.num())));
+
+        CreateScheduledEvent(map)
+    }
+}
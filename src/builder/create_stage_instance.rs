@@ -0,0 +1,39 @@
+use crate::internal::prelude::*;
+use crate::model::channel::StagePrivacyLevel;
+
+use std::collections::HashMap;
+
+/// A builder to start or edit the stage instance of a stage channel, to be used
+/// in conjunction with [`ChannelId::create_stage_instance`] and
+/// [`ChannelId::edit_stage_instance`].
+///
+/// The [`topic`] is required when starting a stage instance.
+///
+/// [`ChannelId::create_stage_instance`]: crate::model::id::ChannelId::create_stage_instance
+/// [`ChannelId::edit_stage_instance`]: crate::model::id::ChannelId::edit_stage_instance
+/// [`topic`]: Self::topic
+#[derive(Clone, Debug, Default)]
+pub struct CreateStageInstance(pub HashMap<&'static str, Value>);
+
+impl CreateStageInstance {
+    /// The topic of the stage instance.
+    ///
+    /// **Note**: Must be between 1 and 120 characters long.
+    pub fn topic<D: ToString>(&mut self, topic: D) -> &mut Self {
+        self.0.insert("topic", Value::String(topic.to_string()));
+        self
+    }
+
+    /// Who can access the stage instance.
+    ///
+    /// Defaults to [`StagePrivacyLevel::GuildOnly`].
+    pub fn privacy_level(&mut self, privacy_level: StagePrivacyLevel) -> &mut Self {
+        self.0.insert("privacy_level", Value::Number(Number::from(privacy_level.num())));
+        self
+    }
+
+    pub(crate) fn channel_id(&mut self, channel_id: u64) -> &mut Self {
+        self.0.insert("channel_id", Value::String(channel_id.to_string()));
+        self
+    }
+}
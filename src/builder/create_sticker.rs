@@ -0,0 +1,49 @@
+use crate::internal::prelude::*;
+use crate::http::AttachmentType;
+
+use std::collections::HashMap;
+
+/// A builder to create a guild sticker, to be used in conjunction with
+/// [`GuildId::create_sticker`].
+///
+/// The [`name`], [`tags`] and [`file`] are required.
+///
+/// [`GuildId::create_sticker`]: crate::model::id::GuildId::create_sticker
+/// [`name`]: Self::name
+/// [`tags`]: Self::tags
+/// [`file`]: Self::file
+#[derive(Clone, Debug, Default)]
+pub struct CreateSticker<'a>(pub HashMap<&'static str, Value>, pub Option<AttachmentType<'a>>);
+
+impl<'a> CreateSticker<'a> {
+    /// The name of the sticker.
+    ///
+    /// **Note**: Must be between 2 and 30 characters long.
+    pub fn name<S: ToString>(&mut self, name: S) -> &mut Self {
+        self.0.insert("name", Value::String(name.to_string()));
+        self
+    }
+
+    /// The description of the sticker.
+    ///
+    /// **Note**: If set, must be between 2 and 100 characters long.
+    pub fn description<S: ToString>(&mut self, description: S) -> &mut Self {
+        self.0.insert("description", Value::String(description.to_string()));
+        self
+    }
+
+    /// The name of the emoji related to the sticker, used for autocompletion.
+    ///
+    /// **Note**: Must be between 2 and 200 characters long.
+    pub fn tags<S: ToString>(&mut self, tags: S) -> &mut Self {
+        self.0.insert("tags", Value::String(tags.to_string()));
+        self
+    }
+
+    /// The file of the sticker, which must be a PNG, APNG or Lottie JSON file
+    /// of at most 500 KB.
+    pub fn file<T: Into<AttachmentType<'a>>>(&mut self, file: T) -> &mut Self {
+        self.1 = Some(file.into());
+        self
+    }
+}
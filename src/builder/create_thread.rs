@@ -0,0 +1,51 @@
+use crate::internal::prelude::*;
+use crate::model::channel::ChannelType;
+
+use std::collections::HashMap;
+
+/// A builder for creating a new thread, to be used in conjunction with
+/// [`ChannelId::create_public_thread`], [`ChannelId::create_private_thread`]
+/// or [`ChannelId::create_thread_from_message`].
+///
+/// The [`name`] is required.
+///
+/// [`ChannelId::create_public_thread`]: crate::model::id::ChannelId::create_public_thread
+/// [`ChannelId::create_private_thread`]: crate::model::id::ChannelId::create_private_thread
+/// [`ChannelId::create_thread_from_message`]: crate::model::id::ChannelId::create_thread_from_message
+/// [`name`]: Self::name
+#[derive(Clone, Debug, Default)]
+pub struct CreateThread(pub HashMap<&'static str, Value>);
+
+impl CreateThread {
+    /// The name of the thread.
+    ///
+    /// **Note**: Must be between 1 and 100 characters long.
+    pub fn name<D: ToString>(&mut self, name: D) -> &mut Self {
+        self.0.insert("name", Value::String(name.to_string()));
+        self
+    }
+
+    /// The amount of minutes of inactivity after which the thread is
+    /// automatically archived.
+    ///
+    /// **Note**: Can only be set to `60`, `1440`, `4320` or `10080`.
+    pub fn auto_archive_duration(&mut self, duration: u16) -> &mut Self {
+        self.0.insert("auto_archive_duration", Value::Number(Number::from(duration)));
+        self
+    }
+
+    /// How many seconds users must wait between sending messages in the
+    /// thread. Users with the Manage Messages or Manage Channels permission
+    /// are unaffected.
+    ///
+    /// **Note**: Must be between 0 and 21600 seconds (6 hours).
+    pub fn rate_limit_per_user(&mut self, seconds: u64) -> &mut Self {
+        self.0.insert("rate_limit_per_user", Value::Number(Number::from(seconds)));
+        self
+    }
+
+    pub(crate) fn kind(&mut self, kind: ChannelType) -> &mut Self {
+        self.0.insert("type", Value::Number(Number::from(kind.num())));
+        self
+    }
+}
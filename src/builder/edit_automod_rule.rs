@@ -0,0 +1,224 @@
+use crate::internal::prelude::*;
+use crate::model::prelude::*;
+
+use serde_json::json;
+
+use std::collections::HashMap;
+
+/// A builder to create or edit an [`AutoModRule`], to be used in conjunction
+/// with [`GuildId::create_automod_rule`] and [`GuildId::edit_automod_rule`].
+///
+/// When creating a rule, the [`name`], [`event_type`], [`trigger_type`] and
+/// at least one action are required. When editing a rule, only the fields
+/// which have been set are sent to Discord.
+///
+/// # Examples
+///
+/// Create a rule blocking messages containing a word, and timing out their
+/// author for a minute:
+///
+/// ```rust,no_run
+/// # use serenity::http::Http;
+/// # use serenity::model::guild::{AutoModEventType, AutoModTriggerType};
+/// # use serenity::model::id::GuildId;
+/// #
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// # let http = Http::default();
+/// let rule = GuildId(7).create_automod_rule(&http, |r| {
+///     r.name("No cats")
+///         .event_type(AutoModEventType::MessageSend)
+///         .trigger_type(AutoModTriggerType::Keyword)
+///         .keyword_filter(vec!["cat*"])
+///         .block_message()
+///         .timeout(60)
+/// }).await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`AutoModRule`]: crate::model::guild::AutoModRule
+/// [`GuildId::create_automod_rule`]: crate::model::id::GuildId::create_automod_rule
+/// [`GuildId::edit_automod_rule`]: crate::model::id::GuildId::edit_automod_rule
+/// [`name`]: Self::name
+/// [`event_type`]: Self::event_type
+/// [`trigger_type`]: Self::trigger_type
+#[derive(Clone, Debug, Default)]
+pub struct EditAutoModRule(pub HashMap<&'static str, Value>);
+
+impl EditAutoModRule {
+    /// The name of the rule.
+    pub fn name<D: ToString>(&mut self, name: D) -> &mut Self {
+        self.0.insert("name", Value::String(name.to_string()));
+        self
+    }
+
+    /// The event checked by the rule.
+    pub fn event_type(&mut self, event_type: AutoModEventType) -> &mut Self {
+        self.0.insert("event_type", Value::Number(Number::from(event_type.num())));
+        self
+    }
+
+    /// The kind of content that triggers the rule.
+    ///
+    /// **Note**: This can only be set when creating the rule.
+    pub fn trigger_type(&mut self, trigger_type: AutoModTriggerType) -> &mut Self {
+        self.0.insert("trigger_type", Value::Number(Number::from(trigger_type.num())));
+        self
+    }
+
+    /// The words that trigger a [`AutoModTriggerType::Keyword`] rule.
+    ///
+    /// Wildcards can be used with `*` at the start or end of a word.
+    pub fn keyword_filter<I, D>(&mut self, keywords: I) -> &mut Self
+    where
+        I: IntoIterator<Item = D>,
+        D: ToString,
+    {
+        let keywords = keywords.into_iter().map(|k| Value::String(k.to_string())).collect();
+        self.trigger_metadata("keyword_filter", Value::Array(keywords))
+    }
+
+    /// The regular expressions that trigger a [`AutoModTriggerType::Keyword`]
+    /// rule.
+    pub fn regex_patterns<I, D>(&mut self, patterns: I) -> &mut Self
+    where
+        I: IntoIterator<Item = D>,
+        D: ToString,
+    {
+        let patterns = patterns.into_iter().map(|p| Value::String(p.to_string())).collect();
+        self.trigger_metadata("regex_patterns", Value::Array(patterns))
+    }
+
+    /// The word lists used by a [`AutoModTriggerType::KeywordPreset`] rule.
+    pub fn presets<I>(&mut self, presets: I) -> &mut Self
+    where I: IntoIterator<Item = AutoModKeywordPresetType> {
+        let presets = presets.into_iter().map(|p| Value::Number(Number::from(p.num()))).collect();
+        self.trigger_metadata("presets", Value::Array(presets))
+    }
+
+    /// The words that are exempt from the rule.
+    pub fn allow_list<I, D>(&mut self, words: I) -> &mut Self
+    where
+        I: IntoIterator<Item = D>,
+        D: ToString,
+    {
+        let words = words.into_iter().map(|w| Value::String(w.to_string())).collect();
+        self.trigger_metadata("allow_list", Value::Array(words))
+    }
+
+    /// The number of unique user and role mentions allowed per message by a
+    /// [`AutoModTriggerType::MentionSpam`] rule.
+    pub fn mention_total_limit(&mut self, limit: u64) -> &mut Self {
+        self.trigger_metadata("mention_total_limit", Value::Number(Number::from(limit)))
+    }
+
+    /// Blocks the messages triggering the rule.
+    pub fn block_message(&mut self) -> &mut Self {
+        self.add_action(AutoModActionType::BlockMessage, json!({}))
+    }
+
+    /// Sends an alert to the given channel when the rule is triggered.
+    pub fn alert_channel<C: Into<ChannelId>>(&mut self, channel_id: C) -> &mut Self {
+        self.add_action(AutoModActionType::SendAlertMessage, json!({ "channel_id": channel_id.into().0.to_string() }))
+    }
+
+    /// Times out the members triggering the rule for the given duration.
+    ///
+    /// **Note**: The duration is in seconds, and can be up to 4 weeks long.
+    pub fn timeout(&mut self, duration_seconds: u64) -> &mut Self {
+        self.add_action(AutoModActionType::Timeout, json!({ "duration_seconds": duration_seconds }))
+    }
+
+    /// Sets whether the rule is enabled.
+    pub fn enabled(&mut self, enabled: bool) -> &mut Self {
+        self.0.insert("enabled", Value::Bool(enabled));
+        self
+    }
+
+    /// The roles that are not affected by the rule.
+    pub fn exempt_roles<I, R>(&mut self, roles: I) -> &mut Self
+    where
+        I: IntoIterator<Item = R>,
+        R: Into<RoleId>,
+    {
+        let roles = roles.into_iter().map(|r| Value::String(r.into().0.to_string())).collect();
+        self.0.insert("exempt_roles", Value::Array(roles));
+        self
+    }
+
+    /// The channels that are not affected by the rule.
+    pub fn exempt_channels<I, C>(&mut self, channels: I) -> &mut Self
+    where
+        I: IntoIterator<Item = C>,
+        C: Into<ChannelId>,
+    {
+        let channels = channels.into_iter().map(|c| Value::String(c.into().0.to_string())).collect();
+        self.0.insert("exempt_channels", Value::Array(channels));
+        self
+    }
+
+    fn trigger_metadata(&mut self, key: &str, value: Value) -> &mut Self {
+        let entry = self.0.entry("trigger_metadata").or_insert_with(|| json!({}));
+
+        if let Value::Object(ref mut metadata) = *entry {
+            metadata.insert(key.to_string(), value);
+        }
+
+        self
+    }
+
+    fn add_action(&mut self, kind: AutoModActionType, metadata: Value) -> &mut Self {
+        let action = json!({
+            "type": kind.num(),
+            "metadata": metadata,
+        });
+        let entry = self.0.entry("actions").or_insert_with(|| Value::Array(vec![]));
+
+        if let Value::Array(ref mut actions) = *entry {
+            actions.push(action);
+        }
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::EditAutoModRule;
+    use crate::model::guild::{AutoModEventType, AutoModKeywordPresetType, AutoModTriggerType};
+    use crate::model::id::{ChannelId, RoleId};
+    use crate::utils;
+    use serde_json::{json, Value};
+
+    #[test]
+    fn test_automod_rule() {
+        let mut builder = EditAutoModRule::default();
+        builder
+            .name("Word filter")
+            .event_type(AutoModEventType::MessageSend)
+            .trigger_type(AutoModTriggerType::KeywordPreset)
+            .presets(vec![AutoModKeywordPresetType::Profanity, AutoModKeywordPresetType::Slurs])
+            .allow_list(vec!["heck"])
+            .block_message()
+            .alert_channel(ChannelId(5))
+            .timeout(60)
+            .exempt_roles(vec![RoleId(7)]);
+
+        let map = utils::hashmap_to_json_map(builder.0);
+        assert_eq!(Value::Object(map), json!({
+            "name": "Word filter",
+            "event_type": 1,
+            "trigger_type": 4,
+            "trigger_metadata": {
+                "presets": [1, 3],
+                "allow_list": ["heck"],
+            },
+            "actions": [
+                {"type": 1, "metadata": {}},
+                {"type": 2, "metadata": {"channel_id": "5"}},
+                {"type": 3, "metadata": {"duration_seconds": 60}},
+            ],
+            "exempt_roles": ["7"],
+        }));
+    }
+}
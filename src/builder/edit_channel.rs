@@ -108,7 +108,7 @@ impl EditChannel {
 
     /// The seconds a user has to wait before sending another message.
     ///
-    /// **Info**: Only values from 0 to 120 are valid.
+    /// **Info**: Only values from 0 to 21600 are valid.
     #[inline]
     pub fn slow_mode_rate(&mut self, seconds: u64) -> &mut Self {
         self.0.insert("rate_limit_per_user", Value::Number(Number::from(seconds)));
@@ -0,0 +1,57 @@
+use crate::internal::prelude::*;
+use crate::model::id::RoleId;
+use std::collections::HashMap;
+
+/// A builder to edit the properties of an [`Emoji`], to be used in
+/// conjunction with [`Emoji::edit`].
+///
+/// Only the fields which have been set are sent to Discord, so the unset ones
+/// are left untouched.
+///
+/// [`Emoji`]: crate::model::guild::Emoji
+/// [`Emoji::edit`]: crate::model::guild::Emoji::edit
+#[derive(Clone, Debug, Default)]
+pub struct EditEmoji(pub HashMap<&'static str, Value>);
+
+impl EditEmoji {
+    /// The new name of the emoji. It must be at least 2 characters long and
+    /// can only contain alphanumeric characters and underscores.
+    pub fn name<S: ToString>(&mut self, name: S) -> &mut Self {
+        self.0.insert("name", Value::String(name.to_string()));
+        self
+    }
+
+    /// Set the list of roles that are allowed to use the emoji. Pass an empty
+    /// list to make usage unrestricted.
+    pub fn roles<T: AsRef<RoleId>, It: IntoIterator<Item=T>>(&mut self, roles: It) -> &mut Self {
+        let role_ids = roles
+            .into_iter()
+            .map(|x| Value::Number(Number::from(x.as_ref().0)))
+            .collect();
+
+        self._roles(role_ids);
+        self
+    }
+
+    fn _roles(&mut self, roles: Vec<Value>) {
+        self.0.insert("roles", Value::Array(roles));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::model::id::RoleId;
+    use crate::utils;
+    use serde_json::{json, Value};
+    use super::EditEmoji;
+
+    #[test]
+    fn test_roles_only() {
+        let mut builder = EditEmoji::default();
+        builder.roles(&[RoleId(1), RoleId(2)]);
+
+        let built = Value::Object(utils::hashmap_to_json_map(builder.0));
+
+        assert_eq!(built, json!({ "roles": [1, 2] }));
+    }
+}
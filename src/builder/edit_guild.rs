@@ -30,13 +30,7 @@ impl EditGuild {
     }
 
     fn _afk_channel(&mut self, channel: Option<ChannelId>) {
-        self.0.insert(
-            "afk_channel_id",
-            match channel {
-                Some(channel) => Value::Number(Number::from(channel.0)),
-                None => Value::Null,
-            },
-        );
+        self.0.insert("afk_channel_id", channel_value(channel));
     }
 
     /// Set the amount of time a user is to be moved to the AFK channel -
@@ -51,6 +45,19 @@ impl EditGuild {
         self
     }
 
+    /// Set the default notification level of the members, whether they are
+    /// notified of all messages or only of their mentions.
+    pub fn default_message_notifications(&mut self, level: DefaultMessageNotificationLevel) -> &mut Self {
+        self.0.insert("default_message_notifications", Value::Number(Number::from(level.num())));
+        self
+    }
+
+    /// Set whose messages have their explicit media scanned and deleted.
+    pub fn explicit_content_filter(&mut self, filter: ExplicitContentFilter) -> &mut Self {
+        self.0.insert("explicit_content_filter", Value::Number(Number::from(filter.num())));
+        self
+    }
+
     /// Set the icon of the guild. Pass `None` to remove the icon.
     ///
     /// # Examples
@@ -109,6 +116,29 @@ impl EditGuild {
         self.0.insert("owner_id", id);
     }
 
+    /// Set the preferred locale of a guild with the `COMMUNITY` feature, used
+    /// in server discovery and in notices from Discord. Pass `None` to reset
+    /// it to `en-US`.
+    pub fn preferred_locale(&mut self, locale: Option<&str>) -> &mut Self {
+        let locale = locale.map_or(Value::Null, |x| Value::String(x.to_string()));
+        self.0.insert("preferred_locale", locale);
+        self
+    }
+
+    /// Set whether the progress bar of the server boosts is shown.
+    pub fn premium_progress_bar_enabled(&mut self, enabled: bool) -> &mut Self {
+        self.0.insert("premium_progress_bar_enabled", Value::Bool(enabled));
+        self
+    }
+
+    /// Set the channel where a guild with the `COMMUNITY` feature receives
+    /// notices from Discord, or `None` to remove it.
+    #[inline]
+    pub fn public_updates_channel<C: Into<ChannelId>>(&mut self, channel: Option<C>) -> &mut Self {
+        self.0.insert("public_updates_channel_id", channel_value(channel.map(Into::into)));
+        self
+    }
+
     /// Set the voice region of the server.
     ///
     /// # Examples
@@ -137,6 +167,14 @@ impl EditGuild {
         self
     }
 
+    /// Set the channel where a guild with the `COMMUNITY` feature displays
+    /// its rules, or `None` to remove it.
+    #[inline]
+    pub fn rules_channel<C: Into<ChannelId>>(&mut self, channel: Option<C>) -> &mut Self {
+        self.0.insert("rules_channel_id", channel_value(channel.map(Into::into)));
+        self
+    }
+
     /// Set the splash image of the guild on the invitation page.
     ///
     /// Requires that the guild have the `INVITE_SPLASH` feature enabled.
@@ -149,6 +187,26 @@ impl EditGuild {
         self
     }
 
+    /// Set the channel where system messages are sent, such as the member
+    /// join notifications, or `None` to disable them.
+    ///
+    /// [`system_channel_flags`] can instead disable only some of them.
+    ///
+    /// [`system_channel_flags`]: Self::system_channel_flags
+    #[inline]
+    pub fn system_channel<C: Into<ChannelId>>(&mut self, channel: Option<C>) -> &mut Self {
+        self.0.insert("system_channel_id", channel_value(channel.map(Into::into)));
+        self
+    }
+
+    /// Set which system messages are not sent in the [`system_channel`].
+    ///
+    /// [`system_channel`]: Self::system_channel
+    pub fn system_channel_flags(&mut self, flags: SystemChannelFlags) -> &mut Self {
+        self.0.insert("system_channel_flags", Value::Number(Number::from(flags.bits())));
+        self
+    }
+
     /// Set the verification level of the guild. This can restrict what a
     /// user must have prior to being able to send messages in a guild.
     ///
@@ -193,3 +251,61 @@ impl EditGuild {
         self.0.insert("verification_level", num);
     }
 }
+
+/// Encodes a channel which can be cleared by sending `null`.
+fn channel_value(channel: Option<ChannelId>) -> Value {
+    match channel {
+        Some(channel) => Value::Number(Number::from(channel.0)),
+        None => Value::Null,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::EditGuild;
+    use crate::model::prelude::*;
+    use crate::utils;
+    use serde_json::{json, Value};
+
+    #[test]
+    fn clear_afk_channel() {
+        let mut builder = EditGuild::default();
+        builder
+            .afk_channel(None::<ChannelId>)
+            .afk_timeout(300)
+            .system_channel(Some(ChannelId(2)));
+
+        let map = utils::hashmap_to_json_map(builder.0);
+        assert_eq!(Value::Object(map), json!({
+            "afk_channel_id": null,
+            "afk_timeout": 300,
+            "system_channel_id": 2,
+        }));
+    }
+
+    #[test]
+    fn guild_settings() {
+        let mut builder = EditGuild::default();
+        builder
+            .verification_level(VerificationLevel::High)
+            .default_message_notifications(DefaultMessageNotificationLevel::Mentions)
+            .explicit_content_filter(ExplicitContentFilter::All)
+            .system_channel_flags(SystemChannelFlags::SUPPRESS_JOIN_NOTIFICATIONS | SystemChannelFlags::SUPPRESS_GUILD_REMINDER_NOTIFICATIONS)
+            .rules_channel(Some(ChannelId(3)))
+            .public_updates_channel(None::<ChannelId>)
+            .preferred_locale(Some("fr"))
+            .premium_progress_bar_enabled(true);
+
+        let map = utils::hashmap_to_json_map(builder.0);
+        assert_eq!(Value::Object(map), json!({
+            "verification_level": 3,
+            "default_message_notifications": 1,
+            "explicit_content_filter": 2,
+            "system_channel_flags": 5,
+            "rules_channel_id": 3,
+            "public_updates_channel_id": null,
+            "preferred_locale": "fr",
+            "premium_progress_bar_enabled": true,
+        }));
+    }
+}
@@ -0,0 +1,35 @@
+use crate::internal::prelude::*;
+
+use std::collections::HashMap;
+
+/// A builder to create or edit a [`GuildTemplate`], to be used in conjunction
+/// with [`GuildId::create_template`] and [`GuildId::edit_template`].
+///
+/// The [`name`] is required when creating a template. When editing one, only
+/// the fields which have been set are sent to Discord.
+///
+/// [`GuildTemplate`]: crate::model::guild::GuildTemplate
+/// [`GuildId::create_template`]: crate::model::id::GuildId::create_template
+/// [`GuildId::edit_template`]: crate::model::id::GuildId::edit_template
+/// [`name`]: Self::name
+#[derive(Clone, Debug, Default)]
+pub struct EditGuildTemplate(pub HashMap<&'static str, Value>);
+
+impl EditGuildTemplate {
+    /// The name of the template.
+    ///
+    /// **Note**: Must be between 1 and 100 characters long.
+    pub fn name<D: ToString>(&mut self, name: D) -> &mut Self {
+        self.0.insert("name", Value::String(name.to_string()));
+        self
+    }
+
+    /// Sets the description of the template, or removes it with `None`.
+    ///
+    /// **Note**: Must be at most 120 characters long.
+    pub fn description(&mut self, description: Option<&str>) -> &mut Self {
+        let description = description.map_or(Value::Null, |d| Value::String(d.to_string()));
+        self.0.insert("description", description);
+        self
+    }
+}
@@ -0,0 +1,167 @@
+use crate::internal::prelude::*;
+use crate::model::prelude::*;
+use crate::utils;
+
+use std::collections::HashMap;
+
+/// A builder to edit the [`GuildWelcomeScreen`] of a guild, to be used in
+/// conjunction with [`GuildId::edit_welcome_screen`].
+///
+/// Only the fields which have been set are sent to Discord.
+///
+/// # Examples
+///
+/// Enable the welcome screen and show a single channel in it:
+///
+/// ```rust,no_run
+/// # use serenity::http::Http;
+/// # use serenity::model::channel::ReactionType;
+/// # use serenity::model::id::GuildId;
+/// #
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// # let http = Http::default();
+/// GuildId(7).edit_welcome_screen(&http, |w| {
+///     w.enabled(true)
+///         .description(Some("A place to talk about crabs"))
+///         .create_welcome_channel(|c| {
+///             c.channel_id(8)
+///                 .description("Read the rules first")
+///                 .emoji(ReactionType::Unicode("\u{1f4dc}".to_string()))
+///         })
+/// }).await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`GuildWelcomeScreen`]: crate::model::guild::GuildWelcomeScreen
+/// [`GuildId::edit_welcome_screen`]: crate::model::id::GuildId::edit_welcome_screen
+#[derive(Clone, Debug, Default)]
+pub struct EditGuildWelcomeScreen(pub HashMap<&'static str, Value>);
+
+impl EditGuildWelcomeScreen {
+    /// Sets whether the welcome screen is shown to new members.
+    pub fn enabled(&mut self, enabled: bool) -> &mut Self {
+        self.0.insert("enabled", Value::Bool(enabled));
+        self
+    }
+
+    /// Sets the description of the guild shown in the welcome screen, or
+    /// removes it with `None`.
+    pub fn description(&mut self, description: Option<&str>) -> &mut Self {
+        let description = description.map_or(Value::Null, |d| Value::String(d.to_string()));
+        self.0.insert("description", description);
+        self
+    }
+
+    /// Creates a channel to show in the welcome screen.
+    ///
+    /// **Note**: The welcome screen can show up to 5 channels.
+    pub fn create_welcome_channel<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateGuildWelcomeChannel) -> &mut CreateGuildWelcomeChannel {
+        let mut channel = CreateGuildWelcomeChannel::default();
+        f(&mut channel);
+
+        self.add_welcome_channel(channel)
+    }
+
+    /// Adds a channel to show in the welcome screen.
+    pub fn add_welcome_channel(&mut self, channel: CreateGuildWelcomeChannel) -> &mut Self {
+        let channel = Value::Object(utils::hashmap_to_json_map(channel.0));
+        let entry = self.0.entry("welcome_channels").or_insert_with(|| Value::Array(vec![]));
+
+        if let Value::Array(ref mut channels) = *entry {
+            channels.push(channel);
+        }
+
+        self
+    }
+
+    /// Sets all the channels shown in the welcome screen, replacing the
+    /// existing ones.
+    pub fn set_welcome_channels(&mut self, channels: Vec<CreateGuildWelcomeChannel>) -> &mut Self {
+        let channels = channels
+            .into_iter()
+            .map(|channel| Value::Object(utils::hashmap_to_json_map(channel.0)))
+            .collect();
+
+        self.0.insert("welcome_channels", Value::Array(channels));
+        self
+    }
+}
+
+/// A builder for a channel shown in a welcome screen, to be used in
+/// conjunction with [`EditGuildWelcomeScreen`].
+///
+/// The [`channel_id`] and [`description`] are required.
+///
+/// [`channel_id`]: Self::channel_id
+/// [`description`]: Self::description
+#[derive(Clone, Debug, Default)]
+pub struct CreateGuildWelcomeChannel(pub HashMap<&'static str, Value>);
+
+impl CreateGuildWelcomeChannel {
+    /// The Id of the channel.
+    pub fn channel_id<C: Into<ChannelId>>(&mut self, channel_id: C) -> &mut Self {
+        self.0.insert("channel_id", Value::String(channel_id.into().0.to_string()));
+        self
+    }
+
+    /// The description shown for the channel.
+    pub fn description<D: ToString>(&mut self, description: D) -> &mut Self {
+        self.0.insert("description", Value::String(description.to_string()));
+        self
+    }
+
+    /// The emoji shown for the channel.
+    pub fn emoji(&mut self, emoji: ReactionType) -> &mut Self {
+        match emoji {
+            ReactionType::Custom { id, name, .. } => {
+                self.0.insert("emoji_id", Value::String(id.0.to_string()));
+                self.0.insert("emoji_name", name.map_or(Value::Null, Value::String));
+            },
+            ReactionType::Unicode(name) => {
+                self.0.insert("emoji_id", Value::Null);
+                self.0.insert("emoji_name", Value::String(name));
+            },
+        }
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::EditGuildWelcomeScreen;
+    use crate::model::channel::ReactionType;
+    use crate::model::id::EmojiId;
+    use crate::utils;
+    use serde_json::{json, Value};
+
+    #[test]
+    fn test_clear_description() {
+        let mut builder = EditGuildWelcomeScreen::default();
+        builder
+            .enabled(false)
+            .description(None)
+            .create_welcome_channel(|c| {
+                c.channel_id(5).description("Rules").emoji(ReactionType::Unicode("\u{1f4dc}".to_string()))
+            })
+            .create_welcome_channel(|c| {
+                c.channel_id(6).description("Memes").emoji(ReactionType::Custom {
+                    animated: false,
+                    id: EmojiId(7),
+                    name: Some("pepe".to_string()),
+                })
+            });
+
+        let map = utils::hashmap_to_json_map(builder.0);
+        assert_eq!(Value::Object(map), json!({
+            "enabled": false,
+            "description": null,
+            "welcome_channels": [
+                {"channel_id": "5", "description": "Rules", "emoji_id": null, "emoji_name": "\u{1f4dc}"},
+                {"channel_id": "6", "description": "Memes", "emoji_id": "7", "emoji_name": "pepe"},
+            ],
+        }));
+    }
+}
@@ -0,0 +1,77 @@
+use crate::internal::prelude::*;
+use crate::model::id::ChannelId;
+
+use std::collections::HashMap;
+
+/// A builder to edit the settings of a guild's widget, to be used in
+/// conjunction with [`GuildId::edit_widget`].
+///
+/// Only the fields which have been set are sent to Discord.
+///
+/// # Examples
+///
+/// Enable the widget, inviting members to a channel:
+///
+/// ```rust,no_run
+/// # use serenity::http::Http;
+/// # use serenity::model::id::{ChannelId, GuildId};
+/// #
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// # let http = Http::default();
+/// let widget = GuildId(7).edit_widget(&http, |w| {
+///     w.enabled(true).channel_id(Some(ChannelId(8)))
+/// }).await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`GuildId::edit_widget`]: crate::model::id::GuildId::edit_widget
+#[derive(Clone, Debug, Default)]
+pub struct EditGuildWidget(pub HashMap<&'static str, Value>);
+
+impl EditGuildWidget {
+    /// Sets whether the widget is enabled.
+    pub fn enabled(&mut self, enabled: bool) -> &mut Self {
+        self.0.insert("enabled", Value::Bool(enabled));
+        self
+    }
+
+    /// Sets the channel the widget invites members to, or disables the invite
+    /// with `None`.
+    pub fn channel_id(&mut self, channel_id: Option<ChannelId>) -> &mut Self {
+        let channel_id = channel_id.map_or(Value::Null, |id| Value::String(id.0.to_string()));
+        self.0.insert("channel_id", channel_id);
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::EditGuildWidget;
+    use crate::model::id::ChannelId;
+    use crate::utils;
+    use serde_json::{json, Value};
+
+    #[test]
+    fn test_widget() {
+        let mut builder = EditGuildWidget::default();
+        builder.enabled(true).channel_id(Some(ChannelId(8)));
+
+        let map = utils::hashmap_to_json_map(builder.0);
+        assert_eq!(Value::Object(map), json!({
+            "enabled": true,
+            "channel_id": "8",
+        }));
+    }
+
+    #[test]
+    fn test_widget_without_invite() {
+        let mut builder = EditGuildWidget::default();
+        builder.channel_id(None);
+
+        let map = utils::hashmap_to_json_map(builder.0);
+        assert_eq!(Value::Object(map), json!({
+            "channel_id": null,
+        }));
+    }
+}
@@ -0,0 +1,60 @@
+use crate::internal::prelude::*;
+use super::embeds::{push_embed, set_embeds};
+use super::{CreateComponents, CreateEmbed};
+
+use std::collections::HashMap;
+
+/// A builder to edit the initial response to an interaction, to be used in
+/// conjunction with [`Interaction::edit_response`].
+///
+/// [`Interaction::edit_response`]: crate::model::interactions::Interaction::edit_response
+#[derive(Clone, Debug, Default)]
+pub struct EditInteractionResponse(pub HashMap<&'static str, Value>);
+
+impl EditInteractionResponse {
+    /// Sets the content of the message.
+    ///
+    /// **Note**: Message contents must be under 2000 unicode code points.
+    pub fn content<D: ToString>(&mut self, content: D) -> &mut Self {
+        self.0.insert("content", Value::String(content.to_string()));
+        self
+    }
+
+    /// Creates an embed for the message.
+    ///
+    /// **Note**: A message can have up to 10 embeds.
+    pub fn embed<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateEmbed) -> &mut CreateEmbed {
+        let mut embed = CreateEmbed::default();
+        f(&mut embed);
+
+        self.add_embed(embed)
+    }
+
+    /// Adds an embed to the message.
+    pub fn add_embed(&mut self, embed: CreateEmbed) -> &mut Self {
+        push_embed(&mut self.0, embed);
+        self
+    }
+
+    /// Sets all the embeds of the message, replacing the existing ones.
+    ///
+    /// Passing an empty list removes all the embeds of the message.
+    pub fn set_embeds(&mut self, embeds: Vec<CreateEmbed>) -> &mut Self {
+        set_embeds(&mut self.0, embeds);
+        self
+    }
+
+    /// Sets the components of the message, such as buttons and select menus.
+    ///
+    /// Refer to the documentation of [`CreateComponents`] for more
+    /// information.
+    pub fn components<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateComponents) -> &mut CreateComponents {
+        let mut components = CreateComponents::default();
+        f(&mut components);
+
+        self.0.insert("components", Value::Array(components.0));
+        self
+    }
+}
@@ -1,5 +1,6 @@
 use crate::internal::prelude::*;
 use crate::model::id::{ChannelId, RoleId};
+use super::Timestamp;
 use std::collections::HashMap;
 
 /// A builder which edits the properties of a [`Member`], to be used in
@@ -31,6 +32,25 @@ impl EditMember {
         self
     }
 
+    /// Times the member out until the given time, preventing them from sending
+    /// messages, reacting to messages, or speaking in voice channels.
+    ///
+    /// The time can be at most 28 days in the future.
+    ///
+    /// Requires the Moderate Members permission.
+    pub fn disable_communication_until<T: Into<Timestamp>>(&mut self, time: T) -> &mut Self {
+        self.0.insert("communication_disabled_until", Value::String(time.into().ts));
+        self
+    }
+
+    /// Removes the timeout of the member.
+    ///
+    /// Requires the Moderate Members permission.
+    pub fn enable_communication(&mut self) -> &mut Self {
+        self.0.insert("communication_disabled_until", Value::Null);
+        self
+    }
+
     /// Changes the member's nickname. Pass an empty string to reset the
     /// nickname.
     ///
@@ -78,14 +98,70 @@ impl EditMember {
         self.0.insert("channel_id", num);
     }
 
-    /// Disconnects the user from their voice channel if any
+    /// Disconnects the user from their voice channel if any.
+    ///
+    /// This sends an explicit `null` channel, which is different from not
+    /// setting a [`voice_channel`] at all.
     ///
     /// Requires the [Move Members] permission.
     ///
     /// [Move Members]: crate::model::permissions::Permissions::MOVE_MEMBERS
+    /// [`voice_channel`]: Self::voice_channel
     pub fn disconnect_member(&mut self) -> &mut Self {
         self.0.insert("channel_id", Value::Null);
 
         self
     }
 }
+
+#[cfg(test)]
+mod test {
+    use chrono::prelude::*;
+    use crate::model::id::ChannelId;
+    use crate::utils;
+    use serde_json::{json, Value};
+    use super::EditMember;
+
+    #[test]
+    fn disable_communication_until() {
+        let past = Utc.ymd(2016, 11, 8).and_hms(0, 0, 0);
+
+        let mut builder = EditMember::default();
+        builder.disable_communication_until(&past);
+
+        let built = Value::Object(utils::hashmap_to_json_map(builder.0));
+
+        assert_eq!(built, json!({ "communication_disabled_until": "2016-11-08T00:00:00+00:00" }));
+    }
+
+    #[test]
+    fn enable_communication() {
+        let mut builder = EditMember::default();
+        builder.enable_communication();
+
+        let built = Value::Object(utils::hashmap_to_json_map(builder.0));
+
+        assert_eq!(built, json!({ "communication_disabled_until": null }));
+    }
+
+    #[test]
+    fn disconnect_member() {
+        let mut builder = EditMember::default();
+        builder.voice_channel(ChannelId(7)).disconnect_member();
+
+        let built = utils::hashmap_to_json_map(builder.0);
+
+        assert_eq!(built.get("channel_id"), Some(&Value::Null));
+        assert_eq!(Value::Object(built), json!({ "channel_id": null }));
+    }
+
+    #[test]
+    fn move_muted_and_deafened() {
+        let mut builder = EditMember::default();
+        builder.voice_channel(ChannelId(7)).mute(true).deafen(true);
+
+        let built = Value::Object(utils::hashmap_to_json_map(builder.0));
+
+        assert_eq!(built, json!({ "channel_id": 7, "mute": true, "deaf": true }));
+    }
+}
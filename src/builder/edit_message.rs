@@ -1,6 +1,11 @@
 use crate::internal::prelude::*;
-use super::CreateEmbed;
+use crate::http::AttachmentType;
+use crate::model::id::AttachmentId;
+use super::embeds::{push_embed, set_embeds};
+use super::{CreateAllowedMentions, CreateComponents, CreateEmbed};
 use crate::utils;
+#[cfg(feature = "model")]
+use crate::model::ModelError;
 
 use std::collections::HashMap;
 
@@ -29,11 +34,39 @@ use std::collections::HashMap;
 /// # }
 /// ```
 ///
+/// Replacing the attachments of a message, keeping a single one of the
+/// existing attachments:
+///
+/// ```rust,no_run
+/// # use serenity::model::id::{ChannelId, MessageId};
+/// # #[cfg(feature = "client")]
+/// # use serenity::client::Context;
+/// # #[cfg(feature = "framework")]
+/// # use serenity::framework::standard::{CommandResult, macros::command};
+/// #
+/// # #[cfg(all(feature = "model", feature = "utils", feature = "framework"))]
+/// # #[command]
+/// # async fn example(ctx: &Context) -> CommandResult {
+/// # let mut message = ChannelId(7).message(&ctx, MessageId(8)).await?;
+/// let kept = message.attachments[0].id;
+///
+/// message.edit(ctx, |m| {
+///     m.keep_attachment(kept).add_file("./new_image.png")
+/// })
+/// .await?;
+/// # Ok(())
+/// # }
+/// ```
+///
 /// [`Message`]: crate::model::channel::Message
 #[derive(Clone, Debug, Default)]
-pub struct EditMessage(pub HashMap<&'static str, Value>);
+pub struct EditMessage<'a>(pub HashMap<&'static str, Value>, pub Vec<AttachmentType<'a>>);
+
+/// The key under which the descriptions of the new files are stored until they
+/// are listed along with the attachments to keep.
+const DESCRIPTIONS_KEY: &str = "_descriptions";
 
-impl EditMessage {
+impl<'a> EditMessage<'a> {
     /// Set the content of the message.
     ///
     /// **Note**: Message contents must be under 2000 unicode code points.
@@ -43,15 +76,57 @@ impl EditMessage {
         self
     }
 
-    /// Set an embed for the message.
+    /// Create an embed for the message, adding it after the existing ones.
+    ///
+    /// **Note**: A message can have up to 10 embeds.
     pub fn embed<F>(&mut self, f: F) -> &mut Self
     where F: FnOnce(&mut CreateEmbed) -> &mut CreateEmbed {
         let mut create_embed = CreateEmbed::default();
         f(&mut create_embed);
-        let map = utils::hashmap_to_json_map(create_embed.0);
-        let embed = Value::Object(map);
+        self.add_embed(create_embed)
+    }
+
+    /// Add an embed to the message.
+    ///
+    /// **Note**: A message can have up to 10 embeds.
+    pub fn add_embed(&mut self, embed: CreateEmbed) -> &mut Self {
+        push_embed(&mut self.0, embed);
+        self
+    }
+
+    /// Set all the embeds of the message, replacing the existing ones.
+    ///
+    /// **Note**: A message can have up to 10 embeds.
+    pub fn set_embeds(&mut self, embeds: Vec<CreateEmbed>) -> &mut Self {
+        set_embeds(&mut self.0, embeds);
+        self
+    }
+
+    /// Set the allowed mentions for the message.
+    ///
+    /// Refer to the documentation of [`CreateAllowedMentions`] for more
+    /// information.
+    pub fn allowed_mentions<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateAllowedMentions) -> &mut CreateAllowedMentions {
+        let mut allowed_mentions = CreateAllowedMentions::default();
+        f(&mut allowed_mentions);
+        let map = utils::hashmap_to_json_map(allowed_mentions.0);
+
+        self.0.insert("allowed_mentions", Value::Object(map));
+        self
+    }
+
+    /// Set the components of the message, such as buttons and select menus,
+    /// replacing the existing ones.
+    ///
+    /// Refer to the documentation for [`CreateComponents`] for more
+    /// information.
+    pub fn components<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateComponents) -> &mut CreateComponents {
+        let mut components = CreateComponents::default();
+        f(&mut components);
 
-        self.0.insert("embed", embed);
+        self.0.insert("components", Value::Array(components.0));
         self
     }
 
@@ -69,4 +144,229 @@ impl EditMessage {
         self
     }
 
+    /// Keep an existing attachment of the message.
+    ///
+    /// Once this is called, only the attachments passed to this method are
+    /// kept, and all the other existing attachments are removed from the
+    /// message.
+    pub fn keep_attachment<A: Into<AttachmentId>>(&mut self, attachment_id: A) -> &mut Self {
+        let id = Value::Number(Number::from(*attachment_id.into().as_u64()));
+        let attachments = self.0
+            .entry("attachments")
+            .or_insert_with(|| Value::Array(Vec::new()));
+
+        if let Value::Array(attachments) = attachments {
+            if !attachments.iter().any(|a| a.get("id") == Some(&id)) {
+                let mut map = JsonMap::new();
+                map.insert("id".to_string(), id);
+
+                attachments.push(Value::Object(map));
+            }
+        }
+
+        self
+    }
+
+    /// Remove all of the existing attachments of the message.
+    ///
+    /// This does not affect the files added with [`add_file`], [`add_files`]
+    /// or [`add_file_with_description`], nor the attachments kept with
+    /// [`keep_attachment`] afterwards.
+    ///
+    /// [`add_file`]: Self::add_file
+    /// [`add_files`]: Self::add_files
+    /// [`add_file_with_description`]: Self::add_file_with_description
+    /// [`keep_attachment`]: Self::keep_attachment
+    pub fn remove_all_attachments(&mut self) -> &mut Self {
+        self.0.insert("attachments", Value::Array(Vec::new()));
+        self
+    }
+
+    /// Appends a file to upload along with the edit.
+    ///
+    /// **Note**: The existing attachments of the message are kept, unless
+    /// [`keep_attachment`] or [`remove_all_attachments`] is used.
+    ///
+    /// [`keep_attachment`]: Self::keep_attachment
+    /// [`remove_all_attachments`]: Self::remove_all_attachments
+    pub fn add_file<T: Into<AttachmentType<'a>>>(&mut self, file: T) -> &mut Self {
+        self.1.push(file.into());
+        self
+    }
+
+    /// Appends a file to upload along with the edit, with a description used
+    /// as its alternative text.
+    ///
+    /// **Note**: The description must be at most 1024 unicode code points.
+    /// As the description is sent along with the attachments to keep, either
+    /// [`keep_attachment`] or [`remove_all_attachments`] must be used too.
+    ///
+    /// [`keep_attachment`]: Self::keep_attachment
+    /// [`remove_all_attachments`]: Self::remove_all_attachments
+    pub fn add_file_with_description<T, D>(&mut self, file: T, description: D) -> &mut Self
+        where T: Into<AttachmentType<'a>>, D: ToString {
+        let descriptions = self.0
+            .entry(DESCRIPTIONS_KEY)
+            .or_insert_with(|| Value::Array(Vec::new()));
+
+        if let Value::Array(descriptions) = descriptions {
+            let mut attachment = JsonMap::new();
+            attachment.insert("id".to_string(), Value::Number(Number::from(self.1.len())));
+            attachment.insert("description".to_string(), Value::String(description.to_string()));
+
+            descriptions.push(Value::Object(attachment));
+        }
+
+        self.1.push(file.into());
+        self
+    }
+
+    /// Appends a list of files to upload along with the edit.
+    ///
+    /// Refer to [`add_file`] for more information.
+    ///
+    /// [`add_file`]: Self::add_file
+    pub fn add_files<T: Into<AttachmentType<'a>>, It: IntoIterator<Item=T>>(&mut self, files: It) -> &mut Self {
+        self.1.extend(files.into_iter().map(|f| f.into()));
+        self
+    }
+
+    /// Splits the builder into the body of the edit and the files to upload,
+    /// listing the descriptions of the new files after the attachments to
+    /// keep.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::NoAttachmentsKept`] if new files are described
+    /// but the attachments to keep were not set, as sending the descriptions
+    /// alone would remove the existing attachments.
+    ///
+    /// [`ModelError::NoAttachmentsKept`]: crate::model::ModelError::NoAttachmentsKept
+    #[cfg(feature = "model")]
+    pub(crate) fn into_parts(mut self) -> Result<(JsonMap, Vec<AttachmentType<'a>>)> {
+        if let Some(Value::Array(descriptions)) = self.0.remove(DESCRIPTIONS_KEY) {
+            match self.0.get_mut("attachments") {
+                Some(Value::Array(attachments)) => attachments.extend(descriptions),
+                _ => return Err(Error::Model(ModelError::NoAttachmentsKept)),
+            }
+        }
+
+        Ok((utils::hashmap_to_json_map(self.0), self.1))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::http::AttachmentType;
+    #[cfg(feature = "model")]
+    use crate::internal::prelude::*;
+    use crate::model::id::AttachmentId;
+    #[cfg(feature = "model")]
+    use crate::model::ModelError;
+    use crate::utils;
+    use serde_json::{json, Value};
+    use super::EditMessage;
+
+    #[test]
+    fn test_no_attachment_mutation() {
+        let mut builder = EditMessage::default();
+        builder.content("hello");
+
+        let built = Value::Object(utils::hashmap_to_json_map(builder.0));
+
+        assert_eq!(built, json!({ "content": "hello" }));
+    }
+
+    #[test]
+    fn test_keep_some_attachments() {
+        let mut builder = EditMessage::default();
+        builder
+            .keep_attachment(AttachmentId::from(1))
+            .keep_attachment(AttachmentId::from(3))
+            .keep_attachment(AttachmentId::from(1));
+
+        assert!(builder.1.is_empty());
+
+        let built = Value::Object(utils::hashmap_to_json_map(builder.0));
+
+        assert_eq!(built, json!({ "attachments": [{ "id": 1 }, { "id": 3 }] }));
+    }
+
+    #[test]
+    fn test_remove_all_attachments() {
+        let mut builder = EditMessage::default();
+        builder.keep_attachment(AttachmentId::from(1)).remove_all_attachments();
+
+        let built = Value::Object(utils::hashmap_to_json_map(builder.0));
+
+        assert_eq!(built, json!({ "attachments": [] }));
+    }
+
+    #[test]
+    fn test_add_new_attachments() {
+        let mut builder = EditMessage::default();
+        builder
+            .remove_all_attachments()
+            .keep_attachment(AttachmentId::from(2))
+            .add_file((&b"data"[..], "a.txt"))
+            .add_files(vec!["./b.png"]);
+
+        assert_eq!(builder.1.len(), 2);
+        assert!(matches!(&builder.1[0], AttachmentType::Bytes { filename, .. } if filename == "a.txt"));
+
+        let built = Value::Object(utils::hashmap_to_json_map(builder.0));
+
+        assert_eq!(built, json!({ "attachments": [{ "id": 2 }] }));
+    }
+
+    #[cfg(feature = "model")]
+    fn image(filename: &str) -> AttachmentType<'static> {
+        AttachmentType::Bytes { data: vec![].into(), filename: filename.to_string() }
+    }
+
+    #[test]
+    #[cfg(feature = "model")]
+    fn test_add_described_attachments() {
+        let mut builder = EditMessage::default();
+        builder
+            .add_file(image("a.png"))
+            .add_file_with_description(image("b.png"), "A dog")
+            .keep_attachment(AttachmentId::from(100));
+
+        let (map, files) = builder.into_parts().unwrap();
+        assert_eq!(files.len(), 2);
+        assert_eq!(Value::Object(map), json!({ "attachments": [{ "id": 100 }, { "id": 1, "description": "A dog" }] }));
+    }
+
+    #[test]
+    #[cfg(feature = "model")]
+    fn test_described_attachments_require_kept_attachments() {
+        let mut builder = EditMessage::default();
+        builder.add_file_with_description(image("a.png"), "A dog");
+
+        match builder.clone().into_parts() {
+            Err(Error::Model(ModelError::NoAttachmentsKept)) => {},
+            other => panic!("unexpected result: {:?}", other.map(|(map, _)| map)),
+        }
+
+        builder.keep_attachment(AttachmentId::from(100));
+
+        let (map, _) = builder.into_parts().unwrap();
+        assert_eq!(Value::Object(map), json!({ "attachments": [{ "id": 100 }, { "id": 0, "description": "A dog" }] }));
+    }
+
+    #[test]
+    #[cfg(feature = "model")]
+    fn test_remove_all_attachments_keeps_descriptions() {
+        let mut before = EditMessage::default();
+        before.add_file_with_description(image("a.png"), "A dog").remove_all_attachments();
+
+        let mut after = EditMessage::default();
+        after.remove_all_attachments().add_file_with_description(image("a.png"), "A dog");
+
+        for builder in vec![before, after] {
+            let (map, _) = builder.into_parts().unwrap();
+            assert_eq!(Value::Object(map), json!({ "attachments": [{ "id": 0, "description": "A dog" }] }));
+        }
+    }
 }
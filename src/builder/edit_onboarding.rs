@@ -0,0 +1,308 @@
+use crate::internal::prelude::*;
+use crate::model::prelude::*;
+use crate::utils;
+
+use std::collections::HashMap;
+
+/// A builder to edit the [`GuildOnboarding`] of a guild, to be used in
+/// conjunction with [`GuildId::edit_onboarding`].
+///
+/// The prompts which are sent replace all the existing ones.
+///
+/// # Examples
+///
+/// Enable the onboarding with a single multi-select prompt:
+///
+/// ```rust,no_run
+/// # use serenity::http::Http;
+/// # use serenity::model::channel::ReactionType;
+/// # use serenity::model::guild::OnboardingPromptType;
+/// # use serenity::model::id::GuildId;
+/// #
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// # let http = Http::default();
+/// GuildId(7).edit_onboarding(&http, |o| {
+///     o.enabled(true)
+///         .default_channel_ids(vec![8])
+///         .create_prompt(|p| {
+///             p.id(1)
+///                 .kind(OnboardingPromptType::MultipleChoice)
+///                 .title("What are you interested in?")
+///                 .in_onboarding(true)
+///                 .create_option(|o| {
+///                     o.id(2)
+///                         .title("News")
+///                         .emoji(ReactionType::Unicode("\u{1f4e1}".to_string()))
+///                         .channel_ids(vec![9])
+///                 })
+///         })
+/// }).await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`GuildOnboarding`]: crate::model::guild::GuildOnboarding
+/// [`GuildId::edit_onboarding`]: crate::model::id::GuildId::edit_onboarding
+#[derive(Clone, Debug, Default)]
+pub struct EditOnboarding(pub HashMap<&'static str, Value>);
+
+impl EditOnboarding {
+    /// Creates a prompt of the onboarding.
+    pub fn create_prompt<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateOnboardingPrompt) -> &mut CreateOnboardingPrompt {
+        let mut prompt = CreateOnboardingPrompt::default();
+        f(&mut prompt);
+
+        self.add_prompt(prompt)
+    }
+
+    /// Adds a prompt to the onboarding.
+    pub fn add_prompt(&mut self, prompt: CreateOnboardingPrompt) -> &mut Self {
+        let prompt = Value::Object(utils::hashmap_to_json_map(prompt.0));
+        let entry = self.0.entry("prompts").or_insert_with(|| Value::Array(vec![]));
+
+        if let Value::Array(ref mut prompts) = *entry {
+            prompts.push(prompt);
+        }
+
+        self
+    }
+
+    /// Sets all the prompts of the onboarding, replacing the existing ones.
+    pub fn set_prompts(&mut self, prompts: Vec<CreateOnboardingPrompt>) -> &mut Self {
+        let prompts = prompts
+            .into_iter()
+            .map(|prompt| Value::Object(utils::hashmap_to_json_map(prompt.0)))
+            .collect();
+
+        self.0.insert("prompts", Value::Array(prompts));
+        self
+    }
+
+    /// The channels new members are opted into by default.
+    pub fn default_channel_ids<I, C>(&mut self, channels: I) -> &mut Self
+    where
+        I: IntoIterator<Item = C>,
+        C: Into<ChannelId>,
+    {
+        let channels = channels.into_iter().map(|c| Value::String(c.into().0.to_string())).collect();
+        self.0.insert("default_channel_ids", Value::Array(channels));
+        self
+    }
+
+    /// Sets whether the onboarding is enabled.
+    pub fn enabled(&mut self, enabled: bool) -> &mut Self {
+        self.0.insert("enabled", Value::Bool(enabled));
+        self
+    }
+
+    /// The criteria used to check that the onboarding is properly set up.
+    pub fn mode(&mut self, mode: OnboardingMode) -> &mut Self {
+        self.0.insert("mode", Value::Number(Number::from(mode.num())));
+        self
+    }
+}
+
+/// A builder for a prompt of an onboarding, to be used in conjunction with
+/// [`EditOnboarding`].
+///
+/// The [`id`], [`title`] and at least one option are required. A prompt
+/// which is [`required`] must also be shown [`in_onboarding`], and needs at
+/// least two options if it is [`single_select`].
+///
+/// [`id`]: Self::id
+/// [`title`]: Self::title
+/// [`required`]: Self::required
+/// [`in_onboarding`]: Self::in_onboarding
+/// [`single_select`]: Self::single_select
+#[derive(Clone, Debug, Default)]
+pub struct CreateOnboardingPrompt(pub HashMap<&'static str, Value>);
+
+impl CreateOnboardingPrompt {
+    /// The Id of the prompt. Any unique Id can be used for a new prompt.
+    pub fn id<P: Into<OnboardingPromptId>>(&mut self, id: P) -> &mut Self {
+        self.0.insert("id", Value::String(id.into().0.to_string()));
+        self
+    }
+
+    /// The type of the prompt.
+    pub fn kind(&mut self, kind: OnboardingPromptType) -> &mut Self {
+        self.0.insert("type", Value::Number(Number::from(kind.num())));
+        self
+    }
+
+    /// The title of the prompt.
+    pub fn title<D: ToString>(&mut self, title: D) -> &mut Self {
+        self.0.insert("title", Value::String(title.to_string()));
+        self
+    }
+
+    /// Sets whether members are limited to selecting one option.
+    pub fn single_select(&mut self, single_select: bool) -> &mut Self {
+        self.0.insert("single_select", Value::Bool(single_select));
+        self
+    }
+
+    /// Sets whether the prompt must be answered to complete the onboarding.
+    pub fn required(&mut self, required: bool) -> &mut Self {
+        self.0.insert("required", Value::Bool(required));
+        self
+    }
+
+    /// Sets whether the prompt is shown during the onboarding, rather than
+    /// only in the "Channels & Roles" tab.
+    pub fn in_onboarding(&mut self, in_onboarding: bool) -> &mut Self {
+        self.0.insert("in_onboarding", Value::Bool(in_onboarding));
+        self
+    }
+
+    /// Creates an option of the prompt.
+    pub fn create_option<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateOnboardingPromptOption) -> &mut CreateOnboardingPromptOption {
+        let mut option = CreateOnboardingPromptOption::default();
+        f(&mut option);
+
+        self.add_option(option)
+    }
+
+    /// Adds an option to the prompt.
+    pub fn add_option(&mut self, option: CreateOnboardingPromptOption) -> &mut Self {
+        let option = Value::Object(utils::hashmap_to_json_map(option.0));
+        let entry = self.0.entry("options").or_insert_with(|| Value::Array(vec![]));
+
+        if let Value::Array(ref mut options) = *entry {
+            options.push(option);
+        }
+
+        self
+    }
+}
+
+/// A builder for an option of an onboarding prompt, to be used in
+/// conjunction with [`CreateOnboardingPrompt`].
+///
+/// The [`id`] and [`title`] are required.
+///
+/// [`id`]: Self::id
+/// [`title`]: Self::title
+#[derive(Clone, Debug, Default)]
+pub struct CreateOnboardingPromptOption(pub HashMap<&'static str, Value>);
+
+impl CreateOnboardingPromptOption {
+    /// The Id of the option. Any unique Id can be used for a new option.
+    pub fn id<O: Into<OnboardingPromptOptionId>>(&mut self, id: O) -> &mut Self {
+        self.0.insert("id", Value::String(id.into().0.to_string()));
+        self
+    }
+
+    /// The title of the option.
+    pub fn title<D: ToString>(&mut self, title: D) -> &mut Self {
+        self.0.insert("title", Value::String(title.to_string()));
+        self
+    }
+
+    /// Sets the description of the option, or removes it with `None`.
+    pub fn description(&mut self, description: Option<&str>) -> &mut Self {
+        let description = description.map_or(Value::Null, |d| Value::String(d.to_string()));
+        self.0.insert("description", description);
+        self
+    }
+
+    /// The emoji shown for the option.
+    pub fn emoji(&mut self, emoji: ReactionType) -> &mut Self {
+        match emoji {
+            ReactionType::Custom { animated, id, name } => {
+                self.0.insert("emoji_id", Value::String(id.0.to_string()));
+                self.0.insert("emoji_name", name.map_or(Value::Null, Value::String));
+                self.0.insert("emoji_animated", Value::Bool(animated));
+            },
+            ReactionType::Unicode(name) => {
+                self.0.insert("emoji_id", Value::Null);
+                self.0.insert("emoji_name", Value::String(name));
+                self.0.insert("emoji_animated", Value::Bool(false));
+            },
+        }
+
+        self
+    }
+
+    /// The channels members are opted into when selecting the option.
+    pub fn channel_ids<I, C>(&mut self, channels: I) -> &mut Self
+    where
+        I: IntoIterator<Item = C>,
+        C: Into<ChannelId>,
+    {
+        let channels = channels.into_iter().map(|c| Value::String(c.into().0.to_string())).collect();
+        self.0.insert("channel_ids", Value::Array(channels));
+        self
+    }
+
+    /// The roles members are given when selecting the option.
+    pub fn role_ids<I, R>(&mut self, roles: I) -> &mut Self
+    where
+        I: IntoIterator<Item = R>,
+        R: Into<RoleId>,
+    {
+        let roles = roles.into_iter().map(|r| Value::String(r.into().0.to_string())).collect();
+        self.0.insert("role_ids", Value::Array(roles));
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::EditOnboarding;
+    use crate::model::channel::ReactionType;
+    use crate::model::guild::{OnboardingMode, OnboardingPromptType};
+    use crate::model::id::{ChannelId, RoleId};
+    use crate::utils;
+    use serde_json::{json, Value};
+
+    #[test]
+    fn test_onboarding() {
+        let mut builder = EditOnboarding::default();
+        builder
+            .enabled(true)
+            .mode(OnboardingMode::Advanced)
+            .default_channel_ids(vec![ChannelId(3)])
+            .create_prompt(|p| {
+                p.id(1)
+                    .kind(OnboardingPromptType::Dropdown)
+                    .title("Pick a team")
+                    .single_select(true)
+                    .required(true)
+                    .in_onboarding(true)
+                    .create_option(|o| {
+                        o.id(2)
+                            .title("Crabs")
+                            .description(None)
+                            .emoji(ReactionType::Unicode("\u{1f980}".to_string()))
+                            .role_ids(vec![RoleId(4)])
+                    })
+            });
+
+        let map = utils::hashmap_to_json_map(builder.0);
+        assert_eq!(Value::Object(map), json!({
+            "enabled": true,
+            "mode": 1,
+            "default_channel_ids": ["3"],
+            "prompts": [{
+                "id": "1",
+                "type": 1,
+                "title": "Pick a team",
+                "single_select": true,
+                "required": true,
+                "in_onboarding": true,
+                "options": [{
+                    "id": "2",
+                    "title": "Crabs",
+                    "description": null,
+                    "emoji_id": null,
+                    "emoji_name": "\u{1f980}",
+                    "emoji_animated": false,
+                    "role_ids": ["4"],
+                }],
+            }],
+        }));
+    }
+}
@@ -0,0 +1,95 @@
+use crate::internal::prelude::*;
+use crate::model::prelude::*;
+use super::Timestamp;
+
+use serde_json::json;
+
+use std::collections::HashMap;
+
+/// A builder to edit the properties of a [`ScheduledEvent`], to be used in
+/// conjunction with [`GuildId::edit_scheduled_event`].
+///
+/// Only the fields which have been set are sent to Discord.
+///
+/// [`ScheduledEvent`]: crate::model::guild::ScheduledEvent
+/// [`GuildId::edit_scheduled_event`]: crate::model::id::GuildId::edit_scheduled_event
+#[derive(Clone, Debug, Default)]
+pub struct EditScheduledEvent(pub HashMap<&'static str, Value>);
+
+impl EditScheduledEvent {
+    /// The new name of the event.
+    ///
+    /// **Note**: Must be between 1 and 100 characters long.
+    pub fn name<D: ToString>(&mut self, name: D) -> &mut Self {
+        self.0.insert("name", Value::String(name.to_string()));
+        self
+    }
+
+    /// The new description of the event.
+    ///
+    /// **Note**: Must be between 1 and 1000 characters long.
+    pub fn description<D: ToString>(&mut self, description: D) -> &mut Self {
+        self.0.insert("description", Value::String(description.to_string()));
+        self
+    }
+
+    /// The new channel the event will be hosted in.
+    ///
+    /// **Note**: When changing the event to an [external] one, this is set to
+    /// `null` automatically.
+    ///
+    /// [external]: ScheduledEventType::External
+    pub fn channel_id<C: Into<ChannelId>>(&mut self, channel_id: C) -> &mut Self {
+        self.0.insert("channel_id", Value::Number(Number::from(channel_id.into().0)));
+        self
+    }
+
+    /// The new time at which the event will start.
+    pub fn start_time<T: Into<Timestamp>>(&mut self, timestamp: T) -> &mut Self {
+        self.0.insert("scheduled_start_time", Value::String(timestamp.into().ts));
+        self
+    }
+
+    /// The new time at which the event will end.
+    pub fn end_time<T: Into<Timestamp>>(&mut self, timestamp: T) -> &mut Self {
+        self.0.insert("scheduled_end_time", Value::String(timestamp.into().ts));
+        self
+    }
+
+    /// The new type of entity hosting the event.
+    ///
+    /// **Note**: When changing the event to an [external] one, the
+    /// [`end_time`] and [`location`] must be set as well.
+    ///
+    /// [external]: ScheduledEventType::External
+    /// [`end_time`]: Self::end_time
+    /// [`location`]: Self::location
+    pub fn kind(&mut self, kind: ScheduledEventType) -> &mut Self {
+        if kind == ScheduledEventType::External {
+            self.0.insert("channel_id", Value::Null);
+        }
+
+        self.0.insert("entity_type", Value::Number(Number::from(kind.num())));
+        self
+    }
+
+    /// The new location of an external event.
+    pub fn location<D: ToString>(&mut self, location: D) -> &mut Self {
+        self.0.insert("entity_metadata", json!({ "location": location.to_string() }));
+        self
+    }
+
+    /// The new status of the event.
+    ///
+    /// **Note**: A [scheduled] event can only be made [active] or [canceled],
+    /// and an [active] event can only be made [completed].
+    ///
+    /// [scheduled]: ScheduledEventStatus::Scheduled
+    /// [active]: ScheduledEventStatus::Active
+    /// [canceled]: ScheduledEventStatus::Canceled
+    /// [completed]: ScheduledEventStatus::Completed
+    pub fn status(&mut self, status: ScheduledEventStatus) -> &mut Self {
+        self.0.insert("status", Value::Number(Number::from(status.num())));
+        self
+    }
+}
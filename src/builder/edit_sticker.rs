@@ -0,0 +1,42 @@
+use crate::internal::prelude::*;
+
+use std::collections::HashMap;
+
+/// A builder to edit the properties of a guild [`Sticker`], to be used in
+/// conjunction with [`GuildId::edit_sticker`] or [`Sticker::edit`].
+///
+/// Only the fields which have been set are sent to Discord, so the unset ones
+/// are left untouched.
+///
+/// [`Sticker`]: crate::model::channel::Sticker
+/// [`Sticker::edit`]: crate::model::channel::Sticker::edit
+/// [`GuildId::edit_sticker`]: crate::model::id::GuildId::edit_sticker
+#[derive(Clone, Debug, Default)]
+pub struct EditSticker(pub HashMap<&'static str, Value>);
+
+impl EditSticker {
+    /// The new name of the sticker.
+    ///
+    /// **Note**: Must be between 2 and 30 characters long.
+    pub fn name<S: ToString>(&mut self, name: S) -> &mut Self {
+        self.0.insert("name", Value::String(name.to_string()));
+        self
+    }
+
+    /// The new description of the sticker.
+    ///
+    /// **Note**: If set, must be between 2 and 100 characters long.
+    pub fn description<S: ToString>(&mut self, description: S) -> &mut Self {
+        self.0.insert("description", Value::String(description.to_string()));
+        self
+    }
+
+    /// The new name of the emoji related to the sticker, used for
+    /// autocompletion.
+    ///
+    /// **Note**: Must be between 2 and 200 characters long.
+    pub fn tags<S: ToString>(&mut self, tags: S) -> &mut Self {
+        self.0.insert("tags", Value::String(tags.to_string()));
+        self
+    }
+}
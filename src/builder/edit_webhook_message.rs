@@ -0,0 +1,127 @@
+use crate::internal::prelude::*;
+use crate::model::id::AttachmentId;
+use super::embeds::{push_embed, set_embeds};
+use super::{CreateComponents, CreateEmbed};
+
+use serde_json::json;
+
+use std::collections::HashMap;
+
+/// A builder to edit a message sent by a [`Webhook`], to be used in
+/// conjunction with [`Webhook::edit_message`].
+///
+/// Only the fields which have been set are changed.
+///
+/// # Examples
+///
+/// Send a message, then edit its content:
+///
+/// ```rust,no_run
+/// # use serenity::http::Http;
+/// #
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// # let http = Http::default();
+/// # let webhook = http.get_webhook_with_token(1, "token").await?;
+/// // Waiting for the message makes Discord return it, along with its Id.
+/// let message = webhook.execute(&http, true, |w| w.content("Deploying...")).await?.unwrap();
+///
+/// webhook.edit_message(&http, message.id, |m| m.content("Deployed!")).await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Webhook`]: crate::model::webhook::Webhook
+/// [`Webhook::edit_message`]: crate::model::webhook::Webhook::edit_message
+#[derive(Clone, Debug, Default)]
+pub struct EditWebhookMessage(pub HashMap<&'static str, Value>);
+
+impl EditWebhookMessage {
+    /// Sets the content of the message.
+    ///
+    /// **Note**: Message contents must be under 2000 unicode code points.
+    pub fn content<D: ToString>(&mut self, content: D) -> &mut Self {
+        self.0.insert("content", Value::String(content.to_string()));
+        self
+    }
+
+    /// Creates an embed for the message.
+    ///
+    /// **Note**: A message can have up to 10 embeds.
+    pub fn embed<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateEmbed) -> &mut CreateEmbed {
+        let mut embed = CreateEmbed::default();
+        f(&mut embed);
+
+        self.add_embed(embed)
+    }
+
+    /// Adds an embed to the message.
+    pub fn add_embed(&mut self, embed: CreateEmbed) -> &mut Self {
+        push_embed(&mut self.0, embed);
+        self
+    }
+
+    /// Sets all the embeds of the message, replacing the existing ones.
+    ///
+    /// Passing an empty list removes all the embeds of the message.
+    pub fn set_embeds(&mut self, embeds: Vec<CreateEmbed>) -> &mut Self {
+        set_embeds(&mut self.0, embeds);
+        self
+    }
+
+    /// Sets the components of the message, such as buttons and select menus.
+    ///
+    /// Refer to the documentation of [`CreateComponents`] for more
+    /// information.
+    ///
+    /// **Note**: Only webhooks owned by an application can send components.
+    pub fn components<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateComponents) -> &mut CreateComponents {
+        let mut components = CreateComponents::default();
+        f(&mut components);
+
+        self.0.insert("components", Value::Array(components.0));
+        self
+    }
+
+    /// Sets the attachments of the message to keep, removing the others.
+    ///
+    /// Passing an empty list removes all the attachments of the message.
+    pub fn keep_attachments<I, A>(&mut self, attachment_ids: I) -> &mut Self
+    where
+        I: IntoIterator<Item = A>,
+        A: Into<AttachmentId>,
+    {
+        let attachments = attachment_ids
+            .into_iter()
+            .map(|id| json!({ "id": id.into().as_u64().to_string() }))
+            .collect();
+
+        self.0.insert("attachments", Value::Array(attachments));
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::EditWebhookMessage;
+    use crate::model::id::AttachmentId;
+    use crate::utils;
+    use serde_json::{json, Value};
+
+    #[test]
+    fn test_edit_webhook_message() {
+        let mut builder = EditWebhookMessage::default();
+        builder
+            .content("Deployed!")
+            .embed(|e| e.title("Changelog"))
+            .keep_attachments(vec![AttachmentId::from(5)]);
+
+        let map = utils::hashmap_to_json_map(builder.0);
+        assert_eq!(Value::Object(map), json!({
+            "content": "Deployed!",
+            "embeds": [{"type": "rich", "title": "Changelog"}],
+            "attachments": [{"id": "5"}],
+        }));
+    }
+}
@@ -0,0 +1,29 @@
+//! Helpers for the builders of messages which contain embeds.
+
+use crate::internal::prelude::*;
+use crate::utils;
+use super::CreateEmbed;
+
+use std::collections::HashMap;
+
+/// Appends an embed to the `embeds` of a message.
+pub(super) fn push_embed(map: &mut HashMap<&'static str, Value>, embed: CreateEmbed) {
+    let embed = Value::Object(utils::hashmap_to_json_map(embed.0));
+    let entry = map
+        .entry("embeds")
+        .or_insert_with(|| Value::Array(vec![]));
+
+    if let Value::Array(ref mut inner) = *entry {
+        inner.push(embed);
+    }
+}
+
+/// Replaces the `embeds` of a message.
+pub(super) fn set_embeds(map: &mut HashMap<&'static str, Value>, embeds: Vec<CreateEmbed>) {
+    let embeds = embeds
+        .into_iter()
+        .map(|embed| Value::Object(utils::hashmap_to_json_map(embed.0)))
+        .collect();
+
+    map.insert("embeds", Value::Array(embeds));
+}
@@ -1,6 +1,11 @@
 use serde_json::Value;
 use std::collections::HashMap;
 use crate::http::AttachmentType;
+use super::embeds::{push_embed, set_embeds};
+use super::create_message::add_attachment_description;
+use super::{CreateAllowedMentions, CreateEmbed};
+use crate::model::id::ChannelId;
+use crate::utils;
 
 /// A builder to create the inner content of a [`Webhook`]'s execution.
 ///
@@ -54,6 +59,20 @@ use crate::http::AttachmentType;
 pub struct ExecuteWebhook<'a>(pub HashMap<&'static str, Value>, pub Vec<AttachmentType<'a>>);
 
 impl<'a> ExecuteWebhook<'a> {
+    /// Set the allowed mentions for the message.
+    ///
+    /// Refer to the documentation of [`CreateAllowedMentions`] for more
+    /// information.
+    pub fn allowed_mentions<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateAllowedMentions) -> &mut CreateAllowedMentions {
+        let mut allowed_mentions = CreateAllowedMentions::default();
+        f(&mut allowed_mentions);
+        let map = utils::hashmap_to_json_map(allowed_mentions.0);
+
+        self.0.insert("allowed_mentions", Value::Object(map));
+        self
+    }
+
     /// Override the default avatar of the webhook with an image URL.
     ///
     /// # Examples
@@ -121,6 +140,17 @@ impl<'a> ExecuteWebhook<'a> {
         self
     }
 
+    /// Appends a file to the webhook message, with a description used as its
+    /// alternative text.
+    ///
+    /// **Note**: The description must be at most 1024 unicode code points.
+    pub fn add_file_with_description<T, D>(&mut self, file: T, description: D) -> &mut Self
+        where T: Into<AttachmentType<'a>>, D: ToString {
+        add_attachment_description(&mut self.0, self.1.len(), description.to_string());
+        self.1.push(file.into());
+        self
+    }
+
     /// Appends a list of files to the webhook message.
     pub fn add_files<T: Into<AttachmentType<'a>>, It: IntoIterator<Item=T>>(&mut self, files: It) -> &mut Self {
         self.1.extend(files.into_iter().map(|f| f.into()));
@@ -154,6 +184,61 @@ impl<'a> ExecuteWebhook<'a> {
         self
     }
 
+    /// Create an embed for the message, adding it after the existing ones.
+    ///
+    /// **Note**: A message can have up to 10 embeds.
+    pub fn embed<F>(&mut self, f: F) -> &mut Self
+    where F: FnOnce(&mut CreateEmbed) -> &mut CreateEmbed {
+        let mut embed = CreateEmbed::default();
+        f(&mut embed);
+        self.add_embed(embed)
+    }
+
+    /// Add an embed to the message.
+    ///
+    /// **Note**: A message can have up to 10 embeds.
+    pub fn add_embed(&mut self, embed: CreateEmbed) -> &mut Self {
+        push_embed(&mut self.0, embed);
+        self
+    }
+
+    /// Set all the embeds of the message, replacing the existing ones.
+    ///
+    /// Unlike [`embeds`], this takes embed builders rather than fake embeds.
+    ///
+    /// **Note**: A message can have up to 10 embeds.
+    ///
+    /// [`embeds`]: Self::embeds
+    pub fn set_embeds(&mut self, embeds: Vec<CreateEmbed>) -> &mut Self {
+        set_embeds(&mut self.0, embeds);
+        self
+    }
+
+    /// Post the message in a thread of the webhook's channel, instead of in
+    /// the channel itself.
+    ///
+    /// The thread is passed as the `thread_id` query parameter rather than in
+    /// the body. To create a thread in a forum channel instead, refer to
+    /// [`thread_name`].
+    ///
+    /// [`thread_name`]: Self::thread_name
+    pub fn in_thread<C: Into<ChannelId>>(&mut self, thread_id: C) -> &mut Self {
+        self.0.insert("thread_id", Value::from(thread_id.into().0));
+        self
+    }
+
+    /// Set the name of the thread to create with the message as its first
+    /// post.
+    ///
+    /// **Note**: This is only valid for webhooks of forum channels, and
+    /// can't be combined with [`in_thread`].
+    ///
+    /// [`in_thread`]: Self::in_thread
+    pub fn thread_name<S: ToString>(&mut self, thread_name: S) -> &mut Self {
+        self.0.insert("thread_name", Value::String(thread_name.to_string()));
+        self
+    }
+
     /// Whether the message is a text-to-speech message.
     ///
     /// # Examples
@@ -237,3 +322,25 @@ impl<'a> Default for ExecuteWebhook<'a> {
         ExecuteWebhook(map, vec![])
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::ExecuteWebhook;
+    use crate::model::id::ChannelId;
+    use serde_json::json;
+
+    #[test]
+    fn test_thread_fields() {
+        let mut builder = ExecuteWebhook::default();
+        builder.content("hello").in_thread(ChannelId(3));
+
+        assert_eq!(builder.0["thread_id"], json!(3));
+        assert!(!builder.0.contains_key("thread_name"));
+
+        let mut builder = ExecuteWebhook::default();
+        builder.content("hello").thread_name("News");
+
+        assert_eq!(builder.0["thread_name"], json!("News"));
+        assert!(!builder.0.contains_key("thread_id"));
+    }
+}
@@ -40,10 +40,15 @@ use std::collections::{
     VecDeque,
 };
 use std::default::Default;
+use std::hash::Hash;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use tracing::instrument;
 
 mod cache_update;
+mod persistence;
 mod settings;
 
 pub use self::cache_update::CacheUpdate;
@@ -51,6 +56,25 @@ pub use self::settings::Settings;
 
 type MessageCache = HashMap<ChannelId, HashMap<MessageId, Message>>;
 
+/// The source of time used to track when cached entries were last accessed.
+#[derive(Debug, Default)]
+struct Clock {
+    /// An offset in milliseconds added to the current time, which is only
+    /// advanced by tests.
+    offset: AtomicU64,
+}
+
+impl Clock {
+    fn now(&self) -> Instant {
+        Instant::now() + Duration::from_millis(self.offset.load(Ordering::Relaxed))
+    }
+
+    #[cfg(test)]
+    fn advance(&self, duration: Duration) {
+        self.offset.fetch_add(duration.as_millis() as u64, Ordering::Relaxed);
+    }
+}
+
 #[async_trait]
 pub trait FromStrAndCache: Sized {
     type Err;
@@ -156,7 +180,14 @@ pub struct Cache {
     /// channel's cache, we can pop the front and remove that ID from the cache.
     pub(crate) message_queue: RwLock<HashMap<ChannelId, VecDeque<MessageId>>>,
     /// The settings for the cache.
-    settings: RwLock<Settings>,
+    pub(crate) settings: RwLock<Settings>,
+    /// The last time each cached user was accessed, if a
+    /// [`Settings::time_to_live`] is set.
+    pub(crate) user_access: RwLock<HashMap<UserId, Instant>>,
+    /// The last time each cached message was accessed, if a
+    /// [`Settings::time_to_live`] is set.
+    pub(crate) message_access: RwLock<HashMap<(ChannelId, MessageId), Instant>>,
+    clock: Clock,
 }
 
 impl Cache {
@@ -334,7 +365,7 @@ impl Cache {
     async fn _channel(&self, id: ChannelId) -> Option<Channel> {
         if let Some(channel) = self.channels.read().await.get(&id) {
             let channel = channel.clone();
-            return Some(Channel::Guild(channel));
+            return Some(Channel::Guild(Box::new(channel)));
         }
 
         if let Some(private_channel) = self.private_channels.read().await.get(&id).cloned() {
@@ -684,9 +715,91 @@ impl Cache {
     }
 
     async fn _message(&self, channel_id: ChannelId, message_id: MessageId) -> Option<Message> {
-        self.messages.read().await.get(&channel_id).and_then(|messages| {
+        let message = self.messages.read().await.get(&channel_id).and_then(|messages| {
             messages.get(&message_id).cloned()
-        })
+        });
+
+        if message.is_some() {
+            self.touch_message(channel_id, message_id).await;
+        }
+
+        message
+    }
+
+    /// Retrieves the cached messages of a channel, in chronological order.
+    ///
+    /// **Note**: This will clone every message. Only the messages received
+    /// since the [`max_messages`] setting was raised are cached.
+    ///
+    /// # Examples
+    ///
+    /// Print the content of the recent messages of a channel:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::cache::Cache;
+    /// #
+    /// # async fn run() {
+    /// # let cache = Cache::default();
+    /// for message in cache.channel_messages(7).await {
+    ///     println!("{}: {}", message.author.name, message.content);
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [`max_messages`]: Settings::max_messages
+    #[inline]
+    pub async fn channel_messages(&self, channel_id: impl Into<ChannelId>) -> Vec<Message> {
+        self._channel_messages(channel_id.into(), None).await
+    }
+
+    /// Retrieves the cached messages of a channel sent at or after the given
+    /// timestamp, in chronological order.
+    ///
+    /// **Note**: This will clone every matching message.
+    #[inline]
+    pub async fn messages_since(
+        &self,
+        channel_id: impl Into<ChannelId>,
+        timestamp: DateTime<Utc>,
+    ) -> Vec<Message> {
+        self._channel_messages(channel_id.into(), Some(timestamp)).await
+    }
+
+    /// Retrieves the most recent cached message of a channel, if any.
+    #[inline]
+    pub async fn last_message(&self, channel_id: impl Into<ChannelId>) -> Option<Message> {
+        self._last_message(channel_id.into()).await
+    }
+
+    async fn _channel_messages(&self, channel_id: ChannelId, since: Option<DateTime<Utc>>) -> Vec<Message> {
+        let mut messages = match self.messages.read().await.get(&channel_id) {
+            Some(messages) => messages
+                .values()
+                .filter(|message| !matches!(since, Some(since) if message.timestamp < since))
+                .cloned()
+                .collect::<Vec<_>>(),
+            None => return Vec::new(),
+        };
+
+        messages.sort_by_key(|message| (message.timestamp, message.id));
+
+        for message in &messages {
+            self.touch_message(channel_id, message.id).await;
+        }
+
+        messages
+    }
+
+    async fn _last_message(&self, channel_id: ChannelId) -> Option<Message> {
+        let message = self.messages.read().await.get(&channel_id).and_then(|messages| {
+            messages.values().max_by_key(|message| (message.timestamp, message.id)).cloned()
+        });
+
+        if let Some(message) = &message {
+            self.touch_message(channel_id, message.id).await;
+        }
+
+        message
     }
 
     /// Retrieves a [`PrivateChannel`] from the cache's [`private_channels`]
@@ -787,6 +900,109 @@ impl Cache {
         self.settings.write().await.max_messages = max;
     }
 
+    /// Sets the maximum amount of messages to cache in a specific channel,
+    /// overriding the value of [`set_max_messages`] for that channel.
+    ///
+    /// [`set_max_messages`]: Self::set_max_messages
+    pub async fn set_max_messages_per_channel(&self, channel_id: impl Into<ChannelId>, max: usize) {
+        self.settings.write().await.max_messages_per_channel(channel_id, max);
+    }
+
+    /// Sets the duration after which cached users and messages which have not
+    /// been accessed expire.
+    ///
+    /// Refer to [`Settings::time_to_live`] for more information.
+    pub async fn set_time_to_live(&self, time_to_live: Option<Duration>) {
+        self.settings.write().await.time_to_live = time_to_live;
+    }
+
+    /// Removes the cached users and messages which have not been accessed for
+    /// longer than the [`Settings::time_to_live`], doing nothing if it is not
+    /// set.
+    ///
+    /// An entry is accessed when it is inserted or updated by an event, or
+    /// retrieved via [`user`] or [`message`]. Users which are members of a
+    /// cached guild or recipients of a cached private channel never expire.
+    ///
+    /// # Examples
+    ///
+    /// Periodically evict the expired entries in a background task:
+    ///
+    /// ```rust,no_run
+    /// use serenity::cache::Cache;
+    /// use std::{sync::Arc, time::Duration};
+    ///
+    /// # async fn run() {
+    /// let cache = Arc::new(Cache::new());
+    /// cache.set_time_to_live(Some(Duration::from_secs(60 * 60))).await;
+    ///
+    /// tokio::spawn(async move {
+    ///     loop {
+    ///         tokio::time::delay_for(Duration::from_secs(60)).await;
+    ///         cache.evict_expired().await;
+    ///     }
+    /// });
+    /// # }
+    /// ```
+    ///
+    /// [`user`]: Self::user
+    /// [`message`]: Self::message
+    pub async fn evict_expired(&self) {
+        let time_to_live = match self.settings.read().await.time_to_live {
+            Some(time_to_live) => time_to_live,
+            None => return,
+        };
+        let now = self.clock.now();
+
+        let is_expired = |last: Instant| now.duration_since(last) >= time_to_live;
+
+        let mut referenced = HashSet::new();
+
+        for guild in self.guilds.read().await.values() {
+            referenced.extend(guild.members.keys().copied());
+        }
+
+        for channel in self.private_channels.read().await.values() {
+            referenced.insert(channel.recipient.id);
+        }
+
+        {
+            let mut users = self.users.write().await;
+            let mut access = self.user_access.write().await;
+
+            users.retain(|id, _| referenced.contains(id) || !is_expired(last_access(&mut access, *id, now)));
+            access.retain(|id, _| users.contains_key(id));
+        }
+
+        let mut messages_map = self.messages.write().await;
+        let mut message_queues = self.message_queue.write().await;
+        let mut access = self.message_access.write().await;
+
+        for (channel_id, messages) in messages_map.iter_mut() {
+            messages.retain(|id, _| !is_expired(last_access(&mut access, (*channel_id, *id), now)));
+
+            if let Some(queue) = message_queues.get_mut(channel_id) {
+                queue.retain(|id| messages.contains_key(id));
+            }
+        }
+
+        access.retain(|(channel_id, id), _| {
+            matches!(messages_map.get(channel_id), Some(messages) if messages.contains_key(id))
+        });
+    }
+
+    pub(crate) async fn touch_user(&self, user_id: UserId) {
+        if self.settings.read().await.time_to_live.is_some() {
+            self.user_access.write().await.insert(user_id, self.clock.now());
+        }
+    }
+
+    pub(crate) async fn touch_message(&self, channel_id: ChannelId, message_id: MessageId) {
+        if self.settings.read().await.time_to_live.is_some() {
+            self.message_access.write().await.insert((channel_id, message_id), self.clock.now());
+        }
+    }
+
     /// Retrieves a `User` from the cache's [`users`] map, if it exists.
     ///
     /// The only advantage of this method is that you can pass in anything that
@@ -817,7 +1033,13 @@ impl Cache {
     }
 
     async fn _user(&self, user_id: UserId) -> Option<User> {
-        self.users.read().await.get(&user_id).cloned()
+        let user = self.users.read().await.get(&user_id).cloned();
+
+        if user.is_some() {
+            self.touch_user(user_id).await;
+        }
+
+        user
     }
 
     /// Clones all users and returns them.
@@ -913,6 +1135,10 @@ impl Cache {
     }
 
     pub(crate) async fn update_user_entry(&self, user: &User) {
+        if !self.settings.read().await.should_cache_user(user.id) {
+            return;
+        }
+
         match self.users.write().await.entry(user.id) {
             Entry::Vacant(e) => {
                 e.insert(user.clone());
@@ -921,9 +1147,17 @@ impl Cache {
                 e.get_mut().clone_from(user);
             },
         }
+
+        self.touch_user(user.id).await;
     }
 }
 
+/// Returns the last time the entry was accessed, considering the entries which
+/// have not been accessed since the time to live was set as accessed `now`.
+fn last_access<K: Eq + Hash>(access: &mut HashMap<K, Instant>, key: K, now: Instant) -> Instant {
+    *access.entry(key).or_insert(now)
+}
+
 impl Default for Cache {
     fn default() -> Cache {
         Cache {
@@ -939,6 +1173,9 @@ impl Default for Cache {
             user: RwLock::new(CurrentUser::default()),
             users: RwLock::new(HashMap::default()),
             message_queue: RwLock::new(HashMap::default()),
+            user_access: RwLock::new(HashMap::default()),
+            message_access: RwLock::new(HashMap::default()),
+            clock: Clock::default(),
         }
     }
 }
@@ -998,7 +1235,9 @@ mod test {
                 message_reference: None,
                 flags: None,
                 stickers: vec![],
+                sticker_items: vec![],
                 referenced_message: None,
+                components: vec![],
             },
         };
         // Check that the channel cache doesn't exist.
@@ -1043,12 +1282,17 @@ mod test {
             user_limit: None,
             nsfw: false,
             slow_mode_rate: Some(0),
+            thread_metadata: None,
+            member: None,
+            available_tags: vec![],
+            applied_tags: vec![],
+            default_reaction_emoji: None,
         };
 
         // Add a channel delete event to the cache, the cached messages for that
         // channel should now be gone.
         let mut delete = ChannelDeleteEvent {
-            channel: Channel::Guild(guild_channel.clone()),
+            channel: Channel::Guild(Box::new(guild_channel.clone())),
         };
         assert!(cache.update(&mut delete).await.is_none());
         assert!(!cache.messages.read().await.contains_key(&delete.channel.id()));
@@ -1111,4 +1355,429 @@ mod test {
         // Assert that the channel's message cache no longer exists.
         assert!(!cache.messages.read().await.contains_key(&ChannelId(2)));
     }
+
+    #[cfg(feature = "utils")]
+    #[tokio::test]
+    async fn test_cache_messages_per_channel() {
+        use crate::utils::CustomMessage;
+
+        let mut settings = Settings::new();
+        settings
+            .max_messages(2)
+            .max_messages_per_channel(ChannelId(1), 3);
+        let cache = Cache::new_with_settings(settings);
+
+        for channel_id in 1..=2 {
+            for id in 1..=5 {
+                let mut message = CustomMessage::new();
+                message.id(MessageId(id)).channel_id(ChannelId(channel_id));
+
+                let mut event = MessageCreateEvent {
+                    message: message.build(),
+                };
+                let removed = cache.update(&mut event).await;
+
+                // The oldest message is evicted once the channel's cap is hit.
+                let cap = if channel_id == 1 { 3 } else { 2 };
+                let expected = if id > cap { Some(MessageId(id - cap)) } else { None };
+                assert_eq!(removed.map(|m| m.id), expected);
+            }
+        }
+
+        let ids = |channel_id: u64| {
+            let messages = &cache.messages;
+
+            async move {
+                let mut ids = messages.read().await
+                    .get(&ChannelId(channel_id))
+                    .map(|messages| messages.keys().copied().collect::<Vec<_>>())
+                    .unwrap_or_default();
+                ids.sort();
+
+                ids
+            }
+        };
+
+        assert_eq!(ids(1).await, vec![MessageId(3), MessageId(4), MessageId(5)]);
+        assert_eq!(ids(2).await, vec![MessageId(4), MessageId(5)]);
+
+        // Lowering the cap drops the oldest messages on the next insertion.
+        cache.set_max_messages_per_channel(ChannelId(1), 1).await;
+
+        let mut message = CustomMessage::new();
+        message.id(MessageId(6)).channel_id(ChannelId(1));
+        let mut event = MessageCreateEvent {
+            message: message.build(),
+        };
+
+        assert_eq!(cache.update(&mut event).await.map(|m| m.id), Some(MessageId(3)));
+        assert_eq!(ids(1).await, vec![MessageId(6)]);
+    }
+
+    #[cfg(feature = "utils")]
+    #[tokio::test]
+    async fn test_cache_time_to_live() {
+        use crate::utils::CustomMessage;
+        use std::time::Duration;
+
+        let mut settings = Settings::new();
+        settings.max_messages(10).time_to_live(Duration::from_secs(60));
+        let cache = Cache::new_with_settings(settings);
+
+        let user = |id: u64| {
+            let mut user = User::default();
+            user.id = UserId(id);
+
+            user
+        };
+
+        for id in 1..=3 {
+            cache.update_user_entry(&user(id)).await;
+
+            let mut message = CustomMessage::new();
+            message.id(MessageId(id)).channel_id(ChannelId(1));
+            let mut event = MessageCreateEvent {
+                message: message.build(),
+            };
+            cache.update(&mut event).await;
+        }
+
+        // The recipient of a private channel is exempt from expiration.
+        cache.private_channels.write().await.insert(ChannelId(2), PrivateChannel {
+            id: ChannelId(2),
+            last_message_id: None,
+            last_pin_timestamp: None,
+            kind: ChannelType::Private,
+            recipient: user(3),
+        });
+
+        cache.clock.advance(Duration::from_secs(30));
+        assert!(cache.user(UserId(1)).await.is_some());
+        assert!(cache.message(ChannelId(1), MessageId(1)).await.is_some());
+
+        // Nothing has expired yet.
+        cache.evict_expired().await;
+        assert_eq!(cache.user_count().await, 3);
+
+        cache.clock.advance(Duration::from_secs(40));
+        cache.evict_expired().await;
+
+        assert!(cache.user(UserId(1)).await.is_some());
+        assert!(cache.user(UserId(2)).await.is_none());
+        assert!(cache.user(UserId(3)).await.is_some());
+        assert!(cache.message(ChannelId(1), MessageId(1)).await.is_some());
+        assert!(cache.message(ChannelId(1), MessageId(2)).await.is_none());
+        assert!(cache.message(ChannelId(1), MessageId(3)).await.is_none());
+        assert_eq!(
+            cache.message_queue.read().await.get(&ChannelId(1)).cloned(),
+            Some(vec![MessageId(1)].into()),
+        );
+
+        cache.clock.advance(Duration::from_secs(60));
+        cache.evict_expired().await;
+
+        assert!(cache.user(UserId(1)).await.is_none());
+        assert!(cache.user(UserId(3)).await.is_some());
+        assert!(cache.message(ChannelId(1), MessageId(1)).await.is_none());
+        assert_eq!(cache.user_access.read().await.len(), 1);
+        assert!(cache.message_access.read().await.is_empty());
+    }
+
+    #[cfg(feature = "utils")]
+    #[tokio::test]
+    async fn test_cache_channel_messages() {
+        use crate::utils::CustomMessage;
+        use chrono::TimeZone;
+
+        let mut settings = Settings::new();
+        settings.max_messages(10);
+        let cache = Cache::new_with_settings(settings);
+        let at = |second: i64| Utc.timestamp_opt(1_600_000_000 + second, 0).unwrap();
+
+        // Messages are inserted out of order, and two share a timestamp.
+        for &(id, second) in &[(3, 30), (1, 10), (4, 30), (2, 20)] {
+            let mut message = CustomMessage::new();
+            message
+                .id(MessageId(id))
+                .channel_id(ChannelId(1))
+                .timestamp(at(second));
+            let mut event = MessageCreateEvent {
+                message: message.build(),
+            };
+            cache.update(&mut event).await;
+        }
+
+        let ids = |messages: Vec<Message>| messages.iter().map(|m| m.id.0).collect::<Vec<_>>();
+
+        assert_eq!(ids(cache.channel_messages(ChannelId(1)).await), [1, 2, 3, 4]);
+        assert_eq!(ids(cache.messages_since(ChannelId(1), at(20)).await), [2, 3, 4]);
+        assert!(cache.messages_since(ChannelId(1), at(31)).await.is_empty());
+        assert_eq!(cache.last_message(ChannelId(1)).await.map(|m| m.id), Some(MessageId(4)));
+
+        assert!(cache.channel_messages(ChannelId(2)).await.is_empty());
+        assert!(cache.last_message(ChannelId(2)).await.is_none());
+    }
+
+    fn guild_create(guild_id: GuildId) -> GuildCreateEvent {
+        let user = User {
+            id: UserId(guild_id.0 + 10),
+            ..Default::default()
+        };
+
+        let mut members = HashMap::new();
+        members.insert(user.id, Member {
+            avatar: None,
+            communication_disabled_until: None,
+            deaf: false,
+            guild_id,
+            joined_at: None,
+            mute: false,
+            nick: None,
+            roles: vec![],
+            user,
+        });
+
+        let channel_id = ChannelId(guild_id.0 + 20);
+        let mut channels = HashMap::new();
+        channels.insert(channel_id, GuildChannel {
+            id: channel_id,
+            bitrate: None,
+            category_id: None,
+            guild_id,
+            kind: ChannelType::Text,
+            last_message_id: None,
+            last_pin_timestamp: None,
+            name: String::new(),
+            permission_overwrites: vec![],
+            position: 0,
+            topic: None,
+            user_limit: None,
+            nsfw: false,
+            slow_mode_rate: Some(0),
+            thread_metadata: None,
+            member: None,
+            available_tags: vec![],
+            applied_tags: vec![],
+            default_reaction_emoji: None,
+        });
+
+        GuildCreateEvent {
+            guild: Guild {
+                id: guild_id,
+                afk_channel_id: None,
+                afk_timeout: 0,
+                application_id: None,
+                default_message_notifications: DefaultMessageNotificationLevel::All,
+                emojis: HashMap::new(),
+                explicit_content_filter: ExplicitContentFilter::None,
+                features: vec![],
+                icon: None,
+                joined_at: Utc::now(),
+                large: false,
+                member_count: 1,
+                members,
+                mfa_level: MfaLevel::None,
+                name: String::new(),
+                owner_id: UserId(3),
+                presences: HashMap::new(),
+                region: String::new(),
+                roles: HashMap::new(),
+                splash: None,
+                system_channel_id: None,
+                verification_level: VerificationLevel::Low,
+                voice_states: HashMap::new(),
+                description: None,
+                premium_tier: PremiumTier::Tier0,
+                channels,
+                premium_subscription_count: 0,
+                banner: None,
+                vanity_url_code: None,
+                preferred_locale: "en-US".to_string(),
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cache_guild_predicate() {
+        let mut settings = Settings::new();
+        settings.cache_guild(|guild_id| guild_id != GuildId(1));
+        let cache = Cache::new_with_settings(settings);
+
+        // The rejected guild leaves the cache empty.
+        cache.update(&mut guild_create(GuildId(1))).await;
+
+        assert_eq!(cache.guild_count().await, 0);
+        assert!(cache.channels.read().await.is_empty());
+        assert_eq!(cache.user_count().await, 0);
+
+        // Its members are not cached either.
+        let mut member_add = GuildMemberAddEvent {
+            guild_id: GuildId(1),
+            member: guild_create(GuildId(1)).guild.members.values().next().cloned().unwrap(),
+        };
+        cache.update(&mut member_add).await;
+        assert_eq!(cache.user_count().await, 0);
+
+        // Other guilds are cached as usual.
+        cache.update(&mut guild_create(GuildId(2))).await;
+
+        assert!(cache.guild(GuildId(2)).await.is_some());
+        assert!(cache.guild_channel(ChannelId(22)).await.is_some());
+        assert!(cache.user(UserId(12)).await.is_some());
+    }
+
+    #[cfg(feature = "utils")]
+    #[tokio::test]
+    async fn test_cache_message_member() {
+        use crate::utils::CustomMessage;
+        use chrono::TimeZone;
+
+        let cache = Cache::new();
+        let mut guild = guild_create(GuildId(1));
+        let joined_at = Utc.timestamp_opt(1_600_000_000, 0).unwrap();
+        guild.guild.members.get_mut(&UserId(11)).unwrap().joined_at = Some(joined_at);
+        guild.guild.members.get_mut(&UserId(11)).unwrap().avatar = Some("abc".to_string());
+        cache.update(&mut guild).await;
+
+        let event = |user_id: u64, partial: PartialMember| {
+            let mut message = CustomMessage::new();
+            message
+                .id(MessageId(user_id))
+                .channel_id(ChannelId(21))
+                .guild_id(GuildId(1))
+                .author(User {
+                    id: UserId(user_id),
+                    ..Default::default()
+                })
+                .member(partial);
+
+            MessageCreateEvent {
+                message: message.build(),
+            }
+        };
+        let partial = |roles: Vec<u64>, nick: Option<&str>| serde_json::from_value::<PartialMember>(serde_json::json!({
+            "deaf": false,
+            "joined_at": null,
+            "mute": false,
+            "nick": nick,
+            "roles": roles.iter().map(ToString::to_string).collect::<Vec<_>>(),
+        })).unwrap();
+
+        // The cached member gets the roles and nickname of the message, but
+        // keeps the data missing from it.
+        cache.update(&mut event(11, partial(vec![5, 6], Some("crab")))).await;
+
+        let member = cache.member(GuildId(1), UserId(11)).await.unwrap();
+        assert_eq!(member.roles, [RoleId(5), RoleId(6)]);
+        assert_eq!(member.nick.as_deref(), Some("crab"));
+        assert_eq!(member.joined_at, Some(joined_at));
+        assert_eq!(member.avatar.as_deref(), Some("abc"));
+
+        // Unknown authors are added to the guild's members.
+        cache.update(&mut event(12, partial(vec![5], None))).await;
+
+        let member = cache.member(GuildId(1), UserId(12)).await.unwrap();
+        assert_eq!(member.roles, [RoleId(5)]);
+        assert_eq!(member.guild_id, GuildId(1));
+        assert!(cache.user(UserId(12)).await.is_some());
+
+        // Messages of guilds which are not cached are ignored.
+        let mut message = event(13, partial(vec![5], None));
+        message.message.guild_id = Some(GuildId(2));
+        cache.update(&mut message).await;
+
+        assert!(cache.guild(GuildId(2)).await.is_none());
+        assert!(cache.member(GuildId(1), UserId(13)).await.is_none());
+    }
+
+    #[cfg(feature = "model")]
+    #[tokio::test]
+    async fn test_cache_voice_states() {
+        let cache = Cache::new();
+        let mut guild = guild_create(GuildId(1));
+        let user_id = UserId(11);
+        let member = guild.guild.members[&user_id].clone();
+        cache.update(&mut guild).await;
+
+        let event = |channel_id: Option<u64>| VoiceStateUpdateEvent {
+            guild_id: Some(GuildId(1)),
+            voice_state: serde_json::from_value(serde_json::json!({
+                "channel_id": channel_id.map(|id| id.to_string()),
+                "deaf": false,
+                "guild_id": "1",
+                "mute": false,
+                "self_deaf": false,
+                "self_mute": false,
+                "self_video": false,
+                "session_id": "session",
+                "suppress": false,
+                "user_id": "11",
+            })).unwrap(),
+        };
+
+        // Joining a channel.
+        assert!(cache.update(&mut event(Some(30))).await.is_none());
+        {
+            let guilds = cache.guilds.read().await;
+            let guild = &guilds[&GuildId(1)];
+
+            assert_eq!(guild.voice_channel_of(user_id), Some(ChannelId(30)));
+            assert_eq!(guild.members_in_voice(ChannelId(30)).len(), 1);
+            assert_eq!(guild.members_in_voice(ChannelId(30))[0].user.id, member.user.id);
+        }
+
+        // Moving to another channel replaces the previous state.
+        let previous = cache.update(&mut event(Some(31))).await;
+        assert_eq!(previous.and_then(|state| state.channel_id), Some(ChannelId(30)));
+        {
+            let guilds = cache.guilds.read().await;
+            let guild = &guilds[&GuildId(1)];
+
+            assert_eq!(guild.voice_states.len(), 1);
+            assert_eq!(guild.voice_channel_of(user_id), Some(ChannelId(31)));
+            assert!(guild.members_in_voice(ChannelId(30)).is_empty());
+            assert_eq!(guild.members_in_voice(ChannelId(31)).len(), 1);
+        }
+
+        // Leaving evicts the state.
+        let previous = cache.update(&mut event(None)).await;
+        assert_eq!(previous.and_then(|state| state.channel_id), Some(ChannelId(31)));
+        {
+            let guilds = cache.guilds.read().await;
+            let guild = &guilds[&GuildId(1)];
+
+            assert!(guild.voice_states.is_empty());
+            assert_eq!(guild.voice_channel_of(user_id), None);
+            assert!(guild.members_in_voice(ChannelId(31)).is_empty());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cache_dump_and_load() {
+        let cache = Cache::new();
+        cache.update(&mut guild_create(GuildId(1))).await;
+        cache.update(&mut guild_create(GuildId(2))).await;
+
+        let mut dump = Vec::new();
+        cache.dump(&mut dump).await.unwrap();
+
+        let loaded = Cache::load(&dump[..]).unwrap();
+
+        assert_eq!(loaded.guild_count().await, 2);
+        assert_eq!(loaded.user_count().await, 2);
+
+        let guild = loaded.guild(GuildId(2)).await.unwrap();
+        assert_eq!(guild.preferred_locale, "en-US");
+        assert!(guild.members.contains_key(&UserId(12)));
+        assert!(guild.channels.contains_key(&ChannelId(22)));
+        assert_eq!(loaded.guild_channel(ChannelId(21)).await.map(|c| c.guild_id), Some(GuildId(1)));
+        assert_eq!(loaded.user(UserId(11)).await.map(|u| u.id), Some(UserId(11)));
+
+        // Dumps of another version are rejected.
+        let mut value = serde_json::from_slice::<Value>(&dump).unwrap();
+        value["version"] = Value::from(0);
+        let dump = serde_json::to_vec(&value).unwrap();
+
+        assert!(Cache::load(&dump[..]).is_err());
+    }
 }
@@ -0,0 +1,146 @@
+use std::collections::{HashMap, HashSet};
+use std::io::{Read, Write};
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
+
+use crate::internal::prelude::*;
+use crate::model::prelude::*;
+use super::Cache;
+
+/// The version of the format written by [`Cache::dump`].
+///
+/// This must be bumped whenever the format changes in an incompatible way, so
+/// that older dumps are rejected by [`Cache::load`].
+const DUMP_VERSION: u64 = 1;
+
+#[derive(Serialize)]
+struct DumpRef<'a> {
+    version: u64,
+    categories: Vec<&'a ChannelCategory>,
+    channels: Vec<&'a GuildChannel>,
+    guilds: Vec<&'a Guild>,
+    private_channels: Vec<&'a PrivateChannel>,
+    shard_count: u64,
+    unavailable_guilds: Vec<GuildId>,
+    user: &'a CurrentUser,
+    users: Vec<&'a User>,
+}
+
+#[derive(Deserialize)]
+struct Dump {
+    categories: Vec<ChannelCategory>,
+    channels: Vec<GuildChannel>,
+    guilds: Vec<Guild>,
+    private_channels: Vec<PrivateChannel>,
+    shard_count: u64,
+    unavailable_guilds: HashSet<GuildId>,
+    user: CurrentUser,
+    users: Vec<User>,
+}
+
+impl Cache {
+    /// Writes the guilds, channels and users of the cache to the `writer`, in
+    /// a versioned JSON format which can be read back with [`Cache::load`].
+    ///
+    /// Messages, presences and settings are not written.
+    ///
+    /// The cache is locked for reading while it is written, so that the dump
+    /// is consistent.
+    ///
+    /// # Examples
+    ///
+    /// Save the cache to a file:
+    ///
+    /// ```rust,no_run
+    /// use serenity::cache::Cache;
+    /// use std::fs::File;
+    ///
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let cache = Cache::new();
+    /// cache.dump(File::create("cache.json")?).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Json`] if the cache could not be serialized or
+    /// written.
+    pub async fn dump(&self, writer: impl Write) -> Result<()> {
+        let channels = self.channels.read().await;
+        let categories = self.categories.read().await;
+        let guilds = self.guilds.read().await;
+        let private_channels = self.private_channels.read().await;
+        let shard_count = self.shard_count.read().await;
+        let unavailable_guilds = self.unavailable_guilds.read().await;
+        let user = self.user.read().await;
+        let users = self.users.read().await;
+
+        let dump = DumpRef {
+            version: DUMP_VERSION,
+            categories: categories.values().collect(),
+            channels: channels.values().collect(),
+            guilds: guilds.values().collect(),
+            private_channels: private_channels.values().collect(),
+            shard_count: *shard_count,
+            unavailable_guilds: unavailable_guilds.iter().copied().collect(),
+            user: &user,
+            users: users.values().collect(),
+        };
+
+        serde_json::to_writer(writer, &dump).map_err(From::from)
+    }
+
+    /// Creates a new cache from a dump written by [`Cache::dump`], with the
+    /// default settings.
+    ///
+    /// # Examples
+    ///
+    /// Restore the cache from a file:
+    ///
+    /// ```rust,no_run
+    /// use serenity::cache::Cache;
+    /// use std::fs::File;
+    ///
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let cache = Cache::load(File::open("cache.json")?)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Decode`] if the dump was written in an incompatible
+    /// version, or an [`Error::Json`] if it could not be read or is malformed.
+    pub fn load(reader: impl Read) -> Result<Cache> {
+        let mut value: Value = serde_json::from_reader(reader)?;
+
+        match value.as_object_mut().and_then(|map| map.remove("version")) {
+            Some(Value::Number(ref version)) if version.as_u64() == Some(DUMP_VERSION) => {},
+            version => {
+                return Err(Error::Decode("Incompatible cache dump version", version.unwrap_or(Value::Null)));
+            },
+        }
+
+        let dump = serde_json::from_value::<Dump>(value)?;
+
+        let categories = dump.categories.into_iter().map(|c| (c.id, c)).collect();
+        let channels = dump.channels.into_iter().map(|c| (c.id, c)).collect();
+        let guilds = dump.guilds.into_iter().map(|g| (g.id, g)).collect();
+        let private_channels = dump.private_channels.into_iter().map(|c| (c.id, c)).collect::<HashMap<_, _>>();
+        let users = dump.users.into_iter().map(|u| (u.id, u)).collect();
+
+        Ok(Cache {
+            categories: RwLock::new(categories),
+            channels: RwLock::new(channels),
+            guilds: RwLock::new(guilds),
+            private_channels: RwLock::new(private_channels),
+            shard_count: RwLock::new(dump.shard_count),
+            unavailable_guilds: RwLock::new(dump.unavailable_guilds),
+            user: RwLock::new(dump.user),
+            users: RwLock::new(users),
+            ..Default::default()
+        })
+    }
+}
@@ -1,3 +1,10 @@
+use crate::model::id::{ChannelId, GuildId, UserId};
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
 /// Settings for the cache.
 ///
 /// # Examples
@@ -10,19 +17,70 @@
 /// let mut settings = CacheSettings::new();
 /// settings.max_messages(10);
 /// ```
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 #[non_exhaustive]
 pub struct Settings {
     /// The maximum number of messages to store in a channel's message cache.
     ///
     /// Defaults to 0.
     pub max_messages: usize,
+    /// The maximum number of messages to store in the message cache of
+    /// specific channels, overriding [`max_messages`] for those channels.
+    ///
+    /// Defaults to an empty map.
+    ///
+    /// [`max_messages`]: #structfield.max_messages
+    pub max_messages_per_channel: HashMap<ChannelId, usize>,
+    /// The duration after which cached users and messages which have not been
+    /// accessed are removed by [`Cache::evict_expired`].
+    ///
+    /// Defaults to `None`, meaning that entries never expire.
+    ///
+    /// [`Cache::evict_expired`]: super::Cache::evict_expired
+    pub time_to_live: Option<Duration>,
+    /// A predicate deciding whether a guild, along with its channels, members
+    /// and messages, is cached.
+    ///
+    /// Defaults to `None`, meaning that all guilds are cached.
+    pub cache_guild: Option<Arc<dyn Fn(GuildId) -> bool + Send + Sync>>,
+    /// A predicate deciding whether a channel, along with its messages, is
+    /// cached.
+    ///
+    /// Defaults to `None`, meaning that all channels are cached.
+    pub cache_channel: Option<Arc<dyn Fn(ChannelId) -> bool + Send + Sync>>,
+    /// A predicate deciding whether a user is cached.
+    ///
+    /// Members of a cached guild are still stored in the guild, but their
+    /// users are not stored in the cache's user map.
+    ///
+    /// Defaults to `None`, meaning that all users are cached.
+    pub cache_user: Option<Arc<dyn Fn(UserId) -> bool + Send + Sync>>,
+}
+
+impl fmt::Debug for Settings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let predicate = |p: bool| if p { Some("<predicate>") } else { None };
+
+        f.debug_struct("Settings")
+            .field("max_messages", &self.max_messages)
+            .field("max_messages_per_channel", &self.max_messages_per_channel)
+            .field("time_to_live", &self.time_to_live)
+            .field("cache_guild", &predicate(self.cache_guild.is_some()))
+            .field("cache_channel", &predicate(self.cache_channel.is_some()))
+            .field("cache_user", &predicate(self.cache_user.is_some()))
+            .finish()
+    }
 }
 
 impl Default for Settings {
     fn default() -> Self {
         Settings {
             max_messages: usize::default(),
+            max_messages_per_channel: HashMap::default(),
+            time_to_live: None,
+            cache_guild: None,
+            cache_channel: None,
+            cache_user: None,
         }
     }
 }
@@ -55,4 +113,140 @@ impl Settings {
 
         self
     }
+
+    /// Sets the maximum number of messages to cache in a specific channel,
+    /// overriding [`max_messages`] for that channel.
+    ///
+    /// Refer to [`max_messages_per_channel`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// Cache up to 1000 messages of a busy channel, and 10 messages of the
+    /// other channels:
+    ///
+    /// ```rust
+    /// use serenity::cache::Settings;
+    /// use serenity::model::id::ChannelId;
+    ///
+    /// let mut settings = Settings::new();
+    /// settings
+    ///     .max_messages(10)
+    ///     .max_messages_per_channel(ChannelId(7), 1000);
+    /// ```
+    ///
+    /// [`max_messages`]: #structfield.max_messages
+    /// [`max_messages_per_channel`]: #structfield.max_messages_per_channel
+    pub fn max_messages_per_channel(&mut self, channel_id: impl Into<ChannelId>, max: usize) -> &mut Self {
+        self.max_messages_per_channel.insert(channel_id.into(), max);
+
+        self
+    }
+
+    /// Sets the duration after which cached users and messages which have not
+    /// been accessed expire.
+    ///
+    /// Refer to [`time_to_live`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// Expire the entries which have not been accessed for an hour:
+    ///
+    /// ```rust
+    /// use serenity::cache::Settings;
+    /// use std::time::Duration;
+    ///
+    /// let mut settings = Settings::new();
+    /// settings.time_to_live(Duration::from_secs(60 * 60));
+    /// ```
+    ///
+    /// [`time_to_live`]: #structfield.time_to_live
+    pub fn time_to_live(&mut self, time_to_live: Duration) -> &mut Self {
+        self.time_to_live = Some(time_to_live);
+
+        self
+    }
+
+    /// Sets the predicate deciding whether a guild is cached.
+    ///
+    /// Refer to [`cache_guild`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// Only cache a single guild:
+    ///
+    /// ```rust
+    /// use serenity::cache::Settings;
+    /// use serenity::model::id::GuildId;
+    ///
+    /// let mut settings = Settings::new();
+    /// settings.cache_guild(|guild_id| guild_id == GuildId(7));
+    /// ```
+    ///
+    /// [`cache_guild`]: #structfield.cache_guild
+    pub fn cache_guild<F>(&mut self, predicate: F) -> &mut Self
+    where F: Fn(GuildId) -> bool + Send + Sync + 'static {
+        self.cache_guild = Some(Arc::new(predicate));
+
+        self
+    }
+
+    /// Sets the predicate deciding whether a channel is cached.
+    ///
+    /// Refer to [`cache_channel`] for more information.
+    ///
+    /// [`cache_channel`]: #structfield.cache_channel
+    pub fn cache_channel<F>(&mut self, predicate: F) -> &mut Self
+    where F: Fn(ChannelId) -> bool + Send + Sync + 'static {
+        self.cache_channel = Some(Arc::new(predicate));
+
+        self
+    }
+
+    /// Sets the predicate deciding whether a user is cached.
+    ///
+    /// Refer to [`cache_user`] for more information.
+    ///
+    /// [`cache_user`]: #structfield.cache_user
+    pub fn cache_user<F>(&mut self, predicate: F) -> &mut Self
+    where F: Fn(UserId) -> bool + Send + Sync + 'static {
+        self.cache_user = Some(Arc::new(predicate));
+
+        self
+    }
+
+    pub(crate) fn should_cache_guild(&self, guild_id: GuildId) -> bool {
+        match self.cache_guild {
+            Some(ref predicate) => predicate(guild_id),
+            None => true,
+        }
+    }
+
+    /// Whether a channel, optionally belonging to a guild, is cached.
+    pub(crate) fn should_cache_channel(&self, channel_id: ChannelId, guild_id: Option<GuildId>) -> bool {
+        if let Some(guild_id) = guild_id {
+            if !self.should_cache_guild(guild_id) {
+                return false;
+            }
+        }
+
+        match self.cache_channel {
+            Some(ref predicate) => predicate(channel_id),
+            None => true,
+        }
+    }
+
+    pub(crate) fn should_cache_user(&self, user_id: UserId) -> bool {
+        match self.cache_user {
+            Some(ref predicate) => predicate(user_id),
+            None => true,
+        }
+    }
+
+    /// Returns the maximum number of messages to cache in the given channel.
+    pub fn max_messages_for(&self, channel_id: impl Into<ChannelId>) -> usize {
+        self.max_messages_per_channel
+            .get(&channel_id.into())
+            .copied()
+            .unwrap_or(self.max_messages)
+    }
 }
@@ -51,14 +51,16 @@ mod shard_queuer;
 mod shard_runner;
 mod shard_runner_message;
 mod intents;
+mod reconnect_policy;
 
-pub use self::shard_manager::{ShardManager, ShardManagerOptions};
+pub use self::shard_manager::{ShardConnectionOptions, ShardManager, ShardManagerOptions};
 pub use self::shard_manager_monitor::{ShardManagerMonitor, ShardManagerError};
 pub use self::shard_messenger::ShardMessenger;
 pub use self::shard_queuer::ShardQueuer;
 pub use self::shard_runner::{ShardRunner, ShardRunnerOptions};
 pub use self::shard_runner_message::{ShardRunnerMessage, ChunkGuildFilter};
 pub use self::intents::GatewayIntents;
+pub use self::reconnect_policy::ReconnectPolicy;
 
 use std::{
     fmt::{
@@ -164,6 +166,17 @@ pub struct ShardRunnerInfo {
     pub stage: ConnectionStage,
 }
 
+impl ShardRunnerInfo {
+    /// Returns the latency between when the last heartbeat was sent and when
+    /// its acknowledgement was received.
+    ///
+    /// Returns `None` if no heartbeat has been acknowledged yet.
+    #[inline]
+    pub fn latency(&self) -> Option<StdDuration> {
+        self.latency
+    }
+}
+
 impl AsRef<ShardMessenger> for ShardRunnerInfo {
     fn as_ref(&self) -> &ShardMessenger {
         &self.runner_tx
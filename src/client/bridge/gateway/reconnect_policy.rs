@@ -0,0 +1,191 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// The policy deciding how long a shard waits before reconnecting after its
+/// connection to the gateway was lost.
+///
+/// The delay grows exponentially with every consecutive failed reconnect,
+/// starting at the [`base`] delay and multiplied by the [`multiplier`] on each
+/// attempt, until it reaches the [`max`] delay. It is reset once the shard
+/// successfully connects again.
+///
+/// A random amount of [`jitter`] is added to the delay, so that shards which
+/// were disconnected at the same time do not all reconnect at once.
+///
+/// **Note**: This is in addition to the 5 seconds which must always pass
+/// between two shard IDENTIFYs.
+///
+/// # Examples
+///
+/// Wait at least 2 seconds between reconnects, and doubling the delay on every
+/// attempt up to a minute:
+///
+/// ```rust
+/// use serenity::client::bridge::gateway::ReconnectPolicy;
+/// use std::time::Duration;
+///
+/// let policy = ReconnectPolicy::new()
+///     .base(Duration::from_secs(2))
+///     .max(Duration::from_secs(60))
+///     .multiplier(2.0);
+///
+/// assert_eq!(policy.backoff(0), Duration::from_secs(2));
+/// assert_eq!(policy.backoff(1), Duration::from_secs(4));
+/// assert_eq!(policy.backoff(10), Duration::from_secs(60));
+/// ```
+///
+/// [`base`]: Self::base
+/// [`multiplier`]: Self::multiplier
+/// [`max`]: Self::max
+/// [`jitter`]: Self::jitter
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ReconnectPolicy {
+    base: Duration,
+    max: Duration,
+    multiplier: f64,
+    jitter: f64,
+}
+
+impl ReconnectPolicy {
+    /// Creates a new policy, with a base delay of 1 second which doubles on
+    /// every attempt up to 2 minutes, and up to 10% of jitter.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a policy which reconnects without any additional delay.
+    pub fn none() -> Self {
+        Self {
+            base: Duration::from_secs(0),
+            max: Duration::from_secs(0),
+            multiplier: 1.0,
+            jitter: 0.0,
+        }
+    }
+
+    /// Sets the delay before the first reconnect attempt.
+    pub fn base(mut self, base: Duration) -> Self {
+        self.base = base;
+
+        self
+    }
+
+    /// Sets the maximum delay between two reconnect attempts, excluding
+    /// jitter.
+    pub fn max(mut self, max: Duration) -> Self {
+        self.max = max;
+
+        self
+    }
+
+    /// Sets the factor the delay is multiplied by on every consecutive
+    /// reconnect attempt.
+    ///
+    /// **Note**: Factors lower than `1.0` are treated as `1.0`.
+    pub fn multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = multiplier.max(1.0);
+
+        self
+    }
+
+    /// Sets the maximum amount of jitter added to the delay, as a fraction of
+    /// the delay.
+    ///
+    /// **Note**: This is clamped between `0.0` and `1.0`.
+    pub fn jitter(mut self, jitter: f64) -> Self {
+        self.jitter = if jitter > 1.0 {
+            1.0
+        } else if jitter > 0.0 {
+            jitter
+        } else {
+            0.0
+        };
+
+        self
+    }
+
+    /// Returns the delay before the reconnect attempt following the given
+    /// amount of consecutive failed attempts, without jitter.
+    pub fn backoff(&self, attempts: u32) -> Duration {
+        let factor = self.multiplier.powi(attempts.min(i32::MAX as u32) as i32);
+        let secs = self.base.as_secs_f64() * factor;
+
+        if !secs.is_finite() || secs >= self.max.as_secs_f64() {
+            self.max
+        } else {
+            Duration::from_secs_f64(secs)
+        }
+    }
+
+    /// Returns the delay before the reconnect attempt following the given
+    /// amount of consecutive failed attempts, with jitter.
+    pub fn delay(&self, attempts: u32) -> Duration {
+        let backoff = self.backoff(attempts);
+
+        if self.jitter == 0.0 {
+            return backoff;
+        }
+
+        // A full random number generator isn't needed to spread the
+        // reconnects of shards apart.
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.subsec_nanos());
+        let random = f64::from(nanos) / 1_000_000_000.0;
+
+        backoff + backoff.mul_f64(self.jitter * random)
+    }
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            base: Duration::from_secs(1),
+            max: Duration::from_secs(120),
+            multiplier: 2.0,
+            jitter: 0.1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ReconnectPolicy;
+    use std::time::Duration;
+
+    #[test]
+    fn backoff_grows_and_caps() {
+        let policy = ReconnectPolicy::new()
+            .base(Duration::from_millis(500))
+            .max(Duration::from_secs(10))
+            .multiplier(3.0)
+            .jitter(0.5);
+
+        let sequence = (0..6).map(|attempts| policy.backoff(attempts)).collect::<Vec<_>>();
+
+        assert_eq!(sequence, vec![
+            Duration::from_millis(500),
+            Duration::from_millis(1500),
+            Duration::from_millis(4500),
+            Duration::from_secs(10),
+            Duration::from_secs(10),
+            Duration::from_secs(10),
+        ]);
+        assert_eq!(policy.backoff(u32::MAX), Duration::from_secs(10));
+
+        for attempts in 0..6 {
+            let delay = policy.delay(attempts);
+            let backoff = policy.backoff(attempts);
+
+            assert!(delay >= backoff);
+            assert!(delay <= backoff + backoff.mul_f64(0.5));
+        }
+    }
+
+    #[test]
+    fn no_backoff() {
+        let policy = ReconnectPolicy::none();
+
+        assert_eq!(policy.delay(0), Duration::from_secs(0));
+        assert_eq!(policy.delay(20), Duration::from_secs(0));
+    }
+}
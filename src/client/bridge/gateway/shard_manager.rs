@@ -1,6 +1,6 @@
 use crate::internal::prelude::*;
 use crate::CacheAndHttp;
-use tokio::time::timeout;
+use tokio::time::{timeout, Duration};
 use tokio::sync::{Mutex, RwLock};
 use std::{
     collections::{HashMap, VecDeque},
@@ -11,6 +11,7 @@ use futures::StreamExt;
 use crate::client::{EventHandler, RawEventHandler};
 use super::{
     GatewayIntents,
+    ReconnectPolicy,
     ShardId,
     ShardManagerMessage,
     ShardManagerMonitor,
@@ -109,16 +110,32 @@ pub struct ShardManager {
     shard_total: u64,
     shard_queuer: Sender<ShardQueuerMessage>,
     shard_shutdown: Receiver<ShardId>,
+    reconnect_policy: ReconnectPolicy,
+    reconnect_attempts: Arc<Mutex<HashMap<ShardId, u32>>>,
 }
 
 impl ShardManager {
     /// Creates a new shard manager, returning both the manager and a monitor
     /// for usage in a separate thread.
+    ///
+    /// The shards are connected with the default [`ShardConnectionOptions`].
     pub async fn new(opt: ShardManagerOptions<'_>) -> (Arc<Mutex<Self>>, ShardManagerMonitor) {
+        Self::new_with_connection_options(opt, ShardConnectionOptions::default()).await
+    }
+
+    /// Creates a new shard manager like [`new`], connecting its shards with
+    /// the given [`ShardConnectionOptions`].
+    ///
+    /// [`new`]: Self::new
+    pub async fn new_with_connection_options(
+        opt: ShardManagerOptions<'_>,
+        connection: ShardConnectionOptions,
+    ) -> (Arc<Mutex<Self>>, ShardManagerMonitor) {
         let (thread_tx, thread_rx) = mpsc::unbounded();
         let (shard_queue_tx, shard_queue_rx) = mpsc::unbounded();
 
         let runners = Arc::new(Mutex::new(HashMap::new()));
+        let reconnect_attempts = Arc::new(Mutex::new(HashMap::new()));
         let (shutdown_send, shutdown_recv) = mpsc::unbounded();
 
         let mut shard_queuer = ShardQueuer {
@@ -130,6 +147,7 @@ impl ShardManager {
             last_start: None,
             manager_tx: thread_tx.clone(),
             queue: VecDeque::new(),
+            ready_at: HashMap::new(),
             runners: Arc::clone(&runners),
             rx: shard_queue_rx,
             #[cfg(feature = "voice")]
@@ -137,6 +155,9 @@ impl ShardManager {
             ws_url: Arc::clone(opt.ws_url),
             cache_and_http: Arc::clone(&opt.cache_and_http),
             intents: opt.intents,
+            reconnect_policy: connection.reconnect_policy,
+            reconnect_attempts: Arc::clone(&reconnect_attempts),
+            transport_compression: connection.transport_compression,
         };
 
         tokio::spawn(async move {
@@ -151,6 +172,8 @@ impl ShardManager {
             shard_total: opt.shard_total,
             shard_shutdown: shutdown_recv,
             runners,
+            reconnect_policy: connection.reconnect_policy,
+            reconnect_attempts,
         }));
 
         (Arc::clone(&manager), ShardManagerMonitor {
@@ -173,10 +196,21 @@ impl ShardManager {
     ///
     /// This will communicate shard boots with the [`ShardQueuer`] so that they
     /// are properly queued.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ClientError::InvalidShardRange`] if the shards to
+    /// initialize do not lie within the total number of shards.
+    ///
+    /// [`ClientError::InvalidShardRange`]: crate::client::ClientError::InvalidShardRange
     #[instrument(skip(self))]
     pub fn initialize(&mut self) -> Result<()> {
         let shard_to = self.shard_index + self.shard_init;
 
+        if shard_to > self.shard_total {
+            return Err(Error::Client(ClientError::InvalidShardRange));
+        }
+
         for shard_id in self.shard_index..shard_to {
             let shard_total = self.shard_total;
 
@@ -245,6 +279,58 @@ impl ShardManager {
         self.boot([shard_id, ShardId(shard_total)]);
     }
 
+    /// Returns the gateway latency of a shard, which is the time between the
+    /// last heartbeat it sent and its acknowledgement.
+    ///
+    /// Returns `None` if the shard is not running, or if none of its
+    /// heartbeats have been acknowledged yet.
+    ///
+    /// # Examples
+    ///
+    /// Print the latency of the first shard:
+    ///
+    /// ```rust,no_run
+    /// use serenity::client::bridge::gateway::ShardId;
+    /// use serenity::client::{Client, EventHandler};
+    ///
+    /// struct Handler;
+    ///
+    /// impl EventHandler for Handler { }
+    ///
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let token = std::env::var("DISCORD_TOKEN")?;
+    /// let client = Client::builder(&token).event_handler(Handler).await?;
+    ///
+    /// if let Some(latency) = client.shard_manager.lock().await.latency(ShardId(0)).await {
+    ///     println!("Shard 0 latency: {:?}", latency);
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub async fn latency(&self, shard_id: ShardId) -> Option<Duration> {
+        self.runners.lock().await
+            .get(&shard_id)
+            .and_then(ShardRunnerInfo::latency)
+    }
+
+    /// Returns the delay the shard will wait for before its next restart,
+    /// according to the [`ReconnectPolicy`], excluding jitter.
+    ///
+    /// Returns `None` if the shard was never started.
+    pub async fn reconnect_backoff(&self, shard_id: ShardId) -> Option<Duration> {
+        self.reconnect_attempts.lock().await
+            .get(&shard_id)
+            .map(|&attempts| self.reconnect_policy.backoff(attempts))
+    }
+
+    /// Resets the reconnect backoff of a shard, after it successfully
+    /// connected to the gateway.
+    pub async fn reset_reconnect_backoff(&self, shard_id: ShardId) {
+        if let Some(attempts) = self.reconnect_attempts.lock().await.get_mut(&shard_id) {
+            *attempts = 0;
+        }
+    }
+
     /// Returns the [`ShardId`]s of the shards that have been instantiated and
     /// currently have a valid [`ShardRunner`].
     ///
@@ -291,7 +377,10 @@ impl ShardManager {
     }
 
     /// Sends a shutdown message for all shards that the manager is responsible
-    /// for that are still known to be running.
+    /// for that are still known to be running, and stops the manager.
+    ///
+    /// Each shard closes its connection with the normal closure code `1000`,
+    /// and is waited for up to 5 seconds.
     ///
     /// If you only need to shutdown a select number of shards, prefer looping
     /// over the [`shutdown`] method.
@@ -299,17 +388,11 @@ impl ShardManager {
     /// [`shutdown`]: Self::shutdown
     #[instrument(skip(self))]
     pub async fn shutdown_all(&mut self) {
-        let keys = {
-            let runners = self.runners.lock().await;
+        let keys = self.runners.lock().await.keys().cloned().collect::<Vec<_>>();
 
-            if runners.is_empty() {
-                return;
-            }
-
-            runners.keys().cloned().collect::<Vec<_>>()
-        };
-
-        info!("Shutting down all shards");
+        if !keys.is_empty() {
+            info!("Shutting down all shards");
+        }
 
         for shard_id in keys {
             self.shutdown(shard_id, 1000).await;
@@ -356,3 +439,107 @@ pub struct ShardManagerOptions<'a> {
     pub cache_and_http: &'a Arc<CacheAndHttp>,
     pub intents: GatewayIntents,
 }
+
+/// Settings for how the shards of a [`ShardManager`] connect to the gateway.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity::client::bridge::gateway::{ReconnectPolicy, ShardConnectionOptions};
+///
+/// let options = ShardConnectionOptions::default()
+///     .reconnect_policy(ReconnectPolicy::none())
+///     .transport_compression(true);
+///
+/// assert_eq!(options.reconnect_policy, ReconnectPolicy::none());
+/// assert!(options.transport_compression);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct ShardConnectionOptions {
+    /// The policy deciding how long to wait before restarting a shard.
+    pub reconnect_policy: ReconnectPolicy,
+    /// Whether shards use the `zlib-stream` transport compression.
+    pub transport_compression: bool,
+}
+
+impl ShardConnectionOptions {
+    /// Sets the policy deciding how long shards wait before reconnecting to
+    /// the gateway after losing their connection.
+    ///
+    /// Defaults to [`ReconnectPolicy::default`].
+    pub fn reconnect_policy(mut self, reconnect_policy: ReconnectPolicy) -> Self {
+        self.reconnect_policy = reconnect_policy;
+
+        self
+    }
+
+    /// Sets whether shards use the `zlib-stream` transport compression, which
+    /// compresses the whole gateway connection rather than only its large
+    /// payloads.
+    ///
+    /// Defaults to `false`.
+    pub fn transport_compression(mut self, transport_compression: bool) -> Self {
+        self.transport_compression = transport_compression;
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ShardManager, ShardQueuerMessage};
+    use crate::client::bridge::gateway::{ReconnectPolicy, ShardId};
+    use crate::client::ClientError;
+    use crate::Error;
+    use futures::channel::mpsc::{self, UnboundedReceiver};
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use tokio::sync::Mutex;
+
+    fn manager(index: u64, init: u64, total: u64) -> (ShardManager, UnboundedReceiver<ShardQueuerMessage>) {
+        let (monitor_tx, _) = mpsc::unbounded();
+        let (shard_queuer, queuer_rx) = mpsc::unbounded();
+        let (_, shard_shutdown) = mpsc::unbounded();
+
+        let manager = ShardManager {
+            monitor_tx,
+            runners: Arc::new(Mutex::new(HashMap::new())),
+            shard_index: index,
+            shard_init: init,
+            shard_total: total,
+            shard_queuer,
+            shard_shutdown,
+            reconnect_policy: ReconnectPolicy::none(),
+            reconnect_attempts: Arc::new(Mutex::new(HashMap::new())),
+        };
+
+        (manager, queuer_rx)
+    }
+
+    #[test]
+    fn initialize_shard_range() {
+        let (mut manager, mut queuer_rx) = manager(4, 4, 16);
+        manager.initialize().unwrap();
+
+        let mut started = Vec::new();
+        while let Ok(msg) = queuer_rx.try_recv() {
+            if let ShardQueuerMessage::Start(id, total) = msg {
+                started.push([id, total]);
+            }
+        }
+
+        assert_eq!(started, (4..8).map(|id| [ShardId(id), ShardId(16)]).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn initialize_out_of_range() {
+        let (mut manager, mut queuer_rx) = manager(12, 8, 16);
+
+        match manager.initialize() {
+            Err(Error::Client(ClientError::InvalidShardRange)) => {},
+            other => panic!("unexpected result: {:?}", other),
+        }
+        assert!(!matches!(queuer_rx.try_recv(), Ok(ShardQueuerMessage::Start(..))));
+    }
+}
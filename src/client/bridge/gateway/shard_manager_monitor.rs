@@ -2,6 +2,7 @@ use tokio::sync::Mutex;
 use std::sync::Arc;
 use super::{ShardManager, ShardManagerMessage};
 use crate::client::bridge::gateway::ShardId;
+use crate::gateway::ConnectionStage;
 use tracing::{debug, warn, instrument};
 use futures::{
     StreamExt,
@@ -71,6 +72,10 @@ impl ShardManagerMonitor {
                         runner.latency = latency;
                         runner.stage = stage;
                     }
+
+                    if stage == ConnectionStage::Connected {
+                        manager.reset_reconnect_backoff(id).await;
+                    }
                 }
                 ShardManagerMessage::Shutdown(shard_id, code) => {
                     self.manager.lock().await.shutdown(shard_id, code).await;
@@ -4,7 +4,7 @@ use super::{ShardClientMessage, ShardRunnerMessage, ChunkGuildFilter};
 use futures::channel::mpsc::{UnboundedSender as Sender, TrySendError};
 use async_tungstenite::tungstenite::Message;
 #[cfg(feature = "collector")]
-use crate::collector::{ReactionFilter, MessageFilter};
+use crate::collector::{ComponentInteractionFilter, ReactionFilter, MessageFilter};
 
 /// A lightweight wrapper around an mpsc sender.
 ///
@@ -171,6 +171,53 @@ impl ShardMessenger {
         let _ = self.send_to_shard(ShardRunnerMessage::SetPresence(status, activity));
     }
 
+    /// Sets the user's full presence information, with multiple activities.
+    ///
+    /// # Examples
+    ///
+    /// Set the current user as watching a guild while streaming on Twitch,
+    /// and being online:
+    ///
+    /// ```rust,no_run
+    /// # use futures::channel::mpsc;
+    /// # use serenity::client::bridge::gateway::ShardMessenger;
+    /// #
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// #     let (tx, _rx) = mpsc::unbounded();
+    /// #     let shard = ShardMessenger::new(tx);
+    /// #
+    /// use serenity::model::gateway::Activity;
+    /// use serenity::model::user::OnlineStatus;
+    ///
+    /// let activities = vec![
+    ///     Activity::watching("over the guild"),
+    ///     Activity::streaming("Speedruns", "https://twitch.tv/serenity"),
+    /// ];
+    /// shard.set_activities(activities, OnlineStatus::Online)?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidStreamUrl`] if a streaming activity does
+    /// not have a Twitch or YouTube URL. The presence is left unchanged.
+    ///
+    /// [`ModelError::InvalidStreamUrl`]: crate::model::ModelError::InvalidStreamUrl
+    pub fn set_activities(&self, activities: Vec<Activity>, mut status: OnlineStatus) -> crate::Result<()> {
+        for activity in &activities {
+            activity.check_stream_url()?;
+        }
+
+        if status == OnlineStatus::Offline {
+            status = OnlineStatus::Invisible;
+        }
+
+        let _ = self.send_to_shard(ShardRunnerMessage::SetActivities(status, activities));
+
+        Ok(())
+    }
+
     /// Sets the user's current online status.
     ///
     /// Note that [`Offline`] is not a valid online status, so it is
@@ -250,6 +297,12 @@ impl ShardMessenger {
     pub fn set_reaction_filter(&self, collector: ReactionFilter) {
         let _ = self.send_to_shard(ShardRunnerMessage::SetReactionFilter(collector));
     }
+
+    /// Sets a new filter for a component interaction collector.
+    #[cfg(feature = "collector")]
+    pub fn set_component_interaction_filter(&self, collector: ComponentInteractionFilter) {
+        let _ = self.send_to_shard(ShardRunnerMessage::SetComponentInteractionFilter(collector));
+    }
 }
 
 impl AsRef<ShardMessenger> for ShardMessenger {
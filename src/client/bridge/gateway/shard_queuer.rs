@@ -14,6 +14,7 @@ use tokio::time::{delay_for, timeout, Duration, Instant};
 use crate::client::{EventHandler, RawEventHandler};
 use super::{
     GatewayIntents,
+    ReconnectPolicy,
     ShardId,
     ShardClientMessage,
     ShardManagerMessage,
@@ -70,8 +71,14 @@ pub struct ShardQueuer {
     pub manager_tx: Sender<ShardManagerMessage>,
     /// The shards that are queued for booting.
     ///
-    /// This will typically be filled with previously failed boots.
+    /// This will typically be filled with previously failed boots, and shards
+    /// waiting for their reconnect backoff.
     pub queue: VecDeque<(u64, u64)>,
+    /// The instants at which restarting shards may be started again, according
+    /// to the [`reconnect_policy`].
+    ///
+    /// [`reconnect_policy`]: Self::reconnect_policy
+    pub ready_at: HashMap<ShardId, Instant>,
     /// A copy of the map of shard runners.
     pub runners: Arc<Mutex<HashMap<ShardId, ShardRunnerInfo>>>,
     /// A receiver channel for the shard queuer to be told to start shards.
@@ -83,6 +90,15 @@ pub struct ShardQueuer {
     pub ws_url: Arc<Mutex<String>>,
     pub cache_and_http: Arc<CacheAndHttp>,
     pub intents: GatewayIntents,
+    /// The policy deciding how long to wait before restarting a shard.
+    pub reconnect_policy: ReconnectPolicy,
+    /// The number of consecutive restarts of each shard since it last
+    /// successfully connected, shared with the [`ShardManager`].
+    ///
+    /// [`ShardManager`]: super::ShardManager
+    pub reconnect_attempts: Arc<Mutex<HashMap<ShardId, u32>>>,
+    /// Whether shards use the `zlib-stream` transport compression.
+    pub transport_compression: bool,
 }
 
 impl ShardQueuer {
@@ -113,7 +129,7 @@ impl ShardQueuer {
         const TIMEOUT: Duration = Duration::from_secs(WAIT_BETWEEN_BOOTS_IN_SECONDS);
 
         loop {
-            match timeout(TIMEOUT, self.rx.next()).await {
+            match timeout(self.next_timeout(TIMEOUT), self.rx.next()).await {
                 Ok(Some(ShardQueuerMessage::Shutdown)) => {
                     debug!("[Shard Queuer] Received to shutdown.");
                     self.shutdown_runners().await;
@@ -126,11 +142,12 @@ impl ShardQueuer {
                 },
                 Ok(Some(ShardQueuerMessage::Start(id, total))) => {
                     debug!("[Shard Queuer] Received to start shard {} of {}.", id.0, total.0);
+                    self.schedule_reconnect_backoff(id.0).await;
                     self.checked_start(id.0, total.0).await;
                 },
                 Ok(None) => break,
                 Err(_) => {
-                    if let Some((id, total)) = self.queue.pop_front() {
+                    if let Some((id, total)) = self.pop_ready() {
                         self.checked_start(id, total).await;
                     }
                 },
@@ -162,6 +179,17 @@ impl ShardQueuer {
     #[instrument(skip(self))]
     async fn checked_start(&mut self, id: u64, total: u64) {
         debug!("[Shard Queuer] Checked start for shard {} out of {}", id, total);
+
+        if let Some(&ready_at) = self.ready_at.get(&ShardId(id)) {
+            if ready_at > Instant::now() {
+                debug!("[Shard Queuer] Deferring start of shard {} until its backoff passed", id);
+                self.queue.push_back((id, total));
+
+                return;
+            }
+        }
+
+        self.ready_at.remove(&ShardId(id));
         self.check_last_start().await;
 
         if let Err(why) = self.start(id, total).await {
@@ -172,17 +200,67 @@ impl ShardQueuer {
         }
 
         self.last_start = Some(Instant::now());
+
+        self.reconnect_attempts.lock().await
+            .entry(ShardId(id))
+            .and_modify(|attempts| *attempts = attempts.saturating_add(1))
+            .or_insert(0);
+    }
+
+    // Shards which were started before are being restarted, and must wait
+    // for their backoff according to the reconnect policy. Rather than
+    // sleeping here, which would hold up every other shard, the shard is
+    // given an instant before which it is kept in the queue.
+    #[instrument(skip(self))]
+    async fn schedule_reconnect_backoff(&mut self, id: u64) {
+        let attempts = match self.reconnect_attempts.lock().await.get(&ShardId(id)) {
+            Some(&attempts) => attempts,
+            None => return,
+        };
+
+        let delay = self.reconnect_policy.delay(attempts);
+
+        if delay > Duration::from_secs(0) {
+            debug!("[Shard Queuer] Waiting {:?} before restarting shard {}", delay, id);
+
+            self.ready_at.insert(ShardId(id), Instant::now() + delay);
+        }
+    }
+
+    /// Removes the first queued shard which is not waiting for its reconnect
+    /// backoff.
+    fn pop_ready(&mut self) -> Option<(u64, u64)> {
+        let now = Instant::now();
+        let ready_at = &self.ready_at;
+        let index = self.queue.iter().position(|(id, _)| match ready_at.get(&ShardId(*id)) {
+            Some(&instant) => instant <= now,
+            None => true,
+        })?;
+
+        self.queue.remove(index)
+    }
+
+    /// The duration to wait for messages before checking the queue again,
+    /// which is shortened when a queued shard's backoff ends sooner.
+    fn next_timeout(&self, max: Duration) -> Duration {
+        let now = Instant::now();
+
+        self.queue.iter()
+            .filter_map(|(id, _)| self.ready_at.get(&ShardId(*id)))
+            .map(|&instant| instant.saturating_duration_since(now))
+            .fold(max, std::cmp::min)
     }
 
     #[instrument(skip(self))]
     async fn start(&mut self, shard_id: u64, shard_total: u64) -> Result<()> {
         let shard_info = [shard_id, shard_total];
 
-        let shard = Shard::new(
+        let shard = Shard::new_with_compression(
             Arc::clone(&self.ws_url),
             &self.cache_and_http.http.token,
             shard_info,
             self.intents,
+            self.transport_compression,
         ).await?;
 
         let mut runner = ShardRunner::new(ShardRunnerOptions {
@@ -258,3 +336,72 @@ impl ShardQueuer {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::ShardQueuer;
+    use crate::client::bridge::gateway::{GatewayIntents, ReconnectPolicy, ShardId};
+    #[cfg(feature = "framework")]
+    use crate::internal::test_utils;
+    use crate::CacheAndHttp;
+    use futures::channel::mpsc;
+    use std::collections::{HashMap, VecDeque};
+    use std::sync::Arc;
+    use tokio::sync::{Mutex, RwLock};
+    use tokio::time::Duration;
+    use typemap_rev::TypeMap;
+
+    fn queuer(reconnect_policy: ReconnectPolicy) -> ShardQueuer {
+        let (manager_tx, _) = mpsc::unbounded();
+        let (_, rx) = mpsc::unbounded();
+
+        ShardQueuer {
+            data: Arc::new(RwLock::new(TypeMap::new())),
+            event_handler: None,
+            raw_event_handler: None,
+            #[cfg(feature = "framework")]
+            framework: Arc::new(Box::new(test_utils::NoFramework)),
+            last_start: None,
+            manager_tx,
+            queue: VecDeque::new(),
+            ready_at: HashMap::new(),
+            runners: Arc::new(Mutex::new(HashMap::new())),
+            rx,
+            #[cfg(feature = "voice")]
+            voice_manager: None,
+            ws_url: Arc::new(Mutex::new("ws://127.0.0.1:1".to_string())),
+            cache_and_http: Arc::new(CacheAndHttp::default()),
+            intents: GatewayIntents::non_privileged(),
+            reconnect_policy,
+            reconnect_attempts: Arc::new(Mutex::new(HashMap::new())),
+            transport_compression: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn backoff_does_not_block_other_shards() {
+        let policy = ReconnectPolicy::new()
+            .base(Duration::from_secs(2))
+            .max(Duration::from_secs(2))
+            .jitter(0.0);
+        let mut queuer = queuer(policy);
+        queuer.reconnect_attempts.lock().await.insert(ShardId(0), 1);
+
+        // The restarting shard is deferred rather than waited for.
+        queuer.schedule_reconnect_backoff(0).await;
+        queuer.checked_start(0, 2).await;
+        assert_eq!(queuer.queue, vec![(0, 2)]);
+        assert!(queuer.runners.lock().await.is_empty());
+        assert!(queuer.pop_ready().is_none());
+
+        let timeout = queuer.next_timeout(Duration::from_secs(5));
+        assert!(timeout > Duration::from_secs(1) && timeout <= Duration::from_secs(2));
+
+        // A shard queued after it may start in the meantime.
+        queuer.queue.push_back((1, 2));
+        assert_eq!(queuer.pop_ready(), Some((1, 2)));
+
+        queuer.ready_at.insert(ShardId(0), tokio::time::Instant::now());
+        assert_eq!(queuer.pop_ready(), Some((0, 2)));
+    }
+}
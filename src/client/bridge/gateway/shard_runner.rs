@@ -1,7 +1,7 @@
 use crate::gateway::{InterMessage, ReconnectType, Shard, ShardAction, GatewayError};
 use crate::internal::prelude::*;
-use crate::internal::ws_impl::{ReceiverExt, SenderExt};
-use crate::model::event::{Event, GatewayEvent};
+use crate::internal::ws_impl::SenderExt;
+use crate::model::event::{Event, GatewayEvent, UnknownEvent};
 use crate::CacheAndHttp;
 use tokio::sync::RwLock;
 use serde::Deserialize;
@@ -27,7 +27,9 @@ use crate::framework::Framework;
 #[cfg(feature = "voice")]
 use crate::client::bridge::voice::VoiceGatewayManager;
 #[cfg(feature = "collector")]
-use crate::collector::{MessageFilter, ReactionAction, ReactionFilter};
+use crate::collector::{ComponentInteractionFilter, MessageFilter, ReactionAction, ReactionFilter};
+#[cfg(feature = "collector")]
+use crate::model::interactions::Interaction;
 
 use tracing::{trace, error, debug, warn, info, instrument};
 
@@ -51,6 +53,8 @@ pub struct ShardRunner {
     message_filters: Vec<MessageFilter>,
     #[cfg(feature = "collector")]
     reaction_filters: Vec<ReactionFilter>,
+    #[cfg(feature = "collector")]
+    component_interaction_filters: Vec<ComponentInteractionFilter>,
 }
 
 impl ShardRunner {
@@ -75,6 +79,8 @@ impl ShardRunner {
             message_filters: Vec::new(),
             #[cfg(feature = "collector")]
             reaction_filters: Vec::new(),
+            #[cfg(feature = "collector")]
+            component_interaction_filters: Vec::new(),
         }
     }
 
@@ -202,6 +208,18 @@ impl ShardRunner {
             }
         }
 
+        // Avoid the clone if there is no component interaction filter.
+        if !self.component_interaction_filters.is_empty() {
+
+            if let Event::InteractionCreate(ref interaction_event) = &event {
+                if let Interaction::MessageComponent(ref interaction) = interaction_event.interaction {
+                    let interaction = Arc::new(interaction.clone());
+
+                    retain(&mut self.component_interaction_filters, |f| f.send_interaction(&interaction));
+                }
+            }
+        }
+
         // Avoid the clone if there is no reacton filter.
         if !self.reaction_filters.is_empty() {
             let reaction = Arc::new(match &event {
@@ -397,6 +415,12 @@ impl ShardRunner {
 
                     self.shard.update_presence().await.is_ok()
                 },
+                ShardClientMessage::Runner(ShardRunnerMessage::SetActivities(status, activities)) => {
+                    self.shard.set_activities(activities);
+                    self.shard.set_status(status);
+
+                    self.shard.update_presence().await.is_ok()
+                },
                 ShardClientMessage::Runner(ShardRunnerMessage::SetStatus(status)) => {
                     self.shard.set_status(status);
 
@@ -412,6 +436,12 @@ impl ShardRunner {
                 ShardClientMessage::Runner(ShardRunnerMessage::SetReactionFilter(collector)) => {
                     self.reaction_filters.push(collector);
 
+                    true
+                },
+                #[cfg(feature = "collector")]
+                ShardClientMessage::Runner(ShardRunnerMessage::SetComponentInteractionFilter(collector)) => {
+                    self.component_interaction_filters.push(collector);
+
                     true
                 },
             },
@@ -489,9 +519,26 @@ impl ShardRunner {
     /// present event was successful.
     #[instrument(skip(self))]
     async fn recv_event(&mut self) -> Result<(Option<Event>, Option<ShardAction>, bool)> {
-        let gw_event = match self.shard.client.recv_json().await {
-            Ok(Some(value)) => {
-                GatewayEvent::deserialize(value).map(Some).map_err(From::from)
+        let gw_event = match self.shard.recv_json().await {
+            // The payload is only borrowed, so that it can still be given to
+            // the raw event handler if it can't be parsed.
+            Ok(Some(value)) => match GatewayEvent::deserialize(&value) {
+                Ok(event) => Ok(Some(event)),
+                Err(why) => match self.raw_event_handler.as_ref().and_then(|_| unparsed_dispatch(value)) {
+                    Some(event) => {
+                        if let GatewayEvent::Dispatch(_, Event::Unknown(ref unknown)) = event {
+                            warn!(
+                                "[ShardRunner {:?}] Failed to parse {} event, providing it as unknown: {:?}",
+                                self.shard.shard_info(),
+                                unknown.kind,
+                                why,
+                            );
+                        }
+
+                        Ok(Some(event))
+                    },
+                    None => Err(From::from(why)),
+                },
             },
             Ok(None) => Ok(None),
             Err(Error::Tungstenite(TungsteniteError::Io(_))) => {
@@ -607,6 +654,23 @@ impl ShardRunner {
     }
 }
 
+/// Reads a dispatched event which failed to be parsed as an
+/// [`Event::Unknown`], holding its raw payload.
+fn unparsed_dispatch(mut value: Value) -> Option<GatewayEvent> {
+    if value.get("op").and_then(Value::as_u64) != Some(0) {
+        return None;
+    }
+
+    let seq = value.get("s").and_then(Value::as_u64)?;
+    let kind = value.get("t").and_then(Value::as_str)?.to_string();
+    let payload = value.get_mut("d")?.take();
+
+    Some(GatewayEvent::Dispatch(seq, Event::Unknown(UnknownEvent {
+        kind,
+        value: payload,
+    })))
+}
+
 /// Options to be passed to [`ShardRunner::new`].
 pub struct ShardRunnerOptions {
     pub data: Arc<RwLock<TypeMap>>,
@@ -620,3 +684,111 @@ pub struct ShardRunnerOptions {
     pub voice_manager: Option<Arc<dyn VoiceGatewayManager + Send + Sync>>,
     pub cache_and_http: Arc<CacheAndHttp>,
 }
+
+#[cfg(test)]
+mod test {
+    use super::unparsed_dispatch;
+    use crate::model::event::{Event, GatewayEvent};
+    use serde_json::json;
+
+    #[test]
+    fn unparsed_dispatch_keeps_payload() {
+        let value = json!({
+            "op": 0,
+            "s": 42,
+            "t": "GUILD_BAN_ADD",
+            "d": {"guild_id": "not an id"},
+        });
+
+        match unparsed_dispatch(value) {
+            Some(GatewayEvent::Dispatch(42, Event::Unknown(unknown))) => {
+                assert_eq!(unknown.kind, "GUILD_BAN_ADD");
+                assert_eq!(unknown.value, json!({"guild_id": "not an id"}));
+            },
+            other => panic!("unexpected event: {:?}", other),
+        }
+
+        assert!(unparsed_dispatch(json!({"op": 11})).is_none());
+    }
+
+    #[cfg(feature = "collector")]
+    #[tokio::test]
+    async fn dispatched_interaction_reaches_collector() {
+        use super::{ShardRunner, ShardRunnerOptions};
+        use crate::client::bridge::gateway::{GatewayIntents, ShardMessenger};
+        use crate::collector::ComponentInteractionCollectorBuilder;
+        use crate::gateway::Shard;
+        use crate::internal::test_utils;
+        use crate::CacheAndHttp;
+        use async_tungstenite::tungstenite::{self, Message};
+        use futures::channel::mpsc;
+        use futures::StreamExt;
+        use std::net::TcpListener;
+        use std::sync::Arc;
+        use tokio::sync::{Mutex, RwLock};
+        use typemap_rev::TypeMap;
+
+        // A gateway dispatching a single button interaction.
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let gateway = std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut socket = tungstenite::accept(stream).unwrap();
+            let payload = json!({
+                "op": 0,
+                "s": 1,
+                "t": "INTERACTION_CREATE",
+                "d": {
+                    "id": "1",
+                    "application_id": "2",
+                    "type": 3,
+                    "data": {"custom_id": "confirm", "component_type": 2},
+                    "message": test_utils::message_json(4, None),
+                    "channel_id": "4",
+                    "user": {"id": "5", "username": "ferris", "discriminator": "0001", "avatar": null},
+                    "token": "token",
+                    "version": 1,
+                },
+            });
+
+            socket.write_message(Message::Text(payload.to_string())).unwrap();
+
+            // Keep the connection open until the shard is dropped.
+            while socket.read_message().is_ok() {}
+        });
+
+        let shard = Shard::new(Arc::new(Mutex::new(url)), "token", [0, 1], GatewayIntents::empty()).await.unwrap();
+        let (manager_tx, _manager_rx) = mpsc::unbounded();
+        let mut runner = ShardRunner::new(ShardRunnerOptions {
+            data: Arc::new(RwLock::new(TypeMap::new())),
+            event_handler: None,
+            raw_event_handler: None,
+            #[cfg(feature = "framework")]
+            framework: Arc::new(Box::new(test_utils::NoFramework)),
+            manager_tx,
+            shard,
+            #[cfg(feature = "voice")]
+            voice_manager: None,
+            cache_and_http: Arc::new(CacheAndHttp::default()),
+        });
+
+        let messenger = ShardMessenger::new(runner.runner_tx());
+        let mut collector = ComponentInteractionCollectorBuilder::new(&messenger)
+            .custom_id("confirm")
+            .await;
+        assert!(runner.recv().await.unwrap());
+
+        let event = loop {
+            if let (Some(event), _, _) = runner.recv_event().await.unwrap() {
+                break event;
+            }
+        };
+        runner.handle_filters(&event);
+
+        let interaction = collector.next().await.unwrap();
+        assert_eq!(interaction.data.custom_id, "confirm");
+
+        drop(runner);
+        gateway.join().unwrap();
+    }
+}
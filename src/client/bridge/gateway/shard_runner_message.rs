@@ -5,7 +5,7 @@ use crate::model::{
 };
 
 #[cfg(feature = "collector")]
-use crate::collector::{MessageFilter, ReactionFilter};
+use crate::collector::{ComponentInteractionFilter, MessageFilter, ReactionFilter};
 use async_tungstenite::tungstenite::Message;
 
 #[derive(Clone, Debug)]
@@ -57,6 +57,9 @@ pub enum ShardRunnerMessage {
     /// Indicates that the client is to update the shard's presence in its
     /// entirity.
     SetPresence(OnlineStatus, Option<Activity>),
+    /// Indicates that the client is to update the shard's presence with
+    /// multiple activities.
+    SetActivities(OnlineStatus, Vec<Activity>),
     /// Indicates that the client is to update the shard's presence's status.
     SetStatus(OnlineStatus),
     /// Sends a new filter for messages to the shard.
@@ -65,4 +68,7 @@ pub enum ShardRunnerMessage {
     /// Sends a new filter for reactions to the shard.
     #[cfg(feature = "collector")]
     SetReactionFilter(ReactionFilter),
+    /// Sends a new filter for component interactions to the shard.
+    #[cfg(feature = "collector")]
+    SetComponentInteractionFilter(ComponentInteractionFilter),
 }
@@ -7,12 +7,13 @@ use std::sync::Arc;
 use tokio::sync::RwLock;
 use futures::channel::mpsc::UnboundedSender as Sender;
 use crate::http::Http;
+use crate::Result;
 use typemap_rev::TypeMap;
 
 #[cfg(feature = "cache")]
 pub use crate::cache::Cache;
 #[cfg(feature = "collector")]
-use crate::collector::{MessageFilter, ReactionFilter};
+use crate::collector::{ComponentInteractionFilter, MessageFilter, ReactionFilter};
 
 /// The context is a general utility struct provided on event dispatches, which
 /// helps with dealing with the current "context" of the event dispatch.
@@ -388,6 +389,57 @@ impl Context {
         self.shard.set_presence(activity, status);
     }
 
+    /// Sets the current user's presence with multiple activities, such as
+    /// watching something while streaming.
+    ///
+    /// # Examples
+    ///
+    /// Setting the current user as watching a guild, while streaming on
+    /// Twitch:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::*;
+    /// # use serenity::model::gateway::Ready;
+    /// #
+    /// struct Handler;
+    ///
+    /// #[serenity::async_trait]
+    /// impl EventHandler for Handler {
+    ///     async fn ready(&self, context: Context, _: Ready) {
+    ///         use serenity::model::gateway::Activity;
+    ///         use serenity::model::user::OnlineStatus;
+    ///
+    ///         let activities = vec![
+    ///             Activity::watching("over the guild"),
+    ///             Activity::streaming("Speedruns", "https://twitch.tv/serenity"),
+    ///         ];
+    ///
+    ///         if let Err(why) = context.set_activities(activities, OnlineStatus::Online).await {
+    ///             println!("Invalid activities: {:?}", why);
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut client = Client::builder("token").event_handler(Handler).await?;
+    ///
+    /// client.start().await?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidStreamUrl`] if a streaming activity does
+    /// not have a Twitch or YouTube URL.
+    ///
+    /// [`ModelError::InvalidStreamUrl`]: crate::model::ModelError::InvalidStreamUrl
+    #[cfg(feature = "gateway")]
+    #[inline]
+    pub async fn set_activities(&self, activities: Vec<Activity>, status: OnlineStatus) -> Result<()> {
+        self.shard.set_activities(activities, status)
+    }
+
     /// Sets a new `filter` for the shard to check if a message event shall be
     /// sent back to `filter`'s paired receiver.
     #[inline]
@@ -403,6 +455,14 @@ impl Context {
     pub async fn set_reaction_filter(&self, filter: ReactionFilter) {
         self.shard.set_reaction_filter(filter);
     }
+
+    /// Sets a new `filter` for the shard to check if a component interaction
+    /// event shall be sent back to `filter`'s paired receiver.
+    #[inline]
+    #[cfg(feature = "collector")]
+    pub async fn set_component_interaction_filter(&self, filter: ComponentInteractionFilter) {
+        self.shard.set_component_interaction_filter(filter);
+    }
 }
 
 impl AsRef<Http> for Context {
@@ -584,6 +584,13 @@ async fn handle_event(
                 }}
             });
         },
+        DispatchEvent::Model(Event::InteractionCreate(event)) => {
+            let event_handler = Arc::clone(event_handler);
+
+            tokio::spawn(async move {
+                event_handler.interaction_create(context, event.interaction).await;
+            });
+        },
         DispatchEvent::Model(Event::InviteCreate(event)) => {
             let event_handler = Arc::clone(event_handler);
 
@@ -680,6 +687,27 @@ async fn handle_event(
                 event_handler.resume(context, event).await;
             });
         },
+        DispatchEvent::Model(Event::StageInstanceCreate(event)) => {
+            let event_handler = Arc::clone(event_handler);
+
+            tokio::spawn(async move {
+                event_handler.stage_instance_create(context, event.stage_instance).await;
+            });
+        },
+        DispatchEvent::Model(Event::StageInstanceUpdate(event)) => {
+            let event_handler = Arc::clone(event_handler);
+
+            tokio::spawn(async move {
+                event_handler.stage_instance_update(context, event.stage_instance).await;
+            });
+        },
+        DispatchEvent::Model(Event::StageInstanceDelete(event)) => {
+            let event_handler = Arc::clone(event_handler);
+
+            tokio::spawn(async move {
+                event_handler.stage_instance_delete(context, event.stage_instance).await;
+            });
+        },
         DispatchEvent::Model(Event::TypingStart(event)) => {
             let event_handler = Arc::clone(event_handler);
 
@@ -734,3 +762,73 @@ async fn handle_event(
         },
     }
 }
+
+#[cfg(all(test, feature = "cache"))]
+mod test {
+    use super::{dispatch, DispatchEvent};
+    use crate::client::{Context, EventHandler, RawEventHandler};
+    #[cfg(feature = "framework")]
+    use crate::internal::test_utils;
+    use crate::model::event::{Event, GatewayEvent};
+    use crate::CacheAndHttp;
+
+    use async_trait::async_trait;
+    use futures::channel::mpsc;
+    use serde::Deserialize;
+    use serde_json::{json, Value};
+    use tokio::sync::RwLock;
+    use typemap_rev::TypeMap;
+
+    use std::sync::{Arc, Mutex};
+
+    struct Typed;
+
+    impl EventHandler for Typed {}
+
+    struct Recorder(Arc<Mutex<Vec<(String, Value)>>>);
+
+    #[async_trait]
+    impl RawEventHandler for Recorder {
+        async fn raw_event(&self, _: Context, event: Event) {
+            if let Event::Unknown(unknown) = event {
+                self.0.lock().unwrap().push((unknown.kind, unknown.value));
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn raw_handler_receives_unknown_event() {
+        let payload = json!({"guild_id": "81384788765712384", "feature": "new"});
+        let event = GatewayEvent::deserialize(json!({
+            "op": 0,
+            "s": 3,
+            "t": "SOMETHING_NEW",
+            "d": payload,
+        })).unwrap();
+        let event = match event {
+            GatewayEvent::Dispatch(_, event) => event,
+            other => panic!("unexpected event: {:?}", other),
+        };
+
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let event_handler: Option<Arc<dyn EventHandler>> = Some(Arc::new(Typed));
+        let raw_event_handler: Option<Arc<dyn RawEventHandler>> = Some(Arc::new(Recorder(Arc::clone(&received))));
+        #[cfg(feature = "framework")]
+        let framework: Arc<Box<dyn crate::framework::Framework + Send + Sync>> = Arc::new(Box::new(test_utils::NoFramework));
+        let (tx, _rx) = mpsc::unbounded();
+
+        dispatch(
+            DispatchEvent::Model(event),
+            #[cfg(feature = "framework")]
+            &framework,
+            &Arc::new(RwLock::new(TypeMap::new())),
+            &event_handler,
+            &raw_event_handler,
+            &tx,
+            0,
+            Arc::new(CacheAndHttp::default()),
+        ).await;
+
+        assert_eq!(*received.lock().unwrap(), vec![("SOMETHING_NEW".to_string(), payload)]);
+    }
+}
@@ -31,6 +31,9 @@ pub enum Error {
     /// When all shards that the client is responsible for have shutdown with an
     /// error.
     Shutdown,
+    /// When the range of shards to start is empty, or does not lie within the
+    /// total number of shards.
+    InvalidShardRange,
 }
 
 impl Display for Error {
@@ -39,6 +42,7 @@ impl Display for Error {
             Error::InvalidToken => f.write_str("The provided token was invalid"),
             Error::ShardBootFailure => f.write_str("Failed to (re-)boot a shard"),
             Error::Shutdown => f.write_str("The clients shards shutdown"),
+            Error::InvalidShardRange => f.write_str("The shard range is out of the total shards"),
         }
     }
 }
@@ -49,6 +53,7 @@ impl StdError for Error {
             Error::InvalidToken => "The provided token was invalid",
             Error::ShardBootFailure => "Failed to (re-)boot a shard",
             Error::Shutdown => "The clients shards shutdown",
+            Error::InvalidShardRange => "The shard range is out of the total shards",
         }
     }
 }
@@ -1,9 +1,16 @@
 use crate::model::prelude::*;
 use serde_json::Value;
 use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use typemap_rev::TypeMap;
 use super::context::Context;
 use crate::client::bridge::gateway::event::*;
 use async_trait::async_trait;
+use futures::future::{self, FutureExt};
+use std::future::Future;
+use std::panic::AssertUnwindSafe;
+use tracing::warn;
 
 /// The core trait for handling events by serenity.
 #[async_trait]
@@ -218,6 +225,12 @@ pub trait EventHandler: Send + Sync {
     #[cfg(not(feature = "cache"))]
     async fn guild_update(&self, _ctx: Context, _new_but_incomplete_data: PartialGuild) {}
 
+    /// Dispatched when an interaction is created, such as when a user uses an
+    /// application command or clicks a button.
+    ///
+    /// Provides the interaction, which must be responded to within 3 seconds.
+    async fn interaction_create(&self, _ctx: Context, _interaction: Interaction) {}
+
     /// Dispatched when a invite is created.
     ///
     /// Provides data about the invite.
@@ -296,12 +309,33 @@ pub trait EventHandler: Send + Sync {
     /// Provides the context of the shard and the event information about the update.
     async fn shard_stage_update(&self, _ctx: Context, _: ShardStageUpdateEvent) {}
 
+    /// Dispatched when the client is shut down by the signal given to
+    /// [`Client::start_with_shutdown`], once all shards have stopped.
+    ///
+    /// Provides the client's data, allowing it to be saved before exiting.
+    ///
+    /// [`Client::start_with_shutdown`]: crate::Client::start_with_shutdown
+    async fn shutdown(&self, _data: Arc<RwLock<TypeMap>>) {}
+
+    /// Dispatched when a stage instance is created, starting a live stage.
+    async fn stage_instance_create(&self, _ctx: Context, _stage_instance: StageInstance) {}
+
+    /// Dispatched when the topic or privacy level of a stage instance is
+    /// updated.
+    async fn stage_instance_update(&self, _ctx: Context, _stage_instance: StageInstance) {}
+
+    /// Dispatched when a stage instance is deleted, ending its live stage.
+    async fn stage_instance_delete(&self, _ctx: Context, _stage_instance: StageInstance) {}
+
     /// Dispatched when a user starts typing.
     async fn typing_start(&self, _ctx: Context, _: TypingStartEvent) {}
 
     /// Dispatched when an unknown event was sent from discord.
     ///
     /// Provides the event's name and its unparsed data.
+    ///
+    /// When a [`RawEventHandler`] is set, this is also dispatched for known
+    /// events which failed to parse.
     async fn unknown(&self, _ctx: Context, _name: String, _raw: Value) {}
 
     /// Dispatched when the bot's data is updated.
@@ -342,8 +376,257 @@ pub trait EventHandler: Send + Sync {
 }
 
 /// This core trait for handling raw events
+///
+/// It can be used alongside an [`EventHandler`], and receives every event
+/// dispatched by the gateway before the [`EventHandler`] does.
+///
+/// Events which serenity does not model yet, as well as the events which could
+/// not be parsed, are provided as an [`Event::Unknown`] holding their name and
+/// their raw payload. This allows handling new events without waiting for a
+/// release.
+///
+/// **Note**: A known event which fails to parse is logged at the warn level
+/// and is dispatched as an [`Event::Unknown`], so an [`EventHandler`] receives
+/// it through [`EventHandler::unknown`]. This only happens when a raw event
+/// handler is set; otherwise the parsing error is returned by the shard.
 #[async_trait]
 pub trait RawEventHandler: Send + Sync {
     /// Dispatched when any event occurs
     async fn raw_event(&self, _ctx: Context, _ev: Event) {}
 }
+
+/// Multiple [`EventHandler`]s registered with [`ClientBuilder::event_handler`],
+/// which every event is dispatched to.
+///
+/// A panic in one of the handlers is caught and logged, without preventing the
+/// event from reaching the others.
+///
+/// [`ClientBuilder::event_handler`]: super::ClientBuilder::event_handler
+pub(crate) struct EventHandlers {
+    pub(crate) handlers: Vec<Arc<dyn EventHandler>>,
+    /// Whether each handler waits for the previous one to finish handling the
+    /// event, rather than all of them handling it concurrently.
+    pub(crate) sequential: bool,
+}
+
+impl EventHandlers {
+    async fn run<F, Fut>(&self, name: &str, f: F)
+    where
+        F: Fn(Arc<dyn EventHandler>) -> Fut + Send + Sync,
+        Fut: Future<Output = ()> + Send,
+    {
+        let run_handler = |handler: &Arc<dyn EventHandler>| {
+            let handling = AssertUnwindSafe(f(Arc::clone(handler))).catch_unwind();
+
+            async move {
+                if handling.await.is_err() {
+                    warn!("An event handler panicked while handling `{}`", name);
+                }
+            }
+        };
+
+        if self.sequential {
+            for handler in &self.handlers {
+                run_handler(handler).await;
+            }
+        } else {
+            future::join_all(self.handlers.iter().map(run_handler)).await;
+        }
+    }
+}
+
+macro_rules! dispatch_to_all {
+    ($($(#[$attr:meta])* fn $name:ident($($arg:ident: $ty:ty),*);)*) => {
+        #[async_trait]
+        impl EventHandler for EventHandlers {
+            $(
+                $(#[$attr])*
+                async fn $name(&self, $($arg: $ty),*) {
+                    self.run(stringify!($name), |handler| {
+                        $(let $arg = Clone::clone(&$arg);)*
+
+                        async move { handler.$name($($arg),*).await }
+                    }).await
+                }
+            )*
+        }
+    };
+}
+
+dispatch_to_all! {
+    #[cfg(feature = "cache")]
+    fn cache_ready(ctx: Context, guilds: Vec<GuildId>);
+    fn channel_create(ctx: Context, channel: &GuildChannel);
+    fn category_create(ctx: Context, category: &ChannelCategory);
+    fn category_delete(ctx: Context, category: &ChannelCategory);
+    fn channel_delete(ctx: Context, channel: &GuildChannel);
+    fn channel_pins_update(ctx: Context, pin: ChannelPinsUpdateEvent);
+    fn channel_recipient_addition(ctx: Context, group_id: ChannelId, user: User);
+    fn channel_recipient_removal(ctx: Context, group_id: ChannelId, user: User);
+    #[cfg(feature = "cache")]
+    fn channel_update(ctx: Context, old: Option<Channel>, new: Channel);
+    #[cfg(not(feature = "cache"))]
+    fn channel_update(ctx: Context, new_data: Channel);
+    fn guild_ban_addition(ctx: Context, guild_id: GuildId, banned_user: User);
+    fn guild_ban_removal(ctx: Context, guild_id: GuildId, unbanned_user: User);
+    #[cfg(feature = "cache")]
+    fn guild_create(ctx: Context, guild: Guild, is_new: bool);
+    #[cfg(not(feature = "cache"))]
+    fn guild_create(ctx: Context, guild: Guild);
+    #[cfg(feature = "cache")]
+    fn guild_delete(ctx: Context, incomplete: GuildUnavailable, full: Option<Guild>);
+    #[cfg(not(feature = "cache"))]
+    fn guild_delete(ctx: Context, incomplete: GuildUnavailable);
+    fn guild_emojis_update(ctx: Context, guild_id: GuildId, current_state: HashMap<EmojiId, Emoji>);
+    fn guild_integrations_update(ctx: Context, guild_id: GuildId);
+    fn guild_member_addition(ctx: Context, guild_id: GuildId, new_member: Member);
+    #[cfg(feature = "cache")]
+    fn guild_member_removal(ctx: Context, guild_id: GuildId, user: User, member_data_if_available: Option<Member>);
+    #[cfg(not(feature = "cache"))]
+    fn guild_member_removal(ctx: Context, guild_id: GuildId, kicked: User);
+    #[cfg(feature = "cache")]
+    fn guild_member_update(ctx: Context, old_if_available: Option<Member>, new: Member);
+    #[cfg(not(feature = "cache"))]
+    fn guild_member_update(ctx: Context, new: GuildMemberUpdateEvent);
+    fn guild_members_chunk(ctx: Context, chunk: GuildMembersChunkEvent);
+    fn guild_role_create(ctx: Context, guild_id: GuildId, new: Role);
+    #[cfg(feature = "cache")]
+    fn guild_role_delete(ctx: Context, guild_id: GuildId, removed_role_id: RoleId, removed_role_data_if_available: Option<Role>);
+    #[cfg(not(feature = "cache"))]
+    fn guild_role_delete(ctx: Context, guild_id: GuildId, removed_role_id: RoleId);
+    #[cfg(feature = "cache")]
+    fn guild_role_update(ctx: Context, guild_id: GuildId, old_data_if_available: Option<Role>, new: Role);
+    #[cfg(not(feature = "cache"))]
+    fn guild_role_update(ctx: Context, guild_id: GuildId, new_data: Role);
+    fn guild_unavailable(ctx: Context, guild_id: GuildId);
+    #[cfg(feature = "cache")]
+    fn guild_update(ctx: Context, old_data_if_available: Option<Guild>, new_but_incomplete: PartialGuild);
+    #[cfg(not(feature = "cache"))]
+    fn guild_update(ctx: Context, new_but_incomplete_data: PartialGuild);
+    fn interaction_create(ctx: Context, interaction: Interaction);
+    fn invite_create(ctx: Context, data: InviteCreateEvent);
+    fn invite_delete(ctx: Context, data: InviteDeleteEvent);
+    fn message(ctx: Context, new_message: Message);
+    fn message_delete(ctx: Context, channel_id: ChannelId, deleted_message_id: MessageId, guild_id: Option<GuildId>);
+    fn message_delete_bulk(ctx: Context, channel_id: ChannelId, multiple_deleted_messages_ids: Vec<MessageId>, guild_id: Option<GuildId>);
+    #[cfg(feature = "cache")]
+    fn message_update(ctx: Context, old_if_available: Option<Message>, new: Option<Message>, event: MessageUpdateEvent);
+    #[cfg(not(feature = "cache"))]
+    fn message_update(ctx: Context, new_data: MessageUpdateEvent);
+    fn reaction_add(ctx: Context, add_reaction: Reaction);
+    fn reaction_remove(ctx: Context, removed_reaction: Reaction);
+    fn reaction_remove_all(ctx: Context, channel_id: ChannelId, removed_from_message_id: MessageId);
+    fn presence_replace(ctx: Context, presences: Vec<Presence>);
+    fn presence_update(ctx: Context, new_data: PresenceUpdateEvent);
+    fn ready(ctx: Context, data_about_bot: Ready);
+    fn resume(ctx: Context, event: ResumedEvent);
+    fn shard_stage_update(ctx: Context, event: ShardStageUpdateEvent);
+    fn shutdown(data: Arc<RwLock<TypeMap>>);
+    fn stage_instance_create(ctx: Context, stage_instance: StageInstance);
+    fn stage_instance_update(ctx: Context, stage_instance: StageInstance);
+    fn stage_instance_delete(ctx: Context, stage_instance: StageInstance);
+    fn typing_start(ctx: Context, event: TypingStartEvent);
+    fn unknown(ctx: Context, name: String, raw: Value);
+    #[cfg(feature = "cache")]
+    fn user_update(ctx: Context, old_data: CurrentUser, new: CurrentUser);
+    #[cfg(not(feature = "cache"))]
+    fn user_update(ctx: Context, new_data: CurrentUser);
+    fn voice_server_update(ctx: Context, event: VoiceServerUpdateEvent);
+    #[cfg(feature = "cache")]
+    fn voice_state_update(ctx: Context, guild_id: Option<GuildId>, old: Option<VoiceState>, new: VoiceState);
+    #[cfg(not(feature = "cache"))]
+    fn voice_state_update(ctx: Context, guild_id: Option<GuildId>, new: VoiceState);
+    fn webhook_update(ctx: Context, guild_id: GuildId, belongs_to_channel_id: ChannelId);
+}
+
+#[cfg(all(test, feature = "cache"))]
+mod test {
+    use super::{EventHandler, EventHandlers};
+    use crate::client::Context;
+    use crate::internal::test_utils;
+    use crate::model::channel::Message;
+    use crate::utils::CustomMessage;
+
+    use async_trait::async_trait;
+
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    struct Counter(Arc<AtomicUsize>);
+
+    #[async_trait]
+    impl EventHandler for Counter {
+        async fn message(&self, _: Context, _: Message) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    struct Panicking;
+
+    #[async_trait]
+    impl EventHandler for Panicking {
+        async fn message(&self, _: Context, _: Message) {
+            panic!("handler failure");
+        }
+    }
+
+    #[tokio::test]
+    async fn dispatch_to_all_handlers() {
+        let ctx = test_utils::context();
+
+        for &sequential in &[false, true] {
+            let first = Arc::new(AtomicUsize::new(0));
+            let second = Arc::new(AtomicUsize::new(0));
+            let handlers = EventHandlers {
+                handlers: vec![
+                    Arc::new(Counter(Arc::clone(&first))),
+                    Arc::new(Panicking),
+                    Arc::new(Counter(Arc::clone(&second))),
+                ],
+                sequential,
+            };
+
+            handlers.message(ctx.clone(), CustomMessage::new().build()).await;
+            handlers.message(ctx.clone(), CustomMessage::new().build()).await;
+
+            assert_eq!(first.load(Ordering::SeqCst), 2);
+            assert_eq!(second.load(Ordering::SeqCst), 2);
+        }
+    }
+
+    /// Lists the methods declared between `start` and the end of the block,
+    /// along with the `cfg` attribute right before each.
+    fn methods<'a>(source: &'a str, start: &str) -> Vec<(Option<&'a str>, &'a str)> {
+        let block = &source[source.find(start).unwrap()..];
+        let block = &block[..block.find("\n}").unwrap()];
+        let mut cfg = None;
+        let mut methods = Vec::new();
+
+        for line in block.lines().map(str::trim) {
+            if line.starts_with("#[cfg(") {
+                cfg = Some(line);
+            } else if line.starts_with("async fn ") || line.starts_with("fn ") {
+                let name = &line[line.find("fn ").unwrap() + 3..];
+                methods.push((cfg.take(), &name[..name.find('(').unwrap()]));
+            } else if !line.starts_with("///") {
+                cfg = None;
+            }
+        }
+
+        methods.sort_unstable();
+        methods
+    }
+
+    /// Methods missing from `dispatch_to_all!` would silently fall back to
+    /// the default no-op, so both lists are kept in sync.
+    #[test]
+    fn dispatch_to_all_methods() {
+        let source = include_str!("event_handler.rs");
+
+        let declared = methods(source, "pub trait EventHandler");
+        let dispatched = methods(source, "dispatch_to_all! {");
+
+        assert!(!declared.is_empty());
+        assert_eq!(declared, dispatched);
+    }
+}
@@ -38,6 +38,9 @@ pub use self::{
     extras::Extras,
 };
 
+#[cfg(feature = "gateway")]
+use self::event_handler::EventHandlers;
+
 pub use crate::CacheAndHttp;
 
 #[cfg(feature = "cache")]
@@ -48,7 +51,7 @@ use tokio::sync::{Mutex, RwLock};
 #[cfg(feature = "gateway")]
 use super::gateway::GatewayError;
 #[cfg(feature = "gateway")]
-use self::bridge::gateway::{GatewayIntents, ShardManager, ShardManagerMonitor, ShardManagerOptions, ShardManagerError};
+use self::bridge::gateway::{GatewayIntents, ReconnectPolicy, ShardConnectionOptions, ShardManager, ShardManagerMonitor, ShardManagerOptions, ShardManagerError};
 use std::{
     boxed::Box,
     sync::Arc,
@@ -66,7 +69,7 @@ use crate::framework::Framework;
 use self::bridge::voice::VoiceGatewayManager;
 use crate::http::Http;
 use typemap_rev::{TypeMap, TypeMapKey};
-use futures::future::BoxFuture;
+use futures::future::{self, BoxFuture, Either};
 
 /// A builder implementing [`Future`] building a [`Client`] to interact with Discord.
 #[cfg(feature = "gateway")]
@@ -75,13 +78,16 @@ pub struct ClientBuilder<'a> {
     http: Option<Http>,
     fut: Option<BoxFuture<'a, Result<Client>>>,
     intents: GatewayIntents,
+    reconnect_policy: ReconnectPolicy,
+    transport_compression: bool,
     #[cfg(feature = "cache")]
     timeout: Option<Duration>,
     #[cfg(feature = "framework")]
     framework: Option<Arc<Box<dyn Framework + Send + Sync + 'static>>>,
     #[cfg(feature = "voice")]
     voice_manager: Option<Arc<dyn VoiceGatewayManager + Send + Sync + 'static>>,
-    event_handler: Option<Arc<dyn EventHandler>>,
+    event_handlers: Vec<Arc<dyn EventHandler>>,
+    sequential_event_handlers: bool,
     raw_event_handler: Option<Arc<dyn RawEventHandler>>,
 }
 
@@ -103,13 +109,16 @@ impl<'a> ClientBuilder<'a> {
             http: None,
             fut: None,
             intents: GatewayIntents::non_privileged(),
+            reconnect_policy: ReconnectPolicy::default(),
+            transport_compression: false,
             #[cfg(feature = "cache")]
             timeout: None,
             #[cfg(feature = "framework")]
             framework: None,
             #[cfg(feature = "voice")]
             voice_manager: None,
-            event_handler: None,
+            event_handlers: Vec::new(),
+            sequential_event_handlers: false,
             raw_event_handler: None,
         }.token(token)
     }
@@ -247,9 +256,72 @@ impl<'a> ClientBuilder<'a> {
         self
     }
 
-    /// Sets an event handler with multiple methods for each possible event.
+    /// Sets the policy deciding how long shards wait before reconnecting to
+    /// the gateway after losing their connection.
+    ///
+    /// Defaults to [`ReconnectPolicy::default`].
+    pub fn reconnect_policy(mut self, reconnect_policy: ReconnectPolicy) -> Self {
+        self.reconnect_policy = reconnect_policy;
+
+        self
+    }
+
+    /// Sets whether shards use the `zlib-stream` transport compression, which
+    /// compresses the whole gateway connection rather than only its large
+    /// payloads.
+    ///
+    /// This saves bandwidth, at the expense of keeping an inflate context in
+    /// memory for every shard. Defaults to `false`.
+    pub fn transport_compression(mut self, transport_compression: bool) -> Self {
+        self.transport_compression = transport_compression;
+
+        self
+    }
+
+    /// Adds an event handler with multiple methods for each possible event.
+    ///
+    /// This can be called multiple times, such as once per plugin of a bot.
+    /// Every event is then dispatched to all of the handlers, and a panic in
+    /// one of them does not prevent the others from handling the event.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use std::error::Error;
+    /// use serenity::prelude::*;
+    ///
+    /// struct Logger;
+    ///
+    /// impl EventHandler for Logger {}
+    ///
+    /// struct Moderation;
+    ///
+    /// impl EventHandler for Moderation {}
+    ///
+    /// # async fn run() -> Result<(), Box<dyn Error>> {
+    /// let token = std::env::var("DISCORD_TOKEN")?;
+    /// let mut client = Client::builder(&token)
+    ///     .event_handler(Logger)
+    ///     .event_handler(Moderation)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
     pub fn event_handler<H: EventHandler + 'static>(mut self, event_handler: H) -> Self {
-        self.event_handler = Some(Arc::new(event_handler));
+        self.event_handlers.push(Arc::new(event_handler));
+
+        self
+    }
+
+    /// Sets whether the event handlers added with [`event_handler`] handle
+    /// each event one after the other, in the order they were added, rather
+    /// than concurrently.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// [`event_handler`]: Self::event_handler
+    pub fn sequential_event_handlers(mut self, sequential: bool) -> Self {
+        self.sequential_event_handlers = sequential;
 
         self
     }
@@ -275,9 +347,20 @@ impl<'a> Future for ClientBuilder<'a> {
             let framework = self.framework.take()
                 .expect("The `framework`-feature is enabled (it's on by default), but no framework was provided.\n\
                 If you don't want to use the command framework, disable default features and specify all features you want to use.");
-            let event_handler = self.event_handler.take();
+            let mut event_handlers = std::mem::take(&mut self.event_handlers);
+            let event_handler: Option<Arc<dyn EventHandler>> = if event_handlers.len() > 1 {
+                Some(Arc::new(EventHandlers {
+                    handlers: event_handlers,
+                    sequential: self.sequential_event_handlers,
+                }))
+            } else {
+                event_handlers.pop()
+            };
             let raw_event_handler = self.raw_event_handler.take();
             let intents = self.intents;
+            let connection = ShardConnectionOptions::default()
+                .reconnect_policy(self.reconnect_policy)
+                .transport_compression(self.transport_compression);
             let http = Arc::new(self.http.take().unwrap());
             #[cfg(feature = "voice")]
             let voice_manager = self.voice_manager.take();
@@ -294,7 +377,7 @@ impl<'a> Future for ClientBuilder<'a> {
                 let url = Arc::new(Mutex::new(http.get_gateway().await?.url));
 
                 let (shard_manager, shard_manager_worker) = {
-                    ShardManager::new(ShardManagerOptions {
+                    ShardManager::new_with_connection_options(ShardManagerOptions {
                         data: &data,
                         event_handler: &event_handler,
                         raw_event_handler: &raw_event_handler,
@@ -308,12 +391,13 @@ impl<'a> Future for ClientBuilder<'a> {
                         ws_url: &url,
                         cache_and_http: &cache_and_http,
                         intents,
-                    }).await
+                    }, connection).await
                 };
 
                 Ok(Client {
                     ws_uri: url,
                     data,
+                    event_handler,
                     shard_manager,
                     shard_manager_worker,
                     #[cfg(feature = "voice")]
@@ -461,6 +545,7 @@ pub struct Client {
     /// [`Event::MessageUpdate`]: crate::model::event::Event::MessageUpdate
     /// [example 05]: https://github.com/serenity-rs/serenity/tree/current/examples/05_command_framework
     pub data: Arc<RwLock<TypeMap>>,
+    event_handler: Option<Arc<dyn EventHandler>>,
     /// A HashMap of all shards instantiated by the Client.
     ///
     /// The key is the shard ID and the value is the shard itself.
@@ -612,6 +697,77 @@ impl Client {
         self.start_connection([0, 0, 1]).await
     }
 
+    /// Establish the connection and start listening for events, until the
+    /// `signal` future completes.
+    ///
+    /// This behaves like [`start`], but once `signal` completes, all shards
+    /// are shut down through [`ShardManager::shutdown_all`], closing their
+    /// connections normally. [`EventHandler::shutdown`] is then dispatched
+    /// before returning.
+    ///
+    /// # Examples
+    ///
+    /// Shutting down when receiving Ctrl-C, using the `signal` feature of
+    /// tokio:
+    ///
+    /// ```rust,ignore
+    /// # use std::error::Error;
+    /// # use serenity::prelude::EventHandler;
+    /// use serenity::Client;
+    ///
+    /// struct Handler;
+    ///
+    /// impl EventHandler for Handler {}
+    ///
+    /// # async fn run() -> Result<(), Box<dyn Error>> {
+    /// let token = std::env::var("DISCORD_TOKEN")?;
+    /// let mut client = Client::builder(&token).event_handler(Handler).await?;
+    ///
+    /// let ctrl_c = async {
+    ///     let _ = tokio::signal::ctrl_c().await;
+    /// };
+    ///
+    /// if let Err(why) = client.start_with_shutdown(ctrl_c).await {
+    ///     println!("Err with client: {:?}", why);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`start`], if the connection ends before
+    /// `signal` completes.
+    ///
+    /// [`start`]: Self::start
+    #[instrument(skip(self, signal))]
+    pub async fn start_with_shutdown(&mut self, signal: impl Future<Output = ()>) -> Result<()> {
+        let shard_manager = Arc::clone(&self.shard_manager);
+        let shutdown = async move {
+            signal.await;
+
+            info!("Shutdown signal received");
+            shard_manager.lock().await.shutdown_all().await;
+        };
+
+        let result = {
+            let start = self.start_connection([0, 0, 1]);
+            futures::pin_mut!(start, shutdown);
+
+            match future::select(start, shutdown).await {
+                Either::Left((result, _)) => result,
+                // Shutting down the manager makes the connection end.
+                Either::Right(((), start)) => start.await,
+            }
+        };
+
+        if let Some(event_handler) = &self.event_handler {
+            event_handler.shutdown(Arc::clone(&self.data)).await;
+        }
+
+        result
+    }
+
     /// Establish the connection(s) and start listening for events.
     ///
     /// This will start receiving events in a loop and start dispatching the
@@ -778,12 +934,18 @@ impl Client {
     /// Returns a [`ClientError::Shutdown`] when all shards have shutdown due to
     /// an error.
     ///
+    /// Returns a [`ClientError::InvalidShardRange`] if `total_shards` is `0`.
+    ///
     /// [`start_shard`]: Self::start_shard
     /// [`start_shard_range`]: Self::start_shard_range
     /// [Gateway docs]: crate::gateway#sharding
     #[instrument(skip(self))]
     pub async fn start_shards(&mut self, total_shards: u64) -> Result<()> {
-        self.start_connection([0, total_shards - 1, total_shards]).await
+        let last_shard = total_shards
+            .checked_sub(1)
+            .ok_or(Error::Client(ClientError::InvalidShardRange))?;
+
+        self.start_connection([0, last_shard, total_shards]).await
     }
 
     /// Establish a range of sharded connections and start listening for events.
@@ -828,6 +990,9 @@ impl Client {
     /// Returns a [`ClientError::Shutdown`] when all shards have shutdown due to
     /// an error.
     ///
+    /// Returns a [`ClientError::InvalidShardRange`] if the last shard of the
+    /// range is before the first one, or is not below `total_shards`.
+    ///
     /// [`start_shard`]: Self::start_shard
     /// [`start_shards`]: Self::start_shards
     /// [Gateway docs]: crate::gateway#sharding
@@ -847,8 +1012,15 @@ impl Client {
     ///
     /// Returns a [`ClientError::Shutdown`] when all shards have shutdown due to
     /// an error.
+    ///
+    /// Returns a [`ClientError::InvalidShardRange`] if the range of shards is
+    /// empty, or does not lie within the total number of shards.
     #[instrument(skip(self))]
     async fn start_connection(&mut self, shard_data: [u64; 3]) -> Result<()> {
+        if !is_valid_shard_range(shard_data) {
+            return Err(Error::Client(ClientError::InvalidShardRange));
+        }
+
         #[cfg(feature = "voice")]
         if let Some(voice_manager) = &self.voice_manager {
             let user = self.cache_and_http.http.get_current_user().await?;
@@ -893,6 +1065,14 @@ impl Client {
     }
 }
 
+/// Whether the shards from `shard_data[0]` up to and including `shard_data[1]`
+/// are part of the `shard_data[2]` total shards.
+fn is_valid_shard_range(shard_data: [u64; 3]) -> bool {
+    let [first, last, total] = shard_data;
+
+    first <= last && last < total
+}
+
 /// Validates that a token is likely in a valid format.
 ///
 /// This performs the following checks on a given token:
@@ -955,3 +1135,76 @@ pub fn validate_token(token: impl AsRef<str>) -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(all(test, feature = "gateway"))]
+mod test {
+    use super::*;
+    #[cfg(feature = "framework")]
+    use crate::internal::test_utils;
+    use async_trait::async_trait;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    struct Handler(Arc<AtomicBool>);
+
+    #[async_trait]
+    impl EventHandler for Handler {
+        async fn shutdown(&self, _data: Arc<RwLock<TypeMap>>) {
+            self.0.store(true, Ordering::SeqCst);
+        }
+    }
+
+    async fn client(event_handler: Option<Arc<dyn EventHandler>>) -> Client {
+        let data = Arc::new(RwLock::new(TypeMap::new()));
+        #[cfg(feature = "framework")]
+        let framework: Arc<Box<dyn Framework + Send + Sync>> = Arc::new(Box::new(test_utils::NoFramework));
+        let ws_uri = Arc::new(Mutex::new("ws://127.0.0.1:1".to_string()));
+        let cache_and_http = Arc::new(CacheAndHttp::default());
+
+        let (shard_manager, shard_manager_worker) = ShardManager::new(ShardManagerOptions {
+            data: &data,
+            event_handler: &event_handler,
+            raw_event_handler: &None,
+            #[cfg(feature = "framework")]
+            framework: &framework,
+            shard_index: 0,
+            shard_init: 0,
+            shard_total: 0,
+            #[cfg(feature = "voice")]
+            voice_manager: &None,
+            ws_url: &ws_uri,
+            cache_and_http: &cache_and_http,
+            intents: GatewayIntents::non_privileged(),
+        }).await;
+
+        Client {
+            data,
+            event_handler,
+            shard_manager,
+            shard_manager_worker,
+            #[cfg(feature = "voice")]
+            voice_manager: None,
+            ws_uri,
+            cache_and_http,
+        }
+    }
+
+    #[tokio::test]
+    async fn start_with_shutdown() {
+        let shutdown = Arc::new(AtomicBool::new(false));
+        let mut client = client(Some(Arc::new(Handler(Arc::clone(&shutdown))))).await;
+
+        client.start_with_shutdown(async {}).await.unwrap();
+
+        assert!(shutdown.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn start_without_shards() {
+        let mut client = client(None).await;
+
+        match client.start_shards(0).await {
+            Err(Error::Client(ClientError::InvalidShardRange)) => {},
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+}
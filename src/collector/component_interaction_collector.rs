@@ -0,0 +1,405 @@
+use std::{
+    boxed::Box,
+    future::Future,
+    sync::Arc,
+    time::Duration,
+    pin::Pin,
+    task::{Context as FutContext, Poll},
+};
+use tokio::{
+    sync::mpsc::{
+        unbounded_channel,
+        UnboundedReceiver as Receiver,
+        UnboundedSender as Sender,
+    },
+    time::{Delay, delay_for},
+};
+use futures::{
+    future::BoxFuture,
+    stream::{Stream, StreamExt},
+};
+use crate::{
+    client::bridge::gateway::ShardMessenger,
+    model::channel::ComponentType,
+    model::interactions::MessageComponentInteraction,
+};
+
+macro_rules! impl_component_interaction_collector {
+    ($($name:ident;)*) => {
+        $(
+            impl<'a> $name<'a> {
+                /// Limits how many interactions will attempt to be filtered.
+                ///
+                /// The filter checks whether the interaction has been made
+                /// on the right message, and by the right user.
+                pub fn filter_limit(mut self, limit: u32) -> Self {
+                    self.filter.as_mut().unwrap().filter_limit = Some(limit);
+
+                    self
+                }
+
+                /// Limits how many interactions can be collected.
+                ///
+                /// An interaction is considered *collected*, if the interaction
+                /// passes all the requirements.
+                pub fn collect_limit(mut self, limit: u32) -> Self {
+                    self.filter.as_mut().unwrap().collect_limit = Some(limit);
+
+                    self
+                }
+
+                /// Sets a filter function where interactions passed to the function must
+                /// return `true`, otherwise the interaction won't be collected.
+                /// This is the last instance to pass for an interaction to count as *collected*.
+                pub fn filter<F: Fn(&Arc<MessageComponentInteraction>) -> bool + 'static + Send + Sync>(mut self, function: F) -> Self {
+                    self.filter.as_mut().unwrap().filter = Some(Arc::new(function));
+
+                    self
+                }
+
+                /// Sets the required author ID of an interaction.
+                /// If an interaction is not made by a user with this ID, it won't be received.
+                pub fn author_id(mut self, author_id: impl Into<u64>) -> Self {
+                    self.filter.as_mut().unwrap().author_id = Some(author_id.into());
+
+                    self
+                }
+
+                /// Sets the message on which the interaction must occur.
+                /// If an interaction is not on a message with this ID, it won't be received.
+                pub fn message_id(mut self, message_id: impl Into<u64>) -> Self {
+                    self.filter.as_mut().unwrap().message_id = Some(message_id.into());
+
+                    self
+                }
+
+                /// Sets the guild in which the interaction must occur.
+                /// If an interaction is not in a guild with this ID, it won't be received.
+                pub fn guild_id(mut self, guild_id: impl Into<u64>) -> Self {
+                    self.filter.as_mut().unwrap().guild_id = Some(guild_id.into());
+
+                    self
+                }
+
+                /// Sets the channel in which the interaction must occur.
+                /// If an interaction is not in a channel with this ID, it won't be received.
+                pub fn channel_id(mut self, channel_id: impl Into<u64>) -> Self {
+                    self.filter.as_mut().unwrap().channel_id = Some(channel_id.into());
+
+                    self
+                }
+
+                /// Sets the developer-defined ID of the component.
+                /// If an interaction is not made on a component with this ID, it won't be received.
+                pub fn custom_id(mut self, custom_id: impl Into<String>) -> Self {
+                    self.filter.as_mut().unwrap().custom_id = Some(custom_id.into());
+
+                    self
+                }
+
+                /// Sets the type of the component.
+                /// If an interaction is not made on a component of this type, it won't be received.
+                pub fn component_type(mut self, component_type: ComponentType) -> Self {
+                    self.filter.as_mut().unwrap().component_type = Some(component_type);
+
+                    self
+                }
+
+                /// Sets a `duration` for how long the collector shall receive
+                /// interactions.
+                pub fn timeout(mut self, duration: Duration) -> Self {
+                    self.timeout = Some(delay_for(duration));
+
+                    self
+                }
+            }
+        )*
+    }
+}
+
+/// Filters events on the shard's end and sends them to the collector.
+#[derive(Clone, Debug)]
+pub struct ComponentInteractionFilter {
+    filtered: u32,
+    collected: u32,
+    options: FilterOptions,
+    sender: Sender<Arc<MessageComponentInteraction>>,
+}
+
+impl ComponentInteractionFilter {
+    /// Creates a new filter
+    fn new(options: FilterOptions) -> (Self, Receiver<Arc<MessageComponentInteraction>>) {
+        let (sender, receiver) = unbounded_channel();
+
+        let filter = Self {
+            filtered: 0,
+            collected: 0,
+            sender,
+            options,
+        };
+
+        (filter, receiver)
+    }
+
+    /// Sends an `interaction` to the consuming collector if the `interaction`
+    /// conforms to the constraints and the limits are not reached yet.
+    pub(crate) fn send_interaction(&mut self, interaction: &Arc<MessageComponentInteraction>) -> bool {
+        if self.is_passing_constraints(interaction) {
+            self.collected += 1;
+
+            if self.sender.send(Arc::clone(interaction)).is_err() {
+                return false;
+            }
+        }
+
+        self.filtered += 1;
+
+        self.is_within_limits()
+    }
+
+    /// Checks if the `interaction` passes set constraints.
+    /// Constraints are optional, as it is possible to limit interactions to
+    /// be made by a specific user or on a specific message.
+    fn is_passing_constraints(&self, interaction: &Arc<MessageComponentInteraction>) -> bool {
+        let options = &self.options;
+
+        (options.guild_id.is_none() || options.guild_id == interaction.guild_id.map(|g| g.0))
+        && (options.message_id.is_none() || options.message_id == Some(interaction.message.id.0))
+        && (options.channel_id.is_none() || options.channel_id == Some(interaction.channel_id.0))
+        && (options.author_id.is_none() || options.author_id == Some(interaction.user.id.0))
+        && (options.custom_id.is_none() || options.custom_id.as_ref() == Some(&interaction.data.custom_id))
+        && (options.component_type.is_none() || options.component_type == Some(interaction.data.component_type))
+        && match options.filter {
+            Some(ref f) => f(interaction),
+            None => true,
+        }
+    }
+
+    /// Checks if the filter is within set receive and collect limits.
+    /// An interaction is considered *received* even when it does not meet the
+    /// constraints.
+    fn is_within_limits(&self) -> bool {
+        fn is_under(limit: Option<u32>, count: u32) -> bool {
+            match limit {
+                Some(limit) => count < limit,
+                None => true,
+            }
+        }
+
+        is_under(self.options.filter_limit, self.filtered)
+        && is_under(self.options.collect_limit, self.collected)
+    }
+}
+
+/// A function deciding whether an interaction is collected.
+type FilterFn = Arc<dyn Fn(&Arc<MessageComponentInteraction>) -> bool + 'static + Send + Sync>;
+
+#[derive(Clone, Default)]
+struct FilterOptions {
+    filter_limit: Option<u32>,
+    collect_limit: Option<u32>,
+    filter: Option<FilterFn>,
+    channel_id: Option<u64>,
+    guild_id: Option<u64>,
+    author_id: Option<u64>,
+    message_id: Option<u64>,
+    custom_id: Option<String>,
+    component_type: Option<ComponentType>,
+}
+
+// Implement the common setters for all component interaction collector types.
+impl_component_interaction_collector! {
+    CollectComponentInteraction;
+    ComponentInteractionCollectorBuilder;
+}
+
+/// Future building a stream of component interactions.
+pub struct ComponentInteractionCollectorBuilder<'a> {
+    filter: Option<FilterOptions>,
+    shard: Option<ShardMessenger>,
+    timeout: Option<Delay>,
+    fut: Option<BoxFuture<'a, ComponentInteractionCollector>>,
+}
+
+impl<'a> ComponentInteractionCollectorBuilder<'a> {
+    /// A future that builds a [`ComponentInteractionCollector`] based on the
+    /// settings.
+    pub fn new(shard_messenger: impl AsRef<ShardMessenger>) -> Self {
+        Self {
+            filter: Some(FilterOptions::default()),
+            shard: Some(shard_messenger.as_ref().clone()),
+            timeout: None,
+            fut: None,
+        }
+    }
+}
+
+impl<'a> Future for ComponentInteractionCollectorBuilder<'a> {
+    type Output = ComponentInteractionCollector;
+
+    fn poll(mut self: Pin<&mut Self>, ctx: &mut FutContext<'_>) -> Poll<Self::Output> {
+        if self.fut.is_none() {
+            let shard_messenger = self.shard.take().unwrap();
+            let (filter, receiver) = ComponentInteractionFilter::new(self.filter.take().unwrap());
+            let timeout = self.timeout.take();
+
+            self.fut = Some(Box::pin(async move {
+                shard_messenger.set_component_interaction_filter(filter);
+
+                ComponentInteractionCollector {
+                    receiver: Box::pin(receiver),
+                    timeout: timeout.map(Box::pin),
+                }
+            }))
+        }
+
+        self.fut.as_mut().unwrap().as_mut().poll(ctx)
+    }
+}
+
+/// Future resolving to the first component interaction matching the filter.
+pub struct CollectComponentInteraction<'a> {
+    filter: Option<FilterOptions>,
+    shard: Option<ShardMessenger>,
+    timeout: Option<Delay>,
+    fut: Option<BoxFuture<'a, Option<Arc<MessageComponentInteraction>>>>,
+}
+
+impl<'a> CollectComponentInteraction<'a> {
+    pub fn new(shard_messenger: impl AsRef<ShardMessenger>) -> Self {
+        Self {
+            filter: Some(FilterOptions::default()),
+            shard: Some(shard_messenger.as_ref().clone()),
+            timeout: None,
+            fut: None,
+        }
+    }
+}
+
+impl<'a> Future for CollectComponentInteraction<'a> {
+    type Output = Option<Arc<MessageComponentInteraction>>;
+
+    fn poll(mut self: Pin<&mut Self>, ctx: &mut FutContext<'_>) -> Poll<Self::Output> {
+        if self.fut.is_none() {
+            let shard_messenger = self.shard.take().unwrap();
+            let (filter, receiver) = ComponentInteractionFilter::new(self.filter.take().unwrap());
+            let timeout = self.timeout.take();
+
+            self.fut = Some(Box::pin(async move {
+                shard_messenger.set_component_interaction_filter(filter);
+
+                ComponentInteractionCollector {
+                    receiver: Box::pin(receiver),
+                    timeout: timeout.map(Box::pin),
+                }.next().await
+            }))
+        }
+
+        self.fut.as_mut().unwrap().as_mut().poll(ctx)
+    }
+}
+
+impl std::fmt::Debug for FilterOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ComponentInteractionFilter")
+            .field("collect_limit", &self.collect_limit)
+            .field("filter", &"Option<Arc<dyn Fn(&Arc<MessageComponentInteraction>) -> bool + 'static + Send + Sync>>")
+            .field("channel_id", &self.channel_id)
+            .field("guild_id", &self.guild_id)
+            .field("author_id", &self.author_id)
+            .field("message_id", &self.message_id)
+            .field("custom_id", &self.custom_id)
+            .field("component_type", &self.component_type)
+            .finish()
+    }
+}
+
+/// A component interaction collector receives interactions matching the given
+/// filter for a set duration.
+pub struct ComponentInteractionCollector {
+    receiver: Pin<Box<Receiver<Arc<MessageComponentInteraction>>>>,
+    timeout: Option<Pin<Box<Delay>>>,
+}
+
+impl ComponentInteractionCollector {
+    /// Stops collecting, this will implicitly be done once the
+    /// collector drops.
+    /// In case the drop does not appear until later, it is preferred to
+    /// stop the collector early.
+    pub fn stop(mut self) {
+        self.receiver.close();
+    }
+}
+
+impl Stream for ComponentInteractionCollector {
+    type Item = Arc<MessageComponentInteraction>;
+    fn poll_next(mut self: Pin<&mut Self>, ctx: &mut FutContext<'_>) -> Poll<Option<Self::Item>> {
+        if let Some(ref mut timeout) = self.timeout {
+
+            match timeout.as_mut().poll(ctx) {
+                Poll::Ready(_) => {
+                    return Poll::Ready(None);
+                },
+                Poll::Pending => (),
+            }
+        }
+
+        self.receiver.as_mut().poll_next(ctx)
+    }
+}
+
+impl Drop for ComponentInteractionCollector {
+    fn drop(&mut self) {
+        self.receiver.close();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ComponentInteractionCollector, ComponentInteractionFilter, FilterOptions};
+    use crate::internal::test_utils;
+    use crate::model::prelude::*;
+    use futures::stream::StreamExt;
+    use serde_json::json;
+    use std::sync::Arc;
+
+    fn interaction(custom_id: &str) -> Arc<MessageComponentInteraction> {
+        let interaction = serde_json::from_value(json!({
+            "id": "1",
+            "application_id": "2",
+            "type": 3,
+            "data": {"custom_id": custom_id, "component_type": 2},
+            "message": test_utils::message_json(4, None),
+            "channel_id": "4",
+            "user": {"id": "5", "username": "ferris", "discriminator": "0001", "avatar": null},
+            "token": "token",
+            "version": 1,
+        })).unwrap();
+
+        Arc::new(interaction)
+    }
+
+    #[tokio::test]
+    async fn collect_custom_id() {
+        let options = FilterOptions {
+            message_id: Some(3),
+            custom_id: Some("confirm".to_string()),
+            component_type: Some(ComponentType::Button),
+            ..Default::default()
+        };
+        let (mut filter, receiver) = ComponentInteractionFilter::new(options);
+
+        assert!(filter.send_interaction(&interaction("cancel")));
+        assert!(filter.send_interaction(&interaction("confirm")));
+        drop(filter);
+
+        let mut collector = ComponentInteractionCollector {
+            receiver: Box::pin(receiver),
+            timeout: None,
+        };
+
+        let collected = collector.next().await.unwrap();
+        assert_eq!(collected.data.custom_id, "confirm");
+        assert!(collector.next().await.is_none());
+    }
+}
@@ -20,7 +20,7 @@ use futures::{
 };
 use crate::{
     client::bridge::gateway::ShardMessenger,
-    model::channel::Reaction,
+    model::channel::{Reaction, ReactionType},
     model::id::UserId,
 };
 
@@ -91,6 +91,17 @@ macro_rules! impl_reaction_collector {
                     self
                 }
 
+                /// Sets the emoji of the reaction.
+                /// If a reaction is not made with this emoji, it won't be received.
+                ///
+                /// Custom emojis are matched by their ID, unicode emojis by
+                /// their exact string.
+                pub fn emoji<E: Into<ReactionType>>(mut self, emoji: E) -> Self {
+                    self.filter.as_mut().unwrap().emoji = Some(emoji.into());
+
+                    self
+                }
+
                 /// If set to `true`, added reactions will be collected.
                 ///
                 /// Set to `true` by default.
@@ -214,6 +225,10 @@ impl ReactionFilter {
         && self.options.message_id.map_or(true, |id| { id == reaction.message_id.0 })
         && self.options.channel_id.map_or(true, |id| { id == reaction.channel_id.0 })
         && self.options.author_id.map_or(true, |id| { id == reaction.user_id.unwrap_or(UserId(0)).0 })
+        && match self.options.emoji {
+            Some(ref emoji) => is_same_emoji(emoji, &reaction.emoji),
+            None => true,
+        }
         && self.options.filter.as_ref().map_or(true, |f| f(&reaction))
     }
 
@@ -227,6 +242,14 @@ impl ReactionFilter {
     }
 }
 
+fn is_same_emoji(expected: &ReactionType, emoji: &ReactionType) -> bool {
+    match (expected, emoji) {
+        (ReactionType::Custom { id: expected, .. }, ReactionType::Custom { id, .. }) => expected == id,
+        (ReactionType::Unicode(expected), ReactionType::Unicode(emoji)) => expected == emoji,
+        _ => false,
+    }
+}
+
 #[derive(Clone)]
 struct FilterOptions {
     filter_limit: Option<u32>,
@@ -236,6 +259,7 @@ struct FilterOptions {
     guild_id: Option<u64>,
     author_id: Option<u64>,
     message_id: Option<u64>,
+    emoji: Option<ReactionType>,
     accept_added: bool,
     accept_removed: bool,
 }
@@ -250,6 +274,7 @@ impl Default for FilterOptions {
             guild_id: None,
             author_id: None,
             message_id: None,
+            emoji: None,
             accept_added: true,
             accept_removed: false,
         }
@@ -354,6 +379,7 @@ impl std::fmt::Debug for FilterOptions {
             .field("channel_id", &self.channel_id)
             .field("guild_id", &self.guild_id)
             .field("author_id", &self.author_id)
+            .field("emoji", &self.emoji)
             .finish()
     }
 }
@@ -397,3 +423,86 @@ impl Drop for ReactionCollector {
         self.receiver.close();
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{FilterOptions, ReactionAction, ReactionCollector, ReactionFilter};
+    use crate::model::prelude::*;
+    use futures::stream::StreamExt;
+    use serde_json::{json, Value};
+    use std::sync::Arc;
+
+    fn reaction(emoji: Value) -> Arc<ReactionAction> {
+        let reaction = serde_json::from_value(json!({
+            "channel_id": "2",
+            "message_id": "3",
+            "user_id": "4",
+            "emoji": emoji,
+        })).unwrap();
+
+        Arc::new(ReactionAction::Added(Arc::new(reaction)))
+    }
+
+    #[tokio::test]
+    async fn collect_reaction_emoji() {
+        let options = FilterOptions {
+            emoji: Some('👍'.into()),
+            ..Default::default()
+        };
+        let (mut filter, receiver) = ReactionFilter::new(options);
+
+        filter.send_reaction(&reaction(json!({"id": null, "name": "👎"})));
+        filter.send_reaction(&reaction(json!({"id": "5", "name": "thumbsup"})));
+        filter.send_reaction(&reaction(json!({"id": null, "name": "👍"})));
+        drop(filter);
+
+        let mut collector = ReactionCollector {
+            receiver: Box::pin(receiver),
+            timeout: None,
+        };
+
+        let collected = collector.next().await.unwrap();
+        assert_eq!(collected.as_inner_ref().emoji, ReactionType::Unicode("👍".to_string()));
+        assert!(collector.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn builder_collects_reaction_emoji() {
+        use super::ReactionCollectorBuilder;
+        use crate::client::bridge::gateway::{ShardClientMessage, ShardMessenger, ShardRunnerMessage};
+        use crate::gateway::InterMessage;
+
+        let (tx, mut rx) = futures::channel::mpsc::unbounded();
+        let messenger = ShardMessenger::new(tx);
+        let mut collector = ReactionCollectorBuilder::new(&messenger).emoji('👍').await;
+
+        let mut filter = match rx.next().await {
+            Some(InterMessage::Client(message)) => match *message {
+                ShardClientMessage::Runner(ShardRunnerMessage::SetReactionFilter(filter)) => filter,
+                _ => panic!("expected a reaction filter"),
+            },
+            _ => panic!("expected a reaction filter"),
+        };
+
+        filter.send_reaction(&reaction(json!({"id": null, "name": "👎"})));
+        filter.send_reaction(&reaction(json!({"id": null, "name": "👍"})));
+        drop(filter);
+
+        let collected = collector.next().await.unwrap();
+        assert_eq!(collected.as_inner_ref().emoji, ReactionType::Unicode("👍".to_string()));
+        assert!(collector.next().await.is_none());
+    }
+
+    #[test]
+    fn custom_emoji_matches_id() {
+        let expected = ReactionType::from(EmojiId(5));
+
+        assert!(super::is_same_emoji(&expected, &ReactionType::Custom {
+            animated: true,
+            id: EmojiId(5),
+            name: Some("thumbsup".to_string()),
+        }));
+        assert!(!super::is_same_emoji(&expected, &ReactionType::from(EmojiId(6))));
+        assert!(!super::is_same_emoji(&expected, &ReactionType::Unicode("5".to_string())));
+    }
+}
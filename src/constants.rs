@@ -3,6 +3,22 @@
 /// The maximum length of the textual size of an embed.
 pub const EMBED_MAX_LENGTH: usize = 6000;
 
+/// The maximum number of embeds which can be sent along with a message.
+pub const EMBED_MAX_COUNT: usize = 10;
+
+/// The maximum unicode code points allowed within the description of an
+/// attachment.
+pub const ATTACHMENT_DESCRIPTION_MAX_LENGTH: usize = 1024;
+
+/// The maximum unicode code points allowed within an audit log reason.
+pub const AUDIT_LOG_REASON_MAX_LENGTH: usize = 512;
+
+/// The maximum number of fields of an embed.
+pub const EMBED_FIELD_MAX_COUNT: usize = 25;
+
+/// The maximum unicode code points allowed within the value of an embed field.
+pub const EMBED_FIELD_VALUE_MAX_LENGTH: usize = 1024;
+
 /// The gateway version used by the library. The gateway URI is retrieved via
 /// the REST API.
 pub const GATEWAY_VERSION: u8 = 8;
@@ -13,9 +29,19 @@ pub const LARGE_THRESHOLD: u8 = 250;
 /// The maximum unicode code points allowed within a message by Discord.
 pub const MESSAGE_CODE_LIMIT: usize = 2000;
 
+/// The maximum number of stickers which can be sent along with a message.
+pub const STICKER_MAX_COUNT: usize = 3;
+
+/// The maximum number of choices which can be sent in response to an
+/// autocomplete interaction.
+pub const AUTOCOMPLETE_CHOICE_MAX_COUNT: usize = 25;
+
 /// The maximum number of members the bot can fetch at once
 pub const MEMBER_FETCH_LIMIT: u64 = 1000;
 
+/// The maximum slow mode rate of a channel, in seconds.
+pub const SLOW_MODE_RATE_LIMIT: u16 = 21600;
+
 /// The [UserAgent] sent along with every request.
 ///
 /// [UserAgent]: ::reqwest::header::USER_AGENT
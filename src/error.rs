@@ -145,7 +145,7 @@ impl From<InvalidHeaderValue> for Error {
 
 #[cfg(feature = "http")]
 impl From<ReqwestError> for Error {
-    fn from(e: ReqwestError) -> Error { HttpError::Request(e).into() }
+    fn from(e: ReqwestError) -> Error { HttpError::from(e).into() }
 }
 
 impl Display for Error {
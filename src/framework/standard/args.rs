@@ -1,5 +1,9 @@
 use uwl::Stream;
 
+use super::ArgumentConvert;
+use crate::client::Context;
+use crate::model::channel::Message;
+
 use std::error::Error as StdError;
 use std::marker::PhantomData;
 use std::{fmt, str::FromStr};
@@ -595,6 +599,44 @@ impl Args {
         Ok(p)
     }
 
+    /// Convert the current argument with its [`ArgumentConvert`] implementation.
+    ///
+    /// Modifications of [`trimmed`] and [`quoted`] are also applied to the
+    /// argument before conversion.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Eos`] if there are no arguments left, or
+    /// [`Error::Parse`] if the conversion failed.
+    ///
+    /// [`trimmed`]: Self::trimmed
+    /// [`quoted`]: Self::quoted
+    pub async fn parse_converted<T: ArgumentConvert>(&self, ctx: &Context, msg: &Message) -> Result<T, T::Err> {
+        let arg = self.current().ok_or(Error::Eos)?;
+
+        T::from_str(ctx, msg, arg).await.map_err(Error::Parse)
+    }
+
+    /// Convert the current argument with its [`ArgumentConvert`] implementation
+    /// and advance.
+    ///
+    /// Shorthand for calling [`parse_converted`], storing the result,
+    /// calling [`advance`] and returning the result.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Eos`] if there are no arguments left, or
+    /// [`Error::Parse`] if the conversion failed. The argument is not
+    /// advanced past on failure.
+    ///
+    /// [`parse_converted`]: Self::parse_converted
+    /// [`advance`]: Self::advance
+    pub async fn single_converted<T: ArgumentConvert>(&mut self, ctx: &Context, msg: &Message) -> Result<T, T::Err> {
+        let p = self.parse_converted::<T>(ctx, msg).await?;
+        self.advance();
+        Ok(p)
+    }
+
     /// Remove surrounding quotations, if present, from the argument; parse it and advance.
     ///
     /// Shorthand for `.quoted().single::<T>()`
@@ -4,7 +4,7 @@ use crate::model::{channel::Message, id::{UserId, GuildId, ChannelId}};
 use std::collections::HashSet;
 use futures::future::BoxFuture;
 
-type DynamicPrefixHook = for<'fut> fn(&'fut Context, &'fut Message) -> BoxFuture<'fut, Option<String>>;
+type DynamicPrefixHook = Box<dyn for<'fut> Fn(&'fut Context, &'fut Message) -> BoxFuture<'fut, Option<String>> + Send + Sync>;
 
 /// A configuration struct for deciding whether the framework
 /// should allow optional whitespace between prefixes, group prefixes and command names.
@@ -127,6 +127,9 @@ pub struct Configuration {
     pub delimiters: Vec<Delimiter>,
     #[doc(hidden)]
     pub case_insensitive: bool,
+    #[doc(hidden)]
+    #[cfg(feature = "command_suggestions")]
+    pub max_suggestion_distance: usize,
 }
 
 impl Configuration {
@@ -293,6 +296,8 @@ impl Configuration {
     /// use the configured prefix from [`prefix`] or [`prefixes`].
     ///
     /// This method can be called many times to add more dynamic prefix hooks.
+    /// The hook can be a closure, letting it capture state such as a database
+    /// connection pool.
     ///
     /// **Note**: Defaults to no dynamic prefix check.
     ///
@@ -346,8 +351,9 @@ impl Configuration {
     ///  [`prefix`]: Self::prefix
     ///  [`prefixes`]: Self::prefixes
     #[inline]
-    pub fn dynamic_prefix(&mut self, dynamic_prefix: DynamicPrefixHook) -> &mut Self {
-        self.dynamic_prefixes.push(dynamic_prefix);
+    pub fn dynamic_prefix<F>(&mut self, dynamic_prefix: F) -> &mut Self
+    where F: for<'fut> Fn(&'fut Context, &'fut Message) -> BoxFuture<'fut, Option<String>> + Send + Sync + 'static {
+        self.dynamic_prefixes.push(Box::new(dynamic_prefix));
 
         self
     }
@@ -581,6 +587,22 @@ impl Configuration {
 
         self
     }
+
+    /// The highest [Levenshtein distance] between an unrecognised command's
+    /// name and a registered command's name for the latter to be suggested to
+    /// the [`unrecognised_command_suggestions`] hook.
+    ///
+    /// **Note**: Defaults to `2`.
+    ///
+    /// [Levenshtein distance]: https://en.wikipedia.org/wiki/Levenshtein_distance
+    /// [`unrecognised_command_suggestions`]: super::StandardFramework::unrecognised_command_suggestions
+    #[cfg(feature = "command_suggestions")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "command_suggestions")))]
+    pub fn max_suggestion_distance(&mut self, distance: usize) -> &mut Self {
+        self.max_suggestion_distance = distance;
+
+        self
+    }
 }
 
 impl Default for Configuration {
@@ -593,6 +615,7 @@ impl Default for Configuration {
     /// - **blocked_users** to an empty HashSet,
     /// - **allowed_channels** to an empty HashSet,
     /// - **case_insensitive** to `false`
+    /// - **max_suggestion_distance** to `2`, with `command_suggestions`
     /// - **delimiters** to `vec![' ']`
     /// - **disabled_commands** to an empty HashSet
     /// - **dynamic_prefixes** to an empty vector
@@ -611,6 +634,8 @@ impl Default for Configuration {
             blocked_users: HashSet::default(),
             allowed_channels: HashSet::default(),
             case_insensitive: false,
+            #[cfg(feature = "command_suggestions")]
+            max_suggestion_distance: 2,
             delimiters: vec![Delimiter::Single(' ')],
             disabled_commands: HashSet::default(),
             dynamic_prefixes: Vec::new(),
@@ -0,0 +1,183 @@
+use async_trait::async_trait;
+
+use crate::client::Context;
+use crate::model::channel::Message;
+use crate::model::id::{ChannelId, RoleId, UserId};
+use crate::model::misc::{ChannelIdParseError, RoleIdParseError, UserIdParseError};
+use crate::utils::parse_message_link;
+use crate::Error;
+
+use std::error::Error as StdError;
+use std::fmt;
+use std::str::FromStr;
+
+/// Converts an argument into `Self`, with access to the context and the
+/// message the argument was sent in.
+///
+/// Unlike [`FromStr`], the conversion may look up data, such as fetching a
+/// message from its link. Arguments are converted with
+/// [`Args::single_converted`] and [`Args::parse_converted`], or by marking
+/// the parameters of a [`command`] with `#[arg]`.
+///
+/// # Errors
+///
+/// If an argument can't be converted, the `Args` methods return an
+/// [`ArgError::Parse`] holding the [`Err`] of the implementation. Propagating
+/// it out of a command with `?`, as `#[arg]` parameters do, hands it to the
+/// [`after`] hook as the command's error.
+///
+/// # Examples
+///
+/// Implementing the trait for a custom type, and taking it as a command's
+/// argument:
+///
+/// ```rust
+/// use serenity::client::Context;
+/// use serenity::framework::standard::{macros::command, ArgumentConvert, CommandResult};
+/// use serenity::model::channel::Message;
+///
+/// enum Difficulty {
+///     Easy,
+///     Hard,
+/// }
+///
+/// #[serenity::async_trait]
+/// impl ArgumentConvert for Difficulty {
+///     type Err = &'static str;
+///
+///     async fn from_str(_: &Context, _: &Message, s: &str) -> Result<Self, Self::Err> {
+///         match s {
+///             "easy" => Ok(Difficulty::Easy),
+///             "hard" => Ok(Difficulty::Hard),
+///             _ => Err("unknown difficulty"),
+///         }
+///     }
+/// }
+///
+/// #[command]
+/// async fn play(ctx: &Context, msg: &Message, #[arg] difficulty: Difficulty) -> CommandResult {
+///     let reply = match difficulty {
+///         Difficulty::Easy => "Starting an easy game.",
+///         Difficulty::Hard => "Starting a hard game.",
+///     };
+///
+///     msg.reply(ctx, reply).await?;
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// [`command`]: super::macros::command
+/// [`Args::single_converted`]: super::Args::single_converted
+/// [`Args::parse_converted`]: super::Args::parse_converted
+/// [`ArgError::Parse`]: super::ArgError::Parse
+/// [`Err`]: Self::Err
+/// [`after`]: super::StandardFramework::after
+#[async_trait]
+pub trait ArgumentConvert: Sized {
+    /// The error returned if the argument can't be converted.
+    type Err;
+
+    /// Converts the argument `s`, sent in `msg`.
+    async fn from_str(ctx: &Context, msg: &Message, s: &str) -> Result<Self, Self::Err>;
+}
+
+macro_rules! impl_argument_convert_for_id {
+    ($($id:ident, $err:ident;)*) => {
+        $(
+            /// Parses a mention or a raw Id.
+            #[async_trait]
+            impl ArgumentConvert for $id {
+                type Err = $err;
+
+                async fn from_str(_: &Context, _: &Message, s: &str) -> Result<Self, Self::Err> {
+                    <$id as FromStr>::from_str(s)
+                }
+            }
+        )*
+    };
+}
+
+impl_argument_convert_for_id! {
+    UserId, UserIdParseError;
+    RoleId, RoleIdParseError;
+    ChannelId, ChannelIdParseError;
+}
+
+/// Error returned when converting a [`Message`] argument failed.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum MessageParseError {
+    /// The argument is not a link to a message.
+    InvalidLink,
+    /// The message could not be fetched.
+    Http(Box<Error>),
+}
+
+impl fmt::Display for MessageParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MessageParseError::InvalidLink => f.write_str("invalid message link"),
+            MessageParseError::Http(_) => f.write_str("could not fetch"),
+        }
+    }
+}
+
+impl StdError for MessageParseError {}
+
+/// Fetches a message from its link, such as
+/// `https://discord.com/channels/381880193251409931/381880193700069377/806164913558781963`.
+#[async_trait]
+impl ArgumentConvert for Message {
+    type Err = MessageParseError;
+
+    async fn from_str(ctx: &Context, _: &Message, s: &str) -> Result<Self, Self::Err> {
+        let (_, channel_id, message_id) = parse_message_link(s).ok_or(MessageParseError::InvalidLink)?;
+
+        ctx.http
+            .get_message(channel_id.0, message_id.0)
+            .await
+            .map_err(|why| MessageParseError::Http(Box::new(why)))
+    }
+}
+
+#[cfg(all(test, feature = "cache", feature = "gateway"))]
+mod test {
+    use crate::framework::standard::{macros::command, Args, CommandResult, Delimiter};
+    use crate::internal::test_utils;
+    use crate::model::prelude::*;
+    use crate::utils::CustomMessage;
+
+    #[command]
+    async fn mentions(#[arg] channel: ChannelId, #[arg] role: RoleId) -> CommandResult {
+        assert_eq!(channel, ChannelId(381880193700069377));
+        assert_eq!(role, RoleId(381880193251409931));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn convert_channel_mention() {
+        let ctx = test_utils::context();
+        let msg = CustomMessage::new().build();
+
+        let mut args = Args::new("<#381880193700069377> 381880193251409931 nope", &[Delimiter::Single(' ')]);
+
+        assert_eq!(args.single_converted::<ChannelId>(&ctx, &msg).await.unwrap(), ChannelId(381880193700069377));
+        assert_eq!(args.single_converted::<RoleId>(&ctx, &msg).await.unwrap(), RoleId(381880193251409931));
+        assert!(args.single_converted::<UserId>(&ctx, &msg).await.is_err());
+        assert_eq!(args.remaining(), 1);
+    }
+
+    #[tokio::test]
+    async fn convert_command_arguments() {
+        let ctx = test_utils::context();
+        let msg = CustomMessage::new().build();
+
+        let args = Args::new("<#381880193700069377> <@&381880193251409931>", &[Delimiter::Single(' ')]);
+        mentions(&ctx, &msg, args).await.unwrap();
+
+        let args = Args::new("<#381880193700069377> nope", &[Delimiter::Single(' ')]);
+        assert!(mentions(&ctx, &msg, args).await.is_err());
+    }
+}
@@ -49,16 +49,65 @@
 //!
 //! The same can be accomplished with no embeds by substituting `with_embeds`
 //! with the [`plain`] function.
+//!
+//! The layout can be customised by passing a [`HelpOptions::formatter`],
+//! which receives the commands the user may see:
+//!
+//! ```rust,no_run
+//! # use serenity::framework::standard::{Args, CommandGroup, CommandResult, HelpOptions};
+//! # use serenity::framework::standard::macros::help;
+//! # use serenity::model::prelude::{Message, UserId};
+//! # use serenity::client::Context;
+//! # use std::collections::HashSet;
+//! use serenity::builder::CreateEmbed;
+//! use serenity::framework::standard::help_commands::{self, CustomisedHelpData};
+//!
+//! fn branded<'a>(_: &'a HelpOptions, data: &'a CustomisedHelpData<'a>) -> CreateEmbed {
+//!     let mut embed = CreateEmbed::default();
+//!     embed.title("Ferris Bot");
+//!
+//!     if let CustomisedHelpData::GroupedCommands { groups, .. } = data {
+//!         for group in groups {
+//!             embed.field(group.name, group.command_names.join(" "), false);
+//!         }
+//!     }
+//!
+//!     embed
+//! }
+//!
+//! #[help]
+//! #[group_order("General", "Moderation")]
+//! #[list_aliases(false)]
+//! #[formatter(branded)]
+//! async fn my_help(
+//!    context: &Context,
+//!    msg: &Message,
+//!    args: Args,
+//!    help_options: &'static HelpOptions,
+//!    groups: &[&'static CommandGroup],
+//!    owners: HashSet<UserId>
+//! ) -> CommandResult {
+//! #  #[cfg(all(feature = "cache", feature = "http"))]
+//! # {
+//!     let _ = help_commands::with_embeds(context, msg, args, help_options, groups, owners).await;
+//!     Ok(())
+//! # }
+//! #
+//! # #[cfg(not(all(feature = "cache", feature = "http")))]
+//! # Ok(())
+//! }
+//! ```
 
 #[cfg(all(feature = "cache", feature = "http"))]
 use super::{
     Args, CommandGroup, CommandOptions, Check,
-    has_correct_roles, HelpBehaviour,
+    has_correct_roles, HelpBehaviour, HelpFormatter,
     HelpOptions, has_correct_permissions, OnlyIn,
     structures::Command as InternalCommand,
 };
 #[cfg(all(feature = "cache", feature = "http"))]
 use crate::{
+    builder::CreateEmbed,
     http::CacheHttp,
     cache::Cache,
     client::Context,
@@ -74,8 +123,9 @@ use std::{
     borrow::Borrow,
     collections::HashSet,
     fmt::Write,
-    ops::{Index, IndexMut},
 };
+#[cfg(any(all(feature = "cache", feature = "http"), feature = "command_suggestions"))]
+use std::ops::{Index, IndexMut};
 #[cfg(all(feature = "cache", feature = "http"))]
 use tracing::warn;
 #[cfg(all(feature = "cache", feature = "http"))]
@@ -202,13 +252,13 @@ pub enum CustomisedHelpData<'a> {
 /// Wraps around a `Vec<Vec<T>>` and provides access
 /// via indexing of tuples representing x and y.
 #[derive(Debug)]
-#[cfg(all(feature = "cache", feature = "http"))]
+#[cfg(any(all(feature = "cache", feature = "http"), feature = "command_suggestions"))]
 struct Matrix {
     vec: Vec<usize>,
     width: usize,
 }
 
-#[cfg(all(feature = "cache", feature = "http"))]
+#[cfg(any(all(feature = "cache", feature = "http"), feature = "command_suggestions"))]
 impl Matrix {
     fn new(columns: usize, rows: usize) -> Matrix {
         Matrix {
@@ -218,7 +268,7 @@ impl Matrix {
     }
 }
 
-#[cfg(all(feature = "cache", feature = "http"))]
+#[cfg(any(all(feature = "cache", feature = "http"), feature = "command_suggestions"))]
 impl Index<(usize, usize)> for Matrix {
     type Output = usize;
 
@@ -227,7 +277,7 @@ impl Index<(usize, usize)> for Matrix {
     }
 }
 
-#[cfg(all(feature = "cache", feature = "http"))]
+#[cfg(any(all(feature = "cache", feature = "http"), feature = "command_suggestions"))]
 impl IndexMut<(usize, usize)> for Matrix {
     fn index_mut(&mut self, matrix_entry: (usize, usize)) -> &mut usize {
         &mut self.vec[matrix_entry.1 * self.width + matrix_entry.0]
@@ -236,7 +286,7 @@ impl IndexMut<(usize, usize)> for Matrix {
 
 /// Calculates and returns levenshtein distance between
 /// two passed words.
-#[cfg(all(feature = "cache", feature = "http"))]
+#[cfg(any(all(feature = "cache", feature = "http"), feature = "command_suggestions"))]
 pub(crate) fn levenshtein_distance(word_a: &str, word_b: &str) -> usize {
     let len_a = word_a.chars().count();
     let len_b = word_b.chars().count();
@@ -814,11 +864,18 @@ async fn create_command_group_commands_pair_from_groups<'a>(
 
         let group_with_cmds = create_single_group(ctx, msg, group, &owners, &help_options).await;
 
-        if !group_with_cmds.command_names.is_empty() || !group_with_cmds.sub_groups.is_empty() {
+        let is_empty = group_with_cmds.command_names.is_empty() && group_with_cmds.sub_groups.is_empty();
+
+        if !(is_empty && help_options.hide_empty_groups) {
             listed_groups.push(group_with_cmds);
         }
     }
 
+    let order = help_options.group_order;
+    listed_groups.sort_by_key(|group| {
+        order.iter().position(|name| *name == group.name).unwrap_or(order.len())
+    });
+
     listed_groups
 }
 
@@ -1068,7 +1125,9 @@ fn flatten_group_to_string(
 
     for sub_group in &group.sub_groups {
 
-        if !(sub_group.command_names.is_empty() && sub_group.sub_groups.is_empty()) {
+        let is_empty = sub_group.command_names.is_empty() && sub_group.sub_groups.is_empty();
+
+        if !(is_empty && help_options.hide_empty_groups) {
             let mut sub_group_text = String::default();
 
             flatten_group_to_string(
@@ -1218,7 +1277,7 @@ async fn send_single_command_embed(
 
             embed.field(&help_options.grouped_label, command.group_name, true);
 
-            if !command.aliases.is_empty() {
+            if help_options.list_aliases && !command.aliases.is_empty() {
                 embed.field(
                     &help_options.aliases_label,
                     format!("`{}`", command.aliases.join("`, `")),
@@ -1289,8 +1348,20 @@ async fn send_error_embed(
     }).await
 }
 
+/// Sends the embed built by a custom [`HelpOptions::formatter`].
+#[cfg(all(feature = "cache", feature = "http"))]
+async fn send_formatted_embed(
+    http: impl AsRef<Http>,
+    channel_id: ChannelId,
+    embed: CreateEmbed,
+) -> Result<Message, Error> {
+    channel_id.send_message(&http, |m| m.set_embed(embed)).await
+}
+
 /// Posts an embed showing each individual command group and its commands.
 ///
+/// If [`HelpOptions::formatter`] is set, its embed is sent instead.
+///
 /// # Examples
 ///
 /// Use the command with `exec_help`:
@@ -1332,6 +1403,10 @@ pub async fn with_embeds(
     let formatted_help =
         create_customised_help_data(ctx, msg, &args, &groups, &owners, help_options).await;
 
+    if let Some(formatter) = help_options.formatter {
+        return send_help_with_formatter(ctx, msg, help_options, &formatted_help, formatter).await;
+    }
+
     let response_result = match formatted_help {
         CustomisedHelpData::SuggestedCommands {
             ref help_description,
@@ -1380,6 +1455,26 @@ pub async fn with_embeds(
     }
 }
 
+/// Builds the help-embed with a custom `formatter` and sends it.
+#[cfg(all(feature = "cache", feature = "http"))]
+async fn send_help_with_formatter(
+    ctx: &Context,
+    msg: &Message,
+    help_options: &HelpOptions,
+    formatted_help: &CustomisedHelpData<'_>,
+    formatter: HelpFormatter,
+) -> Option<Message> {
+    let embed = (formatter.0)(help_options, formatted_help);
+
+    match send_formatted_embed(&ctx.http, msg.channel_id, embed).await {
+        Ok(response) => Some(response),
+        Err(why) => {
+            warn_about_failed_send!(formatted_help, why);
+            None
+        },
+    }
+}
+
 /// Turns grouped commands into a `String` taking plain help format into account.
 #[cfg(all(feature = "cache", feature = "http"))]
 fn grouped_commands_to_plain_string(
@@ -1410,7 +1505,7 @@ fn single_command_to_plain_string(help_options: &HelpOptions, command: &Command<
     let mut result = String::default();
     let _ = writeln!(result, "__**{}**__", command.name);
 
-    if !command.aliases.is_empty() {
+    if help_options.list_aliases && !command.aliases.is_empty() {
         let _ = writeln!(
             result,
             "**{}**: `{}`",
@@ -1496,6 +1591,8 @@ fn single_command_to_plain_string(help_options: &HelpOptions, command: &Command<
 
 /// Posts formatted text displaying each individual command group and its commands.
 ///
+/// If [`HelpOptions::formatter`] is set, its embed is sent instead.
+///
 /// # Examples
 ///
 /// Use the command with `exec_help`:
@@ -1537,6 +1634,10 @@ pub async fn plain(
     let formatted_help =
         create_customised_help_data(ctx, msg, &args, &groups, &owners, help_options).await;
 
+    if let Some(formatter) = help_options.formatter {
+        return send_help_with_formatter(ctx, msg, help_options, &formatted_help, formatter).await;
+    }
+
     let result = match formatted_help {
         CustomisedHelpData::SuggestedCommands {
             ref help_description,
@@ -1644,3 +1745,191 @@ mod matrix_tests {
         matrix[(0, 0)];
     }
 }
+
+#[cfg(test)]
+#[cfg(all(feature = "cache", feature = "gateway"))]
+mod formatter_tests {
+    use super::{create_customised_help_data, CustomisedHelpData};
+    use crate::builder::CreateEmbed;
+    use crate::client::Context;
+    use crate::framework::standard::{
+        Args, CommandGroup, CommandOptions, CommandResult, GroupOptions, HelpBehaviour,
+        HelpFormatter, HelpOptions, OnlyIn,
+        structures::Command,
+    };
+    use crate::internal::test_utils;
+    use crate::model::prelude::*;
+    use crate::utils::{Colour, CustomMessage};
+
+    use futures::future::{BoxFuture, FutureExt};
+    use serde_json::json;
+
+    use std::collections::HashSet;
+
+    fn noop<'fut>(_: &'fut Context, _: &'fut Message, _: Args) -> BoxFuture<'fut, CommandResult> {
+        async { Ok(()) }.boxed()
+    }
+
+    macro_rules! command {
+        ($name:ident, $names:expr, $permissions:expr) => {
+            static $name: Command = Command {
+                fun: noop,
+                options: &CommandOptions {
+                    checks: &[],
+                    bucket: None,
+                    names: $names,
+                    desc: None,
+                    delimiters: &[],
+                    usage: None,
+                    examples: &[],
+                    min_args: None,
+                    max_args: None,
+                    allowed_roles: &[],
+                    required_permissions: $permissions,
+                    help_available: true,
+                    only_in: OnlyIn::None,
+                    owners_only: false,
+                    owner_privilege: true,
+                    sub_commands: &[],
+                },
+            };
+        };
+    }
+
+    macro_rules! group {
+        ($name:ident, $group_name:expr, $commands:expr) => {
+            static $name: CommandGroup = CommandGroup {
+                name: $group_name,
+                options: &GroupOptions {
+                    prefixes: &[],
+                    only_in: OnlyIn::None,
+                    owners_only: false,
+                    owner_privilege: true,
+                    help_available: true,
+                    allowed_roles: &[],
+                    required_permissions: Permissions::empty(),
+                    checks: &[],
+                    default_command: None,
+                    description: None,
+                    summary: None,
+                    commands: $commands,
+                    sub_groups: &[],
+                },
+            };
+        };
+    }
+
+    command!(PING, &["ping"], Permissions::empty());
+    command!(WARN, &["warn"], Permissions::empty());
+    command!(BAN, &["ban"], Permissions::BAN_MEMBERS);
+    group!(GENERAL, "General", &[&PING]);
+    group!(MODERATION, "Moderation", &[&WARN, &BAN]);
+
+    /// Lists every group as `Group: command, command`, one per line.
+    fn list_commands(_: &HelpOptions, data: &CustomisedHelpData<'_>) -> CreateEmbed {
+        let mut embed = CreateEmbed::default();
+
+        if let CustomisedHelpData::GroupedCommands { groups, .. } = data {
+            let lines = groups
+                .iter()
+                .map(|group| format!("{}: {}", group.name, group.command_names.join(", ")))
+                .collect::<Vec<_>>();
+
+            embed.description(lines.join("\n"));
+        }
+
+        embed
+    }
+
+    fn help_options() -> HelpOptions {
+        HelpOptions {
+            names: &["help"],
+            suggestion_text: "Did you mean `{}`?",
+            no_help_available_text: "**Error**: No help available.",
+            usage_label: "Usage",
+            usage_sample_label: "Sample usage",
+            ungrouped_label: "Ungrouped",
+            description_label: "Description",
+            grouped_label: "Group",
+            aliases_label: "Aliases",
+            guild_only_text: "Only in servers",
+            checks_label: "Checks",
+            sub_commands_label: "Sub Commands",
+            dm_only_text: "Only in DM",
+            dm_and_guild_text: "In DM and servers",
+            available_text: "Available",
+            command_not_found_text: "**Error**: Command `{}` not found.",
+            individual_command_tip: "To get help with an individual command, pass its name as an argument to this command.",
+            strikethrough_commands_tip_in_dm: None,
+            strikethrough_commands_tip_in_guild: None,
+            group_prefix: "Prefix",
+            lacking_role: HelpBehaviour::Strike,
+            lacking_permissions: HelpBehaviour::Hide,
+            lacking_ownership: HelpBehaviour::Hide,
+            lacking_conditions: HelpBehaviour::Strike,
+            wrong_channel: HelpBehaviour::Strike,
+            embed_error_colour: Colour::DARK_RED,
+            embed_success_colour: Colour::ROSEWATER,
+            max_levenshtein_distance: 0,
+            indention_prefix: "-",
+            group_order: &["Moderation"],
+            hide_empty_groups: true,
+            list_aliases: true,
+            formatter: Some(HelpFormatter(list_commands)),
+        }
+    }
+
+    #[tokio::test]
+    async fn formatter_receives_permitted_commands() {
+        let ctx = test_utils::context();
+
+        // The @everyone role may only send messages, so nobody may ban.
+        let guild: Guild = serde_json::from_value(json!({
+            "afk_timeout": 0,
+            "channels": [],
+            "default_message_notifications": 0,
+            "emojis": [],
+            "explicit_content_filter": 0,
+            "features": [],
+            "id": "1",
+            "joined_at": "2021-01-01T00:00:00+00:00",
+            "large": false,
+            "member_count": 1,
+            "members": [],
+            "mfa_level": 0,
+            "name": "Ferris",
+            "owner_id": "100",
+            "presences": [],
+            "region": "eu",
+            "roles": [{
+                "id": "1",
+                "guild_id": "1",
+                "name": "@everyone",
+                "color": 0,
+                "hoist": false,
+                "managed": false,
+                "permissions": Permissions::SEND_MESSAGES.bits().to_string(),
+                "position": 0,
+            }],
+            "verification_level": 0,
+            "voice_states": [],
+            "preferred_locale": "en-US",
+        })).unwrap();
+        ctx.cache.guilds.write().await.insert(guild.id, guild);
+
+        let mut author = User::default();
+        author.id = UserId(2);
+        let mut msg = CustomMessage::new();
+        msg.author(author).guild_id(GuildId(1)).channel_id(ChannelId(1));
+        let msg = msg.build();
+
+        let args = Args::new("", &[]);
+        let options = help_options();
+        let groups = [&GENERAL, &MODERATION];
+
+        let data = create_customised_help_data(&ctx, &msg, &args, &groups, &HashSet::new(), &options).await;
+        let embed = (options.formatter.unwrap().0)(&options, &data);
+
+        assert_eq!(embed.0["description"], json!("Moderation: `warn`\nGeneral: `ping`"));
+    }
+}
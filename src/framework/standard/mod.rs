@@ -5,17 +5,21 @@ pub mod macros {
 
 mod args;
 mod configuration;
+mod convert;
 mod parse;
 mod structures;
 
 pub use args::{Args, Delimiter, Error as ArgError, Iter, RawArguments};
 pub use configuration::{Configuration, WithWhiteSpace};
+pub use convert::{ArgumentConvert, MessageParseError};
 pub use structures::*;
 
 use structures::buckets::{Bucket, BucketAction};
 pub use structures::buckets::BucketBuilder;
 
 use parse::{ParseError, Invoke};
+#[cfg(feature = "command_suggestions")]
+use help_commands::Suggestions;
 use parse::map::{CommandMap, GroupMap, Map};
 
 use self::buckets::RevertBucket;
@@ -86,6 +90,8 @@ type DispatchHook = for<'fut> fn(&'fut Context, &'fut Message, DispatchError) ->
 type BeforeHook = for<'fut> fn(&'fut Context, &'fut Message, &'fut str) -> BoxFuture<'fut, bool>;
 type AfterHook = for<'fut> fn(&'fut Context, &'fut Message, &'fut str, Result<(), CommandError>) -> BoxFuture<'fut, ()>;
 type UnrecognisedHook = for<'fut> fn(&'fut Context, &'fut Message, &'fut str) -> BoxFuture<'fut, ()>;
+#[cfg(feature = "command_suggestions")]
+type SuggestionsHook = for<'fut> fn(&'fut Context, &'fut Message, &'fut str, &'fut Suggestions) -> BoxFuture<'fut, ()>;
 type NormalMessageHook = for<'fut> fn(&'fut Context, &'fut Message) -> BoxFuture<'fut, ()>;
 type PrefixOnlyHook = for<'fut> fn(&'fut Context, &'fut Message) -> BoxFuture<'fut, ()>;
 
@@ -102,6 +108,8 @@ pub struct StandardFramework {
     after: Option<AfterHook>,
     dispatch: Option<DispatchHook>,
     unrecognised_command: Option<UnrecognisedHook>,
+    #[cfg(feature = "command_suggestions")]
+    unrecognised_command_suggestions: Option<SuggestionsHook>,
     normal_message: Option<NormalMessageHook>,
     prefix_only: Option<PrefixOnlyHook>,
     config: Configuration,
@@ -559,6 +567,47 @@ impl StandardFramework {
         self
     }
 
+    /// Specify the function to be called with the most similar commands if no
+    /// command could be dispatched.
+    ///
+    /// It is only called if at least one registered command is within the
+    /// [`max_suggestion_distance`] of the unrecognised command's name. The
+    /// closest suggestions come first.
+    ///
+    /// # Examples
+    ///
+    /// Replying with the suggestions:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::*;
+    /// # use serenity::model::prelude::*;
+    /// use serenity::framework::standard::help_commands::Suggestions;
+    /// use serenity::framework::standard::macros::hook;
+    /// use serenity::framework::StandardFramework;
+    ///
+    /// #[hook]
+    /// async fn suggest(ctx: &Context, msg: &Message, _: &str, suggestions: &Suggestions) {
+    ///     let names = suggestions.0.iter().map(|s| s.name.as_str()).collect::<Vec<_>>();
+    ///
+    ///     let _ = msg.reply(ctx, format!("Did you mean `{}`?", names.join("`, `"))).await;
+    /// }
+    ///
+    /// # #[cfg(feature = "command_suggestions")]
+    /// # fn run() {
+    /// let framework = StandardFramework::new()
+    ///     .unrecognised_command_suggestions(suggest);
+    /// # }
+    /// ```
+    ///
+    /// [`max_suggestion_distance`]: Configuration::max_suggestion_distance
+    #[cfg(feature = "command_suggestions")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "command_suggestions")))]
+    pub fn unrecognised_command_suggestions(mut self, f: SuggestionsHook) -> Self {
+        self.unrecognised_command_suggestions = Some(f);
+
+        self
+    }
+
     /// Specify the function to be called if a message contains no command.
     ///
     /// # Examples
@@ -641,6 +690,17 @@ impl Framework for StandardFramework {
                     if let Some(unrecognised_command) = &self.unrecognised_command {
                         unrecognised_command(&mut ctx, &msg, &unreg).await;
                     }
+
+                    #[cfg(feature = "command_suggestions")]
+                    if let Some(suggest) = &self.unrecognised_command_suggestions {
+                        let groups = self.groups.iter().map(|(g, _)| *g).collect::<Vec<_>>();
+                        let help_names = self.help.map(|h| h.options.names);
+                        let suggestions = parse::suggestions(&groups, help_names, &self.config, &unreg);
+
+                        if !suggestions.0.is_empty() {
+                            suggest(&mut ctx, &msg, &unreg, &suggestions).await;
+                        }
+                    }
                 }
 
                 if let Some(normal) = &self.normal_message {
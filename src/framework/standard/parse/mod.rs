@@ -1,4 +1,6 @@
 use super::*;
+#[cfg(feature = "command_suggestions")]
+use super::help_commands::{levenshtein_distance, SuggestedCommandName, Suggestions};
 use crate::client::Context;
 use crate::model::prelude::*;
 
@@ -525,6 +527,100 @@ pub async fn command(
     last
 }
 
+/// Suggests registered commands whose names are similar to the unrecognised
+/// `name`, closest first.
+///
+/// Suggestions are named as they have to be invoked, meaning with the
+/// prefixes of their groups and parent commands. Disabled commands and
+/// commands hidden from help are never suggested.
+#[cfg(feature = "command_suggestions")]
+pub fn suggestions(
+    groups: &[&'static CommandGroup],
+    help_names: Option<&[&'static str]>,
+    config: &Configuration,
+    name: &str,
+) -> Suggestions {
+    fn push_suggestion(
+        suggestions: &mut Vec<SuggestedCommandName>,
+        config: &Configuration,
+        name: &str,
+        path: &str,
+        candidate: &str,
+    ) {
+        let distance = levenshtein_distance(name, &to_lowercase(config, candidate));
+
+        if distance <= config.max_suggestion_distance {
+            suggestions.push(SuggestedCommandName {
+                name: format!("{}{}", path, candidate),
+                levenshtein_distance: distance,
+            });
+        }
+    }
+
+    fn from_commands(
+        suggestions: &mut Vec<SuggestedCommandName>,
+        config: &Configuration,
+        name: &str,
+        path: &str,
+        commands: &[&'static Command],
+    ) {
+        for command in commands {
+            if !command.options.help_available {
+                continue;
+            }
+
+            for candidate in command.options.names {
+                if config.disabled_commands.contains(*candidate) {
+                    continue;
+                }
+
+                push_suggestion(suggestions, config, name, path, candidate);
+
+                let path = format!("{}{} ", path, candidate);
+                from_commands(suggestions, config, name, &path, command.options.sub_commands);
+            }
+        }
+    }
+
+    fn from_groups(
+        suggestions: &mut Vec<SuggestedCommandName>,
+        config: &Configuration,
+        name: &str,
+        path: &str,
+        groups: &[&'static CommandGroup],
+    ) {
+        for group in groups {
+            if !group.options.help_available {
+                continue;
+            }
+
+            let path = match group.options.prefixes.first() {
+                Some(prefix) => format!("{}{} ", path, prefix),
+                None => path.to_string(),
+            };
+
+            from_commands(suggestions, config, name, &path, group.options.commands);
+            from_groups(suggestions, config, name, &path, group.options.sub_groups);
+        }
+    }
+
+    let name = to_lowercase(config, name);
+    let mut suggestions = Vec::new();
+
+    for help_name in help_names.unwrap_or_default() {
+        push_suggestion(&mut suggestions, config, &name, "", help_name);
+    }
+
+    from_groups(&mut suggestions, config, &name, "", groups);
+
+    suggestions.sort_by(|a, b| {
+        a.levenshtein_distance.cmp(&b.levenshtein_distance).then_with(|| a.name.cmp(&b.name))
+    });
+    suggestions.dedup_by(|a, b| a.name == b.name);
+
+    Suggestions(suggestions)
+}
+
 #[derive(Debug)]
 pub enum Invoke {
     Command {
@@ -533,3 +629,131 @@ pub enum Invoke {
     },
     Help(&'static str),
 }
+
+#[cfg(all(test, feature = "cache", feature = "gateway"))]
+mod test {
+    use super::prefix;
+    use crate::framework::standard::Configuration;
+    use crate::internal::test_utils;
+    use crate::model::prelude::*;
+
+    use uwl::Stream;
+
+    use std::collections::HashMap;
+
+    #[tokio::test]
+    async fn dynamic_prefix_per_channel() {
+        let ctx = test_utils::context();
+
+        let mut prefixes = HashMap::new();
+        prefixes.insert(ChannelId(1), "!".to_string());
+        prefixes.insert(ChannelId(2), "$".to_string());
+
+        let mut config = Configuration::default();
+        config.prefix("~").dynamic_prefix(move |_, msg| {
+            let prefix = prefixes.get(&msg.channel_id).cloned();
+
+            Box::pin(async move { prefix })
+        });
+
+        let cases = [
+            (1, "!ping", Some("!")),
+            (2, "$ping", Some("$")),
+            (2, "!ping", None),
+            (3, "~ping", Some("~")),
+            (3, "!ping", None),
+        ];
+
+        for &(channel_id, content, expected) in &cases {
+            let mut msg = test_utils::message(channel_id, None);
+            msg.content = content.to_string();
+            let mut stream = Stream::new(&msg.content);
+
+            let found = prefix(&ctx, &msg, &mut stream, &config).await;
+            assert_eq!(found.as_deref(), expected, "{} in channel {}", content, channel_id);
+
+            if expected.is_some() {
+                assert_eq!(stream.rest(), "ping");
+            }
+        }
+    }
+
+    #[cfg(feature = "command_suggestions")]
+    mod suggestions {
+        use super::super::suggestions;
+        use crate::client::Context;
+        use crate::framework::standard::{
+            Args, Command, CommandGroup, CommandOptions, CommandResult, Configuration, GroupOptions, OnlyIn,
+        };
+        use crate::model::prelude::*;
+
+        use futures::future::{BoxFuture, FutureExt};
+
+        fn noop<'fut>(_: &'fut Context, _: &'fut Message, _: Args) -> BoxFuture<'fut, CommandResult> {
+            async { Ok(()) }.boxed()
+        }
+
+        static HELLO_OPTIONS: CommandOptions = CommandOptions {
+            checks: &[],
+            bucket: None,
+            names: &["hello", "hi"],
+            desc: None,
+            delimiters: &[],
+            usage: None,
+            examples: &[],
+            min_args: None,
+            max_args: None,
+            allowed_roles: &[],
+            required_permissions: Permissions::empty(),
+            help_available: true,
+            only_in: OnlyIn::None,
+            owners_only: false,
+            owner_privilege: true,
+            sub_commands: &[],
+        };
+        static HELLO: Command = Command { fun: noop, options: &HELLO_OPTIONS };
+
+        static GENERAL_OPTIONS: GroupOptions = GroupOptions {
+            prefixes: &["general"],
+            only_in: OnlyIn::None,
+            owners_only: false,
+            owner_privilege: true,
+            help_available: true,
+            allowed_roles: &[],
+            required_permissions: Permissions::empty(),
+            checks: &[],
+            default_command: None,
+            description: None,
+            summary: None,
+            commands: &[&HELLO],
+            sub_groups: &[],
+        };
+        static GENERAL: CommandGroup = CommandGroup { name: "General", options: &GENERAL_OPTIONS };
+
+        #[test]
+        fn suggest_similar_commands() {
+            let mut config = Configuration::default();
+            let names = |name| {
+                suggestions(&[&GENERAL], Some(&["help"]), &config, name)
+                    .0
+                    .into_iter()
+                    .map(|s| (s.name, s.levenshtein_distance))
+                    .collect::<Vec<_>>()
+            };
+
+            assert_eq!(names("hlep"), vec![("help".to_string(), 2)]);
+            assert_eq!(names("helo"), vec![("general hello".to_string(), 1), ("help".to_string(), 1)]);
+            assert_eq!(names("ho"), vec![("general hi".to_string(), 1)]);
+            assert!(names("HLEP").is_empty());
+            assert!(names("xyzzy").is_empty());
+
+            config.case_insensitivity(true);
+            let names = |name| suggestions(&[&GENERAL], Some(&["help"]), &config, name).0;
+            assert_eq!(names("HLEP")[0].name, "help");
+
+            config.max_suggestion_distance(1);
+            let names = |name| suggestions(&[&GENERAL], Some(&["help"]), &config, name).0;
+            assert!(names("hlep").is_empty());
+        }
+    }
+}
@@ -41,6 +41,9 @@ pub(crate) enum Bucket {
     User(TicketCounter),
     /// The bucket will collect tickets per guild.
     Guild(TicketCounter),
+    /// The bucket will collect tickets per user within a guild, and per user
+    /// in direct messages.
+    Member(TicketCounter),
     /// The bucket will collect tickets per channel.
     Channel(TicketCounter),
     /// The bucket will collect tickets per category.
@@ -55,22 +58,23 @@ impl Bucket {
     #[inline]
     pub async fn take(&mut self, ctx: &Context, msg: &Message) -> Option<BucketAction> {
         match self {
-            Self::Global(counter) => counter.take(ctx, msg, 0).await,
-            Self::User(counter) => counter.take(ctx, msg, msg.author.id.0).await,
+            Self::Global(counter) => counter.take(ctx, msg, (0, 0)).await,
+            Self::User(counter) => counter.take(ctx, msg, (msg.author.id.0, 0)).await,
             Self::Guild(counter) => {
                 if let Some(guild_id) = msg.guild_id {
-                    counter.take(ctx, msg, guild_id.0).await
+                    counter.take(ctx, msg, (guild_id.0, 0)).await
                 } else {
                     None
                 }
             }
-            Self::Channel(counter) => counter.take(ctx, msg, msg.channel_id.0).await,
+            Self::Member(counter) => counter.take(ctx, msg, member_key(msg)).await,
+            Self::Channel(counter) => counter.take(ctx, msg, (msg.channel_id.0, 0)).await,
             // This requires the cache, as messages do not contain their channel's
             // category.
             #[cfg(feature = "cache")]
             Self::Category(counter) =>
                 if let Some(category_id) = msg.category_id(ctx).await {
-                    counter.take(ctx, msg, category_id.0).await
+                    counter.take(ctx, msg, (category_id.0, 0)).await
                 } else {
                     None
                 },
@@ -80,30 +84,42 @@ impl Bucket {
     #[inline]
     pub async fn give(&mut self, ctx: &Context, msg: &Message) {
         match self {
-            Self::Global(counter) => counter.give(ctx, msg, 0).await,
-            Self::User(counter) => counter.give(ctx, msg, msg.author.id.0).await,
+            Self::Global(counter) => counter.give(ctx, msg, (0, 0)).await,
+            Self::User(counter) => counter.give(ctx, msg, (msg.author.id.0, 0)).await,
             Self::Guild(counter) => {
                 if let Some(guild_id) = msg.guild_id {
-                    counter.give(ctx, msg, guild_id.0).await
+                    counter.give(ctx, msg, (guild_id.0, 0)).await
                 }
             }
-            Self::Channel(counter) => counter.give(ctx, msg, msg.channel_id.0).await,
+            Self::Member(counter) => counter.give(ctx, msg, member_key(msg)).await,
+            Self::Channel(counter) => counter.give(ctx, msg, (msg.channel_id.0, 0)).await,
             // This requires the cache, as messages do not contain their channel's
             // category.
             #[cfg(feature = "cache")]
             Self::Category(counter) =>
                 if let Some(category_id) = msg.category_id(ctx).await {
-                    counter.give(ctx, msg, category_id.0).await
+                    counter.give(ctx, msg, (category_id.0, 0)).await
                 }
             }
         }
 }
 
+/// The key of the tickets of a user within a guild.
+///
+/// Messages sent in direct messages have no guild, so their key falls back to
+/// the author alone, like the tickets of a [`Bucket::User`].
+fn member_key(msg: &Message) -> (u64, u64) {
+    (msg.author.id.0, msg.guild_id.map_or(0, |guild_id| guild_id.0))
+}
+
 /// Keeps track of who owns how many tickets and when they accessed the last
 /// time.
+///
+/// Ticket owners are keyed by their Id, paired with the Id of their guild for
+/// a [`Bucket::Member`], or `0` otherwise.
 pub(crate) struct TicketCounter {
     pub ratelimit: Ratelimit,
-    pub tickets_for: HashMap<u64, UnitRatelimit>,
+    pub tickets_for: HashMap<(u64, u64), UnitRatelimit>,
     pub check: Option<Check>,
     pub await_ratelimits: bool,
 }
@@ -134,7 +150,7 @@ impl TicketCounter {
     /// However there is no contract: It does not matter what
     /// the caller ends up doing, receiving some action eventually means
     /// no ticket can be taken and the duration must elapse.
-    pub async fn take(&mut self, ctx: &Context, msg: &Message, id: u64) -> Option<BucketAction> {
+    pub async fn take(&mut self, ctx: &Context, msg: &Message, id: (u64, u64)) -> Option<BucketAction> {
         if let Some(ref check) = self.check {
 
             if !(check)(ctx, msg).await {
@@ -195,7 +211,7 @@ impl TicketCounter {
     /// matching ticket holder.
     /// Only call this if the mutable owner already took a ticket in this
     /// atomic execution of calling `take` and `give`.
-    pub async fn give(&mut self, ctx: &Context, msg: &Message, id: u64) {
+    pub async fn give(&mut self, ctx: &Context, msg: &Message, id: (u64, u64)) {
         if let Some(ref check) = self.check {
 
             if !(check)(ctx, msg).await {
@@ -240,6 +256,7 @@ impl std::fmt::Display for RevertBucket {
 impl std::error::Error for RevertBucket {}
 
 /// Decides what a bucket will use to collect tickets for.
+#[non_exhaustive]
 pub enum LimitedFor {
     /// The bucket will collect tickets for every invocation of a command.
     Global,
@@ -247,6 +264,9 @@ pub enum LimitedFor {
     User,
     /// The bucket will collect tickets per guild.
     Guild,
+    /// The bucket will collect tickets per user within a guild, and per user
+    /// in direct messages.
+    Member,
     /// The bucket will collect tickets per channel.
     Channel,
     /// The bucket will collect tickets per category.
@@ -311,6 +331,17 @@ impl BucketBuilder {
         }
     }
 
+    /// A bucket collecting tickets per user within a guild, so that a user
+    /// limited in one guild can still use the command in another.
+    ///
+    /// In direct messages, tickets are collected per user.
+    pub fn new_member() -> Self {
+        Self {
+            limited_for: LimitedFor::Member,
+            ..Default::default()
+        }
+    }
+
     /// A bucket collecting tickets per channel.
     pub fn new_channel() -> Self {
         Self {
@@ -406,6 +437,7 @@ impl BucketBuilder {
         match self.limited_for {
             LimitedFor::User => Bucket::User(counter),
             LimitedFor::Guild => Bucket::Guild(counter),
+            LimitedFor::Member => Bucket::Member(counter),
             LimitedFor::Channel => Bucket::Channel(counter),
             // This requires the cache, as messages do not contain their channel's
             // category.
@@ -415,3 +447,38 @@ impl BucketBuilder {
         }
     }
 }
+
+#[cfg(all(test, feature = "cache", feature = "gateway"))]
+mod test {
+    use super::{BucketAction, BucketBuilder};
+    use crate::internal::test_utils;
+    use crate::model::prelude::*;
+
+    #[tokio::test]
+    async fn member_bucket() {
+        let ctx = test_utils::context();
+
+        let mut builder = BucketBuilder::new_member();
+        builder.time_span(60).limit(1);
+        let mut bucket = builder.construct();
+
+        let limited = |action: Option<BucketAction>| matches!(action, Some(BucketAction::CancelWith(_)));
+
+        for &(user_id, guild_id) in &[(1, Some(1)), (1, Some(2)), (2, Some(1)), (2, Some(2)), (1, None)] {
+            let mut msg = test_utils::message(1, guild_id);
+            msg.author.id = UserId(user_id);
+
+            assert!(!limited(bucket.take(&ctx, &msg).await), "{} in {:?}", user_id, guild_id);
+            assert!(limited(bucket.take(&ctx, &msg).await), "{} in {:?}", user_id, guild_id);
+        }
+
+        // Giving back the ticket of one member leaves the others limited.
+        let mut msg = test_utils::message(1, Some(1));
+        msg.author.id = UserId(1);
+        bucket.give(&ctx, &msg).await;
+        assert!(!limited(bucket.take(&ctx, &msg).await));
+
+        msg.guild_id = Some(GuildId(2));
+        assert!(limited(bucket.take(&ctx, &msg).await));
+    }
+}
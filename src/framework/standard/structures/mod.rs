@@ -10,7 +10,9 @@ use crate::model::{
     id::UserId,
 };
 use crate::utils::Colour;
+use crate::builder::CreateEmbed;
 use super::Args;
+use super::help_commands::CustomisedHelpData;
 use futures::future::BoxFuture;
 
 mod check;
@@ -213,6 +215,42 @@ pub struct HelpOptions {
     /// Help will use this as prefix to express how deeply nested a command or
     /// group is.
     pub indention_prefix: &'static str,
+    /// Names of groups that help will list first, in the given order.
+    /// Groups not named here follow in the order they were registered.
+    pub group_order: &'static [&'static str],
+    /// Whether help will leave out groups without any eligible commands.
+    pub hide_empty_groups: bool,
+    /// Whether help will list a command's aliases.
+    pub list_aliases: bool,
+    /// If set, help will send the embed returned by this function instead of
+    /// building its default layout.
+    ///
+    /// The function receives the same data the default layout would display,
+    /// meaning commands are already filtered according to the user's
+    /// permissions and the `lacking_*` options.
+    pub formatter: Option<HelpFormatter>,
+}
+
+pub type HelpFormatterFn = for<'a> fn(&'a HelpOptions, &'a CustomisedHelpData<'a>) -> CreateEmbed;
+
+/// A function turning the data of a help-request into an embed, see
+/// [`HelpOptions::formatter`].
+#[derive(Clone, Copy)]
+pub struct HelpFormatter(pub HelpFormatterFn);
+
+impl fmt::Debug for HelpFormatter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("HelpFormatter")
+            .field(&"<fn>")
+            .finish()
+    }
+}
+
+impl PartialEq for HelpFormatter {
+    #[inline]
+    fn eq(&self, other: &HelpFormatter) -> bool {
+        self.0 as usize == other.0 as usize
+    }
 }
 
 #[derive(Debug, Default, PartialEq)]
@@ -66,7 +66,12 @@ use std::fmt::{Display, Formatter, Result as FmtResult};
 #[cfg(feature = "client")]
 use crate::client::bridge::gateway::ShardClientMessage;
 
-pub type CurrentPresence = (Option<Activity>, OnlineStatus);
+/// The presence of a shard: its activities and its online status.
+///
+/// **Note**: This used to hold a single optional activity. As a presence can
+/// show multiple activities at once, it now holds all of them, an empty list
+/// meaning that no activity is shown.
+pub type CurrentPresence = (Vec<Activity>, OnlineStatus);
 
 use async_tungstenite::{WebSocketStream, tokio::ConnectStream};
 
@@ -164,10 +169,15 @@ pub enum InterMessage {
     Json(Value),
 }
 
+/// An action to perform on a [`Shard`], as returned by
+/// [`Shard::handle_event`].
 #[non_exhaustive]
 pub enum ShardAction {
+    /// Indicator that a heartbeat should be sent.
     Heartbeat,
+    /// Indicator that the shard should identify.
     Identify,
+    /// Indicator that the shard should reconnect.
     Reconnect(ReconnectType),
 }
 
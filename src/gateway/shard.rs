@@ -1,11 +1,18 @@
 use crate::constants::{self, close_codes};
 use crate::internal::prelude::*;
+use crate::internal::ws_impl::{
+    convert_compressed_ws_message,
+    ReceiverExt,
+    SenderExt,
+    ZlibStreamInflater,
+};
 use crate::model::{
     event::{Event, GatewayEvent},
     gateway::Activity,
     id::GuildId,
     user::OnlineStatus
 };
+use serde::de::Deserialize;
 use tokio::sync::Mutex;
 use crate::client::bridge::gateway::{GatewayIntents, ChunkGuildFilter};
 use std::{
@@ -53,8 +60,11 @@ use crate::internal::ws_impl::create_native_tls_client;
 /// leave the client to do it.
 ///
 /// This can be done by passing in the required parameters to [`new`]. You can
-/// then manually handle the shard yourself and receive events via
-/// [`receive`].
+/// then manually handle the shard yourself: receive events via
+/// [`next_event`], pass them to [`handle_event`] and perform the returned
+/// [`ShardAction`], such as identifying or reconnecting. Heartbeats must be
+/// sent by calling [`check_heartbeat`] regularly, and arbitrary payloads can
+/// be sent via [`send`].
 ///
 /// **Note**: You _really_ do not need to do this. Just call one of the
 /// appropriate methods on the [`Client`].
@@ -65,7 +75,10 @@ use crate::internal::ws_impl::create_native_tls_client;
 ///
 /// [`Client`]: crate::Client
 /// [`new`]: Self::new
-/// [`receive`]: #method.receive
+/// [`next_event`]: Self::next_event
+/// [`handle_event`]: Self::handle_event
+/// [`check_heartbeat`]: Self::check_heartbeat
+/// [`send`]: Self::send
 /// [docs]: https://discord.com/developers/docs/topics/gateway#sharding
 /// [module docs]: crate::gateway#sharding
 pub struct Shard {
@@ -81,11 +94,16 @@ pub struct Shard {
     /// [`latency`]: fn@Self::latency
     heartbeat_instants: (Option<Instant>, Option<Instant>),
     heartbeat_interval: Option<u64>,
+    /// The inflate context of the connection, if it uses the `zlib-stream`
+    /// transport compression.
+    inflater: Option<ZlibStreamInflater>,
     /// This is used by the heartbeater to determine whether the last
     /// heartbeat was sent without an acknowledgement, and whether to reconnect.
     // This _must_ be set to `true` in `Shard::handle_event`'s
     // `Ok(GatewayEvent::HeartbeatAck)` arm.
     last_heartbeat_acknowledged: bool,
+    /// The time between the last heartbeat sent and its acknowledgement.
+    latency: Option<StdDuration>,
     seq: u64,
     session_id: Option<String>,
     shard_info: [u64; 2],
@@ -131,16 +149,44 @@ impl Shard {
     /// #     Ok(())
     /// # }
     /// ```
+    #[inline]
     pub async fn new(
         ws_url: Arc<Mutex<String>>,
         token: &str,
         shard_info: [u64; 2],
         intents: GatewayIntents,
+    ) -> Result<Shard> {
+        Self::new_with_compression(ws_url, token, shard_info, intents, false).await
+    }
+
+    /// Instantiates a new instance of a Shard like [`new`], which may use the
+    /// `zlib-stream` transport compression.
+    ///
+    /// With transport compression, the whole connection is compressed rather
+    /// than only the large payloads, which saves bandwidth at the expense of
+    /// keeping an inflate context in memory for the connection.
+    ///
+    /// **Note**: Payloads must then be received via [`recv_json`] to be
+    /// inflated.
+    ///
+    /// [`new`]: Self::new
+    /// [`recv_json`]: Self::recv_json
+    pub async fn new_with_compression(
+        ws_url: Arc<Mutex<String>>,
+        token: &str,
+        shard_info: [u64; 2],
+        intents: GatewayIntents,
+        transport_compression: bool,
     ) -> Result<Shard> {
         let url = ws_url.lock().await.clone();
-        let client = connect(&url).await?;
+        let client = connect(&url, transport_compression).await?;
+        let inflater = if transport_compression {
+            Some(ZlibStreamInflater::new())
+        } else {
+            None
+        };
 
-        let current_presence = (None, OnlineStatus::Online);
+        let current_presence = (Vec::new(), OnlineStatus::Online);
         let heartbeat_instants = (None, None);
         let heartbeat_interval = None;
         let last_heartbeat_acknowledged = true;
@@ -154,7 +200,9 @@ impl Shard {
             current_presence,
             heartbeat_instants,
             heartbeat_interval,
+            inflater,
             last_heartbeat_acknowledged,
+            latency: None,
             seq,
             stage,
             started: Instant::now(),
@@ -205,6 +253,93 @@ impl Shard {
         self.heartbeat_instants.1.as_ref()
     }
 
+    /// Whether the shard uses the `zlib-stream` transport compression.
+    #[inline]
+    pub fn transport_compression(&self) -> bool {
+        self.inflater.is_some()
+    }
+
+    /// Receives the next payload from the gateway, if any is received within
+    /// half a second.
+    ///
+    /// If the shard uses transport compression, this returns `None` until all
+    /// the messages of a payload were received.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`GatewayError::Closed`] if the gateway closed the
+    /// connection, or an [`Error::Json`] if the payload is malformed.
+    pub async fn recv_json(&mut self) -> Result<Option<Value>> {
+        match self.inflater.as_mut() {
+            Some(inflater) => {
+                let message = self.client.recv_message().await?;
+
+                convert_compressed_ws_message(message, inflater)
+            },
+            None => self.client.recv_json().await,
+        }
+    }
+
+    /// Receives the next event from the gateway, if any is received within
+    /// half a second.
+    ///
+    /// The event must then be passed to [`handle_event`] to update the state
+    /// of the shard.
+    ///
+    /// # Examples
+    ///
+    /// Running the receive loop of a shard manually:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::gateway::{ReconnectType, Shard, ShardAction};
+    /// #
+    /// # async fn run(mut shard: Shard) -> Result<(), Box<dyn std::error::Error>> {
+    /// loop {
+    ///     if !shard.check_heartbeat().await {
+    ///         shard.reconnect().await?;
+    ///     }
+    ///
+    ///     let event = match shard.next_event().await {
+    ///         Ok(Some(event)) => Ok(event),
+    ///         Ok(None) => continue,
+    ///         Err(why) => Err(why),
+    ///     };
+    ///
+    ///     match shard.handle_event(&event)? {
+    ///         Some(ShardAction::Heartbeat) => shard.heartbeat().await?,
+    ///         Some(ShardAction::Identify) => shard.identify().await?,
+    ///         Some(ShardAction::Reconnect(ReconnectType::Resume)) => shard.resume().await?,
+    ///         Some(ShardAction::Reconnect(_)) => shard.reconnect().await?,
+    ///         _ => {},
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`GatewayError::Closed`] if the gateway closed the
+    /// connection, or an [`Error::Json`] if the payload is malformed or isn't
+    /// a known event.
+    ///
+    /// [`handle_event`]: Self::handle_event
+    pub async fn next_event(&mut self) -> Result<Option<GatewayEvent>> {
+        match self.recv_json().await? {
+            Some(value) => Ok(Some(GatewayEvent::deserialize(value)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Sends a payload to the gateway.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Tungstenite`] if the payload couldn't be sent.
+    #[inline]
+    pub async fn send(&mut self, value: &Value) -> Result<()> {
+        self.client.send_json(value).await
+    }
+
     /// Sends a heartbeat to the gateway with the current sequence.
     ///
     /// This sets the last heartbeat time to now, and
@@ -265,7 +400,15 @@ impl Shard {
     #[inline]
     #[instrument(skip(self))]
     pub fn set_activity(&mut self, activity: Option<Activity>) {
-        self.current_presence.0 = activity;
+        self.current_presence.0 = activity.into_iter().collect();
+    }
+
+    /// Sets all the activities of the shard's presence, replacing the
+    /// existing ones.
+    #[inline]
+    #[instrument(skip(self))]
+    pub fn set_activities(&mut self, activities: Vec<Activity>) {
+        self.current_presence.0 = activities;
     }
 
     #[inline]
@@ -476,16 +619,24 @@ impl Shard {
         }))
     }
 
-    /// Handles an event from the gateway over the receiver, requiring the
-    /// receiver to be passed if a reconnect needs to occur.
+    /// Handles an event received from the gateway via [`next_event`],
+    /// updating the state of the shard, such as its sequence and session.
+    ///
+    /// Errors of [`next_event`] must be passed as well, as the gateway closing
+    /// the connection is reported as one.
     ///
-    /// The best case scenario is that one of two values is returned:
+    /// Returns the [`ShardAction`] the caller must then perform, if any:
     ///
-    /// - `Ok(None)`: a heartbeat, late hello, or session invalidation was
-    ///   received;
-    /// - `Ok(Some((event, None)))`: an op0 dispatch was received, and the
-    ///   shard's voice state will be updated, _if_ the `voice` feature is
-    ///   enabled.
+    /// - [`ShardAction::Heartbeat`]: send a heartbeat via [`heartbeat`];
+    /// - [`ShardAction::Identify`]: identify via [`identify`];
+    /// - [`ShardAction::Reconnect`]: reconnect via [`resume`] or
+    ///   [`reconnect`], depending on the [`ReconnectType`].
+    ///
+    /// [`next_event`]: Self::next_event
+    /// [`heartbeat`]: Self::heartbeat
+    /// [`identify`]: Self::identify
+    /// [`resume`]: Self::resume
+    /// [`reconnect`]: Self::reconnect
     ///
     /// # Errors
     ///
@@ -501,13 +652,21 @@ impl Shard {
     /// Returns a `GatewayError::OverloadedShard` if the shard would have too
     /// many guilds assigned to it.
     #[instrument(skip(self))]
-    pub(crate) fn handle_event(&mut self, event: &Result<GatewayEvent>)
+    pub fn handle_event(&mut self, event: &Result<GatewayEvent>)
         -> Result<Option<ShardAction>> {
         match *event {
             Ok(GatewayEvent::Dispatch(seq, ref event)) => self.handle_gateway_dispatch(seq, event),
             Ok(GatewayEvent::Heartbeat(s)) => self.handle_heartbeat_event(s),
             Ok(GatewayEvent::HeartbeatAck) => {
-                self.heartbeat_instants.1 = Some(Instant::now());
+                let received = Instant::now();
+
+                // Only an acknowledgement of a heartbeat still waiting for one
+                // tells the latency.
+                if !self.last_heartbeat_acknowledged {
+                    self.latency = heartbeat_latency(self.heartbeat_instants.0, received);
+                }
+
+                self.heartbeat_instants.1 = Some(received);
                 self.last_heartbeat_acknowledged = true;
 
                 trace!("[Shard {:?}] Received heartbeat ack", self.shard_info);
@@ -622,18 +781,16 @@ impl Shard {
         }
     }
 
-    /// Calculates the heartbeat latency between the shard and the gateway.
-    // Shamelessly stolen from brayzure's commit in eris:
-    // <https://github.com/abalabahaha/eris/commit/0ce296ae9a542bcec0edf1c999ee2d9986bed5a6>
-    #[instrument(skip(self))]
+    /// Returns the heartbeat latency between the shard and the gateway, which
+    /// is the time between the last heartbeat sent and its acknowledgement.
+    ///
+    /// This keeps the latency of the previous heartbeat until the
+    /// acknowledgement of the next one is received.
+    ///
+    /// Returns `None` if no heartbeat has been acknowledged yet.
+    #[inline]
     pub fn latency(&self) -> Option<StdDuration> {
-        if let (Some(sent), Some(received)) = self.heartbeat_instants {
-            if received > sent {
-                return Some(received - sent);
-            }
-        }
-
-        None
+        self.latency
     }
 
     /// Performs a deterministic reconnect.
@@ -777,7 +934,12 @@ impl Shard {
         self.stage = ConnectionStage::Connecting;
         self.started = Instant::now();
         let url = &self.ws_url.lock().await.clone();
-        let client = connect(&url).await?;
+
+        if let Some(inflater) = self.inflater.as_mut() {
+            inflater.reset();
+        }
+
+        let client = connect(url, self.inflater.is_some()).await?;
         self.stage = ConnectionStage::Handshake;
 
         Ok(client)
@@ -788,6 +950,7 @@ impl Shard {
         self.heartbeat_instants = (Some(Instant::now()), None);
         self.heartbeat_interval = None;
         self.last_heartbeat_acknowledged = true;
+        self.latency = None;
         self.session_id = None;
         self.stage = ConnectionStage::Disconnected;
         self.seq = 0;
@@ -833,24 +996,170 @@ impl Shard {
 }
 
 #[cfg(all(feature = "rustls_backend", not(feature = "native_tls_backend")))]
-async fn connect(base_url: &str) -> Result<WsStream> {
-    let url = build_gateway_url(base_url)?;
+async fn connect(base_url: &str, transport_compression: bool) -> Result<WsStream> {
+    let url = build_gateway_url(base_url, transport_compression)?;
 
     Ok(create_rustls_client(url).await?)
 }
 
 #[cfg(feature = "native_tls_backend")]
-async fn connect(base_url: &str) -> Result<WsStream> {
-    let url = build_gateway_url(base_url)?;
+async fn connect(base_url: &str, transport_compression: bool) -> Result<WsStream> {
+    let url = build_gateway_url(base_url, transport_compression)?;
 
     Ok(create_native_tls_client(url).await?)
 }
 
-fn build_gateway_url(base: &str) -> Result<Url> {
-    Url::parse(&format!("{}?v={}", base, constants::GATEWAY_VERSION))
+fn build_gateway_url(base: &str, transport_compression: bool) -> Result<Url> {
+    let compress = if transport_compression { "&compress=zlib-stream" } else { "" };
+
+    Url::parse(&format!("{}?v={}{}", base, constants::GATEWAY_VERSION, compress))
         .map_err(|why| {
             warn!("Error building gateway URL with base `{}`: {:?}", base, why);
 
             Error::Gateway(GatewayError::BuildingUrl)
         })
 }
+
+// Shamelessly stolen from brayzure's commit in eris:
+// <https://github.com/abalabahaha/eris/commit/0ce296ae9a542bcec0edf1c999ee2d9986bed5a6>
+fn heartbeat_latency(sent: Option<Instant>, received: Instant) -> Option<StdDuration> {
+    match sent {
+        Some(sent) if received >= sent => Some(received - sent),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{build_gateway_url, heartbeat_latency, Shard};
+    use crate::client::bridge::gateway::GatewayIntents;
+    use crate::gateway::{ConnectionStage, ShardAction};
+    use crate::model::event::{Event, GatewayEvent};
+    use async_tungstenite::tungstenite::{Error as TungsteniteError, Message};
+    use futures::{SinkExt, Stream, StreamExt};
+    use serde_json::{json, Value};
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
+    use tokio::net::TcpListener;
+    use tokio::sync::Mutex;
+
+    #[test]
+    fn test_build_gateway_url() {
+        let url = build_gateway_url("wss://gateway.discord.gg", true).unwrap();
+        assert_eq!(url.query_pairs().find(|(key, _)| key == "compress").unwrap().1, "zlib-stream");
+
+        let url = build_gateway_url("wss://gateway.discord.gg", false).unwrap();
+        assert!(url.query_pairs().all(|(key, _)| key != "compress"));
+    }
+
+    #[test]
+    fn test_heartbeat_latency() {
+        let sent = Instant::now();
+        let received = sent + Duration::from_millis(42);
+
+        assert_eq!(heartbeat_latency(Some(sent), received), Some(Duration::from_millis(42)));
+
+        // No heartbeat was sent, or it was sent after the acknowledgement.
+        assert_eq!(heartbeat_latency(None, received), None);
+        assert_eq!(heartbeat_latency(Some(received + Duration::from_millis(1)), received), None);
+    }
+
+    async fn next_event(shard: &mut Shard) -> GatewayEvent {
+        loop {
+            if let Some(event) = shard.next_event().await.unwrap() {
+                return event;
+            }
+        }
+    }
+
+    async fn recv_payload<S>(ws: &mut S) -> Value
+    where S: Stream<Item = Result<Message, TungsteniteError>> + Unpin {
+        match ws.next().await {
+            Some(Ok(Message::Text(text))) => serde_json::from_str(&text).unwrap(),
+            other => panic!("unexpected message: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_manual_event_loop() {
+        let mut listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+
+        // A gateway expecting the shard to identify and heartbeat, and sending
+        // back the last payload it receives.
+        let gateway = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut ws = async_tungstenite::tokio::accept_async(stream).await.unwrap();
+            let hello = json!({"op": 10, "d": {"heartbeat_interval": 41250}});
+            let ready = json!({
+                "op": 0,
+                "s": 1,
+                "t": "READY",
+                "d": {
+                    "guilds": [],
+                    "session_id": "session",
+                    "shard": [0, 1],
+                    "user": {
+                        "id": "1",
+                        "avatar": null,
+                        "bot": true,
+                        "discriminator": "0001",
+                        "email": null,
+                        "mfa_enabled": false,
+                        "username": "bot",
+                        "verified": true,
+                    },
+                    "v": 8,
+                },
+            });
+            let ack = json!({"op": 11, "d": null});
+
+            ws.send(Message::Text(hello.to_string())).await.unwrap();
+            let identify = recv_payload(&mut ws).await;
+            ws.send(Message::Text(ready.to_string())).await.unwrap();
+            let heartbeat = recv_payload(&mut ws).await;
+            ws.send(Message::Text(ack.to_string())).await.unwrap();
+            let payload = recv_payload(&mut ws).await;
+
+            (identify, heartbeat, payload)
+        });
+
+        let ws_url = Arc::new(Mutex::new(url));
+        let mut shard = Shard::new(ws_url, "token", [0, 1], GatewayIntents::GUILDS).await.unwrap();
+        assert_eq!(shard.stage(), ConnectionStage::Handshake);
+
+        let event = next_event(&mut shard).await;
+        assert!(matches!(event, GatewayEvent::Hello(41250)));
+        assert!(matches!(shard.handle_event(&Ok(event)), Ok(Some(ShardAction::Identify))));
+        assert_eq!(shard.heartbeat_interval(), Some(&41250));
+
+        shard.identify().await.unwrap();
+        assert_eq!(shard.stage(), ConnectionStage::Identifying);
+
+        let event = next_event(&mut shard).await;
+        assert!(matches!(event, GatewayEvent::Dispatch(1, Event::Ready(_))));
+        assert!(matches!(shard.handle_event(&Ok(event)), Ok(None)));
+        assert_eq!(shard.stage(), ConnectionStage::Connected);
+        assert_eq!(shard.session_id().map(String::as_str), Some("session"));
+        assert_eq!(shard.seq(), 1);
+
+        shard.heartbeat().await.unwrap();
+        assert!(!shard.last_heartbeat_acknowledged());
+
+        let event = next_event(&mut shard).await;
+        assert!(matches!(event, GatewayEvent::HeartbeatAck));
+        assert!(matches!(shard.handle_event(&Ok(event)), Ok(None)));
+        assert!(shard.last_heartbeat_acknowledged());
+        assert!(shard.latency().is_some());
+
+        let payload = json!({"op": 8, "d": {"guild_id": "1", "query": "", "limit": 0}});
+        shard.send(&payload).await.unwrap();
+
+        let (identify, heartbeat, received) = gateway.await.unwrap();
+        assert_eq!(identify["op"], 2);
+        assert_eq!(identify["d"]["token"], "token");
+        assert_eq!(identify["d"]["shard"], json!([0, 1]));
+        assert_eq!(heartbeat, json!({"op": 1, "d": 1}));
+        assert_eq!(received, payload);
+    }
+}
@@ -3,6 +3,7 @@ use crate::constants::{self, OpCode};
 use crate::gateway::{CurrentPresence, WsStream};
 use crate::internal::prelude::*;
 use crate::internal::ws_impl::SenderExt;
+use crate::model::gateway::Activity;
 use crate::model::id::GuildId;
 use async_trait::async_trait;
 use serde_json::json;
@@ -117,7 +118,7 @@ impl WebSocketGatewayClientExt for WsStream {
         shard_info: &[u64; 2],
         current_presence: &CurrentPresence,
     ) -> Result<()> {
-        let &(ref activity, ref status) = current_presence;
+        let (activities, status) = current_presence;
         let now = SystemTime::now();
 
         debug!("[Shard {:?}] Sending presence update", shard_info);
@@ -128,11 +129,7 @@ impl WebSocketGatewayClientExt for WsStream {
                 "afk": false,
                 "since": now,
                 "status": status.name(),
-                "game": activity.as_ref().map(|x| json!({
-                    "name": x.name,
-                    "type": x.kind,
-                    "url": x.url,
-                })),
+                "activities": activities.iter().map(activity_payload).collect::<Vec<_>>(),
             },
         })).await
     }
@@ -157,3 +154,59 @@ impl WebSocketGatewayClientExt for WsStream {
         })).await.map_err(From::from)
     }
 }
+
+/// The payload of an activity sent in a presence update, only including the
+/// fields a bot is allowed to set.
+fn activity_payload(activity: &Activity) -> Value {
+    let mut payload = json!({
+        "name": activity.name,
+        "type": activity.kind,
+    });
+
+    if let Some(ref url) = activity.url {
+        payload["url"] = Value::String(url.clone());
+    }
+
+    if let Some(ref state) = activity.state {
+        payload["state"] = Value::String(state.clone());
+    }
+
+    payload
+}
+
+#[cfg(test)]
+mod test {
+    use super::activity_payload;
+    use crate::model::gateway::Activity;
+    use serde_json::json;
+
+    #[test]
+    fn watching_activity_payload() {
+        let activity = serde_json::from_value::<Activity>(json!({
+            "name": "over the guild",
+            "type": 3,
+        })).unwrap();
+        let payload = activity_payload(&activity);
+
+        assert_eq!(payload, json!({
+            "name": "over the guild",
+            "type": 3,
+        }));
+    }
+
+    #[test]
+    fn streaming_activity_payload() {
+        let activity = serde_json::from_value::<Activity>(json!({
+            "name": "Speedruns",
+            "type": 1,
+            "url": "https://twitch.tv/serenity",
+            "details": "Any%",
+        })).unwrap();
+
+        assert_eq!(activity_payload(&activity), json!({
+            "name": "Speedruns",
+            "type": 1,
+            "url": "https://twitch.tv/serenity",
+        }));
+    }
+}
@@ -1,8 +1,12 @@
 use crate::constants;
 use reqwest::{
     multipart::Part,
+    Body,
     Client,
     ClientBuilder,
+    IntoUrl,
+    Proxy,
+    RequestBuilder,
     Response as ReqwestResponse,
 };
 use reqwest::{
@@ -13,25 +17,36 @@ use reqwest::{
 use crate::internal::prelude::*;
 use crate::model::prelude::*;
 use super::{
-    ratelimiting::{Ratelimiter, RatelimitedRequest},
+    ratelimiting::{
+        AfterResponseHook,
+        BeforeRequestHook,
+        Ratelimiter,
+        RatelimitedRequest,
+        RequestInfo,
+        ResponseInfo,
+    },
     request::Request,
     routing::RouteInfo,
     typing::Typing,
     AttachmentType,
     GuildPagination,
     HttpError,
+    UserPagination,
 };
-use bytes::buf::Buf;
+use bytes::{buf::Buf, Bytes};
+use futures::stream::{self, Stream};
 use serde::de::DeserializeOwned;
 use serde_json::json;
 use tracing::{debug, trace, instrument};
 use std::{
     collections::BTreeMap,
     fmt,
+    future::Future,
     sync::Arc,
+    time::Duration,
 };
 use tokio::{
-    io::AsyncReadExt,
+    io::{AsyncRead, AsyncReadExt, SeekFrom},
     fs::File,
 };
 use crate::http::routing::Route;
@@ -40,10 +55,302 @@ use percent_encoding::{
     NON_ALPHANUMERIC
 };
 
+/// The JSON error code of an unknown invite.
+const UNKNOWN_INVITE: isize = 10006;
+
+/// The number of guilds fetched at once by [`Http::get_guilds_iter`].
+const GUILDS_PAGE_SIZE: u64 = 200;
+
+#[derive(Deserialize)]
+struct GuildVanityUrl {
+    code: Option<String>,
+}
+
+/// Reads the code of a vanity URL response, treating an unknown invite or an
+/// empty code as the guild not having one.
+fn vanity_url_code(response: Result<GuildVanityUrl>) -> Result<Option<String>> {
+    match response {
+        Ok(vanity_url) => Ok(vanity_url.code.filter(|code| !code.is_empty())),
+        Err(Error::Http(why)) => match *why {
+            HttpError::UnsuccessfulRequest(ref response) if response.error.code == UNKNOWN_INVITE => Ok(None),
+            why => Err(Error::Http(Box::new(why))),
+        },
+        Err(why) => Err(why),
+    }
+}
+
+/// Builds the body of a request creating a guild from a template.
+fn guild_from_template_map(name: &str, icon: Option<&str>) -> Value {
+    let mut map = json!({ "name": name });
+
+    if let Some(icon) = icon {
+        map["icon"] = Value::String(icon.to_string());
+    }
+
+    map
+}
+
+/// Builds the headers of a request recording a reason in the audit log.
+///
+/// An empty reason is not sent, and a reason longer than
+/// [`constants::AUDIT_LOG_REASON_MAX_LENGTH`] is rejected.
+fn reason_headers(reason: Option<&str>) -> Result<Option<Headers>> {
+    let reason = match reason {
+        Some(reason) if !reason.is_empty() => reason,
+        _ => return Ok(None),
+    };
+
+    if reason.chars().count() > constants::AUDIT_LOG_REASON_MAX_LENGTH {
+        return Err(Error::ExceededLimit(reason.to_string(), constants::AUDIT_LOG_REASON_MAX_LENGTH as u32));
+    }
+
+    let mut headers = Headers::new();
+    let reason = utf8_percent_encode(reason, NON_ALPHANUMERIC).to_string();
+    headers.insert("X-Audit-Log-Reason", HeaderValue::from_str(&reason)?);
+
+    Ok(Some(headers))
+}
+
+/// A builder for the [`Http`] client, to configure the underlying
+/// [`reqwest::Client`] before it is built.
+///
+/// # Examples
+///
+/// Create a client sending its requests through a proxy:
+///
+/// ```rust,no_run
+/// use serenity::http::HttpBuilder;
+///
+/// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let http = HttpBuilder::new("token")
+///     .proxy("http://localhost:3128")
+///     .build()?;
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct HttpBuilder {
+    client: Option<Arc<Client>>,
+    proxy: Option<String>,
+    token: String,
+    before_request: Option<BeforeRequestHook>,
+    after_response: Option<AfterResponseHook>,
+    ratelimit_retry: Option<u32>,
+    request_timeout: Option<Duration>,
+    download_timeout: Option<Duration>,
+}
+
+impl fmt::Debug for HttpBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HttpBuilder")
+            .field("client", &self.client)
+            .field("proxy", &self.proxy)
+            .field("before_request", &self.before_request.is_some())
+            .field("after_response", &self.after_response.is_some())
+            .field("ratelimit_retry", &self.ratelimit_retry)
+            .field("request_timeout", &self.request_timeout)
+            .field("download_timeout", &self.download_timeout)
+            .finish()
+    }
+}
+
+impl HttpBuilder {
+    /// Constructs a new builder with the given token. The `token` will
+    /// automatically be prefixed "Bot " if not already.
+    pub fn new(token: impl AsRef<str>) -> Self {
+        Self {
+            client: None,
+            proxy: None,
+            token: bot_token(token.as_ref()),
+            before_request: None,
+            after_response: None,
+            ratelimit_retry: None,
+            request_timeout: None,
+            download_timeout: None,
+        }
+    }
+
+    /// Sets the token used to authenticate the requests. The `token` will
+    /// automatically be prefixed "Bot " if not already.
+    pub fn token(mut self, token: impl AsRef<str>) -> Self {
+        self.token = bot_token(token.as_ref());
+        self
+    }
+
+    /// Sets the [`reqwest::Client`] to send the requests with, instead of
+    /// building a new one.
+    ///
+    /// **Note**: The [`proxy`] is not applied to a client set this way.
+    ///
+    /// [`proxy`]: Self::proxy
+    pub fn client(mut self, client: Arc<Client>) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Sets the URL of a proxy to send all the requests through, including
+    /// downloads from Discord's CDN.
+    ///
+    /// If no proxy is set, the `ALL_PROXY` environment variable is used if
+    /// present. The standard `HTTP_PROXY` and `HTTPS_PROXY` variables are
+    /// otherwise respected by the underlying client.
+    pub fn proxy(mut self, proxy: impl Into<String>) -> Self {
+        self.proxy = Some(proxy.into());
+        self
+    }
+
+    /// Sets a hook called right before each request is sent, such as to log or
+    /// trace it.
+    ///
+    /// The hook is called by the [`Ratelimiter`] once any ratelimit has been
+    /// waited for, and again for each retry of the request.
+    ///
+    /// # Examples
+    ///
+    /// Count the sent requests:
+    ///
+    /// ```rust,no_run
+    /// use serenity::http::HttpBuilder;
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use std::sync::Arc;
+    ///
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let count = Arc::new(AtomicUsize::new(0));
+    /// let counter = Arc::clone(&count);
+    ///
+    /// let http = HttpBuilder::new("token")
+    ///     .before_request(move |info| {
+    ///         counter.fetch_add(1, Ordering::Relaxed);
+    ///
+    ///         async move {
+    ///             println!("{:?} {}", info.method, info.url);
+    ///         }
+    ///     })
+    ///     .build()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn before_request<F, Fut>(mut self, hook: F) -> Self
+    where
+        F: Fn(RequestInfo) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.before_request = Some(Arc::new(move |info| Box::pin(hook(info))));
+        self
+    }
+
+    /// Sets a hook called right after each response is received, with its
+    /// status and ratelimit headers, such as to collect metrics.
+    ///
+    /// The hook is called by the [`Ratelimiter`] before waiting for a
+    /// ratelimit to retry the request, and again for each retry.
+    pub fn after_response<F, Fut>(mut self, hook: F) -> Self
+    where
+        F: Fn(ResponseInfo) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.after_response = Some(Arc::new(move |info| Box::pin(hook(info))));
+        self
+    }
+
+    /// Sets how many times a request is retried after being ratelimited with
+    /// a 429 response, once the `retry-after` duration has been waited for.
+    ///
+    /// Both the global and per-route ratelimits are waited for in the same
+    /// way. Setting `0` returns the 429 response as an error right away. By
+    /// default, ratelimited requests are retried until they succeed.
+    pub fn ratelimit_retry(mut self, max_attempts: u32) -> Self {
+        self.ratelimit_retry = Some(max_attempts);
+        self
+    }
+
+    /// Sets how long a request to the REST API may take, from when it is sent
+    /// until its response is fully received.
+    ///
+    /// Waiting for a ratelimit does not count against the timeout. A request
+    /// timing out returns an [`HttpError::Timeout`]. By default, requests
+    /// have no timeout.
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets how long a download may take, such as of an [`Attachment`] or of
+    /// an [`AttachmentType::Image`] to upload.
+    ///
+    /// A download timing out returns an [`HttpError::Timeout`]. By default,
+    /// downloads have no timeout.
+    ///
+    /// [`Attachment`]: crate::model::channel::Attachment
+    pub fn download_timeout(mut self, timeout: Duration) -> Self {
+        self.download_timeout = Some(timeout);
+        self
+    }
+
+    /// Builds the [`Http`] client.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the proxy URL is invalid, or if the
+    /// client could not be built.
+    pub fn build(self) -> Result<Http> {
+        let client = match self.client {
+            Some(client) => client,
+            None => {
+                let mut builder = configure_client_backend(Client::builder());
+
+                if let Some(proxy) = self.proxy.or_else(env_proxy) {
+                    builder = builder.proxy(Proxy::all(&proxy)?);
+                }
+
+                Arc::new(builder.build()?)
+            },
+        };
+
+        let mut http = Http::new(client, &self.token);
+        http.ratelimiter.before_request = self.before_request;
+        http.ratelimiter.after_response = self.after_response;
+        http.ratelimiter.ratelimit_retry = self.ratelimit_retry;
+        http.ratelimiter.request_timeout = self.request_timeout;
+        http.download_timeout = self.download_timeout;
+
+        Ok(http)
+    }
+}
+
+/// Reads the proxy from the `ALL_PROXY` environment variable, which is not
+/// read by the underlying client unlike `HTTP_PROXY` and `HTTPS_PROXY`.
+fn env_proxy() -> Option<String> {
+    std::env::var("ALL_PROXY")
+        .or_else(|_| std::env::var("all_proxy"))
+        .ok()
+        .filter(|proxy| !proxy.is_empty())
+}
+
+/// Applies a timeout to a request sent without going through the
+/// [`Ratelimiter`], if one is set.
+fn with_timeout(request: RequestBuilder, timeout: Option<Duration>) -> RequestBuilder {
+    match timeout {
+        Some(timeout) => request.timeout(timeout),
+        None => request,
+    }
+}
+
+fn bot_token(token: &str) -> String {
+    let token = token.trim();
+
+    if token.starts_with("Bot ") {
+        token.to_string()
+    } else {
+        format!("Bot {}", token)
+    }
+}
+
 pub struct Http {
     pub(crate) client: Arc<Client>,
     pub ratelimiter: Ratelimiter,
     pub token: String,
+    download_timeout: Option<Duration>,
 }
 
 impl fmt::Debug for Http {
@@ -51,6 +358,7 @@ impl fmt::Debug for Http {
         f.debug_struct("Http")
             .field("client", &self.client)
             .field("ratelimiter", &self.ratelimiter)
+            .field("download_timeout", &self.download_timeout)
             .finish()
     }
 }
@@ -63,6 +371,7 @@ impl Http {
             client,
             ratelimiter: Ratelimiter::new(client2, token.to_string()),
             token: token.to_string(),
+            download_timeout: None,
         }
     }
 
@@ -70,13 +379,7 @@ impl Http {
         let builder = configure_client_backend(Client::builder());
         let built = builder.build().expect("Cannot build reqwest::Client");
 
-        let token = if token.trim().starts_with("Bot ") {
-            token.to_string()
-        } else {
-            format!("Bot {}", token)
-        };
-
-        Self::new(Arc::new(built), &token)
+        Self::new(Arc::new(built), &bot_token(token))
     }
 
     /// Adds a single [`Role`] to a [`Member`] in a [`Guild`].
@@ -93,6 +396,18 @@ impl Http {
         }).await
     }
 
+    /// Adds a [`User`] to a thread.
+    ///
+    /// **Note**: The current user must be able to send messages in the thread,
+    /// and the thread must not be archived.
+    pub async fn add_thread_channel_member(&self, channel_id: u64, user_id: u64) -> Result<()> {
+        self.wind(204, Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::AddThreadMember { channel_id, user_id },
+        }).await
+    }
+
     /// Bans a [`User`] from a [`Guild`], removing their messages sent in the last
     /// X number of days.
     ///
@@ -105,7 +420,7 @@ impl Http {
     pub async fn ban_user(&self, guild_id: u64, user_id: u64, delete_message_days: u8, reason: &str) -> Result<()> {
         self.wind(204, Request {
             body: None,
-            headers: None,
+            headers: reason_headers(Some(reason))?,
             route: RouteInfo::GuildBanUser {
                 delete_message_days: Some(delete_message_days),
                 reason: Some(&utf8_percent_encode(reason, NON_ALPHANUMERIC).to_string()),
@@ -115,6 +430,27 @@ impl Http {
         }).await
     }
 
+    /// Bans multiple [`User`]s from a [`Guild`] at once, removing their messages
+    /// sent in the last X number of seconds.
+    ///
+    /// Refer to [Discord's documentation] for the fields of the `map`.
+    ///
+    /// **Note**: Requires that you have both the [Ban Members] and
+    /// [Manage Guild] permissions.
+    ///
+    /// [Ban Members]: Permissions::BAN_MEMBERS
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    /// [Discord's documentation]: https://discord.com/developers/docs/resources/guild#bulk-guild-ban
+    pub async fn bulk_ban_users(&self, guild_id: u64, map: &Value, reason: Option<&str>) -> Result<BulkBanResponse> {
+        let body = serde_json::to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: reason_headers(reason)?,
+            route: RouteInfo::BulkBanUsers { guild_id },
+        }).await
+    }
+
     /// Broadcasts that the current user is typing in the given [`Channel`].
     ///
     /// This lasts for about 10 seconds, and will then need to be renewed to
@@ -130,6 +466,24 @@ impl Http {
         }).await
     }
 
+    /// Creates an [`AutoModRule`] in the guild.
+    ///
+    /// Refer to [Discord's documentation] for field information.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Discord's documentation]: https://discord.com/developers/docs/resources/auto-moderation#create-auto-moderation-rule
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn create_automod_rule(&self, guild_id: u64, map: &JsonMap) -> Result<AutoModRule> {
+        let body = serde_json::to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::CreateAutoModRule { guild_id },
+        }).await
+    }
+
     /// Creates a [`GuildChannel`] in the [`Guild`] given its Id.
     ///
     /// Refer to the Discord's [docs] for information on what fields this requires.
@@ -165,6 +519,53 @@ impl Http {
         }).await
     }
 
+    /// Creates a follow-up message for an interaction.
+    ///
+    /// Refer to Discord's [docs] for field information.
+    ///
+    /// **Note**: Follow-up messages can be sent for 15 minutes after the
+    /// interaction was received.
+    ///
+    /// [docs]: https://discord.com/developers/docs/interactions/receiving-and-responding#create-followup-message
+    pub async fn create_followup_message(&self, application_id: u64, interaction_token: &str, map: &Value) -> Result<Message> {
+        self.fire(Request {
+            body: Some(map.to_string().as_bytes()),
+            headers: None,
+            route: RouteInfo::CreateFollowupMessage { application_id, token: interaction_token },
+        }).await
+    }
+
+    /// Creates a global [`ApplicationCommand`], available in every guild the
+    /// application is in and in direct messages.
+    ///
+    /// Refer to Discord's [docs] for field information.
+    ///
+    /// **Note**: Creating a command with the same name as an existing one
+    /// overwrites it.
+    ///
+    /// [docs]: https://discord.com/developers/docs/interactions/application-commands#create-global-application-command
+    pub async fn create_global_application_command(&self, application_id: u64, map: &Value) -> Result<ApplicationCommand> {
+        self.fire(Request {
+            body: Some(map.to_string().as_bytes()),
+            headers: None,
+            route: RouteInfo::CreateGlobalApplicationCommand { application_id },
+        }).await
+    }
+
+    /// Overwrites all the global [`ApplicationCommand`]s of an application,
+    /// deleting the ones which are not in the list.
+    ///
+    /// Refer to Discord's [docs] for field information.
+    ///
+    /// [docs]: https://discord.com/developers/docs/interactions/application-commands#bulk-overwrite-global-application-commands
+    pub async fn create_global_application_commands(&self, application_id: u64, map: &Value) -> Result<Vec<ApplicationCommand>> {
+        self.fire(Request {
+            body: Some(map.to_string().as_bytes()),
+            headers: None,
+            route: RouteInfo::CreateGlobalApplicationCommands { application_id },
+        }).await
+    }
+
     /// Creates a guild with the data provided.
     ///
     /// Only a [`PartialGuild`] will be immediately returned, and a full [`Guild`]
@@ -208,6 +609,55 @@ impl Http {
         }).await
     }
 
+    /// Creates an [`ApplicationCommand`] available only in the given guild.
+    ///
+    /// Refer to Discord's [docs] for field information.
+    ///
+    /// **Note**: Creating a command with the same name as an existing one
+    /// overwrites it.
+    ///
+    /// [docs]: https://discord.com/developers/docs/interactions/application-commands#create-guild-application-command
+    pub async fn create_guild_application_command(&self, application_id: u64, guild_id: u64, map: &Value) -> Result<ApplicationCommand> {
+        self.fire(Request {
+            body: Some(map.to_string().as_bytes()),
+            headers: None,
+            route: RouteInfo::CreateGuildApplicationCommand { application_id, guild_id },
+        }).await
+    }
+
+    /// Overwrites all the [`ApplicationCommand`]s of an application in the
+    /// given guild, deleting the ones which are not in the list.
+    ///
+    /// Refer to Discord's [docs] for field information.
+    ///
+    /// [docs]: https://discord.com/developers/docs/interactions/application-commands#bulk-overwrite-guild-application-commands
+    pub async fn create_guild_application_commands(&self, application_id: u64, guild_id: u64, map: &Value) -> Result<Vec<ApplicationCommand>> {
+        self.fire(Request {
+            body: Some(map.to_string().as_bytes()),
+            headers: None,
+            route: RouteInfo::CreateGuildApplicationCommands { application_id, guild_id },
+        }).await
+    }
+
+    /// Creates a guild from a [`GuildTemplate`], with the current user as its
+    /// owner.
+    ///
+    /// The `icon` is an optional base64-encoded image, as returned by
+    /// [`utils::read_image`].
+    ///
+    /// **Note**: This is limited to bots in fewer than 10 guilds.
+    ///
+    /// [`utils::read_image`]: crate::utils::read_image
+    pub async fn create_guild_from_template(&self, code: &str, name: &str, icon: Option<&str>) -> Result<PartialGuild> {
+        let body = serde_json::to_vec(&guild_from_template_map(name, icon))?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::CreateGuildFromTemplate { code },
+        }).await
+    }
+
     /// Creates an [`Integration`] for a [`Guild`].
     ///
     /// Refer to Discord's [docs] for field information.
@@ -224,6 +674,39 @@ impl Http {
         }).await
     }
 
+    /// Creates a [`GuildTemplate`] from the current state of a guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn create_guild_template(&self, guild_id: u64, map: &JsonMap) -> Result<GuildTemplate> {
+        let body = serde_json::to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::CreateGuildTemplate { guild_id },
+        }).await
+    }
+
+    /// Responds to an interaction sent by Discord.
+    ///
+    /// Refer to Discord's [docs] for the object it takes.
+    ///
+    /// **Note**: An interaction must be responded to within 3 seconds, and only
+    /// once.
+    ///
+    /// [docs]: https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-response-object
+    pub async fn create_interaction_response(&self, interaction_id: u64, interaction_token: &str, map: &Value) -> Result<()> {
+        let body = serde_json::to_vec(map)?;
+
+        self.wind(204, Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::CreateInteractionResponse { interaction_id, token: interaction_token },
+        }).await
+    }
+
     /// Creates a [`RichInvite`] for the given [channel][`GuildChannel`].
     ///
     /// Refer to Discord's [docs] for field information.
@@ -300,6 +783,107 @@ impl Http {
         serde_json::from_value(value).map_err(From::from)
     }
 
+    /// Creates a [`ScheduledEvent`] in the guild.
+    ///
+    /// Refer to [Discord's documentation] for field information.
+    ///
+    /// **Note**: Requires the Manage Events permission.
+    ///
+    /// [Discord's documentation]: https://discord.com/developers/docs/resources/guild-scheduled-event#create-guild-scheduled-event
+    pub async fn create_scheduled_event(&self, guild_id: u64, map: &JsonMap) -> Result<ScheduledEvent> {
+        let body = serde_json::to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::CreateScheduledEvent { guild_id },
+        }).await
+    }
+
+    /// Starts a stage instance in a stage channel.
+    ///
+    /// The map must contain the `channel_id` and `topic` fields.
+    pub async fn create_stage_instance(&self, map: &JsonMap) -> Result<StageInstance> {
+        let body = serde_json::to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::CreateStageInstance,
+        }).await
+    }
+
+    /// Creates a sticker in a guild, uploading its file.
+    ///
+    /// The map must contain the `name`, `description` and `tags` fields.
+    pub async fn create_sticker<'a>(&self, guild_id: u64, map: JsonMap, file: impl Into<AttachmentType<'a>>) -> Result<Sticker> {
+        let uri = Route::guild_stickers(guild_id);
+        let url = match Url::parse(&uri) {
+            Ok(url) => url,
+            Err(_) => return Err(Error::Url(uri)),
+        };
+
+        let mut multipart = reqwest::multipart::Form::new()
+            .part("file", self.attachment_part(file.into()).await?);
+
+        for (key, value) in map {
+            let value = match value {
+                Value::String(value) => value,
+                value => value.to_string(),
+            };
+
+            multipart = multipart.text(key, value);
+        }
+
+        let response = with_timeout(self.client.post(url), self.ratelimiter.request_timeout)
+            .header(AUTHORIZATION, HeaderValue::from_str(&self.token)?)
+            .header(USER_AGENT, HeaderValue::from_static(constants::USER_AGENT))
+            .multipart(multipart)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(HttpError::from_response(response).await.into());
+        }
+
+        response
+            .json::<Sticker>()
+            .await
+            .map_err(From::from)
+    }
+
+    /// Creates a thread which is not attached to a message.
+    ///
+    /// The type of the thread, public or private, is given in the map.
+    ///
+    /// Refer to [Discord's documentation] for the fields of the map.
+    ///
+    /// [Discord's documentation]: https://discord.com/developers/docs/resources/channel#start-thread-without-message
+    pub async fn create_thread(&self, channel_id: u64, map: &JsonMap) -> Result<GuildChannel> {
+        let body = serde_json::to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::CreateThread { channel_id },
+        }).await
+    }
+
+    /// Creates a public thread attached to a message.
+    ///
+    /// Refer to [Discord's documentation] for the fields of the map.
+    ///
+    /// [Discord's documentation]: https://discord.com/developers/docs/resources/channel#start-thread-with-message
+    pub async fn create_thread_from_message(&self, channel_id: u64, message_id: u64, map: &JsonMap) -> Result<GuildChannel> {
+        let body = serde_json::to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::CreateThreadFromMessage { channel_id, message_id },
+        }).await
+    }
+
     /// Creates a webhook for the given [channel][`GuildChannel`]'s Id, passing in
     /// the given data.
     ///
@@ -339,24 +923,68 @@ impl Http {
         }).await
     }
 
+    /// Crossposts a message in an announcement channel to the channels
+    /// following it.
+    pub async fn crosspost_message(&self, channel_id: u64, message_id: u64) -> Result<Message> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::CrosspostMessage { channel_id, message_id },
+        }).await
+    }
+
+    /// Deletes an [`AutoModRule`] from the guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn delete_automod_rule(&self, guild_id: u64, rule_id: u64) -> Result<()> {
+        self.wind(204, Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::DeleteAutoModRule { guild_id, rule_id },
+        }).await
+    }
+
     /// Deletes a private channel or a channel in a guild.
     pub async fn delete_channel(&self, channel_id: u64) -> Result<Channel> {
+        self.delete_channel_with_reason(channel_id, "").await
+    }
+
+    /// Deletes a private channel or a channel in a guild with a provided
+    /// reason, shown in the audit log.
+    pub async fn delete_channel_with_reason(&self, channel_id: u64, reason: &str) -> Result<Channel> {
         self.fire(Request {
             body: None,
-            headers: None,
+            headers: reason_headers(Some(reason))?,
             route: RouteInfo::DeleteChannel { channel_id },
         }).await
     }
 
     /// Deletes an emoji from a server.
     pub async fn delete_emoji(&self, guild_id: u64, emoji_id: u64) -> Result<()> {
+        self.delete_emoji_with_reason(guild_id, emoji_id, "").await
+    }
+
+    /// Deletes an emoji from a server with a provided reason, shown in the
+    /// audit log.
+    pub async fn delete_emoji_with_reason(&self, guild_id: u64, emoji_id: u64, reason: &str) -> Result<()> {
         self.wind(204, Request {
             body: None,
-            headers: None,
+            headers: reason_headers(Some(reason))?,
             route: RouteInfo::DeleteEmoji { guild_id, emoji_id },
         }).await
     }
 
+    /// Deletes a global [`ApplicationCommand`].
+    pub async fn delete_global_application_command(&self, application_id: u64, command_id: u64) -> Result<()> {
+        self.wind(204, Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::DeleteGlobalApplicationCommand { application_id, command_id },
+        }).await
+    }
+
     /// Deletes a guild, only if connected account owns it.
     pub async fn delete_guild(&self, guild_id: u64) -> Result<PartialGuild> {
         self.fire(Request {
@@ -366,6 +994,15 @@ impl Http {
         }).await
     }
 
+    /// Deletes an [`ApplicationCommand`] of the given guild.
+    pub async fn delete_guild_application_command(&self, application_id: u64, guild_id: u64, command_id: u64) -> Result<()> {
+        self.wind(204, Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::DeleteGuildApplicationCommand { application_id, guild_id, command_id },
+        }).await
+    }
+
     /// Removes an integration from a guild.
     pub async fn delete_guild_integration(&self, guild_id: u64, integration_id: u64) -> Result<()> {
         self.wind(204, Request {
@@ -375,6 +1012,19 @@ impl Http {
         }).await
     }
 
+    /// Deletes a guild's template by code.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn delete_guild_template(&self, guild_id: u64, code: &str) -> Result<GuildTemplate> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::DeleteGuildTemplate { guild_id, code },
+        }).await
+    }
+
     /// Deletes an invite by code.
     pub async fn delete_invite(&self, code: &str) -> Result<Invite> {
         self.fire(Request {
@@ -447,6 +1097,15 @@ impl Http {
         }).await
     }
 
+    /// Deletes the initial response to an interaction.
+    pub async fn delete_original_interaction_response(&self, application_id: u64, interaction_token: &str) -> Result<()> {
+        self.wind(204, Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::DeleteOriginalInteractionResponse { application_id, token: interaction_token },
+        }).await
+    }
+
     /// Deletes a permission override from a role or a member in a channel.
     pub async fn delete_permission(&self, channel_id: u64, target_id: u64) -> Result<()> {
         self.wind(204, Request {
@@ -490,6 +1149,35 @@ impl Http {
         }).await
     }
 
+    /// Deletes a [`ScheduledEvent`] from the guild.
+    ///
+    /// **Note**: Requires the Manage Events permission.
+    pub async fn delete_scheduled_event(&self, guild_id: u64, event_id: u64) -> Result<()> {
+        self.wind(204, Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::DeleteScheduledEvent { guild_id, event_id },
+        }).await
+    }
+
+    /// Ends the stage instance of a stage channel.
+    pub async fn delete_stage_instance(&self, channel_id: u64) -> Result<()> {
+        self.wind(204, Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::DeleteStageInstance { channel_id },
+        }).await
+    }
+
+    /// Deletes a sticker from a guild.
+    pub async fn delete_sticker(&self, guild_id: u64, sticker_id: u64) -> Result<()> {
+        self.wind(204, Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::DeleteSticker { guild_id, sticker_id },
+        }).await
+    }
+
     /// Deletes a [`Webhook`] given its Id.
     ///
     /// This method requires authentication, whereas [`delete_webhook_with_token`]
@@ -549,6 +1237,43 @@ impl Http {
         }).await
     }
 
+    /// Deletes a message sent by a webhook.
+    ///
+    /// Pass the `thread_id` of the thread the message was sent in, if any.
+    ///
+    /// This method does not require authentication.
+    pub async fn delete_webhook_message(
+        &self,
+        webhook_id: u64,
+        thread_id: Option<u64>,
+        token: &str,
+        message_id: u64,
+    ) -> Result<()> {
+        self.wind(204, Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::DeleteWebhookMessage { message_id, thread_id, token, webhook_id },
+        }).await
+    }
+
+    /// Edits an [`AutoModRule`] in the guild.
+    ///
+    /// Refer to [Discord's documentation] for field information.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Discord's documentation]: https://discord.com/developers/docs/resources/auto-moderation#modify-auto-moderation-rule
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn edit_automod_rule(&self, guild_id: u64, rule_id: u64, map: &JsonMap) -> Result<AutoModRule> {
+        let body = serde_json::to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::EditAutoModRule { guild_id, rule_id },
+        }).await
+    }
+
     /// Changes channel information.
     pub async fn edit_channel(&self, channel_id: u64, map: &JsonMap) -> Result<GuildChannel> {
         let body = serde_json::to_vec(map)?;
@@ -567,44 +1292,105 @@ impl Http {
         self.fire(Request {
             body: Some(&body),
             headers: None,
-            route: RouteInfo::EditEmoji { guild_id, emoji_id },
+            route: RouteInfo::EditEmoji { guild_id, emoji_id },
+        }).await
+    }
+
+    /// Changes guild information.
+    pub async fn edit_guild(&self, guild_id: u64, map: &JsonMap) -> Result<PartialGuild> {
+        let body = serde_json::to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::EditGuild { guild_id },
+        }).await
+    }
+
+    /// Edits the positions of a guild's channels.
+    pub async fn edit_guild_channel_positions(
+        &self,
+        guild_id: u64,
+        value: &Value
+    ) -> Result<()> {
+        let body = serde_json::to_vec(value)?;
+
+        self.wind(204, Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::EditGuildChannels { guild_id },
+        }).await
+    }
+
+    /// Edits a [`Guild`]'s embed setting.
+    pub async fn edit_guild_embed(&self, guild_id: u64, map: &Value) -> Result<GuildEmbed> {
+        let body = serde_json::to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::EditGuildEmbed { guild_id },
+        }).await
+    }
+
+    /// Edits the name or description of a guild's template.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn edit_guild_template(&self, guild_id: u64, code: &str, map: &JsonMap) -> Result<GuildTemplate> {
+        let body = serde_json::to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::EditGuildTemplate { guild_id, code },
         }).await
     }
 
-    /// Changes guild information.
-    pub async fn edit_guild(&self, guild_id: u64, map: &JsonMap) -> Result<PartialGuild> {
+    /// Edits a [`Guild`]'s welcome screen.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn edit_guild_welcome_screen(&self, guild_id: u64, map: &JsonMap) -> Result<GuildWelcomeScreen> {
         let body = serde_json::to_vec(map)?;
 
         self.fire(Request {
             body: Some(&body),
             headers: None,
-            route: RouteInfo::EditGuild { guild_id },
+            route: RouteInfo::EditGuildWelcomeScreen { guild_id },
         }).await
     }
 
-    /// Edits the positions of a guild's channels.
-    pub async fn edit_guild_channel_positions(
-        &self,
-        guild_id: u64,
-        value: &Value
-    ) -> Result<()> {
-        let body = serde_json::to_vec(value)?;
+    /// Edits a [`Guild`]'s onboarding, replacing its prompts.
+    ///
+    /// **Note**: Requires the [Manage Guild] and [Manage Roles] permissions.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    /// [Manage Roles]: Permissions::MANAGE_ROLES
+    pub async fn edit_guild_onboarding(&self, guild_id: u64, map: &JsonMap) -> Result<GuildOnboarding> {
+        let body = serde_json::to_vec(map)?;
 
-        self.wind(204, Request {
+        self.fire(Request {
             body: Some(&body),
             headers: None,
-            route: RouteInfo::EditGuildChannels { guild_id },
+            route: RouteInfo::EditGuildOnboarding { guild_id },
         }).await
     }
 
-    /// Edits a [`Guild`]'s embed setting.
-    pub async fn edit_guild_embed(&self, guild_id: u64, map: &Value) -> Result<GuildEmbed> {
+    /// Edits a [`Guild`]'s widget settings.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn edit_guild_widget(&self, guild_id: u64, map: &JsonMap) -> Result<GuildWidget> {
         let body = serde_json::to_vec(map)?;
 
         self.fire(Request {
             body: Some(&body),
             headers: None,
-            route: RouteInfo::EditGuildEmbed { guild_id },
+            route: RouteInfo::EditGuildWidget { guild_id },
         }).await
     }
 
@@ -638,6 +1424,49 @@ impl Http {
         }).await
     }
 
+    /// Edits a message by Id, uploading new files along with the edit.
+    ///
+    /// **Note**: Only the attachments listed in the `attachments` field of the
+    /// map are kept when it is present.
+    ///
+    /// # Errors
+    ///
+    /// Returns an
+    /// [`HttpError::UnsuccessfulRequest(ErrorResponse)`][`HttpError::UnsuccessfulRequest`]
+    /// if the files are too large to send.
+    pub async fn edit_message_and_attachments<'a, T, It: IntoIterator<Item=T>>(
+        &self,
+        channel_id: u64,
+        message_id: u64,
+        files: It,
+        map: JsonMap,
+    ) -> Result<Message>
+        where T: Into<AttachmentType<'a>> {
+        let uri = Route::channel_message(channel_id, message_id);
+        let url = match Url::parse(&uri) {
+            Ok(url) => url,
+            Err(_) => return Err(Error::Url(uri)),
+        };
+
+        let multipart = self.files_form(files, &map).await?;
+
+        let response = with_timeout(self.client.patch(url), self.ratelimiter.request_timeout)
+            .header(AUTHORIZATION, HeaderValue::from_str(&self.token)?)
+            .header(USER_AGENT, HeaderValue::from_static(constants::USER_AGENT))
+            .multipart(multipart)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(HttpError::from_response(response).await.into());
+        }
+
+        response
+            .json::<Message>()
+            .await
+            .map_err(From::from)
+    }
+
     /// Edits the current user's nickname for the provided [`Guild`] via its Id.
     ///
     /// Pass `None` to reset the nickname.
@@ -652,6 +1481,19 @@ impl Http {
         }).await
     }
 
+    /// Edits the initial response to an interaction.
+    ///
+    /// Refer to Discord's [docs] for field information.
+    ///
+    /// [docs]: https://discord.com/developers/docs/interactions/receiving-and-responding#edit-original-interaction-response
+    pub async fn edit_original_interaction_response(&self, application_id: u64, interaction_token: &str, map: &Value) -> Result<Message> {
+        self.fire(Request {
+            body: Some(map.to_string().as_bytes()),
+            headers: None,
+            route: RouteInfo::EditOriginalInteractionResponse { application_id, token: interaction_token },
+        }).await
+    }
+
     /// Edits the current user's profile settings.
     pub async fn edit_profile(&self, map: &JsonMap) -> Result<CurrentUser> {
         let body = serde_json::to_vec(map)?;
@@ -667,10 +1509,22 @@ impl Http {
 
     /// Changes a role in a guild.
     pub async fn edit_role(&self, guild_id: u64, role_id: u64, map: &JsonMap) -> Result<Role> {
+        self.edit_role_with_reason(guild_id, role_id, map, "").await
+    }
+
+    /// Changes a role in a guild with a provided reason, shown in the audit
+    /// log.
+    pub async fn edit_role_with_reason(
+        &self,
+        guild_id: u64,
+        role_id: u64,
+        map: &JsonMap,
+        reason: &str,
+    ) -> Result<Role> {
         let body = serde_json::to_vec(&map)?;
         let mut value = self.request(Request {
             body: Some(&body),
-            headers: None,
+            headers: reason_headers(Some(reason))?,
             route: RouteInfo::EditRole { guild_id, role_id },
         }).await?.json::<Value>().await?;
 
@@ -683,10 +1537,18 @@ impl Http {
 
     /// Changes the position of a role in a guild.
     pub async fn edit_role_position(&self, guild_id: u64, role_id: u64, position: u64) -> Result<Vec<Role>> {
-        let body = serde_json::to_vec(&json!([{
+        self.edit_role_positions(guild_id, &json!([{
             "id": role_id,
             "position": position,
-        }]))?;
+        }])).await
+    }
+
+    /// Changes the positions of multiple roles in a guild at once.
+    ///
+    /// The value is an array of objects with the `id` and new `position` of
+    /// each role.
+    pub async fn edit_role_positions(&self, guild_id: u64, value: &Value) -> Result<Vec<Role>> {
+        let body = serde_json::to_vec(value)?;
 
         let mut value = self.request(Request {
             body: Some(&body),
@@ -705,6 +1567,46 @@ impl Http {
         serde_json::from_value(value).map_err(From::from)
     }
 
+    /// Edits a [`ScheduledEvent`] in the guild.
+    ///
+    /// Refer to [Discord's documentation] for field information.
+    ///
+    /// **Note**: Requires the Manage Events permission.
+    ///
+    /// [Discord's documentation]: https://discord.com/developers/docs/resources/guild-scheduled-event#modify-guild-scheduled-event
+    pub async fn edit_scheduled_event(&self, guild_id: u64, event_id: u64, map: &JsonMap) -> Result<ScheduledEvent> {
+        let body = serde_json::to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::EditScheduledEvent { guild_id, event_id },
+        }).await
+    }
+
+    /// Changes the topic or privacy level of the stage instance of a stage
+    /// channel.
+    pub async fn edit_stage_instance(&self, channel_id: u64, map: &JsonMap) -> Result<StageInstance> {
+        let body = serde_json::to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::EditStageInstance { channel_id },
+        }).await
+    }
+
+    /// Changes the name, description or tags of a sticker in a guild.
+    pub async fn edit_sticker(&self, guild_id: u64, sticker_id: u64, map: &JsonMap) -> Result<Sticker> {
+        let body = serde_json::to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::EditSticker { guild_id, sticker_id },
+        }).await
+    }
+
     /// Edits a the webhook with the given data.
     ///
     /// The Value is a map with optional values of:
@@ -787,6 +1689,31 @@ impl Http {
         }).await
     }
 
+    /// Edits a message sent by a webhook.
+    ///
+    /// Pass the `thread_id` of the thread the message was sent in, if any.
+    /// Refer to [Discord's documentation] for the fields of the map.
+    ///
+    /// This method does not require authentication.
+    ///
+    /// [Discord's documentation]: https://discord.com/developers/docs/resources/webhook#edit-webhook-message
+    pub async fn edit_webhook_message(
+        &self,
+        webhook_id: u64,
+        thread_id: Option<u64>,
+        token: &str,
+        message_id: u64,
+        map: &JsonMap,
+    ) -> Result<Message> {
+        let body = serde_json::to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::EditWebhookMessage { message_id, thread_id, token, webhook_id },
+        }).await
+    }
+
     /// Executes a webhook, posting a [`Message`] in the webhook's associated
     /// [`Channel`].
     ///
@@ -802,6 +1729,8 @@ impl Http {
     /// The map can _optionally_ contain the following data:
     ///
     /// - `avatar_url`: Override the default avatar of the webhook with a URL.
+    /// - `thread_name`: The name of the thread to create, for webhooks of
+    ///   forum channels.
     /// - `tts`: Whether this is a text-to-speech message (defaults to `false`).
     /// - `username`: Override the default username of the webhook.
     ///
@@ -842,6 +1771,30 @@ impl Http {
         wait: bool,
         map: &JsonMap
     ) -> Result<Option<Message>> {
+        self._execute_webhook(RouteInfo::ExecuteWebhook { token, wait, webhook_id }, map).await
+    }
+
+    /// Executes a webhook, posting the message in a thread of the webhook's
+    /// channel.
+    ///
+    /// Refer to [`execute_webhook`] for the meaning of `wait` and the data
+    /// the map can contain.
+    ///
+    /// [`execute_webhook`]: Self::execute_webhook
+    pub async fn execute_webhook_in_thread(
+        &self,
+        webhook_id: u64,
+        thread_id: u64,
+        token: &str,
+        wait: bool,
+        map: &JsonMap
+    ) -> Result<Option<Message>> {
+        let route = RouteInfo::ExecuteWebhookInThread { thread_id, token, wait, webhook_id };
+
+        self._execute_webhook(route, map).await
+    }
+
+    async fn _execute_webhook(&self, route: RouteInfo<'_>, map: &JsonMap) -> Result<Option<Message>> {
         let body = serde_json::to_vec(map)?;
 
         let mut headers = Headers::new();
@@ -850,7 +1803,7 @@ impl Http {
         let response = self.request(Request {
             body: Some(&body),
             headers: Some(headers),
-            route: RouteInfo::ExecuteWebhook { token, wait, webhook_id },
+            route,
         }).await?;
 
         if response.status() == StatusCode::NO_CONTENT {
@@ -882,59 +1835,48 @@ impl Http {
         where
             T: Into<AttachmentType<'a>>,
     {
-        let mut multipart = reqwest::multipart::Form::new();
+        let url = Route::webhook_with_token_optioned(webhook_id, token, wait);
 
-        for (file_num, file) in files.into_iter().enumerate() {
-            match file.into() {
-                AttachmentType::Bytes { data, filename } => {
-                    multipart = multipart
-                        .part(file_num.to_string(), Part::bytes(data.into_owned())
-                            .file_name(filename));
-                },
-                AttachmentType::File { file, filename } => {
-                    let mut buf = Vec::new();
-                    file.try_clone().await?.read_to_end(&mut buf).await?;
-
-                    multipart = multipart
-                        .part(file_num.to_string(),
-                              Part::stream(buf)
-                                  .file_name(filename));
-                },
-                AttachmentType::Path(path) => {
-                    let filename = path
-                        .file_name()
-                        .map(|filename| filename.to_string_lossy().into_owned());
-                    let mut file = File::open(path).await?;
-                    let mut buf = vec![];
-                    file.read_to_end(&mut buf).await?;
-
-                    let part = match filename {
-                        Some(filename) => Part::bytes(buf).file_name(filename),
-                        None => Part::bytes(buf),
-                    };
-
-                    multipart = multipart.part(file_num.to_string(), part);
-                },
-                AttachmentType::Image(url) => {
-                    let url = Url::parse(url).map_err(|_| Error::Url(url.to_string()))?;
-                    let filename = url.path_segments()
-                        .and_then(|segments| segments.last().map(ToString::to_string))
-                        .ok_or_else(|| Error::Url(url.to_string()))?;
-                    let response = self.client.get(url).send().await?;
-                    let mut bytes = response.bytes().await?;
-                    let mut picture: Vec<u8> = vec![0; bytes.len()];
-                    bytes.copy_to_slice(&mut picture[..]);
-                    multipart = multipart
-                        .part(file_num.to_string(), Part::bytes(picture)
-                            .file_name(filename.to_string()));
-                },
-            }
-        }
+        self._execute_webhook_with_files(url, files, map).await
+    }
+
+    /// Send file(s) over a webhook, posting the message in a thread of the
+    /// webhook's channel.
+    ///
+    /// # Errors
+    ///
+    /// Returns an
+    /// [`HttpError::UnsuccessfulRequest(ErrorResponse)`][`HttpError::UnsuccessfulRequest`]
+    /// if the files are too large to send.
+    pub async fn execute_webhook_in_thread_with_files<'a, T, It: IntoIterator<Item=T>>(
+        &self,
+        webhook_id: u64,
+        thread_id: u64,
+        token: &str,
+        wait: bool,
+        files: It,
+        map: JsonMap
+    ) -> Result<Option<Message>>
+        where
+            T: Into<AttachmentType<'a>>,
+    {
+        let url = Route::webhook_with_token_in_thread_optioned(webhook_id, token, wait, thread_id);
+
+        self._execute_webhook_with_files(url, files, map).await
+    }
 
-        multipart = multipart.text("payload_json", serde_json::to_string(&map)?);
+    async fn _execute_webhook_with_files<'a, T, It: IntoIterator<Item=T>>(
+        &self,
+        url: String,
+        files: It,
+        map: JsonMap
+    ) -> Result<Option<Message>>
+        where
+            T: Into<AttachmentType<'a>>,
+    {
+        let multipart = self.files_form(files, &map).await?;
 
-        let response = self.client
-            .post(&Route::webhook_with_token_optioned(webhook_id, token, wait))
+        let response = with_timeout(self.client.post(&url), self.ratelimiter.request_timeout)
             .multipart(multipart)
             .header(CONTENT_TYPE, HeaderValue::from_static(&"multipart/form-data"))
             .send()
@@ -972,6 +1914,32 @@ impl Http {
         }
     }
 
+    /// Gets an [`AutoModRule`] of the guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn get_automod_rule(&self, guild_id: u64, rule_id: u64) -> Result<AutoModRule> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetAutoModRule { guild_id, rule_id },
+        }).await
+    }
+
+    /// Gets all of the [`AutoModRule`]s of the guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn get_automod_rules(&self, guild_id: u64) -> Result<Vec<AutoModRule>> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetAutoModRules { guild_id },
+        }).await
+    }
+
     /// Gets all the users that are banned in specific guild.
     pub async fn get_bans(&self, guild_id: u64) -> Result<Vec<Ban>> {
         self.fire(Request {
@@ -1048,6 +2016,45 @@ impl Http {
         }).await
     }
 
+    /// Gets the archived private threads of a channel, most recently archived
+    /// first, optionally before the given ISO8601 timestamp.
+    ///
+    /// **Note**: Requires the [Read Message History] and Manage Threads
+    /// permissions.
+    ///
+    /// [Read Message History]: Permissions::READ_MESSAGE_HISTORY
+    pub async fn get_channel_archived_private_threads(
+        &self,
+        channel_id: u64,
+        before: Option<&str>,
+        limit: Option<u64>,
+    ) -> Result<ThreadsData> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetArchivedPrivateThreads { channel_id, before, limit },
+        }).await
+    }
+
+    /// Gets the archived public threads of a channel, most recently archived
+    /// first, optionally before the given ISO8601 timestamp.
+    ///
+    /// **Note**: Requires the [Read Message History] permission.
+    ///
+    /// [Read Message History]: Permissions::READ_MESSAGE_HISTORY
+    pub async fn get_channel_archived_public_threads(
+        &self,
+        channel_id: u64,
+        before: Option<&str>,
+        limit: Option<u64>,
+    ) -> Result<ThreadsData> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetArchivedPublicThreads { channel_id, before, limit },
+        }).await
+    }
+
     /// Gets channel information.
     pub async fn get_channel(&self, channel_id: u64) -> Result<Channel> {
         self.fire(Request {
@@ -1113,6 +2120,24 @@ impl Http {
         }).await
     }
 
+    /// Gets a global [`ApplicationCommand`] by its Id.
+    pub async fn get_global_application_command(&self, application_id: u64, command_id: u64) -> Result<ApplicationCommand> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetGlobalApplicationCommand { application_id, command_id },
+        }).await
+    }
+
+    /// Gets all the global [`ApplicationCommand`]s of an application.
+    pub async fn get_global_application_commands(&self, application_id: u64) -> Result<Vec<ApplicationCommand>> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetGlobalApplicationCommands { application_id },
+        }).await
+    }
+
     /// Gets guild information.
     pub async fn get_guild(&self, guild_id: u64) -> Result<PartialGuild> {
         self.fire(Request {
@@ -1122,6 +2147,25 @@ impl Http {
         }).await
     }
 
+    /// Gets an [`ApplicationCommand`] of the given guild by its Id.
+    pub async fn get_guild_application_command(&self, application_id: u64, guild_id: u64, command_id: u64) -> Result<ApplicationCommand> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetGuildApplicationCommand { application_id, guild_id, command_id },
+        }).await
+    }
+
+    /// Gets all the [`ApplicationCommand`]s of an application in the given
+    /// guild.
+    pub async fn get_guild_application_commands(&self, application_id: u64, guild_id: u64) -> Result<Vec<ApplicationCommand>> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetGuildApplicationCommands { application_id, guild_id },
+        }).await
+    }
+
     /// Gets a guild embed information.
     pub async fn get_guild_embed(&self, guild_id: u64) -> Result<GuildEmbed> {
         self.fire(Request {
@@ -1145,27 +2189,52 @@ impl Http {
         self.fire(Request {
             body: None,
             headers: None,
-            route: RouteInfo::GetGuildInvites { guild_id },
+            route: RouteInfo::GetGuildInvites { guild_id },
+        }).await
+    }
+
+    /// Gets a sticker of a guild.
+    pub async fn get_guild_sticker(&self, guild_id: u64, sticker_id: u64) -> Result<Sticker> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetGuildSticker { guild_id, sticker_id },
+        }).await
+    }
+
+    /// Gets all stickers of a guild.
+    pub async fn get_guild_stickers(&self, guild_id: u64) -> Result<Vec<Sticker>> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetGuildStickers { guild_id },
+        }).await
+    }
+
+    /// Gets all the templates of a guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn get_guild_templates(&self, guild_id: u64) -> Result<Vec<GuildTemplate>> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetGuildTemplates { guild_id },
         }).await
     }
 
     /// Gets a guild's vanity URL if it has one.
-    pub async fn get_guild_vanity_url(&self, guild_id: u64) -> Result<String> {
-        #[derive(Deserialize)]
-        struct GuildVanityUrl {
-            code: String,
-        }
-
-        self.request(Request {
+    ///
+    /// Returns `None` if the guild has no vanity code set.
+    pub async fn get_guild_vanity_url(&self, guild_id: u64) -> Result<Option<String>> {
+        let response = self.fire(Request {
             body: None,
             headers: None,
             route: RouteInfo::GetGuildVanityUrl { guild_id },
-        })
-            .await?
-        .json::<GuildVanityUrl>()
-            .await
-            .map(|x| x.code)
-            .map_err(From::from)
+        }).await;
+
+        vanity_url_code(response)
     }
 
     /// Gets the members of a guild. Optionally pass a `limit` and the Id of the
@@ -1201,22 +2270,32 @@ impl Http {
         serde_json::from_value::<Vec<Member>>(value).map_err(From::from)
     }
 
-    /// Gets the amount of users that can be pruned.
-    pub async fn get_guild_prune_count(&self, guild_id: u64, map: &Value) -> Result<GuildPrune> {
-        // Note for 0.6.x: turn this into a function parameter.
-        #[derive(Deserialize)]
-        struct GetGuildPruneCountRequest {
-            days: u64,
-        }
-
-        let req = serde_json::from_value::<GetGuildPruneCountRequest>(map.clone())?;
+    /// Gets a guild's onboarding.
+    pub async fn get_guild_onboarding(&self, guild_id: u64) -> Result<GuildOnboarding> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetGuildOnboarding { guild_id },
+        }).await
+    }
 
+    /// Gets the amount of users that can be pruned.
+    ///
+    /// Members with roles are only counted if they only have roles of the
+    /// `include_roles`.
+    pub async fn get_guild_prune_count(
+        &self,
+        guild_id: u64,
+        days: u64,
+        include_roles: &[u64],
+    ) -> Result<GuildPrune> {
         self.fire(Request {
             body: None,
             headers: None,
             route: RouteInfo::GetGuildPruneCount {
-                days: req.days,
+                days,
                 guild_id,
+                include_roles,
             },
         }).await
     }
@@ -1277,6 +2356,38 @@ impl Http {
         }).await
     }
 
+    /// Gets a guild's welcome screen.
+    pub async fn get_guild_welcome_screen(&self, guild_id: u64) -> Result<GuildWelcomeScreen> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetGuildWelcomeScreen { guild_id },
+        }).await
+    }
+
+    /// Gets a guild's widget settings.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn get_guild_widget(&self, guild_id: u64) -> Result<GuildWidget> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetGuildWidget { guild_id },
+        }).await
+    }
+
+    /// Gets guild information, including the approximate number of members
+    /// and of online members.
+    pub async fn get_guild_with_counts(&self, guild_id: u64) -> Result<PartialGuild> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetGuildWithCounts { guild_id },
+        }).await
+    }
+
     /// Gets a paginated list of the current user's guilds.
     ///
     /// The `limit` has a maximum value of 100.
@@ -1315,6 +2426,86 @@ impl Http {
         }).await
     }
 
+    /// Streams over all the guilds the current user is in, in order of their
+    /// Ids.
+    ///
+    /// The guilds are fetched in pages of 200, as needed. If `with_counts` is
+    /// `true`, the [`approximate_member_count`] and
+    /// [`approximate_presence_count`] of the guilds are filled in.
+    ///
+    /// The stream ends after the first error.
+    ///
+    /// # Examples
+    ///
+    /// Count the members of all the guilds the bot is in:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// #
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let http = Http::default();
+    /// use serenity::futures::StreamExt;
+    ///
+    /// let mut guilds = http.get_guilds_iter(true).boxed();
+    /// let mut members = 0;
+    ///
+    /// while let Some(guild) = guilds.next().await {
+    ///     members += guild?.approximate_member_count.unwrap_or(0);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`approximate_member_count`]: GuildInfo::approximate_member_count
+    /// [`approximate_presence_count`]: GuildInfo::approximate_presence_count
+    pub fn get_guilds_iter(&self, with_counts: bool) -> impl Stream<Item = Result<GuildInfo>> + '_ {
+        struct State {
+            buffer: Vec<GuildInfo>,
+            after: Option<u64>,
+            finished: bool,
+        }
+
+        let state = State {
+            buffer: Vec::new(),
+            after: None,
+            finished: false,
+        };
+
+        stream::unfold(state, move |mut state| async move {
+            if state.buffer.is_empty() && !state.finished {
+                let (after, before, limit) = (state.after, None, GUILDS_PAGE_SIZE);
+                let route = if with_counts {
+                    RouteInfo::GetGuildsWithCounts { after, before, limit }
+                } else {
+                    RouteInfo::GetGuilds { after, before, limit }
+                };
+                let request = Request {
+                    body: None,
+                    headers: None,
+                    route,
+                };
+
+                match self.fire::<Vec<GuildInfo>>(request).await {
+                    Ok(mut page) => {
+                        state.finished = (page.len() as u64) < GUILDS_PAGE_SIZE;
+                        state.after = page.iter().map(|guild| guild.id.0).max();
+
+                        // Reverse to optimize pop()
+                        page.reverse();
+                        state.buffer = page;
+                    },
+                    Err(why) => {
+                        state.finished = true;
+
+                        return Some((Err(why), state));
+                    },
+                }
+            }
+
+            state.buffer.pop().map(|guild| (Ok(guild), state))
+        })
+    }
+
     /// Gets information about a specific invite.
     pub async fn get_invite(&self, mut code: &str, stats: bool) -> Result<Invite> {
         #[cfg(feature = "utils")]
@@ -1365,6 +2556,22 @@ impl Http {
         }).await
     }
 
+    /// Gets the standard sticker packs available to Nitro subscribers.
+    pub async fn get_nitro_sticker_packs(&self) -> Result<Vec<StickerPack>> {
+        #[derive(Deserialize)]
+        struct StickerPacks {
+            sticker_packs: Vec<StickerPack>,
+        }
+
+        let response: StickerPacks = self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetNitroStickerPacks,
+        }).await?;
+
+        Ok(response.sticker_packs)
+    }
+
     /// Gets all pins of a channel.
     pub async fn get_pins(&self, channel_id: u64) -> Result<Vec<Message>> {
         self.fire(Request {
@@ -1398,6 +2605,87 @@ impl Http {
         }).await
     }
 
+    /// Gets a [`ScheduledEvent`] of the guild.
+    ///
+    /// If `with_user_count` is `true`, the [`user_count`] field is populated.
+    ///
+    /// [`user_count`]: ScheduledEvent::user_count
+    pub async fn get_scheduled_event(&self, guild_id: u64, event_id: u64, with_user_count: bool) -> Result<ScheduledEvent> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetScheduledEvent { guild_id, event_id, with_user_count },
+        }).await
+    }
+
+    /// Gets all of the [`ScheduledEvent`]s of the guild.
+    ///
+    /// If `with_user_count` is `true`, the [`user_count`] field of each event
+    /// is populated.
+    ///
+    /// [`user_count`]: ScheduledEvent::user_count
+    pub async fn get_scheduled_events(&self, guild_id: u64, with_user_count: bool) -> Result<Vec<ScheduledEvent>> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetScheduledEvents { guild_id, with_user_count },
+        }).await
+    }
+
+    /// Gets the users subscribed to a [`ScheduledEvent`], paginated by their
+    /// Id.
+    ///
+    /// At most 100 users may be fetched at once. If `with_member` is `true`,
+    /// the [`member`] field of each user is populated.
+    ///
+    /// [`member`]: ScheduledEventUser::member
+    pub async fn get_scheduled_event_users(
+        &self,
+        guild_id: u64,
+        event_id: u64,
+        limit: Option<u64>,
+        target: Option<UserPagination>,
+        with_member: bool,
+    ) -> Result<Vec<ScheduledEventUser>> {
+        let (after, before) = match target {
+            Some(UserPagination::After(id)) => (Some(id.0), None),
+            Some(UserPagination::Before(id)) => (None, Some(id.0)),
+            None => (None, None),
+        };
+
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetScheduledEventUsers {
+                after,
+                before,
+                event_id,
+                guild_id,
+                limit,
+                with_member,
+            },
+        }).await
+    }
+
+    /// Gets the stage instance of a stage channel, if it is live.
+    pub async fn get_stage_instance(&self, channel_id: u64) -> Result<StageInstance> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetStageInstance { channel_id },
+        }).await
+    }
+
+    /// Gets a sticker by its Id, which may be either a standard or a guild
+    /// sticker.
+    pub async fn get_sticker(&self, sticker_id: u64) -> Result<Sticker> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetSticker { sticker_id },
+        }).await
+    }
+
     /// Gets the current unresolved incidents from Discord's Status API.
     ///
     /// Does not require authentication.
@@ -1520,6 +2808,15 @@ impl Http {
         }).await
     }
 
+    /// Joins a thread as the current user.
+    pub async fn join_thread_channel(&self, channel_id: u64) -> Result<()> {
+        self.wind(204, Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::JoinThread { channel_id },
+        }).await
+    }
+
     /// Kicks a member from a guild.
     pub async fn kick_member(&self, guild_id: u64, user_id: u64) -> Result<()> {
         self.kick_member_with_reason(guild_id, user_id, "").await
@@ -1529,7 +2826,7 @@ impl Http {
     pub async fn kick_member_with_reason(&self, guild_id: u64, user_id: u64, reason: &str) -> Result<()> {
         self.wind(204, Request {
             body: None,
-            headers: None,
+            headers: reason_headers(Some(reason))?,
             route: RouteInfo::KickMember {
                 guild_id,
                 user_id,
@@ -1547,6 +2844,15 @@ impl Http {
         }).await
     }
 
+    /// Leaves a thread as the current user.
+    pub async fn leave_thread_channel(&self, channel_id: u64) -> Result<()> {
+        self.wind(204, Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::LeaveThread { channel_id },
+        }).await
+    }
+
     /// Sends file(s) to a channel.
     ///
     /// # Errors
@@ -1562,59 +2868,9 @@ impl Http {
             Err(_) => return Err(Error::Url(uri)),
         };
 
-        let mut multipart = reqwest::multipart::Form::new();
-
-        for (file_num, file) in files.into_iter().enumerate() {
-            match file.into() {
-                AttachmentType::Bytes{ data, filename } => {
-                    multipart = multipart
-                        .part(file_num.to_string(), Part::bytes(data.into_owned())
-                            .file_name(filename));
-                },
-                AttachmentType::File{ file, filename } => {
-                    let mut buf = Vec::new();
-                    file.try_clone().await?.read_to_end(&mut buf).await?;
-
-                    multipart = multipart
-                        .part(file_num.to_string(),
-                            Part::stream(buf)
-                                .file_name(filename));
-                },
-                AttachmentType::Path(path) => {
-                    let filename = path
-                        .file_name()
-                        .map(|filename| filename.to_string_lossy().into_owned());
-                    let mut file = File::open(path).await?;
-                    let mut buf = vec![];
-                    file.read_to_end(&mut buf).await?;
-
-                    let part = match filename {
-                        Some(filename) => Part::bytes(buf).file_name(filename),
-                        None => Part::bytes(buf),
-                    };
-
-                    multipart = multipart.part(file_num.to_string(), part);
-                },
-                AttachmentType::Image(url) => {
-                    let url = Url::parse(url).map_err(|_| Error::Url(url.to_string()))?;
-                    let filename = url.path_segments()
-                      .and_then(|segments| segments.last().map(ToString::to_string))
-                      .ok_or_else(|| Error::Url(url.to_string()))?;
-                    let response = self.client.get(url).send().await?;
-                    let mut bytes = response.bytes().await?;
-                    let mut picture: Vec<u8> = vec![0; bytes.len()];
-                    bytes.copy_to_slice(&mut picture[..]);
-                    multipart = multipart
-                        .part(file_num.to_string(), Part::bytes(picture)
-                            .file_name(filename.to_string()));
-                },
-            }
-        }
-
-        multipart = multipart.text("payload_json", serde_json::to_string(&map)?);
+        let multipart = self.files_form(files, &map).await?;
 
-        let response = self.client
-            .post(url)
+        let response = with_timeout(self.client.post(url), self.ratelimiter.request_timeout)
             .header(AUTHORIZATION, HeaderValue::from_str(&self.token)?)
             .header(USER_AGENT, HeaderValue::from_static(&constants::USER_AGENT))
             .multipart(multipart)
@@ -1631,6 +2887,61 @@ impl Http {
             .map_err(From::from)
     }
 
+    async fn files_form<'a, T, It: IntoIterator<Item=T>>(&self, files: It, map: &JsonMap) -> Result<reqwest::multipart::Form>
+        where T: Into<AttachmentType<'a>> {
+        let mut multipart = reqwest::multipart::Form::new();
+
+        for (file_num, file) in files.into_iter().enumerate() {
+            // The number is the Id of the file in the `attachments` of the
+            // payload.
+            multipart = multipart.part(format!("files[{}]", file_num), self.attachment_part(file.into()).await?);
+        }
+
+        multipart = multipart.text("payload_json", serde_json::to_string(map)?);
+
+        Ok(multipart)
+    }
+
+    async fn attachment_part(&self, file: AttachmentType<'_>) -> Result<Part> {
+        Ok(match file {
+            AttachmentType::Bytes{ data, filename } => {
+                Part::bytes(data.into_owned()).file_name(filename)
+            },
+            AttachmentType::File{ file, filename } => {
+                let mut file = file.try_clone().await?;
+                let position = file.seek(SeekFrom::Current(0)).await?;
+                let length = file.metadata().await?.len().saturating_sub(position);
+
+                reader_part(file, length).file_name(filename)
+            },
+            AttachmentType::Path(path) => {
+                let filename = path
+                    .file_name()
+                    .map(|filename| filename.to_string_lossy().into_owned());
+                let file = File::open(path).await?;
+                let length = file.metadata().await?.len();
+                let part = reader_part(file, length);
+
+                match filename {
+                    Some(filename) => part.file_name(filename),
+                    None => part,
+                }
+            },
+            AttachmentType::Image(url) => {
+                let url = Url::parse(url).map_err(|_| Error::Url(url.to_string()))?;
+                let filename = url.path_segments()
+                  .and_then(|mut segments| segments.next_back().map(ToString::to_string))
+                  .ok_or_else(|| Error::Url(url.to_string()))?;
+                let response = self.download(url).send().await?;
+                let mut bytes = response.bytes().await?;
+                let mut picture: Vec<u8> = vec![0; bytes.len()];
+                bytes.copy_to_slice(&mut picture[..]);
+
+                Part::bytes(picture).file_name(filename)
+            },
+        })
+    }
+
     /// Sends a message to a channel.
     pub async fn send_message(&self, channel_id: u64, map: &Value) -> Result<Message> {
         let body = serde_json::to_vec(map)?;
@@ -1674,24 +2985,50 @@ impl Http {
         }).await
     }
 
+    /// Removes a [`User`] from a thread.
+    ///
+    /// **Note**: Requires the Manage Threads permission, or to be the creator
+    /// of the thread if it is private.
+    pub async fn remove_thread_channel_member(&self, channel_id: u64, user_id: u64) -> Result<()> {
+        self.wind(204, Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::RemoveThreadMember { channel_id, user_id },
+        }).await
+    }
+
     /// Starts removing some members from a guild based on the last time they've been online.
-    pub async fn start_guild_prune(&self, guild_id: u64, map: &Value) -> Result<GuildPrune> {
-        // Note for 0.6.x: turn this into a function parameter.
+    ///
+    /// Members with roles are only removed if they only have roles of the
+    /// `include_roles`. The `reason` is shown in the audit log.
+    ///
+    /// Returns the number of removed members, or `None` if `compute_count` is
+    /// `false`, which is recommended for large guilds.
+    pub async fn start_guild_prune(
+        &self,
+        guild_id: u64,
+        days: u64,
+        compute_count: bool,
+        include_roles: &[u64],
+        reason: Option<&str>,
+    ) -> Result<Option<u64>> {
         #[derive(Deserialize)]
-        struct StartGuildPruneRequest {
-            days: u64,
+        struct StartGuildPruneResponse {
+            pruned: Option<u64>,
         }
 
-        let req = serde_json::from_value::<StartGuildPruneRequest>(map.clone())?;
-
-        self.fire(Request {
+        let response: StartGuildPruneResponse = self.fire(Request {
             body: None,
-            headers: None,
+            headers: reason_headers(reason)?,
             route: RouteInfo::StartGuildPrune {
-                days: req.days,
+                compute_count,
+                days,
                 guild_id,
+                include_roles,
             },
-        }).await
+        }).await?;
+
+        Ok(response.pruned)
     }
 
     /// Starts syncing an integration with a guild.
@@ -1741,6 +3078,19 @@ impl Http {
         Typing::start(self.clone(), channel_id)
     }
 
+    /// Updates a guild's template to match the current state of the guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn sync_guild_template(&self, guild_id: u64, code: &str) -> Result<GuildTemplate> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::SyncGuildTemplate { guild_id, code },
+        }).await
+    }
+
     /// Unpins a message from a channel.
     pub async fn unpin_message(&self, channel_id: u64, message_id: u64) -> Result<()> {
         self.wind(204, Request {
@@ -1853,6 +3203,14 @@ impl Http {
         }
     }
 
+    /// Starts a request downloading a file, such as an attachment from
+    /// Discord's CDN, bounded by the configured [`download_timeout`].
+    ///
+    /// [`download_timeout`]: HttpBuilder::download_timeout
+    pub(crate) fn download<U: IntoUrl>(&self, url: U) -> RequestBuilder {
+        with_timeout(self.client.get(url), self.download_timeout)
+    }
+
     /// Performs a request and then verifies that the response status code is equal
     /// to the expected value.
     ///
@@ -1872,6 +3230,33 @@ impl Http {
     }
 }
 
+/// The size of the chunks read from a streamed attachment.
+const ATTACHMENT_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Streams the contents of a reader in chunks, so that attachments are sent
+/// without being fully buffered in memory.
+fn reader_stream<R: AsyncRead + Unpin>(reader: R) -> impl Stream<Item = std::io::Result<Bytes>> {
+    stream::unfold(Some(reader), |reader| async move {
+        let mut reader = reader?;
+        let mut buf = vec![0; ATTACHMENT_CHUNK_SIZE];
+
+        match reader.read(&mut buf).await {
+            Ok(0) => None,
+            Ok(read) => {
+                buf.truncate(read);
+
+                Some((Ok(Bytes::from(buf)), Some(reader)))
+            },
+            Err(why) => Some((Err(why), None)),
+        }
+    })
+}
+
+fn reader_part<R>(reader: R, length: u64) -> Part
+where R: AsyncRead + Send + Sync + Unpin + 'static {
+    Part::stream_with_length(Body::wrap_stream(reader_stream(reader)), length)
+}
+
 #[cfg(not(feature = "native_tls_backend"))]
 fn configure_client_backend(builder: ClientBuilder) -> ClientBuilder {
     builder.use_rustls_tls()
@@ -1896,6 +3281,267 @@ impl Default for Http {
             client,
             ratelimiter: Ratelimiter::new(client2, ""),
             token: "".to_string(),
+            download_timeout: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{guild_from_template_map, reader_stream, reason_headers, vanity_url_code, GuildVanityUrl, Http, HttpBuilder, ATTACHMENT_CHUNK_SIZE};
+    use crate::http::HttpError;
+    use crate::internal::test_utils::error_response;
+    use crate::Error;
+    use reqwest::StatusCode;
+    use serde_json::json;
+
+    #[test]
+    fn guild_from_template_body() {
+        assert_eq!(guild_from_template_map("Crabs", None), json!({"name": "Crabs"}));
+        assert_eq!(
+            guild_from_template_map("Crabs", Some("data:image/png;base64,AAAA")),
+            json!({"name": "Crabs", "icon": "data:image/png;base64,AAAA"}),
+        );
+    }
+
+    #[test]
+    fn build_with_proxy() {
+        let http = HttpBuilder::new("token").proxy("http://localhost:3128").build().unwrap();
+        assert_eq!(http.token, "Bot token");
+
+        match HttpBuilder::new("token").proxy("http://[::1").build() {
+            Err(Error::Http(why)) => assert!(matches!(*why, HttpError::Request(_))),
+            other => panic!("unexpected result: {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn build_with_ratelimit_retry() {
+        let http = HttpBuilder::new("token").build().unwrap();
+        assert_eq!(http.ratelimiter.ratelimit_retry, None);
+
+        let http = HttpBuilder::new("token").ratelimit_retry(3).build().unwrap();
+        assert_eq!(http.ratelimiter.ratelimit_retry, Some(3));
+    }
+
+    #[tokio::test]
+    async fn stalled_requests_time_out() {
+        use std::net::TcpListener;
+        use std::time::{Duration, Instant};
+
+        // A proxy accepting connections without ever answering them.
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let proxy = format!("http://{}", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            let stalled = listener.incoming().collect::<Vec<_>>();
+            drop(stalled);
+        });
+
+        let timeout = Duration::from_millis(200);
+        let http = HttpBuilder::new("token")
+            .proxy(proxy)
+            .request_timeout(timeout)
+            .download_timeout(timeout)
+            .build()
+            .unwrap();
+
+        let start = Instant::now();
+        match http.get_current_user().await {
+            Err(Error::Http(why)) => assert!(why.is_timeout(), "unexpected error: {:?}", why),
+            other => panic!("unexpected result: {:?}", other.map(|_| ())),
+        }
+        assert!(start.elapsed() >= timeout);
+        assert!(start.elapsed() < Duration::from_secs(5));
+
+        let start = Instant::now();
+        match http.download("https://cdn.discordapp.com/embed/avatars/0.png").send().await.map_err(Error::from) {
+            Err(Error::Http(why)) => assert!(why.is_timeout(), "unexpected error: {:?}", why),
+            other => panic!("unexpected result: {:?}", other.map(|_| ())),
+        }
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+
+    #[tokio::test]
+    async fn attachments_are_streamed() {
+        use futures::StreamExt;
+        use std::io;
+        use std::pin::Pin;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+        use std::task::{Context, Poll};
+        use tokio::io::AsyncRead;
+
+        struct TrackingReader {
+            remaining: usize,
+            live: Arc<AtomicUsize>,
+            max_live: Arc<AtomicUsize>,
+        }
+
+        impl AsyncRead for TrackingReader {
+            fn poll_read(mut self: Pin<&mut Self>, _: &mut Context<'_>, buf: &mut [u8]) -> Poll<io::Result<usize>> {
+                let read = buf.len().min(self.remaining);
+                self.remaining -= read;
+
+                let live = self.live.fetch_add(read, Ordering::SeqCst) + read;
+                self.max_live.fetch_max(live, Ordering::SeqCst);
+
+                Poll::Ready(Ok(read))
+            }
+        }
+
+        let size = 16 * 1024 * 1024;
+        let live = Arc::new(AtomicUsize::new(0));
+        let max_live = Arc::new(AtomicUsize::new(0));
+        let reader = TrackingReader {
+            remaining: size,
+            live: Arc::clone(&live),
+            max_live: Arc::clone(&max_live),
+        };
+
+        let mut stream = Box::pin(reader_stream(reader));
+        let mut sent = 0;
+
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk.unwrap();
+            sent += chunk.len();
+            live.fetch_sub(chunk.len(), Ordering::SeqCst);
+        }
+
+        assert_eq!(sent, size);
+        assert!(max_live.load(Ordering::SeqCst) <= ATTACHMENT_CHUNK_SIZE);
+    }
+
+    #[tokio::test]
+    async fn file_attachments_are_sent() {
+        use crate::http::AttachmentType;
+        use std::io::{Read, Seek, SeekFrom, Write};
+        use std::net::TcpListener;
+        use tokio::fs::File;
+
+        let path = std::env::temp_dir().join(format!("serenity-attachment-{}.txt", std::process::id()));
+        std::fs::write(&path, b"skipped;sent bytes").unwrap();
+
+        // The file is sent from its current position.
+        let mut file = std::fs::File::open(&path).unwrap();
+        file.seek(SeekFrom::Start(8)).unwrap();
+        let file = File::from_std(file);
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/upload", listener.local_addr().unwrap());
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0; 1024];
+
+            while !request.ends_with(b"--\r\n") {
+                let read = stream.read(&mut buf).unwrap();
+                assert_ne!(read, 0, "connection closed before the end of the form");
+                request.extend_from_slice(&buf[..read]);
+            }
+
+            stream.write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\n\r\n").unwrap();
+            String::from_utf8(request).unwrap()
+        });
+
+        let http = Http::new_with_token("token");
+        let attachment = AttachmentType::File { file: &file, filename: "crab.txt".to_string() };
+        let form = http.files_form(vec![attachment], &serde_json::Map::new()).await.unwrap();
+        http.client.post(&url).multipart(form).send().await.unwrap();
+
+        let request = server.join().unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(request.contains("Content-Disposition: form-data; name=\"files[0]\"; filename=\"crab.txt\"\r\n\r\nsent bytes\r\n"));
+        assert!(!request.contains("skipped"));
+    }
+
+    #[test]
+    fn vanity_url_present() {
+        let response = serde_json::from_value::<GuildVanityUrl>(json!({"code": "abc", "uses": 3})).unwrap();
+        assert_eq!(vanity_url_code(Ok(response)).unwrap().as_deref(), Some("abc"));
+    }
+
+    #[test]
+    fn vanity_url_absent() {
+        let response = serde_json::from_value::<GuildVanityUrl>(json!({"code": null, "uses": 0})).unwrap();
+        assert_eq!(vanity_url_code(Ok(response)).unwrap(), None);
+
+        let response = serde_json::from_value::<GuildVanityUrl>(json!({"code": "", "uses": 0})).unwrap();
+        assert_eq!(vanity_url_code(Ok(response)).unwrap(), None);
+
+        assert_eq!(vanity_url_code(Err(error_response(StatusCode::NOT_FOUND, 10006, "Unknown Invite"))).unwrap(), None);
+        assert!(vanity_url_code(Err(error_response(StatusCode::FORBIDDEN, 50013, "Missing Permissions"))).is_err());
+    }
+
+    #[tokio::test]
+    async fn audit_log_reason_header() {
+        use http_crate::response::Builder;
+        use std::sync::Arc;
+
+        let mut http = Http::new_with_token("token");
+        http.ratelimiter.responder = Some(Arc::new(|request| {
+            assert_eq!(request.method(), reqwest::Method::DELETE);
+            assert!(request.url().as_str().ends_with("/guilds/1/emojis/2"));
+
+            let reason = request.headers().get("X-Audit-Log-Reason").unwrap();
+            assert_eq!(reason, "Spam%20and%20%F0%9F%A6%80");
+
+            reqwest::Response::from(Builder::new().status(204).body(Vec::new()).unwrap())
+        }));
+
+        http.delete_emoji_with_reason(1, 2, "Spam and \u{1f980}").await.unwrap();
+    }
+
+    #[test]
+    fn audit_log_reason_limits() {
+        assert!(reason_headers(None).unwrap().is_none());
+        assert!(reason_headers(Some("")).unwrap().is_none());
+
+        // The limit is in code points, not bytes.
+        assert!(reason_headers(Some(&"\u{1f980}".repeat(512))).unwrap().is_some());
+
+        match reason_headers(Some(&"a".repeat(513))) {
+            Err(Error::ExceededLimit(_, 512)) => {},
+            other => panic!("unexpected result: {:?}", other),
         }
     }
+
+    #[tokio::test]
+    async fn guilds_iter_pages() {
+        use futures::StreamExt;
+        use http_crate::response::Builder;
+        use std::sync::Arc;
+
+        let mut http = Http::new_with_token("token");
+        http.ratelimiter.responder = Some(Arc::new(|request| {
+            let url = request.url();
+            assert_eq!(url.path(), "/api/v8/users/@me/guilds");
+
+            let query = url.query_pairs().collect::<std::collections::HashMap<_, _>>();
+            assert_eq!(query.get("limit").map(|l| &**l), Some("200"));
+            assert_eq!(query.get("with_counts").map(|c| &**c), Some("true"));
+
+            let ids = match query.get("after").map(|after| after.parse::<u64>().unwrap()) {
+                None => 1..=200,
+                Some(200) => 201..=250,
+                Some(after) => panic!("unexpected page after {}", after),
+            };
+            let body = ids.map(|id| json!({
+                "id": id.to_string(),
+                "icon": null,
+                "name": format!("guild {}", id),
+                "owner": false,
+                "permissions": "0",
+                "approximate_member_count": 10,
+            })).collect::<Vec<_>>();
+
+            let response = Builder::new().status(200).body(serde_json::to_vec(&body).unwrap()).unwrap();
+            reqwest::Response::from(response)
+        }));
+
+        let guilds = http.get_guilds_iter(true).collect::<Vec<_>>().await;
+        let ids = guilds.into_iter().map(|guild| guild.unwrap().id.0).collect::<Vec<_>>();
+
+        assert_eq!(ids, (1..=250).collect::<Vec<_>>());
+    }
 }
@@ -70,6 +70,13 @@ pub enum Error {
     InvalidHeader(InvalidHeaderValue),
     /// Reqwest's Error contain information on why sending a request failed.
     Request(ReqwestError),
+    /// When a request did not complete within the configured
+    /// [`request_timeout`], or a download from the CDN within the
+    /// [`download_timeout`].
+    ///
+    /// [`request_timeout`]: super::HttpBuilder::request_timeout
+    /// [`download_timeout`]: super::HttpBuilder::download_timeout
+    Timeout(ReqwestError),
 }
 
 impl Error {
@@ -96,6 +103,11 @@ impl Error {
         matches!(self, Self::InvalidHeader(_))
     }
 
+    /// Returns true when the error is caused by a request timing out
+    pub fn is_timeout(&self) -> bool {
+        matches!(self, Self::Timeout(_))
+    }
+
     /// Returns the status code if the error is an unsuccessful request
     pub fn status_code(&self) -> Option<StatusCode> {
         match self {
@@ -113,7 +125,11 @@ impl From<ErrorResponse> for Error {
 
 impl From<ReqwestError> for Error {
     fn from(error: ReqwestError) -> Error {
-        Error::Request(error)
+        if error.is_timeout() {
+            Error::Timeout(error)
+        } else {
+            Error::Request(error)
+        }
     }
 }
 
@@ -138,6 +154,7 @@ impl Display for Error {
             Error::Url(_) => f.write_str("Provided URL is incorrect."),
             Error::InvalidHeader(_) => f.write_str("Provided value is an invalid header value."),
             Error::Request(_) => f.write_str("Error while sending HTTP request."),
+            Error::Timeout(_) => f.write_str("HTTP request timed out."),
         }
     }
 }
@@ -146,7 +163,7 @@ impl StdError for Error {
     fn source(&self) -> Option<&(dyn StdError + 'static)> {
         match self {
             Error::Url(inner) => Some(inner),
-            Error::Request(inner) => Some(inner),
+            Error::Request(inner) | Error::Timeout(inner) => Some(inner),
             _ => None,
         }
     }
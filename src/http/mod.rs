@@ -159,8 +159,14 @@ pub enum AttachmentType<'a> {
     /// Indicates that the `AttachmentType` is a byte slice with a filename.
     Bytes{ data: Cow<'a, [u8]>, filename: String } ,
     /// Indicates that the `AttachmentType` is a `File`
+    ///
+    /// The file is streamed from its current position when uploaded, rather
+    /// than being read into memory.
     File{ file: &'a File, filename: String },
     /// Indicates that the `AttachmentType` is a `Path`
+    ///
+    /// The file is streamed when uploaded, rather than being read into
+    /// memory.
     Path(&'a Path),
     /// Indicates that the `AttachmentType` is an image URL.
     Image(&'a str),
@@ -208,6 +214,19 @@ pub enum GuildPagination {
     Before(GuildId),
 }
 
+/// Representation of the method of a query to send for functions paginating
+/// over users, such as [`get_scheduled_event_users`].
+///
+/// [`get_scheduled_event_users`]: Http::get_scheduled_event_users
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub enum UserPagination {
+    /// The Id to get the users after.
+    After(UserId),
+    /// The Id to get the users before.
+    Before(UserId),
+}
+
 #[cfg(test)]
 mod test {
     use super::AttachmentType;
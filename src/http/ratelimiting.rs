@@ -41,14 +41,16 @@
 
 pub use super::routing::Route;
 
-use reqwest::{Client, Response};
+use futures::future::BoxFuture;
+use reqwest::{Client, Request as ReqwestRequest, Response};
 use reqwest::{header::HeaderMap, StatusCode};
 use crate::internal::prelude::*;
 use tokio::sync::{Mutex, RwLock};
 use std::{
     collections::HashMap,
     fmt,
-    sync::Arc,
+    future::Future,
+    sync::{Arc, RwLock as StdRwLock},
     str::{
         self,
         FromStr,
@@ -58,7 +60,7 @@ use std::{
     f64,
 };
 use tokio::time::{delay_for, Duration};
-use super::{HttpError, Request};
+use super::{HttpError, LightMethod, Request};
 use tracing::{debug, instrument};
 
 /// Ratelimiter for requests to the Discord API.
@@ -86,15 +88,36 @@ pub struct Ratelimiter {
     // When futures is implemented, make tasks clear out their respective entry
     // when the 'reset' passes.
     routes: Arc<RwLock<HashMap<Route, Arc<Mutex<Ratelimit>>>>>,
+    // Copies of the buckets' state, readable without waiting on requests
+    // holding the buckets' locks.
+    snapshots: Arc<StdRwLock<HashMap<Route, RateLimitSnapshot>>>,
+    global_reset: Arc<StdRwLock<Option<SystemTime>>>,
     token: String,
+    pub(super) before_request: Option<BeforeRequestHook>,
+    pub(super) after_response: Option<AfterResponseHook>,
+    pub(super) ratelimit_retry: Option<u32>,
+    pub(super) request_timeout: Option<Duration>,
+    #[cfg(test)]
+    pub(crate) responder: Option<Responder>,
 }
 
+/// A function answering the requests in place of Discord, used to test the
+/// methods sending requests.
+#[cfg(test)]
+pub(crate) type Responder = Arc<dyn Fn(ReqwestRequest) -> Response + Send + Sync>;
+
 impl fmt::Debug for Ratelimiter {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Ratelimiter")
             .field("client", &self.client)
             .field("global", &self.global)
             .field("routes", &self.routes)
+            .field("snapshots", &self.snapshots)
+            .field("global_reset", &self.global_reset)
+            .field("before_request", &self.before_request.is_some())
+            .field("after_response", &self.after_response.is_some())
+            .field("ratelimit_retry", &self.ratelimit_retry)
+            .field("request_timeout", &self.request_timeout)
             .finish()
     }
 }
@@ -114,7 +137,15 @@ impl Ratelimiter {
             client,
             global: Default::default(),
             routes: Default::default(),
+            snapshots: Default::default(),
+            global_reset: Default::default(),
             token,
+            before_request: None,
+            after_response: None,
+            ratelimit_retry: None,
+            request_timeout: None,
+            #[cfg(test)]
+            responder: None,
         }
     }
 
@@ -149,9 +180,70 @@ impl Ratelimiter {
         Arc::clone(&self.routes)
     }
 
+    /// Gets the ratelimit state of a route, as of its last response.
+    ///
+    /// Unlike [`routes`], this does not wait for in-flight requests, making it
+    /// suitable for pacing bulk operations before hitting a 429.
+    ///
+    /// Returns `None` if no response has been received for the route yet.
+    ///
+    /// # Examples
+    ///
+    /// Wait for the bucket of `ChannelsId(7)` to reset when it is exhausted:
+    ///
+    /// ```rust,no_run
+    /// use serenity::http::ratelimiting::Route;
+    /// # use serenity::http::Http;
+    ///
+    /// # async fn run() {
+    /// #     let http = Http::default();
+    /// if let Some(state) = http.ratelimiter.bucket_state(Route::ChannelsId(7)) {
+    ///     if state.remaining == 0 {
+    ///         if let Some(reset_after) = state.reset_after {
+    ///             tokio::time::delay_for(reset_after).await;
+    ///         }
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [`routes`]: Self::routes
+    pub fn bucket_state(&self, route: Route) -> Option<RateLimitSnapshot> {
+        self.snapshots.read().ok()?.get(&route).copied()
+    }
+
+    /// Gets the time at which the global ratelimit ends, if it is currently
+    /// hit.
+    pub fn global_reset(&self) -> Option<SystemTime> {
+        let reset = (*self.global_reset.read().ok()?)?;
+
+        if reset > SystemTime::now() {
+            Some(reset)
+        } else {
+            None
+        }
+    }
+
     #[instrument]
     pub async fn perform(&self, req: RatelimitedRequest<'_>) -> Result<Response> {
+        #[cfg(test)]
+        {
+            if let Some(responder) = &self.responder {
+                return self.perform_with(req, |request| futures::future::ready(Ok(responder(request)))).await;
+            }
+        }
+
+        self.perform_with(req, |request| self.client.execute(request)).await
+    }
+
+    /// Performs the request, sending each attempt with `send`.
+    async fn perform_with<F, Fut>(&self, req: RatelimitedRequest<'_>, send: F) -> Result<Response>
+    where
+        F: Fn(ReqwestRequest) -> Fut,
+        Fut: Future<Output = StdResult<Response, reqwest::Error>>,
+    {
         let RatelimitedRequest { req } = req;
+        let mut retries = 0;
 
         loop {
             // This will block if another thread hit the global ratelimit.
@@ -166,7 +258,7 @@ impl Ratelimiter {
             // amount.
             //
             // This isn't normally important, but might be for ratelimiting.
-            let (_, route, _) = req.route.deconstruct();
+            let (method, route, _) = req.route.deconstruct();
 
             // Perform pre-checking here:
             //
@@ -186,8 +278,24 @@ impl Ratelimiter {
 
             bucket.lock().await.pre_hook(&route).await;
 
-            let request = req.build(&self.client, &self.token)?.build()?;
-            let response = self.client.execute(request).await?;
+            // The timeout only starts once the ratelimits have been waited
+            // for, when the request is sent.
+            let mut request = req.build(&self.client, &self.token)?.build()?;
+            *request.timeout_mut() = self.request_timeout;
+
+            if let Some(before_request) = &self.before_request {
+                before_request(RequestInfo {
+                    method,
+                    route,
+                    url: request.url().to_string(),
+                }).await;
+            }
+
+            let response = send(request).await?;
+
+            if let Some(after_response) = &self.after_response {
+                after_response(ResponseInfo::new(method, route, &response)).await;
+            }
 
             // Check if the request got ratelimited by checking for status 429,
             // and if so, sleep for the value of the header 'retry-after' -
@@ -202,34 +310,140 @@ impl Ratelimiter {
             // It _may_ be possible for the limit to be raised at any time,
             // so check if it did from the value of the 'x-ratelimit-limit'
             // header. If the limit was 5 and is now 7, add 2 to the 'remaining'
+            //
+            // If a maximum number of retries is set and has been reached, the
+            // ratelimit is still recorded but the 429 response is returned.
             if route == Route::None {
                 return Ok(response);
             } else {
+                let can_retry = match self.ratelimit_retry {
+                    Some(max_retries) => retries < max_retries,
+                    None => true,
+                };
+
                 let redo = if response.headers().get("x-ratelimit-global").is_some() {
                     let _ = self.global.lock().await;
 
                     Ok(
                         if let Some(retry_after) = parse_header::<f64>(&response.headers(), "retry-after")? {
                             debug!("Ratelimited on route {:?} for {:?}s", route, retry_after);
-                            delay_for(Duration::from_secs_f64(retry_after)).await;
 
-                            true
+                            if let Ok(mut global_reset) = self.global_reset.write() {
+                                *global_reset = Some(SystemTime::now() + Duration::from_secs_f64(retry_after));
+                            }
+
+                            if can_retry {
+                                delay_for(Duration::from_secs_f64(retry_after)).await;
+                            }
+
+                            can_retry
                         } else {
                             false
                         },
                     )
                 } else {
-                    bucket.lock().await.post_hook(&response, &route).await
+                    let mut bucket = bucket.lock().await;
+                    let redo = if can_retry {
+                        bucket.post_hook(&response, &route).await
+                    } else {
+                        bucket.update(&response).map(|_| false)
+                    };
+
+                    if let Ok(mut snapshots) = self.snapshots.write() {
+                        snapshots.insert(route, bucket.snapshot());
+                    }
+
+                    redo
                 };
 
                 if !redo.unwrap_or(true) {
                     return Ok(response);
                 }
+
+                retries += 1;
             }
         }
     }
 }
 
+/// A hook called by the [`Ratelimiter`] right before sending a request, once
+/// any ratelimit has been waited for.
+///
+/// Refer to [`HttpBuilder::before_request`] for more information.
+///
+/// [`HttpBuilder::before_request`]: super::HttpBuilder::before_request
+pub type BeforeRequestHook = Arc<dyn Fn(RequestInfo) -> BoxFuture<'static, ()> + Send + Sync>;
+
+/// A hook called by the [`Ratelimiter`] right after receiving a response, before
+/// waiting for a ratelimit to retry the request.
+///
+/// Refer to [`HttpBuilder::after_response`] for more information.
+///
+/// [`HttpBuilder::after_response`]: super::HttpBuilder::after_response
+pub type AfterResponseHook = Arc<dyn Fn(ResponseInfo) -> BoxFuture<'static, ()> + Send + Sync>;
+
+/// Information about a request about to be sent, given to a
+/// [`BeforeRequestHook`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct RequestInfo {
+    /// The method of the request.
+    pub method: LightMethod,
+    /// The route of the request, which determines its ratelimit bucket.
+    pub route: Route,
+    /// The full URL of the request.
+    pub url: String,
+}
+
+/// Information about a received response, given to an [`AfterResponseHook`].
+///
+/// The ratelimit fields are read from the headers of the response, and are
+/// `None` if the header is missing or invalid.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct ResponseInfo {
+    /// The method of the request.
+    pub method: LightMethod,
+    /// The route of the request, which determines its ratelimit bucket.
+    pub route: Route,
+    /// The status code of the response.
+    pub status: StatusCode,
+    /// The number of requests that can be made in the current period.
+    pub limit: Option<i64>,
+    /// The number of requests remaining in the current period.
+    pub remaining: Option<i64>,
+    /// The time until the current period resets.
+    pub reset_after: Option<Duration>,
+    /// The time to wait before retrying a ratelimited request.
+    pub retry_after: Option<Duration>,
+    /// Whether the global ratelimit was hit.
+    pub global: bool,
+}
+
+impl ResponseInfo {
+    fn new(method: LightMethod, route: Route, response: &Response) -> Self {
+        let headers = response.headers();
+        let duration = |header| {
+            parse_header::<f64>(headers, header)
+                .ok()
+                .flatten()
+                .filter(|secs| secs.is_finite() && *secs >= 0.0)
+                .map(Duration::from_secs_f64)
+        };
+
+        Self {
+            method,
+            route,
+            status: response.status(),
+            limit: parse_header(headers, "x-ratelimit-limit").ok().flatten(),
+            remaining: parse_header(headers, "x-ratelimit-remaining").ok().flatten(),
+            reset_after: duration("x-ratelimit-reset-after"),
+            retry_after: duration("retry-after"),
+            global: headers.get("x-ratelimit-global").is_some(),
+        }
+    }
+}
+
 /// A set of data containing information about the ratelimits for a particular
 /// [`Route`], which is stored in [`Http`].
 ///
@@ -297,6 +511,22 @@ impl Ratelimit {
 
     #[instrument]
     pub async fn post_hook(&mut self, response: &Response, route: &Route) -> Result<bool> {
+        self.update(response)?;
+
+        Ok(if response.status() != StatusCode::TOO_MANY_REQUESTS {
+            false
+        } else if let Some(retry_after) = parse_header::<f64>(response.headers(), "retry-after")? {
+            debug!("Ratelimited on route {:?} for {:?}ms", route, retry_after);
+            delay_for(Duration::from_secs_f64(retry_after)).await;
+
+            true
+        } else {
+            false
+        })
+    }
+
+    /// Updates the ratelimit from the headers of the response.
+    fn update(&mut self, response: &Response) -> Result<()> {
         if let Some(limit) = parse_header(&response.headers(), "x-ratelimit-limit")? {
             self.limit = limit;
         }
@@ -313,16 +543,7 @@ impl Ratelimit {
             self.reset_after = Some(Duration::from_secs_f64(reset_after));
         }
 
-        Ok(if response.status() != StatusCode::TOO_MANY_REQUESTS {
-            false
-        } else if let Some(retry_after) = parse_header::<f64>(&response.headers(), "retry-after")? {
-            debug!("Ratelimited on route {:?} for {:?}ms", route, retry_after);
-            delay_for(Duration::from_secs_f64(retry_after)).await;
-
-            true
-        } else {
-            false
-        })
+        Ok(())
     }
 
     /// The total number of requests that can be made in a period of time.
@@ -348,6 +569,16 @@ impl Ratelimit {
     pub fn reset_after(&self) -> Option<Duration> {
         self.reset_after
     }
+
+    /// Copies the current state of the ratelimit.
+    pub fn snapshot(&self) -> RateLimitSnapshot {
+        RateLimitSnapshot {
+            limit: self.limit,
+            remaining: self.remaining,
+            reset: self.reset,
+            reset_after: self.reset_after,
+        }
+    }
 }
 
 impl Default for Ratelimit {
@@ -361,6 +592,21 @@ impl Default for Ratelimit {
     }
 }
 
+/// A copy of the state of a [`Ratelimit`], retrieved with
+/// [`Ratelimiter::bucket_state`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct RateLimitSnapshot {
+    /// The total number of requests that can be made in a period of time.
+    pub limit: i64,
+    /// The number of requests remaining in the period of time.
+    pub remaining: i64,
+    /// The absolute time when the interval resets.
+    pub reset: Option<SystemTime>,
+    /// The total time when the interval resets, as of the last response.
+    pub reset_after: Option<Duration>,
+}
+
 /// Information about a request for the ratelimiter to perform.
 ///
 /// This only contains the basic information needed by the ratelimiter to
@@ -410,6 +656,174 @@ mod tests {
 
     type Result<T> = StdResult<T, Box<dyn StdError>>;
 
+    #[tokio::test]
+    async fn test_hooks_called_per_attempt() {
+        use super::{Ratelimiter, Route};
+        use crate::http::{request::Request, routing::RouteInfo, LightMethod, StatusCode};
+        use futures::FutureExt;
+        use http_crate::response::Builder;
+        use reqwest::Client;
+        use std::sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+            Mutex,
+        };
+
+        let requests = Arc::new(AtomicUsize::new(0));
+        let statuses = Arc::new(Mutex::new(Vec::new()));
+        let mut ratelimiter = Ratelimiter::new(Arc::new(Client::new()), "Bot token");
+
+        let counter = Arc::clone(&requests);
+        ratelimiter.before_request = Some(Arc::new(move |info| {
+            assert_eq!(info.method, LightMethod::Get);
+            assert_eq!(info.route, Route::ChannelsId(1));
+            counter.fetch_add(1, Ordering::SeqCst);
+
+            async {}.boxed()
+        }));
+
+        let seen = Arc::clone(&statuses);
+        ratelimiter.after_response = Some(Arc::new(move |info| {
+            seen.lock().unwrap().push((info.status, info.retry_after));
+
+            async {}.boxed()
+        }));
+
+        let sent = AtomicUsize::new(0);
+        let request = Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetChannel { channel_id: 1 },
+        };
+
+        let response = ratelimiter.perform_with(request.into(), |_| {
+            let status = if sent.fetch_add(1, Ordering::SeqCst) == 0 { 429 } else { 200 };
+            let response = Builder::new()
+                .status(status)
+                .header("retry-after", "0")
+                .body(Vec::new())
+                .unwrap();
+
+            async move { Ok(reqwest::Response::from(response)) }
+        }).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+        assert_eq!(*statuses.lock().unwrap(), vec![
+            (StatusCode::TOO_MANY_REQUESTS, Some(std::time::Duration::from_secs(0))),
+            (StatusCode::OK, Some(std::time::Duration::from_secs(0))),
+        ]);
+    }
+
+    #[tokio::test]
+    async fn test_ratelimit_retry() {
+        use super::Ratelimiter;
+        use crate::http::{request::Request, routing::RouteInfo, StatusCode};
+        use http_crate::response::Builder;
+        use reqwest::Client;
+        use std::sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        };
+
+        fn request() -> Request<'static> {
+            Request {
+                body: None,
+                headers: None,
+                route: RouteInfo::GetChannel { channel_id: 1 },
+            }
+        }
+
+        fn response(status: u16, global: bool) -> reqwest::Response {
+            let mut builder = Builder::new()
+                .status(status)
+                .header("retry-after", "0");
+
+            if global {
+                builder = builder.header("x-ratelimit-global", "true");
+            }
+
+            reqwest::Response::from(builder.body(Vec::new()).unwrap())
+        }
+
+        let mut ratelimiter = Ratelimiter::new(Arc::new(Client::new()), "Bot token");
+        ratelimiter.ratelimit_retry = Some(1);
+
+        // A 429 followed by a success is retried once.
+        let sent = AtomicUsize::new(0);
+        let result = ratelimiter.perform_with(request().into(), |_| {
+            let status = if sent.fetch_add(1, Ordering::SeqCst) == 0 { 429 } else { 200 };
+
+            async move { Ok(response(status, false)) }
+        }).await.unwrap();
+
+        assert_eq!(result.status(), StatusCode::OK);
+        assert_eq!(sent.load(Ordering::SeqCst), 2);
+
+        // Global 429s stop being retried once the retries are exhausted.
+        let sent = AtomicUsize::new(0);
+        let result = ratelimiter.perform_with(request().into(), |_| {
+            sent.fetch_add(1, Ordering::SeqCst);
+
+            async move { Ok(response(429, true)) }
+        }).await.unwrap();
+
+        assert_eq!(result.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(sent.load(Ordering::SeqCst), 2);
+
+        // No retries fails fast.
+        ratelimiter.ratelimit_retry = Some(0);
+        let sent = AtomicUsize::new(0);
+        let result = ratelimiter.perform_with(request().into(), |_| {
+            sent.fetch_add(1, Ordering::SeqCst);
+
+            async move { Ok(response(429, false)) }
+        }).await.unwrap();
+
+        assert_eq!(result.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(sent.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_bucket_state() {
+        use super::{Ratelimiter, Route};
+        use crate::http::{request::Request, routing::RouteInfo};
+        use http_crate::response::Builder;
+        use reqwest::Client;
+        use std::sync::Arc;
+        use std::time::{Duration, UNIX_EPOCH};
+
+        let ratelimiter = Ratelimiter::new(Arc::new(Client::new()), "Bot token");
+        assert_eq!(ratelimiter.bucket_state(Route::ChannelsId(1)), None);
+
+        let request = Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetChannel { channel_id: 1 },
+        };
+
+        ratelimiter.perform_with(request.into(), |_| {
+            let response = Builder::new()
+                .status(200)
+                .header("x-ratelimit-limit", "5")
+                .header("x-ratelimit-remaining", "4")
+                .header("x-ratelimit-reset", "1560704880.5")
+                .header("x-ratelimit-reset-after", "2.5")
+                .body(Vec::new())
+                .unwrap();
+
+            async move { Ok(reqwest::Response::from(response)) }
+        }).await.unwrap();
+
+        let state = ratelimiter.bucket_state(Route::ChannelsId(1)).unwrap();
+        assert_eq!(state.limit, 5);
+        assert_eq!(state.remaining, 4);
+        assert_eq!(state.reset, Some(UNIX_EPOCH + Duration::from_secs_f64(1_560_704_880.5)));
+        assert_eq!(state.reset_after, Some(Duration::from_millis(2500)));
+        assert_eq!(ratelimiter.bucket_state(Route::ChannelsId(2)), None);
+        assert_eq!(ratelimiter.global_reset(), None);
+    }
+
     fn headers() -> HeaderMap {
         let pairs = &[
             (
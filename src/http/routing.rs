@@ -14,12 +14,48 @@ use super::LightMethod;
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 #[non_exhaustive]
 pub enum Route {
+    /// Route for the `/applications/:application_id/commands` path.
+    ///
+    /// The data is the relevant [`ApplicationId`].
+    ///
+    /// [`ApplicationId`]: crate::model::id::ApplicationId
+    ApplicationsIdCommands(u64),
+    /// Route for the `/applications/:application_id/commands/:command_id` path.
+    ///
+    /// The data is the relevant [`ApplicationId`].
+    ///
+    /// [`ApplicationId`]: crate::model::id::ApplicationId
+    ApplicationsIdCommandsId(u64),
+    /// Route for the `/applications/:application_id/guilds/:guild_id/commands` path.
+    ///
+    /// The data is the relevant [`ApplicationId`].
+    ///
+    /// [`ApplicationId`]: crate::model::id::ApplicationId
+    ApplicationsIdGuildsIdCommands(u64),
+    /// Route for the `/applications/:application_id/guilds/:guild_id/commands/:command_id` path.
+    ///
+    /// The data is the relevant [`ApplicationId`].
+    ///
+    /// [`ApplicationId`]: crate::model::id::ApplicationId
+    ApplicationsIdGuildsIdCommandsId(u64),
     /// Route for the `/channels/:channel_id` path.
     ///
     /// The data is the relevant [`ChannelId`].
     ///
     /// [`ChannelId`]: crate::model::id::ChannelId
     ChannelsId(u64),
+    /// Route for the `/channels/:channel_id/threads/archived/private` path.
+    ///
+    /// The data is the relevant [`ChannelId`].
+    ///
+    /// [`ChannelId`]: crate::model::id::ChannelId
+    ChannelsIdArchivedPrivateThreads(u64),
+    /// Route for the `/channels/:channel_id/threads/archived/public` path.
+    ///
+    /// The data is the relevant [`ChannelId`].
+    ///
+    /// [`ChannelId`]: crate::model::id::ChannelId
+    ChannelsIdArchivedPublicThreads(u64),
     /// Route for the `/channels/:channel_id/invites` path.
     ///
     /// The data is the relevant [`ChannelId`].
@@ -59,6 +95,13 @@ pub enum Route {
     ///
     /// [`ChannelId`]: crate::model::id::ChannelId
     ChannelsIdMessagesIdAck(u64),
+    /// Route for the `/channels/:channel_id/messages/:message_id/crosspost`
+    /// path.
+    ///
+    /// The data is the relevant [`ChannelId`].
+    ///
+    /// [`ChannelId`]: crate::model::id::ChannelId
+    ChannelsIdMessagesIdCrosspost(u64),
     /// Route for the `/channels/:channel_id/messages/:message_id/reactions`
     /// path.
     ///
@@ -74,6 +117,12 @@ pub enum Route {
     ///
     /// [`ChannelId`]: crate::model::id::ChannelId
     ChannelsIdMessagesIdReactionsUserIdType(u64),
+    /// Route for the `/channels/:channel_id/messages/:message_id/threads` path.
+    ///
+    /// The data is the relevant [`ChannelId`].
+    ///
+    /// [`ChannelId`]: crate::model::id::ChannelId
+    ChannelsIdMessagesIdThreads(u64),
     /// Route for the `/channels/:channel_id/permissions/:target_id` path.
     ///
     /// The data is the relevant [`ChannelId`].
@@ -92,6 +141,18 @@ pub enum Route {
     ///
     /// [`ChannelId`]: crate::model::id::ChannelId
     ChannelsIdPinsMessageId(u64),
+    /// Route for the `/channels/:channel_id/thread-members/:user_id` path.
+    ///
+    /// The data is the relevant [`ChannelId`].
+    ///
+    /// [`ChannelId`]: crate::model::id::ChannelId
+    ChannelsIdThreadMembersUserId(u64),
+    /// Route for the `/channels/:channel_id/threads` path.
+    ///
+    /// The data is the relevant [`ChannelId`].
+    ///
+    /// [`ChannelId`]: crate::model::id::ChannelId
+    ChannelsIdThreads(u64),
     /// Route for the `/channels/:channel_id/typing` path.
     ///
     /// The data is the relevant [`ChannelId`].
@@ -127,12 +188,30 @@ pub enum Route {
     ///
     /// [`GuildId`]: crate::model::id::GuildId
     GuildsIdAuditLogs(u64),
+    /// Route for the `/guilds/:guild_id/auto-moderation/rules` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: crate::model::id::GuildId
+    GuildsIdAutoModerationRules(u64),
+    /// Route for the `/guilds/:guild_id/auto-moderation/rules/:rule_id` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: crate::model::id::GuildId
+    GuildsIdAutoModerationRulesId(u64),
     /// Route for the `/guilds/:guild_id/bans/:user_id` path.
     ///
     /// The data is the relevant [`GuildId`].
     ///
     /// [`GuildId`]: crate::model::id::GuildId
     GuildsIdBansUserId(u64),
+    /// Route for the `/guilds/:guild_id/bulk-ban` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: crate::model::id::GuildId
+    GuildsIdBulkBan(u64),
     /// Route for the `/guilds/:guild_id/channels/:channel_id` path.
     ///
     /// The data is the relevant [`GuildId`].
@@ -206,6 +285,12 @@ pub enum Route {
     ///
     /// [`GuildId`]: crate::model::id::GuildId
     GuildsIdMembersMeNick(u64),
+    /// Route for the `/guilds/:guild_id/onboarding` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: crate::model::id::GuildId
+    GuildsIdOnboarding(u64),
     /// Route for the `/guilds/:guild_id/prune` path.
     ///
     /// The data is the relevant [`GuildId`].
@@ -230,6 +315,48 @@ pub enum Route {
     ///
     /// [`GuildId`]: crate::model::id::GuildId
     GuildsIdRolesId(u64),
+    /// Route for the `/guilds/:guild_id/scheduled-events` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: crate::model::id::GuildId
+    GuildsIdScheduledEvents(u64),
+    /// Route for the `/guilds/:guild_id/scheduled-events/:event_id` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: crate::model::id::GuildId
+    GuildsIdScheduledEventsId(u64),
+    /// Route for the `/guilds/:guild_id/scheduled-events/:event_id/users` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: crate::model::id::GuildId
+    GuildsIdScheduledEventsIdUsers(u64),
+    /// Route for the `/guilds/:guild_id/stickers` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: crate::model::id::GuildId
+    GuildsIdStickers(u64),
+    /// Route for the `/guilds/:guild_id/stickers/:sticker_id` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: crate::model::id::GuildId
+    GuildsIdStickersId(u64),
+    /// Route for the `/guilds/:guild_id/templates` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: crate::model::id::GuildId
+    GuildsIdTemplates(u64),
+    /// Route for the `/guilds/:guild_id/templates/:code` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: crate::model::id::GuildId
+    GuildsIdTemplatesCode(u64),
     /// Route for the `/guilds/:guild_id/vanity-url` path.
     ///
     /// The data is the relevant [`GuildId`].
@@ -242,8 +369,40 @@ pub enum Route {
     ///
     /// [`GuildId`]: crate::model::id::GuildId
     GuildsIdWebhooks(u64),
+    /// Route for the `/guilds/:guild_id/welcome-screen` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: crate::model::id::GuildId
+    GuildsIdWelcomeScreen(u64),
+    /// Route for the `/guilds/:guild_id/widget` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: crate::model::id::GuildId
+    GuildsIdWidget(u64),
+    /// Route for the `/guilds/templates/:code` path.
+    GuildsTemplatesCode,
+    /// Route for the `/interactions/:interaction_id/:token/callback` path.
+    ///
+    /// The data is the relevant [`InteractionId`].
+    ///
+    /// [`InteractionId`]: crate::model::id::InteractionId
+    InteractionsIdTokenCallback(u64),
     /// Route for the `/invites/:code` path.
     InvitesCode,
+    /// Route for the `/stage-instances` path.
+    StageInstances,
+    /// Route for the `/stage-instances/:channel_id` path.
+    ///
+    /// The data is the relevant [`ChannelId`].
+    ///
+    /// [`ChannelId`]: crate::model::id::ChannelId
+    StageInstancesChannelId(u64),
+    /// Route for the `/sticker-packs` path.
+    StickerPacks,
+    /// Route for the `/stickers/:sticker_id` path.
+    StickersId,
     /// Route for the `/users/:user_id` path.
     UsersId,
     /// Route for the `/users/@me` path.
@@ -256,8 +415,22 @@ pub enum Route {
     UsersMeGuildsId,
     /// Route for the `/voice/regions` path.
     VoiceRegions,
+    /// Route for the `/webhooks/:application_id/:token/messages/@original`
+    /// path.
+    ///
+    /// The data is the relevant [`ApplicationId`].
+    ///
+    /// [`ApplicationId`]: crate::model::id::ApplicationId
+    WebhooksApplicationIdTokenMessagesOriginal(u64),
     /// Route for the `/webhooks/:webhook_id` path.
     WebhooksId(u64),
+    /// Route for the `/webhooks/:webhook_id/:token/messages/:message_id`
+    /// path.
+    ///
+    /// The data is the relevant [`WebhookId`].
+    ///
+    /// [`WebhookId`]: crate::model::id::WebhookId
+    WebhooksIdTokenMessagesId(u64),
     /// Route where no ratelimit headers are in place (i.e. user account-only
     /// routes).
     ///
@@ -267,10 +440,62 @@ pub enum Route {
 }
 
 impl Route {
+    pub fn application_commands(application_id: u64) -> String {
+        format!(api!("/applications/{}/commands"), application_id)
+    }
+
+    pub fn application_command(application_id: u64, command_id: u64) -> String {
+        format!(api!("/applications/{}/commands/{}"), application_id, command_id)
+    }
+
+    pub fn application_guild_commands(application_id: u64, guild_id: u64) -> String {
+        format!(api!("/applications/{}/guilds/{}/commands"), application_id, guild_id)
+    }
+
+    pub fn application_guild_command(application_id: u64, guild_id: u64, command_id: u64) -> String {
+        format!(api!("/applications/{}/guilds/{}/commands/{}"), application_id, guild_id, command_id)
+    }
+
     pub fn channel(channel_id: u64) -> String {
         format!(api!("/channels/{}"), channel_id)
     }
 
+    pub fn channel_archived_private_threads(
+        channel_id: u64,
+        before: Option<&str>,
+        limit: Option<u64>,
+    ) -> String {
+        let mut s = format!(api!("/channels/{}/threads/archived/private?"), channel_id);
+
+        if let Some(before) = before {
+            let _ = write!(s, "&before={}", before);
+        }
+
+        if let Some(limit) = limit {
+            let _ = write!(s, "&limit={}", limit);
+        }
+
+        s
+    }
+
+    pub fn channel_archived_public_threads(
+        channel_id: u64,
+        before: Option<&str>,
+        limit: Option<u64>,
+    ) -> String {
+        let mut s = format!(api!("/channels/{}/threads/archived/public?"), channel_id);
+
+        if let Some(before) = before {
+            let _ = write!(s, "&before={}", before);
+        }
+
+        if let Some(limit) = limit {
+            let _ = write!(s, "&limit={}", limit);
+        }
+
+        s
+    }
+
     pub fn channel_invites(channel_id: u64) -> String {
         format!(api!("/channels/{}/invites"), channel_id)
     }
@@ -279,6 +504,10 @@ impl Route {
         format!(api!("/channels/{}/messages/{}"), channel_id, message_id)
     }
 
+    pub fn channel_message_crosspost(channel_id: u64, message_id: u64) -> String {
+        format!(api!("/channels/{}/messages/{}/crosspost"), channel_id, message_id)
+    }
+
     pub fn channel_message_reaction<D, T>(
         channel_id: u64,
         message_id: u64,
@@ -336,6 +565,10 @@ impl Route {
         uri
     }
 
+    pub fn channel_message_threads(channel_id: u64, message_id: u64) -> String {
+        format!(api!("/channels/{}/messages/{}/threads"), channel_id, message_id)
+    }
+
     pub fn channel_messages(channel_id: u64, query: Option<&str>) -> String {
         format!(
             api!("/channels/{}/messages{}"),
@@ -360,6 +593,14 @@ impl Route {
         format!(api!("/channels/{}/pins"), channel_id)
     }
 
+    pub fn channel_thread_member<D: Display>(channel_id: u64, user_id: D) -> String {
+        format!(api!("/channels/{}/thread-members/{}"), channel_id, user_id)
+    }
+
+    pub fn channel_threads(channel_id: u64) -> String {
+        format!(api!("/channels/{}/threads"), channel_id)
+    }
+
     pub fn channel_typing(channel_id: u64) -> String {
         format!(api!("/channels/{}/typing"), channel_id)
     }
@@ -380,6 +621,10 @@ impl Route {
         format!(api!("/guilds/{}"), guild_id)
     }
 
+    pub fn guild_with_counts(guild_id: u64) -> String {
+        format!(api!("/guilds/{}?with_counts=true"), guild_id)
+    }
+
     pub fn guild_audit_logs(
         guild_id: u64,
         action_type: Option<u8>,
@@ -411,6 +656,14 @@ impl Route {
         s
     }
 
+    pub fn guild_automod_rule(guild_id: u64, rule_id: u64) -> String {
+        format!(api!("/guilds/{}/auto-moderation/rules/{}"), guild_id, rule_id)
+    }
+
+    pub fn guild_automod_rules(guild_id: u64) -> String {
+        format!(api!("/guilds/{}/auto-moderation/rules"), guild_id)
+    }
+
     pub fn guild_ban(guild_id: u64, user_id: u64) -> String {
         format!(api!("/guilds/{}/bans/{}"), guild_id, user_id)
     }
@@ -447,6 +700,10 @@ impl Route {
         format!(api!("/guilds/{}/bans"), guild_id)
     }
 
+    pub fn guild_bulk_ban(guild_id: u64) -> String {
+        format!(api!("/guilds/{}/bulk-ban"), guild_id)
+    }
+
     pub fn guild_channels(guild_id: u64) -> String {
         format!(api!("/guilds/{}/channels"), guild_id)
     }
@@ -530,10 +787,34 @@ impl Route {
         format!(api!("/guilds/{}/members/@me/nick"), guild_id)
     }
 
+    pub fn guild_onboarding(guild_id: u64) -> String {
+        format!(api!("/guilds/{}/onboarding"), guild_id)
+    }
+
     pub fn guild_prune(guild_id: u64, days: u64) -> String {
         format!(api!("/guilds/{}/prune?days={}"), guild_id, days)
     }
 
+    pub fn guild_prune_optioned(
+        guild_id: u64,
+        days: u64,
+        compute_count: Option<bool>,
+        include_roles: &[u64],
+    ) -> String {
+        let mut s = Route::guild_prune(guild_id, days);
+
+        if let Some(compute_count) = compute_count {
+            let _ = write!(s, "&compute_prune_count={}", compute_count);
+        }
+
+        if !include_roles.is_empty() {
+            let roles = include_roles.iter().map(u64::to_string).collect::<Vec<_>>();
+            let _ = write!(s, "&include_roles={}", roles.join(","));
+        }
+
+        s
+    }
+
     pub fn guild_regions(guild_id: u64) -> String {
         format!(api!("/guilds/{}/regions"), guild_id)
     }
@@ -546,6 +827,81 @@ impl Route {
         format!(api!("/guilds/{}/roles"), guild_id)
     }
 
+    pub fn guild_scheduled_event(guild_id: u64, event_id: u64) -> String {
+        format!(api!("/guilds/{}/scheduled-events/{}"), guild_id, event_id)
+    }
+
+    pub fn guild_scheduled_event_optioned(
+        guild_id: u64,
+        event_id: u64,
+        with_user_count: bool,
+    ) -> String {
+        format!(
+            api!("/guilds/{}/scheduled-events/{}?with_user_count={}"),
+            guild_id,
+            event_id,
+            with_user_count,
+        )
+    }
+
+    pub fn guild_scheduled_event_users_optioned(
+        guild_id: u64,
+        event_id: u64,
+        after: Option<u64>,
+        before: Option<u64>,
+        limit: Option<u64>,
+        with_member: bool,
+    ) -> String {
+        let mut s = format!(
+            api!("/guilds/{}/scheduled-events/{}/users?with_member={}"),
+            guild_id,
+            event_id,
+            with_member,
+        );
+
+        if let Some(after) = after {
+            let _ = write!(s, "&after={}", after);
+        }
+
+        if let Some(before) = before {
+            let _ = write!(s, "&before={}", before);
+        }
+
+        if let Some(limit) = limit {
+            let _ = write!(s, "&limit={}", limit);
+        }
+
+        s
+    }
+
+    pub fn guild_scheduled_events(guild_id: u64) -> String {
+        format!(api!("/guilds/{}/scheduled-events"), guild_id)
+    }
+
+    pub fn guild_scheduled_events_optioned(guild_id: u64, with_user_count: bool) -> String {
+        format!(
+            api!("/guilds/{}/scheduled-events?with_user_count={}"),
+            guild_id,
+            with_user_count,
+        )
+    }
+
+    pub fn guild_stickers(guild_id: u64) -> String {
+        format!(api!("/guilds/{}/stickers"), guild_id)
+    }
+
+    pub fn guild_sticker(guild_id: u64, sticker_id: u64) -> String {
+        format!(api!("/guilds/{}/stickers/{}"), guild_id, sticker_id)
+    }
+
+    pub fn guild_templates(guild_id: u64) -> String {
+        format!(api!("/guilds/{}/templates"), guild_id)
+    }
+
+    pub fn guild_template(guild_id: u64, code: &str) -> String {
+        format!(api!("/guilds/{}/templates/{}"), guild_id, code)
+    }
+
     pub fn guild_vanity_url(guild_id: u64) -> String {
         format!(api!("/guilds/{}/vanity-url"), guild_id)
     }
@@ -554,10 +910,31 @@ impl Route {
         format!(api!("/guilds/{}/webhooks"), guild_id)
     }
 
+    pub fn guild_welcome_screen(guild_id: u64) -> String {
+        format!(api!("/guilds/{}/welcome-screen"), guild_id)
+    }
+
+    pub fn guild_widget(guild_id: u64) -> String {
+        format!(api!("/guilds/{}/widget"), guild_id)
+    }
+
+    pub fn guild_widget_image(guild_id: u64, style: &str) -> String {
+        format!(api!("/guilds/{}/widget.png?style={}"), guild_id, style)
+    }
+
     pub fn guilds() -> &'static str {
         api!("/guilds")
     }
 
+    pub fn guilds_template(code: &str) -> String {
+        format!(api!("/guilds/templates/{}"), code)
+    }
+
+    pub fn interaction_response<D>(interaction_id: u64, token: D) -> String
+        where D: Display {
+        format!(api!("/interactions/{}/{}/callback"), interaction_id, token)
+    }
+
     pub fn invite(code: &str) -> String {
         format!(api!("/invites/{}"), code)
     }
@@ -574,6 +951,22 @@ impl Route {
         api!("/users/@me/channels")
     }
 
+    pub fn stage_instances() -> &'static str {
+        api!("/stage-instances")
+    }
+
+    pub fn stage_instance(channel_id: u64) -> String {
+        format!(api!("/stage-instances/{}"), channel_id)
+    }
+
+    pub fn sticker_packs() -> &'static str {
+        api!("/sticker-packs")
+    }
+
+    pub fn sticker(sticker_id: u64) -> String {
+        format!(api!("/stickers/{}"), sticker_id)
+    }
+
     pub fn status_incidents_unresolved() -> &'static str {
         status!("/incidents/unresolved.json")
     }
@@ -621,6 +1014,18 @@ impl Route {
         s
     }
 
+    pub fn user_guilds_with_counts_optioned<D: Display>(
+        target: D,
+        after: Option<u64>,
+        before: Option<u64>,
+        limit: u64,
+    ) -> String {
+        let mut s = Route::user_guilds_optioned(target, after, before, limit);
+        s.push_str("&with_counts=true");
+
+        s
+    }
+
     pub fn voice_regions() -> &'static str {
         api!("/voice/regions")
     }
@@ -629,6 +1034,22 @@ impl Route {
         format!(api!("/webhooks/{}"), webhook_id)
     }
 
+    pub fn webhook_original_interaction_response<D>(application_id: u64, token: D) -> String
+        where D: Display {
+        format!(api!("/webhooks/{}/{}/messages/@original"), application_id, token)
+    }
+
+    pub fn webhook_message<D>(webhook_id: u64, token: D, message_id: u64, thread_id: Option<u64>) -> String
+        where D: Display {
+        let mut s = format!(api!("/webhooks/{}/{}/messages/{}"), webhook_id, token, message_id);
+
+        if let Some(thread_id) = thread_id {
+            let _ = write!(s, "?thread_id={}", thread_id);
+        }
+
+        s
+    }
+
     pub fn webhook_with_token<D>(webhook_id: u64, token: D) -> String
         where D: Display {
         format!(api!("/webhooks/{}/{}"), webhook_id, token)
@@ -638,6 +1059,11 @@ impl Route {
         -> String where D: Display {
         format!(api!("/webhooks/{}/{}?wait={}"), webhook_id, token, wait)
     }
+
+    pub fn webhook_with_token_in_thread_optioned<D>(webhook_id: u64, token: D, wait: bool, thread_id: u64)
+        -> String where D: Display {
+        format!(api!("/webhooks/{}/{}?wait={}&thread_id={}"), webhook_id, token, wait, thread_id)
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -648,6 +1074,10 @@ pub enum RouteInfo<'a> {
         role_id: u64,
         user_id: u64,
     },
+    AddThreadMember {
+        channel_id: u64,
+        user_id: u64,
+    },
     GuildBanUser {
         guild_id: u64,
         user_id: u64,
@@ -657,17 +1087,51 @@ pub enum RouteInfo<'a> {
     BroadcastTyping {
         channel_id: u64,
     },
+    BulkBanUsers {
+        guild_id: u64,
+    },
+    CreateAutoModRule {
+        guild_id: u64,
+    },
     CreateChannel {
         guild_id: u64,
     },
     CreateEmoji {
         guild_id: u64,
     },
+    CreateFollowupMessage {
+        application_id: u64,
+        token: &'a str,
+    },
+    CreateGlobalApplicationCommand {
+        application_id: u64,
+    },
+    CreateGlobalApplicationCommands {
+        application_id: u64,
+    },
     CreateGuild,
+    CreateGuildApplicationCommand {
+        application_id: u64,
+        guild_id: u64,
+    },
+    CreateGuildApplicationCommands {
+        application_id: u64,
+        guild_id: u64,
+    },
+    CreateGuildFromTemplate {
+        code: &'a str,
+    },
     CreateGuildIntegration {
         guild_id: u64,
         integration_id: u64,
     },
+    CreateGuildTemplate {
+        guild_id: u64,
+    },
+    CreateInteractionResponse {
+        interaction_id: u64,
+        token: &'a str,
+    },
     CreateInvite {
         channel_id: u64,
     },
@@ -687,9 +1151,31 @@ pub enum RouteInfo<'a> {
     CreateRole {
         guild_id: u64,
     },
+    CreateScheduledEvent {
+        guild_id: u64,
+    },
+    CreateStageInstance,
+    CreateSticker {
+        guild_id: u64,
+    },
+    CreateThread {
+        channel_id: u64,
+    },
+    CreateThreadFromMessage {
+        channel_id: u64,
+        message_id: u64,
+    },
     CreateWebhook {
         channel_id: u64,
     },
+    CrosspostMessage {
+        channel_id: u64,
+        message_id: u64,
+    },
+    DeleteAutoModRule {
+        guild_id: u64,
+        rule_id: u64,
+    },
     DeleteChannel {
         channel_id: u64,
     },
@@ -697,13 +1183,30 @@ pub enum RouteInfo<'a> {
         guild_id: u64,
         emoji_id: u64,
     },
+    DeleteOriginalInteractionResponse {
+        application_id: u64,
+        token: &'a str,
+    },
+    DeleteGlobalApplicationCommand {
+        application_id: u64,
+        command_id: u64,
+    },
     DeleteGuild {
         guild_id: u64,
     },
+    DeleteGuildApplicationCommand {
+        application_id: u64,
+        guild_id: u64,
+        command_id: u64,
+    },
     DeleteGuildIntegration {
         guild_id: u64,
         integration_id: u64,
     },
+    DeleteGuildTemplate {
+        guild_id: u64,
+        code: &'a str,
+    },
     DeleteInvite {
         code: &'a str,
     },
@@ -737,13 +1240,34 @@ pub enum RouteInfo<'a> {
         guild_id: u64,
         role_id: u64,
     },
+    DeleteScheduledEvent {
+        guild_id: u64,
+        event_id: u64,
+    },
+    DeleteStageInstance {
+        channel_id: u64,
+    },
+    DeleteSticker {
+        guild_id: u64,
+        sticker_id: u64,
+    },
     DeleteWebhook {
         webhook_id: u64,
     },
+    DeleteWebhookMessage {
+        message_id: u64,
+        thread_id: Option<u64>,
+        token: &'a str,
+        webhook_id: u64,
+    },
     DeleteWebhookWithToken {
         token: &'a str,
         webhook_id: u64,
     },
+    EditAutoModRule {
+        guild_id: u64,
+        rule_id: u64,
+    },
     EditChannel {
         channel_id: u64,
     },
@@ -760,6 +1284,16 @@ pub enum RouteInfo<'a> {
     EditGuildEmbed {
         guild_id: u64,
     },
+    EditGuildTemplate {
+        guild_id: u64,
+        code: &'a str,
+    },
+    EditGuildWelcomeScreen {
+        guild_id: u64,
+    },
+    EditGuildWidget {
+        guild_id: u64,
+    },
     EditMember {
         guild_id: u64,
         user_id: u64,
@@ -771,6 +1305,13 @@ pub enum RouteInfo<'a> {
     EditNickname {
         guild_id: u64,
     },
+    EditGuildOnboarding {
+        guild_id: u64,
+    },
+    EditOriginalInteractionResponse {
+        application_id: u64,
+        token: &'a str,
+    },
     EditProfile,
     EditRole {
         guild_id: u64,
@@ -779,9 +1320,26 @@ pub enum RouteInfo<'a> {
     EditRolePosition {
         guild_id: u64,
     },
+    EditScheduledEvent {
+        guild_id: u64,
+        event_id: u64,
+    },
+    EditStageInstance {
+        channel_id: u64,
+    },
+    EditSticker {
+        guild_id: u64,
+        sticker_id: u64,
+    },
     EditWebhook {
         webhook_id: u64,
     },
+    EditWebhookMessage {
+        message_id: u64,
+        thread_id: Option<u64>,
+        token: &'a str,
+        webhook_id: u64,
+    },
     EditWebhookWithToken {
         token: &'a str,
         webhook_id: u64,
@@ -791,6 +1349,12 @@ pub enum RouteInfo<'a> {
         wait: bool,
         webhook_id: u64,
     },
+    ExecuteWebhookInThread {
+        thread_id: u64,
+        token: &'a str,
+        wait: bool,
+        webhook_id: u64,
+    },
     GetActiveMaintenance,
     GetAuditLogs {
         action_type: Option<u8>,
@@ -799,10 +1363,27 @@ pub enum RouteInfo<'a> {
         limit: Option<u8>,
         user_id: Option<u64>,
     },
+    GetAutoModRule {
+        guild_id: u64,
+        rule_id: u64,
+    },
+    GetAutoModRules {
+        guild_id: u64,
+    },
     GetBans {
         guild_id: u64,
     },
     GetBotGateway,
+    GetArchivedPrivateThreads {
+        channel_id: u64,
+        before: Option<&'a str>,
+        limit: Option<u64>,
+    },
+    GetArchivedPublicThreads {
+        channel_id: u64,
+        before: Option<&'a str>,
+        limit: Option<u64>,
+    },
     GetChannel {
         channel_id: u64,
     },
@@ -825,9 +1406,25 @@ pub enum RouteInfo<'a> {
         emoji_id: u64,
     },
     GetGateway,
+    GetGlobalApplicationCommand {
+        application_id: u64,
+        command_id: u64,
+    },
+    GetGlobalApplicationCommands {
+        application_id: u64,
+    },
     GetGuild {
         guild_id: u64,
     },
+    GetGuildApplicationCommand {
+        application_id: u64,
+        guild_id: u64,
+        command_id: u64,
+    },
+    GetGuildApplicationCommands {
+        application_id: u64,
+        guild_id: u64,
+    },
     GetGuildEmbed {
         guild_id: u64,
     },
@@ -842,9 +1439,13 @@ pub enum RouteInfo<'a> {
         limit: Option<u64>,
         guild_id: u64,
     },
+    GetGuildOnboarding {
+        guild_id: u64,
+    },
     GetGuildPruneCount {
         days: u64,
         guild_id: u64,
+        include_roles: &'a [u64],
     },
     GetGuildRegions {
         guild_id: u64,
@@ -852,17 +1453,41 @@ pub enum RouteInfo<'a> {
     GetGuildRoles {
         guild_id: u64,
     },
+    GetGuildSticker {
+        guild_id: u64,
+        sticker_id: u64,
+    },
+    GetGuildStickers {
+        guild_id: u64,
+    },
+    GetGuildTemplates {
+        guild_id: u64,
+    },
     GetGuildVanityUrl {
         guild_id: u64,
     },
     GetGuildWebhooks {
         guild_id: u64,
     },
+    GetGuildWelcomeScreen {
+        guild_id: u64,
+    },
+    GetGuildWidget {
+        guild_id: u64,
+    },
+    GetGuildWithCounts {
+        guild_id: u64,
+    },
     GetGuilds {
         after: Option<u64>,
         before: Option<u64>,
         limit: u64,
     },
+    GetGuildsWithCounts {
+        after: Option<u64>,
+        before: Option<u64>,
+        limit: u64,
+    },
     GetInvite {
         code: &'a str,
         stats: bool,
@@ -879,6 +1504,7 @@ pub enum RouteInfo<'a> {
         channel_id: u64,
         query: String,
     },
+    GetNitroStickerPacks,
     GetPins {
         channel_id: u64,
     },
@@ -889,6 +1515,29 @@ pub enum RouteInfo<'a> {
         message_id: u64,
         reaction: String,
     },
+    GetScheduledEvent {
+        guild_id: u64,
+        event_id: u64,
+        with_user_count: bool,
+    },
+    GetScheduledEventUsers {
+        after: Option<u64>,
+        before: Option<u64>,
+        event_id: u64,
+        guild_id: u64,
+        limit: Option<u64>,
+        with_member: bool,
+    },
+    GetScheduledEvents {
+        guild_id: u64,
+        with_user_count: bool,
+    },
+    GetStageInstance {
+        channel_id: u64,
+    },
+    GetSticker {
+        sticker_id: u64,
+    },
     GetUnresolvedIncidents,
     GetUpcomingMaintenances,
     GetUser {
@@ -903,6 +1552,9 @@ pub enum RouteInfo<'a> {
         token: &'a str,
         webhook_id: u64,
     },
+    JoinThread {
+        channel_id: u64,
+    },
     KickMember {
         guild_id: u64,
         user_id: u64,
@@ -914,6 +1566,9 @@ pub enum RouteInfo<'a> {
     LeaveGuild {
         guild_id: u64,
     },
+    LeaveThread {
+        channel_id: u64,
+    },
     PinMessage {
         channel_id: u64,
         message_id: u64,
@@ -927,9 +1582,15 @@ pub enum RouteInfo<'a> {
         role_id: u64,
         user_id: u64,
     },
+    RemoveThreadMember {
+        channel_id: u64,
+        user_id: u64,
+    },
     StartGuildPrune {
+        compute_count: bool,
         days: u64,
         guild_id: u64,
+        include_roles: &'a [u64],
     },
     StartIntegrationSync {
         guild_id: u64,
@@ -938,6 +1599,10 @@ pub enum RouteInfo<'a> {
     StatusIncidentsUnresolved,
     StatusMaintenancesActive,
     StatusMaintenancesUpcoming,
+    SyncGuildTemplate {
+        guild_id: u64,
+        code: &'a str,
+    },
     UnpinMessage {
         channel_id: u64,
         message_id: u64,
@@ -952,6 +1617,11 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdMembersIdRolesId(guild_id),
                 Cow::from(Route::guild_member_role(guild_id, user_id, role_id)),
             ),
+            RouteInfo::AddThreadMember { channel_id, user_id } => (
+                LightMethod::Put,
+                Route::ChannelsIdThreadMembersUserId(channel_id),
+                Cow::from(Route::channel_thread_member(channel_id, user_id)),
+            ),
             RouteInfo::GuildBanUser {
                 guild_id,
                 delete_message_days,
@@ -973,6 +1643,16 @@ impl<'a> RouteInfo<'a> {
                 Route::ChannelsIdTyping(channel_id),
                 Cow::from(Route::channel_typing(channel_id)),
             ),
+            RouteInfo::BulkBanUsers { guild_id } => (
+                LightMethod::Post,
+                Route::GuildsIdBulkBan(guild_id),
+                Cow::from(Route::guild_bulk_ban(guild_id)),
+            ),
+            RouteInfo::CreateAutoModRule { guild_id } => (
+                LightMethod::Post,
+                Route::GuildsIdAutoModerationRules(guild_id),
+                Cow::from(Route::guild_automod_rules(guild_id)),
+            ),
             RouteInfo::CreateChannel { guild_id } => (
                 LightMethod::Post,
                 Route::GuildsIdChannels(guild_id),
@@ -983,16 +1663,56 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdEmojis(guild_id),
                 Cow::from(Route::guild_emojis(guild_id)),
             ),
+            RouteInfo::CreateFollowupMessage { application_id, token } => (
+                LightMethod::Post,
+                Route::WebhooksId(application_id),
+                Cow::from(Route::webhook_with_token(application_id, token)),
+            ),
+            RouteInfo::CreateGlobalApplicationCommand { application_id } => (
+                LightMethod::Post,
+                Route::ApplicationsIdCommands(application_id),
+                Cow::from(Route::application_commands(application_id)),
+            ),
+            RouteInfo::CreateGlobalApplicationCommands { application_id } => (
+                LightMethod::Put,
+                Route::ApplicationsIdCommands(application_id),
+                Cow::from(Route::application_commands(application_id)),
+            ),
             RouteInfo::CreateGuild => (
                 LightMethod::Post,
                 Route::Guilds,
                 Cow::from(Route::guilds()),
             ),
+            RouteInfo::CreateGuildApplicationCommand { application_id, guild_id } => (
+                LightMethod::Post,
+                Route::ApplicationsIdGuildsIdCommands(application_id),
+                Cow::from(Route::application_guild_commands(application_id, guild_id)),
+            ),
+            RouteInfo::CreateGuildApplicationCommands { application_id, guild_id } => (
+                LightMethod::Put,
+                Route::ApplicationsIdGuildsIdCommands(application_id),
+                Cow::from(Route::application_guild_commands(application_id, guild_id)),
+            ),
+            RouteInfo::CreateGuildFromTemplate { code } => (
+                LightMethod::Post,
+                Route::GuildsTemplatesCode,
+                Cow::from(Route::guilds_template(code)),
+            ),
             RouteInfo::CreateGuildIntegration { guild_id, integration_id } => (
                 LightMethod::Post,
                 Route::GuildsIdIntegrationsId(guild_id),
                 Cow::from(Route::guild_integration(guild_id, integration_id)),
             ),
+            RouteInfo::CreateGuildTemplate { guild_id } => (
+                LightMethod::Post,
+                Route::GuildsIdTemplates(guild_id),
+                Cow::from(Route::guild_templates(guild_id)),
+            ),
+            RouteInfo::CreateInteractionResponse { interaction_id, token } => (
+                LightMethod::Post,
+                Route::InteractionsIdTokenCallback(interaction_id),
+                Cow::from(Route::interaction_response(interaction_id, token)),
+            ),
             RouteInfo::CreateInvite { channel_id } => (
                 LightMethod::Post,
                 Route::ChannelsIdInvites(channel_id),
@@ -1028,11 +1748,46 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdRoles(guild_id),
                 Cow::from(Route::guild_roles(guild_id)),
             ),
+            RouteInfo::CreateScheduledEvent { guild_id } => (
+                LightMethod::Post,
+                Route::GuildsIdScheduledEvents(guild_id),
+                Cow::from(Route::guild_scheduled_events(guild_id)),
+            ),
+            RouteInfo::CreateStageInstance => (
+                LightMethod::Post,
+                Route::StageInstances,
+                Cow::from(Route::stage_instances()),
+            ),
+            RouteInfo::CreateSticker { guild_id } => (
+                LightMethod::Post,
+                Route::GuildsIdStickers(guild_id),
+                Cow::from(Route::guild_stickers(guild_id)),
+            ),
+            RouteInfo::CreateThread { channel_id } => (
+                LightMethod::Post,
+                Route::ChannelsIdThreads(channel_id),
+                Cow::from(Route::channel_threads(channel_id)),
+            ),
+            RouteInfo::CreateThreadFromMessage { channel_id, message_id } => (
+                LightMethod::Post,
+                Route::ChannelsIdMessagesIdThreads(channel_id),
+                Cow::from(Route::channel_message_threads(channel_id, message_id)),
+            ),
             RouteInfo::CreateWebhook { channel_id } => (
                 LightMethod::Post,
                 Route::ChannelsIdWebhooks(channel_id),
                 Cow::from(Route::channel_webhooks(channel_id)),
             ),
+            RouteInfo::CrosspostMessage { channel_id, message_id } => (
+                LightMethod::Post,
+                Route::ChannelsIdMessagesIdCrosspost(channel_id),
+                Cow::from(Route::channel_message_crosspost(channel_id, message_id)),
+            ),
+            RouteInfo::DeleteAutoModRule { guild_id, rule_id } => (
+                LightMethod::Delete,
+                Route::GuildsIdAutoModerationRulesId(guild_id),
+                Cow::from(Route::guild_automod_rule(guild_id, rule_id)),
+            ),
             RouteInfo::DeleteChannel { channel_id } => (
                 LightMethod::Delete,
                 Route::ChannelsId(channel_id),
@@ -1043,16 +1798,36 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdEmojisId(guild_id),
                 Cow::from(Route::guild_emoji(guild_id, emoji_id)),
             ),
+            RouteInfo::DeleteOriginalInteractionResponse { application_id, token } => (
+                LightMethod::Delete,
+                Route::WebhooksApplicationIdTokenMessagesOriginal(application_id),
+                Cow::from(Route::webhook_original_interaction_response(application_id, token)),
+            ),
+            RouteInfo::DeleteGlobalApplicationCommand { application_id, command_id } => (
+                LightMethod::Delete,
+                Route::ApplicationsIdCommandsId(application_id),
+                Cow::from(Route::application_command(application_id, command_id)),
+            ),
             RouteInfo::DeleteGuild { guild_id } => (
                 LightMethod::Delete,
                 Route::GuildsId(guild_id),
                 Cow::from(Route::guild(guild_id)),
             ),
+            RouteInfo::DeleteGuildApplicationCommand { application_id, guild_id, command_id } => (
+                LightMethod::Delete,
+                Route::ApplicationsIdGuildsIdCommandsId(application_id),
+                Cow::from(Route::application_guild_command(application_id, guild_id, command_id)),
+            ),
             RouteInfo::DeleteGuildIntegration { guild_id, integration_id } => (
                 LightMethod::Delete,
                 Route::GuildsIdIntegrationsId(guild_id),
                 Cow::from(Route::guild_integration(guild_id, integration_id)),
             ),
+            RouteInfo::DeleteGuildTemplate { guild_id, code } => (
+                LightMethod::Delete,
+                Route::GuildsIdTemplatesCode(guild_id),
+                Cow::from(Route::guild_template(guild_id, code)),
+            ),
             RouteInfo::DeleteInvite { code } => (
                 LightMethod::Delete,
                 Route::InvitesCode,
@@ -1110,16 +1885,41 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdRolesId(guild_id),
                 Cow::from(Route::guild_role(guild_id, role_id)),
             ),
+            RouteInfo::DeleteScheduledEvent { guild_id, event_id } => (
+                LightMethod::Delete,
+                Route::GuildsIdScheduledEventsId(guild_id),
+                Cow::from(Route::guild_scheduled_event(guild_id, event_id)),
+            ),
+            RouteInfo::DeleteStageInstance { channel_id } => (
+                LightMethod::Delete,
+                Route::StageInstancesChannelId(channel_id),
+                Cow::from(Route::stage_instance(channel_id)),
+            ),
+            RouteInfo::DeleteSticker { guild_id, sticker_id } => (
+                LightMethod::Delete,
+                Route::GuildsIdStickersId(guild_id),
+                Cow::from(Route::guild_sticker(guild_id, sticker_id)),
+            ),
             RouteInfo::DeleteWebhook { webhook_id } => (
                 LightMethod::Delete,
                 Route::WebhooksId(webhook_id),
                 Cow::from(Route::webhook(webhook_id)),
             ),
+            RouteInfo::DeleteWebhookMessage { message_id, thread_id, token, webhook_id } => (
+                LightMethod::Delete,
+                Route::WebhooksIdTokenMessagesId(webhook_id),
+                Cow::from(Route::webhook_message(webhook_id, token, message_id, thread_id)),
+            ),
             RouteInfo::DeleteWebhookWithToken { token, webhook_id } => (
                 LightMethod::Delete,
                 Route::WebhooksId(webhook_id),
                 Cow::from(Route::webhook_with_token(webhook_id, token)),
             ),
+            RouteInfo::EditAutoModRule { guild_id, rule_id } => (
+                LightMethod::Patch,
+                Route::GuildsIdAutoModerationRulesId(guild_id),
+                Cow::from(Route::guild_automod_rule(guild_id, rule_id)),
+            ),
             RouteInfo::EditChannel { channel_id } => (
                 LightMethod::Patch,
                 Route::ChannelsId(channel_id),
@@ -1145,6 +1945,21 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdEmbed(guild_id),
                 Cow::from(Route::guild_embed(guild_id)),
             ),
+            RouteInfo::EditGuildTemplate { guild_id, code } => (
+                LightMethod::Patch,
+                Route::GuildsIdTemplatesCode(guild_id),
+                Cow::from(Route::guild_template(guild_id, code)),
+            ),
+            RouteInfo::EditGuildWelcomeScreen { guild_id } => (
+                LightMethod::Patch,
+                Route::GuildsIdWelcomeScreen(guild_id),
+                Cow::from(Route::guild_welcome_screen(guild_id)),
+            ),
+            RouteInfo::EditGuildWidget { guild_id } => (
+                LightMethod::Patch,
+                Route::GuildsIdWidget(guild_id),
+                Cow::from(Route::guild_widget(guild_id)),
+            ),
             RouteInfo::EditMember { guild_id, user_id } => (
                 LightMethod::Patch,
                 Route::GuildsIdMembersId(guild_id),
@@ -1160,6 +1975,16 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdMembersMeNick(guild_id),
                 Cow::from(Route::guild_nickname(guild_id)),
             ),
+            RouteInfo::EditGuildOnboarding { guild_id } => (
+                LightMethod::Put,
+                Route::GuildsIdOnboarding(guild_id),
+                Cow::from(Route::guild_onboarding(guild_id)),
+            ),
+            RouteInfo::EditOriginalInteractionResponse { application_id, token } => (
+                LightMethod::Patch,
+                Route::WebhooksApplicationIdTokenMessagesOriginal(application_id),
+                Cow::from(Route::webhook_original_interaction_response(application_id, token)),
+            ),
             RouteInfo::EditProfile => (
                 LightMethod::Patch,
                 Route::UsersMe,
@@ -1175,11 +2000,31 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdRolesId(guild_id),
                 Cow::from(Route::guild_roles(guild_id)),
             ),
+            RouteInfo::EditScheduledEvent { guild_id, event_id } => (
+                LightMethod::Patch,
+                Route::GuildsIdScheduledEventsId(guild_id),
+                Cow::from(Route::guild_scheduled_event(guild_id, event_id)),
+            ),
+            RouteInfo::EditStageInstance { channel_id } => (
+                LightMethod::Patch,
+                Route::StageInstancesChannelId(channel_id),
+                Cow::from(Route::stage_instance(channel_id)),
+            ),
+            RouteInfo::EditSticker { guild_id, sticker_id } => (
+                LightMethod::Patch,
+                Route::GuildsIdStickersId(guild_id),
+                Cow::from(Route::guild_sticker(guild_id, sticker_id)),
+            ),
             RouteInfo::EditWebhook { webhook_id } => (
                 LightMethod::Patch,
                 Route::WebhooksId(webhook_id),
                 Cow::from(Route::webhook(webhook_id)),
             ),
+            RouteInfo::EditWebhookMessage { message_id, thread_id, token, webhook_id } => (
+                LightMethod::Patch,
+                Route::WebhooksIdTokenMessagesId(webhook_id),
+                Cow::from(Route::webhook_message(webhook_id, token, message_id, thread_id)),
+            ),
             RouteInfo::EditWebhookWithToken { token, webhook_id } => (
                 LightMethod::Patch,
                 Route::WebhooksId(webhook_id),
@@ -1194,6 +2039,16 @@ impl<'a> RouteInfo<'a> {
                     wait,
                 )),
             ),
+            RouteInfo::ExecuteWebhookInThread { thread_id, token, wait, webhook_id } => (
+                LightMethod::Post,
+                Route::WebhooksId(webhook_id),
+                Cow::from(Route::webhook_with_token_in_thread_optioned(
+                    webhook_id,
+                    token,
+                    wait,
+                    thread_id,
+                )),
+            ),
             RouteInfo::GetActiveMaintenance => (
                 LightMethod::Get,
                 Route::None,
@@ -1216,6 +2071,16 @@ impl<'a> RouteInfo<'a> {
                     limit,
                 )),
             ),
+            RouteInfo::GetAutoModRule { guild_id, rule_id } => (
+                LightMethod::Get,
+                Route::GuildsIdAutoModerationRulesId(guild_id),
+                Cow::from(Route::guild_automod_rule(guild_id, rule_id)),
+            ),
+            RouteInfo::GetAutoModRules { guild_id } => (
+                LightMethod::Get,
+                Route::GuildsIdAutoModerationRules(guild_id),
+                Cow::from(Route::guild_automod_rules(guild_id)),
+            ),
             RouteInfo::GetBans { guild_id } => (
                 LightMethod::Get,
                 Route::GuildsIdBans(guild_id),
@@ -1226,6 +2091,16 @@ impl<'a> RouteInfo<'a> {
                 Route::GatewayBot,
                 Cow::from(Route::gateway_bot()),
             ),
+            RouteInfo::GetArchivedPrivateThreads { channel_id, before, limit } => (
+                LightMethod::Get,
+                Route::ChannelsIdArchivedPrivateThreads(channel_id),
+                Cow::from(Route::channel_archived_private_threads(channel_id, before, limit)),
+            ),
+            RouteInfo::GetArchivedPublicThreads { channel_id, before, limit } => (
+                LightMethod::Get,
+                Route::ChannelsIdArchivedPublicThreads(channel_id),
+                Cow::from(Route::channel_archived_public_threads(channel_id, before, limit)),
+            ),
             RouteInfo::GetChannel { channel_id } => (
                 LightMethod::Get,
                 Route::ChannelsId(channel_id),
@@ -1271,11 +2146,31 @@ impl<'a> RouteInfo<'a> {
                 Route::Gateway,
                 Cow::from(Route::gateway()),
             ),
+            RouteInfo::GetGlobalApplicationCommand { application_id, command_id } => (
+                LightMethod::Get,
+                Route::ApplicationsIdCommandsId(application_id),
+                Cow::from(Route::application_command(application_id, command_id)),
+            ),
+            RouteInfo::GetGlobalApplicationCommands { application_id } => (
+                LightMethod::Get,
+                Route::ApplicationsIdCommands(application_id),
+                Cow::from(Route::application_commands(application_id)),
+            ),
             RouteInfo::GetGuild { guild_id } => (
                 LightMethod::Get,
                 Route::GuildsId(guild_id),
                 Cow::from(Route::guild(guild_id)),
             ),
+            RouteInfo::GetGuildApplicationCommand { application_id, guild_id, command_id } => (
+                LightMethod::Get,
+                Route::ApplicationsIdGuildsIdCommandsId(application_id),
+                Cow::from(Route::application_guild_command(application_id, guild_id, command_id)),
+            ),
+            RouteInfo::GetGuildApplicationCommands { application_id, guild_id } => (
+                LightMethod::Get,
+                Route::ApplicationsIdGuildsIdCommands(application_id),
+                Cow::from(Route::application_guild_commands(application_id, guild_id)),
+            ),
             RouteInfo::GetGuildEmbed { guild_id } => (
                 LightMethod::Get,
                 Route::GuildsIdEmbed(guild_id),
@@ -1296,10 +2191,15 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdMembers(guild_id),
                 Cow::from(Route::guild_members_optioned(guild_id, after, limit)),
             ),
-            RouteInfo::GetGuildPruneCount { days, guild_id } => (
+            RouteInfo::GetGuildOnboarding { guild_id } => (
+                LightMethod::Get,
+                Route::GuildsIdOnboarding(guild_id),
+                Cow::from(Route::guild_onboarding(guild_id)),
+            ),
+            RouteInfo::GetGuildPruneCount { days, guild_id, include_roles } => (
                 LightMethod::Get,
                 Route::GuildsIdPrune(guild_id),
-                Cow::from(Route::guild_prune(guild_id, days)),
+                Cow::from(Route::guild_prune_optioned(guild_id, days, None, include_roles)),
             ),
             RouteInfo::GetGuildRegions { guild_id } => (
                 LightMethod::Get,
@@ -1311,6 +2211,21 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdRoles(guild_id),
                 Cow::from(Route::guild_roles(guild_id)),
             ),
+            RouteInfo::GetGuildSticker { guild_id, sticker_id } => (
+                LightMethod::Get,
+                Route::GuildsIdStickersId(guild_id),
+                Cow::from(Route::guild_sticker(guild_id, sticker_id)),
+            ),
+            RouteInfo::GetGuildStickers { guild_id } => (
+                LightMethod::Get,
+                Route::GuildsIdStickers(guild_id),
+                Cow::from(Route::guild_stickers(guild_id)),
+            ),
+            RouteInfo::GetGuildTemplates { guild_id } => (
+                LightMethod::Get,
+                Route::GuildsIdTemplates(guild_id),
+                Cow::from(Route::guild_templates(guild_id)),
+            ),
             RouteInfo::GetGuildVanityUrl { guild_id } => (
                 LightMethod::Get,
                 Route::GuildsIdVanityUrl(guild_id),
@@ -1321,6 +2236,21 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdWebhooks(guild_id),
                 Cow::from(Route::guild_webhooks(guild_id)),
             ),
+            RouteInfo::GetGuildWelcomeScreen { guild_id } => (
+                LightMethod::Get,
+                Route::GuildsIdWelcomeScreen(guild_id),
+                Cow::from(Route::guild_welcome_screen(guild_id)),
+            ),
+            RouteInfo::GetGuildWidget { guild_id } => (
+                LightMethod::Get,
+                Route::GuildsIdWidget(guild_id),
+                Cow::from(Route::guild_widget(guild_id)),
+            ),
+            RouteInfo::GetGuildWithCounts { guild_id } => (
+                LightMethod::Get,
+                Route::GuildsId(guild_id),
+                Cow::from(Route::guild_with_counts(guild_id)),
+            ),
             RouteInfo::GetGuilds { after, before, limit } => (
                 LightMethod::Get,
                 Route::UsersMeGuilds,
@@ -1331,6 +2261,16 @@ impl<'a> RouteInfo<'a> {
                     limit,
                 )),
             ),
+            RouteInfo::GetGuildsWithCounts { after, before, limit } => (
+                LightMethod::Get,
+                Route::UsersMeGuilds,
+                Cow::from(Route::user_guilds_with_counts_optioned(
+                    "@me",
+                    after,
+                    before,
+                    limit,
+                )),
+            ),
             RouteInfo::GetInvite { code, stats } => (
                 LightMethod::Get,
                 Route::InvitesCode,
@@ -1354,6 +2294,11 @@ impl<'a> RouteInfo<'a> {
                     Some(query.as_ref()),
                 )),
             ),
+            RouteInfo::GetNitroStickerPacks => (
+                LightMethod::Get,
+                Route::StickerPacks,
+                Cow::from(Route::sticker_packs()),
+            ),
             RouteInfo::GetPins { channel_id } => (
                 LightMethod::Get,
                 Route::ChannelsIdPins(channel_id),
@@ -1376,6 +2321,49 @@ impl<'a> RouteInfo<'a> {
                     after,
                 )),
             ),
+            RouteInfo::GetScheduledEvent { guild_id, event_id, with_user_count } => (
+                LightMethod::Get,
+                Route::GuildsIdScheduledEventsId(guild_id),
+                Cow::from(Route::guild_scheduled_event_optioned(
+                    guild_id,
+                    event_id,
+                    with_user_count,
+                )),
+            ),
+            RouteInfo::GetScheduledEventUsers {
+                after,
+                before,
+                event_id,
+                guild_id,
+                limit,
+                with_member,
+            } => (
+                LightMethod::Get,
+                Route::GuildsIdScheduledEventsIdUsers(guild_id),
+                Cow::from(Route::guild_scheduled_event_users_optioned(
+                    guild_id,
+                    event_id,
+                    after,
+                    before,
+                    limit,
+                    with_member,
+                )),
+            ),
+            RouteInfo::GetScheduledEvents { guild_id, with_user_count } => (
+                LightMethod::Get,
+                Route::GuildsIdScheduledEvents(guild_id),
+                Cow::from(Route::guild_scheduled_events_optioned(guild_id, with_user_count)),
+            ),
+            RouteInfo::GetStageInstance { channel_id } => (
+                LightMethod::Get,
+                Route::StageInstancesChannelId(channel_id),
+                Cow::from(Route::stage_instance(channel_id)),
+            ),
+            RouteInfo::GetSticker { sticker_id } => (
+                LightMethod::Get,
+                Route::StickersId,
+                Cow::from(Route::sticker(sticker_id)),
+            ),
             RouteInfo::GetUnresolvedIncidents => (
                 LightMethod::Get,
                 Route::None,
@@ -1411,6 +2399,11 @@ impl<'a> RouteInfo<'a> {
                 Route::WebhooksId(webhook_id),
                 Cow::from(Route::webhook_with_token(webhook_id, token)),
             ),
+            RouteInfo::JoinThread { channel_id } => (
+                LightMethod::Put,
+                Route::ChannelsIdThreadMembersUserId(channel_id),
+                Cow::from(Route::channel_thread_member(channel_id, "@me")),
+            ),
             RouteInfo::KickMember { guild_id, user_id, reason } => (
                 LightMethod::Delete,
                 Route::GuildsIdMembersId(guild_id),
@@ -1430,9 +2423,14 @@ impl<'a> RouteInfo<'a> {
                 Route::UsersMeGuildsId,
                 Cow::from(Route::user_guild("@me", guild_id)),
             ),
+            RouteInfo::LeaveThread { channel_id } => (
+                LightMethod::Delete,
+                Route::ChannelsIdThreadMembersUserId(channel_id),
+                Cow::from(Route::channel_thread_member(channel_id, "@me")),
+            ),
             RouteInfo::PinMessage { channel_id, message_id } => (
                 LightMethod::Put,
-                Route::ChannelsIdPins(channel_id),
+                Route::ChannelsIdPinsMessageId(channel_id),
                 Cow::from(Route::channel_pin(channel_id, message_id)),
             ),
             RouteInfo::RemoveBan { guild_id, user_id } => (
@@ -1445,10 +2443,15 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdMembersIdRolesId(guild_id),
                 Cow::from(Route::guild_member_role(guild_id, user_id, role_id)),
             ),
-            RouteInfo::StartGuildPrune { days, guild_id } => (
+            RouteInfo::RemoveThreadMember { channel_id, user_id } => (
+                LightMethod::Delete,
+                Route::ChannelsIdThreadMembersUserId(channel_id),
+                Cow::from(Route::channel_thread_member(channel_id, user_id)),
+            ),
+            RouteInfo::StartGuildPrune { compute_count, days, guild_id, include_roles } => (
                 LightMethod::Post,
                 Route::GuildsIdPrune(guild_id),
-                Cow::from(Route::guild_prune(guild_id, days)),
+                Cow::from(Route::guild_prune_optioned(guild_id, days, Some(compute_count), include_roles)),
             ),
             RouteInfo::StartIntegrationSync { guild_id, integration_id } => (
                 LightMethod::Post,
@@ -1473,6 +2476,11 @@ impl<'a> RouteInfo<'a> {
                 Route::None,
                 Cow::from(Route::status_maintenances_upcoming()),
             ),
+            RouteInfo::SyncGuildTemplate { guild_id, code } => (
+                LightMethod::Put,
+                Route::GuildsIdTemplatesCode(guild_id),
+                Cow::from(Route::guild_template(guild_id, code)),
+            ),
             RouteInfo::UnpinMessage { channel_id, message_id } => (
                 LightMethod::Delete,
                 Route::ChannelsIdPinsMessageId(channel_id),
@@ -1481,3 +2489,155 @@ impl<'a> RouteInfo<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{Route, RouteInfo};
+    use super::super::LightMethod;
+
+    #[test]
+    fn crosspost_message_route() {
+        let (method, route, path) = RouteInfo::CrosspostMessage {
+            channel_id: 1,
+            message_id: 2,
+        }.deconstruct();
+
+        assert_eq!(method, LightMethod::Post);
+        assert_eq!(route, Route::ChannelsIdMessagesIdCrosspost(1));
+        assert_eq!(path, Route::channel_message_crosspost(1, 2));
+        assert!(path.ends_with("/channels/1/messages/2/crosspost"));
+    }
+
+    #[test]
+    fn pin_message_routes() {
+        let (method, route, path) = RouteInfo::PinMessage {
+            channel_id: 1,
+            message_id: 2,
+        }.deconstruct();
+
+        assert_eq!(method, LightMethod::Put);
+        assert_eq!(route, Route::ChannelsIdPinsMessageId(1));
+        assert!(path.ends_with("/channels/1/pins/2"));
+
+        let (method, route, unpin_path) = RouteInfo::UnpinMessage {
+            channel_id: 1,
+            message_id: 2,
+        }.deconstruct();
+
+        assert_eq!(method, LightMethod::Delete);
+        assert_eq!(route, Route::ChannelsIdPinsMessageId(1));
+        assert_eq!(unpin_path, path);
+    }
+
+    #[test]
+    fn execute_webhook_in_thread_route() {
+        let (method, route, path) = RouteInfo::ExecuteWebhookInThread {
+            thread_id: 3,
+            token: "token",
+            wait: true,
+            webhook_id: 1,
+        }.deconstruct();
+
+        assert_eq!(method, LightMethod::Post);
+        assert_eq!(route, Route::WebhooksId(1));
+        assert!(path.ends_with("/webhooks/1/token?wait=true&thread_id=3"));
+
+        let path = Route::webhook_with_token_optioned(1, "token", false);
+        assert!(path.ends_with("/webhooks/1/token?wait=false"));
+    }
+
+    #[test]
+    fn webhook_message_routes() {
+        let (method, route, path) = RouteInfo::EditWebhookMessage {
+            message_id: 2,
+            thread_id: None,
+            token: "token",
+            webhook_id: 1,
+        }.deconstruct();
+
+        assert_eq!(method, LightMethod::Patch);
+        assert_eq!(route, Route::WebhooksIdTokenMessagesId(1));
+        assert!(path.ends_with("/webhooks/1/token/messages/2"));
+
+        let (method, route, path) = RouteInfo::EditWebhookMessage {
+            message_id: 2,
+            thread_id: Some(3),
+            token: "token",
+            webhook_id: 1,
+        }.deconstruct();
+
+        assert_eq!(method, LightMethod::Patch);
+        assert_eq!(route, Route::WebhooksIdTokenMessagesId(1));
+        assert!(path.ends_with("/webhooks/1/token/messages/2?thread_id=3"));
+
+        let (method, _, path) = RouteInfo::DeleteWebhookMessage {
+            message_id: 2,
+            thread_id: Some(3),
+            token: "token",
+            webhook_id: 1,
+        }.deconstruct();
+
+        assert_eq!(method, LightMethod::Delete);
+        assert!(path.ends_with("/webhooks/1/token/messages/2?thread_id=3"));
+    }
+
+    #[test]
+    fn guild_prune_routes() {
+        let (method, route, path) = RouteInfo::GetGuildPruneCount {
+            days: 7,
+            guild_id: 1,
+            include_roles: &[],
+        }.deconstruct();
+
+        assert_eq!(method, LightMethod::Get);
+        assert_eq!(route, Route::GuildsIdPrune(1));
+        assert!(path.ends_with("/guilds/1/prune?days=7"));
+
+        let (method, route, path) = RouteInfo::StartGuildPrune {
+            compute_count: false,
+            days: 30,
+            guild_id: 1,
+            include_roles: &[2, 3],
+        }.deconstruct();
+
+        assert_eq!(method, LightMethod::Post);
+        assert_eq!(route, Route::GuildsIdPrune(1));
+        assert!(path.ends_with("/guilds/1/prune?days=30&compute_prune_count=false&include_roles=2,3"));
+    }
+
+    #[test]
+    fn interaction_response_route() {
+        let (method, route, path) = RouteInfo::CreateInteractionResponse {
+            interaction_id: 1,
+            token: "token",
+        }.deconstruct();
+
+        assert_eq!(method, LightMethod::Post);
+        assert_eq!(route, Route::InteractionsIdTokenCallback(1));
+        assert!(path.ends_with("/interactions/1/token/callback"));
+    }
+
+    #[test]
+    fn guild_application_commands_route() {
+        let (method, route, path) = RouteInfo::CreateGuildApplicationCommands {
+            application_id: 1,
+            guild_id: 2,
+        }.deconstruct();
+
+        assert_eq!(method, LightMethod::Put);
+        assert_eq!(route, Route::ApplicationsIdGuildsIdCommands(1));
+        assert!(path.ends_with("/applications/1/guilds/2/commands"));
+    }
+
+    #[test]
+    fn original_interaction_response_route() {
+        let (method, route, path) = RouteInfo::EditOriginalInteractionResponse {
+            application_id: 1,
+            token: "token",
+        }.deconstruct();
+
+        assert_eq!(method, LightMethod::Patch);
+        assert_eq!(route, Route::WebhooksApplicationIdTokenMessagesOriginal(1));
+        assert!(path.ends_with("/webhooks/1/token/messages/@original"));
+    }
+}
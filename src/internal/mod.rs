@@ -5,3 +5,6 @@ pub mod prelude;
 
 #[cfg(feature = "gateway")]
 pub mod ws_impl;
+
+#[cfg(test)]
+pub mod test_utils;
@@ -0,0 +1,141 @@
+//! Fixtures shared by the unit tests of the library.
+
+use serde_json::{json, Value};
+
+use crate::model::channel::Message;
+use crate::model::guild::{Guild, Member, Role};
+use crate::model::id::{GuildId, RoleId, UserId};
+use crate::model::user::User;
+
+#[cfg(feature = "http")]
+use crate::{
+    http::{error::{DiscordJsonError, ErrorResponse}, HttpError, StatusCode},
+    Error,
+};
+
+#[cfg(all(feature = "cache", feature = "gateway"))]
+use std::sync::Arc;
+
+#[cfg(all(feature = "cache", feature = "gateway"))]
+use typemap_rev::TypeMap;
+
+#[cfg(all(feature = "cache", feature = "gateway"))]
+use crate::{cache::Cache, client::Context, http::Http, prelude::RwLock};
+
+/// The payload of a message with the Id `3` sent by the user `2` to the given
+/// channel, optionally in a guild.
+///
+/// Fields can be overwritten through indexing before deserializing it.
+pub fn message_json(channel_id: u64, guild_id: Option<u64>) -> Value {
+    json!({
+        "id": "3",
+        "attachments": [],
+        "author": {
+            "id": "2",
+            "avatar": null,
+            "discriminator": "0001",
+            "username": "user 1",
+        },
+        "channel_id": channel_id.to_string(),
+        "guild_id": guild_id.map(|id| id.to_string()),
+        "content": "",
+        "edited_timestamp": null,
+        "embeds": [],
+        "type": 0,
+        "mention_everyone": false,
+        "mention_roles": [],
+        "mentions": [],
+        "pinned": false,
+        "timestamp": "2016-11-08T00:00:00+00:00",
+        "tts": false,
+    })
+}
+
+/// The message described by [`message_json`].
+pub fn message(channel_id: u64, guild_id: Option<u64>) -> Message {
+    serde_json::from_value(message_json(channel_id, guild_id)).unwrap()
+}
+
+/// A member of the guild `1` for the user `user_id`, without a nickname,
+/// avatar or roles.
+pub fn member(user_id: u64) -> Member {
+    Member {
+        avatar: None,
+        communication_disabled_until: None,
+        deaf: false,
+        guild_id: GuildId(1),
+        joined_at: None,
+        mute: false,
+        nick: None,
+        roles: vec![],
+        user: User {
+            id: UserId(user_id),
+            avatar: None,
+            bot: false,
+            discriminator: 1,
+            name: "test".to_string(),
+        },
+    }
+}
+
+/// The [`member`] for the user `user_id`, with the given roles.
+pub fn member_with_roles(user_id: u64, roles: &[u64]) -> Member {
+    let mut member = member(user_id);
+    member.roles = roles.iter().copied().map(RoleId).collect();
+
+    member
+}
+
+/// Inserts a role without permissions into the guild, named after its Id.
+pub fn insert_role(guild: &mut Guild, id: u64, position: i64) {
+    let role = serde_json::from_value::<Role>(json!({
+        "id": id.to_string(),
+        "guild_id": guild.id.0.to_string(),
+        "color": 0,
+        "hoist": false,
+        "managed": false,
+        "name": format!("role {}", id),
+        "permissions": "0",
+        "position": position,
+    })).unwrap();
+
+    guild.roles.insert(role.id, role);
+}
+
+/// The error of a request which Discord answered with the given status, and
+/// the JSON error `code` and `message`.
+#[cfg(feature = "http")]
+pub fn error_response(status_code: StatusCode, code: isize, message: &str) -> Error {
+    let error: DiscordJsonError = serde_json::from_value(json!({"code": code, "message": message})).unwrap();
+
+    Error::Http(Box::new(HttpError::UnsuccessfulRequest(ErrorResponse {
+        status_code,
+        url: "https://discord.com/api/v8/".parse().unwrap(),
+        error,
+    })))
+}
+
+/// A context for shard `0` with empty data, an empty cache and an HTTP
+/// client without a token.
+#[cfg(all(feature = "cache", feature = "gateway"))]
+pub fn context() -> Context {
+    let (tx, _) = futures::channel::mpsc::unbounded();
+
+    Context::new(
+        Arc::new(RwLock::new(TypeMap::new())),
+        tx,
+        0,
+        Arc::new(Http::default()),
+        Arc::new(Cache::default()),
+    )
+}
+
+/// A framework which ignores every message.
+#[cfg(feature = "framework")]
+pub struct NoFramework;
+
+#[cfg(feature = "framework")]
+#[async_trait::async_trait]
+impl crate::framework::Framework for NoFramework {
+    async fn dispatch(&self, _: crate::client::Context, _: Message) {}
+}
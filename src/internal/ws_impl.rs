@@ -1,4 +1,5 @@
 use flate2::read::ZlibDecoder;
+use flate2::write::ZlibDecoder as ZlibStreamDecoder;
 use crate::gateway::{GatewayError, WsStream};
 use crate::internal::prelude::*;
 use async_tungstenite::tungstenite::Message;
@@ -6,6 +7,7 @@ use async_trait::async_trait;
 use tracing::{warn, instrument};
 use futures::{SinkExt, StreamExt, TryStreamExt};
 use tokio::time::timeout;
+use std::{io::Write, mem};
 
 #[cfg(all(feature = "rustls_backend", not(feature = "native_tls_backend")))]
 use std::{
@@ -22,6 +24,7 @@ use futures::stream::SplitSink;
 
 #[async_trait]
 pub trait ReceiverExt {
+    async fn recv_message(&mut self) -> Result<Option<Message>>;
     async fn recv_json(&mut self) -> Result<Option<Value>>;
     async fn try_recv_json(&mut self) -> Result<Option<Value>>;
 }
@@ -33,16 +36,18 @@ pub trait SenderExt {
 
 #[async_trait]
 impl ReceiverExt for WsStream {
-    async fn recv_json(&mut self) -> Result<Option<Value>> {
+    async fn recv_message(&mut self) -> Result<Option<Message>> {
         const TIMEOUT: tokio::time::Duration = tokio::time::Duration::from_millis(500);
 
-        let ws_message = match timeout(TIMEOUT, self.next()).await {
-            Ok(Some(Ok(v))) => Some(v),
-            Ok(Some(Err(e))) => return Err(e.into()),
-            Ok(None) | Err(_) => None,
-        };
+        match timeout(TIMEOUT, self.next()).await {
+            Ok(Some(Ok(v))) => Ok(Some(v)),
+            Ok(Some(Err(e))) => Err(e.into()),
+            Ok(None) | Err(_) => Ok(None),
+        }
+    }
 
-        convert_ws_message(ws_message)
+    async fn recv_json(&mut self) -> Result<Option<Value>> {
+        convert_ws_message(self.recv_message().await?)
     }
 
     async fn try_recv_json(&mut self) -> Result<Option<Value>> {
@@ -103,6 +108,76 @@ pub(crate) fn convert_ws_message(message: Option<Message>) -> Result<Option<Valu
     })
 }
 
+/// The suffix ending every complete payload sent with the `zlib-stream`
+/// transport compression.
+const ZLIB_SUFFIX: [u8; 4] = [0x00, 0x00, 0xff, 0xff];
+
+/// Inflates the payloads of a gateway connection using the `zlib-stream`
+/// transport compression.
+///
+/// The whole connection is a single zlib stream, so the inflate context must
+/// be kept across messages. A payload may also be split over several binary
+/// messages, in which case it is complete once the [`ZLIB_SUFFIX`] of the
+/// sync flush is received.
+pub(crate) struct ZlibStreamInflater {
+    decoder: ZlibStreamDecoder<Vec<u8>>,
+    buffer: Vec<u8>,
+}
+
+impl ZlibStreamInflater {
+    pub(crate) fn new() -> Self {
+        Self {
+            decoder: ZlibStreamDecoder::new(Vec::new()),
+            buffer: Vec::new(),
+        }
+    }
+
+    /// Resets the inflate context, which must be done for every new
+    /// connection.
+    pub(crate) fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Pushes the bytes of a binary message, returning the payload if it is
+    /// complete.
+    pub(crate) fn push(&mut self, bytes: &[u8]) -> Result<Option<Value>> {
+        self.buffer.extend_from_slice(bytes);
+
+        if !self.buffer.ends_with(&ZLIB_SUFFIX) {
+            return Ok(None);
+        }
+
+        let result = self.inflate();
+        self.buffer.clear();
+
+        result.map(Some)
+    }
+
+    fn inflate(&mut self) -> Result<Value> {
+        self.decoder.write_all(&self.buffer)?;
+        self.decoder.flush()?;
+
+        let payload = mem::take(self.decoder.get_mut());
+
+        serde_json::from_slice(&payload).map_err(|why| {
+            warn!("Err deserializing inflated bytes: {:?}; bytes: {:?}", why, payload);
+
+            why.into()
+        })
+    }
+}
+
+#[inline]
+pub(crate) fn convert_compressed_ws_message(
+    message: Option<Message>,
+    inflater: &mut ZlibStreamInflater,
+) -> Result<Option<Value>> {
+    match message {
+        Some(Message::Binary(bytes)) => inflater.push(&bytes),
+        message => convert_ws_message(message),
+    }
+}
+
 /// An error that occured while connecting over rustls
 #[derive(Debug)]
 #[non_exhaustive]
@@ -174,3 +249,42 @@ pub(crate) async fn create_native_tls_client(url: Url) -> Result<WsStream> {
 
     Ok(stream)
 }
+
+#[cfg(test)]
+mod test {
+    use super::ZlibStreamInflater;
+    use flate2::{Compress, Compression, FlushCompress};
+    use serde_json::json;
+
+    fn compress(compress: &mut Compress, payload: &str) -> Vec<u8> {
+        let mut output = Vec::with_capacity(payload.len() + 64);
+        compress.compress_vec(payload.as_bytes(), &mut output, FlushCompress::Sync).unwrap();
+
+        output
+    }
+
+    #[test]
+    fn test_zlib_stream_inflation() {
+        let mut compressor = Compress::new(Compression::default(), true);
+        let first = compress(&mut compressor, r#"{"op":10,"d":{"heartbeat_interval":41250}}"#);
+        let second = compress(&mut compressor, r#"{"op":11,"d":null}"#);
+
+        assert!(first.ends_with(&super::ZLIB_SUFFIX));
+
+        let mut inflater = ZlibStreamInflater::new();
+
+        // The first payload is split over several messages.
+        let (head, tail) = first.split_at(first.len() / 2);
+        assert!(inflater.push(head).unwrap().is_none());
+        assert_eq!(inflater.push(tail).unwrap(), Some(json!({"op": 10, "d": {"heartbeat_interval": 41250}})));
+
+        // The second payload relies on the inflate context of the first.
+        assert_eq!(inflater.push(&second).unwrap(), Some(json!({"op": 11, "d": null})));
+
+        // A new connection starts a new stream.
+        inflater.reset();
+        let mut compressor = Compress::new(Compression::default(), true);
+        let third = compress(&mut compressor, r#"{"op":11,"d":null}"#);
+        assert_eq!(inflater.push(&third).unwrap(), Some(json!({"op": 11, "d": null})));
+    }
+}
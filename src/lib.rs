@@ -58,6 +58,9 @@
 
 #[macro_use]
 extern crate serde;
+// Lets the tests use the framework macros, which refer to `serenity`.
+#[cfg(all(test, feature = "standard_framework"))]
+extern crate self as serenity;
 
 #[macro_use]
 mod internal;
@@ -1,7 +1,7 @@
 use crate::model::id::AttachmentId;
 
 #[cfg(feature = "model")]
-use reqwest::Client as ReqwestClient;
+use crate::http::Http;
 #[cfg(feature = "model")]
 use crate::internal::prelude::*;
 
@@ -39,6 +39,9 @@ impl Attachment {
 
     /// Downloads the attachment, returning back a vector of bytes.
     ///
+    /// The attachment is downloaded with the client of the given [`Http`], so
+    /// that its configuration, such as a [proxy], is used.
+    ///
     /// # Examples
     ///
     /// Download all of the attachments associated with a [`Message`]:
@@ -59,7 +62,7 @@ impl Attachment {
     /// impl EventHandler for Handler {
     ///     async fn message(&self, context: Context, mut message: Message) {
     ///         for attachment in message.attachments {
-    ///             let content = match attachment.download().await {
+    ///             let content = match attachment.download(&context.http).await {
     ///                 Ok(content) => content,
     ///                 Err(why) => {
     ///                     println!("Error downloading attachment: {:?}", why);
@@ -112,11 +115,10 @@ impl Attachment {
     /// [`Error::Http`]: crate::Error::Http
     /// [`Error::Io`]: crate::Error::Io
     /// [`Message`]: super::Message
-    pub async fn download(&self) -> Result<Vec<u8>> {
-        let reqwest = ReqwestClient::new();
-
-        Ok(reqwest
-           .get(&self.url)
+    /// [proxy]: crate::http::HttpBuilder::proxy
+    pub async fn download(&self, http: impl AsRef<Http>) -> Result<Vec<u8>> {
+        Ok(http.as_ref()
+           .download(&self.url)
            .send()
            .await?
            .bytes()
@@ -38,8 +38,14 @@ pub struct ChannelCategory {
 impl ChannelCategory {
     /// Adds a permission overwrite to the category's channels.
     #[inline]
-    pub async fn create_permission(&self, http: impl AsRef<Http>, target: &PermissionOverwrite) -> Result<()> {
-        self.id.create_permission(&http, target).await
+    pub async fn create_permission(
+        &self,
+        http: impl AsRef<Http>,
+        target: PermissionOverwriteType,
+        allow: Permissions,
+        deny: Permissions,
+    ) -> Result<()> {
+        self.id.create_permission(&http, target, allow, deny).await
     }
 
     /// Deletes all permission overrides in the category from the channels.
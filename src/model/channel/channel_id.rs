@@ -1,11 +1,21 @@
 use crate::model::prelude::*;
 
+#[cfg(feature = "model")]
+use std::collections::HashSet;
 #[cfg(feature = "model")]
 use std::fmt::Write as FmtWrite;
 #[cfg(feature = "model")]
+use chrono::{DateTime, SecondsFormat, Utc};
+#[cfg(all(feature = "model", feature = "utils"))]
+use crate::constants;
+#[cfg(feature = "model")]
 use crate::builder::{
+    CreateForumPost,
     CreateInvite,
     CreateMessage,
+    CreatePermissionOverwrite,
+    CreateStageInstance,
+    CreateThread,
     EditChannel,
     EditMessage,
     GetMessages
@@ -17,7 +27,7 @@ use crate::http::AttachmentType;
 #[cfg(all(feature = "model", feature = "utils"))]
 use crate::utils;
 #[cfg(feature = "model")]
-use crate::http::{Http, CacheHttp, Typing};
+use crate::http::{Http, HttpError, CacheHttp, Typing};
 #[cfg(feature = "model")]
 use serde_json::json;
 #[cfg(feature = "model")]
@@ -33,6 +43,15 @@ use crate::collector::{
 
 #[cfg(feature = "model")]
 impl ChannelId {
+    /// Adds a [`User`] to the thread.
+    ///
+    /// **Note**: The current user must be able to send messages in the thread,
+    /// and the thread must not be archived.
+    #[inline]
+    pub async fn add_thread_member(self, http: impl AsRef<Http>, user_id: impl Into<UserId>) -> Result<()> {
+        http.as_ref().add_thread_channel_member(self.0, user_id.into().0).await
+    }
+
     /// Broadcasts that the current user is typing to a channel for the next 5
     /// seconds.
     ///
@@ -62,6 +81,15 @@ impl ChannelId {
     }
 
     /// Creates an invite leading to the given channel.
+    ///
+    /// Refer to [`CreateInvite`] for the settings of the invite.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidInviteTarget`] if the invite targets
+    /// both a user's stream and an embedded application.
+    ///
+    /// [`CreateInvite`]: crate::builder::CreateInvite
     #[cfg(feature = "utils")]
     pub async fn create_invite<F>(&self, http: impl AsRef<Http>, f: F) -> Result<RichInvite>
     where
@@ -70,7 +98,7 @@ impl ChannelId {
         let mut invite = CreateInvite::default();
         f(&mut invite);
 
-        let map = utils::hashmap_to_json_map(invite.0);
+        let map = crate::model::invite::invite_map(invite)?;
 
         http.as_ref().create_invite(self.0, &map).await
     }
@@ -84,20 +112,101 @@ impl ChannelId {
     /// Requires the [Manage Channels] permission.
     ///
     /// [Manage Channels]: Permissions::MANAGE_CHANNELS
-    pub async fn create_permission(self, http: impl AsRef<Http>, target: &PermissionOverwrite) -> Result<()> {
-        let (id, kind) = match target.kind {
-            PermissionOverwriteType::Member(id) => (id.0, "member"),
-            PermissionOverwriteType::Role(id) => (id.0, "role"),
+    pub async fn create_permission(
+        self,
+        http: impl AsRef<Http>,
+        target: PermissionOverwriteType,
+        allow: Permissions,
+        deny: Permissions,
+    ) -> Result<()> {
+        let target_id = match target {
+            PermissionOverwriteType::Member(id) => id.0,
+            PermissionOverwriteType::Role(id) => id.0,
         };
 
-        let map = json!({
-            "allow": target.allow.bits(),
-            "deny": target.deny.bits(),
-            "id": id,
-            "type": kind,
-        });
+        let mut overwrite = CreatePermissionOverwrite::default();
+        overwrite.target(target).allow(allow).deny(deny);
+
+        let map = Value::Object(utils::hashmap_to_json_map(overwrite.0));
+
+        http.as_ref().create_permission(self.0, target_id, &map).await
+    }
+
+    /// Creates a post in the forum channel, which is a thread started with an
+    /// initial message.
+    ///
+    /// Refer to [`CreateForumPost`] for the settings of the post.
+    ///
+    /// **Note**: Requires the [Send Messages] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::NoForumPostMessage`] if the initial message
+    /// has no content, embed or component.
+    ///
+    /// Returns a [`ModelError::MessageTooLong`], [`ModelError::EmbedAmount`]
+    /// or [`ModelError::EmbedTooLarge`] if the initial message is over the
+    /// limits of [`send_message`], and a [`ModelError::InvalidTimestamp`] or
+    /// [`ModelError::InvalidButton`] if one of its embeds or buttons is
+    /// malformed.
+    ///
+    /// Returns an [`Error::Http`] if the channel is not a forum channel, or
+    /// one of the applied tags does not belong to it.
+    ///
+    /// [`CreateForumPost`]: crate::builder::CreateForumPost
+    /// [`send_message`]: Self::send_message
+    /// [Send Messages]: Permissions::SEND_MESSAGES
+    /// [`Error::Http`]: crate::error::Error::Http
+    #[cfg(feature = "utils")]
+    pub async fn create_forum_post<F>(self, http: impl AsRef<Http>, f: F) -> Result<GuildChannel>
+    where F: FnOnce(&mut CreateForumPost) -> &mut CreateForumPost
+    {
+        let map = forum_post_map(f)?;
+
+        http.as_ref().create_thread(self.0, &map).await
+    }
+
+    /// Creates a private thread in the channel, which is not attached to a
+    /// message. Only the members which are invited or mentioned can see it.
+    ///
+    /// Refer to [`CreateThread`] for the settings of the thread.
+    ///
+    /// **Note**: Requires the Create Private Threads permission.
+    ///
+    /// [`CreateThread`]: crate::builder::CreateThread
+    #[cfg(feature = "utils")]
+    pub async fn create_private_thread<F>(self, http: impl AsRef<Http>, f: F) -> Result<GuildChannel>
+    where F: FnOnce(&mut CreateThread) -> &mut CreateThread
+    {
+        self._create_thread(http, ChannelType::PrivateThread, f).await
+    }
+
+    /// Creates a public thread in the channel, which is not attached to a
+    /// message.
+    ///
+    /// Refer to [`CreateThread`] for the settings of the thread.
+    ///
+    /// **Note**: Requires the Create Public Threads permission.
+    ///
+    /// [`CreateThread`]: crate::builder::CreateThread
+    #[cfg(feature = "utils")]
+    pub async fn create_public_thread<F>(self, http: impl AsRef<Http>, f: F) -> Result<GuildChannel>
+    where F: FnOnce(&mut CreateThread) -> &mut CreateThread
+    {
+        self._create_thread(http, ChannelType::PublicThread, f).await
+    }
 
-        http.as_ref().create_permission(self.0, id, &map).await
+    #[cfg(feature = "utils")]
+    async fn _create_thread<F>(self, http: impl AsRef<Http>, kind: ChannelType, f: F) -> Result<GuildChannel>
+    where F: FnOnce(&mut CreateThread) -> &mut CreateThread
+    {
+        let mut thread = CreateThread::default();
+        thread.kind(kind);
+        f(&mut thread);
+
+        let map = utils::hashmap_to_json_map(thread.0);
+
+        http.as_ref().create_thread(self.0, &map).await
     }
 
     /// React to a [`Message`] with a custom [`Emoji`] or unicode character.
@@ -119,12 +228,92 @@ impl ChannelId {
         http.as_ref().create_reaction(self.0, message_id.into().0, &reaction_type.into()).await
     }
 
+    /// Starts a stage instance in the stage channel, making it live.
+    ///
+    /// Refer to [`CreateStageInstance`] for the settings of the stage
+    /// instance. Its topic is required.
+    ///
+    /// **Note**: Requires the [Manage Channels], [Mute Members] and
+    /// [Move Members] permissions.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the channel is not a stage channel, or
+    /// is already live.
+    ///
+    /// [`CreateStageInstance`]: crate::builder::CreateStageInstance
+    /// [Manage Channels]: Permissions::MANAGE_CHANNELS
+    /// [Mute Members]: Permissions::MUTE_MEMBERS
+    /// [Move Members]: Permissions::MOVE_MEMBERS
+    /// [`Error::Http`]: crate::error::Error::Http
+    #[cfg(feature = "utils")]
+    pub async fn create_stage_instance<F>(self, http: impl AsRef<Http>, f: F) -> Result<StageInstance>
+    where F: FnOnce(&mut CreateStageInstance) -> &mut CreateStageInstance
+    {
+        let mut stage_instance = CreateStageInstance::default();
+        stage_instance.channel_id(self.0);
+        f(&mut stage_instance);
+
+        let map = utils::hashmap_to_json_map(stage_instance.0);
+
+        http.as_ref().create_stage_instance(&map).await
+    }
+
+    /// Creates a public thread attached to the given [`Message`]. The thread
+    /// shares the Id of the message.
+    ///
+    /// Refer to [`CreateThread`] for the settings of the thread.
+    ///
+    /// **Note**: Requires the Create Public Threads permission.
+    ///
+    /// [`CreateThread`]: crate::builder::CreateThread
+    #[cfg(feature = "utils")]
+    pub async fn create_thread_from_message<F>(
+        self,
+        http: impl AsRef<Http>,
+        message_id: impl Into<MessageId>,
+        f: F,
+    ) -> Result<GuildChannel>
+    where F: FnOnce(&mut CreateThread) -> &mut CreateThread
+    {
+        let mut thread = CreateThread::default();
+        f(&mut thread);
+
+        let map = utils::hashmap_to_json_map(thread.0);
+
+        http.as_ref().create_thread_from_message(self.0, message_id.into().0, &map).await
+    }
+
+    /// Crossposts a [`Message`] in an announcement channel to the channels
+    /// following it.
+    ///
+    /// Refer to [`Message::crosspost`] for more information.
+    ///
+    /// Requires the [Send Messages] permission if the current user is the
+    /// author of the message, and the [Manage Messages] permission otherwise.
+    ///
+    /// [Send Messages]: Permissions::SEND_MESSAGES
+    /// [Manage Messages]: Permissions::MANAGE_MESSAGES
+    #[inline]
+    pub async fn crosspost(self, http: impl AsRef<Http>, message_id: impl Into<MessageId>) -> Result<Message> {
+        http.as_ref().crosspost_message(self.0, message_id.into().0).await
+    }
+
     /// Deletes this channel, returning the channel on a successful deletion.
     #[inline]
     pub async fn delete(self, http: impl AsRef<Http>) -> Result<Channel> {
         http.as_ref().delete_channel(self.0).await
     }
 
+    /// Deletes this channel with a reason, shown in the audit log. Refer to
+    /// [`delete`] for further documentation.
+    ///
+    /// [`delete`]: Self::delete
+    #[inline]
+    pub async fn delete_with_reason(self, http: impl AsRef<Http>, reason: impl AsRef<str>) -> Result<Channel> {
+        http.as_ref().delete_channel_with_reason(self.0, reason.as_ref()).await
+    }
+
     /// Deletes a [`Message`] given its Id.
     ///
     /// Refer to [`Message::delete`] for more information.
@@ -176,6 +365,46 @@ impl ChannelId {
         }
     }
 
+    /// Deletes all messages by Ids from the given slice in the given channel,
+    /// without the restrictions of [`delete_messages`].
+    ///
+    /// Messages sent in the last 2 weeks are deleted in bulk, in batches of at
+    /// most 100 messages, while older messages, which can't be bulk deleted,
+    /// are deleted one by one. Duplicate Ids are ignored.
+    ///
+    /// Returns how many messages were deleted each way. Note that deleting
+    /// many old messages makes as many requests, and will be rate limited.
+    ///
+    /// Requires the [Manage Messages] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error that occurs, in which case the messages of the
+    /// previous requests have already been deleted.
+    ///
+    /// [`delete_messages`]: Self::delete_messages
+    /// [Manage Messages]: Permissions::MANAGE_MESSAGES
+    pub async fn delete_messages_auto(self, http: impl AsRef<Http>, message_ids: &[MessageId]) -> Result<DeletedMessages> {
+        let http = http.as_ref();
+        let (batches, singles) = plan_deletion(message_ids, Utc::now());
+        let mut deleted = DeletedMessages::default();
+
+        for batch in batches {
+            let map = json!({ "messages": batch });
+            http.delete_messages(self.0, &map).await?;
+
+            deleted.bulk += batch.len();
+        }
+
+        for message_id in singles {
+            http.delete_message(self.0, message_id.0).await?;
+
+            deleted.single += 1;
+        }
+
+        Ok(deleted)
+    }
+
     /// Deletes all permission overrides in the channel from a member or role.
     ///
     /// **Note**: Requires the [Manage Channel] permission.
@@ -237,6 +466,19 @@ impl ChannelId {
         ).await
     }
 
+    /// Ends the stage instance of the stage channel.
+    ///
+    /// **Note**: Requires the [Manage Channels], [Mute Members] and
+    /// [Move Members] permissions.
+    ///
+    /// [Manage Channels]: Permissions::MANAGE_CHANNELS
+    /// [Mute Members]: Permissions::MUTE_MEMBERS
+    /// [Move Members]: Permissions::MOVE_MEMBERS
+    #[inline]
+    pub async fn delete_stage_instance(self, http: impl AsRef<Http>) -> Result<()> {
+        http.as_ref().delete_stage_instance(self.0).await
+    }
+
     /// Edits the settings of a [`Channel`], optionally setting new values.
     ///
     /// Refer to `EditChannel`'s documentation for its methods.
@@ -273,9 +515,55 @@ impl ChannelId {
         http.as_ref().edit_channel(self.0, &map).await
     }
 
+    /// Edits the topic or privacy level of the stage instance of the stage
+    /// channel.
+    ///
+    /// Refer to [`CreateStageInstance`] for the settings of the stage
+    /// instance.
+    ///
+    /// **Note**: Requires the [Manage Channels], [Mute Members] and
+    /// [Move Members] permissions.
+    ///
+    /// [`CreateStageInstance`]: crate::builder::CreateStageInstance
+    /// [Manage Channels]: Permissions::MANAGE_CHANNELS
+    /// [Mute Members]: Permissions::MUTE_MEMBERS
+    /// [Move Members]: Permissions::MOVE_MEMBERS
+    #[cfg(feature = "utils")]
+    pub async fn edit_stage_instance<F>(self, http: impl AsRef<Http>, f: F) -> Result<StageInstance>
+    where F: FnOnce(&mut CreateStageInstance) -> &mut CreateStageInstance
+    {
+        let mut stage_instance = CreateStageInstance::default();
+        f(&mut stage_instance);
+
+        let map = utils::hashmap_to_json_map(stage_instance.0);
+
+        http.as_ref().edit_stage_instance(self.0, &map).await
+    }
+
+    /// Sets the slow mode rate of the channel, the amount of seconds a user has
+    /// to wait before sending another message, leaving the other settings of
+    /// the channel untouched. Pass `0` to disable slow mode.
+    ///
+    /// Requires the [Manage Channel] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::SlowModeRateAmount`] if the rate is over
+    /// 21600 seconds (6 hours).
+    ///
+    /// [Manage Channel]: Permissions::MANAGE_CHANNELS
+    #[cfg(feature = "utils")]
+    pub async fn edit_slowmode(self, http: impl AsRef<Http>, seconds: u16) -> Result<GuildChannel> {
+        let map = slow_mode_map(seconds)?;
+
+        http.as_ref().edit_channel(self.0, &map).await
+    }
+
     /// Edits a [`Message`] in the channel given its Id.
     ///
-    /// Message editing preserves all unchanged message data.
+    /// Message editing preserves all unchanged message data: only the fields
+    /// set in the builder are sent, so that the message can be edited with
+    /// only its Id.
     ///
     /// Refer to the documentation for [`EditMessage`] for more information
     /// regarding message restrictions and requirements.
@@ -288,13 +576,30 @@ impl ChannelId {
     /// is over the [`the limit`], containing the number of unicode code points
     /// over the limit.
     ///
+    /// Returns a [`ModelError::EmbedAmount`] if more than 10 embeds were
+    /// set, or a [`ModelError::EmbedTooLarge`] if their combined text is over
+    /// the limit.
+    ///
+    /// Returns a [`ModelError::InvalidTimestamp`] if the timestamp of an
+    /// embed is not in the RFC 3339 format.
+    ///
+    /// Returns a [`ModelError::InvalidAllowedMentions`] if a type of mentions
+    /// is both parsed and listed in the allowed mentions.
+    ///
+    /// Returns a [`ModelError::NoAttachmentsKept`] if files were added with
+    /// a description, but neither [`keep_attachment`] nor
+    /// [`remove_all_attachments`] were used.
+    ///
     /// [`EditMessage`]: crate::builder::EditMessage
     /// [`the limit`]: crate::builder::EditMessage::content
+    /// [`keep_attachment`]: crate::builder::EditMessage::keep_attachment
+    /// [`remove_all_attachments`]: crate::builder::EditMessage::remove_all_attachments
     #[cfg(feature = "utils")]
     #[inline]
-    pub async fn edit_message<F>(self, http: impl AsRef<Http>, message_id: impl Into<MessageId>, f: F) -> Result<Message>
-    where F: FnOnce(&mut EditMessage) -> &mut EditMessage
+    pub async fn edit_message<'a, F>(self, http: impl AsRef<Http>, message_id: impl Into<MessageId>, f: F) -> Result<Message>
+    where for <'b> F: FnOnce(&'b mut EditMessage<'a>) -> &'b mut EditMessage<'a>
     {
+        let message_id = message_id.into();
         let mut msg = EditMessage::default();
         f(&mut msg);
 
@@ -306,9 +611,20 @@ impl ChannelId {
             }
         }
 
-        let map = utils::hashmap_to_json_map(msg.0);
+        let (map, files) = msg.into_parts()?;
+
+        Message::check_embed_count(&map)?;
+        Message::check_embed_length(&map)?;
+        Message::check_embed_timestamp(&map)?;
+        Message::check_components(&map)?;
+        Message::check_attachment_descriptions(&map)?;
+        Message::check_allowed_mentions(&map)?;
 
-        http.as_ref().edit_message(self.0, message_id.into().0, &Value::Object(map)).await
+        if files.is_empty() {
+            http.as_ref().edit_message(self.0, message_id.0, &Value::Object(map)).await
+        } else {
+            http.as_ref().edit_message_and_attachments(self.0, message_id.0, files, map).await
+        }
     }
 
     /// Attempts to find a [`Channel`] by its Id in the cache.
@@ -337,6 +653,61 @@ impl ChannelId {
         cache_http.http().get_channel(self.0).await
     }
 
+    /// Gets the archived private threads of the channel, most recently
+    /// archived first.
+    ///
+    /// The optional `before` attribute is to retrieve the threads archived
+    /// before a certain time. Together with [`ThreadsData::has_more`], this is
+    /// useful for pagination.
+    ///
+    /// **Note**: Requires the [Read Message History] and Manage Threads
+    /// permissions.
+    ///
+    /// [Read Message History]: Permissions::READ_MESSAGE_HISTORY
+    pub async fn get_archived_private_threads(
+        self,
+        http: impl AsRef<Http>,
+        before: impl Into<Option<DateTime<Utc>>>,
+        limit: Option<u64>,
+    ) -> Result<ThreadsData> {
+        let before = before.into().map(|t| t.to_rfc3339_opts(SecondsFormat::Secs, true));
+
+        http.as_ref().get_channel_archived_private_threads(self.0, before.as_deref(), limit).await
+    }
+
+    /// Gets the archived public threads of the channel, most recently archived
+    /// first.
+    ///
+    /// The optional `before` attribute is to retrieve the threads archived
+    /// before a certain time. Together with [`ThreadsData::has_more`], this is
+    /// useful for pagination.
+    ///
+    /// **Note**: Requires the [Read Message History] permission.
+    ///
+    /// [Read Message History]: Permissions::READ_MESSAGE_HISTORY
+    pub async fn get_archived_public_threads(
+        self,
+        http: impl AsRef<Http>,
+        before: impl Into<Option<DateTime<Utc>>>,
+        limit: Option<u64>,
+    ) -> Result<ThreadsData> {
+        let before = before.into().map(|t| t.to_rfc3339_opts(SecondsFormat::Secs, true));
+
+        http.as_ref().get_channel_archived_public_threads(self.0, before.as_deref(), limit).await
+    }
+
+    /// Gets the stage instance of the stage channel.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the channel is not live.
+    ///
+    /// [`Error::Http`]: crate::error::Error::Http
+    #[inline]
+    pub async fn get_stage_instance(self, http: impl AsRef<Http>) -> Result<StageInstance> {
+        http.as_ref().get_stage_instance(self.0).await
+    }
+
     /// Gets all of the channel's invites.
     ///
     /// Requires the [Manage Channels] permission.
@@ -347,6 +718,18 @@ impl ChannelId {
         http.as_ref().get_channel_invites(self.0).await
     }
 
+    /// Joins the thread as the current user.
+    #[inline]
+    pub async fn join_thread(self, http: impl AsRef<Http>) -> Result<()> {
+        http.as_ref().join_thread_channel(self.0).await
+    }
+
+    /// Leaves the thread as the current user.
+    #[inline]
+    pub async fn leave_thread(self, http: impl AsRef<Http>) -> Result<()> {
+        http.as_ref().leave_thread_channel(self.0).await
+    }
+
     /// Gets a message from the channel.
     ///
     /// Requires the [Read Message History] permission.
@@ -410,7 +793,10 @@ impl ChannelId {
     /// A buffer of at most 100 messages is used to reduce the number of calls.
     /// necessary.
     ///
-    /// The stream returns the oldest message first, followed by newer messages.
+    /// The stream returns the newest message first, followed by older messages.
+    /// Messages are only fetched as the stream is polled, so it can be combined
+    /// with [`StreamExt::take`] to only walk the most recent part of the
+    /// history.
     ///
     /// # Examples
     ///
@@ -438,7 +824,26 @@ impl ChannelId {
     /// # }
     /// ```
     ///
+    /// Print the 250 most recent messages:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::id::ChannelId;
+    /// # use serenity::http::Http;
+    /// #
+    /// # async fn run() {
+    /// # let channel_id = ChannelId::default();
+    /// # let ctx = Http::default();
+    /// use serenity::futures::StreamExt;
+    ///
+    /// let mut messages = channel_id.messages_iter(&ctx).take(250).boxed();
+    /// while let Some(Ok(message)) = messages.next().await {
+    ///     println!("{}", message.content);
+    /// }
+    /// # }
+    /// ```
+    ///
     /// [`messages`]: Self::messages
+    /// [`StreamExt::take`]: futures::StreamExt::take
     pub fn messages_iter<H: AsRef<Http>>(self, http: H) -> impl Stream<Item=Result<Message>> {
         MessagesIter::<H>::stream(http, self)
     }
@@ -456,9 +861,14 @@ impl ChannelId {
     }
 
     /// Pins a [`Message`] to the channel.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::TooManyPins`] if the channel already has the
+    /// maximum of 50 pinned messages.
     #[inline]
     pub async fn pin(self, http: impl AsRef<Http>, message_id: impl Into<MessageId>) -> Result<()> {
-        http.as_ref().pin_message(self.0, message_id.into().0).await
+        pin_result(http.as_ref().pin_message(self.0, message_id.into().0).await)
     }
 
     /// Gets the list of [`Message`]s which are pinned to the channel.
@@ -498,6 +908,15 @@ impl ChannelId {
         ).await
     }
 
+    /// Removes a [`User`] from the thread.
+    ///
+    /// **Note**: Requires the Manage Threads permission, or to be the creator
+    /// of the thread if it is private.
+    #[inline]
+    pub async fn remove_thread_member(self, http: impl AsRef<Http>, user_id: impl Into<UserId>) -> Result<()> {
+        http.as_ref().remove_thread_channel_member(self.0, user_id.into().0).await
+    }
+
     /// Sends a message with just the given message content in the channel.
     ///
     /// # Errors
@@ -593,7 +1012,13 @@ impl ChannelId {
         let map = utils::hashmap_to_json_map(msg.0.clone());
 
         Message::check_content_length(&map)?;
+        Message::check_embed_count(&map)?;
         Message::check_embed_length(&map)?;
+        Message::check_embed_timestamp(&map)?;
+        Message::check_components(&map)?;
+        Message::check_sticker_ids(&map)?;
+        Message::check_attachment_descriptions(&map)?;
+        Message::check_allowed_mentions(&map)?;
 
         http.as_ref().send_files(self.0, files, map).await
     }
@@ -613,6 +1038,18 @@ impl ChannelId {
     /// is over the above limit, containing the number of unicode code points
     /// over the limit.
     ///
+    /// Returns a [`ModelError::EmbedAmount`] if more than 10 embeds were
+    /// added.
+    ///
+    /// Returns a [`ModelError::InvalidAllowedMentions`] if a type of mentions
+    /// is both parsed and listed in the allowed mentions.
+    ///
+    /// Returns a [`ModelError::InvalidTimestamp`] if the timestamp of an
+    /// embed is not in the RFC 3339 format.
+    ///
+    /// Returns a [`ModelError::AttachmentDescriptionTooLong`] if the
+    /// description of a file is over 1024 unicode code points.
+    ///
     /// [`CreateMessage`]: crate::builder::CreateMessage
     /// [Send Messages]: Permissions::SEND_MESSAGES
     #[cfg(feature = "utils")]
@@ -624,7 +1061,13 @@ impl ChannelId {
         let map = utils::hashmap_to_json_map(msg.0.clone());
 
         Message::check_content_length(&map)?;
+        Message::check_embed_count(&map)?;
         Message::check_embed_length(&map)?;
+        Message::check_embed_timestamp(&map)?;
+        Message::check_components(&map)?;
+        Message::check_sticker_ids(&map)?;
+        Message::check_attachment_descriptions(&map)?;
+        Message::check_allowed_mentions(&map)?;
 
         let message = if msg.2.is_empty() {
             http.as_ref().send_message(self.0, &Value::Object(map)).await?
@@ -758,6 +1201,123 @@ impl<'a> From<&'a GuildChannel> for ChannelId {
     fn from(public_channel: &GuildChannel) -> ChannelId { public_channel.id }
 }
 
+#[cfg(all(feature = "model", feature = "utils"))]
+fn slow_mode_map(seconds: u16) -> Result<JsonMap> {
+    if seconds > constants::SLOW_MODE_RATE_LIMIT {
+        return Err(Error::Model(ModelError::SlowModeRateAmount(seconds)));
+    }
+
+    let mut channel = EditChannel::default();
+    channel.slow_mode_rate(u64::from(seconds));
+
+    Ok(utils::hashmap_to_json_map(channel.0))
+}
+
+/// Builds a forum post, checking that its initial message is not empty.
+#[cfg(all(feature = "model", feature = "utils"))]
+fn forum_post_map<F>(f: F) -> Result<JsonMap>
+where F: FnOnce(&mut CreateForumPost) -> &mut CreateForumPost
+{
+    let mut post = CreateForumPost::default();
+    f(&mut post);
+
+    let is_set = |value: &Value| match value {
+        Value::String(content) => !content.is_empty(),
+        Value::Array(items) => !items.is_empty(),
+        _ => false,
+    };
+    let message = match post.0.get("message") {
+        Some(Value::Object(message)) => message,
+        _ => return Err(Error::Model(ModelError::NoForumPostMessage)),
+    };
+
+    if !["content", "embeds", "components"].iter().filter_map(|key| message.get(*key)).any(is_set) {
+        return Err(Error::Model(ModelError::NoForumPostMessage));
+    }
+
+    Message::check_content_length(message)?;
+    Message::check_embed_count(message)?;
+    Message::check_embed_length(message)?;
+    Message::check_embed_timestamp(message)?;
+    Message::check_components(message)?;
+
+    Ok(utils::hashmap_to_json_map(post.0))
+}
+
+#[cfg(feature = "model")]
+const MESSAGES_PAGE_SIZE: u64 = 100;
+
+/// The JSON error code of a channel having reached the maximum number of
+/// pinned messages.
+#[cfg(feature = "model")]
+const MAX_PINS_REACHED: isize = 30003;
+
+/// Maps Discord's error for a channel with too many pins to a
+/// [`ModelError::TooManyPins`].
+#[cfg(feature = "model")]
+fn pin_result(result: Result<()>) -> Result<()> {
+    match result {
+        Err(Error::Http(why)) => match *why {
+            HttpError::UnsuccessfulRequest(ref response) if response.error.code == MAX_PINS_REACHED => {
+                Err(Error::Model(ModelError::TooManyPins))
+            },
+            why => Err(Error::Http(Box::new(why))),
+        },
+        result => result,
+    }
+}
+
+/// The number of messages deleted by [`ChannelId::delete_messages_auto`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct DeletedMessages {
+    /// The number of messages deleted in bulk.
+    pub bulk: usize,
+    /// The number of messages deleted one by one.
+    pub single: usize,
+}
+
+/// Splits the messages into batches that can be bulk deleted, and messages
+/// that have to be deleted one by one.
+///
+/// Discord refuses to bulk delete messages older than 2 weeks; a minute of
+/// margin is kept so that the messages don't become too old while the
+/// request is in flight. A lone recent message is deleted on its own, as the
+/// bulk endpoint requires at least 2 messages.
+#[cfg(feature = "model")]
+fn plan_deletion(message_ids: &[MessageId], now: DateTime<Utc>) -> (Vec<Vec<MessageId>>, Vec<MessageId>) {
+    let cutoff = now - chrono::Duration::weeks(2) + chrono::Duration::minutes(1);
+
+    let mut seen = HashSet::with_capacity(message_ids.len());
+    let mut recent = Vec::new();
+    let mut singles = Vec::new();
+
+    for &message_id in message_ids {
+        if !seen.insert(message_id) {
+            continue;
+        }
+
+        if message_id.created_at() > cutoff {
+            recent.push(message_id);
+        } else {
+            singles.push(message_id);
+        }
+    }
+
+    let mut batches = recent
+        .chunks(MESSAGES_PAGE_SIZE as usize)
+        .map(<[MessageId]>::to_vec)
+        .collect::<Vec<_>>();
+
+    if batches.last().map(Vec::len) == Some(1) {
+        if let Some(batch) = batches.pop() {
+            singles.extend(batch);
+        }
+    }
+
+    (batches, singles)
+}
+
 /// A helper class returned by [`ChannelId::messages_iter`]
 #[derive(Clone, Debug)]
 #[cfg(feature = "model")]
@@ -798,26 +1358,41 @@ impl<H: AsRef<Http>> MessagesIter<H> {
     ///
     /// [`Message`]: crate::model::channel::Message
     async fn refresh(&mut self) -> Result<()> {
-        // Number of messages to fetch.
-        let grab_size = 100;
-
         // If `self.before` is not set yet, we can use `.messages` to fetch
         // the last message after very first fetch from last.
-        self.buffer = self.channel_id.messages(&self.http, |b| {
-            if let Some(before) = self.before {
+        let before = self.before;
+        let page = self.channel_id.messages(&self.http, |b| {
+            if let Some(before) = before {
                 b.before(before);
             }
 
-            b.limit(grab_size)
+            b.limit(MESSAGES_PAGE_SIZE)
         }).await?;
 
-        self.buffer.reverse();
+        self.fill(page);
 
-        self.before = self.buffer.first().map(|m| m.id);
+        Ok(())
+    }
 
-        self.tried_fetch = true;
+    /// Replaces the `self.buffer` cache of Messages with a freshly fetched
+    /// page.
+    ///
+    /// The lowest message Id of the page is used as the cursor for the next
+    /// page, which stays valid even if that message gets deleted in the
+    /// meantime. If the page is shorter than the page size, the start of the
+    /// channel has been reached, so `self.before` is marked as None.
+    fn fill(&mut self, mut page: Vec<Message>) {
+        self.before = if page.len() as u64 == MESSAGES_PAGE_SIZE {
+            page.iter().map(|message| message.id).min()
+        } else {
+            None
+        };
 
-        Ok(())
+        // Sort from oldest to newest to optimize pop()
+        page.sort_by_key(|message| message.id);
+        self.buffer = page;
+
+        self.tried_fetch = true;
     }
 
     /// Streams over all the messages in a channel.
@@ -870,3 +1445,319 @@ impl<H: AsRef<Http>> MessagesIter<H> {
         })
     }
 }
+
+#[cfg(test)]
+mod test {
+    #[cfg(all(feature = "model", feature = "utils"))]
+    mod model_utils {
+        use crate::http::Http;
+        use crate::internal::test_utils;
+        use crate::model::prelude::*;
+        use crate::model::ModelError;
+        use crate::utils::CustomMessage;
+        use crate::Error;
+        use serde_json::{json, Value};
+        use super::super::{forum_post_map, pin_result, plan_deletion, slow_mode_map, MessagesIter};
+
+        #[tokio::test]
+        async fn delete_with_reason() {
+            use http_crate::response::Builder;
+            use std::sync::Arc;
+
+            let mut http = Http::new_with_token("token");
+            http.ratelimiter.responder = Some(Arc::new(|request| {
+                assert_eq!(request.method(), reqwest::Method::DELETE);
+                assert_eq!(request.url().path(), "/api/v8/channels/2");
+                assert_eq!(request.headers().get("X-Audit-Log-Reason").unwrap(), "Archived");
+
+                let channel = json!({
+                    "id": "2",
+                    "guild_id": "1",
+                    "type": 0,
+                    "name": "general",
+                    "position": 0,
+                    "permission_overwrites": [],
+                    "nsfw": false,
+                });
+
+                reqwest::Response::from(Builder::new().status(200).body(serde_json::to_vec(&channel).unwrap()).unwrap())
+            }));
+
+            let channel = ChannelId(2).delete_with_reason(&http, "Archived").await.unwrap();
+            assert_eq!(channel.id(), ChannelId(2));
+        }
+
+        #[tokio::test]
+        async fn edit_message_components_only() {
+            use crate::builder::CreateActionRow;
+            use http_crate::response::Builder;
+            use std::sync::Arc;
+
+            let mut http = Http::new_with_token("token");
+            http.ratelimiter.responder = Some(Arc::new(|request| {
+                assert_eq!(request.method(), reqwest::Method::PATCH);
+                assert!(request.url().as_str().ends_with("/channels/1/messages/2"));
+
+                let body = request.body().and_then(reqwest::Body::as_bytes).unwrap();
+                let body = serde_json::from_slice::<Value>(body).unwrap();
+                assert_eq!(body, json!({
+                    "components": [{
+                        "type": 1,
+                        "components": [{"type": 2, "style": 1, "label": "Next", "custom_id": "next"}],
+                    }],
+                }));
+
+                let mut message = test_utils::message_json(1, None);
+                message["content"] = json!("unchanged");
+                let response = Builder::new()
+                    .status(200)
+                    .body(serde_json::to_vec(&message).unwrap())
+                    .unwrap();
+
+                reqwest::Response::from(response)
+            }));
+
+            let mut row = CreateActionRow::default();
+            row.create_button(|b| b.style(ButtonStyle::Primary).label("Next").custom_id("next"));
+
+            let message = ChannelId(1)
+                .edit_message(&http, MessageId(2), |m| m.components(|c| c.add_action_row(row)))
+                .await
+                .unwrap();
+            assert_eq!(message.content, "unchanged");
+        }
+
+        #[tokio::test]
+        async fn edit_message_checks_embeds() {
+            let http = Http::default();
+
+            let result = ChannelId(1)
+                .edit_message(&http, MessageId(2), |m| m.embed(|e| e.timestamp("last tuesday")))
+                .await;
+            match result {
+                Err(Error::Model(ModelError::InvalidTimestamp)) => {},
+                other => panic!("unexpected result: {:?}", other),
+            }
+
+            let description = "a".repeat(6001);
+            let result = ChannelId(1)
+                .edit_message(&http, MessageId(2), |m| m.embed(|e| e.description(description)))
+                .await;
+            match result {
+                Err(Error::Model(ModelError::EmbedTooLarge(1))) => {},
+                other => panic!("unexpected result: {:?}", other),
+            }
+        }
+
+        #[test]
+        fn pin_result_max_pins() {
+            use crate::http::{HttpError, StatusCode};
+            use crate::internal::test_utils::error_response;
+
+            assert!(pin_result(Ok(())).is_ok());
+
+            match pin_result(Err(error_response(StatusCode::BAD_REQUEST, 30003, "Maximum number of pins reached (50)"))) {
+                Err(Error::Model(ModelError::TooManyPins)) => {},
+                other => panic!("unexpected result: {:?}", other),
+            }
+
+            match pin_result(Err(error_response(StatusCode::FORBIDDEN, 50013, "Missing Permissions"))) {
+                Err(Error::Http(why)) => assert!(matches!(*why, HttpError::UnsuccessfulRequest(_))),
+                other => panic!("unexpected result: {:?}", other),
+            }
+        }
+
+        #[test]
+        fn slow_mode_map_limit() {
+            assert!(slow_mode_map(21600).is_ok());
+
+            match slow_mode_map(21601) {
+                Err(Error::Model(ModelError::SlowModeRateAmount(21601))) => {},
+                other => panic!("unexpected result: {:?}", other),
+            }
+        }
+
+        #[test]
+        fn slow_mode_map_single_field() {
+            let map = slow_mode_map(30).unwrap();
+
+            assert_eq!(Value::Object(map), json!({ "rate_limit_per_user": 30 }));
+        }
+
+        #[test]
+        fn forum_post_map_requires_message() {
+            match forum_post_map(|p| p.name("Empty").applied_tags(vec![ForumTagId(1)])) {
+                Err(Error::Model(ModelError::NoForumPostMessage)) => {},
+                other => panic!("unexpected result: {:?}", other),
+            }
+
+            assert!(forum_post_map(|p| p.name("Empty").content("")).is_err());
+
+            let content = "a".repeat(2001);
+            match forum_post_map(|p| p.name("Long").content(content)) {
+                Err(Error::Model(ModelError::MessageTooLong(1))) => {},
+                other => panic!("unexpected result: {:?}", other),
+            }
+
+            match forum_post_map(|p| p.name("Logs").embed(|e| e.timestamp("last tuesday"))) {
+                Err(Error::Model(ModelError::InvalidTimestamp)) => {},
+                other => panic!("unexpected result: {:?}", other),
+            }
+
+            let post = forum_post_map(|p| {
+                p.0.insert("message", json!("Not an object"));
+                p.name("Broken").content("Ignored")
+            });
+            match post {
+                Err(Error::Model(ModelError::NoForumPostMessage)) => {},
+                other => panic!("unexpected result: {:?}", other),
+            }
+
+            let map = forum_post_map(|p| {
+                p.name("Logs")
+                    .applied_tags(vec![ForumTagId(1), ForumTagId(2)])
+                    .embed(|e| e.description("Crashed at startup"))
+            }).unwrap();
+
+            assert_eq!(map["applied_tags"], json!(["1", "2"]));
+            assert_eq!(map["message"], json!({
+                "embeds": [{"type": "rich", "description": "Crashed at startup"}],
+            }));
+        }
+
+        fn gen_page(ids: std::ops::RangeInclusive<u64>) -> Vec<Message> {
+            // Discord returns the newest messages first.
+            ids.rev()
+                .map(|id| {
+                    let mut message = CustomMessage::new();
+                    message.id(MessageId(id));
+
+                    message.build()
+                })
+                .collect()
+        }
+
+        fn drain(iter: &mut MessagesIter<Http>) -> Vec<MessageId> {
+            let mut ids = Vec::new();
+
+            while let Some(message) = iter.buffer.pop() {
+                ids.push(message.id);
+            }
+
+            ids
+        }
+
+        #[test]
+        fn messages_iter_pages() {
+            let mut iter = MessagesIter::new(Http::default(), ChannelId(1));
+
+            iter.fill(gen_page(101..=200));
+            assert_eq!(iter.before, Some(MessageId(101)));
+            assert_eq!(drain(&mut iter), (101..=200).rev().map(MessageId).collect::<Vec<_>>());
+
+            // A message from the previous page was deleted meanwhile, so the
+            // second page is short and ends the history.
+            iter.fill(gen_page(2..=100));
+            assert_eq!(iter.before, None);
+            assert_eq!(drain(&mut iter), (2..=100).rev().map(MessageId).collect::<Vec<_>>());
+        }
+
+        #[test]
+        fn messages_iter_empty_channel() {
+            let mut iter = MessagesIter::new(Http::default(), ChannelId(1));
+
+            iter.fill(Vec::new());
+            assert!(iter.tried_fetch);
+            assert_eq!(iter.before, None);
+            assert!(iter.buffer.is_empty());
+        }
+
+        #[tokio::test]
+        async fn messages_iter_streams_pages() {
+            use futures::StreamExt;
+            use http_crate::response::Builder;
+            use std::sync::Arc;
+
+            fn page_json(ids: std::ops::RangeInclusive<u64>) -> Vec<u8> {
+                let page = ids
+                    .rev()
+                    .map(|id| {
+                        let mut message = test_utils::message_json(1, None);
+                        message["id"] = Value::String(id.to_string());
+
+                        message
+                    })
+                    .collect::<Vec<_>>();
+
+                serde_json::to_vec(&page).unwrap()
+            }
+
+            let mut http = Http::new_with_token("token");
+            http.ratelimiter.responder = Some(Arc::new(|request| {
+                assert_eq!(request.url().path(), "/api/v8/channels/1/messages");
+
+                let query = request.url().query_pairs().collect::<std::collections::HashMap<_, _>>();
+                assert_eq!(query.get("limit").map(|limit| &**limit), Some("100"));
+
+                let body = match query.get("before").map(|before| &**before) {
+                    None => page_json(101..=200),
+                    Some("101") => page_json(51..=100),
+                    other => panic!("unexpected page before {:?}", other),
+                };
+
+                reqwest::Response::from(Builder::new().status(200).body(body).unwrap())
+            }));
+
+            let ids = MessagesIter::<Http>::stream(&http, ChannelId(1))
+                .map(|message| message.unwrap().id)
+                .collect::<Vec<_>>()
+                .await;
+
+            assert_eq!(ids, (51..=200).rev().map(MessageId).collect::<Vec<_>>());
+        }
+
+        /// Generates a snowflake for a message created `days` before `now`.
+        fn message_id(now: chrono::DateTime<chrono::Utc>, days: i64, increment: u64) -> MessageId {
+            let time = now - chrono::Duration::days(days);
+            let millis = time.timestamp_millis() as u64 - 1_420_070_400_000;
+
+            MessageId(millis << 22 | increment)
+        }
+
+        #[test]
+        fn plan_deletion_partitions_by_age() {
+            let now = chrono::Utc::now();
+            let new_ids = (0..150).map(|i| message_id(now, 1, i)).collect::<Vec<_>>();
+            let old_ids = (0..3).map(|i| message_id(now, 15, i)).collect::<Vec<_>>();
+
+            let mut ids = vec![old_ids[0], new_ids[0]];
+            ids.extend(&new_ids);
+            ids.extend(&old_ids);
+
+            let (batches, singles) = plan_deletion(&ids, now);
+
+            assert_eq!(batches.len(), 2);
+            assert_eq!(batches[0], new_ids[..100].to_vec());
+            assert_eq!(batches[1], new_ids[100..].to_vec());
+            assert_eq!(singles, old_ids);
+        }
+
+        #[test]
+        fn plan_deletion_lone_recent_message() {
+            let now = chrono::Utc::now();
+            let new_ids = (0..101).map(|i| message_id(now, 0, i)).collect::<Vec<_>>();
+            let old_id = message_id(now, 30, 0);
+
+            let (batches, singles) = plan_deletion(&[new_ids[0]], now);
+            assert!(batches.is_empty());
+            assert_eq!(singles, vec![new_ids[0]]);
+
+            let mut ids = new_ids.clone();
+            ids.push(old_id);
+
+            let (batches, singles) = plan_deletion(&ids, now);
+            assert_eq!(batches, vec![new_ids[..100].to_vec()]);
+            assert_eq!(singles, vec![old_id, new_ids[100]]);
+        }
+    }
+}
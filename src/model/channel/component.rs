@@ -0,0 +1,323 @@
+use crate::model::prelude::*;
+use serde::de::Error as DeError;
+use serde::ser::{Serialize, Serializer};
+
+/// The type of a component.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum ComponentType {
+    /// A container of other components.
+    ActionRow = 1,
+    /// A clickable button.
+    Button = 2,
+    /// A menu to select one or multiple options from.
+    SelectMenu = 3,
+    /// A text field, only available in modals.
+    InputText = 4,
+}
+
+enum_number!(
+    ComponentType {
+        ActionRow,
+        Button,
+        SelectMenu,
+        InputText,
+    }
+);
+
+impl ComponentType {
+    #[inline]
+    pub fn num(self) -> u64 {
+        match self {
+            ComponentType::ActionRow => 1,
+            ComponentType::Button => 2,
+            ComponentType::SelectMenu => 3,
+            ComponentType::InputText => 4,
+        }
+    }
+}
+
+/// A row of components attached to a [`Message`].
+///
+/// A message can have up to 5 action rows, each containing up to 5 buttons or
+/// a single select menu.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ActionRow {
+    /// The type of the component, always [`ComponentType::ActionRow`].
+    #[serde(rename = "type")]
+    pub kind: ComponentType,
+    /// The components of the row.
+    #[serde(default)]
+    pub components: Vec<ActionRowComponent>,
+}
+
+/// A component which can be held by an [`ActionRow`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum ActionRowComponent {
+    Button(Button),
+    SelectMenu(SelectMenu),
+    InputText(InputText),
+    /// A component of a type not yet supported by the library, kept as its
+    /// raw JSON payload.
+    Unknown(Value),
+}
+
+impl<'de> Deserialize<'de> for ActionRowComponent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        let v = JsonMap::deserialize(deserializer)?;
+        let kind = v.get("type")
+            .and_then(Value::as_u64)
+            .ok_or_else(|| DeError::missing_field("type"))?;
+
+        match kind {
+            2 => serde_json::from_value::<Button>(Value::Object(v))
+                .map(ActionRowComponent::Button)
+                .map_err(DeError::custom),
+            3 => serde_json::from_value::<SelectMenu>(Value::Object(v))
+                .map(ActionRowComponent::SelectMenu)
+                .map_err(DeError::custom),
+            4 => serde_json::from_value::<InputText>(Value::Object(v))
+                .map(ActionRowComponent::InputText)
+                .map_err(DeError::custom),
+            _ => Ok(ActionRowComponent::Unknown(Value::Object(v))),
+        }
+    }
+}
+
+impl Serialize for ActionRowComponent {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+        where S: Serializer {
+        match self {
+            ActionRowComponent::Button(c) => Button::serialize(c, serializer),
+            ActionRowComponent::SelectMenu(c) => SelectMenu::serialize(c, serializer),
+            ActionRowComponent::InputText(c) => InputText::serialize(c, serializer),
+            ActionRowComponent::Unknown(v) => v.serialize(serializer),
+        }
+    }
+}
+
+/// The style of a [`Button`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum ButtonStyle {
+    /// A blurple button.
+    Primary = 1,
+    /// A grey button.
+    Secondary = 2,
+    /// A green button.
+    Success = 3,
+    /// A red button.
+    Danger = 4,
+    /// A grey button which links to an URL, and does not send an interaction
+    /// when clicked.
+    Link = 5,
+}
+
+enum_number!(
+    ButtonStyle {
+        Primary,
+        Secondary,
+        Success,
+        Danger,
+        Link,
+    }
+);
+
+impl ButtonStyle {
+    #[inline]
+    pub fn num(self) -> u64 {
+        match self {
+            ButtonStyle::Primary => 1,
+            ButtonStyle::Secondary => 2,
+            ButtonStyle::Success => 3,
+            ButtonStyle::Danger => 4,
+            ButtonStyle::Link => 5,
+        }
+    }
+}
+
+/// A clickable button.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct Button {
+    /// The type of the component, always [`ComponentType::Button`].
+    #[serde(rename = "type")]
+    pub kind: ComponentType,
+    /// The style of the button.
+    pub style: ButtonStyle,
+    /// The text displayed on the button.
+    pub label: Option<String>,
+    /// The emoji displayed on the button.
+    pub emoji: Option<ReactionType>,
+    /// The developer-defined Id sent along with the interaction when the
+    /// button is clicked.
+    ///
+    /// **Note**: This is never present on [link][`ButtonStyle::Link`]
+    /// buttons.
+    pub custom_id: Option<String>,
+    /// The URL the button leads to.
+    ///
+    /// **Note**: This is only present on [link][`ButtonStyle::Link`] buttons.
+    pub url: Option<String>,
+    /// Whether the button is disabled.
+    #[serde(default)]
+    pub disabled: bool,
+}
+
+/// A menu to select one or multiple options from.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct SelectMenu {
+    /// The type of the component, always [`ComponentType::SelectMenu`].
+    #[serde(rename = "type")]
+    pub kind: ComponentType,
+    /// The developer-defined Id sent along with the interaction when options
+    /// are selected.
+    pub custom_id: Option<String>,
+    /// The text displayed when no option is selected.
+    pub placeholder: Option<String>,
+    /// The minimum number of options which must be selected.
+    pub min_values: Option<u64>,
+    /// The maximum number of options which can be selected.
+    pub max_values: Option<u64>,
+    /// The options of the menu.
+    #[serde(default)]
+    pub options: Vec<SelectMenuOption>,
+    /// Whether the menu is disabled.
+    #[serde(default)]
+    pub disabled: bool,
+}
+
+/// An option of a [`SelectMenu`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct SelectMenuOption {
+    /// The text displayed on the option.
+    pub label: String,
+    /// The developer-defined value sent along with the interaction when the
+    /// option is selected.
+    pub value: String,
+    /// The additional description of the option.
+    pub description: Option<String>,
+    /// The emoji displayed on the option.
+    pub emoji: Option<ReactionType>,
+    /// Whether the option is selected by default.
+    #[serde(default)]
+    pub default: bool,
+}
+
+/// The style of a text field of a modal.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum InputTextStyle {
+    /// A single-line text field.
+    Short = 1,
+    /// A multi-line text field.
+    Paragraph = 2,
+}
+
+enum_number!(
+    InputTextStyle {
+        Short,
+        Paragraph,
+    }
+);
+
+impl InputTextStyle {
+    #[inline]
+    pub fn num(self) -> u64 {
+        match self {
+            InputTextStyle::Short => 1,
+            InputTextStyle::Paragraph => 2,
+        }
+    }
+}
+
+/// A text field, as submitted in a modal.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct InputText {
+    /// The type of the component, always [`ComponentType::InputText`].
+    #[serde(rename = "type")]
+    pub kind: ComponentType,
+    /// The developer-defined Id of the text field.
+    pub custom_id: String,
+    /// The text entered in the field.
+    #[serde(default)]
+    pub value: String,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::model::prelude::*;
+    use serde_json::json;
+
+    #[test]
+    fn action_row_deserialization() {
+        let value = json!({
+            "type": 1,
+            "components": [
+                {
+                    "type": 2,
+                    "style": 1,
+                    "label": "Click me",
+                    "custom_id": "click"
+                },
+                {
+                    "type": 2,
+                    "style": 5,
+                    "label": "Docs",
+                    "emoji": { "name": "📖" },
+                    "url": "https://docs.rs/serenity"
+                },
+            ]
+        });
+
+        let row = serde_json::from_value::<ActionRow>(value).unwrap();
+        assert_eq!(row.kind, ComponentType::ActionRow);
+        assert_eq!(row.components.len(), 2);
+
+        match &row.components[1] {
+            ActionRowComponent::Button(button) => {
+                assert_eq!(button.style, ButtonStyle::Link);
+                assert_eq!(button.url.as_deref(), Some("https://docs.rs/serenity"));
+                assert!(button.custom_id.is_none());
+                assert!(!button.disabled);
+            },
+            other => panic!("unexpected component: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn unknown_component_deserialization() {
+        let value = json!({
+            "type": 1,
+            "components": [
+                {
+                    "type": 99,
+                    "custom_id": "future"
+                },
+                {
+                    "type": 2,
+                    "style": 1,
+                    "label": "Click me",
+                    "custom_id": "click"
+                },
+            ]
+        });
+
+        let row = serde_json::from_value::<ActionRow>(value).unwrap();
+        assert_eq!(row.components.len(), 2);
+
+        match &row.components[0] {
+            ActionRowComponent::Unknown(raw) => {
+                assert_eq!(raw["type"], 99);
+                assert_eq!(serde_json::to_value(&row.components[0]).unwrap(), *raw);
+            },
+            other => panic!("unexpected component: {:?}", other),
+        }
+        assert!(matches!(row.components[1], ActionRowComponent::Button(_)));
+    }
+}
@@ -0,0 +1,82 @@
+use crate::model::prelude::*;
+
+/// A tag which can be applied to the threads of a forum channel.
+///
+/// The tags of a channel are listed in its [`available_tags`].
+///
+/// [`available_tags`]: GuildChannel::available_tags
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ForumTag {
+    /// The Id of the tag.
+    pub id: ForumTagId,
+    /// The name of the tag.
+    pub name: String,
+    /// Whether the tag can only be applied by members with the
+    /// [Manage Threads] permission.
+    ///
+    /// [Manage Threads]: Permissions::MANAGE_THREADS
+    #[serde(default)]
+    pub moderated: bool,
+    /// The Id of the custom emoji of the tag.
+    pub emoji_id: Option<EmojiId>,
+    /// The unicode character of the emoji of the tag.
+    pub emoji_name: Option<String>,
+}
+
+/// The emoji shown on the add reaction button of the threads of a forum
+/// channel.
+///
+/// Either the [`emoji_id`] or the [`emoji_name`] is set.
+///
+/// [`emoji_id`]: Self::emoji_id
+/// [`emoji_name`]: Self::emoji_name
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ForumDefaultReaction {
+    /// The Id of the custom emoji.
+    pub emoji_id: Option<EmojiId>,
+    /// The unicode character of the emoji.
+    pub emoji_name: Option<String>,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::model::prelude::*;
+    use serde_json::json;
+
+    #[test]
+    fn deserialize_forum_channel() {
+        let value = json!({
+            "id": "1019642470629314560",
+            "guild_id": "197038439483310086",
+            "type": 15,
+            "name": "help",
+            "position": 4,
+            "permission_overwrites": [],
+            "nsfw": false,
+            "available_tags": [
+                {"id": "1019642470629314561", "name": "Solved", "moderated": true, "emoji_id": null, "emoji_name": "✅"},
+                {"id": "1019642470629314562", "name": "Bug", "moderated": false, "emoji_id": "41771983429993937", "emoji_name": null}
+            ],
+            "default_reaction_emoji": {"emoji_id": null, "emoji_name": "👍"}
+        });
+
+        let channel = match serde_json::from_value::<Channel>(value).unwrap() {
+            Channel::Guild(channel) => channel,
+            other => panic!("unexpected channel: {:?}", other),
+        };
+
+        assert_eq!(channel.kind, ChannelType::Forum);
+        assert_eq!(channel.available_tags.len(), 2);
+        assert_eq!(channel.available_tags[0].id, ForumTagId(1019642470629314561));
+        assert!(channel.available_tags[0].moderated);
+        assert_eq!(channel.available_tags[0].emoji_name.as_deref(), Some("✅"));
+        assert_eq!(channel.available_tags[1].emoji_id, Some(EmojiId(41771983429993937)));
+        assert!(channel.applied_tags.is_empty());
+
+        let reaction = channel.default_reaction_emoji.unwrap();
+        assert_eq!(reaction.emoji_id, None);
+        assert_eq!(reaction.emoji_name.as_deref(), Some("👍"));
+    }
+}
@@ -14,6 +14,7 @@ use futures::stream::StreamExt;
 use crate::cache::Cache;
 #[cfg(feature = "model")]
 use crate::builder::{
+    CreateChannel,
     CreateInvite,
     CreateMessage,
     EditMessage,
@@ -61,6 +62,8 @@ pub struct GuildChannel {
     /// **Note**: This is only available for voice channels.
     pub bitrate: Option<u64>,
     /// Whether this guild channel belongs in a category.
+    ///
+    /// For threads, this is the Id of the channel the thread was created in.
     #[serde(rename = "parent_id")]
     pub category_id: Option<ChannelId>,
     /// The Id of the guild the channel is located in.
@@ -84,11 +87,17 @@ pub struct GuildChannel {
     /// The name of the channel.
     pub name: String,
     /// Permission overwrites for [`Member`]s and for [`Role`]s.
+    ///
+    /// **Note**: This is always empty for threads.
+    #[serde(default)]
     pub permission_overwrites: Vec<PermissionOverwrite>,
     /// The position of the channel.
     ///
     /// The default text channel will _almost always_ have a position of `-1` or
     /// `0`.
+    ///
+    /// **Note**: This is always `0` for threads.
+    #[serde(default)]
     pub position: i64,
     /// The topic of the channel.
     ///
@@ -112,6 +121,32 @@ pub struct GuildChannel {
     /// channels.
     #[serde(default, rename = "rate_limit_per_user")]
     pub slow_mode_rate: Option<u64>,
+    /// The thread-specific data of the channel.
+    ///
+    /// **Note**: This is only available on threads.
+    #[serde(default)]
+    pub thread_metadata: Option<ThreadMetadata>,
+    /// The current user's thread member, if they have joined the thread.
+    ///
+    /// **Note**: This is only available on threads, on certain endpoints.
+    #[serde(default)]
+    pub member: Option<ThreadMember>,
+    /// The tags which can be applied to the threads of the channel.
+    ///
+    /// **Note**: This is only available for forum channels.
+    #[serde(default)]
+    pub available_tags: Vec<ForumTag>,
+    /// The tags applied to the thread.
+    ///
+    /// **Note**: This is only available for threads of forum channels.
+    #[serde(default)]
+    pub applied_tags: Vec<ForumTagId>,
+    /// The emoji shown on the add reaction button of the threads of the
+    /// channel.
+    ///
+    /// **Note**: This is only available for forum channels.
+    #[serde(default)]
+    pub default_reaction_emoji: Option<ForumDefaultReaction>,
 }
 
 #[cfg(feature = "model")]
@@ -133,6 +168,56 @@ impl GuildChannel {
         self.id.broadcast_typing(&http).await
     }
 
+    /// Creates a copy of the channel in its guild, with the same type, topic,
+    /// settings and permission overwrites.
+    ///
+    /// The copy is named `new_name`, or `"<name>-clone"` if none is given.
+    ///
+    /// **Note**: Requires the [Manage Channels] permission.
+    ///
+    /// [Manage Channels]: Permissions::MANAGE_CHANNELS
+    pub async fn clone_channel(&self, http: impl AsRef<Http>, new_name: Option<&str>) -> Result<GuildChannel> {
+        let map = crate::utils::hashmap_to_json_map(self.clone_builder(new_name).0);
+
+        http.as_ref().create_channel(self.guild_id.0, &map).await
+    }
+
+    fn clone_builder(&self, new_name: Option<&str>) -> CreateChannel {
+        let mut builder = CreateChannel::default();
+        builder
+            .kind(self.kind)
+            .nsfw(self.nsfw)
+            .position(self.position as u32)
+            .permissions(self.permission_overwrites.iter().cloned());
+
+        match new_name {
+            Some(name) => builder.name(name),
+            None => builder.name(format!("{}-clone", self.name)),
+        };
+
+        if let Some(category_id) = self.category_id {
+            builder.category(category_id);
+        }
+
+        if let Some(topic) = &self.topic {
+            builder.topic(topic);
+        }
+
+        if let Some(seconds) = self.slow_mode_rate {
+            builder.rate_limit(seconds);
+        }
+
+        if let Some(bitrate) = self.bitrate {
+            builder.bitrate(bitrate as u32);
+        }
+
+        if let Some(limit) = self.user_limit {
+            builder.user_limit(limit as u32);
+        }
+
+        builder
+    }
+
     /// Creates an invite leading to the given channel.
     ///
     /// # Examples
@@ -172,81 +257,61 @@ impl GuildChannel {
     ///
     /// # Examples
     ///
-    /// Creating a permission overwrite for a member by specifying the
-    /// [`PermissionOverwriteType::Member`] variant, allowing it the [Send Messages]
-    /// permission, but denying the [Send TTS Messages] and [Attach Files]
-    /// permissions:
+    /// Allowing a member the [Send Messages] permission, but denying the
+    /// [Send TTS Messages] and [Attach Files] permissions:
     ///
     /// ```rust,no_run
     /// # #[cfg(feature = "cache")]
     /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
     /// # use serenity::{cache::Cache, http::Http, model::id::{ChannelId, UserId}};
-    /// # use tokio::sync::RwLock;
     /// # use std::sync::Arc;
     /// #
     /// #     let http = Arc::new(Http::default());
     /// #     let cache = Cache::default();
     /// #     let (channel_id, user_id) = (ChannelId(0), UserId(0));
     /// #
-    /// use serenity::model::channel::{
-    ///     PermissionOverwrite,
-    ///     PermissionOverwriteType,
-    /// };
+    /// use serenity::model::channel::PermissionOverwriteType;
     /// use serenity::model::{ModelError, Permissions};
+    ///
     /// let allow = Permissions::SEND_MESSAGES;
     /// let deny = Permissions::SEND_TTS_MESSAGES | Permissions::ATTACH_FILES;
-    /// let overwrite = PermissionOverwrite {
-    ///     allow: allow,
-    ///     deny: deny,
-    ///     kind: PermissionOverwriteType::Member(user_id),
-    /// };
+    ///
     /// // assuming the cache has been unlocked
     /// let channel = cache
     ///     .guild_channel(channel_id)
     ///     .await
     ///     .ok_or(ModelError::ItemMissing)?;
     ///
-    /// channel.create_permission(&http, &overwrite).await?;
+    /// channel.create_permission(&http, PermissionOverwriteType::Member(user_id), allow, deny).await?;
     /// #   Ok(())
     /// # }
     /// ```
     ///
-    /// Creating a permission overwrite for a role by specifying the
-    /// [`PermissionOverwriteType::Role`] variant, allowing it the [Manage Webhooks]
-    /// permission, but denying the [Send TTS Messages] and [Attach Files]
-    /// permissions:
+    /// Allowing a role the [Manage Webhooks] permission, but denying the
+    /// [Send TTS Messages] and [Attach Files] permissions:
     ///
     /// ```rust,no_run
     /// # #[cfg(feature = "cache")]
     /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
-    /// # use serenity::{cache::Cache, http::Http, model::id::{ChannelId, UserId}};
-    /// # use tokio::sync::RwLock;
+    /// # use serenity::{cache::Cache, http::Http, model::id::{ChannelId, RoleId}};
     /// # use std::sync::Arc;
     /// #
     /// #   let http = Arc::new(Http::default());
     /// #   let cache = Cache::default();
-    /// #   let (channel_id, user_id) = (ChannelId(0), UserId(0));
+    /// #   let (channel_id, role_id) = (ChannelId(0), RoleId(0));
     /// #
-    /// use serenity::model::channel::{
-    ///     PermissionOverwrite,
-    ///     PermissionOverwriteType,
-    /// };
-    /// use serenity::model::{ModelError, Permissions, channel::Channel};
+    /// use serenity::model::channel::PermissionOverwriteType;
+    /// use serenity::model::{ModelError, Permissions};
     ///
-    /// let allow = Permissions::SEND_MESSAGES;
+    /// let allow = Permissions::MANAGE_WEBHOOKS;
     /// let deny = Permissions::SEND_TTS_MESSAGES | Permissions::ATTACH_FILES;
-    /// let overwrite = PermissionOverwrite {
-    ///     allow: allow,
-    ///     deny: deny,
-    ///     kind: PermissionOverwriteType::Member(user_id),
-    /// };
     ///
     /// let channel = cache
     ///     .guild_channel(channel_id)
     ///     .await
     ///     .ok_or(ModelError::ItemMissing)?;
     ///
-    /// channel.create_permission(&http, &overwrite).await?;
+    /// channel.create_permission(&http, PermissionOverwriteType::Role(role_id), allow, deny).await?;
     /// #     Ok(())
     /// # }
     /// ```
@@ -257,8 +322,14 @@ impl GuildChannel {
     /// [Send Messages]: Permissions::SEND_MESSAGES
     /// [Send TTS Messages]: Permissions::SEND_TTS_MESSAGES
     #[inline]
-    pub async fn create_permission(&self, http: impl AsRef<Http>, target: &PermissionOverwrite) -> Result<()> {
-        self.id.create_permission(&http, target).await
+    pub async fn create_permission(
+        &self,
+        http: impl AsRef<Http>,
+        target: PermissionOverwriteType,
+        allow: Permissions,
+        deny: Permissions,
+    ) -> Result<()> {
+        self.id.create_permission(&http, target, allow, deny).await
     }
 
     /// Deletes this channel, returning the channel on a successful deletion.
@@ -369,6 +440,41 @@ impl GuildChannel {
         Ok(())
     }
 
+    /// Sets the slow mode rate of the channel, the amount of seconds a user has
+    /// to wait before sending another message, editing the channel in-place if
+    /// the request was successful. Pass `0` to disable slow mode.
+    ///
+    /// Refer to [`ChannelId::edit_slowmode`] for more information.
+    ///
+    /// **Note**: Requires the [Manage Channel] permission.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`]
+    /// if the current user does not have the required permission.
+    ///
+    /// Returns a [`ModelError::SlowModeRateAmount`] if the rate is over
+    /// 21600 seconds (6 hours).
+    ///
+    /// [Manage Channel]: Permissions::MANAGE_CHANNELS
+    #[cfg(feature = "utils")]
+    pub async fn edit_slowmode(&mut self, cache_http: impl CacheHttp, seconds: u16) -> Result<()> {
+        #[cfg(feature = "cache")]
+        {
+            if let Some(cache) = cache_http.cache() {
+                let req = Permissions::MANAGE_CHANNELS;
+
+                if !utils::user_has_perms(&cache, self.id, Some(self.guild_id), req).await? {
+                    return Err(Error::Model(ModelError::InvalidPermissions(req)));
+                }
+            }
+        }
+
+        *self = self.id.edit_slowmode(cache_http.http(), seconds).await?;
+
+        Ok(())
+    }
+
     /// Edits a [`Message`] in the channel given its Id.
     ///
     /// Message editing preserves all unchanged message data.
@@ -387,13 +493,13 @@ impl GuildChannel {
     /// [`EditMessage`]: crate::builder::EditMessage
     /// [`the limit`]: crate::builder::EditMessage::content
     #[inline]
-    pub async fn edit_message<F>(
+    pub async fn edit_message<'a, F>(
         &self,
         http: impl AsRef<Http>,
         message_id: impl Into<MessageId>,
         f: F
     ) -> Result<Message>
-    where F: FnOnce(&mut EditMessage) -> &mut EditMessage
+    where for <'b> F: FnOnce(&'b mut EditMessage<'a>) -> &'b mut EditMessage<'a>
     {
         self.id.edit_message(&http, message_id, f).await
     }
@@ -749,7 +855,8 @@ impl GuildChannel {
 
     /// Retrieves [`Member`]s from the current channel.
     ///
-    /// [`ChannelType::Voice`] returns [`Member`]s using the channel.
+    /// [`ChannelType::Voice`] and [`ChannelType::Stage`] return [`Member`]s
+    /// using the channel.
     /// [`ChannelType::Text`] and [`ChannelType::News`] return [`Member`]s
     /// that can read the channel.
     ///
@@ -765,7 +872,7 @@ impl GuildChannel {
             .ok_or(ModelError::GuildNotFound)?;
 
         match self.kind {
-            ChannelType::Voice => {
+            ChannelType::Voice | ChannelType::Stage => {
                 Ok(guild
                 .voice_states
                 .values()
@@ -884,7 +991,7 @@ impl GuildChannel {
             },
             AttachmentType::Image(url) => {
                 let url = Url::parse(url).map_err(|_| Error::Url(url.to_string()))?;
-                let response = http.as_ref().client.get(url).send().await?;
+                let response = http.as_ref().download(url).send().await?;
                 let mut bytes = response.bytes().await?;
                 let mut picture: Vec<u8> = vec![0; bytes.len()];
                 bytes.copy_to_slice(&mut picture[..]);
@@ -908,3 +1015,47 @@ impl Display for GuildChannel {
         Display::fmt(&self.id.mention(), f)
     }
 }
+
+#[cfg(all(test, feature = "model"))]
+mod test {
+    use crate::model::prelude::*;
+    use crate::utils;
+    use serde_json::{json, Value};
+
+    #[test]
+    fn clone_channel_body() {
+        let channel: GuildChannel = serde_json::from_value(json!({
+            "id": "2",
+            "guild_id": "1",
+            "type": 0,
+            "name": "general",
+            "position": 3,
+            "parent_id": "4",
+            "topic": "Talk about crabs",
+            "nsfw": true,
+            "rate_limit_per_user": 30,
+            "permission_overwrites": [
+                {"id": "1", "type": 0, "allow": "0", "deny": "2048"},
+                {"id": "5", "type": 1, "allow": "3072", "deny": "0"},
+            ],
+        })).unwrap();
+
+        let map = utils::hashmap_to_json_map(channel.clone_builder(None).0);
+        assert_eq!(Value::Object(map), json!({
+            "name": "general-clone",
+            "type": 0,
+            "parent_id": 4,
+            "topic": "Talk about crabs",
+            "nsfw": true,
+            "rate_limit_per_user": 30,
+            "position": 3,
+            "permission_overwrites": [
+                {"id": "1", "type": 0, "allow": "0", "deny": "2048"},
+                {"id": "5", "type": 1, "allow": "3072", "deny": "0"},
+            ],
+        }));
+
+        let map = utils::hashmap_to_json_map(channel.clone_builder(Some("crabs")).0);
+        assert_eq!(map["name"], "crabs");
+    }
+}
@@ -38,6 +38,7 @@ use crate::http::{Http, CacheHttp};
 #[cfg(feature = "collector")]
 use crate::collector::{
     ReactionCollectorBuilder, CollectReaction,
+    ComponentInteractionCollectorBuilder, CollectComponentInteraction,
 };
 #[cfg(feature = "collector")]
 use crate::client::bridge::gateway::ShardMessenger;
@@ -107,10 +108,21 @@ pub struct Message {
     /// Bit flags describing extra features of the message.
     pub flags: Option<MessageFlags>,
     /// Array of stickers sent with the message.
+    ///
+    /// **Note**: This is deprecated by Discord in favour of
+    /// [`sticker_items`].
+    ///
+    /// [`sticker_items`]: Self::sticker_items
     #[serde(default)]
     pub stickers: Vec<Sticker>,
+    /// The stickers sent with the message.
+    #[serde(default)]
+    pub sticker_items: Vec<StickerItem>,
     /// The message that was replied to using this message.
     pub referenced_message: Option<Box<Message>>, // Boxed to avoid recusion
+    /// The components attached to the message.
+    #[serde(default)]
+    pub components: Vec<ActionRow>,
 }
 
 #[cfg(feature = "model")]
@@ -131,6 +143,36 @@ impl Message {
         self.author.id == cache.as_ref().current_user().await.id
     }
 
+    /// Crossposts the message to the channels following the announcement
+    /// channel it was sent in.
+    ///
+    /// **Note**: Requires the [Send Messages] permission if the current user
+    /// is the author of the message, and the [Manage Messages] permission
+    /// otherwise.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` feature is enabled, then returns a
+    /// [`ModelError::InvalidChannelType`] if the channel is in the cache and is
+    /// not a [`ChannelType::News`] channel.
+    ///
+    /// [Send Messages]: Permissions::SEND_MESSAGES
+    /// [Manage Messages]: Permissions::MANAGE_MESSAGES
+    pub async fn crosspost(&self, cache_http: impl CacheHttp) -> Result<Message> {
+        #[cfg(feature = "cache")]
+        {
+            if let Some(cache) = cache_http.cache() {
+                if let Some(channel) = cache.guild_channel(self.channel_id).await {
+                    if channel.kind != ChannelType::News {
+                        return Err(Error::Model(ModelError::InvalidChannelType));
+                    }
+                }
+            }
+        }
+
+        self.channel_id.crosspost(&cache_http.http(), self.id).await
+    }
+
     /// Deletes the message.
     ///
     /// **Note**: The logged in user must either be the author of the message or
@@ -248,11 +290,27 @@ impl Message {
     /// is over [`the limit`], containing the number of unicode code points
     /// over the limit.
     ///
+    /// Returns a [`ModelError::EmbedAmount`] if more than 10 embeds were
+    /// set, or a [`ModelError::EmbedTooLarge`] if their combined text is over
+    /// the limit.
+    ///
+    /// Returns a [`ModelError::InvalidTimestamp`] if the timestamp of an
+    /// embed is not in the RFC 3339 format.
+    ///
+    /// Returns a [`ModelError::InvalidAllowedMentions`] if a type of mentions
+    /// is both parsed and listed in the allowed mentions.
+    ///
+    /// Returns a [`ModelError::NoAttachmentsKept`] if files were added with
+    /// a description, but neither [`keep_attachment`] nor
+    /// [`remove_all_attachments`] were used.
+    ///
     /// [`EditMessage`]: crate::builder::EditMessage
     /// [`the limit`]: crate::builder::EditMessage::content
+    /// [`keep_attachment`]: crate::builder::EditMessage::keep_attachment
+    /// [`remove_all_attachments`]: crate::builder::EditMessage::remove_all_attachments
     #[cfg(feature = "utils")]
-    pub async fn edit<F>(&mut self, cache_http: impl CacheHttp, f: F) -> Result<()>
-    where F: FnOnce(&mut EditMessage) -> &mut EditMessage
+    pub async fn edit<'a, F>(&mut self, cache_http: impl CacheHttp, f: F) -> Result<()>
+    where for <'b> F: FnOnce(&'b mut EditMessage<'a>) -> &'b mut EditMessage<'a>
     {
         #[cfg(feature = "cache")]
         {
@@ -269,19 +327,28 @@ impl Message {
             builder.content(&self.content);
         }
 
-        if let Some(embed) = self.embeds.get(0) {
-            let embed = CreateEmbed::from(embed.clone());
-            builder.embed( |e| {
-                *e = embed;
-                e
-            });
+        if !self.embeds.is_empty() {
+            builder.set_embeds(self.embeds.iter().cloned().map(CreateEmbed::from).collect());
         }
 
         f(&mut builder);
 
-        let map = crate::utils::hashmap_to_json_map(builder.0);
+        let (map, files) = builder.into_parts()?;
+        Message::check_content_length(&map)?;
+        Message::check_embed_count(&map)?;
+        Message::check_embed_length(&map)?;
+        Message::check_embed_timestamp(&map)?;
+        Message::check_components(&map)?;
+        Message::check_attachment_descriptions(&map)?;
+        Message::check_allowed_mentions(&map)?;
 
-        *self = cache_http.http().edit_message(self.channel_id.0, self.id.0, &Value::Object(map)).await?;
+        let http = cache_http.http();
+
+        *self = if files.is_empty() {
+            http.edit_message(self.channel_id.0, self.id.0, &Value::Object(map)).await?
+        } else {
+            http.edit_message_and_attachments(self.channel_id.0, self.id.0, files, map).await?
+        };
 
         Ok(())
     }
@@ -374,15 +441,16 @@ impl Message {
 
     /// Returns the associated `Guild` for the message if one is in the cache.
     ///
-    /// Returns `None` if the guild's Id could not be found via [`guild_id`] or
-    /// if the Guild itself is not cached.
+    /// Returns `None` if the guild's Id could not be found via [`guild_id`],
+    /// such as for messages sent in a private channel, or if the Guild itself
+    /// is not cached.
     ///
     /// Requires the `cache` feature be enabled.
     ///
     /// [`guild_id`]: Self::guild_id
     #[cfg(feature = "cache")]
     pub async fn guild(&self, cache: impl AsRef<Cache>) -> Option<Guild> {
-       cache.as_ref().guild(self.guild_id?).await
+        cache.as_ref().guild(self.guild_id?).await
     }
 
     /// Returns a field to the `Guild` for the message if one is in the cache.
@@ -467,6 +535,9 @@ impl Message {
     /// [`ModelError::InvalidPermissions`] if the current user does not have
     /// the required permissions.
     ///
+    /// Returns a [`ModelError::TooManyPins`] if the channel already has the
+    /// maximum of 50 pinned messages.
+    ///
     /// [Manage Messages]: Permissions::MANAGE_MESSAGES
     pub async fn pin(&self, cache_http: impl CacheHttp) -> Result<()> {
         #[cfg(feature = "cache")]
@@ -631,7 +702,10 @@ impl Message {
         }).await
     }
 
-    /// Delete all embeds in this message
+    /// Suppresses all embeds in this message, including those generated by
+    /// Discord themselves, by setting the [`MessageFlags::SUPPRESS_EMBEDS`]
+    /// flag.
+    ///
     /// **Note**: The logged in user must either be the author of the message or
     /// have the [Manage Messages] permission.
     ///
@@ -642,8 +716,23 @@ impl Message {
     /// the required permissions.
     ///
     /// [Manage Messages]: Permissions::MANAGE_MESSAGES
-    #[cfg(feature = "utils")]
+    #[inline]
     pub async fn suppress_embeds(&mut self, cache_http: impl CacheHttp) -> Result<()> {
+        self._set_embeds_suppressed(cache_http, true).await
+    }
+
+    /// Shows the embeds of this message again, by clearing the
+    /// [`MessageFlags::SUPPRESS_EMBEDS`] flag.
+    ///
+    /// Refer to [`suppress_embeds`] for more information.
+    ///
+    /// [`suppress_embeds`]: Self::suppress_embeds
+    #[inline]
+    pub async fn unsuppress_embeds(&mut self, cache_http: impl CacheHttp) -> Result<()> {
+        self._set_embeds_suppressed(cache_http, false).await
+    }
+
+    async fn _set_embeds_suppressed(&mut self, cache_http: impl CacheHttp, suppress: bool) -> Result<()> {
         #[cfg(feature = "cache")]
         {
             if let Some(cache) = cache_http.cache() {
@@ -657,10 +746,10 @@ impl Message {
             }
         }
 
-        let mut suppress = EditMessage::default();
-        suppress.suppress_embeds(true);
+        let flags = embeds_suppressed_flags(self.flags, suppress);
 
-        let map = crate::utils::hashmap_to_json_map(suppress.0);
+        let mut map = JsonMap::new();
+        map.insert("flags".to_string(), Value::Number(Number::from(flags.bits())));
 
         *self = cache_http.http().edit_message(self.channel_id.0, self.id.0, &Value::Object(map)).await?;
 
@@ -735,10 +824,7 @@ impl Message {
     /// The link will be valid for messages in either private channels or guilds.
     #[inline]
     pub fn link(&self) -> String {
-        match self.guild_id {
-            Some(guild_id) => format!("https://discord.com/channels/{}/{}/{}", guild_id.0, self.channel_id.0, self.id.0),
-            None => format!("https://discord.com/channels/@me/{}/{}", self.channel_id.0, self.id.0),
-        }
+        self.id.link(self.channel_id, self.guild_id)
     }
 
     /// Await a single reaction on this message.
@@ -753,6 +839,18 @@ impl Message {
         ReactionCollectorBuilder::new(shard_messenger).message_id(self.id.0)
     }
 
+    /// Await a single component interaction on this message.
+    #[cfg(feature = "collector")]
+    pub fn await_component_interaction<'a>(&self, shard_messenger: &'a impl AsRef<ShardMessenger>) -> CollectComponentInteraction<'a> {
+        CollectComponentInteraction::new(shard_messenger).message_id(self.id.0)
+    }
+
+    /// Returns a stream builder which can be awaited to obtain a stream of component interactions on this message.
+    #[cfg(feature = "collector")]
+    pub fn await_component_interactions<'a>(&self, shard_messenger: &'a impl AsRef<ShardMessenger>) -> ComponentInteractionCollectorBuilder<'a> {
+        ComponentInteractionCollectorBuilder::new(shard_messenger).message_id(self.id.0)
+    }
+
     /// Retrieves the message channel's category ID if the channel has one.
     #[cfg(feature = "cache")]
     pub async fn category_id(&self, cache: impl AsRef<Cache>) -> Option<ChannelId> {
@@ -771,46 +869,141 @@ impl Message {
         Ok(())
     }
 
-    pub(crate) fn check_embed_length(map: &JsonMap) -> Result<()> {
-        let embed = match map.get("embed") {
-            Some(&Value::Object(ref value)) => value,
+    pub(crate) fn check_attachment_descriptions(map: &JsonMap) -> Result<()> {
+        let descriptions = map
+            .get("attachments")
+            .and_then(Value::as_array)
+            .map_or(&[][..], Vec::as_slice)
+            .iter()
+            .filter_map(|attachment| attachment.get("description").and_then(Value::as_str));
+
+        for description in descriptions {
+            let length = description.chars().count();
+
+            if length > constants::ATTACHMENT_DESCRIPTION_MAX_LENGTH {
+                let overflow = length - constants::ATTACHMENT_DESCRIPTION_MAX_LENGTH;
+                return Err(Error::Model(ModelError::AttachmentDescriptionTooLong(overflow)));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn check_components(map: &JsonMap) -> Result<()> {
+        let rows = match map.get("components") {
+            Some(Value::Array(rows)) => rows,
             _ => return Ok(()),
         };
 
-        let mut total: usize = 0;
+        let buttons = rows
+            .iter()
+            .filter_map(|row| row.get("components").and_then(Value::as_array))
+            .flatten()
+            .filter(|component| component.get("type").and_then(Value::as_u64) == Some(ComponentType::Button.num()));
+
+        for button in buttons {
+            let is_link = button.get("style").and_then(Value::as_u64) == Some(ButtonStyle::Link.num());
+            let has_url = button.get("url").is_some();
+            let has_custom_id = button.get("custom_id").is_some();
 
-        if let Some(&Value::Object(ref author)) = embed.get("author") {
-            if let Some(&Value::Object(ref name)) = author.get("name") {
-                total += name.len();
+            if is_link != has_url || is_link == has_custom_id {
+                return Err(Error::Model(ModelError::InvalidButton));
             }
         }
 
-        if let Some(&Value::String(ref description)) = embed.get("description") {
-            total += description.len();
+        Ok(())
+    }
+
+    pub(crate) fn check_sticker_ids(map: &JsonMap) -> Result<()> {
+        if let Some(Value::Array(sticker_ids)) = map.get("sticker_ids") {
+            if sticker_ids.len() > constants::STICKER_MAX_COUNT {
+                return Err(Error::Model(ModelError::StickerAmount(sticker_ids.len())));
+            }
         }
 
-        if let Some(&Value::Array(ref fields)) = embed.get("fields") {
-            for field_as_value in fields {
-                if let Value::Object(ref field) = *field_as_value {
-                    if let Some(&Value::String(ref field_name)) = field.get("name") {
-                        total += field_name.len();
-                    }
+        Ok(())
+    }
 
-                    if let Some(&Value::String(ref field_value)) = field.get("value") {
-                        total += field_value.len();
-                    }
-                }
+    pub(crate) fn check_allowed_mentions(map: &JsonMap) -> Result<()> {
+        let allowed_mentions = match map.get("allowed_mentions") {
+            Some(Value::Object(allowed_mentions)) => allowed_mentions,
+            _ => return Ok(()),
+        };
+
+        let parse = allowed_mentions.get("parse").and_then(Value::as_array).map_or(&[][..], Vec::as_slice);
+
+        for kind in &["users", "roles"] {
+            let listed = matches!(allowed_mentions.get(*kind), Some(Value::Array(ids)) if !ids.is_empty());
+
+            if listed && parse.iter().any(|value| value == kind) {
+                return Err(Error::Model(ModelError::InvalidAllowedMentions));
             }
         }
 
-        if let Some(&Value::Object(ref footer)) = embed.get("footer") {
-            if let Some(&Value::String(ref text)) = footer.get("text") {
-                total += text.len();
+        Ok(())
+    }
+
+    pub(crate) fn check_embed_count(map: &JsonMap) -> Result<()> {
+        if let Some(Value::Array(embeds)) = map.get("embeds") {
+            if embeds.len() > constants::EMBED_MAX_COUNT {
+                return Err(Error::Model(ModelError::EmbedAmount(embeds.len())));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn check_embed_timestamp(map: &JsonMap) -> Result<()> {
+        for embed in Message::embed_maps(map) {
+            if let Some(Value::String(timestamp)) = embed.get("timestamp") {
+                if DateTime::parse_from_rfc3339(timestamp).is_err() {
+                    return Err(Error::Model(ModelError::InvalidTimestamp));
+                }
             }
         }
 
-        if let Some(&Value::String(ref title)) = embed.get("title") {
-            total += title.len();
+        Ok(())
+    }
+
+    /// Checks the textual length of the embeds of a message, which is limited
+    /// for all of them combined.
+    pub(crate) fn check_embed_length(map: &JsonMap) -> Result<()> {
+        let mut total: usize = 0;
+
+        for embed in Message::embed_maps(map) {
+            if let Some(Value::Object(author)) = embed.get("author") {
+                if let Some(Value::String(name)) = author.get("name") {
+                    total += name.len();
+                }
+            }
+
+            if let Some(Value::String(description)) = embed.get("description") {
+                total += description.len();
+            }
+
+            if let Some(Value::Array(fields)) = embed.get("fields") {
+                for field_as_value in fields {
+                    if let Value::Object(field) = field_as_value {
+                        if let Some(Value::String(field_name)) = field.get("name") {
+                            total += field_name.len();
+                        }
+
+                        if let Some(Value::String(field_value)) = field.get("value") {
+                            total += field_value.len();
+                        }
+                    }
+                }
+            }
+
+            if let Some(Value::Object(footer)) = embed.get("footer") {
+                if let Some(Value::String(text)) = footer.get("text") {
+                    total += text.len();
+                }
+            }
+
+            if let Some(Value::String(title)) = embed.get("title") {
+                total += title.len();
+            }
         }
 
         if total <= constants::EMBED_MAX_LENGTH {
@@ -820,6 +1013,14 @@ impl Message {
             Err(Error::Model(ModelError::EmbedTooLarge(overflow)))
         }
     }
+
+    fn embed_maps(map: &JsonMap) -> impl Iterator<Item = &JsonMap> {
+        map.get("embeds")
+            .and_then(Value::as_array)
+            .map_or(&[][..], Vec::as_slice)
+            .iter()
+            .filter_map(Value::as_object)
+    }
 }
 
 impl AsRef<MessageId> for Message {
@@ -838,6 +1039,20 @@ impl<'a> From<&'a Message> for MessageId {
     fn from(message: &Message) -> MessageId { message.id }
 }
 
+#[cfg(feature = "model")]
+impl MessageId {
+    /// Returns a link to the message in the given channel. When clicked, users
+    /// will jump to the message.
+    ///
+    /// The guild should be `None` for messages in private channels.
+    pub fn link(self, channel_id: ChannelId, guild_id: Option<GuildId>) -> String {
+        match guild_id {
+            Some(guild_id) => format!("https://discord.com/channels/{}/{}/{}", guild_id.0, channel_id.0, self.0),
+            None => format!("https://discord.com/channels/@me/{}/{}", channel_id.0, self.0),
+        }
+    }
+}
+
 /// A representation of a reaction to a message.
 ///
 /// Multiple of the same [reaction type] are sent into one `MessageReaction`,
@@ -989,7 +1204,27 @@ pub struct MessageActivity {
     pub party_id: Option<String>,
 }
 
-/// Reference data sent with crossposted messages.
+/// Reference data sent with crossposted messages and replies.
+///
+/// # Examples
+///
+/// Reply to a message, sending the reply as a normal message if the
+/// referenced message was deleted in the meantime:
+///
+/// ```rust,no_run
+/// # use serenity::http::Http;
+/// # use serenity::model::prelude::*;
+/// #
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// # let http = Http::default();
+/// let reference = MessageReference::new(ChannelId(7))
+///     .message_id(MessageId(8))
+///     .fail_if_not_exists(false);
+///
+/// ChannelId(7).send_message(&http, |m| m.content("Hi!").reference_message(reference)).await?;
+/// #     Ok(())
+/// # }
+/// ```
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct MessageReference {
@@ -999,6 +1234,49 @@ pub struct MessageReference {
     pub channel_id: ChannelId,
     /// ID of the originating message's guild.
     pub guild_id: Option<GuildId>,
+    /// Whether sending a reply should fail if the referenced message does not
+    /// exist. If unset, Discord defaults to `true`.
+    ///
+    /// **Note**: This is only used when sending a message.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fail_if_not_exists: Option<bool>,
+}
+
+impl MessageReference {
+    /// Creates a reference to the given channel.
+    pub fn new(channel_id: impl Into<ChannelId>) -> Self {
+        Self {
+            message_id: None,
+            channel_id: channel_id.into(),
+            guild_id: None,
+            fail_if_not_exists: None,
+        }
+    }
+
+    /// Sets the Id of the referenced message.
+    pub fn message_id(mut self, message_id: impl Into<MessageId>) -> Self {
+        self.message_id = Some(message_id.into());
+        self
+    }
+
+    /// Sets the Id of the referenced channel.
+    pub fn channel_id(mut self, channel_id: impl Into<ChannelId>) -> Self {
+        self.channel_id = channel_id.into();
+        self
+    }
+
+    /// Sets the Id of the referenced guild.
+    pub fn guild_id(mut self, guild_id: impl Into<GuildId>) -> Self {
+        self.guild_id = Some(guild_id.into());
+        self
+    }
+
+    /// Sets whether sending a reply should fail if the referenced message does
+    /// not exist. If `false`, the reply is sent as a normal message instead.
+    pub fn fail_if_not_exists(mut self, fail_if_not_exists: bool) -> Self {
+        self.fail_if_not_exists = Some(fail_if_not_exists);
+        self
+    }
 }
 
 impl From<&Message> for MessageReference {
@@ -1007,6 +1285,7 @@ impl From<&Message> for MessageReference {
             message_id: Some(m.id),
             channel_id: m.channel_id,
             guild_id: m.guild_id,
+            fail_if_not_exists: None,
         }
     }
 }
@@ -1017,6 +1296,7 @@ impl From<(ChannelId, MessageId)> for MessageReference {
             message_id: Some(pair.1),
             channel_id: pair.0,
             guild_id: None,
+            fail_if_not_exists: None,
         }
     }
 }
@@ -1051,9 +1331,22 @@ __impl_bitflags! {
         IS_CROSSPOST = 0b0000_0000_0000_0000_0000_0000_0000_0010;
         /// Do not include any embeds when serializing this message.
         SUPPRESS_EMBEDS = 0b0000_0000_0000_0000_0000_0000_0000_0100;
+        /// This message is only visible to the user who used the interaction
+        /// it responds to.
+        EPHEMERAL = 0b0000_0000_0000_0000_0000_0000_0100_0000;
     }
 }
 
+/// Returns the given flags of a message with the
+/// [`MessageFlags::SUPPRESS_EMBEDS`] flag set or cleared.
+#[cfg(feature = "model")]
+fn embeds_suppressed_flags(flags: Option<MessageFlags>, suppress: bool) -> MessageFlags {
+    let mut flags = flags.unwrap_or_else(MessageFlags::empty);
+    flags.set(MessageFlags::SUPPRESS_EMBEDS, suppress);
+
+    flags
+}
+
 #[cfg(feature = "model")]
 impl<'de> Deserialize<'de> for MessageFlags {
     fn deserialize<D>(deserializer: D) -> StdResult<Self, D::Error>
@@ -1073,3 +1366,263 @@ impl Serialize for MessageFlags {
         serializer.serialize_u64(self.bits())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::internal::test_utils;
+    use crate::model::prelude::*;
+    use serde_json::json;
+
+    #[test]
+    fn message_reference_fail_if_not_exists() {
+        let reference = MessageReference::from((ChannelId(1), MessageId(2)));
+        assert_eq!(reference.fail_if_not_exists, None);
+        assert_eq!(serde_json::to_value(&reference).unwrap(), json!({
+            "message_id": 2,
+            "channel_id": 1,
+            "guild_id": null,
+        }));
+
+        let reference = MessageReference::new(ChannelId(1))
+            .message_id(MessageId(2))
+            .guild_id(GuildId(3))
+            .fail_if_not_exists(false);
+        assert_eq!(serde_json::to_value(&reference).unwrap(), json!({
+            "message_id": 2,
+            "channel_id": 1,
+            "guild_id": 3,
+            "fail_if_not_exists": false,
+        }));
+    }
+
+    #[cfg(feature = "model")]
+    #[test]
+    fn embeds_suppressed_flags() {
+        use super::embeds_suppressed_flags;
+
+        assert_eq!(embeds_suppressed_flags(None, true).bits(), 4);
+        assert_eq!(embeds_suppressed_flags(None, false).bits(), 0);
+
+        let crossposted = Some(MessageFlags::CROSSPOSTED);
+        assert_eq!(embeds_suppressed_flags(crossposted, true).bits(), 5);
+
+        let suppressed = Some(MessageFlags::CROSSPOSTED | MessageFlags::SUPPRESS_EMBEDS);
+        assert_eq!(embeds_suppressed_flags(suppressed, true).bits(), 5);
+        assert_eq!(embeds_suppressed_flags(suppressed, false).bits(), 1);
+    }
+
+    #[cfg(all(feature = "model", feature = "utils"))]
+    #[test]
+    fn check_sticker_ids() {
+        use crate::builder::CreateMessage;
+        use crate::utils;
+
+        let mut builder = CreateMessage::default();
+        builder.sticker_ids(vec![StickerId(1), StickerId(2)]).sticker_id(StickerId(3));
+        let map = utils::hashmap_to_json_map(builder.0.clone());
+
+        assert_eq!(map.get("sticker_ids"), Some(&json!([1, 2, 3])));
+        assert!(Message::check_sticker_ids(&map).is_ok());
+
+        builder.sticker_id(StickerId(4));
+        let map = utils::hashmap_to_json_map(builder.0);
+
+        match Message::check_sticker_ids(&map) {
+            Err(crate::Error::Model(ModelError::StickerAmount(4))) => {},
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[cfg(all(feature = "model", feature = "utils"))]
+    #[test]
+    fn check_allowed_mentions() {
+        use crate::builder::{CreateMessage, ParseValue};
+        use crate::utils;
+
+        let mut builder = CreateMessage::default();
+        builder.allowed_mentions(|am| am.parse(ParseValue::Users).roles(vec![1]).empty_users());
+        let map = utils::hashmap_to_json_map(builder.0.clone());
+
+        assert!(Message::check_allowed_mentions(&map).is_ok());
+
+        builder.allowed_mentions(|am| am.parse(ParseValue::Roles).roles(vec![1]));
+        let map = utils::hashmap_to_json_map(builder.0);
+
+        match Message::check_allowed_mentions(&map) {
+            Err(crate::Error::Model(ModelError::InvalidAllowedMentions)) => {},
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[cfg(all(feature = "model", feature = "utils"))]
+    #[test]
+    fn check_attachment_descriptions() {
+        use crate::builder::CreateMessage;
+        use crate::http::AttachmentType;
+        use crate::utils;
+
+        let file = || AttachmentType::Bytes { data: vec![].into(), filename: "cat.png".to_string() };
+
+        let mut builder = CreateMessage::default();
+        builder.add_file_with_description(file(), "é".repeat(1024));
+        let map = utils::hashmap_to_json_map(builder.0.clone());
+
+        assert!(Message::check_attachment_descriptions(&map).is_ok());
+
+        builder.add_file_with_description(file(), "é".repeat(1030));
+        let map = utils::hashmap_to_json_map(builder.0);
+
+        match Message::check_attachment_descriptions(&map) {
+            Err(crate::Error::Model(ModelError::AttachmentDescriptionTooLong(6))) => {},
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[cfg(all(feature = "model", feature = "utils"))]
+    #[test]
+    fn check_embed_count() {
+        use crate::builder::CreateMessage;
+        use crate::utils;
+
+        let mut builder = CreateMessage::default();
+        for i in 0..10 {
+            builder.embed(|e| e.title(i));
+        }
+        let map = utils::hashmap_to_json_map(builder.0.clone());
+
+        assert!(Message::check_embed_count(&map).is_ok());
+
+        builder.embed(|e| e.title("too many"));
+        let map = utils::hashmap_to_json_map(builder.0);
+
+        match Message::check_embed_count(&map) {
+            Err(crate::Error::Model(ModelError::EmbedAmount(11))) => {},
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[cfg(all(feature = "model", feature = "utils"))]
+    #[test]
+    fn check_embed_timestamp() {
+        use crate::builder::CreateMessage;
+        use crate::utils;
+
+        let mut builder = CreateMessage::default();
+        builder.embed(|e| e.timestamp("2004-06-08T16:04:23"));
+        let map = utils::hashmap_to_json_map(builder.0);
+
+        assert!(Message::check_embed_timestamp(&map).is_ok());
+
+        let mut builder = CreateMessage::default();
+        builder.embed(|e| e.timestamp("last tuesday"));
+        let map = utils::hashmap_to_json_map(builder.0);
+
+        match Message::check_embed_timestamp(&map) {
+            Err(crate::Error::Model(ModelError::InvalidTimestamp)) => {},
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[cfg(feature = "model")]
+    #[test]
+    fn guild_message_link() {
+        let message = test_utils::message(5, Some(7));
+
+        assert_eq!(message.link(), "https://discord.com/channels/7/5/3");
+        assert_eq!(MessageId(3).link(ChannelId(5), Some(GuildId(7))), message.link());
+    }
+
+    #[cfg(feature = "model")]
+    #[test]
+    fn private_message_link() {
+        let message = test_utils::message(5, None);
+
+        assert_eq!(message.link(), "https://discord.com/channels/@me/5/3");
+        assert_eq!(MessageId(3).link(ChannelId(5), None), message.link());
+    }
+
+    #[cfg(all(feature = "cache", feature = "model"))]
+    #[tokio::test]
+    async fn resolve_guild_message() {
+        use crate::cache::{Cache, CacheUpdate};
+
+        let cache = Cache::default();
+        let mut guild_create = GuildCreateEvent {
+            guild: serde_json::from_value(json!({
+                "id": "1",
+                "afk_channel_id": null,
+                "afk_timeout": 0,
+                "channels": [{
+                    "id": "2",
+                    "guild_id": "1",
+                    "type": 0,
+                    "name": "general",
+                    "permission_overwrites": [],
+                    "position": 0,
+                }],
+                "default_message_notifications": 0,
+                "emojis": [],
+                "explicit_content_filter": 0,
+                "features": [],
+                "icon": null,
+                "joined_at": "2016-11-08T00:00:00+00:00",
+                "large": false,
+                "member_count": 0,
+                "members": [],
+                "mfa_level": 0,
+                "name": "Spaghetti",
+                "owner_id": "4",
+                "presences": [],
+                "region": "NA",
+                "roles": [],
+                "splash": null,
+                "verification_level": 0,
+                "voice_states": [],
+                "premium_tier": 0,
+                "preferred_locale": "en-US",
+            })).unwrap(),
+        };
+        assert!(guild_create.update(&cache).await.is_none());
+
+        let message = test_utils::message(2, Some(1));
+
+        match message.channel(&cache).await {
+            Some(Channel::Guild(channel)) => assert_eq!(channel.name, "general"),
+            other => panic!("unexpected channel: {:?}", other),
+        }
+        assert_eq!(message.guild(&cache).await.map(|g| g.name), Some("Spaghetti".to_string()));
+
+        let uncached = test_utils::message(5, Some(6));
+        assert!(uncached.channel(&cache).await.is_none());
+        assert!(uncached.guild(&cache).await.is_none());
+    }
+
+    #[cfg(all(feature = "cache", feature = "model"))]
+    #[tokio::test]
+    async fn resolve_private_message() {
+        use crate::cache::{Cache, CacheUpdate};
+
+        let cache = Cache::default();
+        let mut channel_create = ChannelCreateEvent {
+            channel: serde_json::from_value(json!({
+                "id": "7",
+                "type": 1,
+                "recipients": [{
+                    "id": "2",
+                    "avatar": null,
+                    "discriminator": "0001",
+                    "username": "user 1",
+                }],
+            })).unwrap(),
+        };
+        channel_create.update(&cache).await;
+
+        let message = test_utils::message(7, None);
+
+        match message.channel(&cache).await {
+            Some(Channel::Private(channel)) => assert_eq!(channel.recipient.id, UserId(2)),
+            other => panic!("unexpected channel: {:?}", other),
+        }
+        assert!(message.guild(&cache).await.is_none());
+    }
+}
@@ -2,23 +2,31 @@
 
 mod attachment;
 mod channel_id;
+mod component;
 mod embed;
+mod forum;
 mod guild_channel;
 mod message;
 mod private_channel;
 mod reaction;
 mod channel_category;
+mod stage_instance;
 mod sticker;
+mod thread;
 
 pub use self::attachment::*;
 pub use self::channel_id::*;
+pub use self::component::*;
 pub use self::embed::*;
+pub use self::forum::*;
 pub use self::guild_channel::*;
 pub use self::message::*;
 pub use self::private_channel::*;
 pub use self::reaction::*;
 pub use self::channel_category::*;
+pub use self::stage_instance::*;
 pub use self::sticker::*;
+pub use self::thread::*;
 
 use crate::model::prelude::*;
 use serde::de::Error as DeError;
@@ -49,7 +57,7 @@ pub enum Channel {
     ///
     /// [text]: ChannelType::Text
     /// [voice]: ChannelType::Voice
-    Guild(GuildChannel),
+    Guild(Box<GuildChannel>),
     /// A private channel to another [`User`]. No other users may access the
     /// channel. For multi-user "private channels", use a group.
     Private(PrivateChannel),
@@ -89,7 +97,7 @@ impl Channel {
     /// ```
     pub fn guild(self) -> Option<GuildChannel> {
         match self {
-            Channel::Guild(lock) => Some(lock),
+            Channel::Guild(lock) => Some(*lock),
             _ => None,
         }
     }
@@ -230,8 +238,8 @@ impl<'de> Deserialize<'de> for Channel {
         };
 
         match kind {
-            0 | 2 | 5 | 6 => serde_json::from_value::<GuildChannel>(Value::Object(v))
-                .map(Channel::Guild)
+            0 | 2 | 5 | 6 | 10 | 11 | 12 | 13 | 15 => serde_json::from_value::<GuildChannel>(Value::Object(v))
+                .map(|channel| Channel::Guild(Box::new(channel)))
                 .map_err(DeError::custom),
             1 => serde_json::from_value::<PrivateChannel>(Value::Object(v))
                 .map(Channel::Private)
@@ -292,6 +300,27 @@ pub enum ChannelType {
     ///
     /// Note: `StoreChannel` is serialized into a [`GuildChannel`]
     Store = 6,
+    /// An indicator that the channel is a thread of a news channel.
+    ///
+    /// Note: `NewsThread` is serialized into a [`GuildChannel`]
+    NewsThread = 10,
+    /// An indicator that the channel is a public thread.
+    ///
+    /// Note: `PublicThread` is serialized into a [`GuildChannel`]
+    PublicThread = 11,
+    /// An indicator that the channel is a private thread.
+    ///
+    /// Note: `PrivateThread` is serialized into a [`GuildChannel`]
+    PrivateThread = 12,
+    /// An indicator that the channel is a stage channel.
+    ///
+    /// Note: `Stage` is serialized into a [`GuildChannel`]
+    Stage = 13,
+    /// An indicator that the channel is a forum channel, which can only
+    /// contain threads.
+    ///
+    /// Note: `Forum` is serialized into a [`GuildChannel`]
+    Forum = 15,
 }
 
 enum_number!(
@@ -302,6 +331,11 @@ enum_number!(
         Category,
         News,
         Store,
+        NewsThread,
+        PublicThread,
+        PrivateThread,
+        Stage,
+        Forum,
     }
 );
 
@@ -315,6 +349,11 @@ impl ChannelType {
             ChannelType::Category => "category",
             ChannelType::News => "news",
             ChannelType::Store => "store",
+            ChannelType::NewsThread => "news_thread",
+            ChannelType::PublicThread => "public_thread",
+            ChannelType::PrivateThread => "private_thread",
+            ChannelType::Stage => "stage",
+            ChannelType::Forum => "forum",
         }
     }
 
@@ -327,6 +366,11 @@ impl ChannelType {
             ChannelType::Category => 4,
             ChannelType::News => 5,
             ChannelType::Store => 6,
+            ChannelType::NewsThread => 10,
+            ChannelType::PublicThread => 11,
+            ChannelType::PrivateThread => 12,
+            ChannelType::Stage => 13,
+            ChannelType::Forum => 15,
         }
     }
 }
@@ -418,6 +462,11 @@ mod test {
                 user_limit: None,
                 nsfw: false,
                 slow_mode_rate: Some(0),
+                thread_metadata: None,
+                member: None,
+                available_tags: vec![],
+                applied_tags: vec![],
+                default_reaction_emoji: None,
             }
         }
 
@@ -460,7 +509,7 @@ mod test {
             channel.nsfw = false;
             assert!(!channel.is_nsfw());
 
-            let channel = Channel::Guild(channel);
+            let channel = Channel::Guild(Box::new(channel));
             assert!(!channel.is_nsfw());
 
             let private_channel = private_channel();
@@ -355,6 +355,37 @@ impl ReactionType {
         }
     }
 
+    /// Creates a [`ReactionType::Unicode`], checking that the string is a
+    /// single unicode emoji.
+    ///
+    /// An emoji can be made of several code points, such as a flag, a keycap,
+    /// or a sequence joined with zero-width joiners. To use the shortcode of
+    /// an emoji instead, refer to [`utils::emoji_from_shortcode`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::model::channel::ReactionType;
+    ///
+    /// assert!(ReactionType::unicode("\u{1f44d}").is_ok());
+    /// assert!(ReactionType::unicode("\u{1f1eb}\u{1f1f7}").is_ok());
+    /// assert!(ReactionType::unicode("not an emoji").is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidUnicodeEmoji`] if the string is not a
+    /// single emoji.
+    ///
+    /// [`utils::emoji_from_shortcode`]: crate::utils::emoji_from_shortcode
+    pub fn unicode(emoji: &str) -> Result<ReactionType> {
+        if is_emoji_grapheme(emoji) {
+            Ok(ReactionType::Unicode(emoji.to_string()))
+        } else {
+            Err(Error::Model(ModelError::InvalidUnicodeEmoji))
+        }
+    }
+
     /// Helper function to allow testing equality of unicode emojis without
     /// having to perform any allocation.
     /// Will always return false if the reaction was not a unicode reaction.
@@ -384,6 +415,77 @@ impl ReactionType {
     }
 }
 
+/// Checks whether a string is made of a single emoji, which may span several
+/// code points.
+#[cfg(feature = "model")]
+fn is_emoji_grapheme(emoji: &str) -> bool {
+    let chars = emoji.chars().collect::<Vec<_>>();
+
+    match chars.as_slice() {
+        [] => false,
+        // A keycap, such as `1\u{fe0f}\u{20e3}`.
+        [base, rest @ ..] if is_keycap_base(*base) => {
+            matches!(rest, ['\u{20e3}'] | ['\u{fe0f}', '\u{20e3}'])
+        },
+        // A flag, made of two regional indicators.
+        [first, second] if is_regional_indicator(*first) && is_regional_indicator(*second) => true,
+        // A subdivision flag, made of the black flag and tag characters.
+        ['\u{1f3f4}', tags @ .., '\u{e007f}'] if !tags.is_empty() => {
+            tags.iter().all(|c| ('\u{e0020}'..='\u{e007e}').contains(c))
+        },
+        _ => chars.split(|&c| c == '\u{200d}').all(is_emoji_element),
+    }
+}
+
+/// Checks whether a sequence is a pictographic code point, optionally
+/// followed by a skin tone modifier or the emoji presentation selector.
+#[cfg(feature = "model")]
+fn is_emoji_element(element: &[char]) -> bool {
+    let (base, modifiers) = match element.split_first() {
+        Some((&base, modifiers)) => (base, modifiers),
+        None => return false,
+    };
+
+    if !is_pictographic(base) || is_regional_indicator(base) || is_skin_tone(base) {
+        return false;
+    }
+
+    match modifiers {
+        [] | ['\u{fe0f}'] => true,
+        [modifier] | [modifier, '\u{fe0f}'] => is_skin_tone(*modifier),
+        _ => false,
+    }
+}
+
+#[cfg(feature = "model")]
+fn is_pictographic(c: char) -> bool {
+    matches!(
+        c,
+        '\u{a9}' | '\u{ae}' | '\u{203c}' | '\u{2049}' | '\u{2122}' | '\u{2139}'
+            | '\u{2194}'..='\u{2199}' | '\u{21a9}'..='\u{21aa}' | '\u{231a}'..='\u{231b}'
+            | '\u{2328}' | '\u{23cf}' | '\u{23e9}'..='\u{23f3}' | '\u{23f8}'..='\u{23fa}'
+            | '\u{24c2}' | '\u{25aa}'..='\u{25ab}' | '\u{25b6}' | '\u{25c0}'
+            | '\u{25fb}'..='\u{25fe}' | '\u{2600}'..='\u{27bf}' | '\u{2934}'..='\u{2935}'
+            | '\u{2b05}'..='\u{2b07}' | '\u{2b1b}'..='\u{2b1c}' | '\u{2b50}' | '\u{2b55}'
+            | '\u{3030}' | '\u{303d}' | '\u{3297}' | '\u{3299}' | '\u{1f000}'..='\u{1faff}'
+    )
+}
+
+#[cfg(feature = "model")]
+fn is_keycap_base(c: char) -> bool {
+    matches!(c, '0'..='9' | '#' | '*')
+}
+
+#[cfg(feature = "model")]
+fn is_regional_indicator(c: char) -> bool {
+    ('\u{1f1e6}'..='\u{1f1ff}').contains(&c)
+}
+
+#[cfg(feature = "model")]
+fn is_skin_tone(c: char) -> bool {
+    ('\u{1f3fb}'..='\u{1f3ff}').contains(&c)
+}
+
 impl From<char> for ReactionType {
     /// Creates a `ReactionType` from a `char`.
     ///
@@ -422,6 +524,16 @@ impl From<Emoji> for ReactionType {
     }
 }
 
+impl From<&Emoji> for ReactionType {
+    fn from(emoji: &Emoji) -> ReactionType {
+        ReactionType::Custom {
+            animated: emoji.animated,
+            id: emoji.id,
+            name: Some(emoji.name.clone()),
+        }
+    }
+}
+
 impl From<EmojiId> for ReactionType {
     fn from(emoji_id: EmojiId) -> ReactionType {
         ReactionType::Custom {
@@ -602,3 +714,41 @@ impl Display for ReactionType {
         }
     }
 }
+
+#[cfg(all(test, feature = "model"))]
+mod test {
+    use super::ReactionType;
+
+    #[test]
+    fn unicode_emojis() {
+        assert_eq!(
+            ReactionType::unicode("\u{1f44d}").unwrap(),
+            ReactionType::Unicode("\u{1f44d}".to_string()),
+        );
+
+        for emoji in &[
+            "\u{2764}\u{fe0f}",
+            "\u{1f44d}\u{1f3fd}",
+            "\u{1f1eb}\u{1f1f7}",
+            "1\u{fe0f}\u{20e3}",
+            "\u{1f468}\u{200d}\u{1f4bb}",
+            "\u{1f3f4}\u{e0067}\u{e0062}\u{e0077}\u{e006c}\u{e0073}\u{e007f}",
+        ] {
+            assert!(ReactionType::unicode(emoji).is_ok(), "{:?} should be accepted", emoji);
+        }
+
+        for emoji in &[
+            "not an emoji",
+            "",
+            "a",
+            "1",
+            "\u{1f44d}\u{1f44d}",
+            "\u{1f1eb}",
+            "\u{1f3fd}",
+            "\u{1f468}\u{200d}",
+            "<:custom:600404340292059257>",
+        ] {
+            assert!(ReactionType::unicode(emoji).is_err(), "{:?} should be rejected", emoji);
+        }
+    }
+}
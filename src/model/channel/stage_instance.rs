@@ -0,0 +1,119 @@
+use crate::model::prelude::*;
+
+/// A live session of a stage channel, with a topic.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct StageInstance {
+    /// The Id of the stage instance.
+    pub id: StageInstanceId,
+    /// The Id of the guild of the stage channel.
+    pub guild_id: GuildId,
+    /// The Id of the stage channel.
+    pub channel_id: ChannelId,
+    /// The topic of the stage instance.
+    pub topic: String,
+    /// Who can access the stage instance.
+    pub privacy_level: StagePrivacyLevel,
+    /// The Id of the scheduled event the stage instance was started for, if
+    /// any.
+    #[serde(default)]
+    pub guild_scheduled_event_id: Option<ScheduledEventId>,
+}
+
+/// Who can access a [`StageInstance`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum StagePrivacyLevel {
+    /// The stage instance is visible publicly.
+    ///
+    /// **Note**: This is deprecated by Discord.
+    Public = 1,
+    /// The stage instance is only accessible to guild members.
+    GuildOnly = 2,
+}
+
+enum_number!(
+    StagePrivacyLevel {
+        Public,
+        GuildOnly,
+    }
+);
+
+impl StagePrivacyLevel {
+    pub fn num(self) -> u64 {
+        match self {
+            StagePrivacyLevel::Public => 1,
+            StagePrivacyLevel::GuildOnly => 2,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::model::prelude::*;
+    use serde_json::json;
+
+    #[test]
+    fn deserialize_stage_instance() {
+        let value = json!({
+            "id": "840647391636226060",
+            "guild_id": "197038439483310086",
+            "channel_id": "733488538393510049",
+            "topic": "Testing Testing, 123",
+            "privacy_level": 2,
+            "discoverable_disabled": false,
+            "guild_scheduled_event_id": "947656305244532806"
+        });
+
+        let stage_instance = serde_json::from_value::<StageInstance>(value).unwrap();
+
+        assert_eq!(stage_instance.id, StageInstanceId(840647391636226060));
+        assert_eq!(stage_instance.guild_id, GuildId(197038439483310086));
+        assert_eq!(stage_instance.channel_id, ChannelId(733488538393510049));
+        assert_eq!(stage_instance.topic, "Testing Testing, 123");
+        assert_eq!(stage_instance.privacy_level, StagePrivacyLevel::GuildOnly);
+        assert_eq!(stage_instance.guild_scheduled_event_id, Some(ScheduledEventId(947656305244532806)));
+    }
+
+    #[test]
+    fn deserialize_stage_channel() {
+        let value = json!({
+            "id": "733488538393510049",
+            "guild_id": "197038439483310086",
+            "type": 13,
+            "name": "Town Hall",
+            "position": 3,
+            "permission_overwrites": [],
+            "bitrate": 64000,
+            "user_limit": 10000,
+            "nsfw": false
+        });
+
+        match serde_json::from_value::<Channel>(value).unwrap() {
+            Channel::Guild(channel) => assert_eq!(channel.kind, ChannelType::Stage),
+            other => panic!("unexpected channel: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn deserialize_stage_instance_event() {
+        use crate::model::event::{self, Event, EventType};
+
+        let value = json!({
+            "id": "840647391636226060",
+            "guild_id": "197038439483310086",
+            "channel_id": "733488538393510049",
+            "topic": "Testing Testing, 123",
+            "privacy_level": 2
+        });
+        let kind = serde_json::from_value::<EventType>(json!("STAGE_INSTANCE_DELETE")).unwrap();
+
+        match event::deserialize_event_with_type(kind, value).unwrap() {
+            Event::StageInstanceDelete(event) => {
+                assert_eq!(event.stage_instance.channel_id, ChannelId(733488538393510049));
+                assert_eq!(event.stage_instance.guild_scheduled_event_id, None);
+            },
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+}
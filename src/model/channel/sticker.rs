@@ -1,29 +1,249 @@
-use crate::model::id::{StickerId, StickerPackId};
+use crate::model::prelude::*;
+use crate::model::utils::{default_true, deserialize_default_on_null, deserialize_u64, serialize_u64};
+
+#[cfg(feature = "model")]
+use crate::builder::EditSticker;
+#[cfg(feature = "model")]
+use crate::http::Http;
+#[cfg(feature = "model")]
+use crate::internal::prelude::*;
+#[cfg(feature = "model")]
+use crate::utils;
 
 /// A sticker sent with a message.
 ///
-/// Bots currently can only receive messages with stickers, not send.
+/// This is either a standard sticker, available to everyone through a
+/// [`StickerPack`], or a sticker uploaded in a guild.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Sticker {
     /// The unique ID given to this sticker.
     pub id: StickerId,
     /// The unique ID of the pack the sticker is from.
+    ///
+    /// **Note**: This is only present on standard stickers, and is
+    /// `StickerPackId(0)` otherwise.
+    #[serde(default)]
     pub pack_id: StickerPackId,
     /// The name of the sticker.
     pub name: String,
     /// Description of the sticker
+    ///
+    /// **Note**: This is empty if a guild sticker has no description.
+    #[serde(default, deserialize_with = "deserialize_default_on_null")]
     pub description: String,
     /// A comma-separated list of tags for the sticker.
+    ///
+    /// **Note**: For guild stickers, this is the name of the emoji the sticker
+    /// is tied to.
     pub tags: Option<String>,
     /// The sticker asset hash.
+    ///
+    /// **Note**: This is deprecated by Discord, and always empty on new
+    /// stickers.
+    #[serde(default, deserialize_with = "deserialize_default_on_null")]
     pub asset: String,
     /// The sticker preview asset hash.
+    #[serde(default)]
     pub preview_asset: Option<String>,
+    /// The type of the sticker.
+    ///
+    /// Defaults to [`StickerType::Standard`] for payloads which predate the
+    /// field.
+    #[serde(default = "default_sticker_type", rename = "type")]
+    pub kind: StickerType,
+    /// The type of sticker format.
+    pub format_type: StickerFormatType,
+    /// Whether the guild sticker can be used, which may be `false` due to a
+    /// loss of server boosts.
+    #[serde(default = "default_true")]
+    pub available: bool,
+    /// The Id of the guild which owns the sticker.
+    ///
+    /// **Note**: This is only present on guild stickers.
+    pub guild_id: Option<GuildId>,
+    /// The user which uploaded the guild sticker.
+    ///
+    /// **Note**: This requires the Manage Emojis permission.
+    pub user: Option<User>,
+    /// The sort order of a standard sticker in its pack.
+    pub sort_value: Option<u64>,
+}
+
+#[cfg(feature = "model")]
+impl Sticker {
+    /// Deletes the sticker from its guild.
+    ///
+    /// Requires the [Manage Emojis] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidStickerType`] if the sticker is a
+    /// standard sticker.
+    ///
+    /// [Manage Emojis]: Permissions::MANAGE_EMOJIS
+    pub async fn delete(&self, http: impl AsRef<Http>) -> Result<()> {
+        match self.guild_id {
+            Some(guild_id) => guild_id.delete_sticker(http, self.id).await,
+            None => Err(Error::Model(ModelError::InvalidStickerType)),
+        }
+    }
+
+    /// Edits the sticker in its guild.
+    ///
+    /// Refer to the documentation for [`EditSticker`] for a full list of
+    /// methods.
+    ///
+    /// Requires the [Manage Emojis] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidStickerType`] if the sticker is a
+    /// standard sticker.
+    ///
+    /// [Manage Emojis]: Permissions::MANAGE_EMOJIS
+    pub async fn edit<F>(&mut self, http: impl AsRef<Http>, f: F) -> Result<()>
+    where F: FnOnce(&mut EditSticker) -> &mut EditSticker
+    {
+        let guild_id = match self.guild_id {
+            Some(guild_id) => guild_id,
+            None => return Err(Error::Model(ModelError::InvalidStickerType)),
+        };
+
+        let mut builder = EditSticker::default();
+        f(&mut builder);
+        let map = utils::hashmap_to_json_map(builder.0);
+
+        *self = http.as_ref().edit_sticker(guild_id.0, self.id.0, &map).await?;
+
+        Ok(())
+    }
+
+    /// Generates a URL to the sticker's asset.
+    #[inline]
+    pub fn url(&self) -> String {
+        sticker_url(self.id, self.format_type)
+    }
+}
+
+/// The smallest amount of data required to render a sticker, sent along with
+/// messages.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct StickerItem {
+    /// The unique ID given to this sticker.
+    pub id: StickerId,
+    /// The name of the sticker.
+    pub name: String,
     /// The type of sticker format.
     pub format_type: StickerFormatType,
 }
 
+#[cfg(feature = "model")]
+impl StickerItem {
+    /// Requests the full [`Sticker`] over the REST API.
+    #[inline]
+    pub async fn to_sticker(&self, http: impl AsRef<Http>) -> Result<Sticker> {
+        http.as_ref().get_sticker(self.id.0).await
+    }
+
+    /// Generates a URL to the sticker's asset.
+    #[inline]
+    pub fn url(&self) -> String {
+        sticker_url(self.id, self.format_type)
+    }
+}
+
+#[cfg(feature = "model")]
+impl StickerId {
+    /// Requests the [`Sticker`] over the REST API.
+    #[inline]
+    pub async fn to_sticker(self, http: impl AsRef<Http>) -> Result<Sticker> {
+        http.as_ref().get_sticker(self.0).await
+    }
+}
+
+/// A pack of standard stickers, such as the ones available to Nitro
+/// subscribers.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct StickerPack {
+    /// The unique ID given to this sticker pack.
+    pub id: StickerPackId,
+    /// The stickers in the pack.
+    pub stickers: Vec<Sticker>,
+    /// The name of the sticker pack.
+    pub name: String,
+    /// The Id of the pack's SKU.
+    #[serde(serialize_with = "serialize_u64", deserialize_with = "deserialize_u64")]
+    pub sku_id: u64,
+    /// The Id of a sticker in the pack which is shown as the pack's icon.
+    pub cover_sticker_id: Option<StickerId>,
+    /// The description of the sticker pack.
+    pub description: String,
+    /// The Id of the sticker pack's banner image.
+    pub banner_asset_id: Option<String>,
+}
+
+#[cfg(feature = "model")]
+impl StickerPack {
+    /// Returns the URL of the sticker pack's banner image, if it has one.
+    pub fn banner_url(&self) -> Option<String> {
+        // Banners are stored as the store assets of Discord's sticker
+        // application.
+        self.banner_asset_id
+            .as_ref()
+            .map(|id| format!(cdn!("/app-assets/710982414301790216/store/{}.webp?size=1024"), id))
+    }
+
+    /// Returns the sticker shown as the pack's icon, if it is in the pack.
+    pub fn cover_sticker(&self) -> Option<&Sticker> {
+        let cover_sticker_id = self.cover_sticker_id?;
+
+        self.stickers.iter().find(|sticker| sticker.id == cover_sticker_id)
+    }
+}
+
+#[cfg(feature = "model")]
+fn sticker_url(sticker_id: StickerId, format_type: StickerFormatType) -> String {
+    let extension = match format_type {
+        StickerFormatType::Lottie => "json",
+        StickerFormatType::Png | StickerFormatType::Apng => "png",
+    };
+
+    format!(cdn!("/stickers/{}.{}"), sticker_id, extension)
+}
+
+/// Differentiates between standard and guild stickers.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum StickerType {
+    /// An official sticker in a pack.
+    Standard = 1,
+    /// A sticker uploaded to a guild.
+    Guild = 2,
+}
+
+enum_number!(
+    StickerType {
+        Standard,
+        Guild,
+    }
+);
+
+impl StickerType {
+    pub fn num(self) -> u64 {
+        match self {
+            StickerType::Standard => 1,
+            StickerType::Guild => 2,
+        }
+    }
+}
+
+fn default_sticker_type() -> StickerType {
+    StickerType::Standard
+}
+
 /// Differentiates between sticker formats.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
 #[non_exhaustive]
@@ -55,3 +275,139 @@ impl StickerFormatType {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::model::prelude::*;
+    use serde_json::json;
+
+    #[test]
+    fn standard_sticker_deserialization() {
+        let value = json!({
+            "id": "749054660769218631",
+            "name": "Wave",
+            "tags": "wumpus, hello, sup, hi, oi, heyo, heya, yo, greetings, greet, welcome, wave, :wave, :hello, :hi, :hey, hey, \u{1f44b}, \u{1f44b}\u{1f3fb}, \u{1f44b}\u{1f3fc}, \u{1f44b}\u{1f3fd}, \u{1f44b}\u{1f3fe}, \u{1f44b}\u{1f3ff}, goodbye, bye, see ya, later, laterz, :wave:",
+            "type": 1,
+            "format_type": 3,
+            "description": "Wumpus waves hello",
+            "asset": "",
+            "pack_id": "847199849233514549",
+            "sort_value": 12
+        });
+
+        let sticker = serde_json::from_value::<Sticker>(value).unwrap();
+
+        assert_eq!(sticker.id, StickerId(749054660769218631));
+        assert_eq!(sticker.pack_id, StickerPackId(847199849233514549));
+        assert_eq!(sticker.kind, StickerType::Standard);
+        assert_eq!(sticker.format_type, StickerFormatType::Lottie);
+        assert_eq!(sticker.description, "Wumpus waves hello");
+        assert_eq!(sticker.sort_value, Some(12));
+        assert!(sticker.available);
+        assert!(sticker.guild_id.is_none());
+
+        #[cfg(feature = "model")]
+        assert_eq!(sticker.url(), "https://cdn.discordapp.com/stickers/749054660769218631.json");
+    }
+
+    #[test]
+    fn guild_sticker_deserialization() {
+        let value = json!({
+            "id": "3",
+            "name": "serenity",
+            "tags": "crab",
+            "type": 2,
+            "format_type": 2,
+            "description": null,
+            "available": false,
+            "guild_id": "1",
+            "user": {
+                "id": "2",
+                "username": "ferris",
+                "discriminator": "0001",
+                "avatar": null
+            }
+        });
+
+        let sticker = serde_json::from_value::<Sticker>(value).unwrap();
+
+        assert_eq!(sticker.kind, StickerType::Guild);
+        assert_eq!(sticker.format_type, StickerFormatType::Apng);
+        assert_eq!(sticker.guild_id, Some(GuildId(1)));
+        assert_eq!(sticker.user.as_ref().map(|u| u.id), Some(UserId(2)));
+        assert_eq!(sticker.pack_id, StickerPackId(0));
+        assert!(sticker.description.is_empty());
+        assert!(sticker.asset.is_empty());
+        assert!(!sticker.available);
+
+        #[cfg(feature = "model")]
+        assert_eq!(sticker.url(), "https://cdn.discordapp.com/stickers/3.png");
+    }
+
+    #[test]
+    fn sticker_pack_deserialization() {
+        let value = json!({
+            "id": "847199849233514549",
+            "stickers": [
+                {
+                    "id": "749054660769218631",
+                    "name": "Wave",
+                    "tags": "wumpus, hello",
+                    "type": 1,
+                    "format_type": 3,
+                    "description": "Wumpus waves hello",
+                    "asset": "",
+                    "pack_id": "847199849233514549",
+                    "sort_value": 12
+                },
+                {
+                    "id": "749043879713701898",
+                    "name": "Dance",
+                    "tags": "wumpus, party",
+                    "type": 1,
+                    "format_type": 3,
+                    "description": "Wumpus dances",
+                    "pack_id": "847199849233514549",
+                    "sort_value": 3
+                }
+            ],
+            "name": "Wumpus Beyond",
+            "sku_id": "847199849233514547",
+            "cover_sticker_id": "749043879713701898",
+            "description": "Say hello to Wumpus!",
+            "banner_asset_id": "761773777976819732"
+        });
+
+        let pack = serde_json::from_value::<StickerPack>(value).unwrap();
+
+        assert_eq!(pack.id, StickerPackId(847199849233514549));
+        assert_eq!(pack.name, "Wumpus Beyond");
+        assert_eq!(pack.sku_id, 847199849233514547);
+        assert_eq!(pack.cover_sticker_id, Some(StickerId(749043879713701898)));
+        assert_eq!(pack.stickers.len(), 2);
+        assert!(pack.stickers.iter().all(|s| s.pack_id == pack.id));
+
+        #[cfg(feature = "model")]
+        {
+            assert_eq!(pack.cover_sticker().map(|s| s.name.as_str()), Some("Dance"));
+            assert_eq!(
+                pack.banner_url().as_deref(),
+                Some("https://cdn.discordapp.com/app-assets/710982414301790216/store/761773777976819732.webp?size=1024"),
+            );
+        }
+    }
+
+    #[test]
+    fn sticker_item_deserialization() {
+        let value = json!({
+            "id": "3",
+            "name": "serenity",
+            "format_type": 1
+        });
+
+        let item = serde_json::from_value::<StickerItem>(value).unwrap();
+
+        assert_eq!(item.id, StickerId(3));
+        assert_eq!(item.format_type, StickerFormatType::Png);
+    }
+}
@@ -0,0 +1,127 @@
+use chrono::{DateTime, Utc};
+use crate::model::prelude::*;
+
+/// Thread-specific data of a [`GuildChannel`] which is a thread.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ThreadMetadata {
+    /// Whether the thread is archived.
+    pub archived: bool,
+    /// The amount of minutes of inactivity after which the thread is
+    /// automatically archived.
+    ///
+    /// Can be `60`, `1440`, `4320` or `10080`.
+    pub auto_archive_duration: Option<u64>,
+    /// The last time the thread's archive status was changed.
+    ///
+    /// This is used to sort the archived threads when listing them.
+    pub archive_timestamp: Option<DateTime<Utc>>,
+    /// Whether the thread is locked. Only users with the Manage Threads
+    /// permission can unarchive a locked thread.
+    #[serde(default)]
+    pub locked: bool,
+    /// Whether non-moderators can add other non-moderators to the thread.
+    ///
+    /// **Note**: This is only available on private threads.
+    #[serde(default)]
+    pub invitable: Option<bool>,
+}
+
+/// A member of a thread.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ThreadMember {
+    /// The Id of the thread.
+    ///
+    /// **Note**: This is omitted when the member is sent along with the
+    /// thread itself.
+    pub id: Option<ChannelId>,
+    /// The Id of the user.
+    ///
+    /// **Note**: This is omitted when the member is sent along with the
+    /// thread itself.
+    pub user_id: Option<UserId>,
+    /// The time at which the user last joined the thread.
+    pub join_timestamp: DateTime<Utc>,
+    /// Flags used for the notification settings of the user.
+    pub flags: u64,
+}
+
+/// A list of threads along with the members of those threads which the
+/// current user is, used when listing archived threads.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ThreadsData {
+    /// The threads which were retrieved.
+    pub threads: Vec<GuildChannel>,
+    /// A thread member for each of the threads which the current user has
+    /// joined.
+    pub members: Vec<ThreadMember>,
+    /// Whether there are more threads which can be retrieved by making another
+    /// request.
+    pub has_more: bool,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::model::prelude::*;
+    use serde_json::json;
+
+    #[test]
+    fn archived_threads_deserialization() {
+        let value = json!({
+            "threads": [{
+                "id": "3",
+                "guild_id": "1",
+                "parent_id": "2",
+                "owner_id": "4",
+                "type": 11,
+                "name": "a thread",
+                "last_message_id": "5",
+                "message_count": 1,
+                "member_count": 1,
+                "rate_limit_per_user": 0,
+                "thread_metadata": {
+                    "archived": true,
+                    "auto_archive_duration": 1440,
+                    "archive_timestamp": "2021-07-01T00:00:00+00:00",
+                    "locked": false
+                },
+                "member": {
+                    "join_timestamp": "2021-06-30T00:00:00+00:00",
+                    "flags": 1
+                }
+            }],
+            "members": [{
+                "id": "3",
+                "user_id": "4",
+                "join_timestamp": "2021-06-30T00:00:00+00:00",
+                "flags": 1
+            }],
+            "has_more": true
+        });
+
+        let data = serde_json::from_value::<ThreadsData>(value).unwrap();
+
+        assert!(data.has_more);
+        assert_eq!(data.threads.len(), 1);
+
+        let thread = &data.threads[0];
+        assert_eq!(thread.id, ChannelId(3));
+        assert_eq!(thread.kind, ChannelType::PublicThread);
+        assert_eq!(thread.category_id, Some(ChannelId(2)));
+
+        let metadata = thread.thread_metadata.as_ref().unwrap();
+        assert!(metadata.archived);
+        assert!(!metadata.locked);
+        assert_eq!(metadata.auto_archive_duration, Some(1440));
+        assert!(metadata.invitable.is_none());
+
+        let member = thread.member.as_ref().unwrap();
+        assert!(member.id.is_none());
+        assert_eq!(member.flags, 1);
+
+        assert_eq!(data.members[0].id, Some(ChannelId(3)));
+        assert_eq!(data.members[0].user_id, Some(UserId(4)));
+    }
+}
@@ -66,12 +66,42 @@ use super::Permissions;
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 #[non_exhaustive]
 pub enum Error {
+    /// When attempting to send an attachment with a description longer than
+    /// 1024 unicode code points.
+    ///
+    /// The number of code points larger than the limit is provided.
+    AttachmentDescriptionTooLong(usize),
     /// When attempting to delete below or above the minimum and maximum allowed
     /// number of messages.
     BulkDeleteAmount,
+    /// When attempting to bulk ban no users, or more than the maximum allowed
+    /// number of users at once.
+    BulkBanAmount,
+    /// When attempting to respond to an autocomplete interaction with more
+    /// than 25 choices.
+    ///
+    /// The number of choices is provided.
+    ChoiceAmount(usize),
     /// When attempting to delete a number of days' worth of messages that is
     /// not allowed.
     DeleteMessageDaysAmount(u8),
+    /// When attempting to delete more than 7 days' worth of messages, in
+    /// seconds.
+    ///
+    /// The number of seconds is provided.
+    DeleteMessageSecondsAmount(u32),
+    /// When attempting to move multiple roles to the same position.
+    ///
+    /// The shared position is provided.
+    DuplicateRolePosition(i64),
+    /// When attempting to send a message with more than 10 embeds.
+    ///
+    /// The number of embeds is provided.
+    EmbedAmount(usize),
+    /// When attempting to add more than 25 fields to an embed.
+    ///
+    /// The number of fields is provided.
+    EmbedFieldAmount(usize),
     /// Indicates that the textual content of an embed exceeds the maximum
     /// length.
     EmbedTooLarge(usize),
@@ -82,6 +112,44 @@ pub enum Error {
     /// [`GuildId`]: super::id::GuildId
     /// [`Cache`]: crate::cache::Cache
     GuildNotFound,
+    /// Indicates that the allowed mentions of a message both parse a type of
+    /// mentions and list the allowed mentions of the same type.
+    InvalidAllowedMentions,
+    /// Indicates that a [link] button lacks an URL or has a custom Id, or that
+    /// another button lacks a custom Id or has an URL.
+    ///
+    /// [link]: super::channel::ButtonStyle::Link
+    InvalidButton,
+    /// Indicates that the options of an application command are nested
+    /// incorrectly.
+    ///
+    /// A subcommand group can only hold subcommands, a subcommand can only
+    /// hold options which are neither subcommands nor subcommand groups, and
+    /// those options can't hold any.
+    InvalidCommandOptions,
+    /// Indicates that a modal has no rows or more than 5 of them, or that one
+    /// of its rows doesn't hold exactly one text field.
+    InvalidModal,
+    /// Indicates that an invite was set to target both a user's stream and an
+    /// embedded application.
+    InvalidInviteTarget,
+    /// Indicates that an onboarding prompt has no options, that it is
+    /// required without being shown during the onboarding, or that it is
+    /// required and single-select with a single option.
+    InvalidOnboardingPrompt,
+    /// Indicates that the timestamp of an embed is not in the RFC 3339
+    /// format.
+    InvalidTimestamp,
+    /// Indicates that a string is not a single unicode emoji.
+    InvalidUnicodeEmoji,
+    /// Indicates that a webhook message was set to be posted in a thread and
+    /// to create a new forum thread at the same time.
+    InvalidWebhookThread,
+    /// Indicates that the requested CDN image size is outside of the allowed
+    /// range of 16 to 4096.
+    ///
+    /// The requested size is provided.
+    InvalidImageSize(u16),
     /// An indication that a [role][`Role`] could not be found by
     /// [Id][`RoleId`] in the [`Cache`].
     ///
@@ -126,29 +194,92 @@ pub enum Error {
     ///
     /// [`ChannelType`]: super::channel::ChannelType
     InvalidChannelType,
+    /// When attempting to set the slow mode rate of a channel above the
+    /// maximum of 21600 seconds.
+    ///
+    /// The requested rate is provided.
+    SlowModeRateAmount(u16),
+    /// When attempting to send a message with more than 3 stickers.
+    ///
+    /// The number of stickers is provided.
+    StickerAmount(usize),
+    /// An indicator that a standard [`Sticker`] cannot be edited or deleted,
+    /// as it does not belong to a guild.
+    ///
+    /// [`Sticker`]: super::channel::Sticker
+    InvalidStickerType,
+    /// Indicates that no file was set when creating a [`Sticker`].
+    ///
+    /// [`Sticker`]: super::channel::Sticker
+    NoStickerFileSet,
+    /// Indicates that a forum post was created without any content, embed or
+    /// component in its initial message.
+    NoForumPostMessage,
+    /// Indicates that files were described when editing a message, without
+    /// setting the existing attachments to keep.
+    NoAttachmentsKept,
     /// Indicates that the webhook name is under the 2 characters limit.
     NameTooShort,
     /// Indicates that the webhook name is over the 100 characters limit.
     NameTooLong,
+    /// Indicates that a streaming [`Activity`] was created without a Twitch or
+    /// YouTube URL.
+    ///
+    /// [`Activity`]: super::gateway::Activity
+    InvalidStreamUrl,
+    /// Indicates that an [`Activity`] other than a custom status was created
+    /// without a name.
+    ///
+    /// [`Activity`]: super::gateway::Activity
+    NoActivityName,
+    /// When attempting to pin a message in a channel which already has the
+    /// maximum of 50 pinned messages.
+    TooManyPins,
 }
 
 impl Display for Error {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match self {
+            Error::AttachmentDescriptionTooLong(_) => f.write_str("Attachment description too long."),
+            Error::BulkBanAmount => f.write_str("Too few/many users to bulk ban."),
             Error::BulkDeleteAmount => f.write_str("Too few/many messages to bulk delete."),
+            Error::ChoiceAmount(_) => f.write_str("Too many autocomplete choices."),
             Error::DeleteMessageDaysAmount(_) => f.write_str("Invalid delete message days."),
+            Error::DeleteMessageSecondsAmount(_) => f.write_str("Invalid delete message seconds."),
+            Error::DuplicateRolePosition(_) => f.write_str("Multiple roles share a position."),
+            Error::EmbedAmount(_) => f.write_str("Too many embeds."),
+            Error::EmbedFieldAmount(_) => f.write_str("Too many embed fields."),
             Error::EmbedTooLarge(_) => f.write_str("Embed too large."),
             Error::GuildNotFound => f.write_str("Guild not found in the cache."),
             Error::RoleNotFound => f.write_str("Role not found in the cache."),
             Error::Hierarchy => f.write_str("Role hierarchy prevents this action."),
             Error::InvalidChannelType => f.write_str("The channel cannot perform the action."),
+            Error::InvalidAllowedMentions => f.write_str("Invalid allowed mentions."),
+            Error::InvalidButton => f.write_str("Invalid button."),
+            Error::InvalidCommandOptions => f.write_str("Invalid application command option nesting."),
+            Error::InvalidModal => f.write_str("Invalid modal."),
+            Error::InvalidInviteTarget => f.write_str("Invalid invite target."),
+            Error::InvalidOnboardingPrompt => f.write_str("Invalid onboarding prompt."),
+            Error::InvalidTimestamp => f.write_str("Invalid embed timestamp."),
+            Error::InvalidUnicodeEmoji => f.write_str("Invalid unicode emoji."),
+            Error::InvalidWebhookThread => f.write_str("Invalid webhook thread."),
+            Error::InvalidImageSize(_) => f.write_str("Invalid image size."),
             Error::InvalidPermissions(_) => f.write_str("Invalid permissions."),
             Error::InvalidUser => f.write_str("The current user cannot perform the action."),
             Error::ItemMissing => f.write_str("The required item is missing from the cache."),
             Error::MessageTooLong(_) => f.write_str("Message too large."),
             Error::MessagingBot => f.write_str("Attempted to message another bot user."),
+            Error::SlowModeRateAmount(_) => f.write_str("Slow mode rate too large."),
+            Error::StickerAmount(_) => f.write_str("Too many stickers."),
+            Error::InvalidStickerType => f.write_str("The sticker does not belong to a guild."),
+            Error::NoStickerFileSet => f.write_str("No sticker file set."),
+            Error::NoForumPostMessage => f.write_str("No forum post message set."),
+            Error::NoAttachmentsKept => f.write_str("No attachments to keep set."),
             Error::NameTooShort => f.write_str("Name is under the character limit."),
             Error::NameTooLong => f.write_str("Name is over the character limit."),
+            Error::InvalidStreamUrl => f.write_str("Streaming activities require a Twitch or YouTube URL."),
+            Error::NoActivityName => f.write_str("No activity name set."),
+            Error::TooManyPins => f.write_str("Too many pinned messages."),
         }
     }
 }
@@ -61,21 +61,29 @@ impl CacheUpdate for ChannelCreateEvent {
             Channel::Guild(ref channel) => {
                 let (guild_id, channel_id) = (channel.guild_id, channel.id);
 
+                if !cache.settings.read().await.should_cache_channel(channel_id, Some(guild_id)) {
+                    return None;
+                }
+
                 let old_channel = cache
                     .guilds
                     .write().await
                     .get_mut(&guild_id)
-                    .and_then(|g| g.channels.insert(channel_id, channel.clone()))
-                    .map(Channel::Guild);
+                    .and_then(|g| g.channels.insert(channel_id, (**channel).clone()))
+                    .map(|channel| Channel::Guild(Box::new(channel)));
 
                 cache
                     .channels
                     .write().await
-                    .insert(channel_id, channel.clone());
+                    .insert(channel_id, (**channel).clone());
 
                 old_channel
             },
             Channel::Private(ref mut channel) => {
+                if !cache.settings.read().await.should_cache_channel(channel.id, None) {
+                    return None;
+                }
+
                 if let Some(channel) = cache.private_channels.read().await.get(&channel.id) {
                     return Some(Channel::Private(channel.clone()));
                 }
@@ -102,6 +110,10 @@ impl CacheUpdate for ChannelCreateEvent {
                     .map(Channel::Private)
             },
             Channel::Category(ref category) => {
+                if !cache.settings.read().await.should_cache_channel(category.id, Some(category.guild_id)) {
+                    return None;
+                }
+
                 cache
                     .categories
                     .write()
@@ -224,14 +236,18 @@ impl CacheUpdate for ChannelUpdateEvent {
             Channel::Guild(ref channel) => {
                 let (guild_id, channel_id) = (channel.guild_id, channel.id);
 
-                cache.channels.write().await.insert(channel_id, channel.clone());
+                if !cache.settings.read().await.should_cache_channel(channel_id, Some(guild_id)) {
+                    return None;
+                }
+
+                cache.channels.write().await.insert(channel_id, (**channel).clone());
 
                 cache
                     .guilds
                     .write()
                     .await
                     .get_mut(&guild_id)
-                    .map(|g| g.channels.insert(channel_id, channel.clone()));
+                    .map(|g| g.channels.insert(channel_id, (**channel).clone()));
             },
             Channel::Private(ref channel) => {
                 if let Some(c) = cache.private_channels.write().await.get_mut(&channel.id) {
@@ -291,8 +307,18 @@ impl CacheUpdate for GuildCreateEvent {
 
     async fn update(&mut self, cache: &Cache) -> Option<()> {
         cache.unavailable_guilds.write().await.remove(&self.guild.id);
+
+        if !cache.settings.read().await.should_cache_guild(self.guild.id) {
+            return None;
+        }
+
         let mut guild = self.guild.clone();
 
+        {
+            let settings = cache.settings.read().await;
+            guild.channels.retain(|id, _| settings.should_cache_channel(*id, None));
+        }
+
         for (user_id, member) in &mut guild.members {
             cache.update_user_entry(&member.user).await;
             if let Some(u) = cache.user(user_id).await {
@@ -410,6 +436,10 @@ impl CacheUpdate for GuildMemberAddEvent {
     type Output = ();
 
     async fn update(&mut self, cache: &Cache) -> Option<()> {
+        if !cache.settings.read().await.should_cache_guild(self.guild_id) {
+            return None;
+        }
+
         let user_id = self.member.user.id;
         cache.update_user_entry(&self.member.user).await;
         if let Some(u) = cache.user(user_id).await {
@@ -482,6 +512,10 @@ impl CacheUpdate for GuildMemberRemoveEvent {
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct GuildMemberUpdateEvent {
+    #[serde(default)]
+    pub avatar: Option<String>,
+    #[serde(default)]
+    pub communication_disabled_until: Option<DateTime<Utc>>,
     pub guild_id: GuildId,
     pub nick: Option<String>,
     pub roles: Vec<RoleId>,
@@ -494,6 +528,10 @@ impl CacheUpdate for GuildMemberUpdateEvent {
     type Output = Member;
 
     async fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        if !cache.settings.read().await.should_cache_guild(self.guild_id) {
+            return None;
+        }
+
         cache.update_user_entry(&self.user).await;
 
         if let Some(guild) = cache.guilds.write().await.get_mut(&self.guild_id) {
@@ -502,6 +540,8 @@ impl CacheUpdate for GuildMemberUpdateEvent {
             let item = if let Some(member) = guild.members.get_mut(&self.user.id) {
                 let item = Some(member.clone());
 
+                member.avatar.clone_from(&self.avatar);
+                member.communication_disabled_until = self.communication_disabled_until;
                 member.nick.clone_from(&self.nick);
                 member.roles.clone_from(&self.roles);
                 member.user.clone_from(&self.user);
@@ -517,6 +557,8 @@ impl CacheUpdate for GuildMemberUpdateEvent {
                 guild.members.insert(
                     self.user.id,
                     Member {
+                        avatar: self.avatar.clone(),
+                        communication_disabled_until: self.communication_disabled_until,
                         deaf: false,
                         guild_id: self.guild_id,
                         joined_at: None,
@@ -551,6 +593,10 @@ impl CacheUpdate for GuildMembersChunkEvent {
     type Output = ();
 
     async fn update(&mut self, cache: &Cache) -> Option<()> {
+        if !cache.settings.read().await.should_cache_guild(self.guild_id) {
+            return None;
+        }
+
         for member in self.members.values() {
             cache.update_user_entry(&member.user).await;
         }
@@ -749,6 +795,28 @@ impl<'de> Deserialize<'de> for GuildRoleUpdateEvent {
     }
 }
 
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct InteractionCreateEvent {
+    /// The interaction that was created.
+    pub interaction: Interaction,
+}
+
+impl<'de> Deserialize<'de> for InteractionCreateEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        Ok(Self {
+            interaction: Interaction::deserialize(deserializer)?,
+        })
+    }
+}
+
+impl Serialize for InteractionCreateEvent {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+        where S: Serializer {
+        Interaction::serialize(&self.interaction, serializer)
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct InviteCreateEvent {
@@ -836,6 +904,58 @@ pub struct MessageCreateEvent {
     pub message: Message,
 }
 
+#[cfg(feature = "cache")]
+impl MessageCreateEvent {
+    /// Refreshes the cached member of the author with the partial member
+    /// sent along with a guild message.
+    async fn update_member(&self, cache: &Cache) {
+        let (guild_id, partial) = match (self.message.guild_id, &self.message.member) {
+            (Some(guild_id), Some(partial)) => (guild_id, partial),
+            _ => return,
+        };
+
+        if !cache.settings.read().await.should_cache_guild(guild_id) {
+            return;
+        }
+
+        cache.update_user_entry(&self.message.author).await;
+
+        let mut guilds = cache.guilds.write().await;
+        let guild = match guilds.get_mut(&guild_id) {
+            Some(guild) => guild,
+            None => return,
+        };
+
+        let author = &self.message.author;
+
+        if let Some(member) = guild.members.get_mut(&author.id) {
+            // The partial member lacks the avatar and timeout, so keep the
+            // cached ones, along with the join date if it is missing.
+            member.deaf = partial.deaf;
+            member.mute = partial.mute;
+            member.nick.clone_from(&partial.nick);
+            member.roles.clone_from(&partial.roles);
+            member.user.clone_from(author);
+
+            if partial.joined_at.is_some() {
+                member.joined_at = partial.joined_at;
+            }
+        } else {
+            guild.members.insert(author.id, Member {
+                avatar: None,
+                communication_disabled_until: None,
+                deaf: partial.deaf,
+                guild_id,
+                joined_at: partial.joined_at,
+                mute: partial.mute,
+                nick: partial.nick.clone(),
+                roles: partial.roles.clone(),
+                user: author.clone(),
+            });
+        }
+    }
+}
+
 #[cfg(feature = "cache")]
 #[async_trait]
 impl CacheUpdate for MessageCreateEvent {
@@ -843,13 +963,22 @@ impl CacheUpdate for MessageCreateEvent {
     type Output = Message;
 
     async fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
-        let max = cache.settings().await.max_messages;
+        self.update_member(cache).await;
+
+        let max = {
+            let settings = cache.settings.read().await;
+
+            if !settings.should_cache_channel(self.message.channel_id, self.message.guild_id) {
+                return None;
+            }
+
+            settings.max_messages_for(self.message.channel_id)
+        };
 
         if max == 0 {
             return None;
         }
 
-
         let mut messages_map = cache.messages.write().await;
         let messages = messages_map.entry(self.message.channel_id).or_insert_with(Default::default);
         let mut message_queues = cache.message_queue.write().await;
@@ -860,15 +989,21 @@ impl CacheUpdate for MessageCreateEvent {
 
         let mut removed_msg = None;
 
-        if messages.len() == max {
-
-            if let Some(id) = queue.pop_front() {
-                removed_msg = messages.remove(&id);
+        // Evict the oldest messages until there is room for the new one, as
+        // the maximum may have been lowered since they were cached.
+        while messages.len() >= max {
+            match queue.pop_front() {
+                Some(id) => {
+                    let removed = messages.remove(&id);
+                    removed_msg = removed_msg.or(removed);
+                },
+                None => break,
             }
         }
 
         queue.push_back(self.message.id);
         messages.insert(self.message.id, self.message.clone());
+        cache.touch_message(self.message.channel_id, self.message.id).await;
 
         removed_msg
     }
@@ -924,6 +1059,8 @@ pub struct MessageUpdateEvent {
     pub mention_roles: Option<Vec<RoleId>>,
     pub attachments: Option<Vec<Attachment>>,
     pub embeds: Option<Vec<Value>>,
+    #[serde(default)]
+    pub components: Option<Vec<ActionRow>>,
 }
 
 #[cfg(feature = "cache")]
@@ -941,6 +1078,10 @@ impl CacheUpdate for MessageUpdateEvent {
                     message.attachments = attachments;
                 }
 
+                if let Some(components) = self.components.clone() {
+                    message.components = components;
+                }
+
                 if let Some(content) = self.content.clone() {
                     message.content = content;
                 }
@@ -986,6 +1127,12 @@ impl CacheUpdate for PresenceUpdateEvent {
     type Output = ();
 
     async fn update(&mut self, cache: &Cache) -> Option<()> {
+        if let Some(guild_id) = self.guild_id {
+            if !cache.settings.read().await.should_cache_guild(guild_id) {
+                return None;
+            }
+        }
+
         let user_id = self.presence.user_id;
 
         if let Some(user) = self.presence.user.as_mut() {
@@ -1011,6 +1158,8 @@ impl CacheUpdate for PresenceUpdateEvent {
                 if !guild.members.contains_key(&self.presence.user_id) {
                     if let Some(user) = self.presence.user.as_ref() {
                         guild.members.insert(self.presence.user_id, Member {
+                            avatar: None,
+                            communication_disabled_until: None,
                             deaf: false,
                             guild_id,
                             joined_at: None,
@@ -1177,7 +1326,10 @@ impl CacheUpdate for ReadyEvent {
                 },
                 GuildStatus::OnlineGuild(guild) => {
                     cache.unavailable_guilds.write().await.remove(&guild.id);
-                    cache.guilds.write().await.insert(guild.id, guild);
+
+                    if cache.settings.read().await.should_cache_guild(guild.id) {
+                        cache.guilds.write().await.insert(guild.id, guild);
+                    }
                 },
                 GuildStatus::OnlinePartialGuild(_) => {},
             }
@@ -1226,6 +1378,72 @@ pub struct ResumedEvent {
     #[serde(rename = "_trace")] pub trace: Vec<Option<String>>,
 }
 
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct StageInstanceCreateEvent {
+    /// The stage instance that was created.
+    pub stage_instance: StageInstance,
+}
+
+impl<'de> Deserialize<'de> for StageInstanceCreateEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        Ok(Self {
+            stage_instance: StageInstance::deserialize(deserializer)?,
+        })
+    }
+}
+
+impl Serialize for StageInstanceCreateEvent {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+        where S: Serializer {
+        StageInstance::serialize(&self.stage_instance, serializer)
+    }
+}
+
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct StageInstanceUpdateEvent {
+    /// The stage instance that was updated.
+    pub stage_instance: StageInstance,
+}
+
+impl<'de> Deserialize<'de> for StageInstanceUpdateEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        Ok(Self {
+            stage_instance: StageInstance::deserialize(deserializer)?,
+        })
+    }
+}
+
+impl Serialize for StageInstanceUpdateEvent {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+        where S: Serializer {
+        StageInstance::serialize(&self.stage_instance, serializer)
+    }
+}
+
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct StageInstanceDeleteEvent {
+    /// The stage instance that was deleted.
+    pub stage_instance: StageInstance,
+}
+
+impl<'de> Deserialize<'de> for StageInstanceDeleteEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        Ok(Self {
+            stage_instance: StageInstance::deserialize(deserializer)?,
+        })
+    }
+}
+
+impl Serialize for StageInstanceDeleteEvent {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+        where S: Serializer {
+        StageInstance::serialize(&self.stage_instance, serializer)
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct TypingStartEvent {
@@ -1501,6 +1719,12 @@ pub enum Event {
     /// When a guild is unavailable, such as due to a Discord server outage.
     GuildUnavailable(GuildUnavailableEvent),
     GuildUpdate(GuildUpdateEvent),
+    /// An [`Interaction`] was created.
+    ///
+    /// Fires the [`EventHandler::interaction_create`] event handler.
+    ///
+    /// [`EventHandler::interaction_create`]: crate::client::EventHandler::interaction_create
+    InteractionCreate(InteractionCreateEvent),
     /// An [`Invite`] was created.
     ///
     /// Fires the [`EventHandler::invite_create`] event handler.
@@ -1546,6 +1770,24 @@ pub enum Event {
     Ready(ReadyEvent),
     /// The connection has successfully resumed after a disconnect.
     Resumed(ResumedEvent),
+    /// A [`StageInstance`] was created.
+    ///
+    /// Fires the [`EventHandler::stage_instance_create`] event handler.
+    ///
+    /// [`EventHandler::stage_instance_create`]: crate::client::EventHandler::stage_instance_create
+    StageInstanceCreate(StageInstanceCreateEvent),
+    /// A [`StageInstance`] was updated.
+    ///
+    /// Fires the [`EventHandler::stage_instance_update`] event handler.
+    ///
+    /// [`EventHandler::stage_instance_update`]: crate::client::EventHandler::stage_instance_update
+    StageInstanceUpdate(StageInstanceUpdateEvent),
+    /// A [`StageInstance`] was deleted.
+    ///
+    /// Fires the [`EventHandler::stage_instance_delete`] event handler.
+    ///
+    /// [`EventHandler::stage_instance_delete`]: crate::client::EventHandler::stage_instance_delete
+    StageInstanceDelete(StageInstanceDeleteEvent),
     /// A user is typing; considered to last 5 seconds
     TypingStart(TypingStartEvent),
     /// Update to the logged-in user's information
@@ -1583,6 +1825,7 @@ impl Event {
             Self::GuildRoleUpdate(_) => EventType::GuildRoleUpdate,
             Self::GuildUnavailable(_) => EventType::GuildUnavailable,
             Self::GuildUpdate(_) => EventType::GuildUpdate,
+            Self::InteractionCreate(_) => EventType::InteractionCreate,
             Self::InviteCreate(_) => EventType::InviteCreate,
             Self::InviteDelete(_) => EventType::InviteDelete,
             Self::MessageCreate(_) => EventType::MessageCreate,
@@ -1596,6 +1839,9 @@ impl Event {
             Self::ReactionRemoveAll(_) => EventType::ReactionRemoveAll,
             Self::Ready(_) => EventType::Ready,
             Self::Resumed(_) => EventType::Resumed,
+            Self::StageInstanceCreate(_) => EventType::StageInstanceCreate,
+            Self::StageInstanceUpdate(_) => EventType::StageInstanceUpdate,
+            Self::StageInstanceDelete(_) => EventType::StageInstanceDelete,
             Self::TypingStart(_) => EventType::TypingStart,
             Self::UserUpdate(_) => EventType::UserUpdate,
             Self::VoiceStateUpdate(_) => EventType::VoiceStateUpdate,
@@ -1681,6 +1927,7 @@ pub fn deserialize_event_with_type(kind: EventType, v: Value) -> Result<Event> {
         EventType::GuildRoleUpdate => {
             Event::GuildRoleUpdate(serde_json::from_value(v)?)
         },
+        EventType::InteractionCreate => Event::InteractionCreate(serde_json::from_value(v)?),
         EventType::InviteCreate => Event::InviteCreate(serde_json::from_value(v)?),
         EventType::InviteDelete => Event::InviteDelete(serde_json::from_value(v)?),
         EventType::GuildUpdate => Event::GuildUpdate(serde_json::from_value(v)?),
@@ -1705,6 +1952,9 @@ pub fn deserialize_event_with_type(kind: EventType, v: Value) -> Result<Event> {
         },
         EventType::Ready => Event::Ready(serde_json::from_value(v)?),
         EventType::Resumed => Event::Resumed(serde_json::from_value(v)?),
+        EventType::StageInstanceCreate => Event::StageInstanceCreate(serde_json::from_value(v)?),
+        EventType::StageInstanceUpdate => Event::StageInstanceUpdate(serde_json::from_value(v)?),
+        EventType::StageInstanceDelete => Event::StageInstanceDelete(serde_json::from_value(v)?),
         EventType::TypingStart => Event::TypingStart(serde_json::from_value(v)?),
         EventType::UserUpdate => Event::UserUpdate(serde_json::from_value(v)?),
         EventType::VoiceServerUpdate => {
@@ -1807,6 +2057,10 @@ pub enum EventType {
     ///
     /// This maps to [`GuildUpdateEvent`].
     GuildUpdate,
+    /// Indicator that an interaction was created.
+    ///
+    /// This maps to [`InteractionCreateEvent`].
+    InteractionCreate,
     /// Indicator that an invite was created.
     ///
     /// This maps to [`InviteCreateEvent`].
@@ -1859,6 +2113,18 @@ pub enum EventType {
     ///
     /// This maps to [`ResumedEvent`].
     Resumed,
+    /// Indicator that a stage instance create payload was received.
+    ///
+    /// This maps to [`StageInstanceCreateEvent`].
+    StageInstanceCreate,
+    /// Indicator that a stage instance update payload was received.
+    ///
+    /// This maps to [`StageInstanceUpdateEvent`].
+    StageInstanceUpdate,
+    /// Indicator that a stage instance delete payload was received.
+    ///
+    /// This maps to [`StageInstanceDeleteEvent`].
+    StageInstanceDelete,
     /// Indicator that a typing start payload was received.
     ///
     /// This maps to [`TypingStartEvent`].
@@ -1910,6 +2176,7 @@ impl EventType {
     const GUILD_ROLE_CREATE: &'static str = "GUILD_ROLE_CREATE";
     const GUILD_ROLE_DELETE: &'static str = "GUILD_ROLE_DELETE";
     const GUILD_ROLE_UPDATE: &'static str = "GUILD_ROLE_UPDATE";
+    const INTERACTION_CREATE: &'static str = "INTERACTION_CREATE";
     const INVITE_CREATE: &'static str = "INVITE_CREATE";
     const INVITE_DELETE: &'static str = "INVITE_DELETE";
     const GUILD_UPDATE: &'static str = "GUILD_UPDATE";
@@ -1924,6 +2191,9 @@ impl EventType {
     const PRESENCES_REPLACE: &'static str = "PRESENCES_REPLACE";
     const READY: &'static str = "READY";
     const RESUMED: &'static str = "RESUMED";
+    const STAGE_INSTANCE_CREATE: &'static str = "STAGE_INSTANCE_CREATE";
+    const STAGE_INSTANCE_UPDATE: &'static str = "STAGE_INSTANCE_UPDATE";
+    const STAGE_INSTANCE_DELETE: &'static str = "STAGE_INSTANCE_DELETE";
     const TYPING_START: &'static str = "TYPING_START";
     const USER_UPDATE: &'static str = "USER_UPDATE";
     const VOICE_SERVER_UPDATE: &'static str = "VOICE_SERVER_UPDATE";
@@ -1952,6 +2222,7 @@ impl EventType {
             Self::GuildRoleCreate => Some(Self::GUILD_ROLE_CREATE),
             Self::GuildRoleDelete => Some(Self::GUILD_ROLE_DELETE),
             Self::GuildRoleUpdate => Some(Self::GUILD_ROLE_UPDATE),
+            Self::InteractionCreate => Some(Self::INTERACTION_CREATE),
             Self::InviteCreate => Some(Self::INVITE_CREATE),
             Self::InviteDelete => Some(Self::INVITE_DELETE),
             Self::GuildUpdate => Some(Self::GUILD_UPDATE),
@@ -1966,6 +2237,9 @@ impl EventType {
             Self::PresencesReplace => Some(Self::PRESENCES_REPLACE),
             Self::Ready => Some(Self::READY),
             Self::Resumed => Some(Self::RESUMED),
+            Self::StageInstanceCreate => Some(Self::STAGE_INSTANCE_CREATE),
+            Self::StageInstanceUpdate => Some(Self::STAGE_INSTANCE_UPDATE),
+            Self::StageInstanceDelete => Some(Self::STAGE_INSTANCE_DELETE),
             Self::TypingStart => Some(Self::TYPING_START),
             Self::UserUpdate => Some(Self::USER_UPDATE),
             Self::VoiceServerUpdate => Some(Self::VOICE_SERVER_UPDATE),
@@ -2013,6 +2287,7 @@ impl<'de> Deserialize<'de> for EventType {
                     EventType::GUILD_ROLE_CREATE => EventType::GuildRoleCreate,
                     EventType::GUILD_ROLE_DELETE => EventType::GuildRoleDelete,
                     EventType::GUILD_ROLE_UPDATE => EventType::GuildRoleUpdate,
+                    EventType::INTERACTION_CREATE => EventType::InteractionCreate,
                     EventType::INVITE_CREATE => EventType::InviteCreate,
                     EventType::INVITE_DELETE => EventType::InviteDelete,
                     EventType::GUILD_UPDATE => EventType::GuildUpdate,
@@ -2027,6 +2302,9 @@ impl<'de> Deserialize<'de> for EventType {
                     EventType::PRESENCES_REPLACE => EventType::PresencesReplace,
                     EventType::READY => EventType::Ready,
                     EventType::RESUMED => EventType::Resumed,
+                    EventType::STAGE_INSTANCE_CREATE => EventType::StageInstanceCreate,
+                    EventType::STAGE_INSTANCE_UPDATE => EventType::StageInstanceUpdate,
+                    EventType::STAGE_INSTANCE_DELETE => EventType::StageInstanceDelete,
                     EventType::TYPING_START => EventType::TypingStart,
                     EventType::USER_UPDATE => EventType::UserUpdate,
                     EventType::VOICE_SERVER_UPDATE => EventType::VoiceServerUpdate,
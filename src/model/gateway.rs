@@ -5,6 +5,15 @@ use serde::ser::{SerializeStruct, Serialize, Serializer};
 use super::utils::*;
 use super::prelude::*;
 use bitflags::bitflags;
+#[cfg(any(feature = "model", feature = "gateway"))]
+use url::Url;
+
+#[cfg(feature = "model")]
+use crate::builder::CreateActivity;
+#[cfg(feature = "model")]
+use crate::internal::prelude::*;
+#[cfg(feature = "model")]
+use crate::utils as serenity_utils;
 
 /// A representation of the data retrieved from the bot gateway endpoint.
 ///
@@ -123,7 +132,11 @@ impl Activity {
     /// Creates an `Activity` struct that appears as a `Streaming <name>`
     /// status.
     ///
-    /// **Note**: Maximum `name` length is 128.
+    /// **Note**: Maximum `name` length is 128. The `url` must be a Twitch or
+    /// YouTube URL, otherwise [`ShardMessenger::set_activities`] rejects the
+    /// activity and Discord shows it as playing instead.
+    ///
+    /// [`ShardMessenger::set_activities`]: crate::client::bridge::gateway::ShardMessenger::set_activities
     ///
     /// # Examples
     ///
@@ -217,6 +230,53 @@ impl Activity {
         }
     }
 
+    /// Creates a `Activity` struct that appears as a `Watching <name>` status.
+    ///
+    /// **Note**: Maximum `name` length is 128.
+    ///
+    /// # Examples
+    ///
+    /// Create a command that sets the current watching status:
+    ///
+    /// ```rust,no_run
+    /// use serenity::model::gateway::Activity;
+    /// use serenity::model::channel::Message;
+    /// # #[cfg(feature = "framework")]
+    /// use serenity::framework::standard::{Args, CommandResult, macros::command};
+    /// # #[cfg(feature = "client")]
+    /// use serenity::client::Context;
+    ///
+    /// # #[cfg(feature = "framework")]
+    /// #[command]
+    /// async fn watch(ctx: &Context, _msg: &Message, args: Args) -> CommandResult {
+    ///     let name = args.message();
+    ///     ctx.set_activity(Activity::watching(&name)).await;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn watching(name: &str) -> Activity {
+        Activity {
+            application_id: None,
+            assets: None,
+            details: None,
+            flags: None,
+            instance: None,
+            kind: ActivityType::Watching,
+            name: name.to_string(),
+            party: None,
+            secrets: None,
+            state: None,
+            emoji: None,
+            timestamps: None,
+            #[cfg(feature = "unstable")]
+            sync_id: None,
+            #[cfg(feature = "unstable")]
+            session_id: None,
+            url: None,
+        }
+    }
+
     /// Creates a `Activity` struct that appears as a `Competing in <name>` status.
     ///
     /// **Note**: Maximum `name` length is 128.
@@ -263,6 +323,105 @@ impl Activity {
             url: None,
         }
     }
+
+    /// Creates an `Activity` of any type, through a [`CreateActivity`] builder.
+    ///
+    /// A custom status without a name is given the `"Custom Status"` name
+    /// expected by Discord.
+    ///
+    /// # Examples
+    ///
+    /// Create an activity showing the bot as streaming on Twitch:
+    ///
+    /// ```rust
+    /// use serenity::model::gateway::{Activity, ActivityType};
+    ///
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let activity = Activity::create(|a| {
+    ///     a.kind(ActivityType::Streaming)
+    ///         .name("Speedruns")
+    ///         .url("https://www.twitch.tv/serenity")
+    /// })?;
+    ///
+    /// assert_eq!(activity.url.as_deref(), Some("https://www.twitch.tv/serenity"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidStreamUrl`] if a streaming activity does
+    /// not have a Twitch or YouTube URL.
+    ///
+    /// Returns a [`ModelError::NoActivityName`] if an activity other than a
+    /// custom status does not have a name.
+    pub fn create<F>(f: F) -> Result<Activity>
+    where F: FnOnce(&mut CreateActivity) -> &mut CreateActivity {
+        let mut builder = CreateActivity::default();
+        f(&mut builder);
+        let mut map = serenity_utils::hashmap_to_json_map(builder.0);
+
+        let kind = match map.get("type") {
+            Some(kind) => ActivityType::deserialize(kind.clone())?,
+            None => ActivityType::default(),
+        };
+
+        if kind == ActivityType::Streaming {
+            let url = map.get("url").and_then(Value::as_str);
+
+            if !url.iter().any(|url| is_stream_url(url)) {
+                return Err(Error::Model(ModelError::InvalidStreamUrl));
+            }
+        }
+
+        if !map.contains_key("name") {
+            if kind != ActivityType::Custom {
+                return Err(Error::Model(ModelError::NoActivityName));
+            }
+
+            map.insert("name".to_string(), Value::String("Custom Status".to_string()));
+        }
+
+        serde_json::from_value(Value::Object(map)).map_err(From::from)
+    }
+}
+
+#[cfg(any(feature = "model", feature = "gateway"))]
+impl Activity {
+    /// Checks that a streaming activity has a Twitch or YouTube URL.
+    pub(crate) fn check_stream_url(&self) -> crate::Result<()> {
+        let valid = match self.url {
+            Some(ref url) => is_stream_url(url),
+            None => false,
+        };
+
+        if self.kind == ActivityType::Streaming && !valid {
+            return Err(crate::Error::Model(super::ModelError::InvalidStreamUrl));
+        }
+
+        Ok(())
+    }
+}
+
+/// Whether the URL is a Twitch or YouTube URL, the only ones accepted by
+/// Discord for streaming activities.
+#[cfg(any(feature = "model", feature = "gateway"))]
+fn is_stream_url(url: &str) -> bool {
+    const HOSTS: &[&str] = &[
+        "twitch.tv",
+        "www.twitch.tv",
+        "youtube.com",
+        "www.youtube.com",
+        "m.youtube.com",
+    ];
+
+    match Url::parse(url) {
+        Ok(url) => {
+            matches!(url.scheme(), "http" | "https")
+                && url.host_str().iter().any(|host| HOSTS.contains(host))
+        },
+        Err(_) => false,
+    }
 }
 
 impl<'de> Deserialize<'de> for Activity {
@@ -443,6 +602,8 @@ pub enum ActivityType {
     Streaming = 1,
     /// An indicator that the user is listening to something.
     Listening = 2,
+    /// An indicator that the user is watching something.
+    Watching = 3,
     /// An indicator that the user uses custum statuses
     Custom = 4,
     /// An indicator that the user is competing somewhere.
@@ -454,6 +615,7 @@ enum_number!(
         Playing,
         Streaming,
         Listening,
+        Watching,
         Custom,
         Competing,
     }
@@ -467,6 +629,7 @@ impl ActivityType {
             Playing => 0,
             Streaming => 1,
             Listening => 2,
+            Watching => 3,
             Custom => 4,
             Competing => 5,
         }
@@ -639,3 +802,68 @@ pub struct ActivityTimestamps {
     pub end: Option<u64>,
     pub start: Option<u64>,
 }
+
+#[cfg(all(test, feature = "model"))]
+mod test {
+    use super::{Activity, ActivityType};
+    use crate::model::ModelError;
+    use crate::Error;
+
+    #[test]
+    fn streaming_requires_url() {
+        let missing = Activity::create(|a| a.kind(ActivityType::Streaming).name("Speedruns"));
+        assert!(matches!(missing, Err(Error::Model(ModelError::InvalidStreamUrl))));
+
+        let other_host = Activity::create(|a| {
+            a.kind(ActivityType::Streaming)
+                .name("Speedruns")
+                .url("https://example.com/twitch.tv")
+        });
+        assert!(matches!(other_host, Err(Error::Model(ModelError::InvalidStreamUrl))));
+
+        let twitch = Activity::create(|a| {
+            a.kind(ActivityType::Streaming)
+                .name("Speedruns")
+                .url("https://www.twitch.tv/serenity")
+        }).unwrap();
+        assert_eq!(twitch.kind, ActivityType::Streaming);
+        assert_eq!(twitch.url.as_deref(), Some("https://www.twitch.tv/serenity"));
+
+        let youtube = Activity::create(|a| {
+            a.kind(ActivityType::Streaming)
+                .name("Speedruns")
+                .url("https://youtube.com/watch?v=serenity")
+        });
+        assert!(youtube.is_ok());
+    }
+
+    #[test]
+    fn streaming_url_is_checked() {
+        let twitch = Activity::streaming("Speedruns", "https://twitch.tv/serenity");
+        assert!(twitch.check_stream_url().is_ok());
+
+        let other_host = Activity::streaming("Speedruns", "https://example.com/serenity");
+        assert!(matches!(other_host.check_stream_url(), Err(Error::Model(ModelError::InvalidStreamUrl))));
+
+        assert!(Activity::playing("Speedruns").check_stream_url().is_ok());
+    }
+
+    #[test]
+    fn watching_activity() {
+        let activity = Activity::create(|a| a.kind(ActivityType::Watching).name("over the guild")).unwrap();
+
+        assert_eq!(activity.kind, ActivityType::Watching);
+        assert_eq!(activity.name, "over the guild");
+        assert_eq!(serde_json::to_value(&activity).unwrap()["type"], 3);
+    }
+
+    #[test]
+    fn custom_status_name() {
+        let custom = Activity::create(|a| a.kind(ActivityType::Custom).state("Reading docs")).unwrap();
+        assert_eq!(custom.name, "Custom Status");
+        assert_eq!(custom.state.as_deref(), Some("Reading docs"));
+
+        let unnamed = Activity::create(|a| a.kind(ActivityType::Playing));
+        assert!(matches!(unnamed, Err(Error::Model(ModelError::NoActivityName))));
+    }
+}
@@ -3,7 +3,6 @@ use serde::de::{
     self,
     Deserialize,
     Deserializer,
-    MapAccess,
     Visitor
 };
 use serde::ser::Serializer;
@@ -30,7 +29,11 @@ pub enum Target {
 }
 
 /// Determines the action that was done on a target.
-#[derive(Debug)]
+///
+/// Used to filter the entries returned by [`GuildId::audit_logs`].
+///
+/// [`GuildId::audit_logs`]: crate::model::id::GuildId::audit_logs
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 #[non_exhaustive]
 pub enum Action {
     GuildUpdate,
@@ -43,6 +46,17 @@ pub enum Action {
     Emoji(ActionEmoji),
     Message(ActionMessage),
     Integration(ActionIntegration),
+    StageInstance(ActionStageInstance),
+    Sticker(ActionSticker),
+    ScheduledEvent(ActionScheduledEvent),
+    Thread(ActionThread),
+    ApplicationCommandPermissionUpdate,
+    AutoMod(ActionAutoMod),
+    CreatorMonetization(ActionCreatorMonetization),
+    Onboarding(ActionOnboarding),
+    HomeSettings(ActionHomeSettings),
+    /// An action type not known by the library yet.
+    Unknown(u8),
 }
 
 impl Action {
@@ -60,11 +74,21 @@ impl Action {
             Action::Emoji(ref x) => x.num(),
             Action::Message(ref x) => x.num(),
             Action::Integration(ref x) => x.num(),
+            Action::StageInstance(ref x) => x.num(),
+            Action::Sticker(ref x) => x.num(),
+            Action::ScheduledEvent(ref x) => x.num(),
+            Action::Thread(ref x) => x.num(),
+            ApplicationCommandPermissionUpdate => 121,
+            Action::AutoMod(ref x) => x.num(),
+            Action::CreatorMonetization(ref x) => x.num(),
+            Action::Onboarding(ref x) => x.num(),
+            Action::HomeSettings(ref x) => x.num(),
+            Unknown(x) => x,
         }
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 #[non_exhaustive]
 #[repr(u8)]
 pub enum ActionChannel {
@@ -83,7 +107,7 @@ impl ActionChannel {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 #[non_exhaustive]
 #[repr(u8)]
 pub enum ActionChannelOverwrite {
@@ -102,7 +126,7 @@ impl ActionChannelOverwrite {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 #[non_exhaustive]
 #[repr(u8)]
 pub enum ActionMember {
@@ -133,7 +157,7 @@ impl ActionMember {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 #[non_exhaustive]
 #[repr(u8)]
 pub enum ActionRole {
@@ -152,7 +176,7 @@ impl ActionRole {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 #[non_exhaustive]
 #[repr(u8)]
 pub enum ActionInvite {
@@ -171,7 +195,7 @@ impl ActionInvite {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 #[non_exhaustive]
 #[repr(u8)]
 pub enum ActionWebhook {
@@ -190,13 +214,13 @@ impl ActionWebhook {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 #[non_exhaustive]
 #[repr(u8)]
 pub enum ActionEmoji {
     Create = 60,
-    Delete = 61,
-    Update = 62,
+    Update = 61,
+    Delete = 62,
 }
 
 impl ActionEmoji {
@@ -209,7 +233,7 @@ impl ActionEmoji {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 #[non_exhaustive]
 #[repr(u8)]
 pub enum ActionMessage {
@@ -230,8 +254,7 @@ impl ActionMessage {
     }
 }
 
-
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 #[non_exhaustive]
 #[repr(u8)]
 pub enum ActionIntegration {
@@ -250,6 +273,164 @@ impl ActionIntegration {
     }
 }
 
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum ActionStageInstance {
+    Create = 83,
+    Update = 84,
+    Delete = 85,
+}
+
+impl ActionStageInstance {
+    pub fn num(&self) -> u8 {
+        match *self {
+            ActionStageInstance::Create => 83,
+            ActionStageInstance::Update => 84,
+            ActionStageInstance::Delete => 85,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum ActionSticker {
+    Create = 90,
+    Update = 91,
+    Delete = 92,
+}
+
+impl ActionSticker {
+    pub fn num(&self) -> u8 {
+        match *self {
+            ActionSticker::Create => 90,
+            ActionSticker::Update => 91,
+            ActionSticker::Delete => 92,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum ActionScheduledEvent {
+    Create = 100,
+    Update = 101,
+    Delete = 102,
+}
+
+impl ActionScheduledEvent {
+    pub fn num(&self) -> u8 {
+        match *self {
+            ActionScheduledEvent::Create => 100,
+            ActionScheduledEvent::Update => 101,
+            ActionScheduledEvent::Delete => 102,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum ActionThread {
+    Create = 110,
+    Update = 111,
+    Delete = 112,
+}
+
+impl ActionThread {
+    pub fn num(&self) -> u8 {
+        match *self {
+            ActionThread::Create => 110,
+            ActionThread::Update => 111,
+            ActionThread::Delete => 112,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum ActionAutoMod {
+    RuleCreate = 140,
+    RuleUpdate = 141,
+    RuleDelete = 142,
+    BlockMessage = 143,
+    FlagToChannel = 144,
+    UserCommunicationDisabled = 145,
+}
+
+impl ActionAutoMod {
+    pub fn num(&self) -> u8 {
+        match *self {
+            ActionAutoMod::RuleCreate => 140,
+            ActionAutoMod::RuleUpdate => 141,
+            ActionAutoMod::RuleDelete => 142,
+            ActionAutoMod::BlockMessage => 143,
+            ActionAutoMod::FlagToChannel => 144,
+            ActionAutoMod::UserCommunicationDisabled => 145,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum ActionCreatorMonetization {
+    RequestCreated = 150,
+    TermsAccepted = 151,
+}
+
+impl ActionCreatorMonetization {
+    pub fn num(&self) -> u8 {
+        match *self {
+            ActionCreatorMonetization::RequestCreated => 150,
+            ActionCreatorMonetization::TermsAccepted => 151,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum ActionOnboarding {
+    PromptCreate = 163,
+    PromptUpdate = 164,
+    PromptDelete = 165,
+    Create = 166,
+    Update = 167,
+}
+
+impl ActionOnboarding {
+    pub fn num(&self) -> u8 {
+        match *self {
+            ActionOnboarding::PromptCreate => 163,
+            ActionOnboarding::PromptUpdate => 164,
+            ActionOnboarding::PromptDelete => 165,
+            ActionOnboarding::Create => 166,
+            ActionOnboarding::Update => 167,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum ActionHomeSettings {
+    Create = 190,
+    Update = 191,
+}
+
+impl ActionHomeSettings {
+    pub fn num(&self) -> u8 {
+        match *self {
+            ActionHomeSettings::Create => 190,
+            ActionHomeSettings::Update => 191,
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct Change {
     #[serde(rename = "key")] pub name: String,
@@ -262,8 +443,25 @@ pub struct Change {
 #[non_exhaustive]
 pub struct AuditLogs {
     pub entries: HashMap<AuditLogEntryId, AuditLogEntry>,
+    /// The webhooks referenced in the entries.
     pub webhooks: Vec<Webhook>,
+    /// The users referenced in the entries.
     pub users: Vec<User>,
+    /// The integrations referenced in the entries.
+    pub integrations: Vec<AuditLogIntegration>,
+}
+
+/// A partial [`Integration`] referenced by an [`AuditLogEntry`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct AuditLogIntegration {
+    pub id: IntegrationId,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub account: IntegrationAccount,
+    /// The Id of the application of a bot or OAuth2 integration.
+    pub application_id: Option<ApplicationId>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -318,6 +516,19 @@ pub struct Options {
     /// Name of the role if type is "role"
     #[serde(default)]
     pub role_name: Option<String>,
+    /// Id of the message that was pinned or unpinned.
+    #[serde(default)]
+    pub message_id: Option<MessageId>,
+    /// Id of the application whose command permissions were updated.
+    #[serde(default)]
+    pub application_id: Option<ApplicationId>,
+    /// Name of the auto moderation rule that was triggered.
+    #[serde(default)]
+    pub auto_moderation_rule_name: Option<String>,
+    /// Type of the integration which performed the action, such as
+    /// `"discord"` for a bot.
+    #[serde(default)]
+    pub integration_type: Option<String>,
 }
 
 mod option_u64_handler {
@@ -370,7 +581,7 @@ mod action_handler {
             type Value = Action;
 
             fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-                formatter.write_str("an integer between 1 to 72")
+                formatter.write_str("an audit log action type")
             }
 
             // NOTE: Serde internally delegates number types below `u64` to it.
@@ -379,16 +590,25 @@ mod action_handler {
 
                 Ok(match value {
                     1 => Action::GuildUpdate,
-                    10..=12 => Action::Channel(unsafe { transmute(value) }),
-                    13..=15 => Action::ChannelOverwrite(unsafe { transmute(value) }),
-                    20..=28 => Action::Member(unsafe { transmute(value) }),
-                    30..=32 => Action::Role(unsafe { transmute(value) }),
-                    40..=42 => Action::Invite(unsafe { transmute(value) }),
-                    50..=52 => Action::Webhook(unsafe { transmute(value) }),
-                    60..=62 => Action::Emoji(unsafe { transmute(value) }),
-                    72..=75 => Action::Message(unsafe { transmute(value) }),
-                    80..=82 => Action::Integration(unsafe { transmute(value) }),
-                    _ => return Err(E::custom(format!("Unexpected action number: {}", value))),
+                    10..=12 => Action::Channel(unsafe { transmute::<u8, ActionChannel>(value) }),
+                    13..=15 => Action::ChannelOverwrite(unsafe { transmute::<u8, ActionChannelOverwrite>(value) }),
+                    20..=28 => Action::Member(unsafe { transmute::<u8, ActionMember>(value) }),
+                    30..=32 => Action::Role(unsafe { transmute::<u8, ActionRole>(value) }),
+                    40..=42 => Action::Invite(unsafe { transmute::<u8, ActionInvite>(value) }),
+                    50..=52 => Action::Webhook(unsafe { transmute::<u8, ActionWebhook>(value) }),
+                    60..=62 => Action::Emoji(unsafe { transmute::<u8, ActionEmoji>(value) }),
+                    72..=75 => Action::Message(unsafe { transmute::<u8, ActionMessage>(value) }),
+                    80..=82 => Action::Integration(unsafe { transmute::<u8, ActionIntegration>(value) }),
+                    83..=85 => Action::StageInstance(unsafe { transmute::<u8, ActionStageInstance>(value) }),
+                    90..=92 => Action::Sticker(unsafe { transmute::<u8, ActionSticker>(value) }),
+                    100..=102 => Action::ScheduledEvent(unsafe { transmute::<u8, ActionScheduledEvent>(value) }),
+                    110..=112 => Action::Thread(unsafe { transmute::<u8, ActionThread>(value) }),
+                    121 => Action::ApplicationCommandPermissionUpdate,
+                    140..=145 => Action::AutoMod(unsafe { transmute::<u8, ActionAutoMod>(value) }),
+                    150..=151 => Action::CreatorMonetization(unsafe { transmute::<u8, ActionCreatorMonetization>(value) }),
+                    163..=167 => Action::Onboarding(unsafe { transmute::<u8, ActionOnboarding>(value) }),
+                    190..=191 => Action::HomeSettings(unsafe { transmute::<u8, ActionHomeSettings>(value) }),
+                    _ => Action::Unknown(value),
                 })
             }
         }
@@ -403,81 +623,120 @@ mod action_handler {
         serializer.serialize_u8(action.num())
     }
 }
+
 impl<'de> Deserialize<'de> for AuditLogs {
     fn deserialize<D: Deserializer<'de>>(de: D) -> StdResult<Self, D::Error> {
         #[derive(Deserialize)]
-        #[serde(field_identifier)]
-        enum Field {
-            #[serde(rename = "audit_log_entries")] Entries,
-            #[serde(rename = "webhooks")] Webhooks,
-            #[serde(rename = "users")] Users,
-            // TODO(field added by Discord, undocumented) #[serde(rename = "integrations")] Integrations,
+        struct RawAuditLogs {
+            audit_log_entries: Vec<AuditLogEntry>,
+            #[serde(default)]
+            webhooks: Vec<Webhook>,
+            #[serde(default)]
+            users: Vec<User>,
+            #[serde(default)]
+            integrations: Vec<AuditLogIntegration>,
         }
 
-        struct EntriesVisitor;
-
-        impl<'de> Visitor<'de> for EntriesVisitor {
-            type Value = AuditLogs;
-
-            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-                formatter.write_str("audit log entries")
-            }
+        let raw = RawAuditLogs::deserialize(de)?;
+
+        Ok(AuditLogs {
+            entries: raw.audit_log_entries
+                .into_iter()
+                .map(|entry| (entry.id, entry))
+                .collect(),
+            webhooks: raw.webhooks,
+            users: raw.users,
+            integrations: raw.integrations,
+        })
+    }
+}
 
-            fn visit_map<V: MapAccess<'de>>(self, mut map: V) -> StdResult<AuditLogs, V::Error> {
-                let mut audit_log_entries = None;
-                let mut users = None;
-                let mut webhooks = None;
-
-                loop {
-                    match map.next_key() {
-                        Ok(Some(Field::Entries)) => {
-                            if audit_log_entries.is_some() {
-                                return Err(de::Error::duplicate_field("entries"));
-                            }
-
-                            audit_log_entries = Some(map.next_value::<Vec<AuditLogEntry>>()?);
-                        }
-                        Ok(Some(Field::Webhooks)) => {
-                            if webhooks.is_some() {
-                                return Err(de::Error::duplicate_field("webhooks"));
-                            }
-
-                            webhooks = Some(map.next_value::<Vec<Webhook>>()?);
-                        }
-                        Ok(Some(Field::Users)) => {
-                            if users.is_some() {
-                                return Err(de::Error::duplicate_field("users"));
-                            }
-
-                            users = Some(map.next_value::<Vec<User>>()?);
-                        }
-                        Ok(None) => break, // No more keys
-                        Err(e) => if e.to_string().contains("unknown field") {
-                            // e is of type <V as MapAccess>::Error, which is a macro-defined trait, ultimately
-                            // implemented by serde::de::value::Error. Seeing as every error is a simple string and not
-                            // using a proper Error num, the best we can do here is to check if the string contains
-                            // this error. This was added because Discord randomly started sending new fields.
-                            // But no JSON deserializer should ever error over this.
-                            map.next_value::<serde_json::Value>()?; // Actually read the value to avoid syntax errors
-                        } else {
-                            return Err(e)
-                        }
-                    }
-                }
-
-                Ok(AuditLogs {
-                    entries: audit_log_entries
-                        .unwrap()
-                        .into_iter()
-                        .map(|entry| (entry.id, entry))
-                        .collect(),
-                    webhooks: webhooks.unwrap(),
-                    users: users.unwrap(),
-                })
-            }
-        }
+#[cfg(test)]
+mod test {
+    use crate::model::prelude::*;
+    use serde_json::json;
+
+    #[test]
+    fn deserialize_ban_add() {
+        let value = json!({
+            "audit_log_entries": [{
+                "id": "1030000000000000000",
+                "action_type": 22,
+                "target_id": "1020000000000000000",
+                "user_id": "1010000000000000000",
+                "reason": "Spamming",
+                "changes": null,
+                "options": null,
+            }],
+            "users": [{
+                "id": "1010000000000000000",
+                "username": "moderator",
+                "discriminator": "0001",
+                "avatar": null,
+            }],
+            "webhooks": [],
+            "integrations": [{
+                "id": "1040000000000000000",
+                "name": "Bot",
+                "type": "discord",
+                "account": {"id": "1050000000000000000", "name": "Bot"},
+                "application_id": "1050000000000000000",
+            }],
+            "threads": [],
+        });
+
+        let logs = serde_json::from_value::<AuditLogs>(value).unwrap();
+        let entry = &logs.entries[&AuditLogEntryId(1030000000000000000)];
+
+        assert_eq!(entry.action, Action::Member(ActionMember::BanAdd));
+        assert_eq!(entry.target_id, Some(1020000000000000000));
+        assert_eq!(entry.user_id, UserId(1010000000000000000));
+        assert_eq!(entry.reason.as_deref(), Some("Spamming"));
+        assert_eq!(logs.users[0].name, "moderator");
+        assert_eq!(logs.integrations[0].application_id, Some(ApplicationId(1050000000000000000)));
+    }
 
-        const FIELD: &[&str] = &["audit_log_entries"];
-        de.deserialize_struct("AuditLogs", FIELD, EntriesVisitor)
+    #[test]
+    fn deserialize_channel_update() {
+        let value = json!({
+            "audit_log_entries": [{
+                "id": "1030000000000000000",
+                "action_type": 11,
+                "target_id": "1020000000000000000",
+                "user_id": "1010000000000000000",
+                "changes": [
+                    {"key": "name", "old_value": "general", "new_value": "chat"},
+                    {"key": "rate_limit_per_user", "old_value": 0, "new_value": 5},
+                ],
+            }, {
+                "id": "1030000000000000001",
+                "action_type": 61,
+                "target_id": "1020000000000000001",
+                "user_id": "1010000000000000000",
+            }, {
+                "id": "1030000000000000002",
+                "action_type": 250,
+                "target_id": null,
+                "user_id": "1010000000000000000",
+            }],
+        });
+
+        let logs = serde_json::from_value::<AuditLogs>(value).unwrap();
+        let entry = &logs.entries[&AuditLogEntryId(1030000000000000000)];
+        let changes = entry.changes.as_ref().unwrap();
+
+        assert_eq!(entry.action, Action::Channel(ActionChannel::Update));
+        assert_eq!(entry.action.num(), 11);
+        assert_eq!(changes[0].name, "name");
+        assert_eq!(changes[0].new, Some(json!("chat")));
+        assert_eq!(changes[1].old, Some(json!(0)));
+        assert!(logs.users.is_empty());
+
+        let emoji = &logs.entries[&AuditLogEntryId(1030000000000000001)];
+        assert_eq!(emoji.action, Action::Emoji(ActionEmoji::Update));
+
+        let unknown = &logs.entries[&AuditLogEntryId(1030000000000000002)];
+        assert_eq!(unknown.action, Action::Unknown(250));
+        assert_eq!(unknown.action.num(), 250);
     }
 }
@@ -0,0 +1,257 @@
+use crate::model::prelude::*;
+
+/// A rule of a guild's auto moderation, which acts on content matching its
+/// trigger.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct AutoModRule {
+    /// The Id of the rule.
+    pub id: AutoModRuleId,
+    /// The Id of the guild the rule belongs to.
+    pub guild_id: GuildId,
+    /// The name of the rule.
+    pub name: String,
+    /// The Id of the user that created the rule.
+    pub creator_id: UserId,
+    /// The event checked by the rule.
+    pub event_type: AutoModEventType,
+    /// The kind of content that triggers the rule.
+    pub trigger_type: AutoModTriggerType,
+    /// Additional data used to determine whether the rule is triggered.
+    #[serde(default)]
+    pub trigger_metadata: AutoModTriggerMetadata,
+    /// The actions executed when the rule is triggered.
+    pub actions: Vec<AutoModAction>,
+    /// Whether the rule is enabled.
+    pub enabled: bool,
+    /// The roles that are not affected by the rule.
+    #[serde(default)]
+    pub exempt_roles: Vec<RoleId>,
+    /// The channels that are not affected by the rule.
+    #[serde(default)]
+    pub exempt_channels: Vec<ChannelId>,
+}
+
+/// Additional data of an [`AutoModRule`], depending on its trigger type.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct AutoModTriggerMetadata {
+    /// The words that trigger a [`AutoModTriggerType::Keyword`] rule.
+    #[serde(default)]
+    pub keyword_filter: Vec<String>,
+    /// The regular expressions that trigger a [`AutoModTriggerType::Keyword`]
+    /// rule.
+    #[serde(default)]
+    pub regex_patterns: Vec<String>,
+    /// The word lists used by a [`AutoModTriggerType::KeywordPreset`] rule.
+    #[serde(default)]
+    pub presets: Vec<AutoModKeywordPresetType>,
+    /// The words that are exempt from the rule.
+    #[serde(default)]
+    pub allow_list: Vec<String>,
+    /// The number of unique user and role mentions allowed per message by a
+    /// [`AutoModTriggerType::MentionSpam`] rule.
+    pub mention_total_limit: Option<u64>,
+}
+
+/// An action executed when an [`AutoModRule`] is triggered.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct AutoModAction {
+    /// The type of the action.
+    #[serde(rename = "type")]
+    pub kind: AutoModActionType,
+    /// Additional data of the action, depending on its type.
+    #[serde(default)]
+    pub metadata: AutoModActionMetadata,
+}
+
+/// Additional data of an [`AutoModAction`].
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct AutoModActionMetadata {
+    /// The channel the alert is sent to by a
+    /// [`AutoModActionType::SendAlertMessage`] action.
+    pub channel_id: Option<ChannelId>,
+    /// The duration of the timeout of a [`AutoModActionType::Timeout`] action,
+    /// in seconds.
+    pub duration_seconds: Option<u64>,
+}
+
+/// The event checked by an [`AutoModRule`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum AutoModEventType {
+    /// A member sends or edits a message.
+    MessageSend = 1,
+}
+
+enum_number!(
+    AutoModEventType {
+        MessageSend,
+    }
+);
+
+impl AutoModEventType {
+    pub fn num(self) -> u64 {
+        match self {
+            AutoModEventType::MessageSend => 1,
+        }
+    }
+}
+
+/// The kind of content that triggers an [`AutoModRule`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum AutoModTriggerType {
+    /// The content contains words of the [`keyword_filter`].
+    ///
+    /// [`keyword_filter`]: AutoModTriggerMetadata::keyword_filter
+    Keyword = 1,
+    /// The content is considered spam.
+    Spam = 3,
+    /// The content contains words of the [`presets`].
+    ///
+    /// [`presets`]: AutoModTriggerMetadata::presets
+    KeywordPreset = 4,
+    /// The content contains more mentions than the [`mention_total_limit`].
+    ///
+    /// [`mention_total_limit`]: AutoModTriggerMetadata::mention_total_limit
+    MentionSpam = 5,
+}
+
+enum_number!(
+    AutoModTriggerType {
+        Keyword,
+        Spam,
+        KeywordPreset,
+        MentionSpam,
+    }
+);
+
+impl AutoModTriggerType {
+    pub fn num(self) -> u64 {
+        match self {
+            AutoModTriggerType::Keyword => 1,
+            AutoModTriggerType::Spam => 3,
+            AutoModTriggerType::KeywordPreset => 4,
+            AutoModTriggerType::MentionSpam => 5,
+        }
+    }
+}
+
+/// A word list maintained by Discord, used by
+/// [`AutoModTriggerType::KeywordPreset`] rules.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum AutoModKeywordPresetType {
+    /// Swearing or cursing words.
+    Profanity = 1,
+    /// Sexually explicit words.
+    SexualContent = 2,
+    /// Personal insults or hate speech.
+    Slurs = 3,
+}
+
+enum_number!(
+    AutoModKeywordPresetType {
+        Profanity,
+        SexualContent,
+        Slurs,
+    }
+);
+
+impl AutoModKeywordPresetType {
+    pub fn num(self) -> u64 {
+        match self {
+            AutoModKeywordPresetType::Profanity => 1,
+            AutoModKeywordPresetType::SexualContent => 2,
+            AutoModKeywordPresetType::Slurs => 3,
+        }
+    }
+}
+
+/// The type of an [`AutoModAction`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum AutoModActionType {
+    /// Blocks the message from being sent.
+    BlockMessage = 1,
+    /// Sends an alert to a channel.
+    SendAlertMessage = 2,
+    /// Times out the author of the message.
+    Timeout = 3,
+}
+
+enum_number!(
+    AutoModActionType {
+        BlockMessage,
+        SendAlertMessage,
+        Timeout,
+    }
+);
+
+impl AutoModActionType {
+    pub fn num(self) -> u64 {
+        match self {
+            AutoModActionType::BlockMessage => 1,
+            AutoModActionType::SendAlertMessage => 2,
+            AutoModActionType::Timeout => 3,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::model::prelude::*;
+    use serde_json::json;
+
+    #[test]
+    fn deserialize_keyword_rule() {
+        let value = json!({
+            "id": "969707018069872670",
+            "guild_id": "613425648685547541",
+            "name": "Keyword Filter",
+            "creator_id": "423457898095789043",
+            "trigger_type": 1,
+            "event_type": 1,
+            "actions": [
+                {
+                    "type": 1,
+                    "metadata": {}
+                },
+                {
+                    "type": 3,
+                    "metadata": {
+                        "duration_seconds": 60
+                    }
+                }
+            ],
+            "trigger_metadata": {
+                "keyword_filter": ["cat*", "*dog", "*ana*"],
+                "regex_patterns": []
+            },
+            "enabled": true,
+            "exempt_roles": ["323456789123456789"],
+            "exempt_channels": []
+        });
+
+        let rule = serde_json::from_value::<AutoModRule>(value).unwrap();
+
+        assert_eq!(rule.id, AutoModRuleId(969707018069872670));
+        assert_eq!(rule.guild_id, GuildId(613425648685547541));
+        assert_eq!(rule.creator_id, UserId(423457898095789043));
+        assert_eq!(rule.event_type, AutoModEventType::MessageSend);
+        assert_eq!(rule.trigger_type, AutoModTriggerType::Keyword);
+        assert_eq!(rule.trigger_metadata.keyword_filter, ["cat*", "*dog", "*ana*"]);
+        assert!(rule.trigger_metadata.presets.is_empty());
+        assert_eq!(rule.trigger_metadata.mention_total_limit, None);
+        assert_eq!(rule.actions[0].kind, AutoModActionType::BlockMessage);
+        assert_eq!(rule.actions[1].kind, AutoModActionType::Timeout);
+        assert_eq!(rule.actions[1].metadata.duration_seconds, Some(60));
+        assert_eq!(rule.actions[1].metadata.channel_id, None);
+        assert!(rule.enabled);
+        assert_eq!(rule.exempt_roles, [RoleId(323456789123456789)]);
+        assert!(rule.exempt_channels.is_empty());
+    }
+}
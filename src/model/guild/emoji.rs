@@ -1,23 +1,35 @@
-use std::fmt::{
-    Display,
-    Formatter,
-    Result as FmtResult,
-    Write as FmtWrite
+use std::{
+    error::Error as StdError,
+    fmt::{
+        Display,
+        Formatter,
+        Result as FmtResult,
+        Write as FmtWrite
+    },
+    result::Result as StdResult,
+    str::FromStr
 };
+use crate::model::channel::ReactionType;
 use crate::model::id::{EmojiId, RoleId};
 
 #[cfg(all(feature = "cache", feature = "model"))]
-use serde_json::json;
+use crate::builder::EditEmoji;
 #[cfg(all(feature = "cache", feature = "model"))]
+use crate::utils;
+#[cfg(feature = "model")]
 use crate::internal::prelude::*;
-#[cfg(all(feature = "cache", feature = "model"))]
+#[cfg(feature = "model")]
 use crate::model::ModelError;
-#[cfg(all(feature = "cache", feature = "model"))]
+#[cfg(feature = "model")]
 use crate::model::id::GuildId;
 #[cfg(all(feature = "cache", feature = "model"))]
 use crate::cache::Cache;
-#[cfg(all(feature = "cache", feature = "model"))]
-use crate::http::CacheHttp;
+#[cfg(feature = "model")]
+use crate::http::{CacheHttp, GuildPagination, Http};
+#[cfg(feature = "model")]
+use reqwest::StatusCode;
+#[cfg(feature = "model")]
+use crate::model::id::{ChannelId, MessageId};
 
 /// Represents a custom guild emoji, which can either be created using the API,
 /// or via an integration. Emojis created using the API only work within the
@@ -97,22 +109,37 @@ impl Emoji {
         }
     }
 
-    /// Edits the emoji by updating it with a new name.
+    /// Edits the emoji, optionally setting its new name and the roles that
+    /// are allowed to use it.
+    ///
+    /// Refer to the documentation for [`EditEmoji`] for a full list of methods.
     ///
     /// **Note**: The [Manage Emojis] permission is required.
     ///
     /// **Note**: Only user accounts may use this method.
     ///
+    /// # Examples
+    ///
+    /// Restrict the usage of an emoji to a single role:
+    ///
+    /// ```rust,ignore
+    /// # use serenity::model::id::RoleId;
+    /// // assuming an `emoji` has already been bound
+    /// emoji.edit(&ctx, |e| e.roles(&[RoleId(7)])).await?;
+    /// ```
+    ///
     /// [Manage Emojis]: crate::model::permissions::Permissions::MANAGE_EMOJIS
     #[cfg(feature = "cache")]
-    pub async fn edit(&mut self, cache_http: impl CacheHttp, name: &str) -> Result<()> {
+    pub async fn edit<F>(&mut self, cache_http: impl CacheHttp, f: F) -> Result<()>
+    where F: FnOnce(&mut EditEmoji) -> &mut EditEmoji
+    {
         let cache = cache_http.cache().ok_or(Error::Model(ModelError::ItemMissing))?;
 
         match self.find_guild_id(&cache).await {
             Some(guild_id) => {
-                let map = json!({
-                    "name": name,
-                });
+                let mut edit_emoji = EditEmoji::default();
+                f(&mut edit_emoji);
+                let map = Value::Object(utils::hashmap_to_json_map(edit_emoji.0));
 
                 *self = cache_http
                     .http()
@@ -168,6 +195,76 @@ impl Emoji {
         None
     }
 
+    /// Creates a [`ReactionType`] which can be used to react with the emoji.
+    #[inline]
+    pub fn reaction_type(&self) -> ReactionType {
+        ReactionType::from(self)
+    }
+
+    /// Reacts to a [`Message`] with the emoji.
+    ///
+    /// **Note**: Requires the [Add Reactions] permission.
+    ///
+    /// [`Message`]: crate::model::channel::Message
+    /// [Add Reactions]: crate::model::permissions::Permissions::ADD_REACTIONS
+    #[inline]
+    pub async fn react(
+        &self,
+        http: impl AsRef<Http>,
+        channel_id: impl Into<ChannelId>,
+        message_id: impl Into<MessageId>,
+    ) -> Result<()> {
+        http.as_ref()
+            .create_reaction(channel_id.into().0, message_id.into().0, &self.reaction_type())
+            .await
+    }
+
+    /// Finds the [`Guild`] that owns the emoji, first by looking through the
+    /// Cache and then, if it could not be found there, through the REST API.
+    ///
+    /// The REST API lookup goes through each guild the current user is in and
+    /// stops at the first one which owns the emoji. As this may take one
+    /// request per guild, prefer [`find_guild_id`] when the guilds are known
+    /// to be cached.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if a request fails for another reason than
+    /// the emoji not being in a guild.
+    ///
+    /// [`Guild`]: super::Guild
+    /// [`find_guild_id`]: Self::find_guild_id
+    pub async fn find_guild_id_http(&self, cache_http: impl CacheHttp) -> Result<Option<GuildId>> {
+        #[cfg(feature = "cache")]
+        {
+            if let Some(cache) = cache_http.cache() {
+                if let Some(guild_id) = self.find_guild_id(cache).await {
+                    return Ok(Some(guild_id));
+                }
+            }
+        }
+
+        let http = cache_http.http();
+        let mut target = GuildPagination::After(GuildId(0));
+
+        loop {
+            let guilds = http.get_guilds(&target, 100).await?;
+
+            for guild in &guilds {
+                match http.get_emoji(guild.id.0, self.id.0).await {
+                    Ok(_) => return Ok(Some(guild.id)),
+                    Err(Error::Http(why)) if why.status_code() == Some(StatusCode::NOT_FOUND) => {},
+                    Err(why) => return Err(why),
+                }
+            }
+
+            match guilds.last() {
+                Some(last) if guilds.len() == 100 => target = GuildPagination::After(last.id),
+                _ => return Ok(None),
+            }
+        }
+    }
+
     /// Generates a URL to the emoji's image.
     ///
     /// # Examples
@@ -200,6 +297,23 @@ impl Emoji {
         let extension = if self.animated {"gif"} else {"png"};
         format!(cdn!("/emojis/{}.{}"), self.id, extension)
     }
+
+    /// Generates a URL to the emoji's image, requesting the given size.
+    ///
+    /// The size is rounded up to the next power of two, as those are the only
+    /// sizes accepted by Discord's CDN.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidImageSize`] if the size is lower than 16
+    /// or greater than 4096.
+    pub fn url_with_size(&self, size: u16) -> Result<String> {
+        if !(16..=4096).contains(&size) {
+            return Err(Error::Model(ModelError::InvalidImageSize(size)));
+        }
+
+        Ok(format!("{}?size={}", self.url(), size.next_power_of_two()))
+    }
 }
 
 impl Display for Emoji {
@@ -221,6 +335,69 @@ impl Display for Emoji {
     }
 }
 
+/// An error returned when parsing an [`Emoji`] from its mention format fails.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum EmojiParseError {
+    /// The mention is not surrounded by angle brackets.
+    MissingBrackets,
+    /// The mention is not in the `<:NAME:EMOJI_ID>` or `<a:NAME:EMOJI_ID>`
+    /// format.
+    InvalidFormat,
+    /// The Id of the mention is not a valid number.
+    InvalidId,
+}
+
+impl Display for EmojiParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            EmojiParseError::MissingBrackets => f.write_str("emoji mention is missing its angle brackets"),
+            EmojiParseError::InvalidFormat => f.write_str("invalid emoji mention format"),
+            EmojiParseError::InvalidId => f.write_str("invalid emoji id"),
+        }
+    }
+}
+
+impl StdError for EmojiParseError {}
+
+impl FromStr for Emoji {
+    type Err = EmojiParseError;
+
+    /// Parses an emoji from the format used by its [`Display`] implementation,
+    /// either `<:NAME:EMOJI_ID>` or `<a:NAME:EMOJI_ID>`.
+    ///
+    /// As this information is not part of the mention, the emoji is assumed
+    /// not to be managed, to require colons, and to be usable by every role.
+    fn from_str(s: &str) -> StdResult<Self, Self::Err> {
+        let inner = s
+            .strip_prefix('<')
+            .and_then(|s| s.strip_suffix('>'))
+            .ok_or(EmojiParseError::MissingBrackets)?;
+
+        let (animated, inner) = match inner.strip_prefix("a:") {
+            Some(rest) => (true, rest),
+            None => (false, inner.strip_prefix(':').ok_or(EmojiParseError::InvalidFormat)?),
+        };
+
+        let mut split = inner.splitn(2, ':');
+        let name = split.next().filter(|name| !name.is_empty()).ok_or(EmojiParseError::InvalidFormat)?;
+        let id = split
+            .next()
+            .ok_or(EmojiParseError::InvalidFormat)?
+            .parse::<u64>()
+            .map_err(|_| EmojiParseError::InvalidId)?;
+
+        Ok(Emoji {
+            animated,
+            id: EmojiId(id),
+            name: name.to_string(),
+            managed: false,
+            require_colons: true,
+            roles: Vec::new(),
+        })
+    }
+}
+
 impl From<Emoji> for EmojiId {
     /// Gets the Id of an `Emoji`.
     fn from(emoji: Emoji) -> EmojiId { emoji.id }
@@ -230,3 +407,138 @@ impl<'a> From<&'a Emoji> for EmojiId {
     /// Gets the Id of an `Emoji`.
     fn from(emoji: &Emoji) -> EmojiId { emoji.id }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{Emoji, EmojiParseError};
+    use crate::model::id::EmojiId;
+
+    #[test]
+    fn from_str_round_trip() {
+        for &animated in &[false, true] {
+            let emoji = Emoji {
+                animated,
+                id: EmojiId(12345),
+                name: "blobface".to_string(),
+                managed: false,
+                require_colons: true,
+                roles: vec![],
+            };
+
+            let parsed = emoji.to_string().parse::<Emoji>().unwrap();
+
+            assert_eq!(parsed.animated, emoji.animated);
+            assert_eq!(parsed.id, emoji.id);
+            assert_eq!(parsed.name, emoji.name);
+            assert_eq!(parsed.managed, emoji.managed);
+            assert_eq!(parsed.require_colons, emoji.require_colons);
+            assert_eq!(parsed.roles, emoji.roles);
+        }
+    }
+
+    #[test]
+    fn from_str_errors() {
+        assert_eq!(":blobface:12345".parse::<Emoji>().unwrap_err(), EmojiParseError::MissingBrackets);
+        assert_eq!("<blobface:12345>".parse::<Emoji>().unwrap_err(), EmojiParseError::InvalidFormat);
+        assert_eq!("<:blobface>".parse::<Emoji>().unwrap_err(), EmojiParseError::InvalidFormat);
+        assert_eq!("<::12345>".parse::<Emoji>().unwrap_err(), EmojiParseError::InvalidFormat);
+        assert_eq!("<:blobface:abc>".parse::<Emoji>().unwrap_err(), EmojiParseError::InvalidId);
+    }
+
+    #[cfg(feature = "model")]
+    mod model {
+        use crate::model::prelude::*;
+        use crate::model::ModelError;
+        use crate::Error;
+
+        fn gen() -> Emoji {
+            Emoji {
+                animated: false,
+                id: EmojiId(7),
+                name: "blobface".to_string(),
+                managed: false,
+                require_colons: true,
+                roles: vec![],
+            }
+        }
+
+        #[test]
+        fn url_with_size() {
+            let emoji = gen();
+
+            assert!(emoji.url_with_size(16).unwrap().ends_with("/emojis/7.png?size=16"));
+            assert!(emoji.url_with_size(4096).unwrap().ends_with("/emojis/7.png?size=4096"));
+            assert!(emoji.url_with_size(100).unwrap().ends_with("/emojis/7.png?size=128"));
+
+            match emoji.url_with_size(5000) {
+                Err(Error::Model(ModelError::InvalidImageSize(5000))) => {},
+                other => panic!("unexpected result: {:?}", other),
+            }
+        }
+
+        #[test]
+        fn reaction_type() {
+            for &animated in &[false, true] {
+                let mut emoji = gen();
+                emoji.animated = animated;
+
+                let reaction_type = emoji.reaction_type();
+
+                assert_eq!(reaction_type, ReactionType::Custom {
+                    animated,
+                    id: EmojiId(7),
+                    name: Some("blobface".to_string()),
+                });
+                assert_eq!(reaction_type.as_data(), "blobface:7");
+                assert_eq!(ReactionType::from(emoji).as_data(), "blobface:7");
+            }
+        }
+
+        #[tokio::test]
+        async fn find_guild_id_http() {
+            use crate::http::Http;
+            use http_crate::response::Builder;
+            use serde_json::json;
+            use std::sync::Arc;
+
+            fn http(forbidden_guild: u64) -> Http {
+                let mut http = Http::new_with_token("token");
+                http.ratelimiter.responder = Some(Arc::new(move |request| {
+                    let path = request.url().path().to_string();
+
+                    let (status, body) = if path == "/api/v8/users/@me/guilds" {
+                        let guilds = (1..=3).map(|id| json!({
+                            "id": id.to_string(),
+                            "icon": null,
+                            "name": format!("guild {}", id),
+                            "owner": false,
+                            "permissions": "0",
+                        })).collect::<Vec<_>>();
+
+                        (200, json!(guilds))
+                    } else if path == format!("/api/v8/guilds/{}/emojis/7", forbidden_guild) {
+                        (403, json!({"code": 50001, "message": "Missing Access"}))
+                    } else if path == "/api/v8/guilds/2/emojis/7" {
+                        (200, serde_json::to_value(gen()).unwrap())
+                    } else {
+                        (404, json!({"code": 10014, "message": "Unknown Emoji"}))
+                    };
+
+                    let response = Builder::new().status(status).body(serde_json::to_vec(&body).unwrap()).unwrap();
+                    reqwest::Response::from(response)
+                }));
+
+                http
+            }
+
+            // Missing emojis are skipped until the guild owning the emoji.
+            assert_eq!(gen().find_guild_id_http(Arc::new(http(0))).await.unwrap(), Some(GuildId(2)));
+
+            // Other errors are not mistaken for a missing emoji.
+            match gen().find_guild_id_http(Arc::new(http(1))).await {
+                Err(Error::Http(why)) => assert_eq!(why.status_code(), Some(reqwest::StatusCode::FORBIDDEN)),
+                other => panic!("unexpected result: {:?}", other),
+            }
+        }
+    }
+}
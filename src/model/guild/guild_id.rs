@@ -3,7 +3,22 @@ use crate::model::prelude::*;
 #[cfg(all(feature = "cache", feature = "model"))]
 use crate::cache::Cache;
 #[cfg(feature = "model")]
-use crate::builder::{EditGuild, EditMember, EditRole};
+use crate::builder::{
+    CreateApplicationCommand,
+    CreateApplicationCommands,
+    CreateScheduledEvent,
+    CreateSticker,
+    EditAutoModRule,
+    EditGuild,
+    EditGuildTemplate,
+    EditGuildWelcomeScreen,
+    EditGuildWidget,
+    EditMember,
+    EditOnboarding,
+    EditRole,
+    EditScheduledEvent,
+    EditSticker,
+};
 #[cfg(feature = "model")]
 use crate::internal::prelude::*;
 #[cfg(feature = "model")]
@@ -12,7 +27,7 @@ use crate::utils;
 use crate::builder::CreateChannel;
 #[cfg(feature = "model")]
 use serde_json::json;
-#[cfg(feature = "cache")]
+#[cfg(feature = "model")]
 use futures::stream::Stream;
 #[cfg(feature = "collector")]
 use crate::client::bridge::gateway::ShardMessenger;
@@ -22,7 +37,9 @@ use crate::collector::{
     CollectReaction, ReactionCollectorBuilder,
 };
 #[cfg(feature = "model")]
-use crate::http::{Http, CacheHttp};
+use crate::http::{Http, CacheHttp, UserPagination};
+#[cfg(feature = "model")]
+use crate::http::routing::Route;
 
 #[cfg(feature = "model")]
 impl GuildId {
@@ -88,6 +105,40 @@ impl GuildId {
         http.as_ref().ban_user(self.0, user.0, dmd, reason).await
     }
 
+    /// Bans multiple [`User`]s from the guild at once, deleting the messages
+    /// they sent in the last `delete_message_seconds` seconds.
+    ///
+    /// Up to 200 users can be banned at once. The returned [`BulkBanResponse`]
+    /// lists which users were banned and which could not be.
+    ///
+    /// **Note**: Requires the [Ban Members] and [Manage Guild] permissions.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::BulkBanAmount`] if no users or more than 200
+    /// users are given, and a [`ModelError::DeleteMessageSecondsAmount`] if
+    /// `delete_message_seconds` is over 604800, which is 7 days.
+    ///
+    /// [Ban Members]: Permissions::BAN_MEMBERS
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn bulk_ban(
+        self,
+        http: impl AsRef<Http>,
+        user_ids: &[UserId],
+        delete_message_seconds: u32,
+        reason: Option<&str>,
+    ) -> Result<BulkBanResponse> {
+        if let Some(reason) = reason {
+            if reason.len() > 512 {
+                return Err(Error::ExceededLimit(reason.to_string(), 512));
+            }
+        }
+
+        let map = bulk_ban_map(user_ids, delete_message_seconds)?;
+
+        http.as_ref().bulk_ban_users(self.0, &map, reason).await
+    }
+
     /// Gets a list of the guild's bans.
     ///
     /// Requires the [Ban Members] permission.
@@ -98,17 +149,181 @@ impl GuildId {
         http.as_ref().get_bans(self.0).await
     }
 
-    /// Gets a list of the guild's audit log entries
+    /// Gets a list of the guild's audit log entries, most recent first.
+    ///
+    /// The entries can be filtered by the type of their `action_type`, or by
+    /// the `user_id` of their author. Only the entries before the `before`
+    /// entry are returned, up to `limit` entries. The limit is clamped between
+    /// 1 and 100, and defaults to 50.
+    ///
+    /// Requires the [View Audit Log] permission.
+    ///
+    /// # Examples
+    ///
+    /// Get the last 10 bans of the guild:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use serenity::model::guild::{Action, ActionMember};
+    /// # use serenity::model::id::GuildId;
+    /// #
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let http = Http::default();
+    /// let action = Action::Member(ActionMember::BanAdd);
+    /// let logs = GuildId(7).audit_logs(&http, Some(action), None, None, Some(10)).await?;
+    ///
+    /// for entry in logs.entries.values() {
+    ///     println!("{:?} was banned: {:?}", entry.target_id, entry.reason);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [View Audit Log]: Permissions::VIEW_AUDIT_LOG
+    /// [`Error::Http`]: crate::error::Error::Http
     #[inline]
     pub async fn audit_logs(
         self,
         http: impl AsRef<Http>,
-        action_type: Option<u8>,
+        action_type: Option<Action>,
         user_id: Option<UserId>,
         before: Option<AuditLogEntryId>,
         limit: Option<u8>
     ) -> Result<AuditLogs> {
-        http.as_ref().get_audit_logs(self.0, action_type, user_id.map(|u| u.0), before.map(|a| a.0), limit).await
+        let limit = match limit {
+            Some(0) => Some(1),
+            Some(limit) if limit > 100 => Some(100),
+            limit => limit,
+        };
+
+        http.as_ref().get_audit_logs(
+            self.0,
+            action_type.map(|a| a.num()),
+            user_id.map(|u| u.0),
+            before.map(|a| a.0),
+            limit,
+        ).await
+    }
+
+    /// Gets the auto moderation rules of the guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn automod_rules(self, http: impl AsRef<Http>) -> Result<Vec<AutoModRule>> {
+        http.as_ref().get_automod_rules(self.0).await
+    }
+
+    /// Gets an auto moderation rule of the guild by its Id.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn automod_rule(self, http: impl AsRef<Http>, rule_id: impl Into<AutoModRuleId>) -> Result<AutoModRule> {
+        http.as_ref().get_automod_rule(self.0, rule_id.into().0).await
+    }
+
+    /// Creates an application command available only in the guild.
+    ///
+    /// Refer to the documentation of [`CreateApplicationCommand`] for a full
+    /// list of methods.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidCommandOptions`] if the options are
+    /// nested incorrectly.
+    ///
+    /// Returns an [`Error::Http`] if the command is invalid, or if the
+    /// application was not authorized in the guild with the
+    /// `applications.commands` scope.
+    ///
+    /// [`Error::Http`]: crate::error::Error::Http
+    pub async fn create_application_command<F>(
+        self,
+        http: impl AsRef<Http>,
+        application_id: impl Into<ApplicationId>,
+        f: F,
+    ) -> Result<ApplicationCommand>
+    where F: FnOnce(&mut CreateApplicationCommand) -> &mut CreateApplicationCommand {
+        let mut command = CreateApplicationCommand::default();
+        f(&mut command);
+        let command = command.build();
+
+        ApplicationCommand::check_options(&command)?;
+
+        http.as_ref().create_guild_application_command(application_id.into().0, self.0, &command).await
+    }
+
+    /// Overwrites all the application commands of the application in the
+    /// guild with the given ones.
+    ///
+    /// Refer to the documentation of [`CreateApplicationCommands`] for a full
+    /// list of methods.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidCommandOptions`] if the options of a
+    /// command are nested incorrectly.
+    ///
+    /// Returns an [`Error::Http`] if a command is invalid.
+    ///
+    /// [`Error::Http`]: crate::error::Error::Http
+    pub async fn set_application_commands<F>(
+        self,
+        http: impl AsRef<Http>,
+        application_id: impl Into<ApplicationId>,
+        f: F,
+    ) -> Result<Vec<ApplicationCommand>>
+    where F: FnOnce(&mut CreateApplicationCommands) -> &mut CreateApplicationCommands {
+        let mut commands = CreateApplicationCommands::default();
+        f(&mut commands);
+
+        for command in &commands.0 {
+            ApplicationCommand::check_options(command)?;
+        }
+
+        let commands = Value::Array(commands.0);
+
+        http.as_ref().create_guild_application_commands(application_id.into().0, self.0, &commands).await
+    }
+
+    /// Gets all the application commands of the application in the guild.
+    #[inline]
+    pub async fn get_application_commands(
+        self,
+        http: impl AsRef<Http>,
+        application_id: impl Into<ApplicationId>,
+    ) -> Result<Vec<ApplicationCommand>> {
+        http.as_ref().get_guild_application_commands(application_id.into().0, self.0).await
+    }
+
+    /// Gets an application command of the application in the guild by its
+    /// Id.
+    #[inline]
+    pub async fn get_application_command(
+        self,
+        http: impl AsRef<Http>,
+        application_id: impl Into<ApplicationId>,
+        command_id: impl Into<CommandId>,
+    ) -> Result<ApplicationCommand> {
+        http.as_ref().get_guild_application_command(application_id.into().0, self.0, command_id.into().0).await
+    }
+
+    /// Deletes an application command of the application in the guild.
+    #[inline]
+    pub async fn delete_application_command(
+        self,
+        http: impl AsRef<Http>,
+        application_id: impl Into<ApplicationId>,
+        command_id: impl Into<CommandId>,
+    ) -> Result<()> {
+        http.as_ref().delete_guild_application_command(application_id.into().0, self.0, command_id.into().0).await
     }
 
     /// Gets all of the guild's channels over the REST API.
@@ -127,6 +342,37 @@ impl GuildId {
         Ok(channels)
     }
 
+    /// Gets all of the guild's channels over the REST API, grouped by category
+    /// in the order of the client's channel list.
+    ///
+    /// The categories are sorted by position, and their channels by position
+    /// with the voice and stage channels after the other channels. The first
+    /// group, with no category, holds the channels outside of any category.
+    /// Threads are not included.
+    pub async fn channels_ordered(self, http: impl AsRef<Http>) -> Result<Vec<(Option<GuildChannel>, Vec<GuildChannel>)>> {
+        let channels = http.as_ref().get_channels(self.0).await?;
+
+        Ok(order_channels(channels))
+    }
+
+    /// Creates an auto moderation rule in the guild.
+    ///
+    /// Refer to the documentation of [`EditAutoModRule`] for the required
+    /// fields.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn create_automod_rule<F>(self, http: impl AsRef<Http>, f: F) -> Result<AutoModRule>
+    where F: FnOnce(&mut EditAutoModRule) -> &mut EditAutoModRule {
+        let mut edit_automod_rule = EditAutoModRule::default();
+        f(&mut edit_automod_rule);
+        let map = utils::hashmap_to_json_map(edit_automod_rule.0);
+
+        http.as_ref().create_automod_rule(self.0, &map).await
+    }
+
     /// Creates a [`GuildChannel`] in the the guild.
     ///
     /// Refer to [`Http::create_channel`] for more information.
@@ -223,6 +469,66 @@ impl GuildId {
         Ok(role)
     }
 
+    /// Creates a new [`ScheduledEvent`] in the guild with the data set.
+    ///
+    /// Refer to the documentation for [`CreateScheduledEvent`] for the
+    /// required fields.
+    ///
+    /// **Note**: Requires the Manage Events permission.
+    #[inline]
+    pub async fn create_scheduled_event<F>(self, http: impl AsRef<Http>, f: F) -> Result<ScheduledEvent>
+    where F: FnOnce(&mut CreateScheduledEvent) -> &mut CreateScheduledEvent {
+        let mut create_scheduled_event = CreateScheduledEvent::default();
+        f(&mut create_scheduled_event);
+        let map = utils::hashmap_to_json_map(create_scheduled_event.0);
+
+        http.as_ref().create_scheduled_event(self.0, &map).await
+    }
+
+    /// Creates a new [`Sticker`] in the guild with the data set.
+    ///
+    /// Refer to the documentation for [`CreateSticker`] for the required
+    /// fields.
+    ///
+    /// Requires the [Manage Emojis] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::NoStickerFileSet`] if no file was set.
+    ///
+    /// [Manage Emojis]: Permissions::MANAGE_EMOJIS
+    pub async fn create_sticker<'a, F>(self, http: impl AsRef<Http>, f: F) -> Result<Sticker>
+    where for <'b> F: FnOnce(&'b mut CreateSticker<'a>) -> &'b mut CreateSticker<'a> {
+        let mut create_sticker = CreateSticker::default();
+        f(&mut create_sticker);
+
+        let file = match create_sticker.1 {
+            Some(file) => file,
+            None => return Err(Error::Model(ModelError::NoStickerFileSet)),
+        };
+        let map = utils::hashmap_to_json_map(create_sticker.0);
+
+        http.as_ref().create_sticker(self.0, map, file).await
+    }
+
+    /// Creates a [`GuildTemplate`] from the current state of the guild.
+    ///
+    /// Refer to the documentation of [`EditGuildTemplate`] for a full list of
+    /// methods. The name of the template is required.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn create_template<F>(self, http: impl AsRef<Http>, f: F) -> Result<GuildTemplate>
+    where F: FnOnce(&mut EditGuildTemplate) -> &mut EditGuildTemplate {
+        let mut create_template = EditGuildTemplate::default();
+        f(&mut create_template);
+        let map = utils::hashmap_to_json_map(create_template.0);
+
+        http.as_ref().create_guild_template(self.0, &map).await
+    }
+
     /// Deletes the current guild if the current account is the owner of the
     /// guild.
     ///
@@ -234,6 +540,16 @@ impl GuildId {
         http.as_ref().delete_guild(self.0).await
     }
 
+    /// Deletes an auto moderation rule of the guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn delete_automod_rule(self, http: impl AsRef<Http>, rule_id: impl Into<AutoModRuleId>) -> Result<()> {
+        http.as_ref().delete_automod_rule(self.0, rule_id.into().0).await
+    }
+
     /// Deletes an [`Emoji`] from the guild.
     ///
     /// Requires the [Manage Emojis] permission.
@@ -244,6 +560,20 @@ impl GuildId {
         http.as_ref().delete_emoji(self.0, emoji_id.into().0).await
     }
 
+    /// Deletes an [`Emoji`] from the guild with a reason, shown in the audit
+    /// log. Refer to [`delete_emoji`] for further documentation.
+    ///
+    /// [`delete_emoji`]: Self::delete_emoji
+    #[inline]
+    pub async fn delete_emoji_with_reason(
+        self,
+        http: impl AsRef<Http>,
+        emoji_id: impl Into<EmojiId>,
+        reason: impl AsRef<str>,
+    ) -> Result<()> {
+        http.as_ref().delete_emoji_with_reason(self.0, emoji_id.into().0, reason.as_ref()).await
+    }
+
     /// Deletes an integration by Id from the guild.
     ///
     /// Requires the [Manage Guild] permission.
@@ -267,6 +597,34 @@ impl GuildId {
         http.as_ref().delete_role(self.0, role_id.into().0).await
     }
 
+    /// Deletes a [`ScheduledEvent`] from the guild.
+    ///
+    /// **Note**: Requires the Manage Events permission.
+    #[inline]
+    pub async fn delete_scheduled_event(self, http: impl AsRef<Http>, event_id: impl Into<ScheduledEventId>) -> Result<()> {
+        http.as_ref().delete_scheduled_event(self.0, event_id.into().0).await
+    }
+
+    /// Deletes a [`Sticker`] from the guild.
+    ///
+    /// Requires the [Manage Emojis] permission.
+    ///
+    /// [Manage Emojis]: Permissions::MANAGE_EMOJIS
+    #[inline]
+    pub async fn delete_sticker(self, http: impl AsRef<Http>, sticker_id: impl Into<StickerId>) -> Result<()> {
+        http.as_ref().delete_sticker(self.0, sticker_id.into().0).await
+    }
+
+    /// Deletes a template of the guild by code, returning it.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn delete_template(self, http: impl AsRef<Http>, code: &str) -> Result<GuildTemplate> {
+        http.as_ref().delete_guild_template(self.0, code).await
+    }
+
     /// Edits the current guild with new data where specified.
     ///
     /// Refer to [`Guild::edit`] for more information.
@@ -286,6 +644,29 @@ impl GuildId {
         http.as_ref().edit_guild(self.0, &map).await
     }
 
+    /// Edits an auto moderation rule of the guild, setting its new fields.
+    ///
+    /// Refer to the documentation of [`EditAutoModRule`] for a full list of
+    /// methods.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn edit_automod_rule<F>(
+        self,
+        http: impl AsRef<Http>,
+        rule_id: impl Into<AutoModRuleId>,
+        f: F,
+    ) -> Result<AutoModRule>
+    where F: FnOnce(&mut EditAutoModRule) -> &mut EditAutoModRule {
+        let mut edit_automod_rule = EditAutoModRule::default();
+        f(&mut edit_automod_rule);
+        let map = utils::hashmap_to_json_map(edit_automod_rule.0);
+
+        http.as_ref().edit_automod_rule(self.0, rule_id.into().0, &map).await
+    }
+
     /// Edits an [`Emoji`]'s name in the guild.
     ///
     /// Also see [`Emoji::edit`] if you have the `cache` and `methods` features
@@ -338,6 +719,32 @@ impl GuildId {
         http.as_ref().edit_nickname(self.0, new_nickname).await
     }
 
+    /// Edits the guild's onboarding, replacing all of its prompts.
+    ///
+    /// Refer to the documentation of [`EditOnboarding`] for a full list of
+    /// methods.
+    ///
+    /// **Note**: Requires the [Manage Guild] and [Manage Roles] permissions.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidOnboardingPrompt`] if a prompt has no
+    /// options, is required without being shown during the onboarding, or is
+    /// both required and single-select with a single option.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    /// [Manage Roles]: Permissions::MANAGE_ROLES
+    pub async fn edit_onboarding<F>(self, http: impl AsRef<Http>, f: F) -> Result<GuildOnboarding>
+    where F: FnOnce(&mut EditOnboarding) -> &mut EditOnboarding {
+        let mut edit_onboarding = EditOnboarding::default();
+        f(&mut edit_onboarding);
+        let map = utils::hashmap_to_json_map(edit_onboarding.0);
+
+        check_onboarding_prompts(&map)?;
+
+        http.as_ref().edit_guild_onboarding(self.0, &map).await
+    }
+
     /// Edits a [`Role`], optionally setting its new fields.
     ///
     /// Requires the [Manage Roles] permission.
@@ -356,12 +763,29 @@ impl GuildId {
     #[inline]
     pub async fn edit_role<F>(self, http: impl AsRef<Http>, role_id: impl Into<RoleId>, f: F) -> Result<Role>
     where F: FnOnce(&mut EditRole) -> &mut EditRole
+    {
+        self.edit_role_with_reason(http, role_id, "", f).await
+    }
+
+    /// Edits a [`Role`] with a reason, shown in the audit log. Refer to
+    /// [`edit_role`] for further documentation.
+    ///
+    /// [`edit_role`]: Self::edit_role
+    #[inline]
+    pub async fn edit_role_with_reason<F>(
+        self,
+        http: impl AsRef<Http>,
+        role_id: impl Into<RoleId>,
+        reason: impl AsRef<str>,
+        f: F,
+    ) -> Result<Role>
+    where F: FnOnce(&mut EditRole) -> &mut EditRole
     {
         let mut edit_role = EditRole::default();
         f(&mut edit_role);
         let map = utils::hashmap_to_json_map(edit_role.0);
 
-        http.as_ref().edit_role(self.0, role_id.into().0, &map).await
+        http.as_ref().edit_role_with_reason(self.0, role_id.into().0, &map, reason.as_ref()).await
     }
 
     /// Edits the order of [`Role`]s
@@ -382,6 +806,134 @@ impl GuildId {
         http.as_ref().edit_role_position(self.0, role_id.into().0, position).await
     }
 
+    /// Edits the positions of multiple [`Role`]s in a single request, such as
+    /// when moving a role shifts the ones around it.
+    ///
+    /// Returns all the roles of the guild, with their new positions.
+    ///
+    /// Requires the [Manage Roles] permission.
+    ///
+    /// # Examples
+    ///
+    /// Swap the positions of two roles:
+    ///
+    /// ```rust,no_run
+    /// use serenity::model::id::{GuildId, RoleId};
+    ///
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use serenity::http::Http;
+    /// # let http = Http::default();
+    /// GuildId(7).edit_role_positions(&http, &[(RoleId(8), 2), (RoleId(9), 1)]).await?;
+    /// #    Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::DuplicateRolePosition`] if multiple roles are
+    /// moved to the same position.
+    ///
+    /// [Manage Roles]: Permissions::MANAGE_ROLES
+    pub async fn edit_role_positions(self, http: impl AsRef<Http>, positions: &[(RoleId, i64)]) -> Result<Vec<Role>> {
+        let map = role_positions_map(positions)?;
+
+        http.as_ref().edit_role_positions(self.0, &map).await
+    }
+
+    /// Edits a [`ScheduledEvent`] of the guild, optionally setting its new
+    /// fields.
+    ///
+    /// Refer to the documentation for [`EditScheduledEvent`] for a full list
+    /// of methods.
+    ///
+    /// **Note**: Requires the Manage Events permission.
+    #[inline]
+    pub async fn edit_scheduled_event<F>(
+        self,
+        http: impl AsRef<Http>,
+        event_id: impl Into<ScheduledEventId>,
+        f: F,
+    ) -> Result<ScheduledEvent>
+    where F: FnOnce(&mut EditScheduledEvent) -> &mut EditScheduledEvent {
+        let mut edit_scheduled_event = EditScheduledEvent::default();
+        f(&mut edit_scheduled_event);
+        let map = utils::hashmap_to_json_map(edit_scheduled_event.0);
+
+        http.as_ref().edit_scheduled_event(self.0, event_id.into().0, &map).await
+    }
+
+    /// Edits a [`Sticker`] of the guild, optionally setting its new fields.
+    ///
+    /// Refer to the documentation for [`EditSticker`] for a full list of
+    /// methods.
+    ///
+    /// Requires the [Manage Emojis] permission.
+    ///
+    /// [Manage Emojis]: Permissions::MANAGE_EMOJIS
+    #[inline]
+    pub async fn edit_sticker<F>(self, http: impl AsRef<Http>, sticker_id: impl Into<StickerId>, f: F) -> Result<Sticker>
+    where F: FnOnce(&mut EditSticker) -> &mut EditSticker {
+        let mut edit_sticker = EditSticker::default();
+        f(&mut edit_sticker);
+        let map = utils::hashmap_to_json_map(edit_sticker.0);
+
+        http.as_ref().edit_sticker(self.0, sticker_id.into().0, &map).await
+    }
+
+    /// Edits the name or description of a template of the guild.
+    ///
+    /// Refer to the documentation of [`EditGuildTemplate`] for a full list of
+    /// methods.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn edit_template<F>(self, http: impl AsRef<Http>, code: &str, f: F) -> Result<GuildTemplate>
+    where F: FnOnce(&mut EditGuildTemplate) -> &mut EditGuildTemplate {
+        let mut edit_template = EditGuildTemplate::default();
+        f(&mut edit_template);
+        let map = utils::hashmap_to_json_map(edit_template.0);
+
+        http.as_ref().edit_guild_template(self.0, code, &map).await
+    }
+
+    /// Edits the guild's welcome screen.
+    ///
+    /// Refer to the documentation of [`EditGuildWelcomeScreen`] for a full
+    /// list of methods.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn edit_welcome_screen<F>(self, http: impl AsRef<Http>, f: F) -> Result<GuildWelcomeScreen>
+    where F: FnOnce(&mut EditGuildWelcomeScreen) -> &mut EditGuildWelcomeScreen {
+        let mut edit_welcome_screen = EditGuildWelcomeScreen::default();
+        f(&mut edit_welcome_screen);
+        let map = utils::hashmap_to_json_map(edit_welcome_screen.0);
+
+        http.as_ref().edit_guild_welcome_screen(self.0, &map).await
+    }
+
+    /// Edits the settings of the guild's widget.
+    ///
+    /// Refer to the documentation of [`EditGuildWidget`] for a full list of
+    /// methods.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn edit_widget<F>(self, http: impl AsRef<Http>, f: F) -> Result<GuildWidget>
+    where F: FnOnce(&mut EditGuildWidget) -> &mut EditGuildWidget {
+        let mut edit_widget = EditGuildWidget::default();
+        f(&mut edit_widget);
+        let map = utils::hashmap_to_json_map(edit_widget.0);
+
+        http.as_ref().edit_guild_widget(self.0, &map).await
+    }
+
     /// Tries to find the [`Guild`] by its Id in the cache.
     #[cfg(feature = "cache")]
     #[inline]
@@ -398,6 +950,16 @@ impl GuildId {
         http.as_ref().get_guild(self.0).await
     }
 
+    /// Requests [`PartialGuild`] over REST API, including the
+    /// [`approximate_member_count`] and [`approximate_presence_count`].
+    ///
+    /// [`approximate_member_count`]: PartialGuild::approximate_member_count
+    /// [`approximate_presence_count`]: PartialGuild::approximate_presence_count
+    #[inline]
+    pub async fn to_partial_guild_with_counts(self, http: impl AsRef<Http>) -> Result<PartialGuild> {
+        http.as_ref().get_guild_with_counts(self.0).await
+    }
+
     /// Gets all [`Emoji`]s of this guild via HTTP.
     #[inline]
     pub async fn emojis(&self, http: impl AsRef<Http>) -> Result<Vec<Emoji>> {
@@ -410,6 +972,18 @@ impl GuildId {
         http.as_ref().get_emoji(self.0, emoji_id.0).await
     }
 
+    /// Gets all [`Sticker`]s of this guild via HTTP.
+    #[inline]
+    pub async fn stickers(self, http: impl AsRef<Http>) -> Result<Vec<Sticker>> {
+        http.as_ref().get_guild_stickers(self.0).await
+    }
+
+    /// Gets a [`Sticker`] of this guild by its ID via HTTP.
+    #[inline]
+    pub async fn sticker(self, http: impl AsRef<Http>, sticker_id: impl Into<StickerId>) -> Result<Sticker> {
+        http.as_ref().get_guild_sticker(self.0, sticker_id.into().0).await
+    }
+
     /// Gets all integration of the guild.
     ///
     /// This performs a request over the REST API.
@@ -514,7 +1088,6 @@ impl GuildId {
     /// ```
     ///
     /// [`members`]: Self::members
-    #[cfg(feature = "cache")]
     pub fn members_iter<H: AsRef<Http>>(self, http: H) -> impl Stream<Item=Result<Member>> {
         MembersIter::<H>::stream(http, self)
     }
@@ -531,13 +1104,7 @@ impl GuildId {
         user_id: impl Into<UserId>,
         channel_id: impl Into<ChannelId>
     ) -> Result<Member> {
-        let mut map = Map::new();
-        map.insert(
-            "channel_id".to_string(),
-            Value::Number(Number::from(channel_id.into().0)),
-        );
-
-        http.as_ref().edit_member(self.0, user_id.into().0, &map).await
+        self.edit_member(http, user_id, |m| m.voice_channel(channel_id)).await
     }
 
     /// Returns the name of whatever guild this id holds.
@@ -554,27 +1121,29 @@ impl GuildId {
     /// [Move Members]: Permissions::MOVE_MEMBERS
     #[inline]
     pub async fn disconnect_member(self, http: impl AsRef<Http>, user_id: impl Into<UserId>) -> Result<Member> {
-        let mut map = Map::new();
-        map.insert(
-            "channel_id".to_string(),
-            Value::Null
-        );
-        http.as_ref().edit_member(self.0, user_id.into().0, &map).await
+        self.edit_member(http, user_id, |m| m.disconnect_member()).await
+    }
+
+    /// Gets the guild's onboarding.
+    #[inline]
+    pub async fn onboarding(self, http: impl AsRef<Http>) -> Result<GuildOnboarding> {
+        http.as_ref().get_guild_onboarding(self.0).await
     }
 
     /// Gets the number of [`Member`]s that would be pruned with the given
-    /// number of days.
+    /// number of days, without removing them.
+    ///
+    /// Members with roles are only counted if all their roles are in
+    /// `include_roles`.
     ///
     /// Requires the [Kick Members] permission.
     ///
     /// [Kick Members]: Permissions::KICK_MEMBERS
-    #[inline]
-    pub async fn prune_count(self, http: impl AsRef<Http>, days: u16) -> Result<GuildPrune> {
-        let map = json!({
-            "days": days,
-        });
+    pub async fn prune_count(self, http: impl AsRef<Http>, days: u16, include_roles: &[RoleId]) -> Result<u64> {
+        let include_roles = include_roles.iter().map(|role| role.0).collect::<Vec<_>>();
+        let prune = http.as_ref().get_guild_prune_count(self.0, u64::from(days), &include_roles).await?;
 
-        http.as_ref().get_guild_prune_count(self.0, &map).await
+        Ok(prune.pruned)
     }
 
     /// Re-orders the channels of the guild.
@@ -599,6 +1168,48 @@ impl GuildId {
         http.as_ref().edit_guild_channel_positions(self.0, &Value::Array(items)).await
     }
 
+    /// Gets a [`ScheduledEvent`] of the guild.
+    ///
+    /// If `with_user_count` is `true`, the [`ScheduledEvent::user_count`]
+    /// field is populated.
+    #[inline]
+    pub async fn scheduled_event(
+        self,
+        http: impl AsRef<Http>,
+        event_id: impl Into<ScheduledEventId>,
+        with_user_count: bool,
+    ) -> Result<ScheduledEvent> {
+        http.as_ref().get_scheduled_event(self.0, event_id.into().0, with_user_count).await
+    }
+
+    /// Gets all of the [`ScheduledEvent`]s of the guild.
+    ///
+    /// If `with_user_count` is `true`, the [`ScheduledEvent::user_count`]
+    /// field of each event is populated.
+    #[inline]
+    pub async fn scheduled_events(self, http: impl AsRef<Http>, with_user_count: bool) -> Result<Vec<ScheduledEvent>> {
+        http.as_ref().get_scheduled_events(self.0, with_user_count).await
+    }
+
+    /// Gets the users subscribed to a [`ScheduledEvent`] of the guild.
+    ///
+    /// At most 100 users may be fetched at once, and `target` may be used to
+    /// page through the users by their Id.
+    ///
+    /// If `with_member` is `true`, the [`ScheduledEventUser::member`] field of
+    /// each user is populated.
+    #[inline]
+    pub async fn scheduled_event_users(
+        self,
+        http: impl AsRef<Http>,
+        event_id: impl Into<ScheduledEventId>,
+        limit: Option<u64>,
+        target: Option<UserPagination>,
+        with_member: bool,
+    ) -> Result<Vec<ScheduledEventUser>> {
+        http.as_ref().get_scheduled_event_users(self.0, event_id.into().0, limit, target, with_member).await
+    }
+
     /// Returns the Id of the shard associated with the guild.
     ///
     /// When the cache is enabled this will automatically retrieve the total
@@ -650,20 +1261,50 @@ impl GuildId {
         http.as_ref().start_integration_sync(self.0, integration_id.into().0).await
     }
 
-    /// Starts a prune of [`Member`]s.
+    /// Starts a prune of the [`Member`]s which have not been online for the
+    /// given number of days.
+    ///
+    /// Members with roles are only removed if all their roles are in
+    /// `include_roles`. The `reason` is shown in the audit log.
     ///
-    /// See the documentation on [`GuildPrune`] for more information.
+    /// Returns the number of removed members, or `None` if `compute_count` is
+    /// `false`. Discord recommends not computing it for large guilds.
     ///
     /// **Note**: Requires the [Kick Members] permission.
     ///
     /// [Kick Members]: Permissions::KICK_MEMBERS
+    pub async fn start_prune(
+        self,
+        http: impl AsRef<Http>,
+        days: u16,
+        compute_count: bool,
+        include_roles: &[RoleId],
+        reason: Option<&str>,
+    ) -> Result<Option<u64>> {
+        let include_roles = include_roles.iter().map(|role| role.0).collect::<Vec<_>>();
+
+        http.as_ref().start_guild_prune(self.0, u64::from(days), compute_count, &include_roles, reason).await
+    }
+
+    /// Updates a template of the guild to match the current state of the
+    /// guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
     #[inline]
-    pub async fn start_prune(self, http: impl AsRef<Http>, days: u16) -> Result<GuildPrune> {
-        let map = json!({
-            "days": days,
-        });
+    pub async fn sync_template(self, http: impl AsRef<Http>, code: &str) -> Result<GuildTemplate> {
+        http.as_ref().sync_guild_template(self.0, code).await
+    }
 
-        http.as_ref().start_guild_prune(self.0, &map).await
+    /// Gets the templates of the guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn templates(self, http: impl AsRef<Http>) -> Result<Vec<GuildTemplate>> {
+        http.as_ref().get_guild_templates(self.0).await
     }
 
     /// Unbans a [`User`] from the guild.
@@ -676,14 +1317,42 @@ impl GuildId {
         http.as_ref().remove_ban(self.0, user_id.into().0).await
     }
 
-    /// Retrieve's the guild's vanity URL.
+    /// Retrieves the guild's vanity URL, such as `https://discord.gg/rust`.
+    ///
+    /// Returns `None` if the guild has no vanity URL set.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn vanity_url(self, http: impl AsRef<Http>) -> Result<Option<String>> {
+        let code = http.as_ref().get_guild_vanity_url(self.0).await?;
+
+        Ok(code.map(|code| format!("https://discord.gg/{}", code)))
+    }
+
+    /// Gets the guild's welcome screen.
+    #[inline]
+    pub async fn welcome_screen(self, http: impl AsRef<Http>) -> Result<GuildWelcomeScreen> {
+        http.as_ref().get_guild_welcome_screen(self.0).await
+    }
+
+    /// Gets the settings of the guild's widget.
     ///
     /// **Note**: Requires the [Manage Guild] permission.
     ///
     /// [Manage Guild]: Permissions::MANAGE_GUILD
     #[inline]
-    pub async fn vanity_url(self, http: impl AsRef<Http>) -> Result<String> {
-        http.as_ref().get_guild_vanity_url(self.0).await
+    pub async fn widget(self, http: impl AsRef<Http>) -> Result<GuildWidget> {
+        http.as_ref().get_guild_widget(self.0).await
+    }
+
+    /// Returns the URL of the PNG image of the guild's widget, in the given
+    /// style.
+    ///
+    /// **Note**: The image is only available if the widget is enabled.
+    #[inline]
+    pub fn widget_image_url(self, style: GuildWidgetStyle) -> String {
+        Route::guild_widget_image(self.0, style.name())
     }
 
     /// Retrieves the guild's webhooks.
@@ -761,9 +1430,114 @@ impl<'a> From<&'a Guild> for GuildId {
     fn from(live_guild: &Guild) -> GuildId { live_guild.id }
 }
 
+#[cfg(feature = "model")]
+fn bulk_ban_map(user_ids: &[UserId], delete_message_seconds: u32) -> Result<Value> {
+    if user_ids.is_empty() || user_ids.len() > 200 {
+        return Err(Error::Model(ModelError::BulkBanAmount));
+    }
+
+    if delete_message_seconds > 604_800 {
+        return Err(Error::Model(ModelError::DeleteMessageSecondsAmount(delete_message_seconds)));
+    }
+
+    Ok(json!({
+        "user_ids": user_ids.iter().map(|id| id.0).collect::<Vec<u64>>(),
+        "delete_message_seconds": delete_message_seconds,
+    }))
+}
+
+#[cfg(feature = "model")]
+fn role_positions_map(positions: &[(RoleId, i64)]) -> Result<Value> {
+    for (index, &(_, position)) in positions.iter().enumerate() {
+        if positions[..index].iter().any(|&(_, other)| other == position) {
+            return Err(Error::Model(ModelError::DuplicateRolePosition(position)));
+        }
+    }
+
+    Ok(Value::Array(positions.iter().map(|&(id, position)| json!({
+        "id": id,
+        "position": position,
+    })).collect()))
+}
+
+/// Checks the combinations of the settings of onboarding prompts.
+///
+/// A prompt needs at least one option, and a required prompt must be shown
+/// during the onboarding. A required single-select prompt also needs a
+/// second option, as members would otherwise be given no choice.
+#[cfg(feature = "model")]
+fn check_onboarding_prompts(map: &JsonMap) -> Result<()> {
+    let prompts = match map.get("prompts") {
+        Some(Value::Array(prompts)) => prompts,
+        _ => return Ok(()),
+    };
+
+    for prompt in prompts {
+        let options = prompt.get("options").and_then(Value::as_array).map_or(0, Vec::len);
+        let single_select = prompt.get("single_select").and_then(Value::as_bool).unwrap_or(false);
+        let required = prompt.get("required").and_then(Value::as_bool).unwrap_or(false);
+        let in_onboarding = prompt.get("in_onboarding").and_then(Value::as_bool).unwrap_or(false);
+
+        let valid = match (single_select, required) {
+            (_, false) => options > 0,
+            (false, true) => options > 0 && in_onboarding,
+            (true, true) => options > 1 && in_onboarding,
+        };
+
+        if !valid {
+            return Err(Error::Model(ModelError::InvalidOnboardingPrompt));
+        }
+    }
+
+    Ok(())
+}
+
+/// Groups channels by category, in the order of the client's channel list.
+#[cfg(feature = "model")]
+fn order_channels(channels: Vec<GuildChannel>) -> Vec<(Option<GuildChannel>, Vec<GuildChannel>)> {
+    let (categories, channels): (Vec<GuildChannel>, Vec<GuildChannel>) = channels
+        .into_iter()
+        .filter(|channel| !matches!(
+            channel.kind,
+            ChannelType::NewsThread | ChannelType::PublicThread | ChannelType::PrivateThread
+        ))
+        .partition(|channel| channel.kind == ChannelType::Category);
+
+    let mut groups = categories
+        .into_iter()
+        .map(|category| (Some(category), Vec::new()))
+        .collect::<Vec<_>>();
+    groups.sort_by_key(|(category, _)| category.as_ref().map(|c| (c.position, c.id)));
+
+    let mut uncategorized = Vec::new();
+
+    for channel in channels {
+        let group = channel.category_id.and_then(|category_id| {
+            groups.iter_mut().find(|(category, _)| category.as_ref().map(|c| c.id) == Some(category_id))
+        });
+
+        match group {
+            Some((_, children)) => children.push(channel),
+            None => uncategorized.push(channel),
+        }
+    }
+
+    groups.insert(0, (None, uncategorized));
+
+    for (_, children) in &mut groups {
+        children.sort_by_key(|c| (matches!(c.kind, ChannelType::Voice | ChannelType::Stage), c.position, c.id));
+    }
+
+    groups
+}
+
+/// The number of members fetched at once by [`MembersIter`].
+#[cfg(feature = "model")]
+const MEMBERS_PAGE_SIZE: u64 = 1000;
+
 /// A helper class returned by [`GuildId::members_iter`]
 #[derive(Clone, Debug)]
-#[cfg(all(feature = "model", feature = "cache"))]
+#[cfg(feature = "model")]
 pub struct MembersIter<H: AsRef<Http>> {
     guild_id: GuildId,
     http: H,
@@ -772,7 +1546,7 @@ pub struct MembersIter<H: AsRef<Http>> {
     tried_fetch: bool,
 }
 
-#[cfg(all(feature = "model", feature = "cache"))]
+#[cfg(feature = "model")]
 impl<H: AsRef<Http>> MembersIter<H> {
     fn new(guild_id: GuildId, http: H) -> MembersIter<H> {
         MembersIter {
@@ -793,22 +1567,32 @@ impl<H: AsRef<Http>> MembersIter<H> {
     /// members to be fetched, then this marks `self.after` as None, indicating
     /// that no more calls ought to be made.
     async fn refresh(&mut self) -> Result<()> {
-        // Number of profiles to fetch
-        let grab_size: u64 = 1000;
+        let page = self.guild_id
+            .members(&self.http, Some(MEMBERS_PAGE_SIZE), self.after).await?;
 
-        self.buffer = self.guild_id
-            .members(&self.http, Some(grab_size), self.after).await?;
+        self.fill(page);
 
-         //Get the last member.  If shorter than 1000, there are no more results anyway
-        self.after = self.buffer.get(grab_size as usize - 1)
-            .map(|member| member.user.id);
+        Ok(())
+    }
+
+    /// Replaces the `self.buffer` cache of Members with a freshly fetched page.
+    ///
+    /// The highest user Id of the page is used as the cursor for the next
+    /// page. If the page is shorter than the page size, there are no more
+    /// results, so `self.after` is marked as None. A guild with exactly a
+    /// multiple of the page size members ends with an empty page instead.
+    fn fill(&mut self, mut page: Vec<Member>) {
+        self.after = if page.len() as u64 == MEMBERS_PAGE_SIZE {
+            page.iter().map(|member| member.user.id).max()
+        } else {
+            None
+        };
 
         // Reverse to optimize pop()
-        self.buffer.reverse();
+        page.reverse();
+        self.buffer = page;
 
         self.tried_fetch = true;
-
-        Ok(())
     }
 
     /// Streams over all the members in a guild.
@@ -859,3 +1643,329 @@ impl<H: AsRef<Http>> MembersIter<H> {
         })
     }
 }
+
+#[cfg(test)]
+mod test {
+    #[cfg(feature = "model")]
+    mod model {
+        use crate::http::Http;
+        use crate::internal::test_utils;
+        use crate::model::prelude::*;
+        use serde_json::json;
+        use super::super::{bulk_ban_map, check_onboarding_prompts, order_channels, role_positions_map, MembersIter, MEMBERS_PAGE_SIZE};
+
+        fn gen_page(ids: std::ops::RangeInclusive<u64>) -> Vec<Member> {
+            ids.map(test_utils::member).collect()
+        }
+
+        fn drain(iter: &mut MembersIter<Http>) -> Vec<UserId> {
+            let mut ids = Vec::new();
+
+            while let Some(member) = iter.buffer.pop() {
+                ids.push(member.user.id);
+            }
+
+            ids
+        }
+
+        #[test]
+        fn members_iter_pages() {
+            let mut iter = MembersIter::new(GuildId(1), Http::default());
+
+            iter.fill(gen_page(1..=MEMBERS_PAGE_SIZE));
+            assert_eq!(iter.after, Some(UserId(MEMBERS_PAGE_SIZE)));
+            assert_eq!(drain(&mut iter), (1..=MEMBERS_PAGE_SIZE).map(UserId).collect::<Vec<_>>());
+
+            iter.fill(gen_page(MEMBERS_PAGE_SIZE + 1..=MEMBERS_PAGE_SIZE + 10));
+            assert_eq!(iter.after, None);
+            assert_eq!(drain(&mut iter).len(), 10);
+        }
+
+        #[test]
+        fn members_iter_exact_multiple() {
+            let mut iter = MembersIter::new(GuildId(1), Http::default());
+
+            iter.fill(gen_page(1..=MEMBERS_PAGE_SIZE));
+            assert!(iter.after.is_some());
+            drain(&mut iter);
+
+            iter.fill(Vec::new());
+            assert_eq!(iter.after, None);
+            assert!(iter.buffer.is_empty());
+        }
+
+        /// Streams the members of a guild from a mocked API, which answers
+        /// the first page with a full page and the second with `last_page`
+        /// members.
+        async fn stream_mocked_members(last_page: u64) -> Vec<u64> {
+            use futures::StreamExt;
+            use http_crate::response::Builder;
+            use std::sync::Arc;
+
+            let mut http = Http::new_with_token("token");
+            http.ratelimiter.responder = Some(Arc::new(move |request| {
+                let url = request.url();
+                assert_eq!(url.path(), "/api/v8/guilds/1/members");
+
+                let query = url.query_pairs().collect::<std::collections::HashMap<_, _>>();
+                assert_eq!(query.get("limit").map(|l| &**l), Some("1000"));
+
+                let ids = match query.get("after").map(|after| after.parse::<u64>().unwrap()) {
+                    None => 1..=MEMBERS_PAGE_SIZE,
+                    Some(MEMBERS_PAGE_SIZE) => MEMBERS_PAGE_SIZE + 1..=MEMBERS_PAGE_SIZE + last_page,
+                    Some(after) => panic!("unexpected page after {}", after),
+                };
+                let body = ids.map(|id| json!({
+                    "deaf": false,
+                    "joined_at": null,
+                    "mute": false,
+                    "roles": [],
+                    "user": {
+                        "id": id.to_string(),
+                        "username": "test",
+                        "discriminator": "0001",
+                        "avatar": null,
+                    },
+                })).collect::<Vec<_>>();
+
+                let response = Builder::new().status(200).body(serde_json::to_vec(&body).unwrap()).unwrap();
+                reqwest::Response::from(response)
+            }));
+
+            let members = GuildId(1).members_iter(&http).collect::<Vec<_>>().await;
+            members.into_iter().map(|member| member.unwrap().user.id.0).collect()
+        }
+
+        #[tokio::test]
+        async fn members_iter_mocked_pages() {
+            let ids = stream_mocked_members(10).await;
+            assert_eq!(ids, (1..=MEMBERS_PAGE_SIZE + 10).collect::<Vec<_>>());
+
+            // The empty second page ends the stream.
+            let ids = stream_mocked_members(0).await;
+            assert_eq!(ids, (1..=MEMBERS_PAGE_SIZE).collect::<Vec<_>>());
+        }
+
+        fn gen_channel(id: u64, kind: ChannelType, position: i64, category_id: Option<u64>) -> GuildChannel {
+            serde_json::from_value(json!({
+                "id": id.to_string(),
+                "guild_id": "1",
+                "type": kind.num(),
+                "name": format!("channel-{}", id),
+                "position": position,
+                "parent_id": category_id.map(|id| id.to_string()),
+            })).unwrap()
+        }
+
+        #[test]
+        fn channels_order() {
+            let channels = vec![
+                gen_channel(10, ChannelType::Category, 1, None),
+                gen_channel(11, ChannelType::Voice, 0, Some(10)),
+                gen_channel(12, ChannelType::Text, 2, Some(10)),
+                gen_channel(13, ChannelType::Text, 1, Some(10)),
+                gen_channel(14, ChannelType::Stage, 1, Some(10)),
+                gen_channel(20, ChannelType::Category, 0, None),
+                gen_channel(21, ChannelType::News, 0, Some(20)),
+                gen_channel(22, ChannelType::PublicThread, 0, Some(21)),
+                gen_channel(30, ChannelType::Category, 1, None),
+                gen_channel(2, ChannelType::Voice, 0, None),
+                gen_channel(3, ChannelType::Text, 5, None),
+                gen_channel(4, ChannelType::Text, 5, Some(99)),
+            ];
+
+            let ordered = order_channels(channels)
+                .into_iter()
+                .map(|(category, children)| {
+                    let ids = children.iter().map(|c| c.id.0).collect::<Vec<_>>();
+
+                    (category.map(|c| c.id.0), ids)
+                })
+                .collect::<Vec<_>>();
+
+            assert_eq!(ordered, vec![
+                (None, vec![3, 4, 2]),
+                (Some(20), vec![21]),
+                (Some(10), vec![13, 12, 11, 14]),
+                (Some(30), vec![]),
+            ]);
+        }
+
+        #[test]
+        fn widget_image_urls() {
+            let styles = [
+                (GuildWidgetStyle::Shield, "shield"),
+                (GuildWidgetStyle::Banner1, "banner1"),
+                (GuildWidgetStyle::Banner2, "banner2"),
+                (GuildWidgetStyle::Banner3, "banner3"),
+                (GuildWidgetStyle::Banner4, "banner4"),
+            ];
+
+            for &(style, name) in &styles {
+                assert_eq!(
+                    GuildId(81384788765712384).widget_image_url(style),
+                    format!("https://discord.com/api/v8/guilds/81384788765712384/widget.png?style={}", name),
+                );
+            }
+        }
+
+        #[tokio::test]
+        async fn audit_log_reasons() {
+            use http_crate::response::Builder;
+            use std::sync::Arc;
+
+            let mut http = Http::new_with_token("token");
+            http.ratelimiter.responder = Some(Arc::new(|request| {
+                let reason = request.headers().get("X-Audit-Log-Reason").unwrap();
+                assert_eq!(reason, "Cleanup");
+
+                let response = match (request.method().as_str(), request.url().path()) {
+                    ("DELETE", "/api/v8/guilds/1/emojis/2") => Builder::new().status(204).body(Vec::new()),
+                    ("PATCH", "/api/v8/guilds/1/roles/3") => {
+                        let role = json!({
+                            "id": "3",
+                            "color": 0,
+                            "hoist": true,
+                            "managed": false,
+                            "name": "role",
+                            "permissions": "0",
+                            "position": 1,
+                        });
+
+                        Builder::new().status(200).body(serde_json::to_vec(&role).unwrap())
+                    },
+                    other => panic!("unexpected request: {:?}", other),
+                };
+
+                reqwest::Response::from(response.unwrap())
+            }));
+
+            GuildId(1).delete_emoji_with_reason(&http, 2, "Cleanup").await.unwrap();
+
+            let role = GuildId(1).edit_role_with_reason(&http, 3, "Cleanup", |r| r.hoist(true)).await.unwrap();
+            assert_eq!(role.guild_id, GuildId(1));
+
+            let role = role.edit_with_reason(&http, "Cleanup", |r| r.hoist(true)).await.unwrap();
+            assert!(role.hoist);
+        }
+
+        #[test]
+        fn role_positions_body() {
+            let map = role_positions_map(&[(RoleId(3), 1), (RoleId(1), 2), (RoleId(2), 3)]).unwrap();
+
+            assert_eq!(map, json!([
+                {"id": 3, "position": 1},
+                {"id": 1, "position": 2},
+                {"id": 2, "position": 3},
+            ]));
+        }
+
+        #[test]
+        fn role_positions_duplicate() {
+            match role_positions_map(&[(RoleId(3), 1), (RoleId(1), 2), (RoleId(2), 1)]) {
+                Err(crate::Error::Model(ModelError::DuplicateRolePosition(1))) => {},
+                other => panic!("unexpected result: {:?}", other),
+            }
+        }
+
+        #[test]
+        fn onboarding_prompt_combinations() {
+            use crate::builder::EditOnboarding;
+            use crate::utils;
+
+            let check = |single_select: bool, required: bool, in_onboarding: bool, options: u64| {
+                let mut builder = EditOnboarding::default();
+                builder.create_prompt(|p| {
+                    p.id(1)
+                        .title("Pick a team")
+                        .single_select(single_select)
+                        .required(required)
+                        .in_onboarding(in_onboarding);
+
+                    for id in 0..options {
+                        p.create_option(|o| o.id(id + 2).title("Team"));
+                    }
+
+                    p
+                });
+
+                match check_onboarding_prompts(&utils::hashmap_to_json_map(builder.0)) {
+                    Ok(()) => true,
+                    Err(crate::Error::Model(ModelError::InvalidOnboardingPrompt)) => false,
+                    Err(other) => panic!("unexpected error: {:?}", other),
+                }
+            };
+
+            // (single_select, required, in_onboarding, options, valid)
+            let cases = [
+                (false, false, false, 1, true),
+                (false, false, true, 1, true),
+                (false, true, true, 1, true),
+                (false, true, false, 1, false),
+                (true, false, false, 1, true),
+                (true, false, true, 1, true),
+                (true, true, true, 1, false),
+                (true, true, true, 2, true),
+                (true, true, false, 2, false),
+                (false, false, true, 0, false),
+                (true, false, true, 0, false),
+                (false, true, true, 0, false),
+                (true, true, true, 0, false),
+            ];
+
+            for &(single_select, required, in_onboarding, options, valid) in &cases {
+                assert_eq!(
+                    check(single_select, required, in_onboarding, options),
+                    valid,
+                    "single_select: {}, required: {}, in_onboarding: {}, options: {}",
+                    single_select, required, in_onboarding, options,
+                );
+            }
+        }
+
+        #[test]
+        fn bulk_ban_body() {
+            let map = bulk_ban_map(&[UserId(1), UserId(2)], 3600).unwrap();
+
+            assert_eq!(map, json!({
+                "user_ids": [1, 2],
+                "delete_message_seconds": 3600,
+            }));
+        }
+
+        #[test]
+        fn bulk_ban_limits() {
+            let users = (1..=201).map(UserId).collect::<Vec<_>>();
+
+            assert!(bulk_ban_map(&users[..200], 604_800).is_ok());
+
+            match bulk_ban_map(&[], 0) {
+                Err(Error::Model(ModelError::BulkBanAmount)) => {},
+                other => panic!("unexpected result: {:?}", other),
+            }
+
+            match bulk_ban_map(&users, 0) {
+                Err(Error::Model(ModelError::BulkBanAmount)) => {},
+                other => panic!("unexpected result: {:?}", other),
+            }
+
+            match bulk_ban_map(&users[..1], 604_801) {
+                Err(Error::Model(ModelError::DeleteMessageSecondsAmount(604_801))) => {},
+                other => panic!("unexpected result: {:?}", other),
+            }
+        }
+
+        #[test]
+        fn bulk_ban_response() {
+            let value = json!({
+                "banned_users": ["1", "2"],
+                "failed_users": ["3"],
+            });
+
+            let response = serde_json::from_value::<BulkBanResponse>(value).unwrap();
+
+            assert_eq!(response.banned_users, vec![UserId(1), UserId(2)]);
+            assert_eq!(response.failed_users, vec![UserId(3)]);
+        }
+    }
+}
@@ -8,7 +8,7 @@ use std::fmt::{
 };
 
 #[cfg(feature = "model")]
-use crate::builder::EditMember;
+use crate::builder::{EditMember, Timestamp};
 #[cfg(all(feature = "cache", feature = "model"))]
 use crate::internal::prelude::*;
 #[cfg(feature = "model")]
@@ -26,6 +26,17 @@ use crate::http::{Http, CacheHttp};
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Member {
+    /// The member's guild avatar hash, if they have set one.
+    ///
+    /// This takes priority over the user's global avatar in the guild.
+    #[serde(default)]
+    pub avatar: Option<String>,
+    /// The time until which the member is timed out, if they are.
+    ///
+    /// A timed out member can not send messages, react to messages, or speak
+    /// in voice channels.
+    #[serde(default)]
+    pub communication_disabled_until: Option<DateTime<Utc>>,
     /// Indicator of whether the member can hear in voice channels.
     pub deaf: bool,
     /// The unique Id of the guild that the member is a part of.
@@ -72,27 +83,39 @@ impl Member {
         }
     }
 
-    /// Adds one or multiple [`Role`]s to the member, editing
-    /// its roles in-place if the request was successful.
+    /// Adds one or multiple [`Role`]s to the member in a single request,
+    /// editing its roles in-place if the request was successful. Returns the
+    /// member's new roles.
+    ///
+    /// Roles the member already has are ignored, and no request is made if
+    /// there is nothing to add.
     ///
     /// **Note**: Requires the [Manage Roles] permission.
     ///
     /// [Manage Roles]: Permissions::MANAGE_ROLES
     pub async fn add_roles(&mut self, http: impl AsRef<Http>, role_ids: &[RoleId]) -> Result<Vec<RoleId>> {
-        self.roles.extend_from_slice(role_ids);
+        match roles_with(&self.roles, role_ids) {
+            Some(roles) => self._set_roles(http, roles).await,
+            None => Ok(self.roles.clone()),
+        }
+    }
 
+    async fn _set_roles(&mut self, http: impl AsRef<Http>, roles: Vec<RoleId>) -> Result<Vec<RoleId>> {
         let mut builder = EditMember::default();
-        builder.roles(&self.roles);
+        builder.roles(&roles);
         let map = utils::hashmap_to_json_map(builder.0);
 
-        match http.as_ref().edit_member(self.guild_id.0, self.user.id.0, &map).await {
-            Ok(member) => Ok(member.roles),
-            Err(why) => {
-                self.roles.retain(|r| !role_ids.contains(r));
+        let member = http.as_ref().edit_member(self.guild_id.0, self.user.id.0, &map).await?;
+        self.roles.clone_from(&member.roles);
 
-                Err(why)
-            },
-        }
+        Ok(member.roles)
+    }
+
+    /// Returns the formatted URL of the member's guild avatar, if one exists.
+    ///
+    /// This will produce a WEBP image URL, or GIF if the member has a GIF avatar.
+    pub fn avatar_url(&self) -> Option<String> {
+        avatar_url(self.guild_id, self.user.id, self.avatar.as_ref())
     }
 
     /// Ban a [`User`] from the guild, deleting a number of
@@ -192,6 +215,39 @@ impl Member {
         http.as_ref().edit_member(self.guild_id.0, self.user.id.0, &map).await
     }
 
+    /// Times the member out until the given time, preventing them from sending
+    /// messages, reacting to messages, or speaking in voice channels, editing
+    /// the member in-place if the request was successful.
+    ///
+    /// The time can be at most 28 days in the future.
+    ///
+    /// **Note**: Requires the Moderate Members permission.
+    pub async fn disable_communication_until(&mut self, http: impl AsRef<Http>, time: impl Into<Timestamp>) -> Result<()> {
+        let time = time.into();
+        *self = self.edit(http, |e| e.disable_communication_until(time)).await?;
+
+        Ok(())
+    }
+
+    /// Removes the timeout of the member, editing it in-place if the request
+    /// was successful.
+    ///
+    /// **Note**: Requires the Moderate Members permission.
+    pub async fn enable_communication(&mut self, http: impl AsRef<Http>) -> Result<()> {
+        *self = self.edit(http, |e| e.enable_communication()).await?;
+
+        Ok(())
+    }
+
+    /// Retrieves the URL to the member's guild avatar, falling back to the
+    /// user's [`face`] if the member has not set one.
+    ///
+    /// [`face`]: User::face
+    pub fn face(&self) -> String {
+        self.avatar_url()
+            .unwrap_or_else(|| self.user.face())
+    }
+
     /// Retrieves the ID and position of the member's highest role in the
     /// hierarchy, if they have one.
     ///
@@ -360,6 +416,18 @@ impl Member {
         guild.member_permissions(cache_http, self.user.id).await
     }
 
+    /// Refreshes the information about the member, such as its nickname and
+    /// roles.
+    ///
+    /// Replaces the instance with the data of the member in its guild,
+    /// retrieved over the REST API.
+    #[inline]
+    pub async fn refresh(&mut self, http: impl AsRef<Http>) -> Result<()> {
+        *self = http.as_ref().get_member(self.guild_id.0, self.user.id.0).await?;
+
+        Ok(())
+    }
+
     /// Removes a [`Role`] from the member, editing its roles in-place if the
     /// request was successful.
     ///
@@ -383,26 +451,20 @@ impl Member {
         }
     }
 
-    /// Removes one or multiple [`Role`]s from the member. Returns the member's
-    /// new roles.
+    /// Removes one or multiple [`Role`]s from the member in a single request,
+    /// editing its roles in-place if the request was successful. Returns the
+    /// member's new roles.
+    ///
+    /// Roles the member does not have are ignored, and no request is made if
+    /// there is nothing to remove.
     ///
     /// **Note**: Requires the [Manage Roles] permission.
     ///
     /// [Manage Roles]: Permissions::MANAGE_ROLES
     pub async fn remove_roles(&mut self, http: impl AsRef<Http>, role_ids: &[RoleId]) -> Result<Vec<RoleId>> {
-        self.roles.retain(|r| !role_ids.contains(r));
-
-        let mut builder = EditMember::default();
-        builder.roles(&self.roles);
-        let map = utils::hashmap_to_json_map(builder.0);
-
-        match http.as_ref().edit_member(self.guild_id.0, self.user.id.0, &map).await {
-            Ok(member) => Ok(member.roles),
-            Err(why) => {
-                self.roles.extend_from_slice(role_ids);
-
-                Err(why)
-            },
+        match roles_without(&self.roles, role_ids) {
+            Some(roles) => self._set_roles(http, roles).await,
+            None => Ok(self.roles.clone()),
         }
     }
 
@@ -454,6 +516,55 @@ impl Member {
     }
 }
 
+#[cfg(feature = "model")]
+fn avatar_url(guild_id: GuildId, user_id: UserId, hash: Option<&String>) -> Option<String> {
+    hash.map(|hash| {
+        let ext = if hash.starts_with("a_") {
+            "gif"
+        } else {
+            "webp"
+        };
+
+        cdn!("/guilds/{}/users/{}/avatars/{}.{}?size=1024", guild_id.0, user_id.0, hash, ext)
+    })
+}
+
+/// Computes the roles resulting from adding `added` to `current`, keeping the
+/// existing order and skipping duplicates. Returns `None` if nothing changes.
+#[cfg(feature = "model")]
+fn roles_with(current: &[RoleId], added: &[RoleId]) -> Option<Vec<RoleId>> {
+    let mut roles = current.to_vec();
+
+    for role_id in added {
+        if !roles.contains(role_id) {
+            roles.push(*role_id);
+        }
+    }
+
+    if roles.len() == current.len() {
+        None
+    } else {
+        Some(roles)
+    }
+}
+
+/// Computes the roles resulting from removing `removed` from `current`.
+/// Returns `None` if nothing changes.
+#[cfg(feature = "model")]
+fn roles_without(current: &[RoleId], removed: &[RoleId]) -> Option<Vec<RoleId>> {
+    let roles = current
+        .iter()
+        .filter(|r| !removed.contains(r))
+        .copied()
+        .collect::<Vec<_>>();
+
+    if roles.len() == current.len() {
+        None
+    } else {
+        Some(roles)
+    }
+}
+
 impl Display for Member {
     /// Mentions the user so that they receive a notification.
     ///
@@ -489,3 +600,111 @@ pub struct PartialMember {
     /// Vector of Ids of [`Role`]s given to the member.
     pub roles: Vec<RoleId>,
 }
+
+#[cfg(test)]
+mod test {
+    #[cfg(feature = "model")]
+    mod model {
+        use crate::internal::test_utils;
+        use crate::model::prelude::*;
+        use super::super::{roles_with, roles_without};
+
+        #[tokio::test]
+        async fn refresh() {
+            use crate::http::Http;
+            use http_crate::response::Builder;
+            use std::sync::Arc;
+
+            let mut http = Http::new_with_token("token");
+            http.ratelimiter.responder = Some(Arc::new(|request| {
+                assert_eq!(request.method(), reqwest::Method::GET);
+                assert!(request.url().as_str().ends_with("/guilds/1/members/2"));
+
+                let body = serde_json::json!({
+                    "deaf": false,
+                    "joined_at": "2016-11-08T00:00:00+00:00",
+                    "mute": false,
+                    "nick": "renamed",
+                    "roles": ["3"],
+                    "user": {
+                        "id": "2",
+                        "avatar": "userhash",
+                        "discriminator": "0001",
+                        "username": "test",
+                    },
+                });
+                let response = Builder::new()
+                    .status(200)
+                    .body(serde_json::to_vec(&body).unwrap())
+                    .unwrap();
+
+                reqwest::Response::from(response)
+            }));
+
+            let mut member = test_utils::member(2);
+            assert_eq!(member.nick, None);
+
+            member.refresh(&http).await.unwrap();
+            assert_eq!(member.nick.as_deref(), Some("renamed"));
+            assert_eq!(member.roles, [RoleId(3)]);
+            assert_eq!(member.guild_id, GuildId(1));
+            assert_eq!(member.user.id, UserId(2));
+        }
+
+        #[test]
+        fn avatar_url() {
+            let mut member = test_utils::member(2);
+
+            member.avatar = Some("memberhash".to_string());
+            assert_eq!(
+                member.avatar_url().unwrap(),
+                "https://cdn.discordapp.com/guilds/1/users/2/avatars/memberhash.webp?size=1024",
+            );
+
+            member.avatar = Some("a_memberhash".to_string());
+            assert_eq!(
+                member.avatar_url().unwrap(),
+                "https://cdn.discordapp.com/guilds/1/users/2/avatars/a_memberhash.gif?size=1024",
+            );
+        }
+
+        #[test]
+        fn face_fallback() {
+            let mut member = test_utils::member(2);
+            member.user.avatar = Some("userhash".to_string());
+
+            assert!(member.avatar_url().is_none());
+            assert_eq!(member.face(), "https://cdn.discordapp.com/avatars/2/userhash.webp?size=1024");
+
+            member.avatar = Some("memberhash".to_string());
+            assert_eq!(member.face(), member.avatar_url().unwrap());
+        }
+
+        #[test]
+        fn roles_with_mixed() {
+            let current = [RoleId(1), RoleId(2)];
+            let added = [RoleId(2), RoleId(3), RoleId(3), RoleId(1), RoleId(4)];
+
+            assert_eq!(
+                roles_with(&current, &added),
+                Some(vec![RoleId(1), RoleId(2), RoleId(3), RoleId(4)]),
+            );
+        }
+
+        #[test]
+        fn roles_with_noop() {
+            let current = [RoleId(1), RoleId(2)];
+
+            assert_eq!(roles_with(&current, &[RoleId(2), RoleId(1)]), None);
+            assert_eq!(roles_with(&current, &[]), None);
+        }
+
+        #[test]
+        fn roles_without_mixed() {
+            let current = [RoleId(1), RoleId(2), RoleId(3)];
+
+            assert_eq!(roles_without(&current, &[RoleId(2), RoleId(4)]), Some(vec![RoleId(1), RoleId(3)]));
+            assert_eq!(roles_without(&current, &[RoleId(4)]), None);
+        }
+    }
+}
@@ -1,26 +1,38 @@
 //! Models relating to guilds and types that it owns.
 
+mod automod;
 mod emoji;
 mod guild_id;
 mod integration;
 mod member;
+mod onboarding;
 mod partial_guild;
 mod role;
 mod audit_log;
 mod premium_tier;
+mod scheduled_event;
+mod template;
+mod welcome_screen;
 
+pub use self::automod::*;
 pub use self::emoji::*;
 pub use self::guild_id::*;
 pub use self::integration::*;
 pub use self::member::*;
+pub use self::onboarding::*;
 pub use self::partial_guild::*;
 pub use self::role::*;
 pub use self::audit_log::*;
 pub use self::premium_tier::*;
+pub use self::scheduled_event::*;
+pub use self::template::*;
+pub use self::welcome_screen::*;
 
+use bitflags::bitflags;
 use chrono::{DateTime, Utc};
 use crate::model::prelude::*;
 use serde::de::Error as DeError;
+use serde::ser::{Serialize, Serializer};
 use super::utils::*;
 use futures::stream::StreamExt;
 
@@ -46,6 +58,16 @@ pub struct Ban {
     pub user: User,
 }
 
+/// The result of banning multiple users at once, via [`GuildId::bulk_ban`].
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[non_exhaustive]
+pub struct BulkBanResponse {
+    /// The users that were successfully banned.
+    pub banned_users: Vec<UserId>,
+    /// The users that could not be banned.
+    pub failed_users: Vec<UserId>,
+}
+
 /// Information about a Discord guild, such as channels, emojis, etc.
 #[derive(Clone, Debug, Serialize)]
 #[non_exhaustive]
@@ -303,6 +325,46 @@ impl Guild {
         self.id.ban_with_reason(cache_http.http(), user, dmd, reason).await
     }
 
+    /// Bans multiple [`User`]s from the guild at once, deleting the messages
+    /// they sent in the last `delete_message_seconds` seconds.
+    ///
+    /// Refer to the documentation for [`GuildId::bulk_ban`] for more
+    /// information.
+    ///
+    /// **Note**: Requires the [Ban Members] and [Manage Guild] permissions.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`]
+    /// if the current user does not have permission to perform bans.
+    ///
+    /// Returns a [`ModelError::BulkBanAmount`] if no users or more than 200
+    /// users are given, and a [`ModelError::DeleteMessageSecondsAmount`] if
+    /// `delete_message_seconds` is over 604800, which is 7 days.
+    ///
+    /// [Ban Members]: Permissions::BAN_MEMBERS
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn bulk_ban(
+        &self,
+        cache_http: impl CacheHttp,
+        user_ids: &[UserId],
+        delete_message_seconds: u32,
+        reason: Option<&str>,
+    ) -> Result<BulkBanResponse> {
+        #[cfg(feature = "cache")]
+        {
+            if cache_http.cache().is_some() {
+                let req = Permissions::BAN_MEMBERS | Permissions::MANAGE_GUILD;
+
+                if !self.has_perms(&cache_http, req).await {
+                    return Err(Error::Model(ModelError::InvalidPermissions(req)));
+                }
+            }
+        }
+
+        self.id.bulk_ban(cache_http.http(), user_ids, delete_message_seconds, reason).await
+    }
+
     /// Returns the formatted URL of the guild's banner image, if one exists.
     pub fn banner_url(&self) -> Option<String> {
         self.banner
@@ -336,11 +398,19 @@ impl Guild {
     }
 
     /// Retrieves a list of [`AuditLogs`] for the guild.
+    ///
+    /// Refer to [`GuildId::audit_logs`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [`Error::Http`]: crate::error::Error::Http
     #[inline]
     pub async fn audit_logs(
         &self,
         http: impl AsRef<Http>,
-        action_type: Option<u8>,
+        action_type: Option<Action>,
         user_id: Option<UserId>,
         before: Option<AuditLogEntryId>,
         limit: Option<u8>
@@ -806,6 +876,54 @@ impl Guild {
         }
     }
 
+    /// Returns the highest [`Role`] of a [`Member`] of the guild, looked up in
+    /// the guild's [`roles`].
+    ///
+    /// If several of the member's roles share the highest [`position`], the
+    /// one with the lowest Id, which is the oldest, is returned. Returns
+    /// [`None`] if the member has no known role.
+    ///
+    /// [`roles`]: Self::roles
+    /// [`position`]: Role::position
+    pub fn member_highest_role(&self, member: &Member) -> Option<&Role> {
+        member.roles
+            .iter()
+            .filter_map(|id| self.roles.get(id))
+            .max_by(|a, b| a.position.cmp(&b.position).then(b.id.cmp(&a.id)))
+    }
+
+    /// Returns whether the `actor` is above the `target` in the role
+    /// hierarchy, and can thus moderate them, such as by kicking them or
+    /// editing their roles.
+    ///
+    /// The guild owner can manage anyone and can't be managed. Otherwise, the
+    /// actor's [highest role] must be above the target's. When both roles
+    /// share the same position, the older one is considered higher. A member
+    /// can't manage themselves.
+    ///
+    /// **Note**: This only checks the hierarchy. The actor also needs the
+    /// relevant permissions for the action.
+    ///
+    /// [highest role]: Self::member_highest_role
+    pub fn can_manage(&self, actor: &Member, target: &Member) -> bool {
+        if actor.user.id == target.user.id {
+            return false;
+        }
+
+        if actor.user.id == self.owner_id {
+            return true;
+        } else if target.user.id == self.owner_id {
+            return false;
+        }
+
+        // A lower Id ranks higher on equal positions, and a member without
+        // any role ranks below everyone else.
+        let rank = |member: &Member| self.member_highest_role(member)
+            .map(|role| (role.position, std::cmp::Reverse(role.id)));
+
+        rank(actor) > rank(target)
+    }
+
     /// Returns the formatted URL of the guild's icon, if one exists.
     ///
     /// This will produce a WEBP image URL, or GIF if the guild has a GIF icon.
@@ -939,56 +1057,125 @@ impl Guild {
         members
     }
 
-    /// Retrieves the first [`Member`] found that matches the name - with an
-    /// optional discriminator - provided.
+    /// Returns the Id of the voice channel the user is connected to, according
+    /// to the guild's [`voice_states`].
+    ///
+    /// [`voice_states`]: Self::voice_states
+    pub fn voice_channel_of(&self, user_id: impl Into<UserId>) -> Option<ChannelId> {
+        self.voice_states.get(&user_id.into())?.channel_id
+    }
+
+    /// Gets a list of the members connected to the given voice channel,
+    /// sorted by their Id.
+    ///
+    /// Members missing from the guild's [`members`] are omitted.
+    ///
+    /// [`members`]: Self::members
+    pub fn members_in_voice(&self, channel_id: impl Into<ChannelId>) -> Vec<&Member> {
+        let channel_id = channel_id.into();
+
+        let mut members = self.voice_states
+            .values()
+            .filter(|state| state.channel_id == Some(channel_id))
+            .filter_map(|state| self.members.get(&state.user_id))
+            .collect::<Vec<_>>();
+        members.sort_by_key(|member| member.user.id);
+
+        members
+    }
+
+    /// Retrieves the [`Member`] that matches the name - with an optional
+    /// discriminator - or the mention provided.
     ///
     /// Searching with a discriminator given is the most precise form of lookup,
-    /// as no two people can share the same username *and* discriminator.
+    /// as no two people can share the same username *and* discriminator. Users
+    /// migrated to the new username system have unique usernames, and are
+    /// found from their username alone.
     ///
     /// If a member can not be found by username or username#discriminator,
     /// then a search will be done for the nickname. When searching by nickname,
     /// the hash (`#`) and everything after it is included in the search.
     ///
+    /// If several members match, the one with the lowest Id is returned.
+    ///
     /// The following are valid types of searches:
     ///
-    /// - **username**: "zey"
+    /// - **mention**: "<@114941315417899012>"
+    /// - **username**: "zey" or "@zey"
     /// - **username and discriminator**: "zey#5479"
+    /// - **nickname**: "Zeyla"
+    ///
+    /// Refer to [`member_named_case_insensitive`] for a more lenient search.
+    ///
+    /// [`member_named_case_insensitive`]: Self::member_named_case_insensitive
     pub fn member_named(&self, name: &str) -> Option<&Member> {
-        let (name, discrim) = if let Some(pos) = name.rfind('#') {
-            let split = name.split_at(pos + 1);
-
-            let split2 = (
-                match split.0.get(0..split.0.len() - 1) {
-                    Some(s) => s,
-                    None => "",
-                },
-                split.1,
-            );
+        self.find_member_named(name, true)
+    }
 
-            match split2.1.parse::<u16>() {
-                Ok(discrim_int) => (split2.0, Some(discrim_int)),
-                Err(_) => (name, None),
-            }
-        } else {
-            (&name[..], None)
-        };
+    /// Retrieves the [`Member`] that matches the name or the mention provided,
+    /// ignoring the case of the name.
+    ///
+    /// Exact matches are preferred, as done by [`member_named`], before
+    /// matches differing by their case, and lastly members whose username or
+    /// nickname starts with the name. If several members match, the one with
+    /// the lowest Id is returned.
+    ///
+    /// [`member_named`]: Self::member_named
+    pub fn member_named_case_insensitive(&self, name: &str) -> Option<&Member> {
+        let name = name.trim();
 
-        for member in self.members.values() {
-            let name_matches = member.user.name == name;
+        self.find_member_named(name, true)
+            .or_else(|| self.find_member_named(name, false))
+            .or_else(|| {
+                let prefix = name.strip_prefix('@').unwrap_or(name);
 
-            let discrim_matches = match discrim {
-                Some(discrim) => member.user.discriminator == discrim,
-                None => true,
-            };
+                self.members
+                    .values()
+                    .filter(|member| {
+                        starts_with_case_insensitive(&member.user.name, prefix)
+                        || member.nick.iter().any(|nick| starts_with_case_insensitive(nick, prefix))
+                    })
+                    .min_by_key(|member| member.user.id)
+            })
+    }
 
-            if name_matches && discrim_matches {
-                return Some(member);
-            }
+    fn find_member_named(&self, name: &str, case_sensitive: bool) -> Option<&Member> {
+        let name = name.trim();
+
+        if let Some(user_id) = parse_member_mention(name) {
+            return self.members.get(&user_id);
         }
 
+        let name = name.strip_prefix('@').unwrap_or(name);
+        let (username, discrim) = match name.rfind('#') {
+            Some(pos) => match name[pos + 1..].parse::<u16>() {
+                Ok(discrim) => (&name[..pos], Some(discrim)),
+                Err(_) => (name, None),
+            },
+            None => (name, None),
+        };
+
+        let is_equal = |to_look_at: &str, to_find: &str| {
+            if case_sensitive {
+                to_look_at == to_find
+            } else {
+                to_look_at.to_lowercase() == to_find.to_lowercase()
+            }
+        };
+
         self.members
             .values()
-            .find(|member| member.nick.as_ref().map_or(false, |nick| nick == name))
+            .filter(|member| {
+                is_equal(&member.user.name, username)
+                && discrim.iter().all(|&discrim| member.user.discriminator == discrim)
+            })
+            .min_by_key(|member| member.user.id)
+            .or_else(|| {
+                self.members
+                    .values()
+                    .filter(|member| member.nick.iter().any(|nick| is_equal(nick, name)))
+                    .min_by_key(|member| member.user.id)
+            })
     }
 
     /// Retrieves all [`Member`] that start with a given `String`.
@@ -1476,9 +1663,9 @@ impl Guild {
     }
 
     /// Retrieves the count of the number of [`Member`]s that would be pruned
-    /// with the number of given days.
+    /// with the number of given days, without removing them.
     ///
-    /// See the documentation on [`GuildPrune`] for more information.
+    /// Refer to [`GuildId::prune_count`] for more information.
     ///
     /// **Note**: Requires the [Kick Members] permission.
     ///
@@ -1488,7 +1675,7 @@ impl Guild {
     /// if the current user does not have permission to perform bans.
     ///
     /// [Kick Members]: Permissions::KICK_MEMBERS
-    pub async fn prune_count(&self, cache_http: impl CacheHttp, days: u16) -> Result<GuildPrune> {
+    pub async fn prune_count(&self, cache_http: impl CacheHttp, days: u16, include_roles: &[RoleId]) -> Result<u64> {
         #[cfg(feature = "cache")]
         {
             if cache_http.cache().is_some() {
@@ -1500,7 +1687,7 @@ impl Guild {
             }
         }
 
-        self.id.prune_count(cache_http.http(), days).await
+        self.id.prune_count(cache_http.http(), days, include_roles).await
     }
 
     pub(crate) fn remove_unusable_permissions(&self, permissions: &mut Permissions) {
@@ -1597,7 +1784,7 @@ impl Guild {
 
     /// Starts a prune of [`Member`]s.
     ///
-    /// See the documentation on [`GuildPrune`] for more information.
+    /// Refer to [`GuildId::start_prune`] for more information.
     ///
     /// **Note**: Requires the [Kick Members] permission.
     ///
@@ -1607,7 +1794,14 @@ impl Guild {
     /// if the current user does not have permission to perform bans.
     ///
     /// [Kick Members]: Permissions::KICK_MEMBERS
-    pub async fn start_prune(&self, cache_http: impl CacheHttp, days: u16) -> Result<GuildPrune> {
+    pub async fn start_prune(
+        &self,
+        cache_http: impl CacheHttp,
+        days: u16,
+        compute_count: bool,
+        include_roles: &[RoleId],
+        reason: Option<&str>,
+    ) -> Result<Option<u64>> {
         #[cfg(feature = "cache")]
         {
             if cache_http.cache().is_some() {
@@ -1619,7 +1813,7 @@ impl Guild {
             }
         }
 
-        self.id.start_prune(cache_http.http(), days).await
+        self.id.start_prune(cache_http.http(), days, compute_count, include_roles, reason).await
     }
 
     /// Unbans the given [`User`] from the guild.
@@ -1647,13 +1841,15 @@ impl Guild {
         self.id.unban(&cache_http.http(), user_id).await
     }
 
-    /// Retrieve's the guild's vanity URL.
+    /// Retrieves the guild's vanity URL, such as `https://discord.gg/rust`.
+    ///
+    /// Returns `None` if the guild has no vanity URL set.
     ///
     /// **Note**: Requires the [Manage Guild] permission.
     ///
     /// [Manage Guild]: Permissions::MANAGE_GUILD
     #[inline]
-    pub async fn vanity_url(&self, http: impl AsRef<Http>) -> Result<String> {
+    pub async fn vanity_url(&self, http: impl AsRef<Http>) -> Result<Option<String>> {
         self.id.vanity_url(&http).await
     }
 
@@ -1913,6 +2109,15 @@ fn contains_case_insensitive(to_look_at: &str, to_find: &str) -> bool {
     to_look_at.to_lowercase().contains(&to_find.to_lowercase())
 }
 
+/// Retrieves the Id from a user mention, with or without a nickname marker.
+#[cfg(feature = "model")]
+fn parse_member_mention(mention: &str) -> Option<UserId> {
+    let id = mention.strip_prefix("<@")?.strip_suffix('>')?;
+    let id = id.strip_prefix('!').unwrap_or(id);
+
+    id.parse().ok().map(UserId)
+}
+
 /// Checks if a `&str` starts with another `&str`.
 #[cfg(feature = "model")]
 fn starts_with_case_insensitive(to_look_at: &str, to_find: &str) -> bool {
@@ -1964,6 +2169,50 @@ pub struct GuildEmbed {
     pub enabled: bool,
 }
 
+/// The settings of a guild's widget, an embeddable list of its online members.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GuildWidget {
+    /// Whether the widget is enabled.
+    pub enabled: bool,
+    /// The Id of the channel the widget invites members to, if any.
+    pub channel_id: Option<ChannelId>,
+}
+
+/// The style of a guild's widget image, as retrieved by
+/// [`GuildId::widget_image_url`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum GuildWidgetStyle {
+    /// A small shield showing the number of online members.
+    Shield,
+    /// A large image with the guild's icon, name and online count, and a
+    /// "Powered by Discord" footer.
+    Banner1,
+    /// A smaller image with the guild's icon, name and online count, and a
+    /// Discord logo.
+    Banner2,
+    /// A large image with the guild's icon, name and online count, and a
+    /// "Chat Now" footer.
+    Banner3,
+    /// A large image with a "Join my server" header, and the guild's icon,
+    /// name and online count.
+    Banner4,
+}
+
+impl GuildWidgetStyle {
+    /// The name of the style, as used in the widget image URL.
+    pub fn name(self) -> &'static str {
+        match self {
+            GuildWidgetStyle::Shield => "shield",
+            GuildWidgetStyle::Banner1 => "banner1",
+            GuildWidgetStyle::Banner2 => "banner2",
+            GuildWidgetStyle::Banner3 => "banner3",
+            GuildWidgetStyle::Banner4 => "banner4",
+        }
+    }
+}
+
 /// Representation of the number of members that would be pruned by a guild
 /// prune operation.
 #[derive(Clone, Copy, Debug, Deserialize, Serialize)]
@@ -1974,6 +2223,7 @@ pub struct GuildPrune {
 
 /// Basic information about a guild.
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct GuildInfo {
     /// The unique Id of the guild.
     ///
@@ -1989,6 +2239,14 @@ pub struct GuildInfo {
     pub owner: bool,
     /// The permissions that the current user has.
     pub permissions: Permissions,
+    /// The approximate number of members in the guild.
+    ///
+    /// Only present when the guilds are requested with counts.
+    pub approximate_member_count: Option<u64>,
+    /// The approximate number of online members in the guild.
+    ///
+    /// Only present when the guilds are requested with counts.
+    pub approximate_presence_count: Option<u64>,
 }
 
 #[cfg(any(feature = "model", feature = "utils"))]
@@ -2235,11 +2493,39 @@ impl VerificationLevel {
     }
 }
 
+bitflags! {
+    /// A set of flags disabling the messages a [`Guild`] sends in its system
+    /// channel.
+    pub struct SystemChannelFlags: u64 {
+        /// Suppress the member join notifications.
+        const SUPPRESS_JOIN_NOTIFICATIONS = 1 << 0;
+        /// Suppress the server boost notifications.
+        const SUPPRESS_PREMIUM_SUBSCRIPTIONS = 1 << 1;
+        /// Suppress the server setup tips.
+        const SUPPRESS_GUILD_REMINDER_NOTIFICATIONS = 1 << 2;
+        /// Hide the sticker reply buttons on member join notifications.
+        const SUPPRESS_JOIN_NOTIFICATION_REPLIES = 1 << 3;
+    }
+}
+
+impl<'de> Deserialize<'de> for SystemChannelFlags {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        Ok(SystemChannelFlags::from_bits_truncate(u64::deserialize(deserializer)?))
+    }
+}
+
+impl Serialize for SystemChannelFlags {
+    fn serialize<S: Serializer>(&self, serializer: S) -> StdResult<S::Ok, S::Error> {
+        serializer.serialize_u64(self.bits())
+    }
+}
+
 #[cfg(test)]
 mod test {
     #[cfg(feature = "model")]
     mod model {
         use chrono::prelude::*;
+        use crate::internal::test_utils;
         use crate::model::prelude::*;
         use std::collections::*;
 
@@ -2263,6 +2549,8 @@ mod test {
             let u = gen_user();
 
             Member {
+                avatar: None,
+                communication_disabled_until: None,
                 deaf: false,
                 guild_id: GuildId(1),
                 joined_at: Some(dt),
@@ -2346,5 +2634,102 @@ mod test {
 
             assert_eq!(lhs, gen_member().display_name());
         }
+
+        fn insert_member(guild: &mut Guild, id: u64, name: &str, discriminator: u16, nick: Option<&str>) {
+            let mut member = gen_member();
+            member.user.id = UserId(id);
+            member.user.name = name.to_string();
+            member.user.discriminator = discriminator;
+            member.nick = nick.map(ToString::to_string);
+
+            guild.members.insert(member.user.id, member);
+        }
+
+        #[test]
+        fn member_named_mention() {
+            let mut guild = gen();
+            insert_member(&mut guild, 300, "ferris", 0, None);
+
+            assert_eq!(guild.member_named("<@300>").unwrap().user.id, UserId(300));
+            assert_eq!(guild.member_named("<@!210>").unwrap().user.id, UserId(210));
+            assert!(guild.member_named("<@400>").is_none());
+        }
+
+        #[test]
+        fn member_named_without_discriminator() {
+            let mut guild = gen();
+            insert_member(&mut guild, 300, "ferris", 0, None);
+            insert_member(&mut guild, 301, "crab", 0, Some("ferris"));
+
+            assert_eq!(guild.member_named("ferris").unwrap().user.id, UserId(300));
+            assert_eq!(guild.member_named("@ferris").unwrap().user.id, UserId(300));
+            assert_eq!(guild.member_named("test").unwrap().user.id, UserId(210));
+            assert!(guild.member_named("Ferris").is_none());
+        }
+
+        #[test]
+        fn member_named_case_insensitive() {
+            let mut guild = gen();
+            insert_member(&mut guild, 300, "ferris", 0, Some("Crab"));
+            insert_member(&mut guild, 302, "rustacean", 0, Some("CRAB"));
+            insert_member(&mut guild, 301, "crabby", 0, None);
+
+            assert!(guild.member_named("crab").is_none());
+            assert_eq!(guild.member_named_case_insensitive("CRAB").unwrap().user.id, UserId(302));
+            assert_eq!(guild.member_named_case_insensitive("crab").unwrap().user.id, UserId(300));
+            assert_eq!(guild.member_named_case_insensitive("AAAA").unwrap().user.id, UserId(210));
+            assert_eq!(guild.member_named_case_insensitive("RUST").unwrap().user.id, UserId(302));
+            assert_eq!(guild.member_named_case_insensitive("cra").unwrap().user.id, UserId(300));
+        }
+
+        #[test]
+        fn can_manage_owner() {
+            let mut guild = gen();
+            test_utils::insert_role(&mut guild, 10, 5);
+
+            let owner = guild.members[&UserId(210)].clone();
+            let admin = test_utils::member_with_roles(300, &[10]);
+
+            assert!(guild.can_manage(&owner, &admin));
+            assert!(!guild.can_manage(&admin, &owner));
+            assert!(!guild.can_manage(&owner, &owner));
+        }
+
+        #[test]
+        fn can_manage_higher_role() {
+            let mut guild = gen();
+            test_utils::insert_role(&mut guild, 10, 1);
+            test_utils::insert_role(&mut guild, 11, 2);
+            test_utils::insert_role(&mut guild, 12, 3);
+
+            let moderator = test_utils::member_with_roles(300, &[10, 12]);
+            let member = test_utils::member_with_roles(301, &[11]);
+            let newcomer = test_utils::member_with_roles(302, &[]);
+
+            assert_eq!(guild.member_highest_role(&moderator).unwrap().id, RoleId(12));
+            assert!(guild.member_highest_role(&newcomer).is_none());
+
+            assert!(guild.can_manage(&moderator, &member));
+            assert!(!guild.can_manage(&member, &moderator));
+            assert!(guild.can_manage(&member, &newcomer));
+            assert!(!guild.can_manage(&newcomer, &member));
+            assert!(!guild.can_manage(&newcomer, &test_utils::member_with_roles(303, &[])));
+            assert!(!guild.can_manage(&member, &test_utils::member_with_roles(303, &[11])));
+        }
+
+        #[test]
+        fn can_manage_equal_position() {
+            let mut guild = gen();
+            test_utils::insert_role(&mut guild, 10, 2);
+            test_utils::insert_role(&mut guild, 11, 2);
+
+            let older = test_utils::member_with_roles(300, &[10]);
+            let newer = test_utils::member_with_roles(301, &[11]);
+            let both = test_utils::member_with_roles(302, &[11, 10]);
+
+            assert_eq!(guild.member_highest_role(&both).unwrap().id, RoleId(10));
+            assert!(guild.can_manage(&older, &newer));
+            assert!(!guild.can_manage(&newer, &older));
+        }
     }
 }
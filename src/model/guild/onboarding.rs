@@ -0,0 +1,214 @@
+use serde::de::{Deserialize, Deserializer};
+
+use crate::internal::prelude::*;
+use crate::model::prelude::*;
+
+/// The onboarding of a [`Guild`], the prompts shown to new members to pick
+/// their roles and channels.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GuildOnboarding {
+    /// The Id of the guild the onboarding belongs to.
+    pub guild_id: GuildId,
+    /// The prompts shown during the onboarding and in the
+    /// "Channels & Roles" tab.
+    #[serde(default)]
+    pub prompts: Vec<OnboardingPrompt>,
+    /// The channels new members are opted into by default.
+    #[serde(default)]
+    pub default_channel_ids: Vec<ChannelId>,
+    /// Whether the onboarding is enabled.
+    pub enabled: bool,
+    /// The criteria used to check that the onboarding is properly set up.
+    pub mode: OnboardingMode,
+}
+
+/// A prompt of a [`GuildOnboarding`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct OnboardingPrompt {
+    /// The Id of the prompt.
+    pub id: OnboardingPromptId,
+    /// The type of the prompt.
+    #[serde(rename = "type")]
+    pub kind: OnboardingPromptType,
+    /// The options of the prompt.
+    #[serde(default)]
+    pub options: Vec<OnboardingPromptOption>,
+    /// The title of the prompt.
+    pub title: String,
+    /// Whether members are limited to selecting one option.
+    pub single_select: bool,
+    /// Whether the prompt must be answered to complete the onboarding.
+    pub required: bool,
+    /// Whether the prompt is shown during the onboarding. Otherwise, it is
+    /// only shown in the "Channels & Roles" tab.
+    pub in_onboarding: bool,
+}
+
+/// An option of an [`OnboardingPrompt`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct OnboardingPromptOption {
+    /// The Id of the option.
+    pub id: OnboardingPromptOptionId,
+    /// The channels members are opted into when selecting the option.
+    #[serde(default)]
+    pub channel_ids: Vec<ChannelId>,
+    /// The roles members are given when selecting the option.
+    #[serde(default)]
+    pub role_ids: Vec<RoleId>,
+    /// The emoji of the option, if any.
+    #[serde(default, deserialize_with = "deserialize_option_emoji")]
+    pub emoji: Option<ReactionType>,
+    /// The title of the option.
+    pub title: String,
+    /// The description of the option.
+    pub description: Option<String>,
+}
+
+/// Discord sends an emoji with a null name and Id for options without one.
+fn deserialize_option_emoji<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> StdResult<Option<ReactionType>, D::Error> {
+    let value = Option::<Value>::deserialize(deserializer)?;
+
+    match value {
+        Some(Value::Object(ref map)) if !matches!(map.get("name"), Some(Value::String(_))) => Ok(None),
+        Some(value) => ReactionType::deserialize(value).map(Some).map_err(serde::de::Error::custom),
+        None => Ok(None),
+    }
+}
+
+/// The type of an [`OnboardingPrompt`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum OnboardingPromptType {
+    /// The options are shown as buttons.
+    MultipleChoice = 0,
+    /// The options are shown in a dropdown.
+    Dropdown = 1,
+}
+
+enum_number!(
+    OnboardingPromptType {
+        MultipleChoice,
+        Dropdown,
+    }
+);
+
+impl OnboardingPromptType {
+    pub fn num(self) -> u64 {
+        match self {
+            OnboardingPromptType::MultipleChoice => 0,
+            OnboardingPromptType::Dropdown => 1,
+        }
+    }
+}
+
+/// The criteria used to check that a [`GuildOnboarding`] is properly set up.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum OnboardingMode {
+    /// Only the default channels count towards the constraints.
+    Default = 0,
+    /// The default channels and the prompts count towards the constraints.
+    Advanced = 1,
+}
+
+enum_number!(
+    OnboardingMode {
+        Default,
+        Advanced,
+    }
+);
+
+impl OnboardingMode {
+    pub fn num(self) -> u64 {
+        match self {
+            OnboardingMode::Default => 0,
+            OnboardingMode::Advanced => 1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::model::prelude::*;
+    use serde_json::json;
+
+    #[test]
+    fn deserialize_multi_select_onboarding() {
+        let value = json!({
+            "guild_id": "613425648685547541",
+            "prompts": [
+                {
+                    "id": "1067461047608422473",
+                    "type": 0,
+                    "options": [
+                        {
+                            "id": "1067461047608422476",
+                            "channel_ids": ["1067461047608422470"],
+                            "role_ids": [],
+                            "emoji": {
+                                "id": null,
+                                "name": "\u{1f4e1}",
+                                "animated": false
+                            },
+                            "title": "News",
+                            "description": "Announcements about the project"
+                        },
+                        {
+                            "id": "1067461047608422477",
+                            "channel_ids": [],
+                            "role_ids": ["1067461047608422472"],
+                            "emoji": {
+                                "id": null,
+                                "name": null
+                            },
+                            "title": "Contributor",
+                            "description": null
+                        }
+                    ],
+                    "title": "What are you interested in?",
+                    "single_select": false,
+                    "required": false,
+                    "in_onboarding": true
+                }
+            ],
+            "default_channel_ids": ["1067461047608422470", "1067461047608422471"],
+            "enabled": true,
+            "mode": 0
+        });
+
+        let onboarding = serde_json::from_value::<GuildOnboarding>(value).unwrap();
+
+        assert_eq!(onboarding.guild_id, GuildId(613425648685547541));
+        assert!(onboarding.enabled);
+        assert_eq!(onboarding.mode, OnboardingMode::Default);
+        assert_eq!(onboarding.default_channel_ids, [
+            ChannelId(1067461047608422470),
+            ChannelId(1067461047608422471),
+        ]);
+
+        let prompt = &onboarding.prompts[0];
+        assert_eq!(prompt.id, OnboardingPromptId(1067461047608422473));
+        assert_eq!(prompt.kind, OnboardingPromptType::MultipleChoice);
+        assert!(!prompt.single_select);
+        assert!(!prompt.required);
+        assert!(prompt.in_onboarding);
+        assert_eq!(prompt.options.len(), 2);
+
+        let news = &prompt.options[0];
+        assert_eq!(news.id, OnboardingPromptOptionId(1067461047608422476));
+        assert_eq!(news.channel_ids, [ChannelId(1067461047608422470)]);
+        assert!(news.role_ids.is_empty());
+        assert_eq!(news.emoji, Some(ReactionType::Unicode("\u{1f4e1}".to_string())));
+        assert_eq!(news.description.as_deref(), Some("Announcements about the project"));
+
+        let contributor = &prompt.options[1];
+        assert_eq!(contributor.role_ids, [RoleId(1067461047608422472)]);
+        assert_eq!(contributor.emoji, None);
+        assert_eq!(contributor.description, None);
+    }
+}
@@ -48,6 +48,16 @@ pub struct PartialGuild {
     pub premium_subscription_count: u64,
     pub banner: Option<String>,
     pub vanity_url_code: Option<String>,
+    /// The approximate number of members in the guild.
+    ///
+    /// This is only present when the guild is fetched with
+    /// [`GuildId::to_partial_guild_with_counts`].
+    pub approximate_member_count: Option<u64>,
+    /// The approximate number of online members in the guild.
+    ///
+    /// This is only present when the guild is fetched with
+    /// [`GuildId::to_partial_guild_with_counts`].
+    pub approximate_presence_count: Option<u64>,
 }
 
 #[cfg(feature = "model")]
@@ -421,14 +431,16 @@ impl PartialGuild {
     }
 
     /// Gets the number of [`Member`]s that would be pruned with the given
-    /// number of days.
+    /// number of days, without removing them.
+    ///
+    /// Refer to [`GuildId::prune_count`] for more information.
     ///
     /// Requires the [Kick Members] permission.
     ///
     /// [Kick Members]: Permissions::KICK_MEMBERS
     #[inline]
-    pub async fn prune_count(&self, http: impl AsRef<Http>, days: u16) -> Result<GuildPrune> {
-        self.id.prune_count(&http, days).await
+    pub async fn prune_count(&self, http: impl AsRef<Http>, days: u16, include_roles: &[RoleId]) -> Result<u64> {
+        self.id.prune_count(&http, days, include_roles).await
     }
 
     /// Returns the Id of the shard associated with the guild.
@@ -505,13 +517,15 @@ impl PartialGuild {
         self.id.unban(&http, user_id).await
     }
 
-    /// Retrieve's the guild's vanity URL.
+    /// Retrieves the guild's vanity URL, such as `https://discord.gg/rust`.
+    ///
+    /// Returns `None` if the guild has no vanity URL set.
     ///
     /// **Note**: Requires the [Manage Guild] permission.
     ///
     /// [Manage Guild]: Permissions::MANAGE_GUILD
     #[inline]
-    pub async fn vanity_url(&self, http: impl AsRef<Http>) -> Result<String> {
+    pub async fn vanity_url(&self, http: impl AsRef<Http>) -> Result<Option<String>> {
         self.id.vanity_url(&http).await
     }
 
@@ -697,6 +711,14 @@ impl<'de> Deserialize<'de> for PartialGuild {
             Some(v) => Option::<String>::deserialize(v).map_err(DeError::custom)?,
             None => None,
         };
+        let approximate_member_count = match map.remove("approximate_member_count") {
+            Some(v) => Option::<u64>::deserialize(v).map_err(DeError::custom)?,
+            None => None,
+        };
+        let approximate_presence_count = match map.remove("approximate_presence_count") {
+            Some(v) => Option::<u64>::deserialize(v).map_err(DeError::custom)?,
+            None => None,
+        };
 
         Ok(Self {
             afk_channel_id,
@@ -720,6 +742,61 @@ impl<'de> Deserialize<'de> for PartialGuild {
             premium_subscription_count,
             banner,
             vanity_url_code,
+            approximate_member_count,
+            approximate_presence_count,
         })
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::model::prelude::*;
+    use serde_json::json;
+
+    fn guild_json() -> Value {
+        json!({
+            "id": "81384788765712384",
+            "name": "Discord API",
+            "icon": null,
+            "splash": null,
+            "owner_id": "53905483156684800",
+            "region": "us-east",
+            "afk_channel_id": null,
+            "afk_timeout": 300,
+            "widget_enabled": false,
+            "widget_channel_id": null,
+            "verification_level": 1,
+            "default_message_notifications": 1,
+            "mfa_level": 1,
+            "emojis": [],
+            "features": ["COMMUNITY"],
+            "roles": [],
+            "description": null,
+            "premium_tier": 2,
+            "premium_subscription_count": null,
+            "banner": null,
+            "vanity_url_code": null
+        })
+    }
+
+    #[test]
+    fn deserialize_with_counts() {
+        let mut value = guild_json();
+        value["approximate_member_count"] = json!(4876);
+        value["approximate_presence_count"] = json!(1107);
+
+        let guild = serde_json::from_value::<PartialGuild>(value).unwrap();
+
+        assert_eq!(guild.id, GuildId(81384788765712384));
+        assert_eq!(guild.approximate_member_count, Some(4876));
+        assert_eq!(guild.approximate_presence_count, Some(1107));
+    }
+
+    #[test]
+    fn deserialize_without_counts() {
+        let guild = serde_json::from_value::<PartialGuild>(guild_json()).unwrap();
+
+        assert_eq!(guild.approximate_member_count, None);
+        assert_eq!(guild.approximate_presence_count, None);
+    }
+}
@@ -110,6 +110,20 @@ impl Role {
         self.guild_id.edit_role(http, self.id, f).await
     }
 
+    /// Edits a [`Role`] with a reason, shown in the audit log. Refer to
+    /// [`edit`] for further documentation.
+    ///
+    /// [`edit`]: Self::edit
+    #[inline]
+    pub async fn edit_with_reason(
+        &self,
+        http: impl AsRef<Http>,
+        reason: impl AsRef<str>,
+        f: impl FnOnce(&mut EditRole) -> &mut EditRole,
+    ) -> Result<Role> {
+        self.guild_id.edit_role_with_reason(http, self.id, reason, f).await
+    }
+
     /// Searches the cache for the guild that owns the role.
     ///
     /// # Errors
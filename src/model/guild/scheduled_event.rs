@@ -0,0 +1,195 @@
+use chrono::{DateTime, Utc};
+use crate::model::prelude::*;
+
+/// Information about a guild scheduled event.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ScheduledEvent {
+    /// The Id of the scheduled event.
+    pub id: ScheduledEventId,
+    /// The Id of the guild that the event belongs to.
+    pub guild_id: GuildId,
+    /// The Id of the channel that the event will be hosted in, if any.
+    ///
+    /// This is always `None` for [`ScheduledEventType::External`] events.
+    pub channel_id: Option<ChannelId>,
+    /// The Id of the user that created the event.
+    pub creator_id: Option<UserId>,
+    /// The name of the event.
+    pub name: String,
+    /// The description of the event.
+    pub description: Option<String>,
+    /// The time at which the event is scheduled to start.
+    pub scheduled_start_time: DateTime<Utc>,
+    /// The time at which the event is scheduled to end.
+    ///
+    /// This is always present for [`ScheduledEventType::External`] events.
+    pub scheduled_end_time: Option<DateTime<Utc>>,
+    /// The privacy level of the event.
+    pub privacy_level: ScheduledEventPrivacyLevel,
+    /// The status of the event.
+    pub status: ScheduledEventStatus,
+    /// The type of the entity hosting the event.
+    pub entity_type: ScheduledEventType,
+    /// Additional metadata about the event.
+    ///
+    /// This is only present for [`ScheduledEventType::External`] events.
+    pub entity_metadata: Option<ScheduledEventMetadata>,
+    /// The user that created the event.
+    pub creator: Option<User>,
+    /// The number of users subscribed to the event.
+    ///
+    /// This is only present when fetching the events with the user count.
+    pub user_count: Option<u64>,
+}
+
+/// Additional metadata about a [`ScheduledEvent`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ScheduledEventMetadata {
+    /// The location of an external event.
+    pub location: Option<String>,
+}
+
+/// A user subscribed to a [`ScheduledEvent`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ScheduledEventUser {
+    /// The Id of the event the user is subscribed to.
+    #[serde(rename = "guild_scheduled_event_id")]
+    pub event_id: ScheduledEventId,
+    /// The subscribed user.
+    pub user: User,
+    /// The subscribed user's guild member data, if requested.
+    pub member: Option<PartialMember>,
+}
+
+/// The privacy level of a [`ScheduledEvent`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum ScheduledEventPrivacyLevel {
+    /// The event is only accessible to guild members.
+    GuildOnly = 2,
+}
+
+enum_number!(
+    ScheduledEventPrivacyLevel {
+        GuildOnly,
+    }
+);
+
+impl ScheduledEventPrivacyLevel {
+    pub fn num(self) -> u64 {
+        match self {
+            ScheduledEventPrivacyLevel::GuildOnly => 2,
+        }
+    }
+}
+
+/// The status of a [`ScheduledEvent`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum ScheduledEventStatus {
+    /// The event has not started yet.
+    Scheduled = 1,
+    /// The event is currently ongoing.
+    Active = 2,
+    /// The event has ended.
+    Completed = 3,
+    /// The event was cancelled before starting.
+    Canceled = 4,
+}
+
+enum_number!(
+    ScheduledEventStatus {
+        Scheduled,
+        Active,
+        Completed,
+        Canceled,
+    }
+);
+
+impl ScheduledEventStatus {
+    pub fn num(self) -> u64 {
+        match self {
+            ScheduledEventStatus::Scheduled => 1,
+            ScheduledEventStatus::Active => 2,
+            ScheduledEventStatus::Completed => 3,
+            ScheduledEventStatus::Canceled => 4,
+        }
+    }
+}
+
+/// The type of entity hosting a [`ScheduledEvent`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum ScheduledEventType {
+    /// The event is hosted in a stage channel.
+    StageInstance = 1,
+    /// The event is hosted in a voice channel.
+    Voice = 2,
+    /// The event is hosted outside of Discord.
+    External = 3,
+}
+
+enum_number!(
+    ScheduledEventType {
+        StageInstance,
+        Voice,
+        External,
+    }
+);
+
+impl ScheduledEventType {
+    pub fn num(self) -> u64 {
+        match self {
+            ScheduledEventType::StageInstance => 1,
+            ScheduledEventType::Voice => 2,
+            ScheduledEventType::External => 3,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::prelude::*;
+    use crate::model::prelude::*;
+    use serde_json::json;
+
+    #[test]
+    fn deserialize_scheduled_event() {
+        let value = json!({
+            "id": "912768341709373460",
+            "guild_id": "381870553235193857",
+            "channel_id": null,
+            "creator_id": "210",
+            "name": "Movie night",
+            "description": "Watching a movie together",
+            "scheduled_start_time": "2021-11-25T20:00:00+00:00",
+            "scheduled_end_time": "2021-11-25T23:00:00+00:00",
+            "privacy_level": 2,
+            "status": 1,
+            "entity_type": 3,
+            "entity_id": null,
+            "entity_metadata": {
+                "location": "The cinema"
+            },
+            "user_count": 12
+        });
+
+        let event = serde_json::from_value::<ScheduledEvent>(value).unwrap();
+
+        assert_eq!(event.id, ScheduledEventId(912768341709373460));
+        assert_eq!(event.guild_id, GuildId(381870553235193857));
+        assert_eq!(event.channel_id, None);
+        assert_eq!(event.creator_id, Some(UserId(210)));
+        assert_eq!(event.name, "Movie night");
+        assert_eq!(event.scheduled_start_time, Utc.ymd(2021, 11, 25).and_hms(20, 0, 0));
+        assert_eq!(event.scheduled_end_time, Some(Utc.ymd(2021, 11, 25).and_hms(23, 0, 0)));
+        assert_eq!(event.privacy_level, ScheduledEventPrivacyLevel::GuildOnly);
+        assert_eq!(event.status, ScheduledEventStatus::Scheduled);
+        assert_eq!(event.entity_type, ScheduledEventType::External);
+        assert_eq!(event.entity_metadata.unwrap().location.as_deref(), Some("The cinema"));
+        assert_eq!(event.user_count, Some(12));
+    }
+}
@@ -0,0 +1,79 @@
+use chrono::{DateTime, Utc};
+use crate::model::prelude::*;
+
+/// A template of a guild, which can be used to create new guilds with the
+/// same channels, roles and settings.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GuildTemplate {
+    /// The code of the template, used to create guilds from it.
+    pub code: String,
+    /// The name of the template.
+    pub name: String,
+    /// The description of the template.
+    pub description: Option<String>,
+    /// The number of guilds created from the template.
+    pub usage_count: u64,
+    /// The Id of the user who created the template.
+    pub creator_id: UserId,
+    /// The user who created the template.
+    pub creator: User,
+    /// When the template was created.
+    pub created_at: DateTime<Utc>,
+    /// When the template was last synced with its source guild.
+    pub updated_at: DateTime<Utc>,
+    /// The Id of the guild the template was created from.
+    pub source_guild_id: GuildId,
+    /// The snapshot of the source guild, as it was when the template was
+    /// last synced.
+    pub serialized_source_guild: Value,
+    /// Whether the source guild was changed since the template was last
+    /// synced.
+    pub is_dirty: Option<bool>,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::model::prelude::*;
+    use serde_json::json;
+
+    #[test]
+    fn deserialize_template() {
+        let value = json!({
+            "code": "hgM48av5Q69A",
+            "name": "Friends & Family",
+            "description": null,
+            "usage_count": 49605,
+            "creator_id": "132837293881950208",
+            "creator": {
+                "id": "132837293881950208",
+                "username": "hoges",
+                "avatar": "79b0d9f8c340f2d43e1f8fdc608eb0ef",
+                "discriminator": "0001",
+                "public_flags": 131072
+            },
+            "created_at": "2020-04-02T21:10:38+00:00",
+            "updated_at": "2020-05-01T17:57:38+00:00",
+            "source_guild_id": "678070694164299796",
+            "serialized_source_guild": {
+                "name": "Friends & Family",
+                "region": "us-west",
+                "roles": [{"id": 0, "name": "@everyone", "permissions": "104324689"}],
+                "channels": [{"name": "general", "position": 0, "type": 0, "id": 1}]
+            },
+            "is_dirty": null
+        });
+
+        let template = serde_json::from_value::<GuildTemplate>(value).unwrap();
+
+        assert_eq!(template.code, "hgM48av5Q69A");
+        assert_eq!(template.name, "Friends & Family");
+        assert_eq!(template.description, None);
+        assert_eq!(template.usage_count, 49605);
+        assert_eq!(template.creator_id, UserId(132837293881950208));
+        assert_eq!(template.creator.name, "hoges");
+        assert_eq!(template.source_guild_id, GuildId(678070694164299796));
+        assert_eq!(template.serialized_source_guild["channels"][0]["name"], "general");
+        assert_eq!(template.is_dirty, None);
+    }
+}
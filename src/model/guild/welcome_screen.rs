@@ -0,0 +1,70 @@
+use crate::model::prelude::*;
+
+/// The welcome screen of a [`Guild`], shown to new members of a community
+/// guild.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GuildWelcomeScreen {
+    /// The description of the guild shown in the welcome screen.
+    pub description: Option<String>,
+    /// The channels shown in the welcome screen, up to 5.
+    #[serde(default)]
+    pub welcome_channels: Vec<GuildWelcomeChannel>,
+}
+
+/// A channel shown in a [`GuildWelcomeScreen`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GuildWelcomeChannel {
+    /// The Id of the channel.
+    pub channel_id: ChannelId,
+    /// The description shown for the channel.
+    pub description: String,
+    /// The Id of the channel's emoji, if it is a custom one.
+    pub emoji_id: Option<EmojiId>,
+    /// The name of the channel's emoji if it is a custom one, or the emoji
+    /// itself otherwise.
+    pub emoji_name: Option<String>,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::model::prelude::*;
+    use serde_json::json;
+
+    #[test]
+    fn deserialize_welcome_screen() {
+        let value = json!({
+            "description": "Discord Developers is a place to learn about Discord's API",
+            "welcome_channels": [
+                {
+                    "channel_id": "697138785317814292",
+                    "description": "Follow for official Discord API updates",
+                    "emoji_id": null,
+                    "emoji_name": "\u{1f4e1}"
+                },
+                {
+                    "channel_id": "697236247739105340",
+                    "description": "Get help with Bot Verifications",
+                    "emoji_id": "713090379335204914",
+                    "emoji_name": "verified"
+                }
+            ]
+        });
+
+        let screen = serde_json::from_value::<GuildWelcomeScreen>(value).unwrap();
+
+        assert_eq!(screen.description.as_deref(), Some("Discord Developers is a place to learn about Discord's API"));
+        assert_eq!(screen.welcome_channels.len(), 2);
+
+        let channel = &screen.welcome_channels[0];
+        assert_eq!(channel.channel_id, ChannelId(697138785317814292));
+        assert_eq!(channel.emoji_id, None);
+        assert_eq!(channel.emoji_name.as_deref(), Some("\u{1f4e1}"));
+
+        let channel = &screen.welcome_channels[1];
+        assert_eq!(channel.description, "Get help with Bot Verifications");
+        assert_eq!(channel.emoji_id, Some(EmojiId(713090379335204914)));
+        assert_eq!(channel.emoji_name.as_deref(), Some("verified"));
+    }
+}
@@ -1,23 +1,48 @@
 //! A collection of newtypes defining type-strong IDs.
 
-use chrono::{Utc, DateTime, NaiveDateTime};
+use chrono::{Utc, DateTime, TimeZone};
 use crate::internal::prelude::*;
 use serde::de::{Deserialize, Deserializer};
 use std::fmt::{Display, Formatter, Result as FmtResult};
 use super::utils::U64Visitor;
 
+/// The Discord epoch, the first second of 2015, in seconds since the Unix
+/// epoch.
+const DISCORD_EPOCH: i64 = 1_420_070_400;
+
+/// Decodes the creation time of a snowflake, stored in its upper 42 bits as
+/// the number of milliseconds since the [`DISCORD_EPOCH`].
+fn snowflake_timestamp(snowflake: u64) -> DateTime<Utc> {
+    let offset = snowflake >> 22;
+    let secs = offset / 1000;
+    let nanos = (offset % 1000) * 1_000_000; // 1 million nanoseconds in a millisecond
+
+    Utc.timestamp_opt(DISCORD_EPOCH + secs as i64, nanos as u32).unwrap()
+}
+
+/// An Id wrapping a Discord snowflake, which encodes the time it was created
+/// at.
+///
+/// This allows to retrieve the creation time of any kind of Id, such as in
+/// generic code.
+pub trait Snowflake {
+    /// The raw value of the snowflake.
+    fn snowflake(&self) -> u64;
+
+    /// Retrieves the time that the Id was created at.
+    fn created_at(&self) -> DateTime<Utc> {
+        snowflake_timestamp(self.snowflake())
+    }
+}
+
 macro_rules! id_u64 {
     ($($name:ident;)*) => {
         $(
             impl $name {
                 /// Retrieves the time that the Id was created at.
+                #[inline]
                 pub fn created_at(&self) -> DateTime<Utc> {
-                    let offset = self.0 >> 22;
-                    let secs = offset / 1000;
-                    let millis = (offset % 1000) * 1_000_000; // 1 million nanoseconds in a millisecond
-
-                    let tm = NaiveDateTime::from_timestamp(1_420_070_400 + secs as i64, millis as u32);
-                    DateTime::from_utc(tm, Utc)
+                    snowflake_timestamp(self.0)
                 }
 
                 /// Immutably borrow inner Id.
@@ -33,6 +58,12 @@ macro_rules! id_u64 {
                 }
             }
 
+            impl Snowflake for $name {
+                fn snowflake(&self) -> u64 {
+                    self.0
+                }
+            }
+
             // This is a hack so functions can accept iterators that either:
             // 1. return the id itself (e.g: `MessageId`)
             // 2. return a reference to it (`&MessageId`).
@@ -131,6 +162,22 @@ pub struct AuditLogEntryId(pub u64);
 #[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
 pub struct AttachmentId(u64);
 
+/// An identifier for a guild scheduled event.
+#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
+pub struct ScheduledEventId(pub u64);
+
+/// An identifier for an auto moderation rule.
+#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
+pub struct AutoModRuleId(pub u64);
+
+/// An identifier for a tag of a forum channel.
+#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
+pub struct ForumTagId(pub u64);
+
+/// An identifier for a stage instance.
+#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
+pub struct StageInstanceId(pub u64);
+
 /// An identifier for a sticker.
 #[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
 pub struct StickerId(pub u64);
@@ -139,18 +186,78 @@ pub struct StickerId(pub u64);
 #[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
 pub struct StickerPackId(pub u64);
 
+/// An identifier for an interaction.
+#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
+pub struct InteractionId(pub u64);
+
+/// An identifier for an application command.
+#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
+pub struct CommandId(pub u64);
+
+/// An identifier for the version of an application command.
+#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
+pub struct CommandVersionId(pub u64);
+
+/// An identifier for a prompt of a guild's onboarding.
+#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
+pub struct OnboardingPromptId(pub u64);
+
+/// An identifier for an option of a guild's onboarding prompt.
+#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
+pub struct OnboardingPromptOptionId(pub u64);
+
 id_u64! {
     AttachmentId;
     ApplicationId;
+    AutoModRuleId;
     ChannelId;
+    CommandId;
+    CommandVersionId;
     EmojiId;
+    ForumTagId;
     GuildId;
     IntegrationId;
+    InteractionId;
     MessageId;
+    OnboardingPromptId;
+    OnboardingPromptOptionId;
     RoleId;
+    ScheduledEventId;
+    StageInstanceId;
     StickerId;
     StickerPackId;
     UserId;
     WebhookId;
     AuditLogEntryId;
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn created_at() {
+        let created_at = MessageId(175928847299117063).created_at();
+        assert_eq!(created_at.to_rfc3339(), "2016-04-30T11:18:25.796+00:00");
+        assert_eq!(created_at.timestamp_millis(), 1_462_015_105_796);
+
+        assert_eq!(GuildId(0).created_at().to_rfc3339(), "2015-01-01T00:00:00+00:00");
+    }
+
+    #[test]
+    fn snowflake_created_at() {
+        fn created_at<S: Snowflake>(id: S) -> String {
+            Snowflake::created_at(&id).to_rfc3339()
+        }
+
+        let snowflake = 175928847299117063;
+        let expected = "2016-04-30T11:18:25.796+00:00";
+
+        assert_eq!(created_at(MessageId(snowflake)), expected);
+        assert_eq!(created_at(UserId(snowflake)), expected);
+        assert_eq!(created_at(ChannelId(snowflake)), expected);
+        assert_eq!(created_at(GuildId(snowflake)), expected);
+        assert_eq!(created_at(RoleId(snowflake)), expected);
+        assert_eq!(created_at(EmojiId(snowflake)), expected);
+    }
+}
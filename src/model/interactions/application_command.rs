@@ -0,0 +1,623 @@
+use std::collections::HashMap;
+
+use serde::de::Error as DeError;
+use serde::ser::{Serialize, Serializer};
+
+use crate::model::prelude::*;
+use super::prepare_interaction_map;
+
+#[cfg(feature = "model")]
+use crate::builder::{
+    CreateApplicationCommand,
+    CreateApplicationCommands,
+    CreateInteractionResponse,
+    CreateInteractionResponseFollowup,
+    EditInteractionResponse,
+};
+#[cfg(feature = "model")]
+use crate::http::Http;
+
+/// An interaction sent when a user uses an application command.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(remote = "Self")]
+#[non_exhaustive]
+pub struct ApplicationCommandInteraction {
+    /// The Id of the interaction.
+    pub id: InteractionId,
+    /// The Id of the application the interaction was sent to.
+    pub application_id: ApplicationId,
+    /// The type of the interaction, always
+    /// [`InteractionType::ApplicationCommand`].
+    #[serde(rename = "type")]
+    pub kind: InteractionType,
+    /// The data of the command which was used.
+    pub data: ApplicationCommandInteractionData,
+    /// The Id of the guild the command was used in.
+    pub guild_id: Option<GuildId>,
+    /// The Id of the channel the command was used in.
+    pub channel_id: ChannelId,
+    /// The member which used the command.
+    ///
+    /// **Note**: This is only present if the command was used in a guild.
+    pub member: Option<Member>,
+    /// The user which used the command.
+    pub user: User,
+    /// The token used to respond to the interaction.
+    pub token: String,
+    /// The version of the interaction, always `1`.
+    pub version: u8,
+    /// The selected language of the user which used the command.
+    pub locale: Option<String>,
+    /// The preferred language of the guild the command was used in.
+    pub guild_locale: Option<String>,
+}
+
+#[cfg(feature = "model")]
+impl ApplicationCommandInteraction {
+    /// Responds to the interaction.
+    ///
+    /// Refer to the documentation of [`CreateInteractionResponse`] for a full
+    /// list of methods.
+    ///
+    /// **Note**: An interaction must be responded to within 3 seconds, and only
+    /// once.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the interaction was already responded to,
+    /// or its token has expired.
+    ///
+    /// Returns a [`ModelError::InvalidModal`] if a modal response does not
+    /// have between 1 and 5 rows of a single text input, or a [`ModelError`]
+    /// if the message of the response is over the [limits] of Discord.
+    ///
+    /// [`Error::Http`]: crate::error::Error::Http
+    /// [`ModelError`]: crate::model::ModelError
+    /// [`ModelError::InvalidModal`]: crate::model::ModelError::InvalidModal
+    /// [limits]: crate::model::interactions#limits
+    pub async fn create_interaction_response<F>(&self, http: impl AsRef<Http>, f: F) -> Result<()>
+    where F: FnOnce(&mut CreateInteractionResponse) -> &mut CreateInteractionResponse
+    {
+        super::create_interaction_response(http, self.id, &self.token, f).await
+    }
+
+    /// Edits the initial response to the interaction.
+    ///
+    /// Refer to the documentation of [`EditInteractionResponse`] for a full
+    /// list of methods.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the interaction was not responded to yet,
+    /// or its token has expired.
+    ///
+    /// Returns a [`ModelError`] if the edited message is over the [limits] of
+    /// Discord.
+    ///
+    /// [`Error::Http`]: crate::error::Error::Http
+    /// [`ModelError`]: crate::model::ModelError
+    /// [limits]: crate::model::interactions#limits
+    pub async fn edit_original_interaction_response<F>(&self, http: impl AsRef<Http>, f: F) -> Result<Message>
+    where F: FnOnce(&mut EditInteractionResponse) -> &mut EditInteractionResponse
+    {
+        super::edit_original_interaction_response(http, self.application_id, &self.token, f).await
+    }
+
+    /// Deletes the initial response to the interaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the interaction was not responded to yet,
+    /// or its token has expired.
+    ///
+    /// [`Error::Http`]: crate::error::Error::Http
+    pub async fn delete_original_interaction_response(&self, http: impl AsRef<Http>) -> Result<()> {
+        http.as_ref().delete_original_interaction_response(self.application_id.0, &self.token).await
+    }
+
+    /// Creates a follow-up message to the interaction.
+    ///
+    /// Refer to the documentation of [`CreateInteractionResponseFollowup`] for
+    /// a full list of methods.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the message is invalid, or the token of
+    /// the interaction has expired.
+    ///
+    /// Returns a [`ModelError`] if the follow-up message is over the [limits]
+    /// of Discord.
+    ///
+    /// [`Error::Http`]: crate::error::Error::Http
+    /// [`ModelError`]: crate::model::ModelError
+    /// [limits]: crate::model::interactions#limits
+    pub async fn create_followup_message<F>(&self, http: impl AsRef<Http>, f: F) -> Result<Message>
+    where F: FnOnce(&mut CreateInteractionResponseFollowup) -> &mut CreateInteractionResponseFollowup
+    {
+        super::create_followup_message(http, self.application_id, &self.token, f).await
+    }
+}
+
+impl<'de> Deserialize<'de> for ApplicationCommandInteraction {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        let mut map = JsonMap::deserialize(deserializer)?;
+        prepare_interaction_map(&mut map)?;
+
+        Self::deserialize(Value::Object(map)).map_err(DeError::custom)
+    }
+}
+
+impl Serialize for ApplicationCommandInteraction {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+        where S: Serializer {
+        Self::serialize(self, serializer)
+    }
+}
+
+/// The data of an [`ApplicationCommandInteraction`] or an
+/// [`AutocompleteInteraction`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ApplicationCommandInteractionData {
+    /// The Id of the command.
+    pub id: CommandId,
+    /// The name of the command.
+    pub name: String,
+    /// The type of the command.
+    #[serde(rename = "type")]
+    pub kind: ApplicationCommandType,
+    /// The options the user filled in.
+    #[serde(default)]
+    pub options: Vec<ApplicationCommandInteractionDataOption>,
+    /// The users, members, roles, channels and messages referenced by the
+    /// options.
+    #[serde(default)]
+    pub resolved: ApplicationCommandInteractionDataResolved,
+    /// The Id of the user or message the command was used on.
+    ///
+    /// **Note**: This is only present on [user][`ApplicationCommandType::User`]
+    /// and [message][`ApplicationCommandType::Message`] commands.
+    pub target_id: Option<u64>,
+}
+
+impl ApplicationCommandInteractionData {
+    /// Finds the option currently being autocompleted, searching through the
+    /// options of subcommands and subcommand groups.
+    pub fn focused_option(&self) -> Option<&ApplicationCommandInteractionDataOption> {
+        find_focused_option(&self.options)
+    }
+}
+
+fn find_focused_option(options: &[ApplicationCommandInteractionDataOption]) -> Option<&ApplicationCommandInteractionDataOption> {
+    options.iter().find_map(|option| {
+        if option.focused {
+            Some(option)
+        } else {
+            find_focused_option(&option.options)
+        }
+    })
+}
+
+/// An option filled in by the user when using an application command.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ApplicationCommandInteractionDataOption {
+    /// The name of the option.
+    pub name: String,
+    /// The type of the option.
+    #[serde(rename = "type")]
+    pub kind: ApplicationCommandOptionType,
+    /// The value of the option.
+    ///
+    /// **Note**: This is absent on subcommands and subcommand groups, whose
+    /// own options are found in [`options`].
+    ///
+    /// [`options`]: Self::options
+    pub value: Option<Value>,
+    /// The options of a subcommand or subcommand group.
+    #[serde(default)]
+    pub options: Vec<ApplicationCommandInteractionDataOption>,
+    /// Whether the option is the one currently being autocompleted.
+    #[serde(default)]
+    pub focused: bool,
+}
+
+/// The data referenced by the options of an application command, keyed by
+/// their Id.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ApplicationCommandInteractionDataResolved {
+    #[serde(default)]
+    pub users: HashMap<UserId, User>,
+    /// The members referenced by the options.
+    ///
+    /// **Note**: Their user is found in [`users`].
+    ///
+    /// [`users`]: Self::users
+    #[serde(default)]
+    pub members: HashMap<UserId, PartialMember>,
+    #[serde(default)]
+    pub roles: HashMap<RoleId, Role>,
+    #[serde(default)]
+    pub channels: HashMap<ChannelId, PartialChannel>,
+    #[serde(default)]
+    pub messages: HashMap<MessageId, Message>,
+}
+
+/// A channel referenced by the options of an application command.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct PartialChannel {
+    /// The Id of the channel.
+    pub id: ChannelId,
+    /// The name of the channel.
+    pub name: Option<String>,
+    /// The type of the channel.
+    #[serde(rename = "type")]
+    pub kind: ChannelType,
+    /// The permissions of the user in the channel.
+    pub permissions: Option<Permissions>,
+}
+
+/// An application command registered by an application, either globally or
+/// in a guild.
+///
+/// [Discord docs](https://discord.com/developers/docs/interactions/application-commands#application-command-object).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ApplicationCommand {
+    /// The Id of the command.
+    pub id: CommandId,
+    /// The Id of the application the command belongs to.
+    pub application_id: ApplicationId,
+    /// The Id of the guild the command is registered in.
+    ///
+    /// **Note**: This is absent on global commands.
+    pub guild_id: Option<GuildId>,
+    /// The type of the command.
+    #[serde(rename = "type", default = "default_command_type")]
+    pub kind: ApplicationCommandType,
+    /// The name of the command.
+    pub name: String,
+    /// The description of the command.
+    ///
+    /// **Note**: This is empty on [user][`ApplicationCommandType::User`] and
+    /// [message][`ApplicationCommandType::Message`] commands.
+    pub description: String,
+    /// The options of the command.
+    #[serde(default)]
+    pub options: Vec<ApplicationCommandOption>,
+    /// The permissions a member needs by default to use the command.
+    pub default_member_permissions: Option<Permissions>,
+    /// An autoincrementing version identifier, updated whenever the command
+    /// is changed.
+    pub version: CommandVersionId,
+}
+
+#[cfg(feature = "model")]
+impl ApplicationCommand {
+    /// Creates a global application command, available in every guild the
+    /// application is in and in direct messages.
+    ///
+    /// Refer to the documentation of [`CreateApplicationCommand`] for a full
+    /// list of methods.
+    ///
+    /// **Note**: Global commands can take up to an hour to be available.
+    /// Prefer [`GuildId::create_application_command`] while testing.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidCommandOptions`] if the options are
+    /// nested incorrectly.
+    ///
+    /// Returns an [`Error::Http`] if the command is invalid.
+    ///
+    /// [`Error::Http`]: crate::error::Error::Http
+    pub async fn create_global_application_command<F>(
+        http: impl AsRef<Http>,
+        application_id: impl Into<ApplicationId>,
+        f: F,
+    ) -> Result<ApplicationCommand>
+    where F: FnOnce(&mut CreateApplicationCommand) -> &mut CreateApplicationCommand {
+        let mut command = CreateApplicationCommand::default();
+        f(&mut command);
+        let command = command.build();
+
+        ApplicationCommand::check_options(&command)?;
+
+        http.as_ref().create_global_application_command(application_id.into().0, &command).await
+    }
+
+    /// Overwrites all the global application commands of the application with
+    /// the given ones.
+    ///
+    /// Refer to the documentation of [`CreateApplicationCommands`] for a full
+    /// list of methods.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidCommandOptions`] if the options of a
+    /// command are nested incorrectly.
+    ///
+    /// Returns an [`Error::Http`] if a command is invalid.
+    ///
+    /// [`Error::Http`]: crate::error::Error::Http
+    pub async fn set_global_application_commands<F>(
+        http: impl AsRef<Http>,
+        application_id: impl Into<ApplicationId>,
+        f: F,
+    ) -> Result<Vec<ApplicationCommand>>
+    where F: FnOnce(&mut CreateApplicationCommands) -> &mut CreateApplicationCommands {
+        let mut commands = CreateApplicationCommands::default();
+        f(&mut commands);
+
+        for command in &commands.0 {
+            ApplicationCommand::check_options(command)?;
+        }
+
+        let commands = Value::Array(commands.0);
+
+        http.as_ref().create_global_application_commands(application_id.into().0, &commands).await
+    }
+
+    /// Gets all the global application commands of the application.
+    #[inline]
+    pub async fn get_global_application_commands(
+        http: impl AsRef<Http>,
+        application_id: impl Into<ApplicationId>,
+    ) -> Result<Vec<ApplicationCommand>> {
+        http.as_ref().get_global_application_commands(application_id.into().0).await
+    }
+
+    /// Gets a global application command of the application by its Id.
+    #[inline]
+    pub async fn get_global_application_command(
+        http: impl AsRef<Http>,
+        application_id: impl Into<ApplicationId>,
+        command_id: impl Into<CommandId>,
+    ) -> Result<ApplicationCommand> {
+        http.as_ref().get_global_application_command(application_id.into().0, command_id.into().0).await
+    }
+
+    /// Deletes a global application command of the application.
+    #[inline]
+    pub async fn delete_global_application_command(
+        http: impl AsRef<Http>,
+        application_id: impl Into<ApplicationId>,
+        command_id: impl Into<CommandId>,
+    ) -> Result<()> {
+        http.as_ref().delete_global_application_command(application_id.into().0, command_id.into().0).await
+    }
+
+    /// Checks that the options of a built [`CreateApplicationCommand`] are
+    /// nested correctly.
+    pub(crate) fn check_options(command: &Value) -> Result<()> {
+        match command.get("options") {
+            Some(Value::Array(options)) => check_option_nesting(options, None),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(feature = "model")]
+fn check_option_nesting(options: &[Value], parent: Option<u64>) -> Result<()> {
+    let group = ApplicationCommandOptionType::SubCommandGroup.num();
+    let subcommand = ApplicationCommandOptionType::SubCommand.num();
+
+    for option in options {
+        let kind = option.get("type").and_then(Value::as_u64).unwrap_or_default();
+        let is_subcommand = kind == group || kind == subcommand;
+
+        let allowed = match parent {
+            None => true,
+            Some(parent) if parent == group => kind == subcommand,
+            Some(parent) if parent == subcommand => !is_subcommand,
+            Some(_) => false,
+        };
+
+        if !allowed {
+            return Err(Error::Model(ModelError::InvalidCommandOptions));
+        }
+
+        if let Some(Value::Array(options)) = option.get("options") {
+            check_option_nesting(options, Some(kind))?;
+        }
+    }
+
+    Ok(())
+}
+
+fn default_command_type() -> ApplicationCommandType {
+    ApplicationCommandType::ChatInput
+}
+
+/// An option of an [`ApplicationCommand`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ApplicationCommandOption {
+    /// The type of the option.
+    #[serde(rename = "type")]
+    pub kind: ApplicationCommandOptionType,
+    /// The name of the option.
+    pub name: String,
+    /// The description of the option.
+    pub description: String,
+    /// Whether the option must be filled in.
+    #[serde(default)]
+    pub required: bool,
+    /// The choices the user has to pick from.
+    #[serde(default)]
+    pub choices: Vec<ApplicationCommandOptionChoice>,
+    /// The options of a subcommand or subcommand group.
+    #[serde(default)]
+    pub options: Vec<ApplicationCommandOption>,
+    /// The types of channel the user can pick from, if the option is of type
+    /// [`ApplicationCommandOptionType::Channel`].
+    #[serde(default)]
+    pub channel_types: Vec<ChannelType>,
+    /// The minimum value of an integer or number option.
+    pub min_value: Option<Number>,
+    /// The maximum value of an integer or number option.
+    pub max_value: Option<Number>,
+    /// Whether the option is autocompleted.
+    #[serde(default)]
+    pub autocomplete: bool,
+}
+
+/// A choice of an [`ApplicationCommandOption`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ApplicationCommandOptionChoice {
+    /// The name of the choice, shown to the user.
+    pub name: String,
+    /// The value of the choice, either a string, an integer or a number.
+    pub value: Value,
+}
+
+/// The type of an application command.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum ApplicationCommandType {
+    /// A slash command, used by typing it in the chat.
+    ChatInput = 1,
+    /// A command used from the context menu of a user.
+    User = 2,
+    /// A command used from the context menu of a message.
+    Message = 3,
+}
+
+enum_number!(
+    ApplicationCommandType {
+        ChatInput,
+        User,
+        Message,
+    }
+);
+
+impl ApplicationCommandType {
+    #[inline]
+    pub fn num(self) -> u64 {
+        match self {
+            ApplicationCommandType::ChatInput => 1,
+            ApplicationCommandType::User => 2,
+            ApplicationCommandType::Message => 3,
+        }
+    }
+}
+
+/// The type of an application command option.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum ApplicationCommandOptionType {
+    SubCommand = 1,
+    SubCommandGroup = 2,
+    String = 3,
+    Integer = 4,
+    Boolean = 5,
+    User = 6,
+    Channel = 7,
+    Role = 8,
+    /// Either a user or a role.
+    Mentionable = 9,
+    /// A floating point number.
+    Number = 10,
+    Attachment = 11,
+}
+
+enum_number!(
+    ApplicationCommandOptionType {
+        SubCommand,
+        SubCommandGroup,
+        String,
+        Integer,
+        Boolean,
+        User,
+        Channel,
+        Role,
+        Mentionable,
+        Number,
+        Attachment,
+    }
+);
+
+impl ApplicationCommandOptionType {
+    #[inline]
+    pub fn num(self) -> u64 {
+        match self {
+            ApplicationCommandOptionType::SubCommand => 1,
+            ApplicationCommandOptionType::SubCommandGroup => 2,
+            ApplicationCommandOptionType::String => 3,
+            ApplicationCommandOptionType::Integer => 4,
+            ApplicationCommandOptionType::Boolean => 5,
+            ApplicationCommandOptionType::User => 6,
+            ApplicationCommandOptionType::Channel => 7,
+            ApplicationCommandOptionType::Role => 8,
+            ApplicationCommandOptionType::Mentionable => 9,
+            ApplicationCommandOptionType::Number => 10,
+            ApplicationCommandOptionType::Attachment => 11,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[cfg(feature = "model")]
+    #[test]
+    fn check_option_nesting() {
+        use super::{ApplicationCommand, ApplicationCommandOptionType};
+        use crate::builder::CreateApplicationCommand;
+        use crate::model::ModelError;
+        use crate::Error;
+
+        let mut command = CreateApplicationCommand::default();
+        command.name("tag").description("Manages tags").create_option(|group| {
+            group.kind(ApplicationCommandOptionType::SubCommandGroup)
+                .name("color")
+                .description("Colored tags")
+                .create_sub_option(|sub| {
+                    sub.kind(ApplicationCommandOptionType::SubCommand)
+                        .name("set")
+                        .description("Sets the color of a tag")
+                        .create_sub_option(|o| {
+                            o.kind(ApplicationCommandOptionType::String)
+                                .name("color")
+                                .description("The color")
+                        })
+                })
+        });
+        assert!(ApplicationCommand::check_options(&command.clone().build()).is_ok());
+
+        // A subcommand group can't directly hold a string option.
+        let mut command = CreateApplicationCommand::default();
+        command.name("tag").description("Manages tags").create_option(|group| {
+            group.kind(ApplicationCommandOptionType::SubCommandGroup)
+                .name("color")
+                .description("Colored tags")
+                .create_sub_option(|o| {
+                    o.kind(ApplicationCommandOptionType::String)
+                        .name("color")
+                        .description("The color")
+                })
+        });
+
+        match ApplicationCommand::check_options(&command.build()) {
+            Err(Error::Model(ModelError::InvalidCommandOptions)) => {},
+            other => panic!("unexpected result: {:?}", other),
+        }
+
+        // A subcommand can't hold a subcommand group.
+        let mut command = CreateApplicationCommand::default();
+        command.name("tag").description("Manages tags").create_option(|sub| {
+            sub.kind(ApplicationCommandOptionType::SubCommand)
+                .name("set")
+                .description("Sets a tag")
+                .create_sub_option(|group| {
+                    group.kind(ApplicationCommandOptionType::SubCommandGroup)
+                        .name("color")
+                        .description("Colored tags")
+                })
+        });
+
+        assert!(ApplicationCommand::check_options(&command.build()).is_err());
+    }
+}
@@ -0,0 +1,208 @@
+use serde::de::Error as DeError;
+use serde::ser::{Serialize, Serializer};
+
+use crate::model::prelude::*;
+use super::prepare_interaction_map;
+
+#[cfg(feature = "model")]
+use crate::builder::{CreateAutocompleteResponse, CreateInteractionResponse};
+#[cfg(feature = "model")]
+use crate::constants;
+#[cfg(feature = "model")]
+use crate::http::Http;
+#[cfg(feature = "model")]
+use crate::utils;
+
+/// An interaction sent while a user is typing an application command option
+/// with autocompletion.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(remote = "Self")]
+#[non_exhaustive]
+pub struct AutocompleteInteraction {
+    /// The Id of the interaction.
+    pub id: InteractionId,
+    /// The Id of the application the interaction was sent to.
+    pub application_id: ApplicationId,
+    /// The type of the interaction, always [`InteractionType::Autocomplete`].
+    #[serde(rename = "type")]
+    pub kind: InteractionType,
+    /// The data of the command being typed, including the options filled in
+    /// so far.
+    pub data: ApplicationCommandInteractionData,
+    /// The Id of the guild the command is being used in.
+    pub guild_id: Option<GuildId>,
+    /// The Id of the channel the command is being used in.
+    pub channel_id: ChannelId,
+    /// The member which is using the command.
+    ///
+    /// **Note**: This is only present if the command is used in a guild.
+    pub member: Option<Member>,
+    /// The user which is using the command.
+    pub user: User,
+    /// The token used to respond to the interaction.
+    pub token: String,
+    /// The version of the interaction, always `1`.
+    pub version: u8,
+    /// The selected language of the user which is using the command.
+    pub locale: Option<String>,
+    /// The preferred language of the guild the command is used in.
+    pub guild_locale: Option<String>,
+}
+
+impl AutocompleteInteraction {
+    /// The option being autocompleted, whose [`value`] holds what the user
+    /// typed so far.
+    ///
+    /// [`value`]: ApplicationCommandInteractionDataOption::value
+    #[inline]
+    pub fn focused_option(&self) -> Option<&ApplicationCommandInteractionDataOption> {
+        self.data.focused_option()
+    }
+}
+
+#[cfg(feature = "model")]
+impl AutocompleteInteraction {
+    /// Responds to the interaction.
+    ///
+    /// Refer to the documentation of [`CreateInteractionResponse`] for a full
+    /// list of methods.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the interaction was already responded to,
+    /// or its token has expired.
+    ///
+    /// [`Error::Http`]: crate::error::Error::Http
+    pub async fn create_interaction_response<F>(&self, http: impl AsRef<Http>, f: F) -> Result<()>
+    where F: FnOnce(&mut CreateInteractionResponse) -> &mut CreateInteractionResponse
+    {
+        super::create_interaction_response(http, self.id, &self.token, f).await
+    }
+
+    /// Responds to the interaction with suggested choices.
+    ///
+    /// Refer to the documentation of [`CreateAutocompleteResponse`] for a full
+    /// list of methods.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::ChoiceAmount`] if more than 25 choices were
+    /// added.
+    ///
+    /// Returns an [`Error::Http`] if the interaction was already responded to,
+    /// or its token has expired.
+    ///
+    /// [`Error::Http`]: crate::error::Error::Http
+    pub async fn create_autocomplete_response<F>(&self, http: impl AsRef<Http>, f: F) -> Result<()>
+    where F: FnOnce(&mut CreateAutocompleteResponse) -> &mut CreateAutocompleteResponse
+    {
+        let mut response = CreateAutocompleteResponse::default();
+        f(&mut response);
+        let map = utils::hashmap_to_json_map(response.0);
+
+        AutocompleteInteraction::check_choices(&map)?;
+
+        let response = autocomplete_response(map);
+
+        http.as_ref().create_interaction_response(self.id.0, &self.token, &response).await
+    }
+
+    pub(crate) fn check_choices(map: &JsonMap) -> Result<()> {
+        if let Some(Value::Array(choices)) = map.get("choices") {
+            if choices.len() > constants::AUTOCOMPLETE_CHOICE_MAX_COUNT {
+                return Err(Error::Model(ModelError::ChoiceAmount(choices.len())));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<'de> Deserialize<'de> for AutocompleteInteraction {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        let mut map = JsonMap::deserialize(deserializer)?;
+        prepare_interaction_map(&mut map)?;
+
+        Self::deserialize(Value::Object(map)).map_err(DeError::custom)
+    }
+}
+
+impl Serialize for AutocompleteInteraction {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+        where S: Serializer {
+        Self::serialize(self, serializer)
+    }
+}
+
+/// Wraps built choices into the payload of an autocomplete response.
+#[cfg(feature = "model")]
+fn autocomplete_response(data: JsonMap) -> Value {
+    let mut map = JsonMap::new();
+    map.insert("type".to_string(), Value::Number(Number::from(InteractionResponseType::Autocomplete.num())));
+    map.insert("data".to_string(), Value::Object(data));
+
+    Value::Object(map)
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn focused_option() {
+        use crate::model::interactions::ApplicationCommandInteractionData;
+        use serde_json::json;
+
+        let data: ApplicationCommandInteractionData = serde_json::from_value(json!({
+            "id": "1",
+            "name": "tag",
+            "type": 1,
+            "options": [{
+                "name": "get",
+                "type": 1,
+                "options": [
+                    {"name": "limit", "type": 4, "value": 5},
+                    {"name": "name", "type": 3, "value": "gr", "focused": true},
+                ],
+            }],
+        })).unwrap();
+
+        let option = data.focused_option().unwrap();
+        assert_eq!(option.name, "name");
+        assert_eq!(option.value, Some(json!("gr")));
+    }
+
+    #[cfg(feature = "model")]
+    #[test]
+    fn check_choices() {
+        use super::{autocomplete_response, AutocompleteInteraction};
+        use crate::builder::CreateAutocompleteResponse;
+        use crate::model::ModelError;
+        use crate::utils;
+        use serde_json::json;
+
+        let mut builder = CreateAutocompleteResponse::default();
+        builder.add_string_choice("Red", "red").add_int_choice("One", 1).add_number_choice("Half", 0.5);
+        let map = utils::hashmap_to_json_map(builder.0.clone());
+
+        assert!(AutocompleteInteraction::check_choices(&map).is_ok());
+        assert_eq!(autocomplete_response(map), json!({
+            "type": 8,
+            "data": {
+                "choices": [
+                    {"name": "Red", "value": "red"},
+                    {"name": "One", "value": 1},
+                    {"name": "Half", "value": 0.5},
+                ],
+            },
+        }));
+
+        for i in 3..26 {
+            builder.add_int_choice(i, i);
+        }
+        let map = utils::hashmap_to_json_map(builder.0);
+
+        match AutocompleteInteraction::check_choices(&map) {
+            Err(crate::Error::Model(ModelError::ChoiceAmount(26))) => {},
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use serde::de::Error as DeError;
+use serde::ser::{Serialize, Serializer};
+
+use crate::model::prelude::*;
+use super::prepare_interaction_map;
+
+#[cfg(feature = "model")]
+use crate::builder::{CreateInteractionResponse, CreateInteractionResponseFollowup, EditInteractionResponse};
+#[cfg(feature = "model")]
+use crate::http::Http;
+
+/// An interaction sent when a user uses a component of a message.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(remote = "Self")]
+#[non_exhaustive]
+pub struct MessageComponentInteraction {
+    /// The Id of the interaction.
+    pub id: InteractionId,
+    /// The Id of the application the interaction was sent to.
+    pub application_id: ApplicationId,
+    /// The type of the interaction, always
+    /// [`InteractionType::MessageComponent`].
+    #[serde(rename = "type")]
+    pub kind: InteractionType,
+    /// The data of the component which was used.
+    pub data: MessageComponentInteractionData,
+    /// The message the component is attached to.
+    pub message: Message,
+    /// The Id of the guild the component was used in.
+    pub guild_id: Option<GuildId>,
+    /// The Id of the channel the component was used in.
+    pub channel_id: ChannelId,
+    /// The member which used the component.
+    ///
+    /// **Note**: This is only present if the component was used in a guild.
+    pub member: Option<Member>,
+    /// The user which used the component.
+    pub user: User,
+    /// The token used to respond to the interaction.
+    pub token: String,
+    /// The version of the interaction, always `1`.
+    pub version: u8,
+    /// The selected language of the user which used the component.
+    pub locale: Option<String>,
+    /// The preferred language of the guild the component was used in.
+    pub guild_locale: Option<String>,
+}
+
+#[cfg(feature = "model")]
+impl MessageComponentInteraction {
+    /// Responds to the interaction.
+    ///
+    /// Refer to the documentation of [`CreateInteractionResponse`] for a full
+    /// list of methods.
+    ///
+    /// **Note**: An interaction must be responded to within 3 seconds, and only
+    /// once.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the interaction was already responded to,
+    /// or its token has expired.
+    ///
+    /// Returns a [`ModelError::InvalidModal`] if a modal response does not
+    /// have between 1 and 5 rows of a single text input, or a [`ModelError`]
+    /// if the message of the response is over the [limits] of Discord.
+    ///
+    /// [`Error::Http`]: crate::error::Error::Http
+    /// [`ModelError`]: crate::model::ModelError
+    /// [`ModelError::InvalidModal`]: crate::model::ModelError::InvalidModal
+    /// [limits]: crate::model::interactions#limits
+    pub async fn create_interaction_response<F>(&self, http: impl AsRef<Http>, f: F) -> Result<()>
+    where F: FnOnce(&mut CreateInteractionResponse) -> &mut CreateInteractionResponse
+    {
+        super::create_interaction_response(http, self.id, &self.token, f).await
+    }
+
+    /// Edits the initial response to the interaction.
+    ///
+    /// Refer to the documentation of [`EditInteractionResponse`] for a full
+    /// list of methods.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the interaction was not responded to yet,
+    /// or its token has expired.
+    ///
+    /// Returns a [`ModelError`] if the edited message is over the [limits] of
+    /// Discord.
+    ///
+    /// [`Error::Http`]: crate::error::Error::Http
+    /// [`ModelError`]: crate::model::ModelError
+    /// [limits]: crate::model::interactions#limits
+    pub async fn edit_original_interaction_response<F>(&self, http: impl AsRef<Http>, f: F) -> Result<Message>
+    where F: FnOnce(&mut EditInteractionResponse) -> &mut EditInteractionResponse
+    {
+        super::edit_original_interaction_response(http, self.application_id, &self.token, f).await
+    }
+
+    /// Deletes the initial response to the interaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the interaction was not responded to yet,
+    /// or its token has expired.
+    ///
+    /// [`Error::Http`]: crate::error::Error::Http
+    pub async fn delete_original_interaction_response(&self, http: impl AsRef<Http>) -> Result<()> {
+        http.as_ref().delete_original_interaction_response(self.application_id.0, &self.token).await
+    }
+
+    /// Creates a follow-up message to the interaction.
+    ///
+    /// Refer to the documentation of [`CreateInteractionResponseFollowup`] for
+    /// a full list of methods.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the message is invalid, or the token of
+    /// the interaction has expired.
+    ///
+    /// Returns a [`ModelError`] if the follow-up message is over the [limits]
+    /// of Discord.
+    ///
+    /// [`Error::Http`]: crate::error::Error::Http
+    /// [`ModelError`]: crate::model::ModelError
+    /// [limits]: crate::model::interactions#limits
+    pub async fn create_followup_message<F>(&self, http: impl AsRef<Http>, f: F) -> Result<Message>
+    where F: FnOnce(&mut CreateInteractionResponseFollowup) -> &mut CreateInteractionResponseFollowup
+    {
+        super::create_followup_message(http, self.application_id, &self.token, f).await
+    }
+}
+
+impl<'de> Deserialize<'de> for MessageComponentInteraction {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        let mut map = JsonMap::deserialize(deserializer)?;
+        prepare_interaction_map(&mut map)?;
+
+        Self::deserialize(Value::Object(map)).map_err(DeError::custom)
+    }
+}
+
+impl Serialize for MessageComponentInteraction {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+        where S: Serializer {
+        Self::serialize(self, serializer)
+    }
+}
+
+/// The data of a [`MessageComponentInteraction`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct MessageComponentInteractionData {
+    /// The developer-defined Id of the component.
+    pub custom_id: String,
+    /// The type of the component.
+    pub component_type: ComponentType,
+    /// The values of the options selected in a select menu.
+    #[serde(default)]
+    pub values: Vec<String>,
+}
@@ -0,0 +1,686 @@
+//! Models for interactions, which are sent by Discord when a user uses an
+//! application command or a message component, or submits a modal.
+//!
+//! # Limits
+//!
+//! The messages sent in response to an interaction, or as follow-ups, are
+//! checked against the limits of Discord before being sent, as done when
+//! sending a message to a channel. The following errors are returned:
+//!
+//! - [`ModelError::MessageTooLong`] if the content is over 2000 unicode code
+//!   points;
+//! - [`ModelError::EmbedAmount`] if there are more than 10 embeds, or
+//!   [`ModelError::EmbedTooLarge`] if their combined text is over 6000
+//!   characters;
+//! - [`ModelError::InvalidTimestamp`] if the timestamp of an embed is not in
+//!   the RFC 3339 format;
+//! - [`ModelError::InvalidButton`] if a link button lacks an URL or has a
+//!   custom Id, or another button lacks a custom Id or has an URL.
+//!
+//! [`ModelError::MessageTooLong`]: crate::model::ModelError::MessageTooLong
+//! [`ModelError::EmbedAmount`]: crate::model::ModelError::EmbedAmount
+//! [`ModelError::EmbedTooLarge`]: crate::model::ModelError::EmbedTooLarge
+//! [`ModelError::InvalidTimestamp`]: crate::model::ModelError::InvalidTimestamp
+//! [`ModelError::InvalidButton`]: crate::model::ModelError::InvalidButton
+
+pub mod application_command;
+pub mod autocomplete;
+pub mod message_component;
+pub mod modal;
+
+pub use self::application_command::*;
+pub use self::autocomplete::*;
+pub use self::message_component::*;
+pub use self::modal::*;
+
+use crate::model::prelude::*;
+use serde::de::Error as DeError;
+use serde::ser::{Serialize, Serializer};
+
+#[cfg(feature = "model")]
+use crate::builder::{
+    CreateInteractionResponse,
+    CreateInteractionResponseFollowup,
+    EditInteractionResponse,
+};
+#[cfg(feature = "model")]
+use crate::http::Http;
+#[cfg(feature = "model")]
+use crate::utils;
+
+/// An interaction received over the gateway.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum Interaction {
+    /// A user used an application command.
+    ApplicationCommand(ApplicationCommandInteraction),
+    /// A user clicked a button or selected options of a select menu.
+    MessageComponent(MessageComponentInteraction),
+    /// A user is typing an application command option with autocompletion.
+    Autocomplete(AutocompleteInteraction),
+    /// A user submitted a modal.
+    ModalSubmit(ModalSubmitInteraction),
+}
+
+impl Interaction {
+    /// The Id of the interaction.
+    pub fn id(&self) -> InteractionId {
+        match self {
+            Interaction::ApplicationCommand(i) => i.id,
+            Interaction::MessageComponent(i) => i.id,
+            Interaction::Autocomplete(i) => i.id,
+            Interaction::ModalSubmit(i) => i.id,
+        }
+    }
+
+    /// The type of the interaction.
+    pub fn kind(&self) -> InteractionType {
+        match self {
+            Interaction::ApplicationCommand(_) => InteractionType::ApplicationCommand,
+            Interaction::MessageComponent(_) => InteractionType::MessageComponent,
+            Interaction::Autocomplete(_) => InteractionType::Autocomplete,
+            Interaction::ModalSubmit(_) => InteractionType::ModalSubmit,
+        }
+    }
+
+    /// The Id of the application the interaction was sent to.
+    pub fn application_id(&self) -> ApplicationId {
+        match self {
+            Interaction::ApplicationCommand(i) => i.application_id,
+            Interaction::MessageComponent(i) => i.application_id,
+            Interaction::Autocomplete(i) => i.application_id,
+            Interaction::ModalSubmit(i) => i.application_id,
+        }
+    }
+
+    /// The token used to respond to the interaction.
+    pub fn token(&self) -> &str {
+        match self {
+            Interaction::ApplicationCommand(i) => &i.token,
+            Interaction::MessageComponent(i) => &i.token,
+            Interaction::Autocomplete(i) => &i.token,
+            Interaction::ModalSubmit(i) => &i.token,
+        }
+    }
+
+    /// Responds to the interaction.
+    ///
+    /// Refer to the documentation of [`CreateInteractionResponse`] for a full
+    /// list of methods.
+    ///
+    /// **Note**: An interaction must be responded to within 3 seconds, and only
+    /// once. Use a [deferred] response to take longer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the interaction was already responded to,
+    /// or its token has expired.
+    ///
+    /// Returns a [`ModelError::InvalidModal`] if a modal response does not
+    /// have between 1 and 5 rows of a single text input, or a [`ModelError`]
+    /// if the message of the response is over the [limits] of Discord.
+    ///
+    /// [deferred]: InteractionResponseType::DeferredChannelMessageWithSource
+    /// [`Error::Http`]: crate::error::Error::Http
+    /// [`ModelError`]: crate::model::ModelError
+    /// [`ModelError::InvalidModal`]: crate::model::ModelError::InvalidModal
+    /// [limits]: crate::model::interactions#limits
+    #[cfg(feature = "model")]
+    pub async fn create_response<F>(&self, http: impl AsRef<Http>, f: F) -> Result<()>
+    where F: FnOnce(&mut CreateInteractionResponse) -> &mut CreateInteractionResponse
+    {
+        create_interaction_response(http, self.id(), self.token(), f).await
+    }
+
+    /// Edits the initial response to the interaction.
+    ///
+    /// Refer to the documentation of [`EditInteractionResponse`] for a full
+    /// list of methods.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the interaction was not responded to yet,
+    /// or its token has expired.
+    ///
+    /// Returns a [`ModelError`] if the edited message is over the [limits] of
+    /// Discord.
+    ///
+    /// [`Error::Http`]: crate::error::Error::Http
+    /// [`ModelError`]: crate::model::ModelError
+    /// [limits]: crate::model::interactions#limits
+    #[cfg(feature = "model")]
+    pub async fn edit_response<F>(&self, http: impl AsRef<Http>, f: F) -> Result<Message>
+    where F: FnOnce(&mut EditInteractionResponse) -> &mut EditInteractionResponse
+    {
+        edit_original_interaction_response(http, self.application_id(), self.token(), f).await
+    }
+
+    /// Deletes the initial response to the interaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the interaction was not responded to yet,
+    /// or its token has expired.
+    ///
+    /// [`Error::Http`]: crate::error::Error::Http
+    #[cfg(feature = "model")]
+    pub async fn delete_response(&self, http: impl AsRef<Http>) -> Result<()> {
+        http.as_ref().delete_original_interaction_response(self.application_id().0, self.token()).await
+    }
+
+    /// Creates a follow-up message to the interaction.
+    ///
+    /// Refer to the documentation of [`CreateInteractionResponseFollowup`] for
+    /// a full list of methods.
+    ///
+    /// **Note**: Follow-up messages can be sent for 15 minutes after the
+    /// interaction was received.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the message is invalid, or the token of
+    /// the interaction has expired.
+    ///
+    /// Returns a [`ModelError`] if the follow-up message is over the [limits]
+    /// of Discord.
+    ///
+    /// [`Error::Http`]: crate::error::Error::Http
+    /// [`ModelError`]: crate::model::ModelError
+    /// [limits]: crate::model::interactions#limits
+    #[cfg(feature = "model")]
+    pub async fn create_followup_message<F>(&self, http: impl AsRef<Http>, f: F) -> Result<Message>
+    where F: FnOnce(&mut CreateInteractionResponseFollowup) -> &mut CreateInteractionResponseFollowup
+    {
+        create_followup_message(http, self.application_id(), self.token(), f).await
+    }
+
+    /// Converts this to an [`ApplicationCommandInteraction`], if it is one.
+    pub fn application_command(self) -> Option<ApplicationCommandInteraction> {
+        match self {
+            Interaction::ApplicationCommand(i) => Some(i),
+            _ => None,
+        }
+    }
+
+    /// Converts this to a [`MessageComponentInteraction`], if it is one.
+    pub fn message_component(self) -> Option<MessageComponentInteraction> {
+        match self {
+            Interaction::MessageComponent(i) => Some(i),
+            _ => None,
+        }
+    }
+
+    /// Converts this to an [`AutocompleteInteraction`], if it is one.
+    pub fn autocomplete(self) -> Option<AutocompleteInteraction> {
+        match self {
+            Interaction::Autocomplete(i) => Some(i),
+            _ => None,
+        }
+    }
+
+    /// Converts this to a [`ModalSubmitInteraction`], if it is one.
+    pub fn modal_submit(self) -> Option<ModalSubmitInteraction> {
+        match self {
+            Interaction::ModalSubmit(i) => Some(i),
+            _ => None,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Interaction {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        let v = JsonMap::deserialize(deserializer)?;
+        let kind = v.get("type")
+            .and_then(Value::as_u64)
+            .ok_or_else(|| DeError::missing_field("type"))?;
+
+        match kind {
+            2 => serde_json::from_value::<ApplicationCommandInteraction>(Value::Object(v))
+                .map(Interaction::ApplicationCommand)
+                .map_err(DeError::custom),
+            3 => serde_json::from_value::<MessageComponentInteraction>(Value::Object(v))
+                .map(Interaction::MessageComponent)
+                .map_err(DeError::custom),
+            4 => serde_json::from_value::<AutocompleteInteraction>(Value::Object(v))
+                .map(Interaction::Autocomplete)
+                .map_err(DeError::custom),
+            5 => serde_json::from_value::<ModalSubmitInteraction>(Value::Object(v))
+                .map(Interaction::ModalSubmit)
+                .map_err(DeError::custom),
+            _ => Err(DeError::custom("Unknown interaction type")),
+        }
+    }
+}
+
+impl Serialize for Interaction {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+        where S: Serializer {
+        match self {
+            Interaction::ApplicationCommand(i) => ApplicationCommandInteraction::serialize(i, serializer),
+            Interaction::MessageComponent(i) => MessageComponentInteraction::serialize(i, serializer),
+            Interaction::Autocomplete(i) => AutocompleteInteraction::serialize(i, serializer),
+            Interaction::ModalSubmit(i) => ModalSubmitInteraction::serialize(i, serializer),
+        }
+    }
+}
+
+/// The type of an interaction.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum InteractionType {
+    /// A ping sent to check the interactions endpoint of an application. This
+    /// is never sent over the gateway.
+    Ping = 1,
+    ApplicationCommand = 2,
+    MessageComponent = 3,
+    Autocomplete = 4,
+    ModalSubmit = 5,
+}
+
+enum_number!(
+    InteractionType {
+        Ping,
+        ApplicationCommand,
+        MessageComponent,
+        Autocomplete,
+        ModalSubmit,
+    }
+);
+
+impl InteractionType {
+    #[inline]
+    pub fn num(self) -> u64 {
+        match self {
+            InteractionType::Ping => 1,
+            InteractionType::ApplicationCommand => 2,
+            InteractionType::MessageComponent => 3,
+            InteractionType::Autocomplete => 4,
+            InteractionType::ModalSubmit => 5,
+        }
+    }
+}
+
+/// The type of a response to an interaction.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum InteractionResponseType {
+    /// Acknowledges a [`InteractionType::Ping`].
+    Pong = 1,
+    /// Responds with a message.
+    ChannelMessageWithSource = 4,
+    /// Acknowledges the interaction, showing a loading state until the
+    /// response is edited.
+    DeferredChannelMessageWithSource = 5,
+    /// Acknowledges a component interaction, to edit the message it is
+    /// attached to later.
+    DeferredUpdateMessage = 6,
+    /// Edits the message a component interaction is attached to.
+    UpdateMessage = 7,
+    /// Responds to an autocomplete interaction with suggested choices.
+    Autocomplete = 8,
+    /// Responds with a modal.
+    Modal = 9,
+}
+
+enum_number!(
+    InteractionResponseType {
+        Pong,
+        ChannelMessageWithSource,
+        DeferredChannelMessageWithSource,
+        DeferredUpdateMessage,
+        UpdateMessage,
+        Autocomplete,
+        Modal,
+    }
+);
+
+impl InteractionResponseType {
+    #[inline]
+    pub fn num(self) -> u64 {
+        match self {
+            InteractionResponseType::Pong => 1,
+            InteractionResponseType::ChannelMessageWithSource => 4,
+            InteractionResponseType::DeferredChannelMessageWithSource => 5,
+            InteractionResponseType::DeferredUpdateMessage => 6,
+            InteractionResponseType::UpdateMessage => 7,
+            InteractionResponseType::Autocomplete => 8,
+            InteractionResponseType::Modal => 9,
+        }
+    }
+}
+
+/// Prepares the raw payload of an interaction for deserialization.
+///
+/// Discord sends the `member` of guild interactions without its `guild_id`,
+/// and without the `user` field, which is then only present in the member.
+/// Roles of the resolved data are also sent without their `guild_id`.
+fn prepare_interaction_map<E: DeError>(map: &mut JsonMap) -> StdResult<(), E> {
+    let guild_id = map.get("guild_id").cloned();
+
+    if let Some(guild_id) = guild_id {
+        if let Some(member) = map.get_mut("member").and_then(Value::as_object_mut) {
+            member.insert("guild_id".to_string(), guild_id.clone());
+        }
+
+        let roles = map.get_mut("data")
+            .and_then(|data| data.get_mut("resolved"))
+            .and_then(|resolved| resolved.get_mut("roles"))
+            .and_then(Value::as_object_mut);
+
+        if let Some(roles) = roles {
+            for role in roles.values_mut().filter_map(Value::as_object_mut) {
+                role.insert("guild_id".to_string(), guild_id.clone());
+            }
+        }
+    }
+
+    if !map.contains_key("user") {
+        let user = map.get("member")
+            .and_then(|member| member.get("user"))
+            .cloned()
+            .ok_or_else(|| DeError::missing_field("user"))?;
+
+        map.insert("user".to_string(), user);
+    }
+
+    Ok(())
+}
+
+/// Checks the message of an interaction response against the limits of
+/// Discord, as done when sending a message to a channel.
+#[cfg(feature = "model")]
+fn check_message(map: &JsonMap) -> Result<()> {
+    Message::check_content_length(map)?;
+    Message::check_embed_count(map)?;
+    Message::check_embed_length(map)?;
+    Message::check_embed_timestamp(map)?;
+    Message::check_components(map)
+}
+
+#[cfg(feature = "model")]
+async fn create_interaction_response<F>(
+    http: impl AsRef<Http>,
+    interaction_id: InteractionId,
+    interaction_token: &str,
+    f: F,
+) -> Result<()>
+where F: FnOnce(&mut CreateInteractionResponse) -> &mut CreateInteractionResponse
+{
+    let mut response = CreateInteractionResponse::default();
+    f(&mut response);
+    let map = utils::hashmap_to_json_map(response.0);
+
+    modal::check_modal(&map)?;
+
+    if let Some(Value::Object(data)) = map.get("data") {
+        check_message(data)?;
+    }
+
+    http.as_ref().create_interaction_response(interaction_id.0, interaction_token, &Value::Object(map)).await
+}
+
+#[cfg(feature = "model")]
+async fn edit_original_interaction_response<F>(
+    http: impl AsRef<Http>,
+    application_id: ApplicationId,
+    interaction_token: &str,
+    f: F,
+) -> Result<Message>
+where F: FnOnce(&mut EditInteractionResponse) -> &mut EditInteractionResponse
+{
+    let mut response = EditInteractionResponse::default();
+    f(&mut response);
+    let map = utils::hashmap_to_json_map(response.0);
+
+    check_message(&map)?;
+
+    http.as_ref().edit_original_interaction_response(application_id.0, interaction_token, &Value::Object(map)).await
+}
+
+#[cfg(feature = "model")]
+async fn create_followup_message<F>(
+    http: impl AsRef<Http>,
+    application_id: ApplicationId,
+    interaction_token: &str,
+    f: F,
+) -> Result<Message>
+where F: FnOnce(&mut CreateInteractionResponseFollowup) -> &mut CreateInteractionResponseFollowup
+{
+    let mut followup = CreateInteractionResponseFollowup::default();
+    f(&mut followup);
+    let map = utils::hashmap_to_json_map(followup.0);
+
+    check_message(&map)?;
+
+    http.as_ref().create_followup_message(application_id.0, interaction_token, &Value::Object(map)).await
+}
+
+#[cfg(test)]
+mod test {
+    use crate::internal::test_utils;
+    use crate::model::prelude::*;
+    use serde_json::json;
+
+    fn interaction(kind: u64, data: Value) -> Value {
+        json!({
+            "id": "1",
+            "application_id": "2",
+            "type": kind,
+            "data": data,
+            "guild_id": "3",
+            "channel_id": "4",
+            "member": {
+                "user": {
+                    "id": "5",
+                    "username": "ferris",
+                    "discriminator": "0001",
+                    "avatar": null
+                },
+                "roles": ["6"],
+                "joined_at": "2021-01-01T00:00:00.000000+00:00",
+                "deaf": false,
+                "mute": false,
+                "nick": null
+            },
+            "token": "token",
+            "version": 1
+        })
+    }
+
+    #[test]
+    fn application_command_deserialization() {
+        let value = interaction(2, json!({
+            "id": "7",
+            "name": "ban",
+            "type": 1,
+            "options": [
+                { "name": "user", "type": 6, "value": "5" },
+                { "name": "days", "type": 4, "value": 7 }
+            ],
+            "resolved": {
+                "users": {
+                    "5": { "id": "5", "username": "ferris", "discriminator": "0001", "avatar": null }
+                },
+                "roles": {
+                    "6": {
+                        "id": "6",
+                        "name": "crab",
+                        "color": 0,
+                        "hoist": false,
+                        "position": 1,
+                        "permissions": "0",
+                        "managed": false,
+                        "mentionable": false
+                    }
+                }
+            }
+        }));
+
+        let interaction = serde_json::from_value::<Interaction>(value).unwrap();
+        assert_eq!(interaction.kind(), InteractionType::ApplicationCommand);
+        assert_eq!(interaction.id(), InteractionId(1));
+
+        let command = interaction.application_command().unwrap();
+        assert_eq!(command.user.id, UserId(5));
+        assert_eq!(command.member.as_ref().map(|m| m.guild_id), Some(GuildId(3)));
+        assert_eq!(command.data.name, "ban");
+        assert_eq!(command.data.kind, ApplicationCommandType::ChatInput);
+        assert_eq!(command.data.options[0].kind, ApplicationCommandOptionType::User);
+        assert_eq!(command.data.options[1].value, Some(json!(7)));
+        assert!(command.data.resolved.users.contains_key(&UserId(5)));
+        assert_eq!(command.data.resolved.roles[&RoleId(6)].guild_id, GuildId(3));
+    }
+
+    #[test]
+    fn message_component_deserialization() {
+        let mut value = interaction(3, json!({
+            "custom_id": "menu",
+            "component_type": 3,
+            "values": ["a", "b"]
+        }));
+        value["message"] = test_utils::message_json(4, None);
+
+        let interaction = serde_json::from_value::<Interaction>(value).unwrap();
+        assert_eq!(interaction.kind(), InteractionType::MessageComponent);
+
+        let component = interaction.message_component().unwrap();
+        assert_eq!(component.data.custom_id, "menu");
+        assert_eq!(component.data.component_type, ComponentType::SelectMenu);
+        assert_eq!(component.data.values, vec!["a", "b"]);
+        assert_eq!(component.message.id, MessageId(3));
+    }
+
+    #[test]
+    fn autocomplete_deserialization() {
+        let value = interaction(4, json!({
+            "id": "7",
+            "name": "search",
+            "type": 1,
+            "options": [
+                { "name": "query", "type": 3, "value": "ser", "focused": true }
+            ]
+        }));
+
+        let interaction = serde_json::from_value::<Interaction>(value).unwrap();
+        assert_eq!(interaction.kind(), InteractionType::Autocomplete);
+
+        let autocomplete = interaction.autocomplete().unwrap();
+        assert!(autocomplete.data.options[0].focused);
+        assert_eq!(autocomplete.data.options[0].value, Some(json!("ser")));
+    }
+
+    #[test]
+    fn modal_submit_deserialization() {
+        let value = interaction(5, json!({
+            "custom_id": "feedback",
+            "components": [
+                {
+                    "type": 1,
+                    "components": [
+                        { "type": 4, "custom_id": "text", "value": "Hello" }
+                    ]
+                }
+            ]
+        }));
+
+        let interaction = serde_json::from_value::<Interaction>(value).unwrap();
+        assert_eq!(interaction.kind(), InteractionType::ModalSubmit);
+
+        let modal = interaction.modal_submit().unwrap();
+        assert_eq!(modal.data.custom_id, "feedback");
+
+        match &modal.data.components[0].components[0] {
+            ActionRowComponent::InputText(text) => assert_eq!(text.value, "Hello"),
+            other => panic!("unexpected component: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn private_interaction_deserialization() {
+        let mut value = interaction(3, json!({
+            "custom_id": "button",
+            "component_type": 2
+        }));
+        let map = value.as_object_mut().unwrap();
+        map.remove("guild_id");
+        let member = map.remove("member").unwrap();
+        map.insert("user".to_string(), member["user"].clone());
+        map.insert("message".to_string(), test_utils::message_json(4, None));
+
+        let component = serde_json::from_value::<MessageComponentInteraction>(value).unwrap();
+        assert_eq!(component.user.id, UserId(5));
+        assert!(component.member.is_none());
+        assert!(component.guild_id.is_none());
+    }
+
+    #[cfg(feature = "model")]
+    #[tokio::test]
+    async fn responses_check_embed_timestamps() {
+        use crate::http::Http;
+        use crate::model::ModelError;
+
+        let http = Http::default();
+
+        let result = super::create_interaction_response(&http, InteractionId(1), "token", |r| {
+            r.interaction_response_data(|d| d.embed(|e| e.timestamp("last tuesday")))
+        }).await;
+        match result {
+            Err(crate::Error::Model(ModelError::InvalidTimestamp)) => {},
+            other => panic!("unexpected result: {:?}", other),
+        }
+
+        let result = super::edit_original_interaction_response(&http, ApplicationId(2), "token", |r| {
+            r.embed(|e| e.timestamp("last tuesday"))
+        }).await;
+        match result {
+            Err(crate::Error::Model(ModelError::InvalidTimestamp)) => {},
+            other => panic!("unexpected result: {:?}", other),
+        }
+
+        let result = super::create_followup_message(&http, ApplicationId(2), "token", |f| {
+            f.embed(|e| e.timestamp("last tuesday"))
+        }).await;
+        match result {
+            Err(crate::Error::Model(ModelError::InvalidTimestamp)) => {},
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[cfg(feature = "model")]
+    #[tokio::test]
+    async fn responses_check_message_limits() {
+        use crate::builder::CreateActionRow;
+        use crate::http::Http;
+        use crate::model::ModelError;
+
+        let http = Http::default();
+        let content = "a".repeat(2001);
+
+        let result = super::create_interaction_response(&http, InteractionId(1), "token", |r| {
+            r.interaction_response_data(|d| d.content(content))
+        }).await;
+        match result {
+            Err(crate::Error::Model(ModelError::MessageTooLong(1))) => {},
+            other => panic!("unexpected result: {:?}", other),
+        }
+
+        let description = "a".repeat(6001);
+        let result = super::edit_original_interaction_response(&http, ApplicationId(2), "token", |r| {
+            r.embed(|e| e.description(description))
+        }).await;
+        match result {
+            Err(crate::Error::Model(ModelError::EmbedTooLarge(1))) => {},
+            other => panic!("unexpected result: {:?}", other),
+        }
+
+        let mut row = CreateActionRow::default();
+        row.create_button(|b| b.style(ButtonStyle::Link).label("Docs").custom_id("docs"));
+        let result = super::create_followup_message(&http, ApplicationId(2), "token", |f| {
+            f.components(|c| c.add_action_row(row))
+        }).await;
+        match result {
+            Err(crate::Error::Model(ModelError::InvalidButton)) => {},
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+}
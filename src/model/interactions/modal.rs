@@ -0,0 +1,273 @@
+use serde::de::Error as DeError;
+use serde::ser::{Serialize, Serializer};
+
+use crate::model::prelude::*;
+use super::prepare_interaction_map;
+
+#[cfg(feature = "model")]
+use crate::builder::{CreateInteractionResponse, CreateInteractionResponseFollowup, EditInteractionResponse};
+#[cfg(feature = "model")]
+use crate::http::Http;
+
+/// An interaction sent when a user submits a modal.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(remote = "Self")]
+#[non_exhaustive]
+pub struct ModalSubmitInteraction {
+    /// The Id of the interaction.
+    pub id: InteractionId,
+    /// The Id of the application the interaction was sent to.
+    pub application_id: ApplicationId,
+    /// The type of the interaction, always [`InteractionType::ModalSubmit`].
+    #[serde(rename = "type")]
+    pub kind: InteractionType,
+    /// The data of the submitted modal.
+    pub data: ModalSubmitInteractionData,
+    /// The message the modal was opened from, if it was opened from a
+    /// component.
+    pub message: Option<Message>,
+    /// The Id of the guild the modal was submitted in.
+    pub guild_id: Option<GuildId>,
+    /// The Id of the channel the modal was submitted in.
+    pub channel_id: ChannelId,
+    /// The member which submitted the modal.
+    ///
+    /// **Note**: This is only present if the modal was submitted in a guild.
+    pub member: Option<Member>,
+    /// The user which submitted the modal.
+    pub user: User,
+    /// The token used to respond to the interaction.
+    pub token: String,
+    /// The version of the interaction, always `1`.
+    pub version: u8,
+    /// The selected language of the user which submitted the modal.
+    pub locale: Option<String>,
+    /// The preferred language of the guild the modal was submitted in.
+    pub guild_locale: Option<String>,
+}
+
+#[cfg(feature = "model")]
+impl ModalSubmitInteraction {
+    /// Responds to the interaction.
+    ///
+    /// Refer to the documentation of [`CreateInteractionResponse`] for a full
+    /// list of methods.
+    ///
+    /// **Note**: An interaction must be responded to within 3 seconds, and only
+    /// once.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the interaction was already responded to,
+    /// or its token has expired.
+    ///
+    /// Returns a [`ModelError::InvalidModal`] if a modal response does not
+    /// have between 1 and 5 rows of a single text input, or a [`ModelError`]
+    /// if the message of the response is over the [limits] of Discord.
+    ///
+    /// [`Error::Http`]: crate::error::Error::Http
+    /// [`ModelError`]: crate::model::ModelError
+    /// [`ModelError::InvalidModal`]: crate::model::ModelError::InvalidModal
+    /// [limits]: crate::model::interactions#limits
+    pub async fn create_interaction_response<F>(&self, http: impl AsRef<Http>, f: F) -> Result<()>
+    where F: FnOnce(&mut CreateInteractionResponse) -> &mut CreateInteractionResponse
+    {
+        super::create_interaction_response(http, self.id, &self.token, f).await
+    }
+
+    /// Edits the initial response to the interaction.
+    ///
+    /// Refer to the documentation of [`EditInteractionResponse`] for a full
+    /// list of methods.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the interaction was not responded to yet,
+    /// or its token has expired.
+    ///
+    /// Returns a [`ModelError`] if the edited message is over the [limits] of
+    /// Discord.
+    ///
+    /// [`Error::Http`]: crate::error::Error::Http
+    /// [`ModelError`]: crate::model::ModelError
+    /// [limits]: crate::model::interactions#limits
+    pub async fn edit_original_interaction_response<F>(&self, http: impl AsRef<Http>, f: F) -> Result<Message>
+    where F: FnOnce(&mut EditInteractionResponse) -> &mut EditInteractionResponse
+    {
+        super::edit_original_interaction_response(http, self.application_id, &self.token, f).await
+    }
+
+    /// Deletes the initial response to the interaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the interaction was not responded to yet,
+    /// or its token has expired.
+    ///
+    /// [`Error::Http`]: crate::error::Error::Http
+    pub async fn delete_original_interaction_response(&self, http: impl AsRef<Http>) -> Result<()> {
+        http.as_ref().delete_original_interaction_response(self.application_id.0, &self.token).await
+    }
+
+    /// Creates a follow-up message to the interaction.
+    ///
+    /// Refer to the documentation of [`CreateInteractionResponseFollowup`] for
+    /// a full list of methods.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the message is invalid, or the token of
+    /// the interaction has expired.
+    ///
+    /// Returns a [`ModelError`] if the follow-up message is over the [limits]
+    /// of Discord.
+    ///
+    /// [`Error::Http`]: crate::error::Error::Http
+    /// [`ModelError`]: crate::model::ModelError
+    /// [limits]: crate::model::interactions#limits
+    pub async fn create_followup_message<F>(&self, http: impl AsRef<Http>, f: F) -> Result<Message>
+    where F: FnOnce(&mut CreateInteractionResponseFollowup) -> &mut CreateInteractionResponseFollowup
+    {
+        super::create_followup_message(http, self.application_id, &self.token, f).await
+    }
+}
+
+impl<'de> Deserialize<'de> for ModalSubmitInteraction {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        let mut map = JsonMap::deserialize(deserializer)?;
+        prepare_interaction_map(&mut map)?;
+
+        Self::deserialize(Value::Object(map)).map_err(DeError::custom)
+    }
+}
+
+impl Serialize for ModalSubmitInteraction {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+        where S: Serializer {
+        Self::serialize(self, serializer)
+    }
+}
+
+/// The data of a [`ModalSubmitInteraction`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ModalSubmitInteractionData {
+    /// The developer-defined Id of the modal.
+    pub custom_id: String,
+    /// The rows of the modal, containing the submitted text fields.
+    #[serde(default)]
+    pub components: Vec<ActionRow>,
+}
+
+impl ModalSubmitInteractionData {
+    /// Iterates over the submitted text fields of the modal.
+    pub fn input_texts(&self) -> impl Iterator<Item = &InputText> {
+        self.components.iter().flat_map(|row| &row.components).filter_map(|component| match component {
+            ActionRowComponent::InputText(input_text) => Some(input_text),
+            _ => None,
+        })
+    }
+
+    /// Gets the text entered in the text field with the given custom Id.
+    pub fn value(&self, custom_id: &str) -> Option<&str> {
+        self.input_texts()
+            .find(|input_text| input_text.custom_id == custom_id)
+            .map(|input_text| input_text.value.as_str())
+    }
+}
+
+/// Checks that a modal response has between 1 and 5 rows, each holding a
+/// single text field.
+#[cfg(feature = "model")]
+pub(super) fn check_modal(response: &JsonMap) -> Result<()> {
+    if response.get("type").and_then(Value::as_u64) != Some(InteractionResponseType::Modal.num()) {
+        return Ok(());
+    }
+
+    let rows = response.get("data")
+        .and_then(|data| data.get("components"))
+        .and_then(Value::as_array)
+        .map_or(&[][..], Vec::as_slice);
+
+    if rows.is_empty() || rows.len() > 5 {
+        return Err(Error::Model(ModelError::InvalidModal));
+    }
+
+    for row in rows {
+        let components = row.get("components").and_then(Value::as_array).map_or(&[][..], Vec::as_slice);
+        let is_input_text = |component: &Value| {
+            component.get("type").and_then(Value::as_u64) == Some(ComponentType::InputText.num())
+        };
+
+        if components.len() != 1 || !components.iter().all(is_input_text) {
+            return Err(Error::Model(ModelError::InvalidModal));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::model::prelude::*;
+    use serde_json::json;
+
+    #[test]
+    fn input_texts() {
+        let data: ModalSubmitInteractionData = serde_json::from_value(json!({
+            "custom_id": "profile",
+            "components": [
+                {"type": 1, "components": [{"type": 4, "custom_id": "name", "value": "Ferris"}]},
+                {"type": 1, "components": [{"type": 4, "custom_id": "bio", "value": "A crab"}]},
+            ],
+        })).unwrap();
+
+        assert_eq!(data.custom_id, "profile");
+        assert_eq!(data.input_texts().count(), 2);
+        assert_eq!(data.value("bio"), Some("A crab"));
+        assert_eq!(data.value("age"), None);
+    }
+
+    #[cfg(feature = "model")]
+    #[test]
+    fn check_modal() {
+        use super::check_modal;
+        use crate::builder::CreateInteractionResponse;
+        use crate::utils;
+
+        let mut response = CreateInteractionResponse::default();
+        response.modal(|m| {
+            m.custom_id("profile").title("Profile").components(|c| {
+                c.create_action_row(|row| row.create_input_text(|t| t.custom_id("name")))
+            })
+        });
+        assert!(check_modal(&utils::hashmap_to_json_map(response.0)).is_ok());
+
+        let mut response = CreateInteractionResponse::default();
+        response.modal(|m| {
+            m.custom_id("profile").title("Profile").components(|c| {
+                c.create_action_row(|row| {
+                    row.create_input_text(|t| t.custom_id("name"))
+                        .create_input_text(|t| t.custom_id("bio"))
+                })
+            })
+        });
+
+        match check_modal(&utils::hashmap_to_json_map(response.0)) {
+            Err(crate::Error::Model(ModelError::InvalidModal)) => {},
+            other => panic!("unexpected result: {:?}", other),
+        }
+
+        let mut response = CreateInteractionResponse::default();
+        response.modal(|m| {
+            m.custom_id("profile").title("Profile").components(|c| {
+                for i in 0..6 {
+                    c.create_action_row(|row| row.create_input_text(|t| t.custom_id(i)));
+                }
+
+                c
+            })
+        });
+        assert!(check_modal(&utils::hashmap_to_json_map(response.0)).is_err());
+    }
+}
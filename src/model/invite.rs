@@ -64,6 +64,9 @@ impl Invite {
     /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`]
     /// if the current user does not have the required [permission].
     ///
+    /// Returns a [`ModelError::InvalidInviteTarget`] if the invite targets
+    /// both a user's stream and an embedded application.
+    ///
     /// [`CreateInvite`]: crate::builder::CreateInvite
     /// [Create Invite]: Permissions::CREATE_INVITE
     /// [permission]: super::permissions
@@ -88,7 +91,7 @@ impl Invite {
             }
         }
 
-        let map = utils::hashmap_to_json_map(f(CreateInvite::default()).0);
+        let map = invite_map(f(CreateInvite::default()))?;
 
         cache_http.http().create_invite(channel_id.0, &map).await
     }
@@ -300,6 +303,13 @@ pub struct RichInvite {
     pub temporary: bool,
     /// The amount of times that an invite has been used.
     pub uses: u64,
+    /// The kind of target of the invite, if it targets a stream or an
+    /// embedded application.
+    #[serde(default)]
+    pub target_type: Option<InviteTargetType>,
+    /// The user whose stream is displayed by the invite.
+    #[serde(default)]
+    pub target_user: Option<User>,
 }
 
 #[cfg(feature = "model")]
@@ -382,3 +392,79 @@ impl RichInvite {
     /// ```
     pub fn url(&self) -> String { format!("https://discord.gg/{}", self.code) }
 }
+
+/// The kind of target of an invite.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum InviteTargetType {
+    /// The invite displays the stream of a user in a voice channel.
+    Stream = 1,
+    /// The invite opens an embedded application in a voice channel.
+    EmbeddedApplication = 2,
+}
+
+enum_number!(
+    InviteTargetType {
+        Stream,
+        EmbeddedApplication,
+    }
+);
+
+impl InviteTargetType {
+    pub fn num(self) -> u64 {
+        match self {
+            InviteTargetType::Stream => 1,
+            InviteTargetType::EmbeddedApplication => 2,
+        }
+    }
+}
+
+/// Builds an invite, checking that it doesn't target both a stream and an
+/// embedded application.
+#[cfg(feature = "model")]
+pub(crate) fn invite_map(invite: CreateInvite) -> Result<JsonMap> {
+    if invite.0.contains_key("target_user_id") && invite.0.contains_key("target_application_id") {
+        return Err(Error::Model(ModelError::InvalidInviteTarget));
+    }
+
+    Ok(utils::hashmap_to_json_map(invite.0))
+}
+
+#[cfg(all(test, feature = "model"))]
+mod test {
+    use super::{invite_map, InviteTargetType};
+    use crate::builder::CreateInvite;
+    use crate::model::ModelError;
+    use crate::Error;
+    use serde_json::{json, Value};
+
+    #[test]
+    fn default_invite_leaves_discord_defaults() {
+        let map = invite_map(CreateInvite::default()).unwrap();
+
+        // Discord defaults to a 24 hour invite with unlimited uses, which
+        // neither is temporary nor unique.
+        assert_eq!(Value::Object(map), json!({"validate": null}));
+    }
+
+    #[test]
+    fn invite_targets_are_exclusive() {
+        let mut invite = CreateInvite::default();
+        invite.max_age(0).target_user_id(7);
+
+        let map = invite_map(invite.clone()).unwrap();
+        assert_eq!(map["target_type"], json!(InviteTargetType::Stream.num()));
+        assert_eq!(map["target_user_id"], json!("7"));
+
+        invite.target_application_id(8);
+
+        match invite_map(invite) {
+            Err(Error::Model(ModelError::InvalidInviteTarget)) => {},
+            other => panic!("unexpected result: {:?}", other),
+        }
+
+        let mut invite = CreateInvite::default();
+        invite.target_application_id(8);
+        assert_eq!(invite_map(invite).unwrap()["target_type"], json!(2));
+    }
+}
@@ -14,6 +14,10 @@ use std::fmt;
 use crate::utils;
 
 /// Allows something - such as a channel or role - to be mentioned in a message.
+///
+/// Users and members are mentioned as `<@USER_ID>`, roles as `<@&ROLE_ID>`,
+/// channels as `<#CHANNEL_ID>`, and custom emojis as `<:NAME:EMOJI_ID>`, or
+/// `<a:NAME:EMOJI_ID>` if they are animated.
 pub trait Mentionable {
     /// Creates a mentionable string, that will be able to notify and/or create
     /// a link to the item.
@@ -35,33 +39,23 @@ impl Mentionable for Channel {
 }
 
 impl Mentionable for ChannelCategory {
-    fn mention(&self) -> String {
-        format!("<#{}>", self.name)
-    }
+    fn mention(&self) -> String { self.id.mention() }
 }
 
 impl Mentionable for CurrentUser {
-    fn mention(&self) -> String {
-        format!("<@{}>", self.id.0)
-    }
+    fn mention(&self) -> String { self.id.mention() }
 }
 
 impl Mentionable for Emoji {
-    fn mention(&self) -> String {
-        format!("<:{}:{}>", self.name, self.id.0)
-    }
+    fn mention(&self) -> String { self.to_string() }
 }
 
 impl Mentionable for Member {
-    fn mention(&self) -> String {
-        format!("<@{}>", self.user.id.0)
-    }
+    fn mention(&self) -> String { self.user.id.mention() }
 }
 
 impl Mentionable for PrivateChannel {
-    fn mention(&self) -> String {
-        format!("<#{}>", self.id.0)
-    }
+    fn mention(&self) -> String { self.id.mention() }
 }
 
 impl Mentionable for RoleId {
@@ -69,7 +63,7 @@ impl Mentionable for RoleId {
 }
 
 impl Mentionable for Role {
-    fn mention(&self) -> String { format!("<@&{}>", self.id.0) }
+    fn mention(&self) -> String { self.id.mention() }
 }
 
 impl Mentionable for UserId {
@@ -77,11 +71,11 @@ impl Mentionable for UserId {
 }
 
 impl Mentionable for User {
-    fn mention(&self) -> String { format!("<@{}>", self.id.0) }
+    fn mention(&self) -> String { self.id.mention() }
 }
 
 impl Mentionable for GuildChannel {
-    fn mention(&self) -> String { format!("<#{}>", self.id.0) }
+    fn mention(&self) -> String { self.id.mention() }
 }
 
 #[cfg(all(feature = "model", feature = "utils"))]
@@ -298,7 +292,7 @@ mod test {
 
         #[tokio::test]
         async fn test_mention() {
-            let channel = Channel::Guild(GuildChannel {
+            let channel = Channel::Guild(Box::new(GuildChannel {
                 bitrate: None,
                 category_id: None,
                 guild_id: GuildId(1),
@@ -313,7 +307,12 @@ mod test {
                 user_limit: None,
                 nsfw: false,
                 slow_mode_rate: Some(0),
-            });
+                thread_metadata: None,
+                member: None,
+                available_tags: vec![],
+                applied_tags: vec![],
+                default_reaction_emoji: None,
+            }));
             let emoji = Emoji {
                 animated: false,
                 id: EmojiId(5),
@@ -341,6 +340,8 @@ mod test {
                 name: "fake".to_string(),
             };
             let member = Member {
+                avatar: None,
+                communication_disabled_until: None,
                 deaf: false,
                 guild_id: GuildId(2),
                 joined_at: None,
@@ -7,7 +7,8 @@
 //!
 //! Additionally, presets equivalent to the official client's `@everyone` role
 //! presets are available. These are [`PRESET_GENERAL`], [`PRESET_TEXT`], and
-//! [`PRESET_VOICE`].
+//! [`PRESET_VOICE`]. Thread permissions, which came after these presets, are
+//! available as [`PRESET_THREADS`].
 //!
 //! Permissions follow a hierarchy:
 //!
@@ -30,7 +31,9 @@
 //! - [Manage Guild]
 //! - [Manage Messages]
 //! - [Manage Roles]
+//! - [Manage Threads]
 //! - [Manage Webhooks]
+//! - [Moderate Members]
 //!
 //! [Administrator]: Permissions::ADMINISTRATOR
 //! [Ban Members]: Permissions::BAN_MEMBERS
@@ -39,7 +42,9 @@
 //! [Manage Guild]: Permissions::MANAGE_GUILD
 //! [Manage Messages]: Permissions::MANAGE_MESSAGES
 //! [Manage Roles]: Permissions::MANAGE_ROLES
+//! [Manage Threads]: Permissions::MANAGE_THREADS
 //! [Manage Webhooks]: Permissions::MANAGE_WEBHOOKS
+//! [Moderate Members]: Permissions::MODERATE_MEMBERS
 
 use serde::de::{Deserialize, Deserializer, Error as DeError};
 use serde::ser::{Serialize, Serializer};
@@ -194,6 +199,24 @@ pub const PRESET_VOICE: Permissions = Permissions {
     bits: 0b0000_0011_1111_0000_0000_0000_0000_0000,
 };
 
+/// Returns a set of thread permissions, which are not part of the original
+/// `@everyone` presets as they were added after them.
+///
+/// This includes:
+///
+/// - [Create Private Threads]
+/// - [Create Public Threads]
+/// - [Send Messages in Threads]
+///
+/// [Create Private Threads]: Permissions::CREATE_PRIVATE_THREADS
+/// [Create Public Threads]: Permissions::CREATE_PUBLIC_THREADS
+/// [Send Messages in Threads]: Permissions::SEND_MESSAGES_IN_THREADS
+pub const PRESET_THREADS: Permissions = Permissions {
+    bits: Permissions::CREATE_PRIVATE_THREADS.bits
+        | Permissions::CREATE_PUBLIC_THREADS.bits
+        | Permissions::SEND_MESSAGES_IN_THREADS.bits,
+};
+
 /// A set of permissions that can be assigned to [`User`]s and [`Role`]s via
 /// [`PermissionOverwrite`]s, roles globally in a [`Guild`], and to
 /// [`GuildChannel`]s.
@@ -285,6 +308,8 @@ __impl_bitflags! {
         /// This does not dictate whether custom emojis in this guild can be
         /// used in other guilds.
         USE_EXTERNAL_EMOJIS = 0b0000_0000_0000_0100_0000_0000_0000_0000;
+        /// Allows viewing the insights of a guild.
+        VIEW_GUILD_INSIGHTS = 0b0000_0000_0000_1000_0000_0000_0000_0000;
         /// Allows the joining of a voice channel.
         CONNECT = 0b0000_0000_0001_0000_0000_0000_0000_0000;
         /// Allows the user to speak in a voice channel.
@@ -310,11 +335,40 @@ __impl_bitflags! {
         MANAGE_ROLES = 0b0001_0000_0000_0000_0000_0000_0000_0000;
         /// Allows management of webhooks.
         MANAGE_WEBHOOKS = 0b0010_0000_0000_0000_0000_0000_0000_0000;
-        /// Allows management of emojis created without the use of an
-        /// [`Integration`].
+        /// Allows management of emojis and stickers created without the use
+        /// of an [`Integration`].
         ///
         /// [`Integration`]: super::guild::Integration
+        MANAGE_EMOJIS_AND_STICKERS = 0b0100_0000_0000_0000_0000_0000_0000_0000;
+        /// The former name of [`MANAGE_EMOJIS_AND_STICKERS`], from before
+        /// the permission also applied to stickers.
+        ///
+        /// [`MANAGE_EMOJIS_AND_STICKERS`]: Self::MANAGE_EMOJIS_AND_STICKERS
         MANAGE_EMOJIS = 0b0100_0000_0000_0000_0000_0000_0000_0000;
+        /// Allows the usage of application commands, such as slash commands.
+        USE_SLASH_COMMANDS = 1 << 31;
+        /// Allows requesting to speak in a [stage] channel.
+        ///
+        /// [stage]: super::channel::ChannelType::Stage
+        REQUEST_TO_SPEAK = 1 << 32;
+        /// Allows the creation, editing and deletion of scheduled events.
+        MANAGE_EVENTS = 1 << 33;
+        /// Allows the deletion and archiving of threads, and viewing all
+        /// private threads.
+        MANAGE_THREADS = 1 << 34;
+        /// Allows the creation of public and announcement threads.
+        CREATE_PUBLIC_THREADS = 1 << 35;
+        /// Allows the creation of private threads.
+        CREATE_PRIVATE_THREADS = 1 << 36;
+        /// Allows the usage of custom stickers from other guilds.
+        USE_EXTERNAL_STICKERS = 1 << 37;
+        /// Allows sending messages in threads.
+        SEND_MESSAGES_IN_THREADS = 1 << 38;
+        /// Allows launching activities in a voice channel.
+        USE_EMBEDDED_ACTIVITIES = 1 << 39;
+        /// Allows timing out users, preventing them from sending messages,
+        /// reacting, and speaking in voice channels.
+        MODERATE_MEMBERS = 1 << 40;
     }
 }
 
@@ -328,28 +382,65 @@ generate_get_permission_names! {
     create_invite: "Create Invite",
     deafen_members: "Deafen Members",
     embed_links: "Embed Links",
-    external_emojis: "Use External Emojis",
+    create_private_threads: "Create Private Threads",
+    create_public_threads: "Create Public Threads",
     kick_members: "Kick Members",
     manage_channels: "Manage Channels",
-    manage_emojis: "Manage Emojis",
+    manage_emojis_and_stickers: "Manage Emojis and Stickers",
+    manage_events: "Manage Events",
     manage_guild: "Manage Guilds",
     manage_messages: "Manage Messages",
     manage_nicknames: "Manage Nicknames",
     manage_roles: "Manage Roles",
+    manage_threads: "Manage Threads",
     manage_webhooks: "Manage Webhooks",
     mention_everyone: "Mention Everyone",
+    moderate_members: "Moderate Members",
     move_members: "Move Members",
     mute_members: "Mute Members",
     priority_speaker: "Priority Speaker",
     read_message_history: "Read Message History",
     read_messages: "Read Messages",
+    request_to_speak: "Request To Speak",
     send_messages: "Send Messages",
+    send_messages_in_threads: "Send Messages in Threads",
     send_tts_messages: "Send TTS Messages",
     speak: "Speak",
     stream: "Stream",
+    use_embedded_activities: "Use Embedded Activities",
     use_external_emojis: "Use External Emojis",
+    use_external_stickers: "Use External Stickers",
+    use_slash_commands: "Use Slash Commands",
     use_vad: "Use Voice Activity",
-    view_audit_log: "View Audit Log"
+    view_audit_log: "View Audit Log",
+    view_guild_insights: "View Guild Insights"
+}
+
+impl Permissions {
+    /// Returns the permissions of `required` which are missing from this set.
+    ///
+    /// The names of the missing permissions can be listed with
+    /// [`get_permission_names`], or through the [`Display`] implementation.
+    ///
+    /// # Examples
+    ///
+    /// Telling a member which permissions they lack:
+    ///
+    /// ```rust
+    /// use serenity::model::Permissions;
+    ///
+    /// let member_permissions = Permissions::SEND_MESSAGES;
+    /// let required = Permissions::SEND_MESSAGES | Permissions::EMBED_LINKS;
+    ///
+    /// let missing = member_permissions.missing(required);
+    ///
+    /// assert_eq!(missing.to_string(), "Embed Links");
+    /// ```
+    ///
+    /// [`get_permission_names`]: Self::get_permission_names
+    pub fn missing(self, required: Permissions) -> Permissions {
+        required - self
+    }
 }
 
 #[cfg(feature = "model")]
@@ -414,6 +505,18 @@ impl Permissions {
     /// [Create Invite]: Self::CREATE_INVITE
     pub fn create_invite(self) -> bool { self.contains(Self::CREATE_INVITE) }
 
+    /// Shorthand for checking that the set of permissions contains the
+    /// [Create Private Threads] permission.
+    ///
+    /// [Create Private Threads]: Self::CREATE_PRIVATE_THREADS
+    pub fn create_private_threads(self) -> bool { self.contains(Self::CREATE_PRIVATE_THREADS) }
+
+    /// Shorthand for checking that the set of permissions contains the
+    /// [Create Public Threads] permission.
+    ///
+    /// [Create Public Threads]: Self::CREATE_PUBLIC_THREADS
+    pub fn create_public_threads(self) -> bool { self.contains(Self::CREATE_PUBLIC_THREADS) }
+
     /// Shorthand for checking that the set of permissions contains the
     /// [Deafen Members] permission.
     ///
@@ -450,6 +553,18 @@ impl Permissions {
     /// [Manage Emojis]: Self::MANAGE_EMOJIS
     pub fn manage_emojis(self) -> bool { self.contains(Self::MANAGE_EMOJIS) }
 
+    /// Shorthand for checking that the set of permissions contains the
+    /// [Manage Emojis and Stickers] permission.
+    ///
+    /// [Manage Emojis and Stickers]: Self::MANAGE_EMOJIS_AND_STICKERS
+    pub fn manage_emojis_and_stickers(self) -> bool { self.contains(Self::MANAGE_EMOJIS_AND_STICKERS) }
+
+    /// Shorthand for checking that the set of permissions contains the
+    /// [Manage Events] permission.
+    ///
+    /// [Manage Events]: Self::MANAGE_EVENTS
+    pub fn manage_events(self) -> bool { self.contains(Self::MANAGE_EVENTS) }
+
     /// Shorthand for checking that the set of permissions contains the
     /// [Manage Guild] permission.
     ///
@@ -474,6 +589,12 @@ impl Permissions {
     /// [Manage Roles]: Self::MANAGE_ROLES
     pub fn manage_roles(self) -> bool { self.contains(Self::MANAGE_ROLES) }
 
+    /// Shorthand for checking that the set of permissions contains the
+    /// [Manage Threads] permission.
+    ///
+    /// [Manage Threads]: Self::MANAGE_THREADS
+    pub fn manage_threads(self) -> bool { self.contains(Self::MANAGE_THREADS) }
+
     /// Shorthand for checking that the set of permissions contains the
     /// [Manage Webhooks] permission.
     ///
@@ -486,6 +607,12 @@ impl Permissions {
     /// [Mention Everyone]: Self::MENTION_EVERYONE
     pub fn mention_everyone(self) -> bool { self.contains(Self::MENTION_EVERYONE) }
 
+    /// Shorthand for checking that the set of permissions contains the
+    /// [Moderate Members] permission.
+    ///
+    /// [Moderate Members]: Self::MODERATE_MEMBERS
+    pub fn moderate_members(self) -> bool { self.contains(Self::MODERATE_MEMBERS) }
+
     /// Shorthand for checking that the set of permissions contains the
     /// [Move Members] permission.
     ///
@@ -510,12 +637,24 @@ impl Permissions {
     /// [Read Messages]: Self::READ_MESSAGES
     pub fn read_messages(self) -> bool { self.contains(Self::READ_MESSAGES) }
 
+    /// Shorthand for checking that the set of permissions contains the
+    /// [Request To Speak] permission.
+    ///
+    /// [Request To Speak]: Self::REQUEST_TO_SPEAK
+    pub fn request_to_speak(self) -> bool { self.contains(Self::REQUEST_TO_SPEAK) }
+
     /// Shorthand for checking that the set of permissions contains the
     /// [Send Messages] permission.
     ///
     /// [Send Messages]: Self::SEND_MESSAGES
     pub fn send_messages(self) -> bool { self.contains(Self::SEND_MESSAGES) }
 
+    /// Shorthand for checking that the set of permissions contains the
+    /// [Send Messages in Threads] permission.
+    ///
+    /// [Send Messages in Threads]: Self::SEND_MESSAGES_IN_THREADS
+    pub fn send_messages_in_threads(self) -> bool { self.contains(Self::SEND_MESSAGES_IN_THREADS) }
+
     /// Shorthand for checking that the set of permissions contains the
     /// [Send TTS Messages] permission.
     ///
@@ -534,11 +673,35 @@ impl Permissions {
     /// [Use External Emojis]: Self::USE_EXTERNAL_EMOJIS
     pub fn use_external_emojis(self) -> bool { self.contains(Self::USE_EXTERNAL_EMOJIS) }
 
+    /// Shorthand for checking that the set of permissions contains the
+    /// [Use Embedded Activities] permission.
+    ///
+    /// [Use Embedded Activities]: Self::USE_EMBEDDED_ACTIVITIES
+    pub fn use_embedded_activities(self) -> bool { self.contains(Self::USE_EMBEDDED_ACTIVITIES) }
+
+    /// Shorthand for checking that the set of permissions contains the
+    /// [Use External Stickers] permission.
+    ///
+    /// [Use External Stickers]: Self::USE_EXTERNAL_STICKERS
+    pub fn use_external_stickers(self) -> bool { self.contains(Self::USE_EXTERNAL_STICKERS) }
+
+    /// Shorthand for checking that the set of permissions contains the
+    /// [Use Slash Commands] permission.
+    ///
+    /// [Use Slash Commands]: Self::USE_SLASH_COMMANDS
+    pub fn use_slash_commands(self) -> bool { self.contains(Self::USE_SLASH_COMMANDS) }
+
     /// Shorthand for checking that the set of permissions contains the
     /// [Use VAD] permission.
     ///
     /// [Use VAD]: Self::USE_VAD
     pub fn use_vad(self) -> bool { self.contains(Self::USE_VAD) }
+
+    /// Shorthand for checking that the set of permissions contains the
+    /// [View Guild Insights] permission.
+    ///
+    /// [View Guild Insights]: Self::VIEW_GUILD_INSIGHTS
+    pub fn view_guild_insights(self) -> bool { self.contains(Self::VIEW_GUILD_INSIGHTS) }
 }
 
 impl Default for Permissions {
@@ -581,3 +744,62 @@ impl Display for Permissions {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{Permissions, PRESET_GENERAL, PRESET_TEXT, PRESET_THREADS, PRESET_VOICE};
+
+    #[test]
+    fn newer_flag_values() {
+        assert_eq!(Permissions::VIEW_GUILD_INSIGHTS.bits(), 0x0000_0008_0000);
+        assert_eq!(Permissions::MANAGE_EMOJIS_AND_STICKERS.bits(), 0x0000_4000_0000);
+        assert_eq!(Permissions::MANAGE_EMOJIS, Permissions::MANAGE_EMOJIS_AND_STICKERS);
+        assert_eq!(Permissions::USE_SLASH_COMMANDS.bits(), 0x0000_8000_0000);
+        assert_eq!(Permissions::REQUEST_TO_SPEAK.bits(), 0x0001_0000_0000);
+        assert_eq!(Permissions::MANAGE_EVENTS.bits(), 0x0002_0000_0000);
+        assert_eq!(Permissions::MANAGE_THREADS.bits(), 0x0004_0000_0000);
+        assert_eq!(Permissions::CREATE_PUBLIC_THREADS.bits(), 0x0008_0000_0000);
+        assert_eq!(Permissions::CREATE_PRIVATE_THREADS.bits(), 0x0010_0000_0000);
+        assert_eq!(Permissions::USE_EXTERNAL_STICKERS.bits(), 0x0020_0000_0000);
+        assert_eq!(Permissions::SEND_MESSAGES_IN_THREADS.bits(), 0x0040_0000_0000);
+        assert_eq!(Permissions::USE_EMBEDDED_ACTIVITIES.bits(), 0x0080_0000_0000);
+        assert_eq!(Permissions::MODERATE_MEMBERS.bits(), 0x0100_0000_0000);
+        assert_eq!(Permissions::all().bits(), 0x01FF_FFFF_FFFF);
+    }
+
+    #[test]
+    fn presets() {
+        assert_eq!(PRESET_GENERAL.bits(), 0x0637_DC41);
+        assert_eq!(PRESET_TEXT.bits(), 0x0007_FC40);
+        assert_eq!(PRESET_VOICE.bits(), 0x03F0_0000);
+        assert!(PRESET_TEXT.manage_messages());
+        assert!(PRESET_VOICE.move_members());
+        assert!(PRESET_THREADS.send_messages_in_threads());
+        assert!((PRESET_GENERAL & PRESET_THREADS).is_empty());
+    }
+
+    #[test]
+    fn missing() {
+        let missing = Permissions::SEND_MESSAGES.missing(Permissions::SEND_MESSAGES | Permissions::EMBED_LINKS);
+
+        assert_eq!(missing, Permissions::EMBED_LINKS);
+        assert_eq!(missing.get_permission_names(), vec!["Embed Links"]);
+        assert!(Permissions::all().missing(Permissions::all()).is_empty());
+    }
+
+    #[test]
+    fn permission_names_are_unique() {
+        let names = Permissions::all().get_permission_names();
+        let unique = names.iter().collect::<std::collections::HashSet<_>>();
+
+        assert_eq!(names.len(), unique.len());
+        assert_eq!(Permissions::USE_EXTERNAL_EMOJIS.to_string(), "Use External Emojis");
+    }
+
+    #[test]
+    fn deserialize_newer_flags() {
+        let permissions: Permissions = serde_json::from_str("\"1099511627776\"").unwrap();
+        assert_eq!(permissions, Permissions::MODERATE_MEMBERS);
+        assert_eq!(serde_json::to_string(&permissions).unwrap(), "\"1099511627776\"");
+    }
+}
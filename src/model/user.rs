@@ -645,10 +645,11 @@ impl User {
 
     /// Refreshes the information about the user.
     ///
-    /// Replaces the instance with the data retrieved over the REST API.
+    /// Replaces the instance with the data retrieved over the REST API, even
+    /// if the user is cached.
     #[inline]
     pub async fn refresh(&mut self, cache_http: impl CacheHttp) -> Result<()> {
-        *self = self.id.to_user(cache_http).await?;
+        *self = cache_http.http().get_user(self.id.0).await?;
 
         Ok(())
     }
@@ -937,6 +938,33 @@ mod test {
             assert_eq!(user.tag(), "test#1432");
         }
 
+        #[tokio::test]
+        async fn refresh() {
+            use crate::http::Http;
+            use http_crate::response::Builder;
+            use std::sync::Arc;
+
+            let mut http = Http::new_with_token("token");
+            http.ratelimiter.responder = Some(Arc::new(|request| {
+                assert!(request.url().as_str().ends_with("/users/210"));
+
+                let body = serde_json::json!({
+                    "id": "210",
+                    "avatar": "def",
+                    "discriminator": "1432",
+                    "username": "renamed",
+                });
+
+                reqwest::Response::from(Builder::new().status(200).body(serde_json::to_vec(&body).unwrap()).unwrap())
+            }));
+
+            let mut user = User::default();
+            user.refresh(Arc::new(http)).await.unwrap();
+
+            assert_eq!(user.name, "renamed");
+            assert_eq!(user.avatar.as_deref(), Some("def"));
+        }
+
         #[test]
         fn default_avatars() {
             let mut user = User::default();
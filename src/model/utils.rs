@@ -19,6 +19,14 @@ pub fn default_true() -> bool {
     true
 }
 
+/// Deserializes an optional value, falling back to its default when it is
+/// `null`.
+pub fn deserialize_default_on_null<'de, D: Deserializer<'de>, T: Default + Deserialize<'de>>(
+    deserializer: D,
+) -> StdResult<T, D::Error> {
+    Option::<T>::deserialize(deserializer).map(Option::unwrap_or_default)
+}
+
 pub fn deserialize_emojis<'de, D: Deserializer<'de>>(
     deserializer: D)
     -> StdResult<HashMap<EmojiId, Emoji>, D::Error> {
@@ -160,6 +160,8 @@ impl<'de> Deserialize<'de> for VoiceState {
                             let partial_member: Option<PartialMember> = map.next_value()?;
                             if let Some(partial_member) = partial_member {
                                 member = Some(Member {
+                                    avatar: None,
+                                    communication_disabled_until: None,
                                     deaf: partial_member.deaf,
                                     guild_id: GuildId(0),
                                     joined_at: partial_member.joined_at,
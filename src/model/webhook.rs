@@ -4,6 +4,7 @@ use super::{
     id::{
         ChannelId,
         GuildId,
+        MessageId,
         WebhookId
     },
     user::User
@@ -12,7 +13,7 @@ use super::{
 use std::fmt;
 
 #[cfg(feature = "model")]
-use crate::builder::ExecuteWebhook;
+use crate::builder::{EditWebhookMessage, ExecuteWebhook};
 #[cfg(feature = "model")]
 use crate::internal::prelude::*;
 #[cfg(feature = "model")]
@@ -20,6 +21,8 @@ use std::mem;
 #[cfg(feature = "model")]
 use super::channel::Message;
 #[cfg(feature = "model")]
+use super::ModelError;
+#[cfg(feature = "model")]
 use crate::utils;
 #[cfg(feature = "model")]
 use crate::http::Http;
@@ -78,6 +81,32 @@ impl Webhook {
         http.as_ref().delete_webhook_with_token(self.id.0, &self.token).await
     }
 
+    /// Deletes a message sent by the webhook.
+    ///
+    /// To delete a message sent in a thread, use [`delete_thread_message`].
+    ///
+    /// As this calls the [`Http::delete_webhook_message`] function,
+    /// authentication is not required.
+    ///
+    /// [`delete_thread_message`]: Self::delete_thread_message
+    #[inline]
+    pub async fn delete_message(&self, http: impl AsRef<Http>, message_id: impl Into<MessageId>) -> Result<()> {
+        http.as_ref().delete_webhook_message(self.id.0, None, &self.token, message_id.into().0).await
+    }
+
+    /// Deletes a message sent by the webhook in a thread of its channel.
+    #[inline]
+    pub async fn delete_thread_message(
+        &self,
+        http: impl AsRef<Http>,
+        thread_id: impl Into<ChannelId>,
+        message_id: impl Into<MessageId>,
+    ) -> Result<()> {
+        let thread_id = Some(thread_id.into().0);
+
+        http.as_ref().delete_webhook_message(self.id.0, thread_id, &self.token, message_id.into().0).await
+    }
+
     ///
     /// Edits the webhook in-place. All fields are optional.
     ///
@@ -157,11 +186,100 @@ impl Webhook {
         Ok(())
     }
 
+    /// Edits a message sent by the webhook.
+    ///
+    /// Refer to the documentation of [`EditWebhookMessage`] for a full list
+    /// of methods. To edit a message sent in a thread, use
+    /// [`edit_thread_message`].
+    ///
+    /// As this calls the [`Http::edit_webhook_message`] function,
+    /// authentication is not required.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::MessageTooLong`] if the content of the message
+    /// is over the limit, containing the number of unicode code points over
+    /// the limit.
+    ///
+    /// Returns a [`ModelError::EmbedAmount`] if more than 10 embeds were
+    /// added, or a [`ModelError::EmbedTooLarge`] if their combined text is
+    /// over the limit.
+    ///
+    /// Returns a [`ModelError::InvalidTimestamp`] if the timestamp of an
+    /// embed is not in the RFC 3339 format.
+    ///
+    /// Returns a [`ModelError::InvalidButton`] if a button has both or
+    /// neither of an URL and a custom Id.
+    ///
+    /// Returns an [`Error::Http`] if the message was not sent by the webhook.
+    ///
+    /// [`edit_thread_message`]: Self::edit_thread_message
+    /// [`Error::Http`]: crate::error::Error::Http
+    #[inline]
+    pub async fn edit_message<F>(&self, http: impl AsRef<Http>, message_id: impl Into<MessageId>, f: F) -> Result<Message>
+    where F: FnOnce(&mut EditWebhookMessage) -> &mut EditWebhookMessage
+    {
+        self._edit_message(http, None, message_id.into(), f).await
+    }
+
+    /// Edits a message sent by the webhook in a thread of its channel.
+    ///
+    /// Refer to [`edit_message`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`edit_message`].
+    ///
+    /// [`edit_message`]: Self::edit_message
+    #[inline]
+    pub async fn edit_thread_message<F>(
+        &self,
+        http: impl AsRef<Http>,
+        thread_id: impl Into<ChannelId>,
+        message_id: impl Into<MessageId>,
+        f: F,
+    ) -> Result<Message>
+    where F: FnOnce(&mut EditWebhookMessage) -> &mut EditWebhookMessage
+    {
+        self._edit_message(http, Some(thread_id.into()), message_id.into(), f).await
+    }
+
+    async fn _edit_message<F>(
+        &self,
+        http: impl AsRef<Http>,
+        thread_id: Option<ChannelId>,
+        message_id: MessageId,
+        f: F,
+    ) -> Result<Message>
+    where F: FnOnce(&mut EditWebhookMessage) -> &mut EditWebhookMessage
+    {
+        let mut edit_message = EditWebhookMessage::default();
+        f(&mut edit_message);
+
+        let map = utils::hashmap_to_json_map(edit_message.0);
+
+        Message::check_content_length(&map)?;
+        Message::check_embed_count(&map)?;
+        Message::check_embed_length(&map)?;
+        Message::check_embed_timestamp(&map)?;
+        Message::check_components(&map)?;
+
+        let thread_id = thread_id.map(|id| id.0);
+
+        http.as_ref().edit_webhook_message(self.id.0, thread_id, &self.token, message_id.0, &map).await
+    }
+
     /// Executes a webhook with the fields set via the given builder.
     ///
     /// The builder provides a method of setting only the fields you need,
     /// without needing to pass a long set of arguments.
     ///
+    /// Pass `true` to `wait` to receive the sent [`Message`], which is needed
+    /// to later [edit] or [delete] it. Otherwise, `None` is returned.
+    ///
+    /// [edit]: Self::edit_message
+    /// [delete]: Self::delete_message
+    ///
     /// # Examples
     ///
     /// Execute a webhook with message content of `test`:
@@ -219,6 +337,26 @@ impl Webhook {
     /// #     Ok(())
     /// # }
     /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::EmbedAmount`] if more than 10 embeds were
+    /// added, or a [`ModelError::EmbedTooLarge`] if their combined text is
+    /// over the limit.
+    ///
+    /// Returns a [`ModelError::InvalidTimestamp`] if the timestamp of an
+    /// embed is not in the RFC 3339 format.
+    ///
+    /// Returns a [`ModelError::InvalidAllowedMentions`] if a type of mentions
+    /// is both parsed and listed in the allowed mentions.
+    ///
+    /// Returns a [`ModelError::InvalidWebhookThread`] if both a thread to post
+    /// in and the name of a thread to create were set.
+    ///
+    /// Returns an [`Error::Http`] if the webhook Id or token is invalid, or
+    /// the message is rejected by Discord.
+    ///
+    /// [`Error::Http`]: crate::error::Error::Http
     #[inline]
     pub async fn execute<'a, F>(&self, http: impl AsRef<Http>, wait: bool, f: F) -> Result<Option<Message>>
     where for <'b> F: FnOnce(&'b mut ExecuteWebhook<'a>) -> &'b mut ExecuteWebhook<'a>
@@ -226,12 +364,24 @@ impl Webhook {
         let mut execute_webhook = ExecuteWebhook::default();
         f(&mut execute_webhook);
 
-        let map = utils::hashmap_to_json_map(execute_webhook.0);
+        let mut map = utils::hashmap_to_json_map(execute_webhook.0);
+
+        Message::check_embed_count(&map)?;
+        Message::check_embed_length(&map)?;
+        Message::check_embed_timestamp(&map)?;
+        Message::check_attachment_descriptions(&map)?;
+        Message::check_allowed_mentions(&map)?;
+        let thread_id = take_thread_id(&mut map)?;
+
+        let http = http.as_ref();
 
-        if !execute_webhook.1.is_empty() {
-            http.as_ref().execute_webhook_with_files(self.id.0, &self.token, wait, execute_webhook.1.clone(), map).await
-        } else {
-            http.as_ref().execute_webhook(self.id.0, &self.token, wait, &map).await
+        let files = execute_webhook.1.clone();
+
+        match (thread_id, files.is_empty()) {
+            (Some(thread_id), false) => http.execute_webhook_in_thread_with_files(self.id.0, thread_id, &self.token, wait, files, map).await,
+            (Some(thread_id), true) => http.execute_webhook_in_thread(self.id.0, thread_id, &self.token, wait, &map).await,
+            (None, false) => http.execute_webhook_with_files(self.id.0, &self.token, wait, files, map).await,
+            (None, true) => http.execute_webhook(self.id.0, &self.token, wait, &map).await,
         }
     }
 
@@ -273,3 +423,111 @@ impl WebhookId {
         http.as_ref().get_webhook(self.0).await
     }
 }
+
+/// Takes the thread to post in out of the body of a webhook execution, as it
+/// is sent as a query parameter instead.
+#[cfg(feature = "model")]
+fn take_thread_id(map: &mut JsonMap) -> Result<Option<u64>> {
+    let thread_id = map.remove("thread_id").and_then(|id| id.as_u64());
+
+    if thread_id.is_some() && map.contains_key("thread_name") {
+        return Err(Error::Model(ModelError::InvalidWebhookThread));
+    }
+
+    Ok(thread_id)
+}
+
+#[cfg(all(test, feature = "model"))]
+mod test {
+    use super::take_thread_id;
+    use crate::builder::ExecuteWebhook;
+    use crate::model::ModelError;
+    use crate::utils;
+
+    #[test]
+    fn thread_id_is_not_in_body() {
+        let mut builder = ExecuteWebhook::default();
+        builder.content("hello").in_thread(3);
+        let mut map = utils::hashmap_to_json_map(builder.0.clone());
+
+        assert_eq!(take_thread_id(&mut map).unwrap(), Some(3));
+        assert!(!map.contains_key("thread_id"));
+
+        builder.thread_name("News");
+        let mut map = utils::hashmap_to_json_map(builder.0);
+
+        match take_thread_id(&mut map) {
+            Err(crate::Error::Model(ModelError::InvalidWebhookThread)) => {},
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn embeds_are_checked() {
+        use super::Webhook;
+        use crate::http::Http;
+        use serde_json::json;
+
+        let http = Http::default();
+        let webhook: Webhook = serde_json::from_value(json!({
+            "id": "1",
+            "type": 1,
+            "avatar": null,
+            "channel_id": "2",
+            "name": "hook",
+            "token": "token",
+        })).unwrap();
+
+        let description = "a".repeat(6001);
+        match webhook.execute(&http, false, |w| w.embeds(vec![json!({"description": description})])).await {
+            Err(crate::Error::Model(ModelError::EmbedTooLarge(1))) => {},
+            other => panic!("unexpected result: {:?}", other),
+        }
+
+        match webhook.edit_message(&http, 3, |m| m.embed(|e| e.timestamp("last tuesday"))).await {
+            Err(crate::Error::Model(ModelError::InvalidTimestamp)) => {},
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn edit_thread_message() {
+        use super::Webhook;
+        use crate::http::Http;
+        use crate::internal::test_utils;
+        use http_crate::response::Builder;
+        use serde_json::json;
+        use std::sync::Arc;
+
+        let mut http = Http::new_with_token("token");
+        http.ratelimiter.responder = Some(Arc::new(|request| {
+            assert_eq!(request.method(), reqwest::Method::PATCH);
+            assert_eq!(request.url().path(), "/api/v8/webhooks/1/token/messages/3");
+            assert_eq!(request.url().query(), Some("thread_id=4"));
+
+            let response = Builder::new()
+                .status(200)
+                .body(serde_json::to_vec(&test_utils::message_json(4, None)).unwrap())
+                .unwrap();
+
+            reqwest::Response::from(response)
+        }));
+        let webhook: Webhook = serde_json::from_value(json!({
+            "id": "1",
+            "type": 1,
+            "avatar": null,
+            "channel_id": "2",
+            "name": "hook",
+            "token": "token",
+        })).unwrap();
+
+        let content = "a".repeat(2001);
+        match webhook.edit_thread_message(&http, 4, 3, |m| m.content(content)).await {
+            Err(crate::Error::Model(ModelError::MessageTooLong(1))) => {},
+            other => panic!("unexpected result: {:?}", other),
+        }
+
+        let message = webhook.edit_thread_message(&http, 4, 3, |m| m.content("Deployed!")).await.unwrap();
+        assert_eq!(message.channel_id.0, 4);
+    }
+}
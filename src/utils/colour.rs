@@ -1,6 +1,10 @@
 // Disable this lint to avoid it wanting to change `0xABCDEF` to `0xAB_CDEF`.
 #![allow(clippy::unreadable_literal)]
 
+use std::error::Error as StdError;
+use std::fmt;
+use std::str::FromStr;
+
 macro_rules! colour {
     ($(#[$attr:meta] $constname:ident, $name:ident, $val:expr;)*) => {
         impl Colour {
@@ -205,6 +209,122 @@ impl Colour {
     pub fn hex(self) -> String {
         format!("{:06X}", self.0)
     }
+
+    /// Returns the complementary colour, inverting each of the RGB
+    /// components.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::utils::Colour;
+    ///
+    /// assert_eq!(Colour::from_rgb(255, 136, 0).invert().tuple(), (0, 119, 255));
+    /// ```
+    pub const fn invert(self) -> Colour {
+        Colour(!self.0 & 0xFFFFFF)
+    }
+
+    /// Mixes the colour with white, by a `factor` between `0.0` (the same
+    /// colour) and `1.0` (white).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::utils::Colour;
+    ///
+    /// assert_eq!(Colour::from_rgb(0, 100, 200).lighten(0.5).tuple(), (128, 178, 228));
+    /// ```
+    pub fn lighten(self, factor: f32) -> Colour {
+        let factor = clamp_factor(factor);
+        let lighten = |c: u8| c + (f32::from(255 - c) * factor).round() as u8;
+
+        Colour::from_rgb(lighten(self.r()), lighten(self.g()), lighten(self.b()))
+    }
+
+    /// Mixes the colour with black, by a `factor` between `0.0` (the same
+    /// colour) and `1.0` (black).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::utils::Colour;
+    ///
+    /// assert_eq!(Colour::from_rgb(0, 100, 200).darken(0.5).tuple(), (0, 50, 100));
+    /// ```
+    pub fn darken(self, factor: f32) -> Colour {
+        let factor = clamp_factor(factor);
+        let darken = |c: u8| c - (f32::from(c) * factor).round() as u8;
+
+        Colour::from_rgb(darken(self.r()), darken(self.g()), darken(self.b()))
+    }
+}
+
+fn clamp_factor(factor: f32) -> f32 {
+    if factor > 1.0 {
+        1.0
+    } else if factor > 0.0 {
+        factor
+    } else {
+        0.0
+    }
+}
+
+/// The error returned when parsing a [`Colour`] from a string failed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ColourParseError {
+    /// The string is not 6 hexadecimal digits, optionally prefixed by `#`.
+    InvalidHex,
+    /// The string starts with `rgb(` but doesn't hold 3 components between 0
+    /// and 255.
+    InvalidRgb,
+}
+
+impl fmt::Display for ColourParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ColourParseError::InvalidHex => f.write_str("invalid hex colour"),
+            ColourParseError::InvalidRgb => f.write_str("invalid rgb colour"),
+        }
+    }
+}
+
+impl StdError for ColourParseError {}
+
+impl FromStr for Colour {
+    type Err = ColourParseError;
+
+    /// Parses a colour in the `#RRGGBB`, `RRGGBB` or `rgb(r, g, b)` format.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::utils::Colour;
+    ///
+    /// assert_eq!("#FF8800".parse(), Ok(Colour::from_rgb(255, 136, 0)));
+    /// assert_eq!("ff8800".parse(), Ok(Colour::from_rgb(255, 136, 0)));
+    /// assert_eq!("rgb(255, 136, 0)".parse(), Ok(Colour::from_rgb(255, 136, 0)));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        if let Some(components) = s.strip_prefix("rgb(").and_then(|s| s.strip_suffix(')')) {
+            let mut components = components.split(',').map(|c| c.trim().parse::<u8>());
+
+            return match (components.next(), components.next(), components.next(), components.next()) {
+                (Some(Ok(r)), Some(Ok(g)), Some(Ok(b)), None) => Ok(Colour::from_rgb(r, g, b)),
+                _ => Err(ColourParseError::InvalidRgb),
+            };
+        }
+
+        let hex = s.strip_prefix('#').unwrap_or(s);
+
+        if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(ColourParseError::InvalidHex);
+        }
+
+        u32::from_str_radix(hex, 16).map(Colour).map_err(|_| ColourParseError::InvalidHex)
+    }
 }
 
 impl From<i32> for Colour {
@@ -262,12 +382,26 @@ impl From<(u8, u8, u8)> for Colour {
 }
 
 colour! {
+    /// Creates a new `Colour`, setting its RGB value to `(0, 0, 0)`.
+    BLACK, black, 0x000000;
     /// Creates a new `Colour`, setting its RGB value to `(111, 198, 226)`.
     BLITZ_BLUE, blitz_blue, 0x6FC6E2;
     /// Creates a new `Colour`, setting its RGB value to `(52, 152, 219)`.
     BLUE, blue, 0x3498DB;
     /// Creates a new `Colour`, setting its RGB value to `(114, 137, 218)`.
     BLURPLE, blurple, 0x7289DA;
+    /// Creates a new `Colour`, setting its RGB value to `(88, 101, 242)`.
+    BRAND_BLURPLE, brand_blurple, 0x5865F2;
+    /// Creates a new `Colour`, setting its RGB value to `(235, 69, 158)`.
+    BRAND_FUCHSIA, brand_fuchsia, 0xEB459E;
+    /// Creates a new `Colour`, setting its RGB value to `(87, 242, 135)`.
+    BRAND_GREEN, brand_green, 0x57F287;
+    /// Creates a new `Colour`, setting its RGB value to `(237, 66, 69)`.
+    BRAND_RED, brand_red, 0xED4245;
+    /// Creates a new `Colour`, setting its RGB value to `(254, 231, 92)`.
+    BRAND_YELLOW, brand_yellow, 0xFEE75C;
+    /// Creates a new `Colour`, setting its RGB value to `(44, 47, 51)`.
+    DARK_BUT_NOT_BLACK, dark_but_not_black, 0x2C2F33;
     /// Creates a new `Colour`, setting its RGB value to `(32, 102, 148)`.
     DARK_BLUE, dark_blue, 0x206694;
     /// Creates a new `Colour`, setting its RGB value to `(194, 124, 14)`.
@@ -296,6 +430,10 @@ colour! {
     FOOYOO, fooyoo, 0x11CA80;
     /// Creates a new `Colour`, setting its RGB value to `(241, 196, 15)`.
     GOLD, gold, 0xF1C40F;
+    /// Creates a new `Colour`, setting its RGB value to `(46, 204, 113)`.
+    GREEN, green, 0x2ECC71;
+    /// Creates a new `Colour`, setting its RGB value to `(153, 170, 181)`.
+    GREYPLE, greyple, 0x99AAB5;
     /// Creates a new `Colour`, setting its RGB value to `(186, 218, 85)`.
     KERBAL, kerbal, 0xBADA55;
     /// Creates a new `Colour`, setting its RGB value to `(151, 156, 159)`.
@@ -306,6 +444,8 @@ colour! {
     MAGENTA, magenta, 0xE91E63;
     /// Creates a new `Colour`, setting its RGB value to `(230, 131, 151)`.
     MEIBE_PINK, meibe_pink, 0xE68397;
+    /// Creates a new `Colour`, setting its RGB value to `(35, 39, 42)`.
+    NOT_QUITE_BLACK, not_quite_black, 0x23272A;
     /// Creates a new `Colour`, setting its RGB value to `(230, 126, 34)`.
     ORANGE, orange, 0xE67E22;
     /// Creates a new `Colour`, setting its RGB value to `(155, 89, 182)`.
@@ -318,6 +458,8 @@ colour! {
     ROSEWATER, rosewater, 0xF6DBD8;
     /// Creates a new `Colour`, setting its RGB value to `(26, 188, 156)`.
     TEAL, teal, 0x1ABC9C;
+    /// Creates a new `Colour`, setting its RGB value to `(255, 255, 255)`.
+    WHITE, white, 0xFFFFFF;
 }
 
 impl Default for Colour {
@@ -327,7 +469,7 @@ impl Default for Colour {
 
 #[cfg(test)]
 mod test {
-    use super::Colour;
+    use super::{Colour, ColourParseError};
     use std::u32;
 
     #[test]
@@ -369,6 +511,44 @@ mod test {
         assert_eq!(Colour::default().0, 0);
     }
 
+    #[test]
+    fn from_str() {
+        assert_eq!("#ff8800".parse(), Ok(Colour::from_rgb(0xFF, 0x88, 0x00)));
+        assert_eq!(" FF8800 ".parse(), Ok(Colour::new(0xFF8800)));
+        assert_eq!("rgb(255,136, 0)".parse(), Ok(Colour::new(0xFF8800)));
+
+        assert_eq!("#xyz".parse::<Colour>(), Err(ColourParseError::InvalidHex));
+        assert_eq!("#ff88001".parse::<Colour>(), Err(ColourParseError::InvalidHex));
+        assert_eq!("+f8800".parse::<Colour>(), Err(ColourParseError::InvalidHex));
+        assert_eq!("rgb(256, 0, 0)".parse::<Colour>(), Err(ColourParseError::InvalidRgb));
+        assert_eq!("rgb(1, 2)".parse::<Colour>(), Err(ColourParseError::InvalidRgb));
+    }
+
+    #[test]
+    fn hex_round_trip() {
+        let colour = Colour::from_rgb(0x0A, 0xBC, 0xDE);
+
+        assert_eq!(colour.hex(), "0ABCDE");
+        assert_eq!(colour.hex().parse(), Ok(colour));
+    }
+
+    #[test]
+    fn invert() {
+        assert_eq!(Colour::WHITE.invert(), Colour::BLACK);
+        assert_eq!(Colour::new(0xFF8800).invert(), Colour::new(0x0077FF));
+    }
+
+    #[test]
+    fn lighten_darken() {
+        let colour = Colour::from_rgb(0, 100, 200);
+
+        assert_eq!(colour.lighten(0.0), colour);
+        assert_eq!(colour.lighten(1.0), Colour::WHITE);
+        assert_eq!(colour.lighten(2.0), Colour::WHITE);
+        assert_eq!(colour.darken(1.0), Colour::BLACK);
+        assert_eq!(colour.darken(-1.0), colour);
+    }
+
     #[test]
     fn from() {
         assert_eq!(Colour::from(7i32).0, 7);
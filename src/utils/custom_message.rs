@@ -268,6 +268,8 @@ fn dummy_message() -> Message {
         message_reference: None,
         flags: None,
         stickers: Vec::new(),
+        sticker_items: Vec::new(),
         referenced_message: None,
+        components: Vec::new(),
     }
 }
@@ -0,0 +1,69 @@
+//! Resolution of the shortcodes of unicode emojis, such as `:fire:`.
+
+include!("emoji_shortcodes.rs");
+
+/// Resolves the shortcode of a unicode emoji, as typed in the Discord client,
+/// to the emoji itself.
+///
+/// The surrounding colons are optional.
+///
+/// The known shortcodes are the Unicode names and GitHub aliases of the
+/// emojis, along with the names specific to the Discord client.
+///
+/// **Note**: The skin tone variants typed as `:thumbsup_tone1:` in the Discord
+/// client are not known, and `None` is returned for them.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity::utils::emoji_from_shortcode;
+///
+/// assert_eq!(emoji_from_shortcode(":thumbsup:").as_deref(), Some("\u{1f44d}"));
+/// assert_eq!(emoji_from_shortcode("fire").as_deref(), Some("\u{1f525}"));
+/// assert_eq!(emoji_from_shortcode(":not_an_emoji:"), None);
+/// ```
+pub fn emoji_from_shortcode(shortcode: &str) -> Option<String> {
+    let name = shortcode
+        .strip_prefix(':')
+        .and_then(|name| name.strip_suffix(':'))
+        .unwrap_or(shortcode);
+
+    SHORTCODES
+        .binary_search_by(|&(code, _)| code.cmp(name))
+        .ok()
+        .map(|index| SHORTCODES[index].1.to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{emoji_from_shortcode, SHORTCODES};
+
+    #[test]
+    fn resolve_shortcodes() {
+        assert_eq!(emoji_from_shortcode(":fire:").as_deref(), Some("\u{1f525}"));
+        assert_eq!(emoji_from_shortcode(":+1:").as_deref(), Some("\u{1f44d}"));
+        assert_eq!(emoji_from_shortcode("one").as_deref(), Some("1\u{fe0f}\u{20e3}"));
+        assert_eq!(emoji_from_shortcode(":heart:").as_deref(), Some("\u{2764}\u{fe0f}"));
+        assert_eq!(emoji_from_shortcode(":slight_smile:").as_deref(), Some("\u{1f642}"));
+        assert_eq!(emoji_from_shortcode(":rainbow_flag:").as_deref(), Some("\u{1f3f3}\u{fe0f}\u{200d}\u{1f308}"));
+        assert_eq!(emoji_from_shortcode(":fire"), None);
+        assert_eq!(emoji_from_shortcode(":"), None);
+        assert_eq!(emoji_from_shortcode("::"), None);
+        assert_eq!(emoji_from_shortcode(":not_an_emoji:"), None);
+    }
+
+    #[test]
+    fn shortcodes_are_sorted() {
+        assert!(SHORTCODES.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[cfg(feature = "model")]
+    #[test]
+    fn shortcodes_are_emojis() {
+        use crate::model::channel::ReactionType;
+
+        for &(name, emoji) in SHORTCODES {
+            assert!(ReactionType::unicode(emoji).is_ok(), "{} is not a valid emoji", name);
+        }
+    }
+}
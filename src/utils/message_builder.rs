@@ -188,7 +188,29 @@ impl MessageBuilder {
         self
     }
 
-    /// Mentions something that implements the [`Mentionable`] trait.
+    /// Mentions something that implements the [`Mentionable`] trait, such as
+    /// a [`User`], a [`Member`], a [`Role`], a channel or an [`Emoji`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::model::id::{ChannelId, RoleId, UserId};
+    /// use serenity::utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new()
+    ///     .mention(&UserId(1))
+    ///     .push(" has been given ")
+    ///     .mention(&RoleId(2))
+    ///     .push(", see ")
+    ///     .mention(&ChannelId(3))
+    ///     .build();
+    ///
+    /// assert_eq!(content, "<@1> has been given <@&2>, see <#3>");
+    /// ```
+    ///
+    /// [`User`]: crate::model::user::User
+    /// [`Member`]: crate::model::guild::Member
+    /// [`Role`]: crate::model::guild::Role
     pub fn mention<M: Mentionable>(&mut self, item: &M) -> &mut Self {
         let _ = write!(self.0, "{}", item.mention());
 
@@ -374,6 +396,15 @@ impl MessageBuilder {
         self
     }
 
+    /// Pushes a bold spoiler'd inline text to the content.
+    pub fn push_bold_spoiler<D: I>(&mut self, content: D) -> &mut Self {
+        self.0.push_str("||**");
+        self.0.push_str(&content.into().to_string());
+        self.0.push_str("**||");
+
+        self
+    }
+
     /// Pushes a quoted inline text to the content
     pub fn push_quote<D: I>(&mut self, content: D) -> &mut Self {
         self.0.push_str("> ");
@@ -846,6 +877,48 @@ impl MessageBuilder {
         self
     }
 
+    /// Pushes a quote to the content, prefixing each of its lines with `> `.
+    ///
+    /// Unlike [`quote_rest`], only the given text is quoted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new().quote("hello\nworld").push("\n- Ferris").build();
+    ///
+    /// assert_eq!(content, "> hello\n> world\n- Ferris");
+    /// ```
+    ///
+    /// [`quote_rest`]: Self::quote_rest
+    pub fn quote<D: I>(&mut self, content: D) -> &mut Self {
+        self._quote(&content.into().to_string());
+
+        self
+    }
+
+    /// Pushes a quote to the content normalizing content, prefixing each of
+    /// its lines with `> `.
+    pub fn quote_safe<D: I>(&mut self, content: D) -> &mut Self {
+        let mut c = content.into();
+        c.inner = normalize(&c.inner);
+        self._quote(&c.to_string());
+
+        self
+    }
+
+    fn _quote(&mut self, content: &str) {
+        for (i, line) in content.split('\n').enumerate() {
+            if i != 0 {
+                self.0.push('\n');
+            }
+
+            self.0.push_str("> ");
+            self.0.push_str(line);
+        }
+    }
+
     /// Starts a multi-line quote, every push after this one will be quoted
     pub fn quote_rest(&mut self) -> &mut Self {
         self.0.push_str("\n>>> ");
@@ -1333,6 +1406,43 @@ mod test {
         assert_eq!(content_emoji, "<:Rohrkatze:32>");
     }
 
+    #[test]
+    fn mention_each_type() {
+        let user = User {
+            id: UserId(6),
+            avatar: None,
+            bot: false,
+            discriminator: 4132,
+            name: "fake".to_string(),
+        };
+        let member = Member {
+            avatar: None,
+            communication_disabled_until: None,
+            deaf: false,
+            guild_id: GuildId(2),
+            joined_at: None,
+            mute: false,
+            nick: None,
+            roles: vec![],
+            user: user.clone(),
+        };
+        let emoji = Emoji {
+            animated: true,
+            id: EmojiId(32),
+            name: "Rohrkatze".to_string(),
+            managed: false,
+            require_colons: true,
+            roles: vec![],
+        };
+
+        assert_eq!(MessageBuilder::new().mention(&user).build(), "<@6>");
+        assert_eq!(MessageBuilder::new().mention(&member).build(), "<@6>");
+        assert_eq!(MessageBuilder::new().mention(&UserId(7)).build(), "<@7>");
+        assert_eq!(MessageBuilder::new().mention(&RoleId(8)).build(), "<@&8>");
+        assert_eq!(MessageBuilder::new().mention(&ChannelId(9)).build(), "<#9>");
+        assert_eq!(MessageBuilder::new().mention(&emoji).build(), "<a:Rohrkatze:32>");
+    }
+
     #[test]
     fn content() {
         let content = Bold + Italic + Code + "Fun!";
@@ -1556,10 +1666,24 @@ mod test {
             push_spoiler_line => [
                 "" => "||||\n",
                 "foo" => "||foo||\n"
+            ],
+            push_bold_spoiler => [
+                "x" => "||**x**||",
+                "" => "||****||"
+            ],
+            quote => [
+                "x" => "> x",
+                "a\nb\n\nc" => "> a\n> b\n> \n> c"
             ]
         };
     }
 
+    #[test]
+    fn quote_safe() {
+        let content = MessageBuilder::new().quote_safe("@everyone\nhi").build();
+        assert_eq!(content, "> @\u{200B}everyone\n> hi");
+    }
+
     #[test]
     fn normalize() {
         assert_eq!(super::normalize("@everyone"), "@\u{200B}everyone");
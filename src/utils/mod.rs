@@ -2,11 +2,13 @@
 //! fully use the library.
 
 mod colour;
+mod emoji;
 mod message_builder;
 mod custom_message;
 
 pub use self::{
-    colour::Colour,
+    colour::{Colour, ColourParseError},
+    emoji::emoji_from_shortcode,
     message_builder::{
         Content,
         ContentModifier,
@@ -20,12 +22,10 @@ pub type Color = Colour;
 use crate::internal::prelude::*;
 use crate::model::{
     misc::EmojiIdentifier,
-    id::EmojiId,
+    id::{ChannelId, EmojiId, GuildId, MessageId},
 };
 #[cfg(feature = "cache")]
 use crate::model::id::{
-    ChannelId,
-    GuildId,
     RoleId,
     UserId,
 };
@@ -326,6 +326,59 @@ pub fn parse_emoji(mention: impl AsRef<str>) -> Option<EmojiIdentifier> {
     }
 }
 
+/// Retrieves the guild, channel and message Ids from a message link, such as
+/// the ones generated by [`Message::link`].
+///
+/// Links of the `ptb` and `canary` clients, and of the former `discordapp.com`
+/// domain, are recognized. Links to messages in private channels use `@me` in
+/// place of a guild Id, for which no guild is returned.
+///
+/// # Examples
+///
+/// Parsing the link of a message in a guild:
+///
+/// ```rust
+/// use serenity::model::id::{ChannelId, GuildId, MessageId};
+/// use serenity::utils::parse_message_link;
+///
+/// let link = "https://discord.com/channels/381880193251409931/381880193700069377/806164913558781963";
+///
+/// assert_eq!(parse_message_link(link), Some((
+///     Some(GuildId(381880193251409931)),
+///     ChannelId(381880193700069377),
+///     MessageId(806164913558781963),
+/// )));
+/// ```
+///
+/// [`Message::link`]: crate::model::channel::Message::link
+pub fn parse_message_link(link: &str) -> Option<(Option<GuildId>, ChannelId, MessageId)> {
+    const HOSTS: &[&str] = &["discord.com", "discordapp.com"];
+
+    let link = link.split(&['?', '#'][..]).next()?;
+    let link = link.strip_prefix("https://").or_else(|| link.strip_prefix("http://"))?;
+
+    let mut parts = link.trim_end_matches('/').split('/');
+    let host = parts.next()?;
+    let host = host.strip_prefix("ptb.").or_else(|| host.strip_prefix("canary.")).unwrap_or(host);
+
+    if !HOSTS.contains(&host) || parts.next()? != "channels" {
+        return None;
+    }
+
+    let guild_id = match parts.next()? {
+        "@me" => None,
+        guild_id => Some(GuildId(guild_id.parse().ok()?)),
+    };
+    let channel_id = ChannelId(parts.next()?.parse().ok()?);
+    let message_id = MessageId(parts.next()?.parse().ok()?);
+
+    if parts.next().is_some() {
+        return None;
+    }
+
+    Some((guild_id, channel_id, message_id))
+}
+
 /// Reads an image from a path and encodes it into base64.
 ///
 /// This can be used for methods like [`EditProfile::avatar`].
@@ -458,6 +511,7 @@ pub fn shard_id(guild_id: impl Into<u64>, shard_count: u64) -> u64 { (guild_id.i
 #[derive(Clone, Debug)]
 pub struct ContentSafeOptions {
     clean_role: bool,
+    strip_roles: bool,
     clean_user: bool,
     clean_channel: bool,
     clean_here: bool,
@@ -481,6 +535,18 @@ impl ContentSafeOptions {
         self
     }
 
+    /// If set to true, [`content_safe`] will remove role mentions instead of
+    /// replacing them with the role's name.
+    ///
+    /// This has no effect if [`clean_role`] is set to false.
+    ///
+    /// [`clean_role`]: Self::clean_role
+    pub fn strip_roles(mut self, b: bool) -> Self {
+        self.strip_roles = b;
+
+        self
+    }
+
     /// If set to true, [`content_safe`] will replace user mentions
     /// (`<@!{id}>` or `<@{id}>`) with the user's name prefixed with `@`
     /// (`@username`) or with `@invalid-user` if the identifier is invalid.
@@ -536,10 +602,14 @@ impl ContentSafeOptions {
 
 #[cfg(feature = "cache")]
 impl Default for ContentSafeOptions {
-    /// Instantiates with all options set to `true`.
+    /// Instantiates with all options set to `true`, except for
+    /// [`strip_roles`].
+    ///
+    /// [`strip_roles`]: Self::strip_roles
     fn default() -> Self {
         ContentSafeOptions {
             clean_role: true,
+            strip_roles: false,
             clean_user: true,
             clean_channel: true,
             clean_here: true,
@@ -552,7 +622,7 @@ impl Default for ContentSafeOptions {
 
 #[cfg(feature = "cache")]
 #[inline]
-async fn clean_roles(cache: impl AsRef<Cache>, s: &mut String) {
+async fn clean_roles(cache: impl AsRef<Cache>, s: &mut String, strip: bool) {
     let mut progress = 0;
 
     while let Some(mut mention_start) = s[progress..].find("<@&") {
@@ -565,8 +635,16 @@ async fn clean_roles(cache: impl AsRef<Cache>, s: &mut String) {
             if let Ok(id) = RoleId::from_str(&s[mention_start..mention_end]) {
                 let to_replace = format!("<@&{}>", &s[mention_start..mention_end]);
 
-                *s = if let Some(role) = id.to_role_cached(&cache).await {
-                    s.replace(&to_replace, &format!("@{}", &role.name))
+                *s = if strip {
+                    s.replace(&to_replace, "")
+                } else if let Some(role) = id.to_role_cached(&cache).await {
+                    // The @everyone role shares its id with the guild, and
+                    // its name would ping everyone once prefixed.
+                    if role.id.0 == role.guild_id.0 {
+                        s.replace(&to_replace, "@\u{200B}everyone")
+                    } else {
+                        s.replace(&to_replace, &format!("@{}", &role.name))
+                    }
                 } else {
                     s.replace(&to_replace, &"@deleted-role")
                 };
@@ -575,8 +653,9 @@ async fn clean_roles(cache: impl AsRef<Cache>, s: &mut String) {
 
                 if !id.is_empty() && id.as_bytes().iter().all(u8::is_ascii_digit){
                     let to_replace = format!("<@&{}>", id);
+                    let replacement = if strip { "" } else { "@deleted-role" };
 
-                    *s = s.replace(&to_replace, &"@deleted-role");
+                    *s = s.replace(&to_replace, replacement);
                 } else {
                     progress = mention_end;
                 }
@@ -733,7 +812,7 @@ pub async fn content_safe(cache: impl AsRef<Cache>, s: impl AsRef<str>, options:
     let mut content = s.as_ref().to_string();
 
     if options.clean_role {
-        clean_roles(&cache, &mut content).await;
+        clean_roles(&cache, &mut content, options.strip_roles).await;
     }
 
     if options.clean_channel {
@@ -796,6 +875,40 @@ mod test {
         assert_eq!(emoji.id, 12_345);
     }
 
+    #[test]
+    fn test_message_link_parser() {
+        let ids = (ChannelId(381880193700069377), MessageId(806164913558781963));
+
+        assert_eq!(
+            parse_message_link("https://discord.com/channels/381880193251409931/381880193700069377/806164913558781963"),
+            Some((Some(GuildId(381880193251409931)), ids.0, ids.1)),
+        );
+        assert_eq!(
+            parse_message_link("https://ptb.discordapp.com/channels/381880193251409931/381880193700069377/806164913558781963/"),
+            Some((Some(GuildId(381880193251409931)), ids.0, ids.1)),
+        );
+        assert_eq!(
+            parse_message_link("https://canary.discord.com/channels/@me/381880193700069377/806164913558781963?context=1"),
+            Some((None, ids.0, ids.1)),
+        );
+
+        assert_eq!(parse_message_link("https://discord.com/channels/381880193251409931/381880193700069377"), None);
+        assert_eq!(parse_message_link("https://discord.com/channels/general/381880193700069377/806164913558781963"), None);
+        assert_eq!(parse_message_link("https://discord.com/channels/1/2/3/4"), None);
+        assert_eq!(parse_message_link("https://example.com/channels/1/2/3"), None);
+        assert_eq!(parse_message_link("https://notdiscord.com/channels/1/2/3"), None);
+    }
+
+    #[cfg(feature = "model")]
+    #[test]
+    fn test_message_link_round_trip() {
+        let link = MessageId(3).link(ChannelId(2), Some(GuildId(1)));
+        assert_eq!(parse_message_link(&link), Some((Some(GuildId(1)), ChannelId(2), MessageId(3))));
+
+        let link = MessageId(3).link(ChannelId(2), None);
+        assert_eq!(parse_message_link(&link), Some((None, ChannelId(2), MessageId(3))));
+    }
+
     #[test]
     fn test_quote_parser() {
         let parsed = parse_quotes("a \"b c\" d\"e f\"  g");
@@ -861,6 +974,8 @@ mod test {
         };
 
         let member = Member {
+            avatar: None,
+            communication_disabled_until: None,
             deaf: false,
             guild_id: guild.id,
             joined_at: None,
@@ -882,6 +997,15 @@ mod test {
             position: 0,
         };
 
+        let everyone_role = Role {
+            id: RoleId(guild.id.0),
+            colour: Colour::default(),
+            hoist: false,
+            mentionable: false,
+            name: "@everyone".to_string(),
+            ..role.clone()
+        };
+
         let channel = GuildChannel {
             id: ChannelId(111880193700067777),
             bitrate: None,
@@ -897,12 +1021,18 @@ mod test {
             user_limit: None,
             nsfw: false,
             slow_mode_rate: Some(0),
+            thread_metadata: None,
+            member: None,
+            available_tags: vec![],
+            applied_tags: vec![],
+            default_reaction_emoji: None,
         };
 
         let cache = Arc::new(Cache::default());
 
         guild.members.insert(user.id, member.clone());
         guild.roles.insert(role.id, role.clone());
+        guild.roles.insert(everyone_role.id, everyone_role.clone());
         cache.users.write().await.insert(user.id, user.clone());
         cache.guilds.write().await.insert(guild.id, guild.clone());
         cache.channels.write().await.insert(channel.id, channel.clone());
@@ -974,6 +1104,23 @@ mod test {
         assert_eq!(without_role_mentions,
             content_safe(&cache, with_role_mentions, &options).await);
 
+        let strip_options = options.clone().strip_roles(true);
+        assert_eq!("<@&> @deleted-role   ",
+            content_safe(&cache, with_role_mentions, &strip_options).await);
+
+        // The @everyone role, and a role missing from the cache
+        let with_role_edge_cases = "@everyone <@&381880193251409931> <@&444444444444444444>";
+
+        assert_eq!("@\u{200B}everyone @\u{200B}everyone @deleted-role",
+            content_safe(&cache, with_role_edge_cases, &options).await);
+
+        assert_eq!("@\u{200B}everyone  ",
+            content_safe(&cache, with_role_edge_cases, &strip_options).await);
+
+        let everyone_options = options.clone().clean_everyone(false);
+        assert_eq!("@everyone @\u{200B}everyone @deleted-role",
+            content_safe(&cache, with_role_edge_cases, &everyone_options).await);
+
         let options = options.clean_role(false);
         assert_eq!(with_role_mentions,
             content_safe(&cache, with_role_mentions, &options).await);